@@ -0,0 +1,200 @@
+/**
+ * @fileoverview Crash Reports
+ *
+ * Writes a JSON crash report (error message, stack, app version and the tail
+ * of the current log file) to the crash-reports folder in userData whenever
+ * the main process or a renderer dies, and starts Electron's native crash
+ * reporter so minidumps are kept locally. Reports are written synchronously
+ * because the process may exit immediately afterwards.
+ *
+ * Only depends on fs and Electron so it works before logging is initialized.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import type { App } from "electron";
+import { crashReporter } from "electron";
+
+export const CRASH_REPORTS_DIR_NAME = "crash-reports";
+
+/** Number of trailing log lines captured with each report */
+export const CRASH_LOG_TAIL_LINES = 200;
+
+/** Bytes read from the end of the log file to find the trailing lines */
+const LOG_TAIL_READ_BYTES = 256 * 1024;
+
+export type CrashKind =
+  | "uncaught-exception"
+  | "unhandled-rejection"
+  | "renderer-gone"
+  | "child-process-gone";
+
+export interface CrashReport {
+  id: string;
+  kind: CrashKind;
+  createdAt: string;
+  message: string;
+  stack: string | null;
+  appVersion: string;
+  electronVersion: string | null;
+  platform: string;
+  /** Extra facts about the crash (e.g. renderer exit reason) */
+  details: Record<string, unknown>;
+  /** Last lines of the most recent log file at the time of the crash */
+  logTail: string[];
+}
+
+export interface CrashReportInput {
+  kind: CrashKind;
+  error?: unknown;
+  message?: string;
+  details?: Record<string, unknown>;
+}
+
+export function getCrashReportsDir(app: App): string {
+  return path.join(app.getPath("userData"), CRASH_REPORTS_DIR_NAME);
+}
+
+/**
+ * Reads the last lines of the newest sheetpilot_*.log in a directory
+ */
+export function readLogTail(
+  logDir: string,
+  maxLines: number = CRASH_LOG_TAIL_LINES
+): string[] {
+  try {
+    const logFiles = fs
+      .readdirSync(logDir)
+      .filter((file) => file.startsWith("sheetpilot_") && file.endsWith(".log"))
+      .sort();
+    const latest = logFiles[logFiles.length - 1];
+    if (!latest) return [];
+
+    const logPath = path.join(logDir, latest);
+    const { size } = fs.statSync(logPath);
+    const length = Math.min(size, LOG_TAIL_READ_BYTES);
+    const buffer = Buffer.alloc(length);
+    const fd = fs.openSync(logPath, "r");
+    try {
+      fs.readSync(fd, buffer, 0, length, size - length);
+    } finally {
+      fs.closeSync(fd);
+    }
+
+    const lines = buffer.toString("utf8").split("\n").filter((line) => line.trim() !== "");
+    // The first line may be cut off when only part of the file was read
+    if (length < size) lines.shift();
+    return lines.slice(-maxLines);
+  } catch {
+    return [];
+  }
+}
+
+const describeError = (
+  error: unknown,
+  fallbackMessage: string | undefined
+): { message: string; stack: string | null } => {
+  if (error instanceof Error) {
+    return { message: error.message, stack: error.stack ?? null };
+  }
+  if (error !== undefined) {
+    return { message: String(error), stack: null };
+  }
+  return { message: fallbackMessage ?? "Unknown crash", stack: null };
+};
+
+/**
+ * Builds and writes a crash report; returns the report path, or null if it could not be written
+ */
+export function writeCrashReport(app: App, input: CrashReportInput): string | null {
+  try {
+    const createdAt = new Date();
+    const id = `${createdAt.toISOString().replace(/[:.]/g, "-")}_${input.kind}`;
+    const { message, stack } = describeError(input.error, input.message);
+    const report: CrashReport = {
+      id,
+      kind: input.kind,
+      createdAt: createdAt.toISOString(),
+      message,
+      stack,
+      appVersion: app.getVersion(),
+      electronVersion: process.versions.electron ?? null,
+      platform: process.platform,
+      details: input.details ?? {},
+      logTail: readLogTail(app.getPath("userData")),
+    };
+
+    const dir = getCrashReportsDir(app);
+    fs.mkdirSync(dir, { recursive: true });
+    const reportPath = path.join(dir, `crash_${id}.json`);
+    fs.writeFileSync(reportPath, JSON.stringify(report, null, 2), "utf8");
+    return reportPath;
+  } catch (err: unknown) {
+    console.error("Could not write crash report:", err);
+    return null;
+  }
+}
+
+/**
+ * Reads every saved crash report, newest first (unreadable files are skipped)
+ */
+export function listCrashReports(app: App): CrashReport[] {
+  const dir = getCrashReportsDir(app);
+  if (!fs.existsSync(dir)) return [];
+
+  const reports: CrashReport[] = [];
+  for (const file of fs.readdirSync(dir)) {
+    if (!file.startsWith("crash_") || !file.endsWith(".json")) continue;
+    try {
+      reports.push(JSON.parse(fs.readFileSync(path.join(dir, file), "utf8")) as CrashReport);
+    } catch {
+      // Partially written report from a crash during the crash; ignore
+    }
+  }
+  return reports.sort((a, b) => (a.createdAt < b.createdAt ? 1 : a.createdAt > b.createdAt ? -1 : 0));
+}
+
+/**
+ * Lists native minidumps captured by Electron's crash reporter
+ */
+export function listMinidumps(app: App): Array<{ file: string; size: number }> {
+  const dumps: Array<{ file: string; size: number }> = [];
+  const visit = (dir: string): void => {
+    if (!fs.existsSync(dir)) return;
+    for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+      const fullPath = path.join(dir, entry.name);
+      if (entry.isDirectory()) {
+        visit(fullPath);
+      } else if (entry.name.endsWith(".dmp")) {
+        dumps.push({ file: fullPath, size: fs.statSync(fullPath).size });
+      }
+    }
+  };
+  try {
+    visit(app.getPath("crashDumps"));
+  } catch {
+    // crashDumps path is unavailable before the crash reporter starts
+  }
+  return dumps;
+}
+
+/**
+ * Starts Electron's crash reporter in local-only mode so native crashes leave a minidump
+ */
+export function startNativeCrashReporter(app: App): void {
+  try {
+    crashReporter.start({
+      productName: "Sheetpilot",
+      submitURL: "",
+      uploadToServer: false,
+      compress: true,
+      extra: { appVersion: app.getVersion() },
+    });
+  } catch (err: unknown) {
+    console.error("Could not start native crash reporter:", err);
+  }
+}
@@ -1,8 +1,11 @@
 import type { App } from "electron";
 import { dialog } from "electron";
 import type { LoggerLike } from "@/bootstrap/logging/logger-contract";
+import { startNativeCrashReporter, writeCrashReport } from "./crash-reports";
 
 export function registerCrashHandlers(app: App, logger: LoggerLike): void {
+  startNativeCrashReporter(app);
+
   // Global safety nets for unhandled errors
   process.on("uncaughtException", (error: Error) => {
     logger.error("Uncaught exception detected", {
@@ -11,6 +14,12 @@ export function registerCrashHandlers(app: App, logger: LoggerLike): void {
       name: error.name,
     });
 
+    // Capture the report before anything else can fail
+    const reportPath = writeCrashReport(app, {
+      kind: "uncaught-exception",
+      error,
+    });
+
     // Always log to console first
     console.error(
       "═══════════════════════════════════════════════════════════"
@@ -34,7 +43,9 @@ export function registerCrashHandlers(app: App, logger: LoggerLike): void {
       try {
         dialog.showErrorBox(
           "Application Error",
-          `An unexpected error occurred:\n\n${error.message}\n\n${error.stack || ""}\n\nThe application will now exit.`
+          `An unexpected error occurred:\n\n${error.message}\n\n${error.stack || ""}\n\n${
+            reportPath ? `A crash report was saved to:\n${reportPath}\n\n` : ""
+          }The application will now exit.`
         );
         dialogShown = true;
       } catch (err: unknown) {
@@ -56,6 +67,21 @@ export function registerCrashHandlers(app: App, logger: LoggerLike): void {
       reason: reason instanceof Error ? reason.message : String(reason),
       stack: reason instanceof Error ? reason.stack : undefined,
     });
+    writeCrashReport(app, { kind: "unhandled-rejection", error: reason });
+  });
+
+  app.on("child-process-gone", (_event, details) => {
+    if (details.reason === "clean-exit") return;
+    logger.error("Child process exited abnormally", {
+      type: details.type,
+      reason: details.reason,
+      exitCode: details.exitCode,
+    });
+    writeCrashReport(app, {
+      kind: "child-process-gone",
+      message: `${details.type} process gone: ${details.reason}`,
+      details: { ...details },
+    });
   });
 
   process.on("rejectionHandled", () => {
@@ -0,0 +1,41 @@
+import * as fs from "fs";
+import * as path from "path";
+import { randomUUID } from "crypto";
+import type { App } from "electron";
+import type { LoggerLike } from "@/bootstrap/logging/logger-contract";
+
+const INSTANCE_ID_FILE = "instance-id";
+const INSTANCE_ID_PATTERN = /^[0-9a-f-]{36}$/;
+
+/**
+ * Reads this installation's identifier, creating it on first run
+ *
+ * Sessions are bound to it, so a token (or a copied database) from another
+ * installation is not accepted here.
+ */
+export function loadAppInstanceId(app: App, logger: LoggerLike): string {
+  const filePath = path.join(app.getPath("userData"), INSTANCE_ID_FILE);
+  try {
+    const existing = fs.readFileSync(filePath, "utf8").trim();
+    if (INSTANCE_ID_PATTERN.test(existing)) {
+      return existing;
+    }
+    logger.warn("App instance id file is malformed; replacing it", {
+      filePath,
+    });
+  } catch {
+    // First run (or unreadable file): create a new id below
+  }
+
+  const instanceId = randomUUID();
+  try {
+    fs.writeFileSync(filePath, instanceId, { encoding: "utf8", mode: 0o600 });
+    logger.info("App instance id created", { filePath });
+  } catch (error) {
+    // Sessions still work for this run; they just won't survive a restart
+    logger.warn("Could not persist app instance id", {
+      error: error instanceof Error ? error.message : String(error),
+    });
+  }
+  return instanceId;
+}
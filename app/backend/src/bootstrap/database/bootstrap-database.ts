@@ -5,20 +5,36 @@ import type { LoggerLike } from "@/bootstrap/logging/logger-contract";
 import {
   ensureSchema,
   getDb,
+  getCompatibilityInfo,
   getDbPath,
+  isDatabaseReadOnly,
   runMigrations,
   setDbPath,
+  setSessionInstanceId,
 } from "@/models";
+import { loadAppInstanceId } from "./app-instance-id";
 
 export function bootstrapDatabase(app: App, logger: LoggerLike): void {
   const timer = logger.startTimer("bootstrap-database");
   const dbFile = path.join(app.getPath("userData"), "sheetpilot.sqlite");
   logger.verbose("Setting database path", { dbFile });
   setDbPath(dbFile);
+  setSessionInstanceId(loadAppInstanceId(app, logger));
 
   // Run migrations before ensuring schema (handles version tracking and backups)
   logger.verbose("Running database migrations if needed");
   const db = getDb(); // This will initialize schema if needed
+  if (isDatabaseReadOnly(db)) {
+    // A newer app version migrated this database; read it but never touch it
+    const compatibility = getCompatibilityInfo();
+    logger.warn("Database is newer than this app version; opened read-only", {
+      schemaVersion: compatibility.schemaVersion,
+      appSchemaVersion: compatibility.appSchemaVersion,
+      dbPath: getDbPath(),
+    });
+    timer.done({ readOnly: true });
+    return;
+  }
   const migrationResult = runMigrations(db, getDbPath());
   if (!migrationResult.success) {
     logger.error("Database migration failed", {
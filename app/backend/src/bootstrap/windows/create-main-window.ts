@@ -3,7 +3,16 @@ import * as path from "path";
 import type { App, BrowserWindow } from "electron";
 import { BrowserWindow as ElectronBrowserWindow, dialog } from "electron";
 import type { LoggerLike } from "@/bootstrap/logging/logger-contract";
+import { writeCrashReport } from "@/bootstrap/crash-handlers/crash-reports";
 import type { WindowState } from "./window-state";
+import {
+  MAIN_WINDOW_LABEL,
+  WINDOW_LABEL_ARG_PREFIX,
+} from "@/logic/ipc-capabilities";
+import {
+  getFallbackDownloadPath,
+  getPlatformCapabilities,
+} from "@/services/health/app-health";
 
 export function createMainWindow(params: {
   app: App;
@@ -52,6 +61,8 @@ export function createMainWindow(params: {
     ...(iconPath ? { icon: iconPath } : {}),
     webPreferences: {
       preload: preloadPath,
+      // Tells the preload which bridges this window may expose
+      additionalArguments: [`${WINDOW_LABEL_ARG_PREFIX}${MAIN_WINDOW_LABEL}`],
       contextIsolation: true,
       nodeIntegration: false,
       sandbox: true,
@@ -105,6 +116,25 @@ export function createMainWindow(params: {
     }
   );
 
+  // Without a save dialog, downloads go straight to Downloads; with neither,
+  // they are refused (the renderer returns exports as text instead)
+  window.webContents.session.on("will-download", (event, item) => {
+    const capabilities = getPlatformCapabilities();
+    if (capabilities.fileDialogs) {
+      return;
+    }
+    if (capabilities.downloadsWritable) {
+      const savePath = getFallbackDownloadPath(item.getFilename());
+      item.setSavePath(savePath);
+      params.logger.info("Saving download without a dialog", { savePath });
+      return;
+    }
+    params.logger.warn("Download refused: no file dialog or writable Downloads folder", {
+      filename: item.getFilename(),
+    });
+    event.preventDefault();
+  });
+
   window.webContents.on(
     "console-message",
     (_e, level, message, line, sourceId) => {
@@ -201,6 +231,11 @@ export function createMainWindow(params: {
       reason: details.reason,
       exitCode: details.exitCode,
     });
+    writeCrashReport(params.app, {
+      kind: "renderer-gone",
+      message: `Renderer process gone: ${details.reason}`,
+      details: { reason: details.reason, exitCode: details.exitCode },
+    });
 
     try {
       dialog.showErrorBox(
@@ -10,7 +10,7 @@
  */
 
 import type { App, BrowserWindow } from "electron";
-import { dialog } from "electron";
+import { dialog, powerMonitor } from "electron";
 import { APP_VERSION } from "@sheetpilot/shared";
 import type { LoggerLike } from "@/bootstrap/logging/logger-contract";
 import { initializeLoggingOrExit } from "@/bootstrap/logging/init-logging";
@@ -22,6 +22,22 @@ import {
 } from "@/bootstrap/ipc/register-ipc";
 import { registerDefaultPluginsBootstrap } from "@/bootstrap/plugins/register-default-plugins";
 import type { RuntimeFlags } from "@/bootstrap/env";
+import { startScheduler, stopScheduler } from "@/services/scheduler/scheduler";
+import { registerScheduledExportTask } from "@/services/scheduler/export-schedule";
+import { registerGapReminderTask } from "@/services/timesheet/timesheet-gaps";
+import { registerLogIndexTask } from "@/services/logs/log-indexer";
+import { registerNetworkBackupTask } from "@/services/backup/network-backup";
+import {
+  holdFrontendLogs,
+  releaseFrontendLogs,
+} from "@/services/logs/frontend-log-queue";
+import { registerShutdownCoordinator } from "@/services/shutdown/shutdown-coordinator";
+import { registerSessionLockPolicy } from "@/services/security/security-policy";
+import { registerCredentialAccessMonitor } from "@/services/security/credential-access-monitor";
+import { registerCredentialKeychain } from "@/services/security/credential-keychain";
+import { detectPlatformCapabilities } from "@/services/health/app-health";
+import { startTimesheetStatusCountsWatcher } from "@/routes/handlers/timesheet/status-counts";
+import { stopStatusCountsWatcher } from "@/services/timesheet/status-counts";
 
 export interface AppControllerParams {
   app: App;
@@ -45,9 +61,13 @@ export async function initializeLogging(
 ): Promise<{ appLogger: LoggerLike; dbLogger: LoggerLike }> {
   const { app, shimAppLogger, logging } = params;
 
+  // Frontend lines are held (and persisted) until logging is up; if setup
+  // fails they are flushed on the next start
+  holdFrontendLogs(app.getPath("userData"));
   if (!initializeLoggingOrExit(app, shimAppLogger, logging.initializeLogging)) {
     throw new Error("Logging initialization failed - application will exit");
   }
+  releaseFrontendLogs();
 
   const appLogger: LoggerLike = logging.appLogger;
   const dbLogger: LoggerLike = logging.dbLogger;
@@ -117,6 +137,83 @@ export function initializeRoutes(params: {
   }
 }
 
+/**
+ * Register scheduled tasks and start the scheduler
+ * Failures are logged but never block startup
+ */
+export function initializeScheduler(app: App, logger: LoggerLike): void {
+  try {
+    registerScheduledExportTask();
+    registerLogIndexTask();
+    registerNetworkBackupTask();
+    registerGapReminderTask();
+    startScheduler();
+    app.on("will-quit", () => stopScheduler());
+  } catch (err: unknown) {
+    logger.error("Could not start scheduler", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
+
+/**
+ * Cancel or finish in-flight work, close browsers and flush logs on quit
+ */
+export function initializeShutdownCoordinator(app: App, logger: LoggerLike): void {
+  try {
+    registerShutdownCoordinator(app);
+  } catch (err: unknown) {
+    logger.error("Could not register shutdown coordinator", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
+
+/**
+ * End sessions on workstation lock/sleep according to the security policy,
+ * alert on unexpected credential reads and start the keychain migration
+ */
+export function initializeSecurityPolicy(logger: LoggerLike): void {
+  try {
+    registerCredentialAccessMonitor();
+    registerCredentialKeychain();
+    registerSessionLockPolicy(powerMonitor);
+  } catch (err: unknown) {
+    logger.error("Could not register security policy", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
+
+/**
+ * Keep the window title, badge and renderer status counts current
+ * Failures are logged but never block startup
+ */
+export function initializeStatusCounts(app: App, logger: LoggerLike): void {
+  try {
+    startTimesheetStatusCountsWatcher();
+    app.on("will-quit", () => stopStatusCountsWatcher());
+  } catch (err: unknown) {
+    logger.error("Could not start status count updates", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
+
+/**
+ * Probe file dialogs and the Downloads folder so exports can fall back to
+ * text on locked-down builds
+ */
+export function initializePlatformCapabilities(logger: LoggerLike): void {
+  try {
+    detectPlatformCapabilities();
+  } catch (err: unknown) {
+    logger.error("Could not detect platform capabilities", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
+
 /**
  * Set the main window reference for IPC handlers
  */
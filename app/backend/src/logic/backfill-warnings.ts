@@ -0,0 +1,72 @@
+/**
+ * @fileoverview Backfill Warnings
+ *
+ * Flags entries dated in a quarter whose form is closed or about to close,
+ * so the user hears about it when saving or before submitting rather than
+ * when the bot fails to load an old form.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import { getQuarterClosure, type QuarterClosureState } from '@sheetpilot/bot';
+
+/**
+ * Warning for an entry whose quarter form is not (or soon not) accepting entries
+ */
+export interface BackfillWarning {
+  id?: number | undefined;
+  date: string;
+  state: Exclude<QuarterClosureState, 'open' | 'invalid'>;
+  quarterId: string | null;
+  lockDate: string | null;
+  message: string;
+}
+
+/**
+ * Checks one entry date against its quarter's closure state
+ *
+ * @param date - MM/DD/YYYY or YYYY-MM-DD; empty or malformed dates are ignored
+ * @returns Warning, or null if the quarter is open
+ */
+export function getBackfillWarning(
+  date: string | null | undefined,
+  today: Date = new Date(),
+  id?: number
+): BackfillWarning | null {
+  if (!date) return null;
+
+  let isoDate: string;
+  try {
+    isoDate = normalizeDateToISO(date);
+  } catch {
+    return null;
+  }
+
+  const closure = getQuarterClosure(isoDate, today);
+  if (closure.state === 'open' || closure.state === 'invalid' || !closure.message) {
+    return null;
+  }
+  return {
+    id,
+    date,
+    state: closure.state,
+    quarterId: closure.quarter?.id ?? null,
+    lockDate: closure.lockDate,
+    message: closure.message
+  };
+}
+
+/**
+ * Checks many entries, returning warnings only for affected ones
+ */
+export function getBackfillWarnings(
+  entries: Array<{ id: number; date: string | null }>,
+  today: Date = new Date()
+): BackfillWarning[] {
+  return entries
+    .map((entry) => getBackfillWarning(entry.date, today, entry.id))
+    .filter((warning): warning is BackfillWarning => warning !== null);
+}
@@ -0,0 +1,194 @@
+/**
+ * @fileoverview Calendar Import Logic
+ *
+ * Pure functions that turn calendar events into draft timesheet rows:
+ * category/keyword filtering, duration rounding, and duplicate flagging.
+ * Fetching events lives in the Graph service.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { roundMinutesToQuarterHours } from '@sheetpilot/shared';
+import { getSystemTimeZone, toSlashDateInZone } from './time-zones';
+
+export const DEFAULT_CALENDAR_IMPORT_PROJECT = 'Internal Meetings';
+
+/**
+ * Calendar event (normalized from the Graph calendarView response)
+ */
+export interface CalendarEvent {
+  id: string;
+  subject: string;
+  /** Start/end as ISO timestamps */
+  start: string;
+  end: string;
+  categories: string[];
+  isAllDay: boolean;
+  isCancelled: boolean;
+  /** Graph free/busy status: free, tentative, busy, oof, workingElsewhere */
+  showAs?: string | undefined;
+}
+
+/**
+ * Rules for deciding which events become entries
+ *
+ * An event is included when it matches any include category or keyword
+ * (or when both include lists are empty) and matches no exclude keyword.
+ * Keyword matching is case-insensitive against the subject.
+ */
+export interface CalendarImportRules {
+  includeCategories?: string[] | undefined;
+  includeKeywords?: string[] | undefined;
+  excludeKeywords?: string[] | undefined;
+  /** Skip events marked free (default true) */
+  skipFree?: boolean | undefined;
+}
+
+export interface CalendarImportMapping {
+  project: string;
+  tool?: string | null | undefined;
+  chargeCode?: string | null | undefined;
+}
+
+/**
+ * Zones used to date imported events
+ */
+export interface CalendarImportTimeZones {
+  /** Zone entries are dated in (the system zone when omitted) */
+  home?: string | undefined;
+  /** Zone the meetings took place in, recorded on each draft for auditing */
+  entry?: string | undefined;
+}
+
+/**
+ * Draft row proposed from a calendar event
+ */
+export interface CalendarDraftPreview {
+  eventId: string;
+  /** MM/DD/YYYY, matching the timesheet table */
+  date: string;
+  hours: number;
+  project: string;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string;
+  /** Zone the meeting took place in, when the import was for a trip */
+  timeZone?: string;
+  /** True if an entry with the same date, project and description already exists */
+  isDuplicate: boolean;
+}
+
+export interface CalendarSkippedEvent {
+  eventId: string;
+  subject: string;
+  reason: 'all-day' | 'cancelled' | 'free' | 'filtered' | 'too-short' | 'multi-day';
+}
+
+const MAX_DESCRIPTION_LENGTH = 500;
+
+export { roundMinutesToQuarterHours };
+
+const lower = (values: string[] | undefined): string[] =>
+  (values ?? []).map((v) => v.trim().toLowerCase()).filter((v) => v.length > 0);
+
+/**
+ * Checks whether an event passes the category/keyword rules
+ */
+export function matchesCalendarRules(event: CalendarEvent, rules: CalendarImportRules): boolean {
+  const subject = event.subject.toLowerCase();
+  const categories = lower(event.categories);
+  const includeCategories = lower(rules.includeCategories);
+  const includeKeywords = lower(rules.includeKeywords);
+  const excludeKeywords = lower(rules.excludeKeywords);
+
+  if (excludeKeywords.some((keyword) => subject.includes(keyword))) {
+    return false;
+  }
+  if (includeCategories.length === 0 && includeKeywords.length === 0) {
+    return true;
+  }
+  return (
+    includeCategories.some((category) => categories.includes(category)) ||
+    includeKeywords.some((keyword) => subject.includes(keyword))
+  );
+}
+
+/**
+ * Converts calendar events into draft previews
+ *
+ * @param events - Events in the requested range
+ * @param rules - Category/keyword filter rules
+ * @param mapping - Project/tool/charge code to assign
+ * @param isExisting - Returns true if an entry with this key is already stored
+ * @param timeZones - Home zone for dates; hours come from the absolute duration
+ */
+export function buildCalendarDraftPreviews(
+  events: CalendarEvent[],
+  rules: CalendarImportRules,
+  mapping: CalendarImportMapping,
+  isExisting: (key: { date: string; project: string; taskDescription: string }) => boolean = () => false,
+  timeZones: CalendarImportTimeZones = {}
+): { drafts: CalendarDraftPreview[]; skipped: CalendarSkippedEvent[] } {
+  // Keys proposed earlier in this batch; the table is unique on (date, project, description)
+  const proposedKeys = new Set<string>();
+  const drafts: CalendarDraftPreview[] = [];
+  const skipped: CalendarSkippedEvent[] = [];
+  const homeTimeZone = timeZones.home ?? getSystemTimeZone();
+  const toSlashDate = (instant: Date): string => toSlashDateInZone(instant, homeTimeZone);
+  const skip = (event: CalendarEvent, reason: CalendarSkippedEvent['reason']): void => {
+    skipped.push({ eventId: event.id, subject: event.subject, reason });
+  };
+
+  const sorted = [...events].sort((a, b) => a.start.localeCompare(b.start));
+  for (const event of sorted) {
+    if (event.isCancelled) {
+      skip(event, 'cancelled');
+      continue;
+    }
+    if (event.isAllDay) {
+      skip(event, 'all-day');
+      continue;
+    }
+    if ((rules.skipFree ?? true) && event.showAs === 'free') {
+      skip(event, 'free');
+      continue;
+    }
+    if (!matchesCalendarRules(event, rules)) {
+      skip(event, 'filtered');
+      continue;
+    }
+
+    const start = new Date(event.start);
+    const end = new Date(event.end);
+    if (toSlashDate(start) !== toSlashDate(new Date(end.getTime() - 1))) {
+      skip(event, 'multi-day');
+      continue;
+    }
+    const hours = roundMinutesToQuarterHours((end.getTime() - start.getTime()) / 60000);
+    if (hours < 0.25) {
+      skip(event, 'too-short');
+      continue;
+    }
+
+    const date = toSlashDate(start);
+    const taskDescription = (event.subject.trim() || 'Meeting').slice(0, MAX_DESCRIPTION_LENGTH);
+    const key = `${date}|${mapping.project}|${taskDescription}`;
+    drafts.push({
+      eventId: event.id,
+      date,
+      hours,
+      project: mapping.project,
+      tool: mapping.tool ?? null,
+      chargeCode: mapping.chargeCode ?? null,
+      taskDescription,
+      ...(timeZones.entry ? { timeZone: timeZones.entry } : {}),
+      isDuplicate:
+        proposedKeys.has(key) || isExisting({ date, project: mapping.project, taskDescription }),
+    });
+    proposedKeys.add(key);
+  }
+
+  return { drafts, skipped };
+}
@@ -0,0 +1,149 @@
+/**
+ * @fileoverview Charge Code Quotas
+ *
+ * Soft hour budgets per charge code per quarter. Submitted hours plus the
+ * pending drafts are compared against each quota so overruns show up before
+ * submitting instead of weeks later in finance's reports. Quotas never block
+ * a submission; they only produce warnings.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import { getQuarterForDate } from '@sheetpilot/bot';
+
+export interface ChargeCodeQuota {
+  chargeCode: string;
+  /** Quarter identifier, e.g. 'Q3-2025' */
+  quarterId: string;
+  /** Hours budgeted for the charge code in that quarter */
+  hours: number;
+}
+
+export type QuotaState = 'ok' | 'near' | 'over';
+
+export interface ChargeCodeQuotaStatus extends ChargeCodeQuota {
+  submittedHours: number;
+  /** Hours in drafts that would be submitted next */
+  pendingHours: number;
+  /** submittedHours + pendingHours */
+  projectedHours: number;
+  /** Negative once the quota is exceeded */
+  remainingHours: number;
+  state: QuotaState;
+}
+
+export interface QuotaWarning {
+  chargeCode: string;
+  quarterId: string;
+  quotaHours: number;
+  projectedHours: number;
+  /** Entry IDs whose hours count toward the overrun */
+  entryIds: number[];
+  message: string;
+}
+
+/** Projected use at or above this share of a quota is reported as 'near' */
+export const QUOTA_NEAR_RATIO = 0.9;
+
+interface QuotaEntry {
+  id: number;
+  date: string | null;
+  hours: number | null;
+  detail_charge_code?: string | null;
+}
+
+const roundHours = (hours: number): number => Math.round(hours * 100) / 100;
+
+/**
+ * Key matching a charge code regardless of case and surrounding whitespace
+ */
+export const chargeCodeKey = (chargeCode: string): string => chargeCode.trim().toLowerCase();
+
+const quotaKey = (chargeCode: string, quarterId: string): string =>
+  `${chargeCodeKey(chargeCode)}|${quarterId}`;
+
+/**
+ * Quarter an entry date falls in (MM/DD/YYYY or YYYY-MM-DD), or null
+ */
+const getEntryQuarterId = (date: string | null): string | null => {
+  if (!date) return null;
+  try {
+    return getQuarterForDate(normalizeDateToISO(date))?.id ?? null;
+  } catch {
+    return null;
+  }
+};
+
+/**
+ * Compares each quota with submitted hours and pending entries
+ *
+ * @param getSubmittedHours - Hours already submitted for a quota's charge code and quarter
+ */
+export function buildQuotaStatus(
+  quotas: ChargeCodeQuota[],
+  getSubmittedHours: (quota: ChargeCodeQuota) => number,
+  pendingEntries: QuotaEntry[]
+): ChargeCodeQuotaStatus[] {
+  const pendingByQuota = new Map<string, number>();
+  for (const entry of pendingEntries) {
+    if (!entry.detail_charge_code || !entry.hours) continue;
+    const quarterId = getEntryQuarterId(entry.date);
+    if (!quarterId) continue;
+    const key = quotaKey(entry.detail_charge_code, quarterId);
+    pendingByQuota.set(key, (pendingByQuota.get(key) ?? 0) + entry.hours);
+  }
+
+  return quotas.map((quota) => {
+    const submittedHours = roundHours(getSubmittedHours(quota));
+    const pendingHours = roundHours(pendingByQuota.get(quotaKey(quota.chargeCode, quota.quarterId)) ?? 0);
+    const projectedHours = roundHours(submittedHours + pendingHours);
+    let state: QuotaState = 'ok';
+    if (projectedHours > quota.hours) {
+      state = 'over';
+    } else if (projectedHours >= quota.hours * QUOTA_NEAR_RATIO) {
+      state = 'near';
+    }
+    return {
+      ...quota,
+      submittedHours,
+      pendingHours,
+      projectedHours,
+      remainingHours: roundHours(quota.hours - projectedHours),
+      state
+    };
+  });
+}
+
+/**
+ * Warnings for quotas the pending entries would push over budget; quotas
+ * already exceeded by submitted hours alone only warn if drafts add to them
+ */
+export function getQuotaWarnings(
+  statuses: ChargeCodeQuotaStatus[],
+  pendingEntries: QuotaEntry[]
+): QuotaWarning[] {
+  return statuses
+    .filter((status) => status.state === 'over' && status.pendingHours > 0)
+    .map((status) => {
+      const key = quotaKey(status.chargeCode, status.quarterId);
+      const entryIds = pendingEntries
+        .filter(
+          (entry) =>
+            entry.detail_charge_code &&
+            entry.hours &&
+            quotaKey(entry.detail_charge_code, getEntryQuarterId(entry.date) ?? '') === key
+        )
+        .map((entry) => entry.id);
+      return {
+        chargeCode: status.chargeCode,
+        quarterId: status.quarterId,
+        quotaHours: status.hours,
+        projectedHours: status.projectedHours,
+        entryIds,
+        message: `${status.chargeCode} would reach ${status.projectedHours}h of its ${status.hours}h quota for ${status.quarterId}`
+      };
+    });
+}
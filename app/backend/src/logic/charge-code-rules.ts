@@ -0,0 +1,93 @@
+/**
+ * @fileoverview Charge Code Rules
+ *
+ * Pattern and allow-list checks for charge codes. Rules are configured per
+ * submission service profile so malformed codes are rejected before the bot
+ * tries to match them against the form's dropdown options.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+/** Profile used when the active submission service has no rule of its own */
+export const DEFAULT_CHARGE_CODE_PROFILE = 'default';
+
+/**
+ * Charge code rule for one service profile
+ *
+ * A code passes when it matches the pattern (if set) or appears in the
+ * allow-list (if set). With neither set, every code passes.
+ */
+export interface ChargeCodeRule {
+  /** Regular expression source, e.g. `^[A-Z]{2}\d{4}-\d{2}$` */
+  pattern?: string | undefined;
+  /** Codes accepted regardless of the pattern */
+  allowList?: string[] | undefined;
+}
+
+const isStringArray = (value: unknown): value is string[] =>
+  Array.isArray(value) && value.every((item) => typeof item === 'string');
+
+const toRule = (value: unknown): ChargeCodeRule | null => {
+  if (!value || typeof value !== 'object') return null;
+  const raw = value as Record<string, unknown>;
+  const rule: ChargeCodeRule = {};
+  if (typeof raw['pattern'] === 'string' && raw['pattern'].length > 0) {
+    rule.pattern = raw['pattern'];
+  }
+  if (isStringArray(raw['allowList'])) {
+    rule.allowList = raw['allowList'];
+  }
+  return rule.pattern !== undefined || rule.allowList !== undefined ? rule : null;
+};
+
+/**
+ * Picks the rule for a service profile from plugin options
+ *
+ * Expects `options.chargeCodeRules` keyed by profile name, falling back to
+ * the `default` profile.
+ *
+ * @param options - Submission plugin options from plugin-config.json
+ * @param profile - Active submission service name
+ * @returns Rule to apply, or null when none is configured
+ */
+export function resolveChargeCodeRule(
+  options: Record<string, unknown> | undefined,
+  profile: string | null
+): ChargeCodeRule | null {
+  const rules = options?.['chargeCodeRules'];
+  if (!rules || typeof rules !== 'object') return null;
+  const byProfile = rules as Record<string, unknown>;
+  return (
+    (profile ? toRule(byProfile[profile]) : null) ?? toRule(byProfile[DEFAULT_CHARGE_CODE_PROFILE])
+  );
+}
+
+/**
+ * Checks a charge code against a rule
+ *
+ * Empty codes are left to the required-field checks.
+ *
+ * @returns Error message, or null if the code is acceptable
+ */
+export function checkChargeCode(
+  chargeCode: string | null | undefined,
+  rule: ChargeCodeRule | null | undefined
+): string | null {
+  if (!rule || !chargeCode) return null;
+  if (rule.allowList?.includes(chargeCode)) return null;
+
+  if (rule.pattern !== undefined) {
+    let matches: boolean;
+    try {
+      matches = new RegExp(rule.pattern).test(chargeCode);
+    } catch {
+      return `Charge code rule pattern is invalid: ${rule.pattern}`;
+    }
+    if (matches) return null;
+    return `Charge code "${chargeCode}" does not match the expected format ${rule.pattern}`;
+  }
+
+  return `Charge code "${chargeCode}" is not in the allowed list`;
+}
@@ -0,0 +1,75 @@
+/**
+ * @fileoverview Description Template Logic
+ *
+ * Description templates word recurring tasks the same way for the whole
+ * team, e.g. `Calibrated {tool} for {project} ({ticket})`. Placeholders are
+ * filled in when a draft is created; a placeholder without a value is left
+ * out along with the brackets or separator around it, so an entry without a
+ * ticket reads `Calibrated Mixer for OSC` rather than `... ()`.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const DESCRIPTION_TEMPLATE_VARIABLES = ['project', 'tool', 'date', 'ticket'] as const;
+
+export type DescriptionTemplateVariable = (typeof DESCRIPTION_TEMPLATE_VARIABLES)[number];
+
+export type DescriptionTemplateVars = Partial<Record<DescriptionTemplateVariable, string | null>>;
+
+export interface DescriptionTemplate {
+  id: string;
+  name: string;
+  template: string;
+}
+
+export interface RenderedDescription {
+  description: string;
+  /** Placeholders the template uses that had no value */
+  missing: DescriptionTemplateVariable[];
+}
+
+const PLACEHOLDER = /\{(\w+)\}/g;
+
+const isTemplateVariable = (name: string): name is DescriptionTemplateVariable =>
+  (DESCRIPTION_TEMPLATE_VARIABLES as readonly string[]).includes(name);
+
+/**
+ * Placeholders in a template that are not template variables
+ */
+export function findUnknownPlaceholders(template: string): string[] {
+  const unknown = Array.from(template.matchAll(PLACEHOLDER), (match) => match[1]!).filter(
+    (name) => !isTemplateVariable(name)
+  );
+  return Array.from(new Set(unknown));
+}
+
+/**
+ * Fills in a template's placeholders
+ */
+export function renderDescriptionTemplate(template: string, vars: DescriptionTemplateVars): RenderedDescription {
+  const missing = new Set<DescriptionTemplateVariable>();
+  const rendered = template.replace(PLACEHOLDER, (token, name: string) => {
+    if (!isTemplateVariable(name)) {
+      return token;
+    }
+    const value = vars[name]?.trim();
+    if (!value) {
+      missing.add(name);
+      return '';
+    }
+    return value;
+  });
+
+  const description = rendered
+    // Brackets left empty by a missing value
+    .replace(/\(\s*\)|\[\s*\]/g, '')
+    // Separators left dangling at either end or doubled up
+    .replace(/\s*([-–,:;/|])\s*(?=[-–,:;/|]|$)/g, '')
+    .replace(/^\s*[-–,:;/|]\s*/, '')
+    .replace(/\s{2,}/g, ' ')
+    .trim();
+
+  return { description, missing: Array.from(missing) };
+}
@@ -0,0 +1,108 @@
+/**
+ * @fileoverview Draft Statistics
+ *
+ * Summarizes per-date draft totals into the figures shown in the editor
+ * header: draft count, total hours, dates covered and a per-quarter
+ * breakdown.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { getQuarterForDate, type QuarterDefinition } from '@sheetpilot/bot';
+
+/**
+ * Draft count and hours for one date (date is YYYY-MM-DD or null when unset)
+ */
+export interface DraftDateTotal {
+  date: string | null;
+  entryCount: number;
+  hours: number;
+}
+
+export interface DraftQuarterStats {
+  /** Quarter ID, or null for dates outside every configured quarter */
+  quarterId: string | null;
+  quarterName: string | null;
+  draftCount: number;
+  hours: number;
+}
+
+export interface DraftStats {
+  draftCount: number;
+  totalHours: number;
+  /** Distinct dates with at least one draft */
+  datesCovered: number;
+  earliestDate: string | null;
+  latestDate: string | null;
+  /** Drafts that have no date yet (not included in any quarter) */
+  undatedCount: number;
+  quarters: DraftQuarterStats[];
+}
+
+const roundHours = (hours: number): number => Math.round(hours * 100) / 100;
+
+/**
+ * Builds draft statistics from per-date totals
+ *
+ * @param totals - Per-date draft totals, in any order
+ * @param quarterFor - Quarter lookup (defaults to the configured quarters)
+ */
+export function summarizeDraftStats(
+  totals: DraftDateTotal[],
+  quarterFor: (date: string) => QuarterDefinition | null = getQuarterForDate
+): DraftStats {
+  const quarters = new Map<string | null, DraftQuarterStats>();
+  const dates: string[] = [];
+  let draftCount = 0;
+  let totalHours = 0;
+  let undatedCount = 0;
+
+  for (const total of totals) {
+    draftCount += total.entryCount;
+    totalHours += total.hours;
+    if (!total.date) {
+      undatedCount += total.entryCount;
+      continue;
+    }
+    dates.push(total.date);
+
+    const quarter = quarterFor(total.date);
+    const key = quarter?.id ?? null;
+    const stats = quarters.get(key) ?? {
+      quarterId: key,
+      quarterName: quarter?.name ?? null,
+      draftCount: 0,
+      hours: 0,
+    };
+    stats.draftCount += total.entryCount;
+    stats.hours = roundHours(stats.hours + total.hours);
+    quarters.set(key, stats);
+  }
+
+  dates.sort();
+  return {
+    draftCount,
+    totalHours: roundHours(totalHours),
+    datesCovered: new Set(dates).size,
+    earliestDate: dates[0] ?? null,
+    latestDate: dates[dates.length - 1] ?? null,
+    undatedCount,
+    // Configured quarters in date order, dates outside every quarter last
+    quarters: [...quarters.values()].sort((a, b) => {
+      if (a.quarterId === null) return 1;
+      if (b.quarterId === null) return -1;
+      return a.quarterId === b.quarterId ? 0 : compareQuarterIds(a.quarterId, b.quarterId);
+    }),
+  };
+}
+
+/** Orders quarter IDs like Q4-2025 before Q1-2026 */
+const compareQuarterIds = (a: string, b: string): number => {
+  const key = (id: string): string => {
+    const match = /^Q(\d)-(\d{4})$/.exec(id);
+    return match ? `${match[2]}-${match[1]}` : id;
+  };
+  return key(a).localeCompare(key(b));
+};
@@ -0,0 +1,50 @@
+/**
+ * @fileoverview Entry Categories
+ *
+ * Local labels such as "Production support" or "Project work" that a team
+ * puts on entries to tell kinds of work apart in the grid and in reports.
+ * Categories are never sent to Smartsheet. Entries store the category name,
+ * so exports and backups stay readable without the category list.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const MAX_ENTRY_CATEGORY_NAME_LENGTH = 60;
+
+/** Color hint for the UI, "#rrggbb" */
+export const ENTRY_CATEGORY_COLOR_PATTERN = /^#[0-9a-fA-F]{6}$/;
+
+/** Color used for categories saved without one */
+export const DEFAULT_ENTRY_CATEGORY_COLOR = '#8a8f98';
+
+export interface EntryCategory {
+  id: string;
+  name: string;
+  color: string;
+}
+
+export const sameCategoryName = (a: string, b: string): boolean =>
+  a.trim().toLowerCase() === b.trim().toLowerCase();
+
+/**
+ * Checks an entry's category against the managed list
+ *
+ * @returns Error message, or null if the category is empty or known
+ */
+export function checkEntryCategory(
+  category: string | null | undefined,
+  categories: readonly EntryCategory[]
+): string | null {
+  if (!category) {
+    return null;
+  }
+  if (categories.some((candidate) => candidate.name === category)) {
+    return null;
+  }
+  const names = categories.map((candidate) => candidate.name);
+  return names.length > 0
+    ? `Unknown category "${category}". Use one of: ${names.join(', ')}`
+    : `Unknown category "${category}". Add it to the category list first`;
+}
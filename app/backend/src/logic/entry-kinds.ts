@@ -0,0 +1,135 @@
+/**
+ * @fileoverview Entry Kind Submission Rules
+ *
+ * How PTO, holiday and training entries reach the form. Some forms book time
+ * off to a dedicated project; others must not see it at all. Rules are read
+ * from the submission plugin options per service profile, like the charge
+ * code rules:
+ *
+ *   "entryKinds": {
+ *     "default": {
+ *       "pto": { "project": "PTO/RTO" },
+ *       "holiday": "exclude"
+ *     }
+ *   }
+ *
+ * Work entries are always submitted as entered.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import {
+  ENTRY_KIND_LABELS,
+  entryKindNeedsProject,
+  parseEntryKind,
+  type EntryKind
+} from '@sheetpilot/shared';
+
+/** Profile used when the active submission service has no rules of its own */
+export const DEFAULT_ENTRY_KIND_PROFILE = 'default';
+
+/** What happens to entries of one kind when submitting */
+export type EntryKindSubmission =
+  /** Submit under this project, tool and charge code instead of the entered ones */
+  | { action: 'map'; project: string; tool: string | null; chargeCode: string | null }
+  /** Keep the entry out of the submission; it stays a draft */
+  | { action: 'exclude' };
+
+export type EntryKindRules = Partial<Record<Exclude<EntryKind, 'work'>, EntryKindSubmission>>;
+
+const optionalString = (value: unknown): string | null =>
+  typeof value === 'string' && value.length > 0 ? value : null;
+
+const toSubmission = (value: unknown): EntryKindSubmission | null => {
+  if (value === 'exclude') return { action: 'exclude' };
+  if (!value || typeof value !== 'object') return null;
+  const raw = value as Record<string, unknown>;
+  const project = optionalString(raw['project']);
+  if (!project) return null;
+  return {
+    action: 'map',
+    project,
+    tool: optionalString(raw['tool']),
+    chargeCode: optionalString(raw['chargeCode'])
+  };
+};
+
+const toRules = (value: unknown): EntryKindRules | null => {
+  if (!value || typeof value !== 'object') return null;
+  const raw = value as Record<string, unknown>;
+  const rules: EntryKindRules = {};
+  for (const kind of ['pto', 'holiday', 'training'] as const) {
+    const submission = toSubmission(raw[kind]);
+    if (submission) rules[kind] = submission;
+  }
+  return Object.keys(rules).length > 0 ? rules : null;
+};
+
+/**
+ * Picks the rules for a service profile from plugin options
+ *
+ * Expects `options.entryKinds` keyed by profile name, falling back to the
+ * `default` profile. A profile's rules replace the default ones as a whole.
+ *
+ * @param options - Submission plugin options from plugin-config.json
+ * @param profile - Active submission service name
+ * @returns Rules to apply (empty when none are configured)
+ */
+export function resolveEntryKindRules(
+  options: Record<string, unknown> | undefined,
+  profile: string | null
+): EntryKindRules {
+  const configured = options?.['entryKinds'];
+  if (!configured || typeof configured !== 'object') return {};
+  const byProfile = configured as Record<string, unknown>;
+  return (
+    (profile ? toRules(byProfile[profile]) : null) ??
+    toRules(byProfile[DEFAULT_ENTRY_KIND_PROFILE]) ??
+    {}
+  );
+}
+
+/** Entry fields the submission plan looks at */
+export interface EntryKindSource {
+  entry_kind?: string | null;
+  project: string | null;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+}
+
+export type EntryKindPlan =
+  | { action: 'submit'; project: string | null; tool: string | null; chargeCode: string | null }
+  | { action: 'exclude'; reason: string };
+
+/**
+ * Decides how one entry is submitted under the active rules
+ *
+ * Time off without a project and without a rule cannot be filled in, so it
+ * is excluded rather than failing on the form.
+ */
+export function planEntryKindSubmission(entry: EntryKindSource, rules: EntryKindRules): EntryKindPlan {
+  const kind = parseEntryKind(entry.entry_kind);
+  const rule = kind === 'work' ? undefined : rules[kind];
+  const label = ENTRY_KIND_LABELS[kind];
+
+  if (rule?.action === 'exclude') {
+    return { action: 'exclude', reason: `${label} entries are not submitted for this service` };
+  }
+  if (rule?.action === 'map') {
+    return { action: 'submit', project: rule.project, tool: rule.tool, chargeCode: rule.chargeCode };
+  }
+  if (!entry.project && !entryKindNeedsProject(kind)) {
+    return {
+      action: 'exclude',
+      reason: `${label} entry has no project and no ${label} project is configured for this service`
+    };
+  }
+  return {
+    action: 'submit',
+    project: entry.project,
+    tool: entry.tool ?? null,
+    chargeCode: entry.detail_charge_code ?? null
+  };
+}
@@ -0,0 +1,84 @@
+/**
+ * @fileoverview External Reference Logic
+ *
+ * Entries can carry an external reference (e.g. an MES work order number)
+ * so exported hours can be matched to the work they were spent on. Sites set
+ * the format with `external_ref.pattern` in settings.json, a regular
+ * expression the whole reference must match (e.g. `WO-\d{6}`), and can have
+ * the reference appended to the task description Smartsheet receives with
+ * `external_ref.append_to_description`.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const MAX_EXTERNAL_REF_LENGTH = 64;
+
+export interface ExternalRefSettings {
+  /** Regular expression the whole reference must match */
+  pattern?: string;
+  /** Add " [<ref>]" to the task description when filling the form */
+  append_to_description?: boolean;
+}
+
+export interface ExternalRefRule {
+  pattern: RegExp | null;
+  /** Pattern source as configured, for error messages */
+  source: string | null;
+  appendToDescription: boolean;
+}
+
+/**
+ * Rule for a stored (possibly malformed) setting; an invalid pattern is
+ * reported in `error` and not enforced
+ */
+export function resolveExternalRefRule(raw: unknown): { rule: ExternalRefRule; error: string | null } {
+  const settings = raw && typeof raw === 'object' ? (raw as Record<string, unknown>) : {};
+  const appendToDescription = settings['append_to_description'] === true;
+  const source = typeof settings['pattern'] === 'string' ? settings['pattern'].trim() : '';
+  if (!source) {
+    return { rule: { pattern: null, source: null, appendToDescription }, error: null };
+  }
+  try {
+    return {
+      rule: { pattern: new RegExp(`^(?:${source})$`), source, appendToDescription },
+      error: null,
+    };
+  } catch (err: unknown) {
+    return {
+      rule: { pattern: null, source: null, appendToDescription },
+      error: `Invalid external_ref pattern /${source}/: ${err instanceof Error ? err.message : String(err)}`,
+    };
+  }
+}
+
+/**
+ * Checks a reference against the configured format
+ *
+ * @returns An error message, or null when the reference is empty or valid
+ */
+export function checkExternalRef(value: string | null | undefined, rule: ExternalRefRule): string | null {
+  const ref = value?.trim();
+  if (!ref || !rule.pattern) {
+    return null;
+  }
+  return rule.pattern.test(ref)
+    ? null
+    : `External reference "${ref}" does not match the required format (${rule.source})`;
+}
+
+/**
+ * Task description sent to the form, with the reference appended when configured
+ */
+export function describeWithExternalRef(
+  taskDescription: string,
+  externalRef: string | null | undefined,
+  rule: ExternalRefRule
+): string {
+  const ref = externalRef?.trim();
+  if (!rule.appendToDescription || !ref || taskDescription.includes(`[${ref}]`)) {
+    return taskDescription;
+  }
+  return `${taskDescription} [${ref}]`;
+}
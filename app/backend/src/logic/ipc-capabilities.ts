@@ -0,0 +1,71 @@
+/**
+ * @fileoverview IPC Capabilities
+ *
+ * Which windows may use which groups of IPC commands. Every BrowserWindow is
+ * given a label (passed to its preload as a command-line argument); the
+ * preload only exposes bridges the label is allowed to use, and sensitive
+ * handlers check the invoking window's label again in the main process.
+ *
+ * Kept free of Electron imports so the preload and main process share it.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const MAIN_WINDOW_LABEL = 'main';
+
+/** Argument used to tell a window's preload which label it runs under */
+export const WINDOW_LABEL_ARG_PREFIX = '--sheetpilot-window=';
+
+/**
+ * Command groups
+ * - core: everything not listed below
+ * - credentials: reading/writing stored service credentials
+ * - admin: destructive maintenance and the raw read-only query runner
+ * - database: full archive and schema reads
+ */
+export type IpcCapability = 'core' | 'credentials' | 'admin' | 'database';
+
+/** Capabilities per window label; unlisted labels only get core commands */
+export const WINDOW_CAPABILITIES: Readonly<Record<string, readonly IpcCapability[]>> = {
+  [MAIN_WINDOW_LABEL]: ['core', 'credentials', 'admin', 'database']
+};
+
+const CHANNEL_CAPABILITIES: ReadonlyArray<[prefix: string, capability: IpcCapability]> = [
+  ['credentials:', 'credentials'],
+  ['admin:', 'admin'],
+  ['database:', 'database']
+];
+
+/** Preload bridges that are only exposed with the matching capability */
+export const BRIDGE_CAPABILITIES: Readonly<Record<string, IpcCapability>> = {
+  credentials: 'credentials',
+  admin: 'admin',
+  database: 'database'
+};
+
+/**
+ * Capability a channel requires
+ */
+export function getChannelCapability(channel: string): IpcCapability {
+  return CHANNEL_CAPABILITIES.find(([prefix]) => channel.startsWith(prefix))?.[1] ?? 'core';
+}
+
+/**
+ * Whether a window label grants a capability
+ */
+export function windowHasCapability(label: string | undefined, capability: IpcCapability): boolean {
+  if (!label) {
+    return false;
+  }
+  return (WINDOW_CAPABILITIES[label] ?? ['core']).includes(capability);
+}
+
+/**
+ * Reads the window label from a renderer's command-line arguments
+ */
+export function parseWindowLabel(argv: readonly string[]): string | undefined {
+  const arg = argv.find((value) => value.startsWith(WINDOW_LABEL_ARG_PREFIX));
+  return arg ? arg.slice(WINDOW_LABEL_ARG_PREFIX.length) : undefined;
+}
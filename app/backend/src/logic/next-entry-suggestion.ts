@@ -0,0 +1,272 @@
+/**
+ * @fileoverview Next Entry Suggestion Logic
+ *
+ * Pure functions for proposing the next likely draft row for a date based on
+ * patterns in recent history (same weekday, contiguous blocks of work).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { normalizeDateToISO } from '@sheetpilot/shared';
+
+/**
+ * Historical entry used as input for suggestions (database row shape)
+ */
+export interface SuggestionHistoryEntry {
+  id: number;
+  date: string;
+  hours: number | null;
+  project: string;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+  task_description: string;
+}
+
+/**
+ * Proposed draft row
+ */
+export interface NextEntrySuggestion {
+  date: string;
+  hours: number;
+  project: string;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string;
+  /** 0-1 score: share of matching weekdays in the lookback window that contained this entry */
+  confidence: number;
+  /** Number of times this entry appeared in the lookback window */
+  occurrences: number;
+  /** Which pattern produced the suggestion */
+  basis: 'follows-previous-block' | 'same-weekday' | 'recent-history';
+}
+
+export interface SuggestNextEntryOptions {
+  /** How many weeks of history to consider (default 8) */
+  lookbackWeeks?: number;
+  /** Maximum hours allowed on a single date (default 24) */
+  maxDailyHours?: number;
+}
+
+const DEFAULT_LOOKBACK_WEEKS = 8;
+const DEFAULT_MAX_DAILY_HOURS = 24;
+const MS_PER_DAY = 24 * 60 * 60 * 1000;
+
+const SAME_WEEKDAY_WEIGHT = 3;
+const OTHER_DAY_WEIGHT = 1;
+const SUCCESSOR_WEIGHT = 4;
+
+type Candidate = {
+  key: string;
+  entry: SuggestionHistoryEntry;
+  score: number;
+  occurrences: number;
+  sameWeekdayDates: Set<string>;
+  followsPrevious: boolean;
+  hoursCounts: Map<number, number>;
+};
+
+const toDayNumber = (dateStr: string): number | null => {
+  let iso: string;
+  try {
+    iso = normalizeDateToISO(dateStr);
+  } catch {
+    return null;
+  }
+  const match = iso.match(/^(\d{4})-(\d{2})-(\d{2})$/);
+  if (!match) return null;
+  const time = Date.UTC(
+    parseInt(match[1]!, 10),
+    parseInt(match[2]!, 10) - 1,
+    parseInt(match[3]!, 10)
+  );
+  return isNaN(time) ? null : Math.floor(time / MS_PER_DAY);
+};
+
+// 1970-01-01 was a Thursday; shift so 0 = Sunday like Date.getUTCDay()
+const weekdayOf = (dayNumber: number): number => (dayNumber + 4) % 7;
+
+/**
+ * Builds the identity key used to group equivalent entries
+ */
+export function entryKey(entry: Pick<SuggestionHistoryEntry, 'project' | 'tool' | 'detail_charge_code' | 'task_description'>): string {
+  return [
+    entry.project,
+    entry.tool ?? '',
+    entry.detail_charge_code ?? '',
+    entry.task_description.trim().toLowerCase(),
+  ].join('\u0000');
+}
+
+const groupByDay = (
+  entries: SuggestionHistoryEntry[]
+): Map<number, SuggestionHistoryEntry[]> => {
+  const byDay = new Map<number, SuggestionHistoryEntry[]>();
+  for (const entry of entries) {
+    const day = toDayNumber(entry.date);
+    if (day === null) continue;
+    const list = byDay.get(day) ?? [];
+    list.push(entry);
+    byDay.set(day, list);
+  }
+  for (const list of byDay.values()) {
+    list.sort((a, b) => a.id - b.id);
+  }
+  return byDay;
+};
+
+/**
+ * Keys of entries that historically came right after `previousKey` on the same day
+ */
+const findSuccessorKeys = (
+  byDay: Map<number, SuggestionHistoryEntry[]>,
+  previousKey: string | null
+): Set<string> => {
+  const successors = new Set<string>();
+  if (!previousKey) return successors;
+  for (const dayEntries of byDay.values()) {
+    for (let i = 0; i < dayEntries.length - 1; i++) {
+      if (entryKey(dayEntries[i]!) === previousKey) {
+        successors.add(entryKey(dayEntries[i + 1]!));
+      }
+    }
+  }
+  return successors;
+};
+
+const mostCommonHours = (hoursCounts: Map<number, number>): number | null => {
+  let best: { hours: number; count: number } | null = null;
+  for (const [hours, count] of hoursCounts) {
+    if (!best || count > best.count || (count === best.count && hours > best.hours)) {
+      best = { hours, count };
+    }
+  }
+  return best?.hours ?? null;
+};
+
+const roundDownToQuarterHour = (hours: number): number => Math.floor(hours * 4) / 4;
+
+const pickBasis = (candidate: Candidate): NextEntrySuggestion['basis'] => {
+  if (candidate.followsPrevious) return 'follows-previous-block';
+  if (candidate.sameWeekdayDates.size > 0) return 'same-weekday';
+  return 'recent-history';
+};
+
+/**
+ * Suggests the next likely draft row for a date
+ *
+ * Scores entries from the lookback window, favouring entries seen on the same
+ * weekday and entries that historically followed the last block already entered
+ * for the target date. Entries already present on the date are never suggested.
+ *
+ * @param targetDate - Date to suggest for (MM/DD/YYYY or YYYY-MM-DD)
+ * @param history - Recent entries (drafts and submitted), any order
+ * @param existingForDate - Entries already recorded for the target date
+ * @returns Suggested row, or null if there is no usable pattern or no capacity left
+ */
+export function suggestNextEntry(
+  targetDate: string,
+  history: SuggestionHistoryEntry[],
+  existingForDate: SuggestionHistoryEntry[] = [],
+  options: SuggestNextEntryOptions = {}
+): NextEntrySuggestion | null {
+  const targetDay = toDayNumber(targetDate);
+  if (targetDay === null) return null;
+
+  const lookbackDays = (options.lookbackWeeks ?? DEFAULT_LOOKBACK_WEEKS) * 7;
+  const maxDailyHours = options.maxDailyHours ?? DEFAULT_MAX_DAILY_HOURS;
+  const targetWeekday = weekdayOf(targetDay);
+
+  const usedHours = existingForDate.reduce((sum, e) => sum + (e.hours ?? 0), 0);
+  const remainingHours = roundDownToQuarterHour(maxDailyHours - usedHours);
+  if (remainingHours < 0.25) return null;
+
+  const windowed = history.filter((entry) => {
+    const day = toDayNumber(entry.date);
+    return day !== null && day < targetDay && targetDay - day <= lookbackDays;
+  });
+  if (windowed.length === 0) return null;
+
+  const byDay = groupByDay(windowed);
+  const existingKeys = new Set(existingForDate.map(entryKey));
+  const lastExisting = [...existingForDate].sort((a, b) => a.id - b.id).pop();
+  const successorKeys = findSuccessorKeys(byDay, lastExisting ? entryKey(lastExisting) : null);
+
+  const sameWeekdayDayCount = [...byDay.keys()].filter(
+    (day) => weekdayOf(day) === targetWeekday
+  ).length;
+
+  const candidates = new Map<string, Candidate>();
+  for (const [day, dayEntries] of byDay) {
+    const isSameWeekday = weekdayOf(day) === targetWeekday;
+    for (const entry of dayEntries) {
+      const key = entryKey(entry);
+      if (existingKeys.has(key)) continue;
+
+      const candidate = candidates.get(key) ?? {
+        key,
+        entry,
+        score: 0,
+        occurrences: 0,
+        sameWeekdayDates: new Set<string>(),
+        followsPrevious: successorKeys.has(key),
+        hoursCounts: new Map<number, number>(),
+      };
+      candidate.occurrences++;
+      candidate.score += isSameWeekday ? SAME_WEEKDAY_WEIGHT : OTHER_DAY_WEIGHT;
+      if (isSameWeekday) candidate.sameWeekdayDates.add(String(day));
+      if (entry.hours !== null && entry.hours !== undefined) {
+        candidate.hoursCounts.set(entry.hours, (candidate.hoursCounts.get(entry.hours) ?? 0) + 1);
+      }
+      // Keep the most recent variant so descriptions reflect current wording
+      if (entry.id > candidate.entry.id) candidate.entry = entry;
+      candidates.set(key, candidate);
+    }
+  }
+
+  const ranked = [...candidates.values()]
+    .map((c) => ({ ...c, score: c.score + (c.followsPrevious ? SUCCESSOR_WEIGHT : 0) }))
+    .sort((a, b) => b.score - a.score || b.entry.id - a.entry.id);
+
+  const best = ranked[0];
+  if (!best) return null;
+
+  const typicalHours = mostCommonHours(best.hoursCounts) ?? remainingHours;
+  const hours = Math.min(typicalHours, remainingHours);
+
+  const confidence =
+    sameWeekdayDayCount > 0 ? best.sameWeekdayDates.size / sameWeekdayDayCount : 0;
+
+  return {
+    date: targetDate,
+    hours,
+    project: best.entry.project,
+    tool: best.entry.tool ?? null,
+    chargeCode: best.entry.detail_charge_code ?? null,
+    taskDescription: best.entry.task_description,
+    confidence: Math.round(confidence * 100) / 100,
+    occurrences: best.occurrences,
+    basis: pickBasis(best),
+  };
+}
+
+/**
+ * Splits mixed history into entries on the target date and everything else
+ */
+export function partitionHistoryByDate(
+  targetDate: string,
+  entries: SuggestionHistoryEntry[]
+): { existingForDate: SuggestionHistoryEntry[]; history: SuggestionHistoryEntry[] } {
+  const targetDay = toDayNumber(targetDate);
+  const existingForDate: SuggestionHistoryEntry[] = [];
+  const history: SuggestionHistoryEntry[] = [];
+  for (const entry of entries) {
+    if (targetDay !== null && toDayNumber(entry.date) === targetDay) {
+      existingForDate.push(entry);
+    } else {
+      history.push(entry);
+    }
+  }
+  return { existingForDate, history };
+}
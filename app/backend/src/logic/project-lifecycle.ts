@@ -0,0 +1,102 @@
+/**
+ * @fileoverview Project Lifecycle
+ *
+ * Projects are retired mid-quarter: the catalog records the first day a
+ * project no longer accepts hours (`retired_on`) and optionally the project
+ * replacing it. Entries dated on or after that day still match the dropdown
+ * but bounce in payroll, so saves and the submission preflight reject them
+ * and drafts can be moved to the successor in bulk.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { normalizeDateToISO } from '@sheetpilot/shared';
+
+/** Retirement columns of a catalog project */
+export interface ProjectRetirementRow {
+  name: string;
+  retired_on: string | null;
+  successor_project: string | null;
+}
+
+export interface RetiredProjectIssue {
+  id: number;
+  date: string;
+  project: string;
+  retiredOn: string;
+  successor: string | null;
+  message: string;
+}
+
+const toIsoDate = (date: string | null | undefined): string | null => {
+  if (!date) return null;
+  const iso = normalizeDateToISO(date);
+  return /^\d{4}-\d{2}-\d{2}$/.test(iso) ? iso : null;
+};
+
+/**
+ * Whether a project is retired for an entry date
+ *
+ * @param date - MM/DD/YYYY or YYYY-MM-DD; entries without a valid date are never flagged
+ * @returns The retirement, or null if the project accepts hours on that date
+ */
+export function findProjectRetirement(
+  project: string | null | undefined,
+  date: string | null | undefined,
+  retirements: readonly ProjectRetirementRow[]
+): ProjectRetirementRow | null {
+  const isoDate = toIsoDate(date);
+  if (!project || !isoDate) return null;
+  const retirement = retirements.find((r) => r.name === project && r.retired_on);
+  return retirement && isoDate >= retirement.retired_on! ? retirement : null;
+}
+
+/**
+ * User-facing explanation for an entry booked to a retired project
+ */
+export function formatRetiredProjectMessage(retirement: ProjectRetirementRow): string {
+  const next = retirement.successor_project
+    ? `use ${retirement.successor_project} instead`
+    : 'pick another project';
+  return `${retirement.name} was retired on ${retirement.retired_on}; ${next}`;
+}
+
+/**
+ * Checks a project for an entry date
+ *
+ * @returns Error message, or null if the project is active on that date
+ */
+export function checkProjectActive(
+  project: string | null | undefined,
+  date: string | null | undefined,
+  retirements: readonly ProjectRetirementRow[]
+): string | null {
+  const retirement = findProjectRetirement(project, date, retirements);
+  return retirement ? formatRetiredProjectMessage(retirement) : null;
+}
+
+/**
+ * Entries booked to a project on or after its retirement date
+ */
+export function getRetiredProjectIssues(
+  entries: ReadonlyArray<{ id: number; date: string | null; project: string | null }>,
+  retirements: readonly ProjectRetirementRow[]
+): RetiredProjectIssue[] {
+  if (retirements.length === 0) return [];
+  const issues: RetiredProjectIssue[] = [];
+  for (const entry of entries) {
+    const retirement = findProjectRetirement(entry.project, entry.date, retirements);
+    if (!retirement) continue;
+    issues.push({
+      id: entry.id,
+      date: entry.date!,
+      project: retirement.name,
+      retiredOn: retirement.retired_on!,
+      successor: retirement.successor_project,
+      message: formatRetiredProjectMessage(retirement)
+    });
+  }
+  return issues;
+}
@@ -0,0 +1,156 @@
+/**
+ * @fileoverview Submission Estimate
+ *
+ * Estimates how much browser work submitting the current drafts takes: bot
+ * runs (browser launch + login), form loads, and wall-clock time. Timings
+ * come from recent run artifacts when there are enough of them, otherwise
+ * from conservative defaults. The estimate compares the current strategy
+ * (one bot run per quarter form, entries submitted back to back) with
+ * submitting each entry in its own run, so users can decide whether to
+ * submit now or let drafts pile up.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { SubmissionFormRunReport } from '@sheetpilot/shared';
+import type { SubmissionPlan } from './submission-plan';
+
+/** Per-entry fill + submit time when there is no history */
+export const DEFAULT_ROW_MS = 15_000;
+/** Per-run login and form checks when there is no history */
+export const DEFAULT_FORM_OVERHEAD_MS = 25_000;
+/** Browser launch per bot run (not covered by run artifacts) */
+export const BROWSER_LAUNCH_MS = 5_000;
+/** Fewer submitted rows than this and history is ignored */
+export const MIN_HISTORY_ROWS = 5;
+
+export interface SubmissionTimingProfile {
+  basis: 'history' | 'defaults';
+  /** Form runs and rows the medians were taken from */
+  sampleForms: number;
+  sampleRows: number;
+  /** Median fill + submit time per entry */
+  rowMs: number;
+  /** Median per-run time outside rows (login, title check) */
+  formOverheadMs: number;
+  /** Share of attempted rows that failed */
+  failureRate: number;
+}
+
+export interface SubmissionStrategyEstimate {
+  botRuns: number;
+  logins: number;
+  formLoads: number;
+  estimatedMs: number;
+}
+
+export interface SubmissionEstimate {
+  entryCount: number;
+  /** Entries outside every configured quarter; they fail without a run */
+  unroutableEntries: number;
+  /** What submitting now does: one run per quarter form */
+  batched: SubmissionStrategyEstimate;
+  /** For comparison: a separate run for every entry */
+  perEntry: SubmissionStrategyEstimate;
+  groups: Array<{ quarterId: string; entryCount: number; estimatedMs: number }>;
+  timing: SubmissionTimingProfile;
+}
+
+const median = (values: number[]): number => {
+  const sorted = [...values].sort((a, b) => a - b);
+  const mid = Math.floor(sorted.length / 2);
+  return sorted.length % 2 === 0
+    ? Math.round(((sorted[mid - 1] ?? 0) + (sorted[mid] ?? 0)) / 2)
+    : (sorted[mid] ?? 0);
+};
+
+/**
+ * Derives per-entry and per-run timings from recent form runs
+ */
+export function buildTimingProfile(forms: SubmissionFormRunReport[]): SubmissionTimingProfile {
+  const rowTimes: number[] = [];
+  const overheads: number[] = [];
+  let attempted = 0;
+  let failed = 0;
+
+  for (const form of forms) {
+    let formRowMs = 0;
+    for (const row of form.rows) {
+      if (row.outcome === 'skipped') continue;
+      attempted++;
+      if (row.outcome === 'failed') failed++;
+      const rowMs = (row.fillMs ?? 0) + (row.submitMs ?? 0);
+      formRowMs += rowMs;
+      if (row.outcome === 'submitted' && row.fillMs !== null) {
+        rowTimes.push(rowMs);
+      }
+    }
+    if (form.durationMs !== null) {
+      overheads.push(Math.max(0, form.durationMs - formRowMs));
+    }
+  }
+
+  if (rowTimes.length < MIN_HISTORY_ROWS) {
+    return {
+      basis: 'defaults',
+      sampleForms: forms.length,
+      sampleRows: rowTimes.length,
+      rowMs: DEFAULT_ROW_MS,
+      formOverheadMs: DEFAULT_FORM_OVERHEAD_MS,
+      failureRate: attempted > 0 ? failed / attempted : 0
+    };
+  }
+
+  return {
+    basis: 'history',
+    sampleForms: forms.length,
+    sampleRows: rowTimes.length,
+    rowMs: median(rowTimes),
+    formOverheadMs: overheads.length > 0 ? median(overheads) : DEFAULT_FORM_OVERHEAD_MS,
+    failureRate: failed / attempted
+  };
+}
+
+/**
+ * Estimates runs, form loads and duration for a submission plan
+ *
+ * @param pacing - Current submission pacing; each entry takes at least the average paced gap
+ */
+export function estimateSubmission(
+  plan: SubmissionPlan,
+  timing: SubmissionTimingProfile,
+  pacing: { minIntervalMs: number; jitterMs: number }
+): SubmissionEstimate {
+  const runOverheadMs = BROWSER_LAUNCH_MS + timing.formOverheadMs;
+  const rowMs = Math.max(timing.rowMs, pacing.minIntervalMs + pacing.jitterMs / 2);
+
+  const routable = plan.groups.filter((g) => g.quarterId !== null);
+  const groups = routable.map((g) => ({
+    quarterId: g.quarterId!,
+    entryCount: g.entryIds.length,
+    estimatedMs: Math.round(runOverheadMs + g.entryIds.length * rowMs)
+  }));
+  const routableEntries = groups.reduce((sum, g) => sum + g.entryCount, 0);
+  const entryCount = plan.groups.reduce((sum, g) => sum + g.entryIds.length, 0);
+
+  return {
+    entryCount,
+    unroutableEntries: entryCount - routableEntries,
+    batched: {
+      botRuns: groups.length,
+      logins: groups.length,
+      formLoads: routableEntries,
+      estimatedMs: groups.reduce((sum, g) => sum + g.estimatedMs, 0)
+    },
+    perEntry: {
+      botRuns: routableEntries,
+      logins: routableEntries,
+      formLoads: routableEntries,
+      estimatedMs: Math.round(routableEntries * (runOverheadMs + timing.rowMs))
+    },
+    groups,
+    timing
+  };
+}
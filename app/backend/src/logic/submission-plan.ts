@@ -0,0 +1,109 @@
+/**
+ * @fileoverview Submission Plan
+ *
+ * Splits pending entries into one group per quarter form, in the submission
+ * order (oldest first unless `submission_order` says otherwise), so a batch
+ * that crosses a quarter boundary (e.g. Sep 30 → Oct 1) can be reviewed as
+ * two target forms and submitted in a single action. The bot runs the groups
+ * and their entries in the same order.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import {
+  getQuarterForDate,
+  getSubmissionOrder,
+  orderEntriesForSubmission,
+  orderQuarterGroups,
+  type QuarterDefinition,
+  type SubmissionOrder
+} from '@sheetpilot/bot';
+
+/**
+ * Entries bound for one quarter's form
+ */
+export interface SubmissionPlanGroup {
+  /** Quarter ID, or null for dates outside every configured quarter */
+  quarterId: string | null;
+  quarterName: string | null;
+  formUrl: string | null;
+  /** In the order they will be filled */
+  entryIds: number[];
+  hours: number;
+  /** First and last entry date in the group (YYYY-MM-DD) */
+  firstDate: string | null;
+  lastDate: string | null;
+}
+
+export interface SubmissionPlan {
+  /** Order the groups and their entries are submitted in */
+  order: SubmissionOrder;
+  groups: SubmissionPlanGroup[];
+  /** True when the entries go to more than one quarter form */
+  splitAcrossQuarters: boolean;
+}
+
+/**
+ * Groups entries by the quarter form they will be submitted to
+ *
+ * @param entries - Pending entries (date is MM/DD/YYYY or YYYY-MM-DD)
+ * @param quarterFor - Quarter lookup (defaults to the configured quarters)
+ * @param order - Submission order (defaults to the configured order)
+ */
+export function buildSubmissionPlan(
+  entries: Array<{ id: number; date: string; hours?: number | null }>,
+  quarterFor: (date: string) => QuarterDefinition | null = getQuarterForDate,
+  order: SubmissionOrder = getSubmissionOrder()
+): SubmissionPlan {
+  const groups = new Map<string | null, SubmissionPlanGroup>();
+  const quarters = new Map<string | null, QuarterDefinition | null>();
+  const datedIds = new Map<string | null, Array<{ id: number; date: string }>>();
+
+  for (const entry of entries) {
+    const isoDate = entry.date ? normalizeDateToISO(entry.date) : '';
+    const quarter = isoDate ? quarterFor(isoDate) : null;
+    const key = quarter?.id ?? null;
+    const group = groups.get(key) ?? {
+      quarterId: key,
+      quarterName: quarter?.name ?? null,
+      formUrl: quarter?.formUrl ?? null,
+      entryIds: [],
+      hours: 0,
+      firstDate: null,
+      lastDate: null
+    };
+    quarters.set(key, quarter);
+    const dated = datedIds.get(key) ?? [];
+    dated.push({ id: entry.id, date: isoDate });
+    datedIds.set(key, dated);
+    group.hours = Math.round((group.hours + (entry.hours ?? 0)) * 100) / 100;
+    if (isoDate) {
+      if (!group.firstDate || isoDate < group.firstDate) group.firstDate = isoDate;
+      if (!group.lastDate || isoDate > group.lastDate) group.lastDate = isoDate;
+    }
+    groups.set(key, group);
+  }
+
+  // Entries outside every quarter (which fail) go last in every order
+  const ordered = orderQuarterGroups(
+    [...groups.values()],
+    (group) => ({
+      quarter: quarters.get(group.quarterId) ?? null,
+      firstDate: group.firstDate ?? '',
+      lastDate: group.lastDate ?? ''
+    }),
+    order
+  ).map((group) => ({
+    ...group,
+    entryIds: orderEntriesForSubmission(datedIds.get(group.quarterId) ?? [], order).map((entry) => entry.id)
+  }));
+
+  return {
+    order,
+    groups: ordered,
+    splitAcrossQuarters: ordered.filter((g) => g.quarterId !== null).length > 1
+  };
+}
@@ -0,0 +1,74 @@
+/**
+ * @fileoverview Submission Postconditions
+ *
+ * Reads the postconditions a submit must satisfy (e.g. "URL contains
+ * /confirmation", "page shows a submission ID") from the submission plugin
+ * options, per service profile like the charge code rules. The bot checks
+ * them after each submit and captures matched values for the entry.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { SubmissionPostcondition } from '@sheetpilot/bot';
+
+/** Profile used when the active submission service has no postconditions of its own */
+export const DEFAULT_POSTCONDITION_PROFILE = 'default';
+
+const optionalDescription = (raw: Record<string, unknown>): { description?: string } =>
+  typeof raw['description'] === 'string' && raw['description'].length > 0
+    ? { description: raw['description'] }
+    : {};
+
+const toPostcondition = (value: unknown): SubmissionPostcondition | null => {
+  if (!value || typeof value !== 'object') return null;
+  const raw = value as Record<string, unknown>;
+  if (raw['kind'] === 'url-contains' && typeof raw['value'] === 'string' && raw['value'].length > 0) {
+    return { kind: 'url-contains', value: raw['value'], ...optionalDescription(raw) };
+  }
+  if (raw['kind'] === 'page-matches' && typeof raw['pattern'] === 'string' && raw['pattern'].length > 0) {
+    try {
+      new RegExp(raw['pattern']);
+    } catch {
+      return null;
+    }
+    return {
+      kind: 'page-matches',
+      pattern: raw['pattern'],
+      ...(typeof raw['capture'] === 'string' && raw['capture'].length > 0 ? { capture: raw['capture'] } : {}),
+      ...optionalDescription(raw)
+    };
+  }
+  return null;
+};
+
+const toPostconditionList = (value: unknown): SubmissionPostcondition[] | null =>
+  Array.isArray(value)
+    ? value.map(toPostcondition).filter((c): c is SubmissionPostcondition => c !== null)
+    : null;
+
+/**
+ * Picks the postconditions for a service profile from plugin options
+ *
+ * Expects `options.postconditions` keyed by profile name, falling back to
+ * the `default` profile. Malformed entries (unknown kind, invalid pattern)
+ * are dropped.
+ *
+ * @param options - Submission plugin options from plugin-config.json
+ * @param profile - Active submission service name
+ * @returns Postconditions to check; empty when none are configured
+ */
+export function resolveSubmissionPostconditions(
+  options: Record<string, unknown> | undefined,
+  profile: string | null
+): SubmissionPostcondition[] {
+  const configured = options?.['postconditions'];
+  if (!configured || typeof configured !== 'object') return [];
+  const byProfile = configured as Record<string, unknown>;
+  return (
+    (profile ? toPostconditionList(byProfile[profile]) : null) ??
+    toPostconditionList(byProfile[DEFAULT_POSTCONDITION_PROFILE]) ??
+    []
+  );
+}
@@ -0,0 +1,104 @@
+/**
+ * @fileoverview Time Zone Logic
+ *
+ * Entries are recorded against the user's home time zone (`home_time_zone` in
+ * settings.json, the system zone when unset). Calendar events are absolute
+ * instants, so when a laptop switches zone during travel, bucketing them by
+ * the machine's current zone moved meetings near midnight onto the wrong day
+ * and shifted the import range by the zone difference. These helpers do date
+ * arithmetic in an explicit IANA zone instead.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+const pad = (n: number): string => String(n).padStart(2, '0');
+
+/**
+ * Whether `timeZone` is an IANA zone name this runtime knows (e.g. "America/Chicago")
+ */
+export function isValidTimeZone(timeZone: string): boolean {
+  try {
+    new Intl.DateTimeFormat('en-US', { timeZone });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+export function getSystemTimeZone(): string {
+  return Intl.DateTimeFormat().resolvedOptions().timeZone || 'UTC';
+}
+
+/**
+ * Home time zone for a stored (possibly missing or malformed) setting
+ */
+export function resolveHomeTimeZone(configured: string | undefined): string {
+  return configured && isValidTimeZone(configured) ? configured : getSystemTimeZone();
+}
+
+interface ZonedParts {
+  year: number;
+  month: number;
+  day: number;
+  hour: number;
+  minute: number;
+  second: number;
+}
+
+function getZonedParts(instant: Date, timeZone: string): ZonedParts {
+  const parts = new Intl.DateTimeFormat('en-US', {
+    timeZone,
+    hourCycle: 'h23',
+    year: 'numeric',
+    month: '2-digit',
+    day: '2-digit',
+    hour: '2-digit',
+    minute: '2-digit',
+    second: '2-digit',
+  }).formatToParts(instant);
+  const part = (type: Intl.DateTimeFormatPartTypes): number =>
+    Number(parts.find((p) => p.type === type)?.value ?? 0);
+  return {
+    year: part('year'),
+    month: part('month'),
+    day: part('day'),
+    hour: part('hour'),
+    minute: part('minute'),
+    second: part('second'),
+  };
+}
+
+/**
+ * Calendar date of an instant in `timeZone`, as MM/DD/YYYY
+ */
+export function toSlashDateInZone(instant: Date, timeZone: string): string {
+  const { year, month, day } = getZonedParts(instant, timeZone);
+  return `${pad(month)}/${pad(day)}/${year}`;
+}
+
+/**
+ * Hour of the day (0-23) of an instant in `timeZone`
+ */
+export function getHourInZone(instant: Date, timeZone: string): number {
+  return getZonedParts(instant, timeZone).hour;
+}
+
+/**
+ * Instant at which a calendar day starts in `timeZone`
+ *
+ * @param isoDate - YYYY-MM-DD
+ */
+export function startOfDayInZone(isoDate: string, timeZone: string): Date {
+  const [year, month, day] = isoDate.split('-').map(Number);
+  const utcMidnight = Date.UTC(year!, month! - 1, day!);
+  // Offset of the zone at that moment; a second pass settles days where it changes (DST)
+  let instant = utcMidnight;
+  for (let pass = 0; pass < 2; pass++) {
+    const zoned = getZonedParts(new Date(instant), timeZone);
+    const zonedAsUtc = Date.UTC(zoned.year, zoned.month - 1, zoned.day, zoned.hour, zoned.minute, zoned.second);
+    instant = utcMidnight - (zonedAsUtc - instant);
+  }
+  return new Date(instant);
+}
@@ -0,0 +1,153 @@
+/**
+ * @fileoverview Timesheet Gaps
+ *
+ * Finds working days in a range that have no hours logged, or fewer than the
+ * daily target, so missing time is caught during the week instead of at
+ * month-end. Working days come from `workweek` in settings.json (weekdays and
+ * target hours) minus its holiday calendar. Drafts count as logged: the gap is
+ * time never entered, not time not yet submitted.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export interface WorkweekSettings {
+  /** Working weekdays, 0 = Sunday to 6 = Saturday (default Monday to Friday) */
+  working_days?: number[];
+  /** Hours expected on a working day (default 8) */
+  daily_target_hours?: number;
+  /** Days off that are not working days, YYYY-MM-DD */
+  holidays?: string[];
+}
+
+export interface Workweek {
+  workingDays: number[];
+  dailyTargetHours: number;
+  holidays: Set<string>;
+}
+
+export interface DailyHours {
+  /** YYYY-MM-DD */
+  date: string;
+  submittedHours: number;
+  draftHours: number;
+}
+
+export type MissingDayReason = 'no-hours' | 'under-target';
+
+export interface MissingTimesheetDay {
+  /** YYYY-MM-DD */
+  date: string;
+  /** 0 = Sunday to 6 = Saturday */
+  weekday: number;
+  /** Submitted plus draft hours */
+  loggedHours: number;
+  submittedHours: number;
+  draftHours: number;
+  /** Hours short of the daily target */
+  shortfallHours: number;
+  reason: MissingDayReason;
+}
+
+export const DEFAULT_WORKING_DAYS = [1, 2, 3, 4, 5];
+export const DEFAULT_DAILY_TARGET_HOURS = 8;
+
+const ISO_DATE = /^\d{4}-\d{2}-\d{2}$/;
+const WEEKDAY_NAMES = ['Sun', 'Mon', 'Tue', 'Wed', 'Thu', 'Fri', 'Sat'];
+
+const roundHours = (hours: number): number => Math.round(hours * 100) / 100;
+
+/**
+ * Workweek for stored (possibly missing or hand-edited) settings; invalid
+ * values fall back to the defaults and malformed holidays are dropped
+ */
+export function resolveWorkweek(settings: WorkweekSettings | undefined): Workweek {
+  const days = Array.isArray(settings?.working_days)
+    ? [...new Set(settings.working_days.filter((day) => Number.isInteger(day) && day >= 0 && day <= 6))]
+    : [];
+  const target = settings?.daily_target_hours;
+  const holidays = Array.isArray(settings?.holidays)
+    ? settings.holidays.filter((day): day is string => typeof day === 'string' && ISO_DATE.test(day.trim()))
+    : [];
+  return {
+    workingDays: days.length > 0 ? days.sort((a, b) => a - b) : [...DEFAULT_WORKING_DAYS],
+    dailyTargetHours:
+      typeof target === 'number' && target > 0 && target <= 24 ? target : DEFAULT_DAILY_TARGET_HOURS,
+    holidays: new Set(holidays.map((day) => day.trim()))
+  };
+}
+
+/**
+ * Weekday of a YYYY-MM-DD date, 0 = Sunday
+ */
+export function getWeekday(isoDate: string): number {
+  return new Date(`${isoDate}T00:00:00Z`).getUTCDay();
+}
+
+/**
+ * Shifts a YYYY-MM-DD date by whole days
+ */
+export function addDays(isoDate: string, days: number): string {
+  const date = new Date(`${isoDate}T00:00:00Z`);
+  date.setUTCDate(date.getUTCDate() + days);
+  return date.toISOString().slice(0, 10);
+}
+
+export function isWorkingDay(isoDate: string, workweek: Workweek): boolean {
+  return workweek.workingDays.includes(getWeekday(isoDate)) && !workweek.holidays.has(isoDate);
+}
+
+/**
+ * Working days with no hours or fewer than the daily target, oldest first
+ *
+ * @param days - Hours per day; days missing from the list count as empty
+ */
+export function findMissingDays(
+  startDate: string,
+  endDate: string,
+  days: DailyHours[],
+  workweek: Workweek
+): MissingTimesheetDay[] {
+  const byDate = new Map(days.map((day) => [day.date, day]));
+  const missing: MissingTimesheetDay[] = [];
+  for (let date = startDate; date <= endDate; date = addDays(date, 1)) {
+    if (!isWorkingDay(date, workweek)) continue;
+    const submittedHours = roundHours(byDate.get(date)?.submittedHours ?? 0);
+    const draftHours = roundHours(byDate.get(date)?.draftHours ?? 0);
+    const loggedHours = roundHours(submittedHours + draftHours);
+    if (loggedHours >= workweek.dailyTargetHours) continue;
+    missing.push({
+      date,
+      weekday: getWeekday(date),
+      loggedHours,
+      submittedHours,
+      draftHours,
+      shortfallHours: roundHours(workweek.dailyTargetHours - loggedHours),
+      reason: loggedHours === 0 ? 'no-hours' : 'under-target'
+    });
+  }
+  return missing;
+}
+
+/**
+ * One-line description for a reminder, e.g.
+ * "2 days have no hours (Mon 03/03, Tue 03/04); 1 day is under 8h (Thu 03/06)"
+ */
+export function describeMissingDays(missing: MissingTimesheetDay[], dailyTargetHours: number): string {
+  const label = (day: MissingTimesheetDay): string =>
+    `${WEEKDAY_NAMES[day.weekday]} ${day.date.slice(5, 7)}/${day.date.slice(8, 10)}`;
+  const count = (n: number): string => `${n} ${n === 1 ? 'day' : 'days'}`;
+  const empty = missing.filter((day) => day.reason === 'no-hours');
+  const short = missing.filter((day) => day.reason === 'under-target');
+  const parts: string[] = [];
+  if (empty.length > 0) {
+    parts.push(`${count(empty.length)} ${empty.length === 1 ? 'has' : 'have'} no hours (${empty.map(label).join(', ')})`);
+  }
+  if (short.length > 0) {
+    parts.push(
+      `${count(short.length)} ${short.length === 1 ? 'is' : 'are'} under ${dailyTargetHours}h (${short.map(label).join(', ')})`
+    );
+  }
+  return parts.join('; ');
+}
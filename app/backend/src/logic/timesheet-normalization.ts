@@ -9,20 +9,10 @@
  * @since 2025
  */
 
+import type { TimesheetRow } from '@sheetpilot/shared';
 import { projectNeedsTools, toolNeedsChargeCode } from './dropdown-logic';
 
-/**
- * Timesheet row interface
- */
-export interface TimesheetRow {
-  id?: number;
-  date?: string;
-  hours?: number;
-  project?: string;
-  tool?: string | null;
-  chargeCode?: string | null;
-  taskDescription?: string;
-}
+export type { TimesheetRow };
 
 /**
  * Normalize a timesheet row based on business rules
@@ -11,6 +11,9 @@
 
 import { projectNeedsTools, toolNeedsChargeCode } from './dropdown-logic';
 import { validateQuarterAvailability } from '@sheetpilot/bot';
+import { checkChargeCode, type ChargeCodeRule } from './charge-code-rules';
+import { checkProjectActive, type ProjectRetirementRow } from './project-lifecycle';
+import { entryKindNeedsProject, isValidHours, type EntryKind } from '@sheetpilot/shared';
 
 /**
  * Timesheet row interface
@@ -23,6 +26,8 @@ export interface TimesheetRow {
   tool?: string | null;
   chargeCode?: string | null;
   taskDescription?: string;
+  /** Missing means work */
+  kind?: EntryKind | undefined;
 }
 
 /**
@@ -63,31 +68,7 @@ export function isValidDate(dateStr?: string): boolean {
   return actualMonth === month && actualDay === day && actualYear === year;
 }
 
-/**
- * Check if hours value is valid
- * 
- * Validates that hours is:
- * - A number
- * - In 15-minute increments (multiple of 0.25)
- * - Within range: 0.25 to 24.0 hours
- * 
- * @param hours - Hours value to validate
- * @returns true if valid, false otherwise
- */
-export function isValidHours(hours?: number | null): boolean {
-  if (hours === undefined || hours === null) return false;
-  if (typeof hours !== 'number' || isNaN(hours)) return false;
-  
-  // Check if it's a multiple of 0.25 (15-minute increments)
-  // Use modulo with tolerance for floating point precision
-  const remainder = (hours * 4) % 1;
-  if (Math.abs(remainder) > 0.0001 && Math.abs(remainder - 1) > 0.0001) {
-    return false;
-  }
-  
-  // Check range: 0.25 to 24.0
-  return hours >= 0.25 && hours <= 24.0;
-}
+export { isValidHours };
 
 const toIsoDateForQuarterCheck = (dateStr: string): string | null => {
   const [month, day, year] = dateStr.split('/');
@@ -129,11 +110,16 @@ const validateHoursField = (value: unknown): string | null => {
 
 const validateProjectField = (
   value: unknown,
-  projects: string[]
+  projects: string[],
+  date?: string,
+  projectRetirements: readonly ProjectRetirementRow[] = [],
+  kind?: EntryKind
 ): string | null => {
+  // PTO and holidays get their project from the service profile when submitted
+  if (!value && !entryKindNeedsProject(kind)) return null;
   if (!value) return 'Project is required - please pick a project';
   if (!projects.includes(String(value))) return 'Please pick from the list';
-  return null;
+  return checkProjectActive(String(value), date, projectRetirements);
 };
 
 const validateToolField = (
@@ -150,14 +136,15 @@ const validateToolField = (
 const validateChargeCodeField = (
   value: unknown,
   tool: string | null | undefined,
-  chargeCodes: string[]
+  chargeCodes: string[],
+  chargeCodeRule?: ChargeCodeRule | null
 ): string | null => {
   if (!toolNeedsChargeCode(tool || undefined)) {
     return null;
   }
   if (!value) return 'Please pick a charge code for this tool';
   if (!chargeCodes.includes(String(value))) return 'Please pick from the list';
-  return null;
+  return checkChargeCode(String(value), chargeCodeRule);
 };
 
 const validateTaskDescriptionField = (value: unknown): string | null => {
@@ -176,7 +163,9 @@ export function validateField(
   prop: string | number,
   rows: TimesheetRow[],
   projects: string[],
-  chargeCodes: string[]
+  chargeCodes: string[],
+  chargeCodeRule?: ChargeCodeRule | null,
+  projectRetirements?: readonly ProjectRetirementRow[]
 ): string | null {
   const rowData = rows[row];
   
@@ -186,11 +175,11 @@ export function validateField(
     case 'hours':
       return validateHoursField(value);
     case 'project':
-      return validateProjectField(value, projects);
+      return validateProjectField(value, projects, rowData?.date, projectRetirements, rowData?.kind);
     case 'tool':
       return validateToolField(value, rowData?.project);
     case 'chargeCode':
-      return validateChargeCodeField(value, rowData?.tool, chargeCodes);
+      return validateChargeCodeField(value, rowData?.tool, chargeCodes, chargeCodeRule);
     case 'taskDescription':
       return validateTaskDescriptionField(value);
     default:
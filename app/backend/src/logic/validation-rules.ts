@@ -0,0 +1,173 @@
+/**
+ * @fileoverview User-Defined Validation Rules
+ *
+ * Evaluates team-defined rules (stored in the validation_rules table)
+ * against timesheet entries. A rule checks one field, optionally only when
+ * another field matches a condition, e.g. "tool is required when project
+ * starts with FAB-".
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const RULE_FIELDS = ['date', 'hours', 'project', 'tool', 'chargeCode', 'taskDescription'] as const;
+export const RULE_OPERATORS = [
+  'required',
+  'empty',
+  'equals',
+  'notEquals',
+  'startsWith',
+  'contains',
+  'matches',
+  'atMost',
+  'atLeast'
+] as const;
+export const RULE_ACTIONS = ['warn', 'block'] as const;
+
+export type RuleField = (typeof RULE_FIELDS)[number];
+export type RuleOperator = (typeof RULE_OPERATORS)[number];
+export type RuleAction = (typeof RULE_ACTIONS)[number];
+
+/**
+ * A single field test
+ *
+ * String comparisons are case-sensitive; `matches` takes a regular
+ * expression source; `atMost`/`atLeast` compare numerically.
+ */
+export interface RuleCondition {
+  field: RuleField;
+  operator: RuleOperator;
+  value?: string | null | undefined;
+}
+
+/**
+ * Stored validation rule
+ *
+ * The entry violates the rule when `when` holds (or is absent) and the
+ * field test does not.
+ */
+export interface ValidationRule extends RuleCondition {
+  id: number;
+  name: string;
+  when: RuleCondition | null;
+  action: RuleAction;
+  message: string;
+  isActive: boolean;
+}
+
+/**
+ * Entry fields rules can refer to
+ */
+export interface RuleEntry {
+  id?: number | undefined;
+  date?: string | null | undefined;
+  hours?: number | null | undefined;
+  project?: string | null | undefined;
+  tool?: string | null | undefined;
+  chargeCode?: string | null | undefined;
+  taskDescription?: string | null | undefined;
+}
+
+export interface RuleViolation {
+  ruleId: number;
+  ruleName: string;
+  entryId?: number | undefined;
+  field: RuleField;
+  action: RuleAction;
+  message: string;
+}
+
+const isEmpty = (value: unknown): boolean =>
+  value === undefined || value === null || (typeof value === 'string' && value.trim() === '');
+
+/**
+ * Tests one condition against an entry
+ */
+export function matchesRuleCondition(entry: RuleEntry, condition: RuleCondition): boolean {
+  const raw = entry[condition.field];
+  const actual = isEmpty(raw) ? '' : String(raw);
+  const expected = condition.value ?? '';
+
+  switch (condition.operator) {
+    case 'required':
+      return !isEmpty(raw);
+    case 'empty':
+      return isEmpty(raw);
+    case 'equals':
+      return actual === expected;
+    case 'notEquals':
+      return actual !== expected;
+    case 'startsWith':
+      return actual.startsWith(expected);
+    case 'contains':
+      return actual.includes(expected);
+    case 'matches':
+      try {
+        return new RegExp(expected).test(actual);
+      } catch {
+        return false;
+      }
+    case 'atMost':
+    case 'atLeast': {
+      const actualNumber = Number(raw);
+      const limit = Number(expected);
+      if (isEmpty(raw) || Number.isNaN(actualNumber) || Number.isNaN(limit)) return false;
+      return condition.operator === 'atMost' ? actualNumber <= limit : actualNumber >= limit;
+    }
+    default:
+      return true;
+  }
+}
+
+/**
+ * Evaluates active rules against one entry
+ */
+export function evaluateValidationRules(entry: RuleEntry, rules: ValidationRule[]): RuleViolation[] {
+  return rules
+    .filter((rule) => rule.isActive)
+    .filter((rule) => rule.when === null || matchesRuleCondition(entry, rule.when))
+    .filter((rule) => !matchesRuleCondition(entry, rule))
+    .map((rule) => ({
+      ruleId: rule.id,
+      ruleName: rule.name,
+      entryId: entry.id,
+      field: rule.field,
+      action: rule.action,
+      message: rule.message
+    }));
+}
+
+/**
+ * Evaluates active rules against many entries
+ */
+export function evaluateValidationRulesForEntries(
+  entries: RuleEntry[],
+  rules: ValidationRule[]
+): RuleViolation[] {
+  if (rules.length === 0) return [];
+  return entries.flatMap((entry) => evaluateValidationRules(entry, rules));
+}
+
+/**
+ * Maps a stored timesheet row onto the fields rules refer to
+ */
+export function toRuleEntry(row: {
+  id: number;
+  date: string | null;
+  hours: number | null;
+  project: string | null;
+  tool?: string | null | undefined;
+  detail_charge_code?: string | null | undefined;
+  task_description: string | null;
+}): RuleEntry {
+  return {
+    id: row.id,
+    date: row.date,
+    hours: row.hours,
+    project: row.project,
+    tool: row.tool ?? null,
+    chargeCode: row.detail_charge_code ?? null,
+    taskDescription: row.task_description
+  };
+}
@@ -0,0 +1,170 @@
+/**
+ * @fileoverview Week Diff
+ *
+ * Compares a week's drafts against what was last submitted for that week, so
+ * a user resubmitting corrections can see exactly what will change:
+ * - added: new drafts, and corrections that moved an entry into the week
+ * - removed: submitted entries whose correction moves them out of the week
+ * - modified: submitted entries with a pending correction, field by field
+ *
+ * Submitted entries replaced by a submitted correction are not compared; the
+ * correction is the last submitted version.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { SubmissionStatus, normalizeDateToISO } from '@sheetpilot/shared';
+
+/** Entry fields as stored (date may be MM/DD/YYYY or YYYY-MM-DD) */
+export interface WeekDiffSourceRow {
+  id: number;
+  date: string | null;
+  hours: number | null;
+  project: string | null;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+  task_description: string | null;
+  status?: string | null;
+  amends_id?: number | null;
+}
+
+export type WeekDiffField = 'date' | 'hours' | 'project' | 'tool' | 'chargeCode' | 'taskDescription';
+
+export interface WeekDiffEntry {
+  id: number;
+  /** Submitted entry this draft corrects, if any */
+  amendsId: number | null;
+  date: string | null;
+  hours: number | null;
+  project: string | null;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string | null;
+}
+
+export interface WeekDiffFieldChange {
+  field: WeekDiffField;
+  before: string | number | null;
+  after: string | number | null;
+}
+
+export interface WeekDiffModification {
+  /** Submitted entry being corrected */
+  originalId: number;
+  /** Draft holding the correction */
+  draftId: number;
+  before: WeekDiffEntry;
+  after: WeekDiffEntry;
+  changes: WeekDiffFieldChange[];
+}
+
+export interface WeekDiffRemoval {
+  entry: WeekDiffEntry;
+  /** Draft that moves the entry out of the week */
+  draftId: number;
+  /** Date the correction moves the entry to */
+  movedToDate: string | null;
+}
+
+export interface WeekDiff {
+  weekStart: string;
+  weekEnd: string;
+  added: WeekDiffEntry[];
+  removed: WeekDiffRemoval[];
+  modified: WeekDiffModification[];
+  /** Submitted entries with no pending change */
+  unchangedCount: number;
+}
+
+const DIFF_FIELDS: readonly WeekDiffField[] = ['date', 'hours', 'project', 'tool', 'chargeCode', 'taskDescription'];
+
+/**
+ * Last day of the week starting on `weekStart` (both YYYY-MM-DD)
+ */
+export function getWeekEnd(weekStart: string): string {
+  const end = new Date(`${weekStart}T00:00:00Z`);
+  end.setUTCDate(end.getUTCDate() + 6);
+  return end.toISOString().slice(0, 10);
+}
+
+const emptyToNull = (value: string | null | undefined): string | null =>
+  value === undefined || value === null || value.trim() === '' ? null : value;
+
+export const toWeekDiffEntry = (row: WeekDiffSourceRow): WeekDiffEntry => ({
+  id: row.id,
+  amendsId: row.amends_id ?? null,
+  date: row.date ? normalizeDateToISO(row.date) : null,
+  hours: row.hours,
+  project: emptyToNull(row.project),
+  tool: emptyToNull(row.tool),
+  chargeCode: emptyToNull(row.detail_charge_code),
+  taskDescription: emptyToNull(row.task_description)
+});
+
+/**
+ * Field-level differences between two versions of an entry
+ */
+export function diffEntryFields(before: WeekDiffEntry, after: WeekDiffEntry): WeekDiffFieldChange[] {
+  return DIFF_FIELDS.filter((field) => before[field] !== after[field]).map((field) => ({
+    field,
+    before: before[field],
+    after: after[field]
+  }));
+}
+
+/**
+ * Diffs a week's entries against the last submitted version of the week
+ *
+ * @param weekStart - First day of the week, YYYY-MM-DD
+ * @param rows - Entries dated in the week plus entries linked to them by amendment
+ */
+export function diffWeek(weekStart: string, rows: WeekDiffSourceRow[]): WeekDiff {
+  const weekEnd = getWeekEnd(weekStart);
+  const entries = rows.map(toWeekDiffEntry);
+  const inWeek = (entry: WeekDiffEntry): boolean =>
+    entry.date !== null && entry.date >= weekStart && entry.date <= weekEnd;
+  const isSubmitted = (row: WeekDiffSourceRow): boolean => row.status === SubmissionStatus.COMPLETE;
+  const isDraft = (row: WeekDiffSourceRow): boolean => row.status === null || row.status === undefined;
+
+  const supersededIds = new Set<number>();
+  const pendingCorrections = new Map<number, WeekDiffEntry>();
+  rows.forEach((row, index) => {
+    if (row.amends_id === null || row.amends_id === undefined) return;
+    if (isSubmitted(row)) supersededIds.add(row.amends_id);
+    if (isDraft(row)) pendingCorrections.set(row.amends_id, entries[index]!);
+  });
+
+  const diff: WeekDiff = { weekStart, weekEnd, added: [], removed: [], modified: [], unchangedCount: 0 };
+  const submittedInWeek = new Set<number>();
+
+  rows.forEach((row, index) => {
+    const entry = entries[index]!;
+    if (!isSubmitted(row) || !inWeek(entry) || supersededIds.has(row.id)) return;
+    submittedInWeek.add(row.id);
+
+    const correction = pendingCorrections.get(row.id);
+    if (!correction) {
+      diff.unchangedCount++;
+    } else if (!inWeek(correction)) {
+      diff.removed.push({ entry, draftId: correction.id, movedToDate: correction.date });
+    } else {
+      const changes = diffEntryFields(entry, correction);
+      if (changes.length === 0) {
+        diff.unchangedCount++;
+      } else {
+        diff.modified.push({ originalId: row.id, draftId: correction.id, before: entry, after: correction, changes });
+      }
+    }
+  });
+
+  rows.forEach((row, index) => {
+    const entry = entries[index]!;
+    if (!isDraft(row) || !inWeek(entry)) return;
+    if (entry.amendsId !== null && submittedInWeek.has(entry.amendsId)) return;
+    diff.added.push(entry);
+  });
+
+  return diff;
+}
@@ -24,9 +24,16 @@ import {
   initializePlugins,
   initializeDatabase,
   initializeRoutes,
+  initializeScheduler,
+  initializeSecurityPolicy,
+  initializePlatformCapabilities,
+  initializeShutdownCoordinator,
+  initializeStatusCounts,
   setMainWindowReference,
 } from "./core/AppController";
 import type { LoggerLike } from "./bootstrap/logging/logger-contract";
+import { timeStartupPhase } from "./services/startup/startup-timings";
+import { scheduleDeferredStartupTasks } from "./services/startup/deferred-startup";
 
 const __filename = fileURLToPath(import.meta.url);
 const __dirname = dirname(__filename);
@@ -72,14 +79,16 @@ app
     const logging = await loggingPromise;
 
     // Initialize logging
-    const { appLogger, dbLogger } = await initializeLogging({
-      app,
-      flags,
-      backendDirname: __dirname,
-      shimAppLogger,
-      shimDbLogger,
-      logging,
-    });
+    const { appLogger, dbLogger } = await timeStartupPhase("logging", () =>
+      initializeLogging({
+        app,
+        flags,
+        backendDirname: __dirname,
+        shimAppLogger,
+        shimDbLogger,
+        logging,
+      })
+    );
 
     setAppUserModelId(app, appLogger, "com.sheetpilot.app");
     appLogger.verbose("Fixing desktop shortcut icon (Windows only)");
@@ -90,30 +99,46 @@ app
     });
 
     // Initialize plugins
-    await initializePlugins(appLogger);
+    await timeStartupPhase("plugins", () => initializePlugins(appLogger));
 
     // Initialize database
-    initializeDatabase(app, dbLogger);
+    timeStartupPhase("database", () => initializeDatabase(app, dbLogger));
 
     // Initialize routes (IPC handlers)
-    initializeRoutes({
-      logger: appLogger,
-      backendDirname: __dirname,
-    });
+    timeStartupPhase("routes", () =>
+      initializeRoutes({
+        logger: appLogger,
+        backendDirname: __dirname,
+      })
+    );
+
+    // Start background scheduler (scheduled exports)
+    timeStartupPhase("scheduler", () => initializeScheduler(app, appLogger));
+
+    // Security policy: sign out on workstation lock/sleep, alert on credential reads
+    initializeSecurityPolicy(appLogger);
+
+    // Complete or roll back in-flight work when the app quits
+    initializeShutdownCoordinator(app, appLogger);
+
+    // Kiosk builds may block save dialogs or the Downloads folder
+    initializePlatformCapabilities(appLogger);
 
     appLogger.verbose("Creating main application window");
     const windowState = getDefaultWindowState();
-    mainWindow = createMainWindow({
-      app,
-      logger: appLogger,
-      packagedLike: flags.packagedLike,
-      isSmoke: flags.isSmoke,
-      backendDirname: __dirname,
-      windowState,
-      scheduleWindowStateSave: windowStateSaver.scheduleSave,
-      restoreWindowStateAsync: (window) =>
-        restoreWindowState({ app, screen, window, logger: appLogger }),
-    });
+    mainWindow = timeStartupPhase("main-window", () =>
+      createMainWindow({
+        app,
+        logger: appLogger,
+        packagedLike: flags.packagedLike,
+        isSmoke: flags.isSmoke,
+        backendDirname: __dirname,
+        windowState,
+        scheduleWindowStateSave: windowStateSaver.scheduleSave,
+        restoreWindowStateAsync: (window) =>
+          restoreWindowState({ app, screen, window, logger: appLogger }),
+      })
+    );
 
     if (!mainWindow) {
       return;
@@ -126,6 +151,12 @@ app
 
     setMainWindowReference(mainWindow, appLogger);
 
+    // Window title and badge follow draft/failed/submitting counts
+    initializeStatusCounts(app, appLogger);
+
+    // Crash recovery and log cleanup run after the window is visible
+    scheduleDeferredStartupTasks(app, mainWindow);
+
     void loadRenderer({
       app,
       window: mainWindow,
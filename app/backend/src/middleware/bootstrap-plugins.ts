@@ -17,6 +17,10 @@ import { MemoryDataService } from '@/services/plugins/memory-data-service';
 import { SQLiteCredentialService } from '@/services/plugins/sqlite-credential-service';
 import { ElectronBotService } from '@/services/plugins/electron-bot-service';
 import { MockSubmissionService } from '@/services/plugins/mock-submission-service';
+import { resolveChargeCodeRule, type ChargeCodeRule } from '@/logic/charge-code-rules';
+import { resolveSubmissionPostconditions } from '@/logic/submission-postconditions';
+import { resolveEntryKindRules, type EntryKindRules } from '@/logic/entry-kinds';
+import type { SubmissionPostcondition } from '@sheetpilot/bot';
 import * as path from 'path';
 
 /**
@@ -73,3 +77,30 @@ export function getSubmissionService() {
   return registry.getPlugin('submission');
 }
 
+
+/**
+ * Get the charge code rule for the active submission service profile
+ */
+export function getChargeCodeRule(): ChargeCodeRule | null {
+  const registry = PluginRegistry.getInstance();
+  const submissionConfig = registry.getNamespaceConfig('submission');
+  return resolveChargeCodeRule(submissionConfig?.options, registry.getActivePluginName('submission'));
+}
+
+/**
+ * Get the submission postconditions for the active submission service profile
+ */
+export function getActiveSubmissionPostconditions(): SubmissionPostcondition[] {
+  const registry = PluginRegistry.getInstance();
+  const submissionConfig = registry.getNamespaceConfig('submission');
+  return resolveSubmissionPostconditions(submissionConfig?.options, registry.getActivePluginName('submission'));
+}
+
+/**
+ * Get the PTO, holiday and training rules for the active submission service profile
+ */
+export function getEntryKindRules(): EntryKindRules {
+  const registry = PluginRegistry.getInstance();
+  const submissionConfig = registry.getNamespaceConfig('submission');
+  return resolveEntryKindRules(submissionConfig?.options, registry.getActivePluginName('submission'));
+}
@@ -108,6 +108,22 @@ export function getProjectByName(name: string): Project | undefined {
   return stmt.get(name) as Project | undefined;
 }
 
+/**
+ * Gets projects with a retirement date, including inactive ones so old
+ * drafts still resolve to their successor
+ */
+export function getProjectRetirements(): Array<
+  Pick<Project, "name" | "retired_on" | "successor_project">
+> {
+  const db = getDb();
+  const stmt = db.prepare(`
+    SELECT name, retired_on, successor_project FROM business_config_projects
+    WHERE retired_on IS NOT NULL
+    ORDER BY name
+  `);
+  return stmt.all() as Array<Pick<Project, "name" | "retired_on" | "successor_project">>;
+}
+
 /**
  * Gets a tool by ID
  */
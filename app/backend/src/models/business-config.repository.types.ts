@@ -17,6 +17,10 @@ export interface Project {
   requires_tools: boolean;
   display_order: number;
   is_active: boolean;
+  /** First day (YYYY-MM-DD) the project no longer accepts hours; null while active */
+  retired_on: string | null;
+  /** Project that replaces this one once retired */
+  successor_project: string | null;
   created_at: string;
   updated_at: string;
 }
@@ -64,6 +68,9 @@ export interface ProjectUpdate {
   requires_tools?: boolean;
   display_order?: number;
   is_active?: boolean;
+  /** Null un-retires the project */
+  retired_on?: string | null;
+  successor_project?: string | null;
 }
 
 /**
@@ -93,6 +100,8 @@ export interface ProjectCreate {
   requires_tools?: boolean;
   display_order?: number;
   is_active?: boolean;
+  retired_on?: string | null;
+  successor_project?: string | null;
 }
 
 /**
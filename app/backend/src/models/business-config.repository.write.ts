@@ -45,6 +45,16 @@ export function updateProject(id: number, updates: ProjectUpdate): void {
       value: updates.is_active ? 1 : 0,
       include: updates.is_active !== undefined,
     },
+    {
+      clause: "retired_on = ?",
+      value: updates.retired_on,
+      include: updates.retired_on !== undefined,
+    },
+    {
+      clause: "successor_project = ?",
+      value: updates.successor_project,
+      include: updates.successor_project !== undefined,
+    },
   ]);
 
   if (fields.length === 0) {
@@ -138,14 +148,17 @@ export function updateChargeCode(id: number, updates: ChargeCodeUpdate): void {
 export function addProject(project: ProjectCreate): number {
   const db = getDb();
   const stmt = db.prepare(`
-    INSERT INTO business_config_projects (name, requires_tools, display_order, is_active)
-    VALUES (?, ?, ?, ?)
+    INSERT INTO business_config_projects
+      (name, requires_tools, display_order, is_active, retired_on, successor_project)
+    VALUES (?, ?, ?, ?, ?, ?)
   `);
   const result = stmt.run(
     project.name,
     project.requires_tools !== undefined ? (project.requires_tools ? 1 : 0) : 1,
     project.display_order ?? 0,
-    project.is_active !== undefined ? (project.is_active ? 1 : 0) : 1
+    project.is_active !== undefined ? (project.is_active ? 1 : 0) : 1,
+    project.retired_on ?? null,
+    project.successor_project ?? null
   );
   dbLogger.info("Project added", {
     id: Number(result.lastInsertRowid),
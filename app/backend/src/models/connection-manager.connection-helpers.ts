@@ -16,6 +16,9 @@ import {
   DatabaseSchemaError,
 } from "@sheetpilot/shared/errors";
 import { ensureSchemaInternal } from "./connection-manager.schema";
+import { attachStatementCache } from "./statement-cache";
+import { getDatabaseTimeouts } from "./connection-stats";
+import { guardNewerSchema, isDatabaseReadOnly } from "./schema-compatibility";
 
 /**
  * Type for checking connection health
@@ -192,6 +195,13 @@ export function openDatabaseConnection(dbPath: string): BetterSqlite3.Database {
   db.pragma("journal_mode = WAL");
   db.pragma("synchronous = NORMAL");
   db.pragma("cache_size = -32768"); // 32MB cache
+  db.pragma(`busy_timeout = ${getDatabaseTimeouts().busyTimeoutMs}`);
+
+  // Reuse prepared statements for hot queries on this connection
+  attachStatementCache(db);
+
+  // A newer app version's schema must not be written by this one
+  guardNewerSchema(db, dbPath);
 
   return db;
 }
@@ -206,6 +216,10 @@ export function initializeSchemaIfNeeded(
 ): boolean {
   // Schema initialization (thread-safe - only first call succeeds)
   if (!schemaInitialized) {
+    if (isDatabaseReadOnly(db)) {
+      dbLogger.verbose("Skipping schema initialization for read-only database", { dbPath });
+      return true;
+    }
     const timer = dbLogger.startTimer("schema-init");
     try {
       ensureSchemaInternal(db);
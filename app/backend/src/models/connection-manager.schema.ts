@@ -53,7 +53,7 @@ const getUniqueIndexExists = (db: BetterSqlite3.Database): boolean => {
   return Boolean(indexExists);
 };
 
-const getDuplicateCount = (db: BetterSqlite3.Database): number => {
+export const getDuplicateCount = (db: BetterSqlite3.Database): number => {
   const duplicateCount = db
     .prepare(
       `
@@ -74,7 +74,7 @@ const getDuplicateCount = (db: BetterSqlite3.Database): number => {
   return duplicateCount?.count ?? 0;
 };
 
-const logDuplicateWarning = (duplicateCount: number, dbPath: string): void => {
+export const logDuplicateWarning = (duplicateCount: number, dbPath: string): void => {
   dbLogger.warn(
     "Skipping unique index creation due to existing duplicate data",
     {
@@ -15,6 +15,14 @@ import { DatabaseConnectionError } from "@sheetpilot/shared/errors";
 import { ensureSchemaInternal } from "./connection-manager.schema";
 import { fixGeneratedHoursColumnIfNeeded } from "./connection-manager.migration-helpers";
 import { performConnectionInitialization } from "./connection-manager.connection-helpers";
+import { clearStatementCache, detachStatementCache } from "./statement-cache";
+import {
+  setDatabaseTimeouts,
+  type DatabaseTimeoutSettings,
+} from "./connection-stats";
+import { isDatabaseReadOnly } from "./schema-compatibility";
+import { runMigrations } from "./migrations";
+import { clearAllCredentials } from "./credentials-repository";
 
 /**
  * Database file path configuration
@@ -88,6 +96,7 @@ function isConnectionHealthy(): boolean {
 export function closeConnection(): void {
   if (connectionInstance) {
     try {
+      detachStatementCache(connectionInstance);
       connectionInstance.close();
       dbLogger.info("Database connection closed");
     } catch (error) {
@@ -104,6 +113,22 @@ export function closeConnection(): void {
   }
 }
 
+/**
+ * Sets the busy timeout and slow statement threshold, applying the busy
+ * timeout to the open connection right away (new connections pick it up
+ * when they open)
+ */
+export function configureDatabaseTimeouts(
+  overrides: Partial<DatabaseTimeoutSettings>
+): DatabaseTimeoutSettings {
+  const applied = setDatabaseTimeouts(overrides);
+  if (isConnectionHealthy()) {
+    connectionInstance!.pragma(`busy_timeout = ${applied.busyTimeoutMs}`);
+  }
+  dbLogger.verbose("Database timeouts configured", { ...applied });
+  return applied;
+}
+
 /**
  * Resets the preventReconnection flag (for testing)
  */
@@ -118,6 +143,7 @@ export function resetPreventReconnectionFlag(): void {
 export function closeConnectionForTesting(): void {
   if (connectionInstance) {
     try {
+      detachStatementCache(connectionInstance);
       connectionInstance.close();
       dbLogger.info("Database connection closed for testing");
     } catch (error) {
@@ -242,6 +268,14 @@ export function ensureSchema() {
   dbLogger.info("Ensuring database schema is up to date");
   const db = getDb();
 
+  // Tables written by a newer app version are left exactly as they are
+  if (isDatabaseReadOnly(db)) {
+    dbLogger.warn("Database schema is newer than this app version; skipping schema checks");
+    schemaInitialized = true;
+    timer.done({ skipped: true, reason: "schema-too-new" });
+    return;
+  }
+
   ensureSchemaInternal(db);
 
   // Safety check: Always verify hours column is not generated (fix if needed)
@@ -264,6 +298,10 @@ export function rebuildDatabase(): void {
   try {
     dbLogger.warn("Rebuilding database - dropping all tables");
 
+    // Credentials also live in the OS keychain store, which dropping the
+    // table would leave behind
+    clearAllCredentials();
+
     // Drop all tables
     db.exec(`
             DROP TABLE IF EXISTS timesheet;
@@ -275,10 +313,20 @@ export function rebuildDatabase(): void {
     // Reset schema initialized flag to force recreation
     schemaInitialized = false;
 
-    // Recreate schema
+    // Statements prepared against the dropped tables must not be reused
+    clearStatementCache(db);
+
+    // Recreate schema; columns added after the baseline (submission keys,
+    // amendments, time zones, ...) only exist through migrations
     ensureSchema();
+    const migrationResult = runMigrations(db, getDbPath());
+    if (!migrationResult.success) {
+      throw new Error(`Could not migrate rebuilt database: ${migrationResult.error ?? "unknown error"}`);
+    }
 
-    dbLogger.info("Database rebuilt successfully");
+    dbLogger.info("Database rebuilt successfully", {
+      schemaVersion: migrationResult.toVersion,
+    });
     timer.done();
   } catch (error) {
     dbLogger.error("Could not rebuild database", error);
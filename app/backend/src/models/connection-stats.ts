@@ -0,0 +1,145 @@
+/**
+ * @fileoverview Database Timeouts and Connection Statistics
+ *
+ * SheetPilot uses one synchronous better-sqlite3 connection, not a pool:
+ * every statement runs on the main process thread, so a slow statement or a
+ * long wait for a lock held by another process stalls IPC (and any UI
+ * waiting on it) for its whole duration. These settings bound the lock wait
+ * (SQLite busy timeout) and decide which statements are logged as slow; the
+ * statistics record statement time, slow statements and lock timeouts so
+ * stalls during submissions can be traced to the database or ruled out.
+ *
+ * Statement time is measured for statements prepared through the statement
+ * cache, which covers the hot queries.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+
+export interface DatabaseTimeoutSettings {
+  /** How long a statement waits for another process's lock before SQLITE_BUSY */
+  busyTimeoutMs: number;
+  /** Statements taking at least this long are logged and counted as slow */
+  slowStatementMs: number;
+}
+
+export const DEFAULT_DATABASE_TIMEOUTS: DatabaseTimeoutSettings = {
+  busyTimeoutMs: 5000,
+  slowStatementMs: 100,
+};
+
+/** Largest busy timeout accepted; longer waits would freeze the app */
+export const MAX_BUSY_TIMEOUT_MS = 60_000;
+/** Slow statements kept for diagnostics, newest first */
+const RECENT_SLOW_LIMIT = 10;
+/** SQL is truncated to this many characters in logs and statistics */
+const SQL_PREVIEW_LENGTH = 120;
+
+export interface SlowStatement {
+  sql: string;
+  durationMs: number;
+  at: string;
+}
+
+export interface DatabaseConnectionStats extends DatabaseTimeoutSettings {
+  executions: number;
+  totalMs: number;
+  maxMs: number;
+  slowStatements: number;
+  /** Statements that gave up waiting for a lock */
+  busyTimeouts: number;
+  recentSlow: SlowStatement[];
+}
+
+let timeouts: DatabaseTimeoutSettings = { ...DEFAULT_DATABASE_TIMEOUTS };
+let executions = 0;
+let totalMs = 0;
+let maxMs = 0;
+let slowStatements = 0;
+let busyTimeouts = 0;
+let recentSlow: SlowStatement[] = [];
+
+/**
+ * Keeps the known, finite, non-negative numbers from a stored settings object
+ */
+export function sanitizeDatabaseTimeoutSettings(raw: unknown): Partial<DatabaseTimeoutSettings> {
+  if (!raw || typeof raw !== "object") return {};
+  const stored = raw as Record<string, unknown>;
+  const result: Partial<DatabaseTimeoutSettings> = {};
+  for (const key of Object.keys(DEFAULT_DATABASE_TIMEOUTS) as Array<keyof DatabaseTimeoutSettings>) {
+    const value = stored[key];
+    if (typeof value === "number" && Number.isFinite(value) && value >= 0) {
+      result[key] = key === "busyTimeoutMs" ? Math.min(value, MAX_BUSY_TIMEOUT_MS) : value;
+    }
+  }
+  return result;
+}
+
+/**
+ * Replaces the timeouts (unset values fall back to the defaults)
+ * Use configureDatabaseTimeouts() so the open connection picks them up too.
+ */
+export function setDatabaseTimeouts(overrides: Partial<DatabaseTimeoutSettings>): DatabaseTimeoutSettings {
+  timeouts = { ...DEFAULT_DATABASE_TIMEOUTS, ...overrides };
+  return { ...timeouts };
+}
+
+export function getDatabaseTimeouts(): DatabaseTimeoutSettings {
+  return { ...timeouts };
+}
+
+const previewSql = (sql: string): string => {
+  const compact = sql.replace(/\s+/g, " ").trim();
+  return compact.length > SQL_PREVIEW_LENGTH ? `${compact.slice(0, SQL_PREVIEW_LENGTH)}…` : compact;
+};
+
+/**
+ * Records one statement execution
+ */
+export function recordStatementDuration(sql: string, durationMs: number): void {
+  executions++;
+  totalMs += durationMs;
+  maxMs = Math.max(maxMs, durationMs);
+  if (durationMs < timeouts.slowStatementMs) {
+    return;
+  }
+  slowStatements++;
+  const slow = { sql: previewSql(sql), durationMs: Math.round(durationMs), at: new Date().toISOString() };
+  recentSlow = [slow, ...recentSlow].slice(0, RECENT_SLOW_LIMIT);
+  dbLogger.warn("Slow database statement", slow);
+}
+
+/**
+ * Records a statement that failed with SQLITE_BUSY after the busy timeout
+ */
+export function recordBusyTimeout(sql: string): void {
+  busyTimeouts++;
+  dbLogger.warn("Database statement timed out waiting for a lock", {
+    sql: previewSql(sql),
+    busyTimeoutMs: timeouts.busyTimeoutMs,
+  });
+}
+
+export function getDatabaseConnectionStats(): DatabaseConnectionStats {
+  return {
+    ...timeouts,
+    executions,
+    totalMs: Math.round(totalMs),
+    maxMs: Math.round(maxMs),
+    slowStatements,
+    busyTimeouts,
+    recentSlow: [...recentSlow],
+  };
+}
+
+export function resetDatabaseConnectionStats(): void {
+  executions = 0;
+  totalMs = 0;
+  maxMs = 0;
+  slowStatements = 0;
+  busyTimeouts = 0;
+  recentSlow = [];
+}
@@ -2,6 +2,12 @@
  * @fileoverview Credentials Repository
  * 
  * Handles all credential storage and retrieval operations with secure encryption.
+ *
+ * Credentials are moving from the SQLite table to the OS keychain. While the
+ * migration is under way both stores are written, reads try the keychain
+ * first and fall back to SQLite, and a SQLite-only credential is copied to
+ * the keychain when it is read. A keychain copy is only trusted once it has
+ * been read back and matches, so a failing keychain never strands anyone.
  * 
  * @author Andrew Hughes
  * @version 1.0.0
@@ -84,7 +90,90 @@ function decryptPassword(encryptedPassword: string): string {
 }
 
 /**
- * Stores or updates credentials for a service
+ * OS keychain store the credentials are migrating to
+ */
+export interface CredentialKeychain {
+    read(service: string): { email: string; password: string } | null;
+    write(service: string, email: string, password: string): void;
+    remove(service: string): void;
+    /** Services with an entry in the keychain */
+    services(): string[];
+}
+
+let credentialKeychain: CredentialKeychain | null = null;
+
+/**
+ * Sets the keychain written alongside SQLite; null leaves SQLite as the only store
+ */
+export function setCredentialKeychain(keychain: CredentialKeychain | null): void {
+    credentialKeychain = keychain;
+}
+
+/**
+ * Writes credentials to the keychain and reads them back
+ *
+ * @returns Whether the keychain now holds exactly these credentials; a copy
+ * that does not read back is removed so reads keep falling back to SQLite
+ */
+function writeKeychainVerified(service: string, email: string, password: string): boolean {
+    if (!credentialKeychain) return false;
+    try {
+        credentialKeychain.write(service, email, password);
+        const stored = credentialKeychain.read(service);
+        if (stored?.email === email && stored.password === password) {
+            return true;
+        }
+        dbLogger.warn('Keychain copy did not read back; keeping SQLite copy only', { service });
+    } catch (error) {
+        dbLogger.warn('Could not write credentials to keychain', {
+            service,
+            error: error instanceof Error ? error.message : String(error)
+        });
+    }
+    removeFromKeychain(service);
+    return false;
+}
+
+function removeFromKeychain(service: string): void {
+    if (!credentialKeychain) return;
+    try {
+        credentialKeychain.remove(service);
+    } catch (error) {
+        dbLogger.warn('Could not remove credentials from keychain', {
+            service,
+            error: error instanceof Error ? error.message : String(error)
+        });
+    }
+}
+
+function readFromKeychain(service: string): { email: string; password: string } | null {
+    if (!credentialKeychain) return null;
+    try {
+        return credentialKeychain.read(service);
+    } catch (error) {
+        dbLogger.warn('Could not read credentials from keychain; falling back to SQLite', {
+            service,
+            error: error instanceof Error ? error.message : String(error)
+        });
+        return null;
+    }
+}
+
+function listKeychainServices(): string[] {
+    if (!credentialKeychain) return [];
+    try {
+        return credentialKeychain.services();
+    } catch (error) {
+        dbLogger.warn('Could not list keychain credentials', {
+            error: error instanceof Error ? error.message : String(error)
+        });
+        return [];
+    }
+}
+
+/**
+ * Stores or updates credentials for a service (in SQLite and, when
+ * configured, the keychain)
  */
 export function storeCredentials(service: string, email: string, password: string) {
     const timer = dbLogger.startTimer('store-credentials');
@@ -112,10 +201,12 @@ export function storeCredentials(service: string, email: string, password: strin
             result = insert.run(service, email, encryptedPassword);
         }
         
+        const inKeychain = writeKeychainVerified(service, email, password);
         dbLogger.audit('store-credentials', 'Credentials stored', { 
             service,
             email,
-            changes: result.changes 
+            changes: result.changes,
+            inKeychain
         });
         timer.done({ changes: result.changes });
         
@@ -135,11 +226,116 @@ export function storeCredentials(service: string, email: string, password: strin
     }
 }
 
+// Set by the security policy when the workstation locks; cleared by the next
+// successful login. Stored credentials stay encrypted on disk either way.
+let credentialVaultLocked = false;
+
+/**
+ * Locks the credential vault so stored passwords cannot be read
+ */
+export function lockCredentialVault(): void {
+    if (!credentialVaultLocked) {
+        credentialVaultLocked = true;
+        dbLogger.audit('lock-credential-vault', 'Credential vault locked');
+    }
+}
+
+/**
+ * Unlocks the credential vault (after the user has re-authenticated)
+ */
+export function unlockCredentialVault(): void {
+    if (credentialVaultLocked) {
+        credentialVaultLocked = false;
+        dbLogger.audit('unlock-credential-vault', 'Credential vault unlocked');
+    }
+}
+
+/**
+ * Whether stored passwords are currently unreadable
+ */
+export function isCredentialVaultLocked(): boolean {
+    return credentialVaultLocked;
+}
+
+/** Why a caller needs a stored password */
+export type CredentialAccessPurpose = 'submission' | 'validation' | 'cloud-sync' | 'reveal' | 'other';
+
+export interface CredentialAccessOptions {
+    /** Command or flow that triggered the read (e.g. the IPC channel) */
+    caller?: string;
+    purpose?: CredentialAccessPurpose;
+    ignoreVaultLock?: boolean;
+}
+
+export interface CredentialAccessEvent {
+    service: string;
+    caller: string;
+    purpose: CredentialAccessPurpose;
+    accessedAt: string;
+    outcome: 'found' | 'not-found' | 'locked' | 'error';
+}
+
+let credentialAccessListener: ((event: CredentialAccessEvent) => void) | null = null;
+
+/**
+ * Sets the listener told about every credential read (used for alerting)
+ */
+export function setCredentialAccessListener(
+    listener: ((event: CredentialAccessEvent) => void) | null
+): void {
+    credentialAccessListener = listener;
+}
+
+/**
+ * Writes the audit record for a credential read and notifies the listener
+ */
+function recordCredentialAccess(
+    service: string,
+    options: CredentialAccessOptions,
+    outcome: CredentialAccessEvent['outcome']
+): void {
+    const event: CredentialAccessEvent = {
+        service,
+        caller: options.caller ?? 'unknown',
+        purpose: options.purpose ?? 'other',
+        accessedAt: new Date().toISOString(),
+        outcome
+    };
+    dbLogger.audit('credentials-get', 'Credential read', event);
+    try {
+        credentialAccessListener?.(event);
+    } catch (error) {
+        dbLogger.error('Credential access listener failed', error);
+    }
+}
+
 /**
  * Retrieves credentials for a service
+ *
+ * Reads the keychain first and falls back to SQLite; a SQLite-only
+ * credential is copied to the keychain on the way out. Every call is
+ * audited with its caller and purpose. Returns null while the vault is
+ * locked unless `ignoreVaultLock` is set, which only login uses to verify
+ * the password that will unlock it.
  */
-export function getCredentials(service: string): { email: string; password: string } | null {
+export function getCredentials(
+    service: string,
+    options: CredentialAccessOptions = {}
+): { email: string; password: string } | null {
+    if (credentialVaultLocked && !options.ignoreVaultLock) {
+        dbLogger.verbose('Credential vault is locked', { service });
+        recordCredentialAccess(service, options, 'locked');
+        return null;
+    }
     const timer = dbLogger.startTimer('get-credentials');
+    
+    const fromKeychain = readFromKeychain(service);
+    if (fromKeychain) {
+        recordCredentialAccess(service, options, 'found');
+        timer.done({ found: true, email: fromKeychain.email, source: 'keychain' });
+        return fromKeychain;
+    }
+    
     const db = getDb();
     
     try {
@@ -155,22 +351,25 @@ export function getCredentials(service: string): { email: string; password: stri
         
         if (!result) {
             dbLogger.verbose('No credentials found', { service });
+            recordCredentialAccess(service, options, 'not-found');
             timer.done({ found: false });
             return null;
         }
         
-        dbLogger.audit('get-credentials', 'Credentials retrieved', { 
-            service,
-            email: result.email 
-        });
-        timer.done({ found: true, email: result.email });
-        
-        return {
+        const credentials = {
             email: result.email,
             password: decryptPassword(result.password)
         };
+        if (credentialKeychain && writeKeychainVerified(service, credentials.email, credentials.password)) {
+            dbLogger.audit('migrate-credentials', 'Credentials copied to keychain', { service });
+        }
+        recordCredentialAccess(service, options, 'found');
+        timer.done({ found: true, email: result.email, source: 'sqlite' });
+        
+        return credentials;
     } catch (error: unknown) {
         dbLogger.error('Could not retrieve credentials', error);
+        recordCredentialAccess(service, options, 'error');
         timer.done({ outcome: 'error' });
         return null;
     }
@@ -211,6 +410,7 @@ export function deleteCredentials(service: string) {
         `);
         
         const result = deleteCreds.run(service);
+        removeFromKeychain(service);
         
         if (result.changes > 0) {
             dbLogger.audit('delete-credentials', 'Credentials deleted', { 
@@ -249,6 +449,9 @@ export function clearAllCredentials(): void {
         
         const deleteAll = db.prepare('DELETE FROM credentials');
         const result = deleteAll.run();
+        for (const service of listKeychainServices()) {
+            removeFromKeychain(service);
+        }
         
         dbLogger.info('All credentials cleared', { count: result.changes });
         timer.done({ changes: result.changes });
@@ -259,4 +462,67 @@ export function clearAllCredentials(): void {
     }
 }
 
+/** Where a credential lives during the keychain migration */
+export type CredentialMigrationStatus = 'migrated' | 'sqlite-only' | 'keychain-only' | 'mismatch';
 
+export interface CredentialMigrationAuditEntry {
+    service: string;
+    email: string;
+    status: CredentialMigrationStatus;
+}
+
+export interface CredentialMigrationAudit {
+    keychainAvailable: boolean;
+    entries: CredentialMigrationAuditEntry[];
+    /** Services that would be lost if SQLite were dropped now */
+    sqliteOnly: string[];
+}
+
+/**
+ * Compares the keychain with SQLite service by service (passwords are
+ * compared, never returned)
+ */
+export function auditCredentialMigration(): CredentialMigrationAudit {
+    const db = getDb();
+    const rows = db.prepare(`
+        SELECT service, email, password FROM credentials
+        ORDER BY service
+    `).all() as Array<{ service: string; email: string; password: string }>;
+    
+    const entries: CredentialMigrationAuditEntry[] = [];
+    const seen = new Set<string>();
+    for (const row of rows) {
+        seen.add(row.service);
+        const inKeychain = readFromKeychain(row.service);
+        let status: CredentialMigrationStatus = 'sqlite-only';
+        if (inKeychain) {
+            let matches = inKeychain.email === row.email;
+            if (matches) {
+                try {
+                    matches = decryptPassword(row.password) === inKeychain.password;
+                } catch {
+                    matches = false;
+                }
+            }
+            status = matches ? 'migrated' : 'mismatch';
+        }
+        entries.push({ service: row.service, email: row.email, status });
+    }
+    
+    for (const service of listKeychainServices()) {
+        if (seen.has(service)) continue;
+        const inKeychain = readFromKeychain(service);
+        if (inKeychain) {
+            entries.push({ service, email: inKeychain.email, status: 'keychain-only' });
+        }
+    }
+    entries.sort((a, b) => a.service.localeCompare(b.service));
+    
+    const sqliteOnly = entries.filter((entry) => entry.status === 'sqlite-only').map((entry) => entry.service);
+    dbLogger.audit('audit-credential-migration', 'Credential migration audited', {
+        keychainAvailable: credentialKeychain !== null,
+        services: entries.length,
+        sqliteOnly
+    });
+    return { keychainAvailable: credentialKeychain !== null, entries, sqliteOnly };
+}
@@ -0,0 +1,137 @@
+/**
+ * @fileoverview Dropdown Resolution Repository
+ *
+ * Remembers which dropdown option was confirmed when a typed value matched
+ * several options (e.g. "FAB" matching three projects), so the bot picks the
+ * same option next time. Admins can review and remove the mappings.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+
+export interface DropdownResolutionInput {
+  fieldKey: string;
+  typedValue: string;
+  optionText: string;
+  candidates: string[];
+}
+
+export interface DropdownResolutionRow {
+  id: number;
+  field_key: string;
+  typed_value: string;
+  option_text: string;
+  /** JSON array of option texts */
+  candidates: string;
+  confirmed_count: number;
+  first_confirmed_at: string;
+  last_confirmed_at: string;
+}
+
+export interface DropdownResolutionRecord {
+  id: number;
+  fieldKey: string;
+  typedValue: string;
+  optionText: string;
+  /** Options the typed value matched when it was last confirmed */
+  candidates: string[];
+  /** Submitted rows that used this option since it was first confirmed */
+  confirmedCount: number;
+  firstConfirmedAt: string;
+  lastConfirmedAt: string;
+}
+
+const parseCandidates = (candidates: string): string[] => {
+  try {
+    const parsed = JSON.parse(candidates) as unknown;
+    return Array.isArray(parsed) ? parsed.map(String) : [];
+  } catch {
+    return [];
+  }
+};
+
+const toRecord = (row: DropdownResolutionRow): DropdownResolutionRecord => ({
+  id: row.id,
+  fieldKey: row.field_key,
+  typedValue: row.typed_value,
+  optionText: row.option_text,
+  candidates: parseCandidates(row.candidates),
+  confirmedCount: row.confirmed_count,
+  firstConfirmedAt: row.first_confirmed_at,
+  lastConfirmedAt: row.last_confirmed_at,
+});
+
+/**
+ * Option last confirmed for a typed value, or null
+ */
+export function lookupDropdownResolution(fieldKey: string, typedValue: string): string | null {
+  const db = getDb();
+  const row = db
+    .prepare(`SELECT option_text FROM dropdown_resolutions WHERE field_key = ? AND typed_value = ?`)
+    .get(fieldKey, typedValue.trim()) as { option_text: string } | undefined;
+  return row?.option_text ?? null;
+}
+
+/**
+ * Records the option a submitted row used for an ambiguous typed value
+ *
+ * Confirming the same option again bumps its count; confirming a different
+ * one replaces it, since the latest submission reflects what the user wants.
+ */
+export function confirmDropdownResolution(resolution: DropdownResolutionInput): void {
+  const db = getDb();
+  const typedValue = resolution.typedValue.trim();
+  const previous = lookupDropdownResolution(resolution.fieldKey, typedValue);
+  db.prepare(
+    `
+      INSERT INTO dropdown_resolutions (field_key, typed_value, option_text, candidates)
+      VALUES (?, ?, ?, ?)
+      ON CONFLICT(field_key, typed_value) DO UPDATE SET
+        confirmed_count = CASE WHEN option_text = excluded.option_text
+          THEN confirmed_count + 1 ELSE 1 END,
+        first_confirmed_at = CASE WHEN option_text = excluded.option_text
+          THEN first_confirmed_at ELSE CURRENT_TIMESTAMP END,
+        option_text = excluded.option_text,
+        candidates = excluded.candidates,
+        last_confirmed_at = CURRENT_TIMESTAMP
+    `
+  ).run(resolution.fieldKey, typedValue, resolution.optionText, JSON.stringify(resolution.candidates));
+  if (previous !== resolution.optionText) {
+    dbLogger.info("Dropdown resolution recorded", {
+      fieldKey: resolution.fieldKey,
+      typedValue,
+      optionText: resolution.optionText,
+      previous,
+    });
+  }
+}
+
+/**
+ * Lists remembered dropdown resolutions, most recently confirmed first
+ */
+export function listDropdownResolutions(fieldKey?: string): DropdownResolutionRecord[] {
+  const db = getDb();
+  const rows = (
+    fieldKey
+      ? db
+          .prepare(`SELECT * FROM dropdown_resolutions WHERE field_key = ? ORDER BY last_confirmed_at DESC, id DESC`)
+          .all(fieldKey)
+      : db.prepare(`SELECT * FROM dropdown_resolutions ORDER BY last_confirmed_at DESC, id DESC`).all()
+  ) as DropdownResolutionRow[];
+  return rows.map(toRecord);
+}
+
+/**
+ * Forgets a resolution so the bot takes the first matching option again
+ *
+ * @returns Whether a row was removed
+ */
+export function deleteDropdownResolution(id: number): boolean {
+  const db = getDb();
+  const result = db.prepare(`DELETE FROM dropdown_resolutions WHERE id = ?`).run(id);
+  return result.changes > 0;
+}
@@ -20,9 +20,29 @@ export {
     resetPreventReconnectionFlag,
     shutdownDatabase,
     ensureSchema,
-    rebuildDatabase
+    rebuildDatabase,
+    configureDatabaseTimeouts
 } from './connection-manager';
 
+// Database Timeouts and Connection Statistics
+export {
+    getDatabaseTimeouts,
+    getDatabaseConnectionStats,
+    resetDatabaseConnectionStats,
+    sanitizeDatabaseTimeoutSettings,
+    DEFAULT_DATABASE_TIMEOUTS,
+    MAX_BUSY_TIMEOUT_MS
+} from './connection-stats';
+export type { DatabaseTimeoutSettings, DatabaseConnectionStats, SlowStatement } from './connection-stats';
+
+// Prepared Statement Cache
+export {
+    prepareCached,
+    getStatementCacheStats,
+    STATEMENT_CACHE_LIMIT
+} from './statement-cache';
+export type { StatementCacheStats } from './statement-cache';
+
 // Timesheet Repository
 export {
     insertTimesheetEntry,
@@ -30,34 +50,213 @@ export {
     checkDuplicateEntry,
     getDuplicateEntries,
     getPendingTimesheetEntries,
+    getTimesheetEntryById,
+    getDraftTimesheetEntryById,
+    findSubmittedTimesheetEntryId,
+    getCompletedTimesheetEntries,
+    getCompletedTimesheetEntriesPage,
+    getCompletedTimesheetEntriesAfter,
+    countCompletedTimesheetEntries,
+    ARCHIVE_COLUMNS,
+    getAllTimesheetEntries,
+    getTotalHoursForDate,
+    getSubmittedHoursForDate,
+    insertDraftTimesheetEntry,
+    updateDraftTimesheetEntry,
+    saveDraftTimesheetEntry,
+    upsertDraftTimesheetEntry,
+    replaceUnsubmittedTimesheetEntry,
+    deleteUnsubmittedTimesheetEntry,
+    reassignDraftProject,
     markTimesheetEntriesAsInProgress,
     resetTimesheetEntriesStatus,
     resetInProgressTimesheetEntries,
+    getInProgressTimesheetEntryIds,
+    getEntryStatusCounts,
+    getDatabaseChangeCount,
+    generateSubmissionKey,
+    assignSubmissionKeys,
+    reconcileSubmissionKeys,
     markTimesheetEntriesAsSubmitted,
+    recordSubmissionRun,
     removeFailedTimesheetEntries,
+    recordTimesheetFailureReasons,
+    recordSubmissionCaptures,
     getTimesheetEntriesByIds,
     getSubmittedTimesheetEntriesForExport,
-    type TimesheetDbRow
+    getAmendmentChain,
+    getTimesheetEntryStatus,
+    amendTimesheetEntry,
+    SUBMITTED_ENTRY_LOCKED_ERROR,
+    getRecentTimesheetHistory,
+    getToolUsage,
+    getDraftDateTotals,
+    getActivityHeatmap,
+    getDailyHourTotals,
+    getTimesheetEntriesInRange,
+    getWeekDiffEntries,
+    bulkRecodeSubmittedEntries,
+    listRecodeHistory,
+    deleteCompletedEntry,
+    listDeletedEntries,
+    renameEntryCategory,
+    clearEntryCategory,
+    getSubmittedChargeCodeHours,
+    TIMESHEET_ISO_DATE_SQL,
+    type TimesheetDbRow,
+    type TimesheetDraftFields,
+    type ArchiveColumn,
+    type ArchiveCursor,
+    type TimesheetDraftSaveResult,
+    type ToolUsageRow,
+    type DraftDateTotalRow,
+    type ActivityHeatmapDay,
+    type BulkRecodeRequest,
+    type BulkRecodeResult,
+    type RecodeHistoryRow,
+    type DeleteCompletedEntryResult,
+    type DeletedEntryRow,
+    type EntryStatusCountsRow
 } from './timesheet-repository';
 
+// Data Integrity Check
+export {
+    checkDataIntegrity,
+    IDS_PER_ISSUE,
+    type IntegrityCheckOptions,
+    type IntegrityIssue,
+    type IntegrityIssueKind,
+    type IntegrityReport
+} from './integrity-check';
+
+// Database Backup Snapshots
+export {
+    snapshotDatabase,
+    checkBackupIntegrity,
+    type BackupIntegrityResult
+} from './database-backup';
+
 // Credentials Repository
 export {
     storeCredentials,
     getCredentials,
     listCredentials,
     deleteCredentials,
-    clearAllCredentials
+    clearAllCredentials,
+    lockCredentialVault,
+    unlockCredentialVault,
+    isCredentialVaultLocked,
+    setCredentialAccessListener,
+    setCredentialKeychain,
+    auditCredentialMigration,
+    type CredentialKeychain,
+    type CredentialMigrationAudit,
+    type CredentialMigrationAuditEntry,
+    type CredentialMigrationStatus,
+    type CredentialAccessEvent,
+    type CredentialAccessOptions,
+    type CredentialAccessPurpose
 } from './credentials-repository';
 
 // Session Repository
 export {
     createSession,
+    rotateSession,
     validateSession,
     clearSession,
     clearUserSessions,
-    getSessionByEmail
+    clearAllSessions,
+    purgeExpiredSessions,
+    getSessionStats,
+    hasActiveSession,
+    hashSessionToken,
+    setSessionInstanceId,
+    SESSION_LIFETIME_MS,
+    type SessionStats,
+    type UserSessionStats
 } from './session-repository';
 
+// Dropdown Resolution Repository
+export {
+    lookupDropdownResolution,
+    confirmDropdownResolution,
+    listDropdownResolutions,
+    deleteDropdownResolution,
+    type DropdownResolutionInput,
+    type DropdownResolutionRecord
+} from './dropdown-resolution-repository';
+
+// Run History Repository
+export {
+    startRun,
+    finishRun,
+    listRuns,
+    getRunByJobId,
+    type RunTrigger,
+    type RunStatus,
+    type RunHistoryRecord
+} from './run-history-repository';
+
+// Log Index Repository
+export {
+    getLogIndexCursor,
+    appendLogIndexLines,
+    removeLogIndexFiles,
+    listIndexedLogFiles,
+    queryLogIndex,
+    type IndexedLogLine,
+    type LogIndexCursor,
+    type LogQueryFilter,
+    type LogQueryResultLine
+} from './log-index-repository';
+
+// Scheduled Export Repository
+export {
+    listScheduledExports,
+    getScheduledExport,
+    getDueScheduledExports,
+    saveScheduledExport,
+    deleteScheduledExport,
+    markScheduledExportRun,
+    type ScheduledExport,
+    type ScheduledExportInput,
+    type ScheduledExportFormat
+} from './scheduled-export-repository';
+
+// Validation Rules Repository
+export {
+    listValidationRules,
+    getValidationRule,
+    createValidationRule,
+    updateValidationRule,
+    deleteValidationRule,
+    type ValidationRuleInput
+} from './validation-rules-repository';
+
+// Read-only Query Runner
+export {
+    checkReadonlySql,
+    runReadonlyQuery,
+    type ReadonlyQueryParams,
+    type ReadonlyQueryResult
+} from './readonly-query';
+
+// Schema Information
+export {
+    getSchemaInfo,
+    getCompatibilityInfo,
+    type SchemaInfo,
+    type SchemaTableInfo,
+    type SchemaColumnInfo
+} from './schema-info';
+
+// Schema Compatibility
+export {
+    guardNewerSchema,
+    isDatabaseReadOnly,
+    type SchemaCompatibilityInfo
+} from './schema-compatibility';
+
 // Migrations
 export {
     CURRENT_SCHEMA_VERSION,
@@ -97,6 +296,7 @@ export {
     getAllChargeCodes as repoGetAllChargeCodes,
     getProjectById,
     getProjectByName,
+    getProjectRetirements,
     getToolById,
     getToolByName,
     getChargeCodeById,
@@ -0,0 +1,249 @@
+/**
+ * @fileoverview Data Integrity Check
+ *
+ * Scans for rows that break invariants the current code relies on. Most of
+ * these can only come from databases written by older versions (or edited by
+ * hand): Complete entries without a submission time, status spellings from
+ * before SubmissionStatus, hours the CHECK constraint never caught, entries
+ * stuck in_progress after a crash, links to deleted rows, and sessions that
+ * can no longer validate.
+ *
+ * The check is read-only unless `repair` is set. Repairs only touch issues
+ * with an unambiguous fix and run in one transaction; everything else is
+ * reported for the user to fix by hand.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type BetterSqlite3 from "better-sqlite3";
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { parseSubmissionStatus } from "@sheetpilot/shared";
+import { getDb } from "./connection-manager";
+import { STATUS_SQL } from "./timesheet-repository.types";
+
+export type IntegrityIssueKind =
+  | "complete-missing-submitted-at"
+  | "complete-missing-fields"
+  | "unknown-status"
+  | "invalid-hours"
+  | "stale-in-progress"
+  | "dangling-amendment"
+  | "dangling-submitted-run"
+  | "expired-session";
+
+export interface IntegrityIssue {
+  kind: IntegrityIssueKind;
+  description: string;
+  /** Affected rows */
+  count: number;
+  /** Affected entry IDs (first IDS_PER_ISSUE); empty for sessions */
+  ids: number[];
+  /** Whether `repair` fixes this kind of issue */
+  repairable: boolean;
+  /** Rows fixed by this run (0 unless repairing) */
+  repaired: number;
+}
+
+export interface IntegrityReport {
+  checkedAt: string;
+  repairRequested: boolean;
+  issues: IntegrityIssue[];
+  totalIssues: number;
+  totalRepaired: number;
+}
+
+export interface IntegrityCheckOptions {
+  /** Fix repairable issues (default false: report only) */
+  repair?: boolean;
+  /**
+   * A submission is running, so in_progress entries are expected and are
+   * neither reported nor reset
+   */
+  submissionInProgress?: boolean;
+}
+
+/** IDs listed per issue; counts are always exact */
+export const IDS_PER_ISSUE = 50;
+
+const KNOWN_STATUS_SQL = `(${STATUS_SQL.IN_PROGRESS}, ${STATUS_SQL.COMPLETE})`;
+// SQLite's % truncates to integers, so compare against the truncated value
+const INVALID_HOURS_SQL = `hours IS NOT NULL AND (hours < 0.25 OR hours > 24 OR hours * 4 != CAST(hours * 4 AS INTEGER))`;
+
+interface EntryCheck {
+  kind: IntegrityIssueKind;
+  description: string;
+  where: string;
+  repair?: (db: BetterSqlite3.Database, ids: number[]) => number;
+}
+
+const ENTRY_CHECKS: EntryCheck[] = [
+  {
+    kind: "unknown-status",
+    description: "Entries with a status spelling this version does not recognise",
+    where: `status IS NOT NULL AND status NOT IN ${KNOWN_STATUS_SQL}`,
+    // Runs first so normalised rows are seen by the checks below. Only
+    // case/whitespace variants have an unambiguous canonical value
+    repair: (db, ids) => {
+      const select = db.prepare("SELECT status FROM timesheet WHERE id = ?");
+      const update = db.prepare("UPDATE timesheet SET status = ? WHERE id = ?");
+      let changes = 0;
+      for (const id of ids) {
+        const row = select.get(id) as { status: unknown } | undefined;
+        const canonical = parseSubmissionStatus(row?.status);
+        if (canonical) {
+          changes += update.run(canonical, id).changes;
+        }
+      }
+      return changes;
+    },
+  },
+  {
+    kind: "complete-missing-submitted-at",
+    description: "Submitted entries without a submission time",
+    where: `status = ${STATUS_SQL.COMPLETE} AND submitted_at IS NULL`,
+    // Best available time: last edit, then creation, then now
+    repair: (db) =>
+      db
+        .prepare(
+          `UPDATE timesheet
+           SET submitted_at = COALESCE(updated_at, created_at, datetime('now'))
+           WHERE status = ${STATUS_SQL.COMPLETE} AND submitted_at IS NULL`
+        )
+        .run().changes,
+  },
+  {
+    kind: "complete-missing-fields",
+    description: "Submitted entries missing a date, hours, project or task description",
+    where: `status = ${STATUS_SQL.COMPLETE} AND (date IS NULL OR date = '' OR hours IS NULL
+            OR project IS NULL OR project = '' OR task_description IS NULL OR task_description = '')`,
+  },
+  {
+    kind: "invalid-hours",
+    description: "Entries whose hours are outside 0.25-24 or not in 15-minute steps",
+    where: INVALID_HOURS_SQL,
+  },
+  {
+    kind: "stale-in-progress",
+    description: "Entries left in_progress with no submission running",
+    where: `status = ${STATUS_SQL.IN_PROGRESS}`,
+    // Back to drafts; they keep their submission key so a retry reconciles
+    repair: (db) =>
+      db
+        .prepare(`UPDATE timesheet SET status = NULL WHERE status = ${STATUS_SQL.IN_PROGRESS}`)
+        .run().changes,
+  },
+  {
+    kind: "dangling-amendment",
+    description: "Amendments linked to entries that no longer exist",
+    where: `amends_id IS NOT NULL AND amends_id NOT IN (SELECT id FROM timesheet)`,
+  },
+  {
+    kind: "dangling-submitted-run",
+    description: "Entries linked to run history rows that no longer exist",
+    where: `submitted_run_id IS NOT NULL AND submitted_run_id NOT IN (SELECT id FROM run_history)`,
+    repair: (db) =>
+      db
+        .prepare(
+          `UPDATE timesheet SET submitted_run_id = NULL
+           WHERE submitted_run_id IS NOT NULL AND submitted_run_id NOT IN (SELECT id FROM run_history)`
+        )
+        .run().changes,
+  },
+];
+
+const findEntryIds = (db: BetterSqlite3.Database, where: string): number[] =>
+  (db.prepare(`SELECT id FROM timesheet WHERE ${where} ORDER BY id`).all() as Array<{ id: number }>).map(
+    (row) => row.id
+  );
+
+/**
+ * Session tokens whose expiry has passed or cannot be parsed; validateSession
+ * removes these only when the token is presented again, which never happens
+ * for abandoned logins
+ */
+const findExpiredSessionTokens = (db: BetterSqlite3.Database, now: Date): string[] =>
+  (
+    db
+      .prepare("SELECT session_token, expires_at FROM sessions WHERE expires_at IS NOT NULL")
+      .all() as Array<{ session_token: string; expires_at: string }>
+  )
+    .filter((row) => {
+      const expiresAt = new Date(row.expires_at).getTime();
+      return Number.isNaN(expiresAt) || expiresAt < now.getTime();
+    })
+    .map((row) => row.session_token);
+
+/**
+ * Scans for invariant violations and optionally repairs them
+ */
+export function checkDataIntegrity(
+  options: IntegrityCheckOptions = {},
+  now: Date = new Date()
+): IntegrityReport {
+  const db = getDb();
+  const repair = options.repair ?? false;
+  const timer = dbLogger.startTimer("check-data-integrity");
+
+  const run = (): IntegrityIssue[] => {
+    const issues: IntegrityIssue[] = [];
+    for (const check of ENTRY_CHECKS) {
+      if (check.kind === "stale-in-progress" && options.submissionInProgress) {
+        continue;
+      }
+      const ids = findEntryIds(db, check.where);
+      if (ids.length === 0) continue;
+      const repaired = repair && check.repair ? check.repair(db, ids) : 0;
+      issues.push({
+        kind: check.kind,
+        description: check.description,
+        count: ids.length,
+        ids: ids.slice(0, IDS_PER_ISSUE),
+        repairable: check.repair !== undefined,
+        repaired,
+      });
+    }
+
+    const expiredTokens = findExpiredSessionTokens(db, now);
+    if (expiredTokens.length > 0) {
+      let repaired = 0;
+      if (repair) {
+        const remove = db.prepare("DELETE FROM sessions WHERE session_token = ?");
+        for (const token of expiredTokens) {
+          repaired += remove.run(token).changes;
+        }
+      }
+      issues.push({
+        kind: "expired-session",
+        description: "Sessions that have expired or have an unreadable expiry",
+        count: expiredTokens.length,
+        ids: [],
+        repairable: true,
+        repaired,
+      });
+    }
+    return issues;
+  };
+
+  const issues = repair ? db.transaction(run)() : run();
+  const report: IntegrityReport = {
+    checkedAt: now.toISOString(),
+    repairRequested: repair,
+    issues,
+    totalIssues: issues.reduce((sum, issue) => sum + issue.count, 0),
+    totalRepaired: issues.reduce((sum, issue) => sum + issue.repaired, 0),
+  };
+
+  const summary = Object.fromEntries(issues.map((issue) => [issue.kind, issue.count]));
+  if (repair && report.totalRepaired > 0) {
+    dbLogger.audit("repair-integrity", "Data integrity issues repaired", {
+      ...summary,
+      totalRepaired: report.totalRepaired,
+    });
+  } else if (report.totalIssues > 0) {
+    dbLogger.warn("Data integrity issues found", summary);
+  }
+  timer.done({ totalIssues: report.totalIssues, totalRepaired: report.totalRepaired });
+  return report;
+}
@@ -0,0 +1,195 @@
+/**
+ * @fileoverview Log Index Repository
+ *
+ * Stores parsed log lines (level, timestamp, run ID, message) so log queries
+ * can filter by date, level and run without re-parsing every log file.
+ * The index is optional and filled in the background by the log indexer.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+
+export interface IndexedLogLine {
+  lineNumber: number;
+  timestamp: string;
+  level: string;
+  runId: string | null;
+  component: string | null;
+  message: string;
+}
+
+export interface LogIndexCursor {
+  fileName: string;
+  byteOffset: number;
+  lineCount: number;
+}
+
+export interface LogQueryFilter {
+  /** Inclusive lower bound (ISO 8601) */
+  from?: string | undefined;
+  /** Inclusive upper bound (ISO 8601) */
+  to?: string | undefined;
+  levels?: string[] | undefined;
+  runId?: string | undefined;
+  /** Case-insensitive substring match on the message */
+  search?: string | undefined;
+  limit?: number | undefined;
+}
+
+export interface LogQueryResultLine extends IndexedLogLine {
+  fileName: string;
+}
+
+interface LogIndexRow {
+  file_name: string;
+  line_number: number;
+  timestamp: string;
+  level: string;
+  run_id: string | null;
+  component: string | null;
+  message: string;
+}
+
+const DEFAULT_QUERY_LIMIT = 500;
+
+const toResultLine = (row: LogIndexRow): LogQueryResultLine => ({
+  fileName: row.file_name,
+  lineNumber: row.line_number,
+  timestamp: row.timestamp,
+  level: row.level,
+  runId: row.run_id,
+  component: row.component,
+  message: row.message,
+});
+
+/**
+ * Gets how far a log file has been indexed (offset 0 if never indexed)
+ */
+export function getLogIndexCursor(fileName: string): LogIndexCursor {
+  const db = getDb();
+  const row = db
+    .prepare(
+      `SELECT byte_offset, line_count FROM log_index_files WHERE file_name = ?`
+    )
+    .get(fileName) as { byte_offset: number; line_count: number } | undefined;
+  return {
+    fileName,
+    byteOffset: row?.byte_offset ?? 0,
+    lineCount: row?.line_count ?? 0,
+  };
+}
+
+/**
+ * Appends parsed lines for a file and advances its cursor in one transaction
+ */
+export function appendLogIndexLines(
+  cursor: LogIndexCursor,
+  lines: IndexedLogLine[]
+): void {
+  const db = getDb();
+  const insertLine = db.prepare(`
+        INSERT INTO log_index (file_name, line_number, timestamp, level, run_id, component, message)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+    `);
+  const saveCursor = db.prepare(`
+        INSERT INTO log_index_files (file_name, byte_offset, line_count, indexed_at)
+        VALUES (?, ?, ?, datetime('now'))
+        ON CONFLICT(file_name) DO UPDATE SET
+            byte_offset = excluded.byte_offset,
+            line_count = excluded.line_count,
+            indexed_at = excluded.indexed_at
+    `);
+
+  db.transaction(() => {
+    for (const line of lines) {
+      insertLine.run(
+        cursor.fileName,
+        line.lineNumber,
+        line.timestamp,
+        line.level,
+        line.runId,
+        line.component,
+        line.message
+      );
+    }
+    saveCursor.run(cursor.fileName, cursor.byteOffset, cursor.lineCount);
+  })();
+}
+
+/**
+ * Drops indexed lines and cursors for log files that no longer exist
+ * (rotated or cleaned up), or for a single file that must be re-indexed
+ */
+export function removeLogIndexFiles(fileNames: string[]): number {
+  if (fileNames.length === 0) return 0;
+  const db = getDb();
+  const deleteLines = db.prepare(`DELETE FROM log_index WHERE file_name = ?`);
+  const deleteCursor = db.prepare(
+    `DELETE FROM log_index_files WHERE file_name = ?`
+  );
+  let removed = 0;
+  db.transaction(() => {
+    for (const fileName of fileNames) {
+      removed += deleteLines.run(fileName).changes;
+      deleteCursor.run(fileName);
+    }
+  })();
+  dbLogger.verbose("Log index files removed", { fileNames, removed });
+  return removed;
+}
+
+/**
+ * Lists every file the index has a cursor for
+ */
+export function listIndexedLogFiles(): string[] {
+  const db = getDb();
+  const rows = db
+    .prepare(`SELECT file_name FROM log_index_files ORDER BY file_name`)
+    .all() as Array<{ file_name: string }>;
+  return rows.map((row) => row.file_name);
+}
+
+/**
+ * Queries indexed log lines, newest first
+ */
+export function queryLogIndex(filter: LogQueryFilter): LogQueryResultLine[] {
+  const db = getDb();
+  const clauses: string[] = [];
+  const params: Array<string | number> = [];
+
+  if (filter.from) {
+    clauses.push("timestamp >= ?");
+    params.push(filter.from);
+  }
+  if (filter.to) {
+    clauses.push("timestamp <= ?");
+    params.push(filter.to);
+  }
+  if (filter.levels && filter.levels.length > 0) {
+    clauses.push(`level IN (${filter.levels.map(() => "?").join(", ")})`);
+    params.push(...filter.levels);
+  }
+  if (filter.runId) {
+    clauses.push("run_id = ?");
+    params.push(filter.runId);
+  }
+  if (filter.search) {
+    clauses.push("message LIKE ? ESCAPE '\\'");
+    params.push(`%${filter.search.replace(/[\\%_]/g, (ch) => `\\${ch}`)}%`);
+  }
+
+  const where = clauses.length > 0 ? `WHERE ${clauses.join(" AND ")}` : "";
+  const rows = db
+    .prepare(
+      `SELECT file_name, line_number, timestamp, level, run_id, component, message
+       FROM log_index ${where}
+       ORDER BY timestamp DESC, id DESC
+       LIMIT ?`
+    )
+    .all(...params, filter.limit ?? DEFAULT_QUERY_LIMIT) as LogIndexRow[];
+  return rows.map(toResultLine);
+}
@@ -10,7 +10,8 @@
 
 import type BetterSqlite3 from "better-sqlite3";
 import { dbLogger } from "@sheetpilot/shared/logger";
-import { ensureSchemaInternal } from "./connection-manager";
+import { ensureSchemaInternal, getDbPath } from "./connection-manager";
+import { getDuplicateCount, logDuplicateWarning } from "./connection-manager.schema";
 import {
   isHoursColumnGenerated,
   createTimesheetTableWithSchema,
@@ -18,6 +19,13 @@ import {
   replaceTimesheetTableAndIndexes,
   createBusinessConfigTables,
   seedBusinessConfigFromStatic,
+  createRunHistoryTables,
+  createValidationRulesTable,
+  createLogIndexTables,
+  createRecodeHistoryTable,
+  createDeletedEntriesTable,
+  createDropdownResolutionsTable,
+  addColumnIfMissing,
 } from "./migrations.helpers";
 
 /**
@@ -185,4 +193,262 @@ export const migrations: Migration[] = [
       dbLogger.info("Migration 4: Business configuration migration completed");
     },
   },
+  {
+    version: 5,
+    description: "Create run history and scheduled export tables",
+    up: (db: BetterSqlite3.Database) => {
+      dbLogger.info("Migration 5: Creating run history and scheduled export tables");
+      createRunHistoryTables(db);
+      dbLogger.info("Migration 5: Run history tables created");
+    },
+  },
+  {
+    version: 6,
+    description: "Add cloud upload flag to scheduled exports",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(
+        db,
+        "scheduled_exports",
+        "upload_to_cloud",
+        "BOOLEAN NOT NULL DEFAULT 0"
+      );
+      dbLogger.info("Migration 6: Cloud upload flag migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 7,
+    description: "Link run history rows to background jobs",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "run_history", "job_id", "TEXT");
+      db.exec(
+        "CREATE INDEX IF NOT EXISTS idx_run_history_job_id ON run_history(job_id)"
+      );
+      dbLogger.info("Migration 7: Run history job link migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 8,
+    description: "Add submission idempotency keys to timesheet entries",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "submission_key", "TEXT");
+      db.exec(`
+        CREATE UNIQUE INDEX IF NOT EXISTS uq_timesheet_submission_key
+        ON timesheet(submission_key) WHERE submission_key IS NOT NULL
+      `);
+      dbLogger.info("Migration 8: Submission key migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 9,
+    description: "Create user-defined validation rules table",
+    up: (db: BetterSqlite3.Database) => {
+      dbLogger.info("Migration 9: Creating validation rules table");
+      createValidationRulesTable(db);
+      dbLogger.info("Migration 9: Validation rules table created");
+    },
+  },
+  {
+    version: 10,
+    description: "Link amendment drafts to the submitted entries they correct",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(
+        db,
+        "timesheet",
+        "amends_id",
+        "INTEGER REFERENCES timesheet(id) ON DELETE SET NULL"
+      );
+      // An amendment repeats its original's natural key, so amendments are
+      // left out of the duplicate check. Existing duplicates are tolerated
+      // as in ensureSchemaInternal: the index is left alone and the
+      // application layer keeps checking uniqueness.
+      const duplicateCount = getDuplicateCount(db);
+      if (duplicateCount > 0) {
+        logDuplicateWarning(duplicateCount, getDbPath());
+      } else {
+        db.exec(`
+          DROP INDEX IF EXISTS uq_timesheet_nk;
+          CREATE UNIQUE INDEX uq_timesheet_nk
+            ON timesheet(date, project, task_description)
+            WHERE date IS NOT NULL
+              AND project IS NOT NULL
+              AND task_description IS NOT NULL
+              AND amends_id IS NULL;
+        `);
+      }
+      db.exec(`CREATE INDEX IF NOT EXISTS idx_timesheet_amends_id ON timesheet(amends_id);`);
+      dbLogger.info("Migration 10: Amendment link migration completed", {
+        added,
+        naturalKeyIndexReplaced: duplicateCount === 0,
+      });
+    },
+  },
+  {
+    version: 11,
+    description: "Drop plaintext session tokens (sessions now store token hashes)",
+    up: (db: BetterSqlite3.Database) => {
+      // Existing rows hold raw tokens that can never match a hash; removing
+      // them signs everyone out once and leaves no usable secrets at rest
+      const removed = db.prepare(`DELETE FROM sessions`).run().changes;
+      dbLogger.info("Migration 11: Plaintext sessions removed", { removed });
+    },
+  },
+  {
+    version: 12,
+    description: "Create log search index tables",
+    up: (db: BetterSqlite3.Database) => {
+      createLogIndexTables(db);
+      dbLogger.info("Migration 12: Log index tables created");
+    },
+  },
+  {
+    version: 13,
+    description: "Track entry created/edited times and the submitting run",
+    up: (db: BetterSqlite3.Database) => {
+      // ADD COLUMN cannot default to CURRENT_TIMESTAMP, so triggers stamp the
+      // times; existing rows keep NULL since their history is unknown
+      const added = [
+        addColumnIfMissing(db, "timesheet", "created_at", "DATETIME"),
+        addColumnIfMissing(db, "timesheet", "updated_at", "DATETIME"),
+        addColumnIfMissing(
+          db,
+          "timesheet",
+          "submitted_run_id",
+          "INTEGER REFERENCES run_history(id) ON DELETE SET NULL"
+        ),
+      ].filter(Boolean).length;
+      db.exec(`
+        CREATE TRIGGER IF NOT EXISTS trg_timesheet_created_at
+        AFTER INSERT ON timesheet
+        WHEN NEW.created_at IS NULL
+        BEGIN
+          UPDATE timesheet
+          SET created_at = datetime('now'), updated_at = datetime('now')
+          WHERE id = NEW.id;
+        END;
+
+        -- Only edits to the entry itself count; status changes do not
+        CREATE TRIGGER IF NOT EXISTS trg_timesheet_updated_at
+        AFTER UPDATE OF date, hours, project, tool, detail_charge_code, task_description
+        ON timesheet
+        BEGIN
+          UPDATE timesheet SET updated_at = datetime('now') WHERE id = NEW.id;
+        END;
+      `);
+      dbLogger.info("Migration 13: Entry audit columns migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 14,
+    description: "Add failure_reason for entries the form rejected",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "failure_reason", "TEXT");
+      // A rejection describes the entry as it was sent; editing it clears the reason
+      db.exec(`
+        CREATE TRIGGER IF NOT EXISTS trg_timesheet_clear_failure_reason
+        AFTER UPDATE OF date, hours, project, tool, detail_charge_code, task_description
+        ON timesheet
+        WHEN NEW.failure_reason IS NOT NULL
+        BEGIN
+          UPDATE timesheet SET failure_reason = NULL WHERE id = NEW.id;
+        END;
+      `);
+      dbLogger.info("Migration 14: Failure reason column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 15,
+    description: "Add submission_captures for values matched by submit postconditions",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "submission_captures", "TEXT");
+      dbLogger.info("Migration 15: Submission captures column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 16,
+    description: "Add project retirement date and successor to the project catalog",
+    up: (db: BetterSqlite3.Database) => {
+      const retiredOnAdded = addColumnIfMissing(db, "business_config_projects", "retired_on", "TEXT");
+      const successorAdded = addColumnIfMissing(db, "business_config_projects", "successor_project", "TEXT");
+      dbLogger.info("Migration 16: Project retirement columns migration completed", {
+        retiredOnAdded,
+        successorAdded,
+      });
+    },
+  },
+  {
+    version: 17,
+    description: "Create recode history for admin bulk recodes",
+    up: (db: BetterSqlite3.Database) => {
+      createRecodeHistoryTable(db);
+      dbLogger.info("Migration 17: Recode history table created");
+    },
+  },
+  {
+    version: 18,
+    description: "Add time_zone for entries worked away from the home time zone",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "time_zone", "TEXT");
+      dbLogger.info("Migration 18: Entry time zone column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 19,
+    description: "Add external_ref for work order numbers and other cross-references",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "external_ref", "TEXT");
+      dbLogger.info("Migration 19: External reference column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 20,
+    description: "Create deleted entries for admin removal of submitted entries",
+    up: (db: BetterSqlite3.Database) => {
+      createDeletedEntriesTable(db);
+      dbLogger.info("Migration 20: Deleted entries table created");
+    },
+  },
+  {
+    version: 21,
+    description: "Add category for grouping entries by kind of work",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "category", "TEXT");
+      dbLogger.info("Migration 21: Entry category column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 22,
+    description: "Add entry kind for PTO, holiday and training entries",
+    up: (db: BetterSqlite3.Database) => {
+      const added = addColumnIfMissing(db, "timesheet", "entry_kind", "TEXT");
+      dbLogger.info("Migration 22: Entry kind column migration completed", {
+        added,
+      });
+    },
+  },
+  {
+    version: 23,
+    description: "Create dropdown resolutions for options confirmed on ambiguous matches",
+    up: (db: BetterSqlite3.Database) => {
+      createDropdownResolutionsTable(db);
+      dbLogger.info("Migration 23: Dropdown resolutions table created");
+    },
+  },
 ];
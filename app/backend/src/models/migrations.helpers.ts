@@ -292,3 +292,170 @@ export function seedBusinessConfigFromStatic(db: BetterSqlite3.Database): void {
 
   dbLogger.info("Migration 4: Business configuration seeding completed");
 }
+
+export function createRunHistoryTables(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- Run history for background and scheduled work (exports, backups, jobs)
+    CREATE TABLE IF NOT EXISTS run_history(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      job_type TEXT NOT NULL,                 -- e.g. 'scheduled-export'
+      trigger TEXT NOT NULL DEFAULT 'manual', -- 'manual' | 'scheduled'
+      status TEXT NOT NULL DEFAULT 'running', -- 'running' | 'success' | 'failure' | 'cancelled'
+      detail TEXT,                            -- JSON payload describing the run
+      error TEXT,
+      started_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+      finished_at DATETIME
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_run_history_job_type ON run_history(job_type);
+    CREATE INDEX IF NOT EXISTS idx_run_history_started_at ON run_history(started_at);
+
+    -- Scheduled export definitions
+    CREATE TABLE IF NOT EXISTS scheduled_exports(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      name TEXT NOT NULL,
+      format TEXT NOT NULL DEFAULT 'csv',     -- 'csv' | 'xlsx'
+      target_dir TEXT NOT NULL,
+      day_of_month INTEGER NOT NULL DEFAULT 1 CHECK(day_of_month >= 1 AND day_of_month <= 28),
+      is_active BOOLEAN NOT NULL DEFAULT 1,
+      last_run_at DATETIME,
+      next_run_at DATETIME,
+      created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+      updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+  `);
+}
+
+/**
+ * Creates the user-defined validation rules table
+ */
+export function createValidationRulesTable(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- Team-defined checks evaluated on draft save and before submission
+    CREATE TABLE IF NOT EXISTS validation_rules(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      name TEXT NOT NULL,
+      field TEXT NOT NULL,                    -- entry field the rule checks
+      operator TEXT NOT NULL,                 -- 'required' | 'equals' | 'startsWith' | ...
+      value TEXT,
+      when_field TEXT,                        -- optional precondition
+      when_operator TEXT,
+      when_value TEXT,
+      action TEXT NOT NULL DEFAULT 'warn' CHECK(action IN ('warn', 'block')),
+      message TEXT NOT NULL,
+      is_active BOOLEAN NOT NULL DEFAULT 1,
+      created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+      updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+  `);
+}
+
+/**
+ * Creates the optional log search index tables
+ */
+export function createLogIndexTables(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- One row per indexed log line, filled in the background from sheetpilot_*.log
+    CREATE TABLE IF NOT EXISTS log_index(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      file_name TEXT NOT NULL,
+      line_number INTEGER NOT NULL,
+      timestamp TEXT NOT NULL,                -- ISO 8601 from the log entry
+      level TEXT NOT NULL,
+      run_id TEXT,                            -- runId/jobId from the entry context, if any
+      component TEXT,
+      message TEXT NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_log_index_timestamp ON log_index(timestamp);
+    CREATE INDEX IF NOT EXISTS idx_log_index_level_timestamp ON log_index(level, timestamp);
+    CREATE INDEX IF NOT EXISTS idx_log_index_run_id ON log_index(run_id);
+
+    -- How far each log file has been indexed, so indexing resumes instead of re-reading
+    CREATE TABLE IF NOT EXISTS log_index_files(
+      file_name TEXT PRIMARY KEY,
+      byte_offset INTEGER NOT NULL DEFAULT 0,
+      line_count INTEGER NOT NULL DEFAULT 0,
+      indexed_at DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+  `);
+}
+
+/**
+ * Creates the audit table for admin bulk recodes of submitted entries
+ */
+export function createRecodeHistoryTable(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- One row per bulk recode; submitted rows are otherwise never changed locally
+    CREATE TABLE IF NOT EXISTS recode_history(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      field TEXT NOT NULL,                    -- 'project' | 'chargeCode'
+      from_value TEXT NOT NULL,
+      to_value TEXT NOT NULL,
+      filter TEXT,                            -- JSON filter the admin applied
+      entry_ids TEXT NOT NULL,                -- JSON array of recoded entry IDs
+      performed_by TEXT,
+      performed_at DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_recode_history_performed_at ON recode_history(performed_at);
+  `);
+}
+
+/**
+ * Creates the table submitted entries are moved to when an admin deletes them
+ */
+export function createDeletedEntriesTable(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- Submitted rows removed by an admin, kept with the reason instead of being dropped
+    CREATE TABLE IF NOT EXISTS deleted_entries(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      entry_id INTEGER NOT NULL,              -- timesheet.id the row had
+      entry TEXT NOT NULL,                    -- JSON copy of the timesheet row
+      reason TEXT NOT NULL,
+      deleted_by TEXT,
+      deleted_at DATETIME DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_deleted_entries_deleted_at ON deleted_entries(deleted_at);
+  `);
+}
+
+/**
+ * Creates the table of dropdown options confirmed for ambiguous typed values
+ */
+export function createDropdownResolutionsTable(db: BetterSqlite3.Database): void {
+  db.exec(`
+    -- Option the bot should pick when a typed value matches several dropdown options
+    CREATE TABLE IF NOT EXISTS dropdown_resolutions(
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      field_key TEXT NOT NULL,                -- FIELD_DEFINITIONS key, e.g. project_code
+      typed_value TEXT NOT NULL,
+      option_text TEXT NOT NULL,              -- option confirmed by a submitted row
+      candidates TEXT NOT NULL,               -- JSON array of options the value matched
+      confirmed_count INTEGER NOT NULL DEFAULT 1,
+      first_confirmed_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+      last_confirmed_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+      UNIQUE(field_key, typed_value)
+    );
+  `);
+}
+
+/**
+ * Adds a column to a table unless it already exists (ALTER TABLE has no IF NOT EXISTS)
+ */
+export function addColumnIfMissing(
+  db: BetterSqlite3.Database,
+  table: string,
+  column: string,
+  definition: string
+): boolean {
+  const columns = db.prepare(`PRAGMA table_info(${table})`).all() as Array<{
+    name: string;
+  }>;
+  if (columns.some((col) => col.name === column)) {
+    return false;
+  }
+  db.exec(`ALTER TABLE ${table} ADD COLUMN ${column} ${definition}`);
+  return true;
+}
@@ -4,7 +4,7 @@ import * as path from "path";
 import { dbLogger } from "@sheetpilot/shared/logger";
 import { migrations } from "./migrations.definitions";
 
-export const CURRENT_SCHEMA_VERSION = 4;
+export const CURRENT_SCHEMA_VERSION = 23;
 
 export function getCurrentSchemaVersion(db: BetterSqlite3.Database): number {
   try {
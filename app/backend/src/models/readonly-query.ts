@@ -0,0 +1,239 @@
+/**
+ * @fileoverview Read-only Query Runner
+ *
+ * Executes ad-hoc SELECT statements for admin reporting on a separate
+ * read-only connection, so a typo can never modify the live database.
+ * Secret tables are refused twice: by a text check on the statement, then
+ * by the tables the prepared statement actually opens.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import Database from "better-sqlite3";
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDbPath } from "./connection-manager";
+
+export type ReadonlyQueryParams =
+  | Array<string | number | null>
+  | Record<string, string | number | null>;
+
+export interface ReadonlyQueryOptions {
+  /** Maximum wall time spent reading rows (default 5000ms) */
+  timeoutMs?: number;
+  /** Maximum rows returned; further rows are dropped (default 1000) */
+  maxRows?: number;
+}
+
+export interface ReadonlyQueryResult {
+  columns: string[];
+  rows: unknown[][];
+  rowCount: number;
+  truncated: boolean;
+  elapsedMs: number;
+}
+
+const DEFAULT_TIMEOUT_MS = 5000;
+const DEFAULT_MAX_ROWS = 1000;
+
+/** Tables holding secrets; never readable through ad-hoc queries */
+const PROTECTED_TABLES = ["credentials", "sessions"];
+
+/** Keywords that have no place in a report query even if SQLite would treat them as reads */
+const FORBIDDEN_KEYWORDS = ["attach", "detach", "pragma", "load_extension", "vacuum"];
+
+/**
+ * Removes comments and string literals so keyword checks only see SQL
+ * structure. One left-to-right pass, so a "--" or "/*" inside a literal is
+ * never read as a comment (and a quote inside a comment never opens one).
+ * Quoted identifiers ("x", `x`, [x]) are kept, lowercased, as "x".
+ */
+const stripLiteralsAndComments = (sql: string): string => {
+  let out = "";
+  let i = 0;
+  while (i < sql.length) {
+    const ch = sql[i]!;
+    const next = sql[i + 1];
+    if (ch === "-" && next === "-") {
+      const end = sql.indexOf("\n", i);
+      i = end === -1 ? sql.length : end;
+      out += " ";
+    } else if (ch === "/" && next === "*") {
+      const end = sql.indexOf("*/", i + 2);
+      i = end === -1 ? sql.length : end + 2;
+      out += " ";
+    } else if (ch === "'") {
+      // '' inside a literal is an escaped quote
+      let j = i + 1;
+      while (j < sql.length && !(sql[j] === "'" && sql[j + 1] !== "'")) {
+        j += sql[j] === "'" ? 2 : 1;
+      }
+      i = j + 1;
+      out += "''";
+    } else if (ch === '"' || ch === "`" || ch === "[") {
+      const close = ch === "[" ? "]" : ch;
+      let j = i + 1;
+      let name = "";
+      while (j < sql.length) {
+        if (sql[j] === close) {
+          if (close !== "]" && sql[j + 1] === close) {
+            name += close;
+            j += 2;
+            continue;
+          }
+          break;
+        }
+        name += sql[j];
+        j++;
+      }
+      i = j + 1;
+      out += `"${name.toLowerCase()}"`;
+    } else {
+      out += ch;
+      i++;
+    }
+  }
+  return out;
+};
+
+/**
+ * Protected table a prepared query would read, found from its bytecode:
+ * every table or index cursor is opened by root page, so reads through
+ * views, subqueries or odd quoting are caught as well
+ */
+const findProtectedTableRead = (
+  db: Database.Database,
+  sql: string,
+  params: ReadonlyQueryParams
+): string | null => {
+  const placeholders = PROTECTED_TABLES.map(() => "?").join(", ");
+  const protectedPages = new Map(
+    (
+      db
+        .prepare(
+          `SELECT rootpage, tbl_name FROM sqlite_schema WHERE tbl_name IN (${placeholders}) AND rootpage > 0`
+        )
+        .all(...PROTECTED_TABLES) as Array<{ rootpage: number; tbl_name: string }>
+    ).map((row) => [row.rootpage, row.tbl_name])
+  );
+  if (protectedPages.size === 0) {
+    return null;
+  }
+  const explain = db.prepare(`EXPLAIN ${sql}`);
+  const program = (
+    Array.isArray(params) ? explain.all(...params) : explain.all(params)
+  ) as Array<{ opcode: string; p2: number; p3: number }>;
+  for (const op of program) {
+    if ((op.opcode === "OpenRead" || op.opcode === "ReopenIdx") && op.p3 === 0) {
+      const table = protectedPages.get(op.p2);
+      if (table) return table;
+    }
+  }
+  return null;
+};
+
+/**
+ * Checks a query against the statement whitelist
+ *
+ * @returns Error message, or null if the query is allowed
+ */
+export function checkReadonlySql(sql: string): string | null {
+  const structural = stripLiteralsAndComments(sql).trim().replace(/;\s*$/, "");
+  if (structural.length === 0) {
+    return "Query is empty";
+  }
+  if (structural.includes(";")) {
+    return "Only a single statement is allowed";
+  }
+  const lowered = structural.toLowerCase();
+  const firstKeyword = lowered.match(/^[a-z]+/)?.[0];
+  if (firstKeyword !== "select" && firstKeyword !== "with") {
+    return "Only SELECT (or WITH ... SELECT) statements are allowed";
+  }
+  for (const keyword of FORBIDDEN_KEYWORDS) {
+    if (new RegExp(`\\b${keyword}\\b`).test(lowered)) {
+      return `"${keyword.toUpperCase()}" is not allowed in report queries`;
+    }
+  }
+  for (const table of PROTECTED_TABLES) {
+    if (new RegExp(`(^|[^a-z0-9_])"?${table}"?([^a-z0-9_]|$)`).test(lowered)) {
+      return `The ${table} table cannot be queried`;
+    }
+  }
+  return null;
+}
+
+/**
+ * Runs a whitelisted SELECT on a read-only connection
+ *
+ * The deadline is checked between rows; better-sqlite3 cannot interrupt a
+ * single step, so the busy timeout also bounds time spent waiting on locks.
+ *
+ * @throws Error if the query is rejected, fails, or exceeds the timeout
+ */
+export function runReadonlyQuery(
+  sql: string,
+  params: ReadonlyQueryParams = [],
+  options: ReadonlyQueryOptions = {}
+): ReadonlyQueryResult {
+  const rejection = checkReadonlySql(sql);
+  if (rejection) {
+    throw new Error(rejection);
+  }
+
+  const timeoutMs = options.timeoutMs ?? DEFAULT_TIMEOUT_MS;
+  const maxRows = options.maxRows ?? DEFAULT_MAX_ROWS;
+  const started = Date.now();
+  const db = new Database(getDbPath(), {
+    readonly: true,
+    fileMustExist: true,
+    timeout: timeoutMs,
+  });
+
+  try {
+    db.pragma("query_only = ON");
+    const stmt = db.prepare(sql);
+    if (!stmt.reader || !stmt.readonly) {
+      throw new Error("Only read-only queries that return rows are allowed");
+    }
+    // The text check above is a first line; this is the one that holds
+    const protectedTable = findProtectedTableRead(db, sql, params);
+    if (protectedTable) {
+      throw new Error(`The ${protectedTable} table cannot be queried`);
+    }
+    stmt.raw(true);
+
+    const rows: unknown[][] = [];
+    let truncated = false;
+    const iterator = Array.isArray(params)
+      ? stmt.iterate(...params)
+      : stmt.iterate(params);
+    for (const row of iterator as Iterable<unknown[]>) {
+      if (Date.now() - started > timeoutMs) {
+        throw new Error(`Query exceeded the ${timeoutMs}ms time limit`);
+      }
+      if (rows.length >= maxRows) {
+        truncated = true;
+        break;
+      }
+      rows.push(row);
+    }
+
+    const result: ReadonlyQueryResult = {
+      columns: stmt.columns().map((column) => column.name),
+      rows,
+      rowCount: rows.length,
+      truncated,
+      elapsedMs: Date.now() - started,
+    };
+    dbLogger.verbose("Read-only query completed", {
+      rowCount: result.rowCount,
+      truncated,
+      elapsedMs: result.elapsedMs,
+    });
+    return result;
+  } finally {
+    db.close();
+  }
+}
@@ -0,0 +1,141 @@
+/**
+ * @fileoverview Run History Repository
+ *
+ * Records the outcome of background and scheduled work (exports, backups, jobs)
+ * so failures are visible after the fact.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+
+export type RunTrigger = "manual" | "scheduled";
+export type RunStatus = "running" | "success" | "failure" | "cancelled";
+
+export interface RunHistoryRow {
+  id: number;
+  job_type: string;
+  trigger: RunTrigger;
+  status: RunStatus;
+  detail: string | null;
+  error: string | null;
+  started_at: string;
+  finished_at: string | null;
+  job_id: string | null;
+}
+
+export interface RunHistoryRecord {
+  id: number;
+  jobType: string;
+  trigger: RunTrigger;
+  status: RunStatus;
+  detail: Record<string, unknown> | null;
+  error: string | null;
+  startedAt: string;
+  finishedAt: string | null;
+  /** Background job that produced this run, if any */
+  jobId: string | null;
+}
+
+const parseDetail = (detail: string | null): Record<string, unknown> | null => {
+  if (!detail) return null;
+  try {
+    return JSON.parse(detail) as Record<string, unknown>;
+  } catch {
+    return { raw: detail };
+  }
+};
+
+const toRecord = (row: RunHistoryRow): RunHistoryRecord => ({
+  id: row.id,
+  jobType: row.job_type,
+  trigger: row.trigger,
+  status: row.status,
+  detail: parseDetail(row.detail),
+  error: row.error,
+  startedAt: row.started_at,
+  finishedAt: row.finished_at,
+  jobId: row.job_id ?? null,
+});
+
+/**
+ * Records the start of a run and returns its ID
+ */
+export function startRun(
+  jobType: string,
+  trigger: RunTrigger,
+  detail?: Record<string, unknown>,
+  jobId?: string
+): number {
+  const db = getDb();
+  const result = db
+    .prepare(
+      `
+        INSERT INTO run_history (job_type, trigger, status, detail, started_at, job_id)
+        VALUES (?, ?, 'running', ?, datetime('now'), ?)
+    `
+    )
+    .run(jobType, trigger, detail ? JSON.stringify(detail) : null, jobId ?? null);
+
+  const id = Number(result.lastInsertRowid);
+  dbLogger.verbose("Run started", { id, jobType, trigger, jobId });
+  return id;
+}
+
+/**
+ * Records the outcome of a run
+ */
+export function finishRun(
+  id: number,
+  status: Exclude<RunStatus, "running">,
+  detail?: Record<string, unknown>,
+  error?: string
+): void {
+  const db = getDb();
+  db.prepare(
+    `
+        UPDATE run_history
+        SET status = ?,
+            detail = COALESCE(?, detail),
+            error = ?,
+            finished_at = datetime('now')
+        WHERE id = ?
+    `
+  ).run(status, detail ? JSON.stringify(detail) : null, error ?? null, id);
+
+  dbLogger.audit("run-finished", "Run finished", { id, status, error });
+}
+
+/**
+ * Lists recent runs, newest first
+ */
+export function listRuns(
+  options: { jobType?: string; limit?: number } = {}
+): RunHistoryRecord[] {
+  const db = getDb();
+  const limit = options.limit ?? 50;
+  const rows = options.jobType
+    ? db
+        .prepare(
+          `SELECT * FROM run_history WHERE job_type = ? ORDER BY id DESC LIMIT ?`
+        )
+        .all(options.jobType, limit)
+    : db
+        .prepare(`SELECT * FROM run_history ORDER BY id DESC LIMIT ?`)
+        .all(limit);
+  return (rows as RunHistoryRow[]).map(toRecord);
+}
+
+/**
+ * Gets the run recorded for a background job
+ */
+export function getRunByJobId(jobId: string): RunHistoryRecord | null {
+  const db = getDb();
+  const row = db
+    .prepare(`SELECT * FROM run_history WHERE job_id = ? ORDER BY id DESC LIMIT 1`)
+    .get(jobId) as RunHistoryRow | undefined;
+  return row ? toRecord(row) : null;
+}
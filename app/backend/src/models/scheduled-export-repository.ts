@@ -0,0 +1,185 @@
+/**
+ * @fileoverview Scheduled Export Repository
+ *
+ * Persists scheduled export definitions (what to export, where, and when).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+
+export type ScheduledExportFormat = "csv" | "xlsx";
+
+export interface ScheduledExportRow {
+  id: number;
+  name: string;
+  format: ScheduledExportFormat;
+  target_dir: string;
+  day_of_month: number;
+  is_active: number;
+  upload_to_cloud: number;
+  last_run_at: string | null;
+  next_run_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface ScheduledExport {
+  id: number;
+  name: string;
+  format: ScheduledExportFormat;
+  targetDir: string;
+  dayOfMonth: number;
+  isActive: boolean;
+  uploadToCloud: boolean;
+  lastRunAt: string | null;
+  nextRunAt: string | null;
+}
+
+export interface ScheduledExportInput {
+  id?: number | undefined;
+  name: string;
+  format: ScheduledExportFormat;
+  targetDir: string;
+  dayOfMonth: number;
+  isActive: boolean;
+  uploadToCloud?: boolean | undefined;
+}
+
+const toScheduledExport = (row: ScheduledExportRow): ScheduledExport => ({
+  id: row.id,
+  name: row.name,
+  format: row.format,
+  targetDir: row.target_dir,
+  dayOfMonth: row.day_of_month,
+  isActive: row.is_active === 1,
+  uploadToCloud: row.upload_to_cloud === 1,
+  lastRunAt: row.last_run_at,
+  nextRunAt: row.next_run_at,
+});
+
+/**
+ * Lists all scheduled exports
+ */
+export function listScheduledExports(): ScheduledExport[] {
+  const db = getDb();
+  const rows = db
+    .prepare(`SELECT * FROM scheduled_exports ORDER BY id`)
+    .all() as ScheduledExportRow[];
+  return rows.map(toScheduledExport);
+}
+
+/**
+ * Gets a scheduled export by ID
+ */
+export function getScheduledExport(id: number): ScheduledExport | null {
+  const db = getDb();
+  const row = db
+    .prepare(`SELECT * FROM scheduled_exports WHERE id = ?`)
+    .get(id) as ScheduledExportRow | undefined;
+  return row ? toScheduledExport(row) : null;
+}
+
+/**
+ * Lists active scheduled exports whose next run time has passed
+ */
+export function getDueScheduledExports(nowIso: string): ScheduledExport[] {
+  const db = getDb();
+  const rows = db
+    .prepare(
+      `
+        SELECT * FROM scheduled_exports
+        WHERE is_active = 1
+          AND next_run_at IS NOT NULL
+          AND next_run_at <= ?
+        ORDER BY next_run_at
+    `
+    )
+    .all(nowIso) as ScheduledExportRow[];
+  return rows.map(toScheduledExport);
+}
+
+/**
+ * Creates or updates a scheduled export
+ *
+ * @returns ID of the saved schedule
+ */
+export function saveScheduledExport(
+  input: ScheduledExportInput,
+  nextRunAt: string
+): number {
+  const db = getDb();
+  if (input.id) {
+    db.prepare(
+      `
+        UPDATE scheduled_exports
+        SET name = ?, format = ?, target_dir = ?, day_of_month = ?,
+            is_active = ?, upload_to_cloud = ?, next_run_at = ?, updated_at = datetime('now')
+        WHERE id = ?
+    `
+    ).run(
+      input.name,
+      input.format,
+      input.targetDir,
+      input.dayOfMonth,
+      input.isActive ? 1 : 0,
+      input.uploadToCloud ? 1 : 0,
+      nextRunAt,
+      input.id
+    );
+    dbLogger.info("Scheduled export updated", { id: input.id });
+    return input.id;
+  }
+
+  const result = db
+    .prepare(
+      `
+        INSERT INTO scheduled_exports (name, format, target_dir, day_of_month, is_active, upload_to_cloud, next_run_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+    `
+    )
+    .run(
+      input.name,
+      input.format,
+      input.targetDir,
+      input.dayOfMonth,
+      input.isActive ? 1 : 0,
+      input.uploadToCloud ? 1 : 0,
+      nextRunAt
+    );
+  const id = Number(result.lastInsertRowid);
+  dbLogger.info("Scheduled export created", { id });
+  return id;
+}
+
+/**
+ * Deletes a scheduled export
+ */
+export function deleteScheduledExport(id: number): boolean {
+  const db = getDb();
+  const result = db
+    .prepare(`DELETE FROM scheduled_exports WHERE id = ?`)
+    .run(id);
+  return result.changes > 0;
+}
+
+/**
+ * Records that a scheduled export ran and when it should run next
+ */
+export function markScheduledExportRun(
+  id: number,
+  lastRunAt: string,
+  nextRunAt: string
+): void {
+  const db = getDb();
+  db.prepare(
+    `
+        UPDATE scheduled_exports
+        SET last_run_at = ?, next_run_at = ?, updated_at = datetime('now')
+        WHERE id = ?
+    `
+  ).run(lastRunAt, nextRunAt, id);
+}
@@ -0,0 +1,126 @@
+/**
+ * @fileoverview Schema Compatibility
+ *
+ * Guards against older app builds writing to a database a newer build has
+ * already migrated (for example when someone runs an old installer from the
+ * share drive). Migrations only move forward, so an older build cannot know
+ * what the newer tables mean; instead of corrupting them it opens the
+ * database for reading only. SQLite's query_only pragma rejects every write,
+ * and statements that would write fail at prepare time with
+ * DatabaseSchemaTooNewError so callers get a typed error instead of
+ * SQLITE_READONLY.
+ *
+ * The schema version is checked when a connection opens and again whenever
+ * compatibility info is requested, so a newer build migrating the database
+ * while this one is running is picked up too.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type BetterSqlite3 from "better-sqlite3";
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { DatabaseSchemaTooNewError } from "@sheetpilot/shared/errors";
+import { CURRENT_SCHEMA_VERSION, getCurrentSchemaVersion } from "./migrations";
+import { clearStatementCache } from "./statement-cache";
+
+export interface SchemaCompatibilityInfo {
+  dbPath: string;
+  /** Version recorded in the database */
+  schemaVersion: number;
+  /** Newest version this app build understands */
+  appSchemaVersion: number;
+  /** False when the database is newer than this build and writes are refused */
+  compatible: boolean;
+  /** Why writes are refused (only when not compatible) */
+  message?: string;
+}
+
+/** Connections switched to read-only by guardNewerSchema */
+const readOnlyConnections = new WeakSet<BetterSqlite3.Database>();
+
+const createTooNewError = (schemaVersion: number): DatabaseSchemaTooNewError =>
+  new DatabaseSchemaTooNewError({
+    schemaVersion,
+    appSchemaVersion: CURRENT_SCHEMA_VERSION,
+  });
+
+/**
+ * Makes a connection refuse writes with DatabaseSchemaTooNewError
+ */
+function makeConnectionReadOnly(db: BetterSqlite3.Database, schemaVersion: number): void {
+  readOnlyConnections.add(db);
+  db.pragma("query_only = ON");
+  // Write statements cached before the check would only fail with SQLITE_READONLY
+  clearStatementCache(db);
+
+  const prepare = db.prepare.bind(db);
+  db.prepare = ((source: string) => {
+    const statement = prepare(source);
+    if (!statement.readonly) {
+      throw createTooNewError(schemaVersion);
+    }
+    return statement;
+  }) as typeof db.prepare;
+
+  const exec = db.exec.bind(db);
+  db.exec = ((source: string) => {
+    try {
+      return exec(source);
+    } catch (err: unknown) {
+      if ((err as { code?: string } | null)?.code === "SQLITE_READONLY") {
+        throw createTooNewError(schemaVersion);
+      }
+      throw err;
+    }
+  }) as typeof db.exec;
+}
+
+/**
+ * Switches the connection to read-only if its schema is newer than this
+ * build supports
+ *
+ * @returns Whether the connection is read-only
+ */
+export function guardNewerSchema(db: BetterSqlite3.Database, dbPath: string): boolean {
+  if (readOnlyConnections.has(db)) {
+    return true;
+  }
+  const schemaVersion = getCurrentSchemaVersion(db);
+  if (schemaVersion <= CURRENT_SCHEMA_VERSION) {
+    return false;
+  }
+  makeConnectionReadOnly(db, schemaVersion);
+  dbLogger.warn("Database schema is newer than this app version; writes are disabled", {
+    dbPath,
+    schemaVersion,
+    appSchemaVersion: CURRENT_SCHEMA_VERSION,
+  });
+  return true;
+}
+
+/**
+ * Whether the connection refuses writes because its schema is too new
+ */
+export function isDatabaseReadOnly(db: BetterSqlite3.Database): boolean {
+  return readOnlyConnections.has(db);
+}
+
+/**
+ * Describes whether this build can safely write to the database
+ */
+export function getSchemaCompatibilityInfo(
+  db: BetterSqlite3.Database,
+  dbPath: string
+): SchemaCompatibilityInfo {
+  const readOnly = guardNewerSchema(db, dbPath);
+  const schemaVersion = getCurrentSchemaVersion(db);
+  return {
+    dbPath,
+    schemaVersion,
+    appSchemaVersion: CURRENT_SCHEMA_VERSION,
+    compatible: !readOnly,
+    ...(readOnly ? { message: createTooNewError(schemaVersion).message } : {}),
+  };
+}
@@ -0,0 +1,121 @@
+/**
+ * @fileoverview Schema Information
+ *
+ * Describes the live database (tables, columns, indexes, row counts and
+ * schema version) for diagnostics and support.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { getDb, getDbPath } from "./connection-manager";
+import { CURRENT_SCHEMA_VERSION, getCurrentSchemaVersion } from "./migrations";
+import {
+  getSchemaCompatibilityInfo,
+  type SchemaCompatibilityInfo,
+} from "./schema-compatibility";
+
+export interface SchemaColumnInfo {
+  name: string;
+  type: string;
+  notNull: boolean;
+  defaultValue: string | null;
+  primaryKey: boolean;
+}
+
+export interface SchemaTableInfo {
+  name: string;
+  rowCount: number;
+  columns: SchemaColumnInfo[];
+  indexes: string[];
+}
+
+export interface SchemaInfo {
+  dbPath: string;
+  /** Version recorded in schema_info */
+  schemaVersion: number;
+  /** Version this build of the app migrates to */
+  expectedSchemaVersion: number;
+  /** Database was written by a newer app version */
+  isNewerThanApp: boolean;
+  sqliteVersion: string;
+  journalMode: string;
+  tables: SchemaTableInfo[];
+}
+
+/**
+ * Quotes an identifier taken from sqlite_master for use in PRAGMA/SELECT
+ */
+const quoteIdentifier = (name: string): string =>
+  `"${name.replace(/"/g, '""')}"`;
+
+/**
+ * Reads table/column metadata, row counts and schema version from the live database
+ */
+export function getSchemaInfo(): SchemaInfo {
+  const db = getDb();
+  const schemaVersion = getCurrentSchemaVersion(db);
+
+  const tableNames = db
+    .prepare(
+      `
+        SELECT name FROM sqlite_master
+        WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+        ORDER BY name
+    `
+    )
+    .all() as Array<{ name: string }>;
+
+  const tables = tableNames.map(({ name }): SchemaTableInfo => {
+    const quoted = quoteIdentifier(name);
+    const columns = db.prepare(`PRAGMA table_info(${quoted})`).all() as Array<{
+      name: string;
+      type: string;
+      notnull: number;
+      dflt_value: string | null;
+      pk: number;
+    }>;
+    const indexes = db.prepare(`PRAGMA index_list(${quoted})`).all() as Array<{
+      name: string;
+    }>;
+    const count = db
+      .prepare(`SELECT COUNT(*) AS count FROM ${quoted}`)
+      .get() as { count: number };
+
+    return {
+      name,
+      rowCount: count.count,
+      columns: columns.map((column) => ({
+        name: column.name,
+        type: column.type,
+        notNull: column.notnull === 1,
+        defaultValue: column.dflt_value,
+        primaryKey: column.pk > 0,
+      })),
+      indexes: indexes.map((index) => index.name),
+    };
+  });
+
+  const sqliteVersion = (
+    db.prepare("SELECT sqlite_version() AS version").get() as { version: string }
+  ).version;
+
+  return {
+    dbPath: getDbPath(),
+    schemaVersion,
+    expectedSchemaVersion: CURRENT_SCHEMA_VERSION,
+    isNewerThanApp: schemaVersion > CURRENT_SCHEMA_VERSION,
+    sqliteVersion,
+    journalMode: String(db.pragma("journal_mode", { simple: true })),
+    tables,
+  };
+}
+
+/**
+ * Whether this build can write to the live database (false when a newer app
+ * version has migrated it)
+ */
+export function getCompatibilityInfo(): SchemaCompatibilityInfo {
+  return getSchemaCompatibilityInfo(getDb(), getDbPath());
+}
@@ -3,15 +3,74 @@
  *
  * Handles all session management database operations.
  *
+ * Only a SHA-256 hash of each token is stored. The hash is salted with the
+ * app-instance identifier, so a token issued by one installation never
+ * validates against another installation's database.
+ *
  * @author Andrew Hughes
  * @version 1.0.0
  * @since 2025
  */
 
-import { randomUUID } from "crypto";
+import { createHash, randomUUID, timingSafeEqual } from "crypto";
 import { dbLogger } from "@sheetpilot/shared/logger";
 import { getDb } from "./connection-manager";
 
+/** How long a "stay logged in" session lasts */
+export const SESSION_LIFETIME_MS = 30 * 24 * 60 * 60 * 1000;
+
+// Replaced at startup with the persisted installation id; the per-process
+// fallback keeps sessions working (for one run) if that never happens
+let sessionInstanceId: string = randomUUID();
+
+/**
+ * Sets the app-instance identifier sessions are bound to
+ */
+export function setSessionInstanceId(instanceId: string): void {
+  sessionInstanceId = instanceId;
+}
+
+/**
+ * Hashes a session token for storage and lookup
+ */
+export function hashSessionToken(token: string): string {
+  return createHash("sha256")
+    .update(`${sessionInstanceId}:${token}`)
+    .digest("hex");
+}
+
+/**
+ * Constant-time comparison of two token hashes
+ */
+function hashesMatch(stored: string, candidate: string): boolean {
+  const a = Buffer.from(stored, "utf8");
+  const b = Buffer.from(candidate, "utf8");
+  return a.length === b.length && timingSafeEqual(a, b);
+}
+
+const insertSession = (
+  email: string,
+  stayLoggedIn: boolean,
+  isAdmin: boolean
+): string => {
+  const sessionToken = randomUUID();
+
+  const expiresAt = stayLoggedIn
+    ? new Date(Date.now() + SESSION_LIFETIME_MS).toISOString()
+    : null;
+
+  getDb()
+    .prepare(
+      `
+            INSERT INTO sessions (session_token, email, expires_at, is_admin)
+            VALUES (?, ?, ?, ?)
+        `
+    )
+    .run(hashSessionToken(sessionToken), email, expiresAt, isAdmin ? 1 : 0);
+
+  return sessionToken;
+};
+
 /**
  * Creates a new session for a user
  */
@@ -21,23 +80,11 @@ export function createSession(
   isAdmin: boolean = false
 ): string {
   const timer = dbLogger.startTimer("create-session");
-  const db = getDb();
 
   try {
-    const sessionToken = randomUUID();
-
-    const expiresAt = stayLoggedIn
-      ? new Date(Date.now() + 30 * 24 * 60 * 60 * 1000).toISOString()
-      : null;
-
     dbLogger.verbose("Creating session", { email, stayLoggedIn, isAdmin });
 
-    const insert = db.prepare(`
-            INSERT INTO sessions (session_token, email, expires_at, is_admin)
-            VALUES (?, ?, ?, ?)
-        `);
-
-    insert.run(sessionToken, email, expiresAt, isAdmin ? 1 : 0);
+    const sessionToken = insertSession(email, stayLoggedIn, isAdmin);
 
     dbLogger.info("Session created successfully", { email, isAdmin });
     timer.done({ sessionCreated: true });
@@ -50,6 +97,39 @@ export function createSession(
   }
 }
 
+/**
+ * Issues a fresh token on privilege elevation (admin login)
+ *
+ * Every existing session for the account is revoked in the same transaction,
+ * so a token captured before elevation can never carry admin rights.
+ */
+export function rotateSession(
+  email: string,
+  stayLoggedIn: boolean,
+  isAdmin: boolean
+): string {
+  const timer = dbLogger.startTimer("rotate-session");
+  const db = getDb();
+
+  try {
+    const rotate = db.transaction(() => {
+      const revoked = db
+        .prepare(`DELETE FROM sessions WHERE email = ?`)
+        .run(email).changes;
+      return { revoked, token: insertSession(email, stayLoggedIn, isAdmin) };
+    });
+    const { revoked, token } = rotate();
+
+    dbLogger.info("Session rotated", { email, isAdmin, revoked });
+    timer.done({ revoked });
+    return token;
+  } catch (error) {
+    dbLogger.error("Could not rotate session", error);
+    timer.done({ outcome: "error" });
+    throw error;
+  }
+}
+
 /**
  * Validates a session token
  */
@@ -63,20 +143,26 @@ export function validateSession(token: string): {
 
   try {
     dbLogger.verbose("Validating session", {
-      token: token.substring(0, 8) + "...",
+      tokenHash: hashSessionToken(token).substring(0, 8) + "...",
     });
 
+    const tokenHash = hashSessionToken(token);
     const getSession = db.prepare(`
-            SELECT email, expires_at, is_admin
+            SELECT session_token, email, expires_at, is_admin
             FROM sessions
             WHERE session_token = ?
         `);
 
-    const session = getSession.get(token) as
-      | { email: string; expires_at: string | null; is_admin: number }
+    const session = getSession.get(tokenHash) as
+      | {
+          session_token: string;
+          email: string;
+          expires_at: string | null;
+          is_admin: number;
+        }
       | undefined;
 
-    if (!session) {
+    if (!session || !hashesMatch(session.session_token, tokenHash)) {
       dbLogger.verbose("Session not found");
       timer.done({ valid: false });
       return { valid: false };
@@ -132,7 +218,7 @@ export function clearSession(token: string): void {
 
   try {
     dbLogger.verbose("Clearing session", {
-      token: token.substring(0, 8) + "...",
+      tokenHash: hashSessionToken(token).substring(0, 8) + "...",
     });
 
     const deleteSession = db.prepare(`
@@ -140,7 +226,7 @@ export function clearSession(token: string): void {
             WHERE session_token = ?
         `);
 
-    const result = deleteSession.run(token);
+    const result = deleteSession.run(hashSessionToken(token));
 
     if (result.changes > 0) {
       dbLogger.info("Session cleared successfully");
@@ -156,8 +242,10 @@ export function clearSession(token: string): void {
 
 /**
  * Clears all sessions for a specific user
+ *
+ * @returns Number of sessions cleared
  */
-export function clearUserSessions(email: string): void {
+export function clearUserSessions(email: string): number {
   const timer = dbLogger.startTimer("clear-user-sessions");
   const db = getDb();
 
@@ -173,56 +261,178 @@ export function clearUserSessions(email: string): void {
 
     dbLogger.info("User sessions cleared", { email, count: result.changes });
     timer.done({ changes: result.changes });
+    return result.changes;
   } catch (error) {
     dbLogger.error("Could not clear user sessions", error);
     timer.done({ outcome: "error" });
+    return 0;
   }
 }
 
 /**
- * Gets an active session for a user email
+ * Revokes every session (e.g. when the workstation locks)
+ *
+ * @returns Number of sessions revoked
+ */
+export function clearAllSessions(): number {
+  const timer = dbLogger.startTimer("clear-all-sessions");
+  const db = getDb();
+
+  try {
+    const result = db.prepare(`DELETE FROM sessions`).run();
+    dbLogger.info("All sessions cleared", { count: result.changes });
+    timer.done({ changes: result.changes });
+    return result.changes;
+  } catch (error) {
+    dbLogger.error("Could not clear all sessions", error);
+    timer.done({ outcome: "error" });
+    return 0;
+  }
+}
+
+/**
+ * Checks whether a user has an unexpired session
+ *
+ * Tokens are not recoverable from their stored hashes, so callers that need
+ * a token must create one.
  */
-export function getSessionByEmail(email: string): string | null {
-  const timer = dbLogger.startTimer("get-session-by-email");
+export function hasActiveSession(email: string): boolean {
+  const timer = dbLogger.startTimer("has-active-session");
   const db = getDb();
 
   try {
-    dbLogger.verbose("Getting session by email", { email });
+    dbLogger.verbose("Checking for active session", { email });
 
     const getSession = db.prepare(`
-            SELECT session_token, expires_at
+            SELECT 1
             FROM sessions
             WHERE email = ?
-            ORDER BY created_at DESC
+              AND (expires_at IS NULL OR expires_at > ?)
             LIMIT 1
         `);
 
-    const session = getSession.get(email) as
-      | { session_token: string; expires_at: string | null }
-      | undefined;
+    const found = getSession.get(email, new Date().toISOString()) !== undefined;
+    timer.done({ found });
+    return found;
+  } catch (error) {
+    dbLogger.error("Could not check for active session", error);
+    timer.done({ outcome: "error" });
+    return false;
+  }
+}
 
-    if (!session) {
-      timer.done({ found: false });
-      return null;
-    }
+type SessionRow = {
+  session_token: string;
+  email: string;
+  expires_at: string | null;
+  is_admin: number;
+  created_at: string;
+};
 
-    // Check if expired
-    if (session.expires_at) {
-      const expiresAt = new Date(session.expires_at);
-      const now = new Date();
+/**
+ * Whether a session can be purged: its expiry has passed or cannot be read,
+ * or it has no expiry (not "stay logged in") and is older than the longest
+ * session lifetime, so the app run it belonged to is long over
+ */
+const isSessionExpired = (row: SessionRow, now: Date): boolean => {
+  if (row.expires_at) {
+    const expiresAt = new Date(row.expires_at).getTime();
+    return Number.isNaN(expiresAt) || expiresAt < now.getTime();
+  }
+  // created_at is SQLite UTC ("YYYY-MM-DD HH:MM:SS")
+  const createdAt = new Date(`${row.created_at.replace(" ", "T")}Z`).getTime();
+  return !Number.isNaN(createdAt) && now.getTime() - createdAt > SESSION_LIFETIME_MS;
+};
+
+const listSessionRows = (): SessionRow[] =>
+  getDb()
+    .prepare(
+      `SELECT session_token, email, expires_at, is_admin, created_at FROM sessions ORDER BY created_at`
+    )
+    .all() as SessionRow[];
 
-      if (now > expiresAt) {
-        clearSession(session.session_token);
-        timer.done({ found: false, reason: "expired" });
-        return null;
-      }
-    }
+/**
+ * Deletes expired and abandoned sessions
+ *
+ * @returns Number of sessions deleted
+ */
+export function purgeExpiredSessions(now: Date = new Date()): number {
+  const timer = dbLogger.startTimer("purge-expired-sessions");
+  const db = getDb();
 
-    timer.done({ found: true });
-    return session.session_token;
+  try {
+    const purge = db.transaction(() => {
+      const remove = db.prepare(`DELETE FROM sessions WHERE session_token = ?`);
+      let removed = 0;
+      for (const row of listSessionRows()) {
+        if (isSessionExpired(row, now)) {
+          removed += remove.run(row.session_token).changes;
+        }
+      }
+      return removed;
+    });
+    const removed = purge();
+    dbLogger.info("Expired sessions purged", { count: removed });
+    timer.done({ changes: removed });
+    return removed;
   } catch (error) {
-    dbLogger.error("Could not get session by email", error);
+    dbLogger.error("Could not purge expired sessions", error);
     timer.done({ outcome: "error" });
-    return null;
+    throw error;
   }
 }
+
+export interface UserSessionStats {
+  email: string;
+  sessions: number;
+  active: number;
+  isAdmin: boolean;
+  /** created_at of the user's oldest active session */
+  oldestActiveCreatedAt: string | null;
+}
+
+export interface SessionStats {
+  total: number;
+  active: number;
+  /** Sessions `purgeExpiredSessions` would delete */
+  expired: number;
+  oldestActiveCreatedAt: string | null;
+  /** Users with the most sessions first */
+  users: UserSessionStats[];
+}
+
+/**
+ * Session counts per user, for admins cleaning up shared machines
+ */
+export function getSessionStats(now: Date = new Date()): SessionStats {
+  const rows = listSessionRows();
+  const users = new Map<string, UserSessionStats>();
+  const stats: SessionStats = { total: rows.length, active: 0, expired: 0, oldestActiveCreatedAt: null, users: [] };
+
+  // Rows are oldest first, so the first active one seen is the oldest
+  for (const row of rows) {
+    const user = users.get(row.email) ?? {
+      email: row.email,
+      sessions: 0,
+      active: 0,
+      isAdmin: false,
+      oldestActiveCreatedAt: null,
+    };
+    users.set(row.email, user);
+    user.sessions++;
+    user.isAdmin ||= row.is_admin === 1;
+    if (isSessionExpired(row, now)) {
+      stats.expired++;
+      continue;
+    }
+    user.active++;
+    stats.active++;
+    user.oldestActiveCreatedAt ??= row.created_at;
+    stats.oldestActiveCreatedAt ??= row.created_at;
+  }
+
+  stats.users = [...users.values()].sort(
+    (a, b) => b.sessions - a.sessions || a.email.localeCompare(b.email)
+  );
+  return stats;
+}
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview Prepared Statement Cache
+ *
+ * Reuses prepared statements for hot queries instead of re-preparing the
+ * same SQL on every call. Each cache belongs to one connection: it is
+ * attached when the connection manager opens a connection and dropped when
+ * the connection closes, so statements never outlive their database.
+ * Connections without an attached cache simply prepare every time.
+ *
+ * Callers must not change statement modes (raw, pluck, expand) on cached
+ * statements, since those settings would leak to other callers.
+ *
+ * Cached statements also report their run/get/all time to the connection
+ * statistics (see connection-stats.ts).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type BetterSqlite3 from "better-sqlite3";
+import { performance } from "perf_hooks";
+import { recordBusyTimeout, recordStatementDuration } from "./connection-stats";
+
+/** Most statements kept per connection; the least recently used is dropped first */
+export const STATEMENT_CACHE_LIMIT = 64;
+
+interface StatementCache {
+  statements: Map<string, BetterSqlite3.Statement>;
+  hits: number;
+  misses: number;
+}
+
+export interface StatementCacheStats {
+  size: number;
+  hits: number;
+  misses: number;
+}
+
+const caches = new WeakMap<BetterSqlite3.Database, StatementCache>();
+
+/**
+ * Enables statement caching for a connection
+ */
+export function attachStatementCache(db: BetterSqlite3.Database): void {
+  caches.set(db, { statements: new Map(), hits: 0, misses: 0 });
+}
+
+/**
+ * Drops a connection's cached statements (call before closing it)
+ */
+export function detachStatementCache(db: BetterSqlite3.Database): void {
+  caches.delete(db);
+}
+
+/**
+ * Discards a connection's cached statements but keeps caching enabled
+ */
+export function clearStatementCache(db: BetterSqlite3.Database): void {
+  caches.get(db)?.statements.clear();
+}
+
+const TIMED_METHODS = ["run", "get", "all"] as const;
+
+/**
+ * Wraps a statement's run/get/all so each execution is timed
+ */
+function instrumentStatement(statement: BetterSqlite3.Statement, sql: string): void {
+  const target = statement as unknown as Record<string, unknown>;
+  for (const method of TIMED_METHODS) {
+    const original = target[method];
+    if (typeof original !== "function") continue;
+    target[method] = (...args: unknown[]): unknown => {
+      const started = performance.now();
+      try {
+        return (original as (...a: unknown[]) => unknown).apply(statement, args);
+      } catch (err: unknown) {
+        if ((err as { code?: string } | null)?.code === "SQLITE_BUSY") {
+          recordBusyTimeout(sql);
+        }
+        throw err;
+      } finally {
+        recordStatementDuration(sql, performance.now() - started);
+      }
+    };
+  }
+}
+
+/**
+ * Prepares SQL, reusing the connection's cached statement when there is one
+ */
+export function prepareCached(
+  db: BetterSqlite3.Database,
+  sql: string
+): BetterSqlite3.Statement {
+  const cache = caches.get(db);
+  if (!cache) {
+    return db.prepare(sql);
+  }
+
+  const cached = cache.statements.get(sql);
+  if (cached) {
+    cache.hits++;
+    // Re-insert to mark as most recently used
+    cache.statements.delete(sql);
+    cache.statements.set(sql, cached);
+    return cached;
+  }
+
+  cache.misses++;
+  const statement = db.prepare(sql);
+  instrumentStatement(statement, sql);
+  cache.statements.set(sql, statement);
+  if (cache.statements.size > STATEMENT_CACHE_LIMIT) {
+    const oldest = cache.statements.keys().next().value;
+    if (oldest !== undefined) {
+      cache.statements.delete(oldest);
+    }
+  }
+  return statement;
+}
+
+/**
+ * Cache size and hit counts for a connection (null if caching is not enabled)
+ */
+export function getStatementCacheStats(
+  db: BetterSqlite3.Database
+): StatementCacheStats | null {
+  const cache = caches.get(db);
+  if (!cache) {
+    return null;
+  }
+  return { size: cache.statements.size, hits: cache.hits, misses: cache.misses };
+}
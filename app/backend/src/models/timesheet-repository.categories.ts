@@ -0,0 +1,35 @@
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+
+/**
+ * Renames a category on every entry that uses it, submitted ones included
+ * (categories are local and never sent to Smartsheet)
+ *
+ * @returns Number of entries changed
+ */
+export function renameEntryCategory(fromName: string, toName: string): number {
+  const db = getDb();
+  const result = db
+    .prepare(`UPDATE timesheet SET category = ? WHERE category = ?`)
+    .run(toName, fromName);
+  if (result.changes > 0) {
+    dbLogger.info("Entry category renamed", { fromName, toName, changes: result.changes });
+  }
+  return result.changes;
+}
+
+/**
+ * Removes a category from every entry that uses it
+ *
+ * @returns Number of entries changed
+ */
+export function clearEntryCategory(name: string): number {
+  const db = getDb();
+  const result = db
+    .prepare(`UPDATE timesheet SET category = NULL WHERE category = ?`)
+    .run(name);
+  if (result.changes > 0) {
+    dbLogger.info("Entry category cleared", { name, changes: result.changes });
+  }
+  return result.changes;
+}
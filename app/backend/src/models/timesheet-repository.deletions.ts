@@ -0,0 +1,93 @@
+import { SubmissionStatus } from "@sheetpilot/shared";
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+import { STATUS_SQL, type TimesheetDbRow } from "./timesheet-repository.types";
+
+export interface DeleteCompletedEntryRequest {
+  id: number;
+  /** Why the entry is being removed (e.g. payroll reversed it); required */
+  reason: string;
+  /** Admin who deleted it, for the audit trail */
+  performedBy?: string | undefined;
+}
+
+export interface DeleteCompletedEntryResult {
+  /** The row as it was before deletion */
+  entry: TimesheetDbRow;
+  /** Row in deleted_entries holding the copy */
+  deletionId: number;
+}
+
+export interface DeletedEntryRow {
+  id: number;
+  entry_id: number;
+  /** JSON copy of the timesheet row */
+  entry: string;
+  reason: string;
+  deleted_by: string | null;
+  deleted_at: string;
+}
+
+/**
+ * Removes a submitted entry after payroll has corrected it server-side
+ *
+ * Complete rows are otherwise never deleted locally. The row is moved to
+ * deleted_entries with the reason, so it can be looked up (or put back by
+ * hand) later instead of being lost.
+ *
+ * @throws If the entry does not exist, is not Complete, or the reason is blank
+ */
+export function deleteCompletedEntry(
+  request: DeleteCompletedEntryRequest
+): DeleteCompletedEntryResult {
+  const reason = request.reason.trim();
+  if (!reason) {
+    throw new Error("A reason is required to delete a submitted entry");
+  }
+
+  const db = getDb();
+  const remove = db.transaction((): DeleteCompletedEntryResult => {
+    const entry = db.prepare(`SELECT * FROM timesheet WHERE id = ?`).get(request.id) as
+      | TimesheetDbRow
+      | undefined;
+    if (!entry) {
+      throw new Error(`Entry ${request.id} not found`);
+    }
+    if (entry.status !== SubmissionStatus.COMPLETE) {
+      throw new Error(`Entry ${request.id} is not submitted; delete it from the timesheet instead`);
+    }
+
+    const deletion = db
+      .prepare(
+        `
+          INSERT INTO deleted_entries (entry_id, entry, reason, deleted_by)
+          VALUES (?, ?, ?, ?)
+        `
+      )
+      .run(entry.id, JSON.stringify(entry), reason, request.performedBy ?? null);
+    db.prepare(`DELETE FROM timesheet WHERE id = ? AND status = ${STATUS_SQL.COMPLETE}`).run(entry.id);
+    return { entry, deletionId: Number(deletion.lastInsertRowid) };
+  });
+
+  const result = remove();
+  dbLogger.audit("delete-completed-entry", "Submitted entry deleted", {
+    id: request.id,
+    date: result.entry.date,
+    project: result.entry.project,
+    hours: result.entry.hours,
+    reason,
+    deletionId: result.deletionId,
+    performedBy: request.performedBy ?? null,
+  });
+  return result;
+}
+
+/**
+ * Lists deleted submitted entries, newest first
+ */
+export function listDeletedEntries(limit = 100): DeletedEntryRow[] {
+  const db = getDb();
+  return db
+    .prepare(`SELECT * FROM deleted_entries ORDER BY id DESC LIMIT ?`)
+    .all(limit) as DeletedEntryRow[];
+}
@@ -0,0 +1,269 @@
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+import { prepareCached } from "./statement-cache";
+import { getTimesheetEntryById } from "./timesheet-repository.read";
+import { STATUS_SQL } from "./timesheet-repository.types";
+import { TIMESHEET_ISO_DATE_SQL } from "./timesheet-repository.reports";
+import type {
+  TimesheetBulkInsertEntry,
+  TimesheetDraftFields,
+  TimesheetDraftSaveResult,
+} from "./timesheet-repository.types";
+
+const DRAFT_COLUMNS: Array<{
+  column: string;
+  value: (fields: TimesheetDraftFields) => string | number | null | undefined;
+}> = [
+  { column: "date", value: (fields) => fields.date },
+  // NULL hours are never written over existing hours
+  { column: "hours", value: (fields) => fields.hours ?? undefined },
+  { column: "project", value: (fields) => fields.project },
+  {
+    column: "tool",
+    value: (fields) => (fields.tool === undefined ? undefined : fields.tool || null),
+  },
+  {
+    column: "detail_charge_code",
+    value: (fields) =>
+      fields.chargeCode === undefined ? undefined : fields.chargeCode || null,
+  },
+  { column: "task_description", value: (fields) => fields.taskDescription },
+  {
+    column: "time_zone",
+    value: (fields) =>
+      fields.timeZone === undefined ? undefined : fields.timeZone || null,
+  },
+  {
+    column: "external_ref",
+    value: (fields) =>
+      fields.externalRef === undefined ? undefined : fields.externalRef || null,
+  },
+  {
+    column: "category",
+    value: (fields) =>
+      fields.category === undefined ? undefined : fields.category || null,
+  },
+  {
+    column: "entry_kind",
+    // Work is stored as NULL so rows from before migration 22 read the same
+    value: (fields) =>
+      fields.kind === undefined ? undefined : fields.kind === "work" ? null : fields.kind,
+  },
+];
+
+/**
+ * Inserts a new draft; incomplete fields are stored as NULL
+ *
+ * @returns ID of the new draft
+ */
+export function insertDraftTimesheetEntry(fields: TimesheetDraftFields): number {
+  const db = getDb();
+  // Columns added by migrations are only named when set, so unmigrated databases still accept drafts
+  const optional = [
+    { column: "time_zone", value: fields.timeZone },
+    { column: "external_ref", value: fields.externalRef },
+    { column: "category", value: fields.category },
+    { column: "entry_kind", value: fields.kind === "work" ? null : fields.kind },
+  ].filter((entry): entry is { column: string; value: string } => Boolean(entry.value));
+  const result = prepareCached(
+    db,
+    `
+        INSERT INTO timesheet
+          (date, hours, project, tool, detail_charge_code, task_description${optional.map((entry) => `, ${entry.column}`).join("")}, status)
+        VALUES (?, ?, ?, ?, ?, ?${", ?".repeat(optional.length)}, NULL)
+    `
+  ).run(
+    fields.date || null,
+    fields.hours || null,
+    fields.project || null,
+    fields.tool || null,
+    fields.chargeCode || null,
+    fields.taskDescription || null,
+    ...optional.map((entry) => entry.value)
+  );
+  return Number(result.lastInsertRowid);
+}
+
+/**
+ * Updates the given fields of a draft (submitted and in-progress rows are untouched)
+ *
+ * @returns Number of rows changed
+ */
+export function updateDraftTimesheetEntry(
+  id: number,
+  fields: TimesheetDraftFields
+): number {
+  const updates = DRAFT_COLUMNS.flatMap(({ column, value }) => {
+    const fieldValue = value(fields);
+    return fieldValue === undefined ? [] : [{ column, value: fieldValue }];
+  });
+  if (updates.length === 0) {
+    return 0;
+  }
+
+  const db = getDb();
+  const setSql = updates.map((update) => `${update.column} = ?`).join(", ");
+  const result = db
+    .prepare(`UPDATE timesheet SET ${setSql} WHERE id = ? AND status IS NULL`)
+    .run(...updates.map((update) => update.value), id);
+  return result.changes;
+}
+
+/**
+ * Saves a draft: updates it when an ID is given, otherwise inserts a new one
+ */
+export function saveDraftTimesheetEntry(
+  id: number | null | undefined,
+  fields: TimesheetDraftFields
+): TimesheetDraftSaveResult {
+  const db = getDb();
+  const save = db.transaction((): TimesheetDraftSaveResult => {
+    if (id) {
+      const changes = updateDraftTimesheetEntry(id, fields);
+      return { changes, id, entry: getTimesheetEntryById(id) };
+    }
+    const newId = insertDraftTimesheetEntry(fields);
+    return { changes: 1, id: newId, entry: getTimesheetEntryById(newId) };
+  });
+  return save();
+}
+
+/**
+ * Inserts a complete draft, or resets the existing row with the same
+ * date, project and task description back to a draft with these values
+ *
+ * @returns Number of rows changed
+ */
+export function upsertDraftTimesheetEntry(
+  entry: TimesheetBulkInsertEntry
+): number {
+  const db = getDb();
+  const result = prepareCached(
+    db,
+    `
+        INSERT INTO timesheet
+          (date, hours, project, tool, detail_charge_code, task_description, status)
+        VALUES (?, ?, ?, ?, ?, ?, NULL)
+        ON CONFLICT(date, project, task_description) DO UPDATE SET
+          hours = excluded.hours,
+          tool = excluded.tool,
+          detail_charge_code = excluded.detail_charge_code,
+          status = NULL
+    `
+  ).run(
+    entry.date,
+    entry.hours,
+    entry.project,
+    entry.tool ?? null,
+    entry.detailChargeCode ?? null,
+    entry.taskDescription
+  );
+  return result.changes;
+}
+
+/**
+ * Replaces every field of an unsubmitted entry and returns it to draft status
+ *
+ * @returns Number of rows changed
+ */
+export function replaceUnsubmittedTimesheetEntry(
+  id: number,
+  entry: TimesheetBulkInsertEntry
+): number {
+  const db = getDb();
+  const result = prepareCached(
+    db,
+    `
+        UPDATE timesheet
+        SET date = ?,
+            hours = ?,
+            project = ?,
+            tool = ?,
+            detail_charge_code = ?,
+            task_description = ?,
+            status = NULL
+        WHERE id = ? AND (status IS NULL OR status != ${STATUS_SQL.COMPLETE})
+    `
+  ).run(
+    entry.date,
+    entry.hours,
+    entry.project,
+    entry.tool ?? null,
+    entry.detailChargeCode ?? null,
+    entry.taskDescription,
+    id
+  );
+  return result.changes;
+}
+
+/**
+ * Deletes an entry unless it has been submitted
+ *
+ * @param draftsOnly - Also keep in-progress entries
+ * @returns Number of rows deleted
+ */
+export function deleteUnsubmittedTimesheetEntry(
+  id: number,
+  draftsOnly = false
+): number {
+  const db = getDb();
+  const statusFilter = draftsOnly
+    ? "status IS NULL"
+    : `(status IS NULL OR status != ${STATUS_SQL.COMPLETE})`;
+  const result = prepareCached(
+    db,
+    `DELETE FROM timesheet WHERE id = ? AND ${statusFilter}`
+  ).run(id);
+  if (result.changes > 0) {
+    dbLogger.verbose("Timesheet entry deleted", { id });
+  }
+  return result.changes;
+}
+
+/**
+ * Moves drafts from one project to another (e.g. a retired project to its
+ * successor)
+ *
+ * Tools are kept only if the new project offers them; otherwise the tool and
+ * charge code are cleared for the user to pick again.
+ *
+ * @param options.fromDate - Only move drafts dated on or after this day (YYYY-MM-DD)
+ * @param options.validTools - Tools of the new project; empty when it takes no tools
+ * @returns IDs of the drafts that were moved
+ */
+export function reassignDraftProject(
+  fromProject: string,
+  toProject: string,
+  options: { fromDate?: string | null; validTools: readonly string[] }
+): number[] {
+  const db = getDb();
+  const dateClause = options.fromDate
+    ? `AND ${TIMESHEET_ISO_DATE_SQL} >= ?`
+    : "";
+  const params = options.fromDate ? [fromProject, options.fromDate] : [fromProject];
+  const reassign = db.transaction(() => {
+    const drafts = db
+      .prepare(
+        `SELECT id, tool FROM timesheet WHERE status IS NULL AND project = ? ${dateClause} ORDER BY id`
+      )
+      .all(...params) as Array<{ id: number; tool: string | null }>;
+    const keepTool = db.prepare(`UPDATE timesheet SET project = ? WHERE id = ?`);
+    const clearTool = db.prepare(
+      `UPDATE timesheet SET project = ?, tool = NULL, detail_charge_code = NULL WHERE id = ?`
+    );
+    for (const draft of drafts) {
+      const stmt = draft.tool && options.validTools.includes(draft.tool) ? keepTool : clearTool;
+      stmt.run(toProject, draft.id);
+    }
+    return drafts.map((draft) => draft.id);
+  });
+
+  const ids = reassign();
+  dbLogger.audit("reassign-draft-project", "Drafts moved to another project", {
+    fromProject,
+    toProject,
+    fromDate: options.fromDate ?? null,
+    count: ids.length,
+  });
+  return ids;
+}
@@ -1,6 +1,10 @@
 import { dbLogger } from "@sheetpilot/shared/logger";
+import { SubmissionStatus, type TimesheetEntryStatus } from "@sheetpilot/shared";
 import { getDb } from "./connection-manager";
-import type { TimesheetBulkInsertEntry } from "./timesheet-repository.types";
+import type {
+  TimesheetBulkInsertEntry,
+  TimesheetDbRow,
+} from "./timesheet-repository.types";
 
 /**
  * Inserts a new timesheet entry with deduplication
@@ -74,10 +78,15 @@ export function insertTimesheetEntries(entries: TimesheetBulkInsertEntry[]): {
       count: entries.length,
     });
 
+    // Columns added by migrations (time_zone, category) are only named when an entry sets them
+    const optional = [
+      { column: "time_zone", value: (entry: TimesheetBulkInsertEntry) => entry.timeZone },
+      { column: "category", value: (entry: TimesheetBulkInsertEntry) => entry.category },
+    ].filter((field) => entries.some((entry) => field.value(entry)));
     const insert = db.prepare(`
             INSERT INTO timesheet
-              (date, hours, project, tool, detail_charge_code, task_description)
-            VALUES (?, ?, ?, ?, ?, ?)
+              (date, hours, project, tool, detail_charge_code, task_description${optional.map((field) => `, ${field.column}`).join("")})
+            VALUES (?, ?, ?, ?, ?, ?${", ?".repeat(optional.length)})
             ON CONFLICT(date, project, task_description) DO NOTHING
         `);
 
@@ -91,7 +100,8 @@ export function insertTimesheetEntries(entries: TimesheetBulkInsertEntry[]): {
             entry.project,
             entry.tool ?? null,
             entry.detailChargeCode ?? null,
-            entry.taskDescription
+            entry.taskDescription,
+            ...optional.map((field) => field.value(entry) ?? null)
           );
             if (result.changes > 0) {
               return { inserted: acc.inserted + 1, duplicates: acc.duplicates };
@@ -132,3 +142,79 @@ export function insertTimesheetEntries(entries: TimesheetBulkInsertEntry[]): {
     };
   }
 }
+
+/**
+ * Clones a submitted entry into a new draft that amends it
+ *
+ * The draft keeps the original's fields (including its entry kind, category,
+ * external reference and time zone) and links back through amends_id; the
+ * original row is left untouched.
+ */
+export function amendTimesheetEntry(id: number): {
+  success: boolean;
+  id?: number;
+  errorMessage?: string;
+} {
+  const db = getDb();
+  const amend = db.transaction(() => {
+    const original = db
+      .prepare(`SELECT * FROM timesheet WHERE id = ?`)
+      .get(id) as TimesheetDbRow | undefined;
+    if (!original) {
+      return { success: false, errorMessage: "Entry not found" };
+    }
+    if (original.status !== SubmissionStatus.COMPLETE) {
+      return {
+        success: false,
+        errorMessage: "Only submitted entries can be amended; edit the draft instead",
+      };
+    }
+
+    const existing = db
+      .prepare(`SELECT id, status FROM timesheet WHERE amends_id = ? ORDER BY id DESC LIMIT 1`)
+      .get(id) as { id: number; status: TimesheetEntryStatus } | undefined;
+    if (existing?.status === SubmissionStatus.COMPLETE) {
+      // Keep the chain linear so every correction supersedes exactly one entry
+      return {
+        success: false,
+        errorMessage: "This entry has already been corrected; amend the latest correction instead",
+      };
+    }
+    if (existing) {
+      return { success: true, id: existing.id };
+    }
+
+    const result = db
+      .prepare(
+        `
+            INSERT INTO timesheet
+              (date, hours, project, tool, detail_charge_code, task_description,
+               time_zone, external_ref, category, entry_kind, status, amends_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, NULL, ?)
+        `
+      )
+      .run(
+        original.date,
+        original.hours,
+        original.project,
+        original.tool ?? null,
+        original.detail_charge_code ?? null,
+        original.task_description,
+        original.time_zone ?? null,
+        original.external_ref ?? null,
+        original.category ?? null,
+        original.entry_kind ?? null,
+        id
+      );
+    return { success: true, id: Number(result.lastInsertRowid) };
+  });
+
+  const result = amend();
+  if (result.success) {
+    dbLogger.audit("amend-entry", "Amendment draft created for submitted entry", {
+      originalId: id,
+      amendmentId: result.id,
+    });
+  }
+  return result;
+}
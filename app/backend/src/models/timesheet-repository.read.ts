@@ -1,16 +1,38 @@
 import { dbLogger } from "@sheetpilot/shared/logger";
 import { getDb } from "./connection-manager";
+import { prepareCached } from "./statement-cache";
+import type { TimesheetEntryStatus } from "@sheetpilot/shared";
+import { ARCHIVE_COLUMNS, STATUS_SQL } from "./timesheet-repository.types";
 import type {
+  ArchiveColumn,
   TimesheetDedupKey,
   TimesheetDbRow,
 } from "./timesheet-repository.types";
 
+/** Returned by save/delete paths when asked to change a submitted entry */
+export const SUBMITTED_ENTRY_LOCKED_ERROR =
+  "This entry has already been submitted and cannot be changed. Amend it to submit a correction.";
+
+/**
+ * Gets the submission status of an entry
+ *
+ * @returns NULL for drafts, the status string otherwise, or undefined if the entry does not exist
+ */
+export function getTimesheetEntryStatus(
+  id: number
+): TimesheetEntryStatus | undefined {
+  const db = getDb();
+  const row = prepareCached(db, `SELECT status FROM timesheet WHERE id = ?`)
+    .get(id) as { status: TimesheetEntryStatus } | undefined;
+  return row ? row.status : undefined;
+}
+
 /**
  * Checks if a timesheet entry would be a duplicate
  */
 export function checkDuplicateEntry(entry: TimesheetDedupKey): boolean {
   const db = getDb();
-  const checkDuplicate = db.prepare(`
+  const checkDuplicate = prepareCached(db, `
         SELECT COUNT(*) as count 
         FROM timesheet 
         WHERE date = ? AND project = ? AND task_description = ?
@@ -76,7 +98,7 @@ export function getPendingTimesheetEntries(): TimesheetDbRow[] {
   const db = getDb();
 
   dbLogger.verbose("Fetching pending timesheet entries");
-  const getPending = db.prepare(`
+  const getPending = prepareCached(db, `
         SELECT * FROM timesheet 
         WHERE status IS NULL
         ORDER BY date, hours
@@ -88,6 +110,164 @@ export function getPendingTimesheetEntries(): TimesheetDbRow[] {
   return entries;
 }
 
+/**
+ * Gets an entry by ID regardless of status
+ */
+export function getTimesheetEntryById(id: number): TimesheetDbRow | undefined {
+  const db = getDb();
+  return prepareCached(db, `SELECT * FROM timesheet WHERE id = ?`).get(id) as
+    | TimesheetDbRow
+    | undefined;
+}
+
+/**
+ * Gets a draft by ID (undefined if it does not exist or is not a draft)
+ */
+export function getDraftTimesheetEntryById(
+  id: number
+): TimesheetDbRow | undefined {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `SELECT * FROM timesheet WHERE id = ? AND status IS NULL`
+  ).get(id) as TimesheetDbRow | undefined;
+}
+
+/**
+ * Gets the ID of a submitted entry with the same date, project and task
+ */
+export function findSubmittedTimesheetEntryId(
+  entry: TimesheetDedupKey
+): number | undefined {
+  const db = getDb();
+  const row = prepareCached(
+    db,
+    `
+        SELECT id FROM timesheet
+        WHERE date = ? AND project = ? AND task_description = ?
+          AND status = ${STATUS_SQL.COMPLETE}
+    `
+  ).get(entry.date, entry.project, entry.taskDescription) as
+    | { id: number }
+    | undefined;
+  return row?.id;
+}
+
+/**
+ * Gets all submitted entries, oldest first
+ */
+export function getCompletedTimesheetEntries(): TimesheetDbRow[] {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `
+        SELECT * FROM timesheet
+        WHERE status = ${STATUS_SQL.COMPLETE}
+        ORDER BY date ASC, hours ASC
+    `
+  ).all() as TimesheetDbRow[];
+}
+
+/**
+ * Gets one page of submitted entries, oldest first, with the total count
+ */
+export function getCompletedTimesheetEntriesPage(
+  limit: number,
+  offset: number
+): { entries: TimesheetDbRow[]; totalCount: number } {
+  const db = getDb();
+  const count = prepareCached(
+    db,
+    `SELECT COUNT(*) as total FROM timesheet WHERE status = ${STATUS_SQL.COMPLETE}`
+  ).get() as { total: number };
+  const entries = prepareCached(
+    db,
+    `
+        SELECT * FROM timesheet
+        WHERE status = ${STATUS_SQL.COMPLETE}
+        ORDER BY date ASC, hours ASC
+        LIMIT ? OFFSET ?
+    `
+  ).all(limit, offset) as TimesheetDbRow[];
+  return { entries, totalCount: count.total };
+}
+
+/** Position after the last entry of a page */
+export interface ArchiveCursor {
+  date: string;
+  id: number;
+}
+
+/**
+ * Gets submitted entries after a cursor, oldest first
+ *
+ * Keyset paging on (date, id) keeps each page as cheap as the first, unlike
+ * OFFSET, and rows submitted while paging do not shift later pages.
+ *
+ * @param columns - Columns to return (defaults to all of `ARCHIVE_COLUMNS`)
+ * @returns The page and the cursor for the next one (null after the last page)
+ */
+export function getCompletedTimesheetEntriesAfter(options: {
+  cursor?: ArchiveCursor | null;
+  limit: number;
+  columns?: readonly ArchiveColumn[];
+}): { entries: Array<Partial<TimesheetDbRow> & Pick<TimesheetDbRow, "id" | "date">>; nextCursor: ArchiveCursor | null } {
+  const db = getDb();
+  const requested = new Set<ArchiveColumn>(options.columns ?? ARCHIVE_COLUMNS);
+  requested.add("id");
+  requested.add("date");
+  // Only allowlisted names reach the SQL
+  const columns = ARCHIVE_COLUMNS.filter((column) => requested.has(column));
+  const after = options.cursor
+    ? "AND (date > ? OR (date = ? AND id > ?))"
+    : "";
+  const params = options.cursor
+    ? [options.cursor.date, options.cursor.date, options.cursor.id]
+    : [];
+  const rows = prepareCached(
+    db,
+    `
+        SELECT ${columns.join(", ")} FROM timesheet
+        WHERE status = ${STATUS_SQL.COMPLETE} ${after}
+        ORDER BY date ASC, id ASC
+        LIMIT ?
+    `
+  ).all(...params, options.limit + 1) as Array<Partial<TimesheetDbRow> & Pick<TimesheetDbRow, "id" | "date">>;
+  const hasMore = rows.length > options.limit;
+  const entries = hasMore ? rows.slice(0, options.limit) : rows;
+  const last = entries[entries.length - 1];
+  return {
+    entries,
+    nextCursor: hasMore && last ? { date: last.date, id: last.id } : null,
+  };
+}
+
+/**
+ * Counts submitted entries
+ */
+export function countCompletedTimesheetEntries(): number {
+  const db = getDb();
+  const row = prepareCached(
+    db,
+    `SELECT COUNT(*) as total FROM timesheet WHERE status = ${STATUS_SQL.COMPLETE}`
+  ).get() as { total: number };
+  return row.total;
+}
+
+/**
+ * Gets every entry regardless of status, newest first
+ */
+export function getAllTimesheetEntries(): TimesheetDbRow[] {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `
+        SELECT * FROM timesheet
+        ORDER BY date DESC, hours DESC
+    `
+  ).all() as TimesheetDbRow[];
+}
+
 /**
  * Gets timesheet entries by IDs
  */
@@ -103,15 +283,70 @@ export function getTimesheetEntriesByIds(ids: number[]): TimesheetDbRow[] {
 
 /**
  * Gets submitted timesheet entries for export
+ *
+ * Originals replaced by a submitted correction carry `superseded_by` so
+ * exports can report corrected totals.
  */
-export function getSubmittedTimesheetEntriesForExport() {
+export function getSubmittedTimesheetEntriesForExport(): Array<
+  TimesheetDbRow & { superseded_by: number | null }
+> {
   const db = getDb();
-  const stmt = db.prepare(`
+  const stmt = prepareCached(db, `
         SELECT * FROM timesheet 
-        WHERE status = 'Complete'
+        WHERE status = ${STATUS_SQL.COMPLETE}
         ORDER BY date, project
     `);
-  return stmt.all() as TimesheetDbRow[];
+  const rows = stmt.all() as TimesheetDbRow[];
+  const supersededBy = new Map<number, number>();
+  for (const row of rows) {
+    if (row.amends_id !== null && row.amends_id !== undefined) {
+      supersededBy.set(row.amends_id, row.id);
+    }
+  }
+  return rows.map((row) => ({
+    ...row,
+    superseded_by: supersededBy.get(row.id) ?? null,
+  }));
+}
+
+/**
+ * Gets the amendment chain an entry belongs to, oldest (original) first
+ *
+ * @returns Empty array if the entry does not exist
+ */
+export function getAmendmentChain(id: number): TimesheetDbRow[] {
+  const db = getDb();
+  const root = db
+    .prepare(
+      `
+        WITH RECURSIVE ancestors(id, amends_id) AS (
+          SELECT id, amends_id FROM timesheet WHERE id = ?
+          UNION ALL
+          SELECT t.id, t.amends_id FROM timesheet t
+          JOIN ancestors a ON t.id = a.amends_id
+        )
+        SELECT id FROM ancestors WHERE amends_id IS NULL
+    `
+    )
+    .get(id) as { id: number } | undefined;
+  if (!root) {
+    return [];
+  }
+  return db
+    .prepare(
+      `
+        WITH RECURSIVE chain(id, depth) AS (
+          SELECT id, 0 FROM timesheet WHERE id = ?
+          UNION ALL
+          SELECT t.id, c.depth + 1 FROM timesheet t
+          JOIN chain c ON t.amends_id = c.id
+        )
+        SELECT t.* FROM timesheet t
+        JOIN chain c ON t.id = c.id
+        ORDER BY c.depth, t.id
+    `
+    )
+    .all(root.id) as TimesheetDbRow[];
 }
 
 /**
@@ -119,7 +354,7 @@ export function getSubmittedTimesheetEntriesForExport() {
  */
 export function getTotalHoursForDate(date: string): number {
   const db = getDb();
-  const stmt = db.prepare(`
+  const stmt = prepareCached(db, `
         SELECT COALESCE(SUM(hours), 0) as total
         FROM timesheet
         WHERE date = ? AND hours IS NOT NULL
@@ -127,3 +362,37 @@ export function getTotalHoursForDate(date: string): number {
   const result = stmt.get(date) as { total: number } | undefined;
   return result?.total ?? 0;
 }
+
+/**
+ * Gets total submitted hours for a date
+ */
+export function getSubmittedHoursForDate(date: string): number {
+  const db = getDb();
+  const result = prepareCached(
+    db,
+    `
+        SELECT COALESCE(SUM(hours), 0) as total
+        FROM timesheet
+        WHERE date = ? AND status = ${STATUS_SQL.COMPLETE} AND hours IS NOT NULL
+    `
+  ).get(date) as { total: number } | undefined;
+  return result?.total ?? 0;
+}
+
+/**
+ * Gets the most recent complete-looking entries (drafts and submitted)
+ * Used for pattern-based suggestions; rows missing core fields are skipped
+ */
+export function getRecentTimesheetHistory(limit = 500): TimesheetDbRow[] {
+  const db = getDb();
+  const stmt = db.prepare(`
+        SELECT id, date, hours, project, tool, detail_charge_code, task_description, status
+        FROM timesheet
+        WHERE date IS NOT NULL
+          AND project IS NOT NULL
+          AND task_description IS NOT NULL
+        ORDER BY id DESC
+        LIMIT ?
+    `);
+  return stmt.all(limit) as TimesheetDbRow[];
+}
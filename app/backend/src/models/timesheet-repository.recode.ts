@@ -0,0 +1,145 @@
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+import { STATUS_SQL } from "./timesheet-repository.types";
+import { TIMESHEET_ISO_DATE_SQL } from "./timesheet-repository.reports";
+
+/** Fields a bulk recode can rewrite */
+export type RecodeField = "project" | "chargeCode";
+
+const RECODE_COLUMNS: Record<RecodeField, string> = {
+  project: "project",
+  chargeCode: "detail_charge_code",
+};
+
+export interface RecodeFilter {
+  /** First day, YYYY-MM-DD */
+  startDate?: string | undefined;
+  /** Last day, YYYY-MM-DD */
+  endDate?: string | undefined;
+  /** Only entries booked to this project (useful when recoding charge codes) */
+  project?: string | undefined;
+}
+
+export interface BulkRecodeRequest {
+  field: RecodeField;
+  fromValue: string;
+  toValue: string;
+  filter?: RecodeFilter | undefined;
+  /** Count matching entries without changing them */
+  dryRun?: boolean | undefined;
+  /** Admin who ran the recode, for the audit trail */
+  performedBy?: string | undefined;
+}
+
+export interface BulkRecodeResult {
+  entryIds: number[];
+  /** Recode history row, or null for dry runs and recodes that matched nothing */
+  historyId: number | null;
+}
+
+export interface RecodeHistoryRow {
+  id: number;
+  field: RecodeField;
+  from_value: string;
+  to_value: string;
+  filter: string | null;
+  entry_ids: string;
+  performed_by: string | null;
+  performed_at: string;
+}
+
+/**
+ * Renames a project or charge code on submitted entries after payroll
+ * renames it, so reports and exports use the new name
+ *
+ * Only Complete rows are touched (drafts are edited normally). Each recode
+ * that changes anything is recorded in recode_history with the affected IDs.
+ *
+ * @throws If the rename would make entries duplicate existing ones
+ */
+export function bulkRecodeSubmittedEntries(request: BulkRecodeRequest): BulkRecodeResult {
+  const db = getDb();
+  const column = RECODE_COLUMNS[request.field];
+  const filter = request.filter ?? {};
+  const clauses = [`status = ${STATUS_SQL.COMPLETE}`, `${column} = ?`];
+  const params: string[] = [request.fromValue];
+  if (filter.startDate) {
+    clauses.push(`${TIMESHEET_ISO_DATE_SQL} >= ?`);
+    params.push(filter.startDate);
+  }
+  if (filter.endDate) {
+    clauses.push(`${TIMESHEET_ISO_DATE_SQL} <= ?`);
+    params.push(filter.endDate);
+  }
+  if (filter.project) {
+    clauses.push(`project = ?`);
+    params.push(filter.project);
+  }
+
+  const recode = db.transaction((): BulkRecodeResult => {
+    const entryIds = (
+      db
+        .prepare(`SELECT id FROM timesheet WHERE ${clauses.join(" AND ")} ORDER BY id`)
+        .all(...params) as Array<{ id: number }>
+    ).map((row) => row.id);
+    if (request.dryRun || entryIds.length === 0) {
+      return { entryIds, historyId: null };
+    }
+
+    const update = db.prepare(`UPDATE timesheet SET ${column} = ? WHERE id = ?`);
+    for (const id of entryIds) {
+      update.run(request.toValue, id);
+    }
+    const history = db
+      .prepare(
+        `
+          INSERT INTO recode_history (field, from_value, to_value, filter, entry_ids, performed_by)
+          VALUES (?, ?, ?, ?, ?, ?)
+        `
+      )
+      .run(
+        request.field,
+        request.fromValue,
+        request.toValue,
+        Object.keys(filter).length > 0 ? JSON.stringify(filter) : null,
+        JSON.stringify(entryIds),
+        request.performedBy ?? null
+      );
+    return { entryIds, historyId: Number(history.lastInsertRowid) };
+  });
+
+  let result: BulkRecodeResult;
+  try {
+    result = recode();
+  } catch (err: unknown) {
+    if (err instanceof Error && err.message.includes("UNIQUE constraint failed")) {
+      throw new Error(
+        `Renaming ${request.fromValue} to ${request.toValue} would duplicate existing submitted entries; narrow the filter`
+      );
+    }
+    throw err;
+  }
+
+  if (!request.dryRun && result.historyId !== null) {
+    dbLogger.audit("bulk-recode", "Submitted entries recoded", {
+      field: request.field,
+      fromValue: request.fromValue,
+      toValue: request.toValue,
+      filter,
+      count: result.entryIds.length,
+      historyId: result.historyId,
+      performedBy: request.performedBy ?? null,
+    });
+  }
+  return result;
+}
+
+/**
+ * Lists bulk recodes, newest first
+ */
+export function listRecodeHistory(limit = 100): RecodeHistoryRow[] {
+  const db = getDb();
+  return db
+    .prepare(`SELECT * FROM recode_history ORDER BY id DESC LIMIT ?`)
+    .all(limit) as RecodeHistoryRow[];
+}
@@ -0,0 +1,235 @@
+import { dbLogger } from "@sheetpilot/shared/logger";
+import { getDb } from "./connection-manager";
+import { prepareCached } from "./statement-cache";
+import { STATUS_SQL, type TimesheetDbRow } from "./timesheet-repository.types";
+
+/**
+ * SQL expression converting the stored `date` column (MM/DD/YYYY, unpadded
+ * parts allowed, or already YYYY-MM-DD) to a sortable YYYY-MM-DD string
+ */
+export const TIMESHEET_ISO_DATE_SQL = `
+    CASE
+      WHEN instr(date, '/') = 0 THEN date
+      ELSE printf('%04d-%02d-%02d',
+        CAST(substr(substr(date, instr(date, '/') + 1), instr(substr(date, instr(date, '/') + 1), '/') + 1) AS INTEGER),
+        CAST(substr(date, 1, instr(date, '/') - 1) AS INTEGER),
+        CAST(substr(substr(date, instr(date, '/') + 1), 1, instr(substr(date, instr(date, '/') + 1), '/') - 1) AS INTEGER))
+    END`;
+
+export interface ToolUsageRow {
+  project: string;
+  /** Null for projects that do not use tools */
+  tool: string | null;
+  hours: number;
+  entryCount: number;
+}
+
+/**
+ * Aggregates hours per tool per project for an inclusive ISO date range
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ * @param includeDrafts - Also count entries that have not been submitted
+ */
+export function getToolUsage(
+  startDate: string,
+  endDate: string,
+  includeDrafts = false
+): ToolUsageRow[] {
+  const timer = dbLogger.startTimer("get-tool-usage");
+  const db = getDb();
+  const statusClause = includeDrafts ? "" : `AND status = ${STATUS_SQL.COMPLETE}`;
+  const rows = db
+    .prepare(
+      `
+        SELECT project,
+               NULLIF(tool, '') AS tool,
+               ROUND(SUM(COALESCE(hours, 0)), 2) AS hours,
+               COUNT(*) AS entryCount
+        FROM timesheet
+        WHERE ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+          ${statusClause}
+        GROUP BY project, NULLIF(tool, '')
+        ORDER BY project, hours DESC
+    `
+    )
+    .all(startDate, endDate) as ToolUsageRow[];
+  timer.done({ groups: rows.length });
+  return rows;
+}
+
+export interface DraftDateTotalRow {
+  /** YYYY-MM-DD, or null for drafts without a date yet */
+  date: string | null;
+  entryCount: number;
+  hours: number;
+}
+
+/**
+ * Draft count and hours per date, oldest first (undated drafts last)
+ */
+export function getDraftDateTotals(): DraftDateTotalRow[] {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `
+        SELECT CASE WHEN date IS NULL OR date = '' THEN NULL
+                    ELSE ${TIMESHEET_ISO_DATE_SQL} END AS date,
+               COUNT(*) AS entryCount,
+               ROUND(SUM(COALESCE(hours, 0)), 2) AS hours
+        FROM timesheet
+        WHERE status IS NULL
+        GROUP BY 1
+        ORDER BY date IS NULL, date
+    `
+  ).all() as DraftDateTotalRow[];
+}
+
+export interface ActivityHeatmapDay {
+  /** YYYY-MM-DD */
+  date: string;
+  submittedHours: number;
+  /** Hours still sitting in drafts, e.g. a day logged but never submitted */
+  draftHours: number;
+}
+
+/**
+ * Submitted and draft hours for every day of an inclusive ISO date range,
+ * oldest first (days without entries are included with zero hours).
+ * Originals replaced by a submitted correction are not counted twice.
+ *
+ * @param first - First day, YYYY-MM-DD
+ * @param last - Last day, YYYY-MM-DD
+ */
+export function getDailyHourTotals(first: string, last: string): ActivityHeatmapDay[] {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `
+        WITH RECURSIVE days(day) AS (
+          SELECT ?
+          UNION ALL
+          SELECT date(day, '+1 day') FROM days WHERE day < ?
+        ),
+        totals AS (
+          SELECT ${TIMESHEET_ISO_DATE_SQL} AS day,
+                 SUM(CASE WHEN status = ${STATUS_SQL.COMPLETE}
+                           AND id NOT IN (
+                             SELECT amends_id FROM timesheet
+                             WHERE status = ${STATUS_SQL.COMPLETE} AND amends_id IS NOT NULL
+                           )
+                          THEN COALESCE(hours, 0) ELSE 0 END) AS submittedHours,
+                 SUM(CASE WHEN status IS NULL THEN COALESCE(hours, 0) ELSE 0 END) AS draftHours
+          FROM timesheet
+          WHERE date IS NOT NULL AND date != ''
+            AND ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+          GROUP BY 1
+        )
+        SELECT days.day AS date,
+               ROUND(COALESCE(totals.submittedHours, 0), 2) AS submittedHours,
+               ROUND(COALESCE(totals.draftHours, 0), 2) AS draftHours
+        FROM days
+        LEFT JOIN totals ON totals.day = days.day
+        ORDER BY days.day
+    `
+  ).all(first, last, first, last) as ActivityHeatmapDay[];
+}
+
+/**
+ * Submitted and draft hours for every day of a calendar year (see
+ * `getDailyHourTotals`)
+ */
+export function getActivityHeatmap(year: number): ActivityHeatmapDay[] {
+  const timer = dbLogger.startTimer("get-activity-heatmap");
+  const first = `${String(year).padStart(4, "0")}-01-01`;
+  const last = `${String(year).padStart(4, "0")}-12-31`;
+  const days = getDailyHourTotals(first, last);
+  timer.done({ year, days: days.length });
+  return days;
+}
+
+/**
+ * Gets entries in an inclusive ISO date range, oldest first
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ * @param includeDrafts - Also return entries that have not been submitted
+ */
+export function getTimesheetEntriesInRange(
+  startDate: string,
+  endDate: string,
+  includeDrafts = false
+): TimesheetDbRow[] {
+  const db = getDb();
+  const statusClause = includeDrafts ? "" : `AND status = ${STATUS_SQL.COMPLETE}`;
+  return prepareCached(
+    db,
+    `
+        SELECT * FROM timesheet
+        WHERE ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+          ${statusClause}
+        ORDER BY ${TIMESHEET_ISO_DATE_SQL}, id
+    `
+  ).all(startDate, endDate) as TimesheetDbRow[];
+}
+
+/**
+ * Gets the entries needed to diff a date range against its last submission
+ *
+ * Returns every entry dated in the range, plus entries linked to them by an
+ * amendment across the range boundary: submitted originals of drafts that
+ * moved into the range, and corrections of in-range entries (drafts that
+ * moved out, or submitted corrections that superseded them).
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ */
+export function getWeekDiffEntries(
+  startDate: string,
+  endDate: string
+): TimesheetDbRow[] {
+  const db = getDb();
+  return prepareCached(
+    db,
+    `
+        SELECT * FROM timesheet
+        WHERE ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+           OR id IN (
+             SELECT amends_id FROM timesheet
+             WHERE status IS NULL AND amends_id IS NOT NULL
+               AND ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+           )
+           OR amends_id IN (
+             SELECT id FROM timesheet
+             WHERE ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+           )
+        ORDER BY id
+    `
+  ).all(startDate, endDate, startDate, endDate, startDate, endDate) as TimesheetDbRow[];
+}
+
+/**
+ * Submitted hours booked to a charge code (case-insensitive) in an inclusive
+ * ISO date range
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ */
+export function getSubmittedChargeCodeHours(
+  chargeCode: string,
+  startDate: string,
+  endDate: string
+): number {
+  const db = getDb();
+  const row = prepareCached(
+    db,
+    `
+        SELECT ROUND(COALESCE(SUM(hours), 0), 2) AS hours
+        FROM timesheet
+        WHERE status = ${STATUS_SQL.COMPLETE}
+          AND lower(trim(detail_charge_code)) = lower(trim(?))
+          AND ${TIMESHEET_ISO_DATE_SQL} BETWEEN ? AND ?
+    `
+  ).get(chargeCode, startDate, endDate) as { hours: number };
+  return row.hours;
+}
@@ -1,5 +1,96 @@
+import { randomBytes } from "crypto";
 import { dbLogger } from "@sheetpilot/shared/logger";
+import { SubmissionStatus, type TimesheetEntryStatus } from "@sheetpilot/shared";
 import { getDb } from "./connection-manager";
+import { STATUS_SQL } from "./timesheet-repository.types";
+import { prepareCached } from "./statement-cache";
+
+const BASE32_ALPHABET = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/**
+ * Generates a submission idempotency key, e.g. SP-7K2MQX4ABZ
+ */
+export function generateSubmissionKey(): string {
+  const bytes = randomBytes(10);
+  let key = "";
+  for (const byte of bytes) {
+    key += BASE32_ALPHABET.charAt(byte % 32);
+  }
+  return `SP-${key}`;
+}
+
+interface EntryStatusUpdate {
+  /** Target status */
+  status: TimesheetEntryStatus;
+  /** Only rows currently in one of these statuses change (any status if omitted) */
+  from?: readonly TimesheetEntryStatus[];
+  /** Sets submitted_at to now */
+  stampSubmittedAt?: boolean;
+  /** Clears the failure reason left by an earlier rejected submission */
+  clearFailureReason?: boolean;
+  /** Throws and rolls back unless every requested row changed */
+  requireAll?: boolean;
+  /** Logged when requireAll is not met */
+  failureMessage?: string;
+}
+
+/**
+ * Shared UPDATE behind every per-entry status transition
+ *
+ * @returns Number of rows changed
+ */
+function updateEntryStatus(
+  ids: readonly number[],
+  update: EntryStatusUpdate
+): number {
+  const db = getDb();
+  const placeholders = ids.map(() => "?").join(",");
+  const setClauses = ["status = ?"];
+  if (update.stampSubmittedAt) {
+    setClauses.push("submitted_at = datetime('now')");
+  }
+  if (update.clearFailureReason) {
+    setClauses.push("failure_reason = NULL");
+  }
+
+  const fromClauses: string[] = [];
+  const fromParams: string[] = [];
+  for (const from of update.from ?? []) {
+    if (from === null) {
+      fromClauses.push("status IS NULL");
+    } else {
+      fromClauses.push("status = ?");
+      fromParams.push(from);
+    }
+  }
+  const fromSql =
+    fromClauses.length > 0 ? ` AND (${fromClauses.join(" OR ")})` : "";
+
+  const statement = db.prepare(`
+        UPDATE timesheet
+        SET ${setClauses.join(", ")}
+        WHERE id IN (${placeholders})${fromSql}
+    `);
+
+  if (!update.requireAll) {
+    return statement.run(update.status, ...ids, ...fromParams).changes;
+  }
+
+  const tx = db.transaction((entryIds: readonly number[]) => {
+    const result = statement.run(update.status, ...entryIds, ...fromParams);
+    if (result.changes !== entryIds.length) {
+      const errorMessage = `Database update mismatch: expected ${entryIds.length} rows, updated ${result.changes} rows`;
+      dbLogger.error(update.failureMessage ?? "Could not update timesheet entry status", {
+        expected: entryIds.length,
+        updated: result.changes,
+        ids: [...entryIds],
+      });
+      throw new Error(errorMessage);
+    }
+    return result.changes;
+  });
+  return tx(ids);
+}
 
 /**
  * Marks timesheet entries as in-progress
@@ -11,25 +102,20 @@ export function markTimesheetEntriesAsInProgress(ids: number[]) {
   }
 
   const timer = dbLogger.startTimer("mark-entries-in-progress");
-  const db = getDb();
-
   dbLogger.info("Marking timesheet entries as in-progress", {
     count: ids.length,
     ids,
   });
-  const placeholders = ids.map(() => "?").join(",");
-  const updateInProgress = db.prepare(`
-        UPDATE timesheet 
-        SET status = 'in_progress'
-        WHERE id IN (${placeholders}) AND status IS NULL
-    `);
 
-  const result = updateInProgress.run(...ids);
+  const changes = updateEntryStatus(ids, {
+    status: SubmissionStatus.IN_PROGRESS,
+    from: [null],
+  });
   dbLogger.audit("mark-in-progress", "Entries marked as in-progress", {
     count: ids.length,
-    changes: result.changes,
+    changes,
   });
-  timer.done({ count: ids.length, changes: result.changes });
+  timer.done({ count: ids.length, changes });
 }
 
 /**
@@ -42,25 +128,17 @@ export function resetTimesheetEntriesStatus(ids: number[]) {
   }
 
   const timer = dbLogger.startTimer("reset-entries-status");
-  const db = getDb();
-
   dbLogger.info("Resetting timesheet entries to NULL status", {
     count: ids.length,
     ids,
   });
-  const placeholders = ids.map(() => "?").join(",");
-  const resetStatus = db.prepare(`
-        UPDATE timesheet 
-        SET status = NULL
-        WHERE id IN (${placeholders})
-    `);
 
-  const result = resetStatus.run(...ids);
+  const changes = updateEntryStatus(ids, { status: null });
   dbLogger.audit("reset-status", "Entries status reset to NULL", {
     count: ids.length,
-    changes: result.changes,
+    changes,
   });
-  timer.done({ count: ids.length, changes: result.changes });
+  timer.done({ count: ids.length, changes });
 }
 
 /**
@@ -73,39 +151,19 @@ export function markTimesheetEntriesAsSubmitted(ids: number[]) {
   }
 
   const timer = dbLogger.startTimer("mark-entries-submitted");
-  const db = getDb();
-
   dbLogger.info("Marking timesheet entries as submitted", {
     count: ids.length,
     ids,
   });
-  const placeholders = ids.map(() => "?").join(",");
-
-  const tx = db.transaction((entryIds: readonly number[]) => {
-    const updateSubmitted = db.prepare(`
-            UPDATE timesheet 
-            SET status = 'Complete', 
-                submitted_at = datetime('now')
-            WHERE id IN (${placeholders})
-              AND (status IS NULL OR status = 'in_progress')
-        `);
-
-    const result = updateSubmitted.run(...entryIds);
-
-    if (result.changes !== entryIds.length) {
-      const errorMessage = `Database update mismatch: expected ${entryIds.length} rows, updated ${result.changes} rows`;
-      dbLogger.error("Could not mark timesheet entries as submitted", {
-        expected: entryIds.length,
-        updated: result.changes,
-        ids: [...entryIds],
-      });
-      throw new Error(errorMessage);
-    }
 
-    return result.changes;
+  const changes = updateEntryStatus(ids, {
+    status: SubmissionStatus.COMPLETE,
+    from: [null, SubmissionStatus.IN_PROGRESS],
+    stampSubmittedAt: true,
+    clearFailureReason: true,
+    requireAll: true,
+    failureMessage: "Could not mark timesheet entries as submitted",
   });
-
-  const changes = tx(ids);
   dbLogger.audit("mark-submitted", "Entries marked as submitted", {
     count: ids.length,
     changes,
@@ -113,6 +171,34 @@ export function markTimesheetEntriesAsSubmitted(ids: number[]) {
   timer.done({ count: ids.length, changes });
 }
 
+/**
+ * Records which run submitted the given entries (submitted entries only)
+ *
+ * @returns Number of rows changed
+ */
+export function recordSubmissionRun(ids: number[], runId: number): number {
+  if (ids.length === 0) {
+    return 0;
+  }
+  const db = getDb();
+  const placeholders = ids.map(() => "?").join(",");
+  const result = db
+    .prepare(
+      `
+        UPDATE timesheet
+        SET submitted_run_id = ?
+        WHERE id IN (${placeholders}) AND status = ${STATUS_SQL.COMPLETE}
+    `
+    )
+    .run(runId, ...ids);
+  dbLogger.verbose("Submission run recorded on entries", {
+    runId,
+    count: ids.length,
+    changes: result.changes,
+  });
+  return result.changes;
+}
+
 /**
  * Reverts failed timesheet entries back to pending status
  */
@@ -123,38 +209,17 @@ export function removeFailedTimesheetEntries(ids: number[]) {
   }
 
   const timer = dbLogger.startTimer("revert-failed-entries");
-  const db = getDb();
-
   dbLogger.warn("Reverting failed timesheet entries back to pending", {
     count: ids.length,
     ids,
   });
-  const placeholders = ids.map(() => "?").join(",");
-
-  const tx = db.transaction((entryIds: readonly number[]) => {
-    const revertFailed = db.prepare(`
-            UPDATE timesheet 
-            SET status = NULL
-            WHERE id IN (${placeholders})
-              AND status = 'in_progress'
-        `);
 
-    const result = revertFailed.run(...entryIds);
-
-    if (result.changes !== entryIds.length) {
-      const errorMessage = `Database update mismatch: expected ${entryIds.length} rows, updated ${result.changes} rows`;
-      dbLogger.error("Could not revert failed timesheet entries", {
-        expected: entryIds.length,
-        updated: result.changes,
-        ids: [...entryIds],
-      });
-      throw new Error(errorMessage);
-    }
-
-    return result.changes;
+  const changes = updateEntryStatus(ids, {
+    status: null,
+    from: [SubmissionStatus.IN_PROGRESS],
+    requireAll: true,
+    failureMessage: "Could not revert failed timesheet entries",
   });
-
-  const changes = tx(ids);
   dbLogger.audit("revert-failed", "Failed entries reverted to pending", {
     count: ids.length,
     changes,
@@ -162,6 +227,83 @@ export function removeFailedTimesheetEntries(ids: number[]) {
   timer.done({ count: ids.length, changes });
 }
 
+/**
+ * Stores values captured by submit postconditions on submitted entries
+ *
+ * @returns Number of rows changed
+ */
+export function recordSubmissionCaptures(
+  captures: ReadonlyArray<{ id: number; values: Record<string, string> }>
+): number {
+  if (captures.length === 0) {
+    return 0;
+  }
+  const db = getDb();
+  const statement = db.prepare(`
+        UPDATE timesheet
+        SET submission_captures = ?
+        WHERE id = ? AND status = ${STATUS_SQL.COMPLETE}
+    `);
+  const tx = db.transaction(() =>
+    captures.reduce(
+      (changes, { id, values }) =>
+        changes + statement.run(JSON.stringify(values), id).changes,
+      0
+    )
+  );
+  const changes = tx();
+  dbLogger.verbose("Submission captures recorded on entries", {
+    count: captures.length,
+    changes,
+  });
+  return changes;
+}
+
+/**
+ * Stores why the form rejected entries; only pending entries are updated,
+ * so call this after the failed entries are reverted
+ *
+ * @returns Number of rows changed
+ */
+export function recordTimesheetFailureReasons(
+  reasons: ReadonlyArray<{ id: number; reason: string }>
+): number {
+  if (reasons.length === 0) {
+    return 0;
+  }
+  const db = getDb();
+  const statement = db.prepare(`
+        UPDATE timesheet
+        SET failure_reason = ?
+        WHERE id = ? AND status IS NULL
+    `);
+  const tx = db.transaction(() =>
+    reasons.reduce(
+      (changes, { id, reason }) => changes + statement.run(reason, id).changes,
+      0
+    )
+  );
+  const changes = tx();
+  dbLogger.audit("record-failure-reasons", "Rejected entries annotated", {
+    count: reasons.length,
+    changes,
+  });
+  return changes;
+}
+
+/**
+ * Gets IDs of entries currently marked in_progress
+ * Used to record which entries an interrupted submission was working on
+ */
+export function getInProgressTimesheetEntryIds(): number[] {
+  const db = getDb();
+  const rows = prepareCached(
+    db,
+    `SELECT id FROM timesheet WHERE status = ${STATUS_SQL.IN_PROGRESS} ORDER BY id`
+  ).all() as Array<{ id: number }>;
+  return rows.map((row) => row.id);
+}
+
 /**
  * Resets in-progress timesheet entries to NULL
  * Used during error recovery to ensure entries aren't stuck
@@ -170,10 +312,10 @@ export function resetInProgressTimesheetEntries(): number {
   const timer = dbLogger.startTimer("reset-in-progress-entries");
   const db = getDb();
 
-  const update = db.prepare(`
+  const update = prepareCached(db, `
         UPDATE timesheet 
         SET status = NULL
-        WHERE status = 'in_progress'
+        WHERE status = ${STATUS_SQL.IN_PROGRESS}
     `);
 
   const result = update.run();
@@ -185,3 +327,149 @@ export function resetInProgressTimesheetEntries(): number {
   timer.done({ changes: result.changes });
   return result.changes;
 }
+
+/**
+ * Assigns submission keys to entries that don't have one yet
+ * Existing keys are kept so a retried entry is sent with the same key.
+ *
+ * @returns Submission key for every requested entry, and the IDs that already had one
+ */
+export function assignSubmissionKeys(ids: number[]): {
+  keys: Map<number, string>;
+  retriedIds: number[];
+} {
+  const keys = new Map<number, string>();
+  const retriedIds: number[] = [];
+  if (ids.length === 0) {
+    return { keys, retriedIds };
+  }
+
+  const db = getDb();
+  const select = db.prepare(
+    "SELECT submission_key FROM timesheet WHERE id = ?"
+  );
+  const update = db.prepare(
+    "UPDATE timesheet SET submission_key = ? WHERE id = ? AND submission_key IS NULL"
+  );
+
+  const tx = db.transaction((entryIds: readonly number[]) => {
+    for (const id of entryIds) {
+      const row = select.get(id) as { submission_key: string | null } | undefined;
+      if (!row) continue;
+      if (row.submission_key) {
+        keys.set(id, row.submission_key);
+        retriedIds.push(id);
+        continue;
+      }
+      const key = generateSubmissionKey();
+      update.run(key, id);
+      keys.set(id, key);
+    }
+  });
+  tx(ids);
+
+  if (retriedIds.length > 0) {
+    dbLogger.warn("Resubmitting entries that were previously attempted", {
+      count: retriedIds.length,
+      ids: retriedIds,
+    });
+  }
+  return { keys, retriedIds };
+}
+
+/**
+ * Marks pending or in-progress entries whose submission key was found in the
+ * destination sheet as Complete, so they are not submitted a second time.
+ *
+ * @param keys - Submission keys found in the sheet
+ * @returns IDs marked Complete, and keys that matched entries already Complete
+ */
+export function reconcileSubmissionKeys(keys: string[]): {
+  matchedIds: number[];
+  alreadyCompleteKeys: string[];
+  unknownKeys: string[];
+} {
+  const result = {
+    matchedIds: [] as number[],
+    alreadyCompleteKeys: [] as string[],
+    unknownKeys: [] as string[],
+  };
+  if (keys.length === 0) {
+    return result;
+  }
+
+  const timer = dbLogger.startTimer("reconcile-submission-keys");
+  const db = getDb();
+  const select = db.prepare(
+    "SELECT id, status FROM timesheet WHERE submission_key = ?"
+  );
+  const markComplete = db.prepare(`
+        UPDATE timesheet
+        SET status = ${STATUS_SQL.COMPLETE},
+            submitted_at = COALESCE(submitted_at, datetime('now'))
+        WHERE id = ? AND (status IS NULL OR status = ${STATUS_SQL.IN_PROGRESS})
+    `);
+
+  const tx = db.transaction((submissionKeys: readonly string[]) => {
+    for (const key of submissionKeys) {
+      const row = select.get(key) as { id: number; status: TimesheetEntryStatus } | undefined;
+      if (!row) {
+        result.unknownKeys.push(key);
+      } else if (row.status === SubmissionStatus.COMPLETE) {
+        result.alreadyCompleteKeys.push(key);
+      } else {
+        markComplete.run(row.id);
+        result.matchedIds.push(row.id);
+      }
+    }
+  });
+  tx(keys);
+
+  dbLogger.audit("reconcile-submission-keys", "Reconciled submission keys", {
+    keys: keys.length,
+    matched: result.matchedIds.length,
+    alreadyComplete: result.alreadyCompleteKeys.length,
+    unknown: result.unknownKeys.length,
+  });
+  timer.done({ matched: result.matchedIds.length });
+  return result;
+}
+
+export interface EntryStatusCountsRow {
+  /** Drafts that have never been sent */
+  drafts: number;
+  /** Drafts that were sent before but not accepted (they keep their submission key) */
+  failed: number;
+  /** Entries the current submission is sending */
+  submitting: number;
+}
+
+/**
+ * Counts unsubmitted entries by state in one query (backs the badge and title)
+ */
+export function getEntryStatusCounts(): EntryStatusCountsRow {
+  const db = getDb();
+  const row = prepareCached(
+    db,
+    `
+        SELECT COALESCE(SUM(status IS NULL AND submission_key IS NULL), 0) AS drafts,
+               COALESCE(SUM(status IS NULL AND submission_key IS NOT NULL), 0) AS failed,
+               COALESCE(SUM(status = ${STATUS_SQL.IN_PROGRESS}), 0) AS submitting
+        FROM timesheet
+        WHERE status IS NULL OR status = ${STATUS_SQL.IN_PROGRESS}
+    `
+  ).get() as EntryStatusCountsRow | undefined;
+  return row ?? { drafts: 0, failed: 0, submitting: 0 };
+}
+
+/**
+ * Rows changed through this connection since it opened; when unchanged, no
+ * entry can have changed state
+ */
+export function getDatabaseChangeCount(): number {
+  const db = getDb();
+  const row = prepareCached(db, `SELECT total_changes() AS changes`).get() as
+    | { changes: number }
+    | undefined;
+  return row?.changes ?? 0;
+}
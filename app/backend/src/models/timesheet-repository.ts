@@ -1,4 +1,9 @@
 export * from "@/models/timesheet-repository.types";
 export * from "@/models/timesheet-repository.insert";
 export * from "@/models/timesheet-repository.read";
+export * from "@/models/timesheet-repository.drafts";
 export * from "@/models/timesheet-repository.status";
+export * from "@/models/timesheet-repository.reports";
+export * from "@/models/timesheet-repository.recode";
+export * from "@/models/timesheet-repository.deletions";
+export * from "@/models/timesheet-repository.categories";
@@ -1,3 +1,13 @@
+import { SubmissionStatus, type EntryKind, type TimesheetEntryStatus } from "@sheetpilot/shared";
+
+/**
+ * Quoted status literals for SQL, so status filters always match SubmissionStatus
+ */
+export const STATUS_SQL = {
+  IN_PROGRESS: `'${SubmissionStatus.IN_PROGRESS}'`,
+  COMPLETE: `'${SubmissionStatus.COMPLETE}'`,
+} as const;
+
 export interface TimesheetDedupKey {
   date: string;
   project: string;
@@ -11,6 +21,10 @@ export interface TimesheetBulkInsertEntry {
   tool?: string | null;
   detailChargeCode?: string | null;
   taskDescription: string;
+  /** IANA zone the work was done in, if away from home */
+  timeZone?: string | null;
+  /** Entry category name (see logic/entry-categories.ts) */
+  category?: string | null;
 }
 
 export interface TimesheetDbRow {
@@ -21,8 +35,76 @@ export interface TimesheetDbRow {
   tool?: string | null;
   detail_charge_code?: string | null;
   task_description: string;
-  status?: string | null;
+  status?: TimesheetEntryStatus;
   submitted_at?: string | null;
-  created_at?: string;
-  updated_at?: string;
+  /** Idempotency key typed into the form; assigned on first submission attempt */
+  submission_key?: string | null;
+  /** Submitted entry this draft corrects, if it is an amendment */
+  amends_id?: number | null;
+  /** When the row was first saved (NULL for rows older than the audit columns) */
+  created_at?: string | null;
+  /** Last edit to the entry's fields; status changes do not count */
+  updated_at?: string | null;
+  /** Run history ID of the submission that marked it Complete */
+  submitted_run_id?: number | null;
+  /** Why the form rejected the last submission; cleared on edit or submit */
+  failure_reason?: string | null;
+  /** JSON object of values captured by submit postconditions (e.g. a submission ID) */
+  submission_captures?: string | null;
+  /** IANA zone the work was done in; date and hours are in the home zone */
+  time_zone?: string | null;
+  /** Work order or other reference in an outside system (e.g. the MES) */
+  external_ref?: string | null;
+  /** Local category name; never submitted */
+  category?: string | null;
+  /** pto, holiday or training; NULL (or "work") is work */
+  entry_kind?: string | null;
+}
+
+/** Columns archive list views may select; id and date are always returned */
+export const ARCHIVE_COLUMNS = [
+  "id",
+  "date",
+  "hours",
+  "project",
+  "tool",
+  "detail_charge_code",
+  "task_description",
+  "status",
+  "submitted_at",
+  "submission_key",
+  "amends_id",
+  "created_at",
+  "updated_at",
+  "submitted_run_id",
+  "submission_captures",
+  "time_zone",
+  "external_ref",
+  "category",
+  "entry_kind",
+] as const;
+
+export type ArchiveColumn = (typeof ARCHIVE_COLUMNS)[number];
+
+/**
+ * Editable draft fields; omitted fields are left unchanged on update
+ */
+export interface TimesheetDraftFields {
+  date?: string | undefined;
+  hours?: number | null | undefined;
+  project?: string | undefined;
+  tool?: string | null | undefined;
+  chargeCode?: string | null | undefined;
+  taskDescription?: string | undefined;
+  timeZone?: string | null | undefined;
+  externalRef?: string | null | undefined;
+  category?: string | null | undefined;
+  kind?: EntryKind | null | undefined;
+}
+
+export interface TimesheetDraftSaveResult {
+  changes: number;
+  id: number;
+  /** Row as stored after the save (undefined if it no longer exists) */
+  entry: TimesheetDbRow | undefined;
 }
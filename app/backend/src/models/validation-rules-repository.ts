@@ -0,0 +1,161 @@
+/**
+ * @fileoverview Validation Rules Repository
+ *
+ * Persists user-defined validation rules. Evaluation lives in
+ * logic/validation-rules.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { dbLogger } from "@sheetpilot/shared/logger";
+import type {
+  RuleAction,
+  RuleCondition,
+  RuleField,
+  RuleOperator,
+  ValidationRule,
+} from "@/logic/validation-rules";
+import { getDb } from "./connection-manager";
+
+export interface ValidationRuleRow {
+  id: number;
+  name: string;
+  field: string;
+  operator: string;
+  value: string | null;
+  when_field: string | null;
+  when_operator: string | null;
+  when_value: string | null;
+  action: string;
+  message: string;
+  is_active: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface ValidationRuleInput {
+  name: string;
+  field: RuleField;
+  operator: RuleOperator;
+  value?: string | null | undefined;
+  when?: RuleCondition | null | undefined;
+  action: RuleAction;
+  message: string;
+  isActive?: boolean | undefined;
+}
+
+const toValidationRule = (row: ValidationRuleRow): ValidationRule => ({
+  id: row.id,
+  name: row.name,
+  field: row.field as RuleField,
+  operator: row.operator as RuleOperator,
+  value: row.value,
+  when:
+    row.when_field && row.when_operator
+      ? {
+          field: row.when_field as RuleField,
+          operator: row.when_operator as RuleOperator,
+          value: row.when_value,
+        }
+      : null,
+  action: row.action as RuleAction,
+  message: row.message,
+  isActive: row.is_active === 1,
+});
+
+const toParams = (input: ValidationRuleInput) => [
+  input.name,
+  input.field,
+  input.operator,
+  input.value ?? null,
+  input.when?.field ?? null,
+  input.when?.operator ?? null,
+  input.when?.value ?? null,
+  input.action,
+  input.message,
+  input.isActive === false ? 0 : 1,
+];
+
+/**
+ * Lists validation rules
+ *
+ * @param activeOnly - Only return rules that are switched on
+ */
+export function listValidationRules(activeOnly = false): ValidationRule[] {
+  const db = getDb();
+  const rows = db
+    .prepare(
+      `SELECT * FROM validation_rules ${activeOnly ? "WHERE is_active = 1" : ""} ORDER BY id`
+    )
+    .all() as ValidationRuleRow[];
+  return rows.map(toValidationRule);
+}
+
+/**
+ * Gets a validation rule by ID
+ */
+export function getValidationRule(id: number): ValidationRule | null {
+  const db = getDb();
+  const row = db
+    .prepare(`SELECT * FROM validation_rules WHERE id = ?`)
+    .get(id) as ValidationRuleRow | undefined;
+  return row ? toValidationRule(row) : null;
+}
+
+/**
+ * Creates a validation rule
+ *
+ * @returns ID of the new rule
+ */
+export function createValidationRule(input: ValidationRuleInput): number {
+  const db = getDb();
+  const result = db
+    .prepare(
+      `
+        INSERT INTO validation_rules
+        (name, field, operator, value, when_field, when_operator, when_value, action, message, is_active)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    `
+    )
+    .run(...toParams(input));
+  const id = Number(result.lastInsertRowid);
+  dbLogger.info("Validation rule created", { id, field: input.field, action: input.action });
+  return id;
+}
+
+/**
+ * Replaces a validation rule's definition
+ *
+ * @returns True if the rule existed
+ */
+export function updateValidationRule(id: number, input: ValidationRuleInput): boolean {
+  const db = getDb();
+  const result = db
+    .prepare(
+      `
+        UPDATE validation_rules
+        SET name = ?, field = ?, operator = ?, value = ?,
+            when_field = ?, when_operator = ?, when_value = ?,
+            action = ?, message = ?, is_active = ?, updated_at = datetime('now')
+        WHERE id = ?
+    `
+    )
+    .run(...toParams(input), id);
+  if (result.changes > 0) {
+    dbLogger.info("Validation rule updated", { id });
+  }
+  return result.changes > 0;
+}
+
+/**
+ * Deletes a validation rule
+ */
+export function deleteValidationRule(id: number): boolean {
+  const db = getDb();
+  const result = db
+    .prepare(`DELETE FROM validation_rules WHERE id = ?`)
+    .run(id);
+  return result.changes > 0;
+}
@@ -4,7 +4,169 @@ export const adminBridge = {
   clearCredentials: (token: string): Promise<{ success: boolean; error?: string }> =>
     ipcRenderer.invoke('admin:clearCredentials', token),
   rebuildDatabase: (token: string): Promise<{ success: boolean; error?: string }> =>
-    ipcRenderer.invoke('admin:rebuildDatabase', token)
+    ipcRenderer.invoke('admin:rebuildDatabase', token),
+  runReadonlyQuery: (
+    token: string,
+    sql: string,
+    params?: Array<string | number | null> | Record<string, string | number | null>
+  ): Promise<{
+    success: boolean;
+    columns?: string[];
+    rows?: unknown[][];
+    rowCount?: number;
+    truncated?: boolean;
+    elapsedMs?: number;
+    error?: string;
+  }> => ipcRenderer.invoke('admin:runReadonlyQuery', token, sql, params),
+  checkDataIntegrity: (
+    token: string,
+    repair?: boolean
+  ): Promise<{
+    success: boolean;
+    report?: {
+      checkedAt: string;
+      repairRequested: boolean;
+      issues: Array<{
+        kind: string;
+        description: string;
+        count: number;
+        ids: number[];
+        repairable: boolean;
+        repaired: number;
+      }>;
+      totalIssues: number;
+      totalRepaired: number;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke('admin:checkDataIntegrity', token, repair),
+  bulkRecode: (
+    token: string,
+    request: {
+      field: 'project' | 'chargeCode';
+      fromValue: string;
+      toValue: string;
+      filter?: { startDate?: string; endDate?: string; project?: string };
+      dryRun?: boolean;
+    }
+  ): Promise<{
+    success: boolean;
+    count?: number;
+    entryIds?: number[];
+    historyId?: number | null;
+    error?: string;
+  }> => ipcRenderer.invoke('admin:bulkRecode', token, request),
+  deleteCompletedEntry: (
+    token: string,
+    id: number,
+    reason: string
+  ): Promise<{
+    success: boolean;
+    entry?: {
+      id: number;
+      date: string;
+      hours: number;
+      project: string;
+      tool?: string | null;
+      chargeCode?: string | null;
+      taskDescription: string;
+    };
+    deletionId?: number;
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('admin:deleteCompletedEntry', token, id, reason),
+  purgeExpiredSessions: (token: string): Promise<{ success: boolean; purged?: number; error?: string }> =>
+    ipcRenderer.invoke('admin:purgeExpiredSessions', token),
+  getSessionStats: (
+    token: string
+  ): Promise<{
+    success: boolean;
+    stats?: {
+      total: number;
+      active: number;
+      expired: number;
+      oldestActiveCreatedAt: string | null;
+      users: Array<{
+        email: string;
+        sessions: number;
+        active: number;
+        isAdmin: boolean;
+        oldestActiveCreatedAt: string | null;
+      }>;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke('admin:getSessionStats', token),
+  revokeUserSessions: (
+    token: string,
+    email: string
+  ): Promise<{ success: boolean; revoked?: number; error?: string }> =>
+    ipcRenderer.invoke('admin:revokeUserSessions', token, email),
+  inspectForm: (
+    token: string,
+    request: { baseUrl: string; formId: string }
+  ): Promise<{
+    success: boolean;
+    inspection?: {
+      formUrl: string;
+      formId: string;
+      title: string;
+      inputs: Array<{
+        tag: string;
+        type: string | null;
+        role: string | null;
+        id: string | null;
+        name: string | null;
+        placeholder: string | null;
+        ariaLabel: string | null;
+        ariaLabelledByText: string | null;
+        labelText: string | null;
+        ariaRequired: boolean;
+        required: boolean;
+        label: string;
+        suggestedLocator: string | null;
+        fieldKey: string | null;
+      }>;
+      suggestedFieldOverrides: Record<string, { optional?: boolean; locator?: string }>;
+      missingFields: string[];
+    };
+    error?: string;
+  }> => ipcRenderer.invoke('admin:inspectForm', token, request),
+  lintAutomationConfig: (
+    token: string,
+    checkReachability?: boolean
+  ): Promise<{
+    success: boolean;
+    report?: {
+      ok: boolean;
+      errors: number;
+      warnings: number;
+      findings: Array<{
+        severity: 'error' | 'warning';
+        area: 'selectors' | 'login' | 'fields' | 'quarters';
+        target: string;
+        message: string;
+      }>;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke('admin:lintAutomationConfig', token, checkReachability),
+  listDropdownResolutions: (
+    token: string,
+    fieldKey?: string
+  ): Promise<{
+    success: boolean;
+    resolutions?: Array<{
+      id: number;
+      fieldKey: string;
+      typedValue: string;
+      optionText: string;
+      candidates: string[];
+      confirmedCount: number;
+      firstConfirmedAt: string;
+      lastConfirmedAt: string;
+    }>;
+    error?: string;
+  }> => ipcRenderer.invoke('admin:listDropdownResolutions', token, fieldKey),
+  deleteDropdownResolution: (token: string, id: number): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('admin:deleteDropdownResolution', token, id)
 };
 
 
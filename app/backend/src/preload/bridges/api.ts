@@ -1,7 +1,50 @@
 import { ipcRenderer } from 'electron';
 
-export const apiBridge = {
-  ping: (msg: string): Promise<string> => ipcRenderer.invoke('ping', msg)
+type ClockSkewStatus = {
+  skewMs: number | null;
+  source: 'file-times' | 'http-date' | null;
+  skewed: boolean;
+  thresholdMs: number;
+  checkedAt: string;
+  message: string | null;
+};
+
+type AppHealth = {
+  version: string;
+  database: { connected: boolean };
+  capabilities: {
+    fileDialogs: boolean;
+    downloadsWritable: boolean;
+    exportMode: 'download' | 'string';
+    checkedAt: string;
+  };
+  clock: ClockSkewStatus | null;
 };
 
+type AppEnvironmentInfo = {
+  environment: 'dev' | 'test' | 'prod';
+  source: 'packaged' | 'profile' | 'node-env' | 'default';
+  mockRoutingAllowed: boolean;
+  label: string | null;
+};
 
+export const apiBridge = {
+  ping: (msg: string): Promise<string> => ipcRenderer.invoke('ping', msg),
+  getHealth: (): Promise<{
+    success: boolean;
+    health?: AppHealth;
+    error?: string;
+  }> => ipcRenderer.invoke('app:getHealth'),
+  getEnvironment: (): Promise<{
+    success: boolean;
+    environment?: AppEnvironmentInfo;
+    error?: string;
+  }> => ipcRenderer.invoke('app:getEnvironment'),
+  onClockSkew: (callback: (status: ClockSkewStatus) => void) => {
+    ipcRenderer.removeAllListeners('app:clockSkew');
+    ipcRenderer.on('app:clockSkew', (_event, status) => callback(status));
+  },
+  removeClockSkewListener: (): void => {
+    ipcRenderer.removeAllListeners('app:clockSkew');
+  }
+};
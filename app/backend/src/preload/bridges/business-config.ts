@@ -65,6 +65,8 @@ export const businessConfigBridge = {
       requires_tools?: boolean;
       display_order?: number;
       is_active?: boolean;
+      retired_on?: string | null;
+      successor_project?: string | null;
     }
   ): Promise<{
     success: boolean;
@@ -105,6 +107,8 @@ export const businessConfigBridge = {
       requires_tools?: boolean;
       display_order?: number;
       is_active?: boolean;
+      retired_on?: string | null;
+      successor_project?: string | null;
     }
   ): Promise<{
     success: boolean;
@@ -0,0 +1,44 @@
+import { ipcRenderer } from 'electron';
+
+type DeviceCodePrompt = {
+  userCode: string;
+  verificationUri: string;
+  message: string;
+  expiresAt: string;
+};
+
+type CloudConnectionStatus = {
+  connected: boolean;
+  account: string | null;
+  folderPath: string | null;
+  pendingLogin: DeviceCodePrompt | null;
+  lastError: string | null;
+};
+
+type CloudUploadResponse = {
+  success: boolean;
+  /** Background job performing the upload; its result is the uploaded file */
+  jobId?: string;
+  error?: string;
+};
+
+export const cloudBridge = {
+  getStatus: (): Promise<{ success: boolean; status?: CloudConnectionStatus; error?: string }> =>
+    ipcRenderer.invoke('cloud:getStatus'),
+  connect: (token: string, config: {
+    clientId: string;
+    tenantId: string;
+    folderPath: string;
+  }): Promise<{ success: boolean; prompt?: DeviceCodePrompt; error?: string }> =>
+    ipcRenderer.invoke('cloud:connect', token, config),
+  disconnect: (token: string): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('cloud:disconnect', token),
+  uploadTimesheetExport: (
+    token: string,
+    format?: 'csv' | 'xlsx',
+    templateName?: string
+  ): Promise<CloudUploadResponse & { entryCount?: number }> =>
+    ipcRenderer.invoke('cloud:uploadTimesheetExport', token, format, templateName),
+  uploadLatestLog: (token: string): Promise<CloudUploadResponse> =>
+    ipcRenderer.invoke('cloud:uploadLatestLog', token)
+};
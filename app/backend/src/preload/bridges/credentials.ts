@@ -15,6 +15,26 @@ export const credentialsBridge = {
     credentials: Array<{ id: number; service: string; email: string; created_at: string; updated_at: string }>;
     error?: string;
   }> => ipcRenderer.invoke('credentials:list'),
+  migrationAudit: (): Promise<{
+    success: boolean;
+    keychainAvailable?: boolean;
+    entries?: Array<{
+      service: string;
+      email: string;
+      status: 'migrated' | 'sqlite-only' | 'keychain-only' | 'mismatch';
+    }>;
+    sqliteOnly?: string[];
+    error?: string;
+  }> => ipcRenderer.invoke('credentials:migrationAudit'),
+  reveal: (
+    token: string,
+    service: string
+  ): Promise<{
+    success: boolean;
+    email?: string;
+    password?: string;
+    error?: string;
+  }> => ipcRenderer.invoke('credentials:reveal', token, service),
   delete: (
     service: string
   ): Promise<{
@@ -40,6 +40,78 @@ export const databaseBridge = {
       created_at: string;
       updated_at: string;
     }>;
+    environment?: "dev" | "test";
     error?: string;
   }> => ipcRenderer.invoke("database:getAllArchiveData", token),
+  getArchivePage: (
+    token: string,
+    request?: {
+      cursor?: string;
+      limit?: number;
+      columns?: string[];
+      gzip?: boolean;
+    }
+  ): Promise<{
+    success: boolean;
+    entries?: Array<Record<string, unknown>>;
+    gzipped?: Uint8Array;
+    count?: number;
+    nextCursor?: string | null;
+    totalCount?: number;
+    environment?: "dev" | "test";
+    error?: string;
+  }> => ipcRenderer.invoke("database:getArchivePage", token, request),
+  getSchemaInfo: (
+    token: string
+  ): Promise<{
+    success: boolean;
+    schemaInfo?: {
+      dbPath: string;
+      schemaVersion: number;
+      expectedSchemaVersion: number;
+      isNewerThanApp: boolean;
+      sqliteVersion: string;
+      journalMode: string;
+      tables: Array<{
+        name: string;
+        rowCount: number;
+        columns: Array<{
+          name: string;
+          type: string;
+          notNull: boolean;
+          defaultValue: string | null;
+          primaryKey: boolean;
+        }>;
+        indexes: string[];
+      }>;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke("database:getSchemaInfo", token),
+  getConnectionStats: (
+    token: string
+  ): Promise<{
+    success: boolean;
+    stats?: {
+      busyTimeoutMs: number;
+      slowStatementMs: number;
+      executions: number;
+      totalMs: number;
+      maxMs: number;
+      slowStatements: number;
+      busyTimeouts: number;
+      recentSlow: Array<{ sql: string; durationMs: number; at: string }>;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke("database:getConnectionStats", token),
+  getCompatibilityInfo: (): Promise<{
+    success: boolean;
+    compatibility?: {
+      dbPath: string;
+      schemaVersion: number;
+      appSchemaVersion: number;
+      compatible: boolean;
+      message?: string;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke("database:getCompatibilityInfo"),
 };
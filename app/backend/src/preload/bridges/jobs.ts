@@ -0,0 +1,30 @@
+import { ipcRenderer } from 'electron';
+
+type JobSnapshot = {
+  id: string;
+  type: string;
+  status: 'queued' | 'running' | 'succeeded' | 'failed' | 'cancelled';
+  trigger: 'manual' | 'scheduled';
+  percent: number;
+  message: string | null;
+  result: unknown;
+  error: string | null;
+  runId: number | null;
+  createdAt: string;
+  startedAt: string | null;
+  finishedAt: string | null;
+};
+
+export const jobsBridge = {
+  getStatus: (jobId: string): Promise<{ success: boolean; job?: JobSnapshot; error?: string }> =>
+    ipcRenderer.invoke('jobs:getStatus', jobId),
+  list: (): Promise<{ success: boolean; jobs: JobSnapshot[]; error?: string }> => ipcRenderer.invoke('jobs:list'),
+  cancel: (jobId: string): Promise<{ success: boolean; error?: string }> => ipcRenderer.invoke('jobs:cancel', jobId),
+  onJobProgress: (callback: (job: JobSnapshot) => void) => {
+    ipcRenderer.removeAllListeners('job:progress');
+    ipcRenderer.on('job:progress', (_event, job) => callback(job));
+  },
+  removeJobProgressListener: (): void => {
+    ipcRenderer.removeAllListeners('job:progress');
+  }
+};
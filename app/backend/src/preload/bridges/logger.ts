@@ -1,24 +1,38 @@
 import { ipcRenderer } from 'electron';
 
+/** Structured fields attached to a renderer log entry as top-level metadata */
+export interface FrontendLogFields {
+  category?: string;
+  component?: string;
+  userActionId?: string;
+}
+
+// Only forward structured fields when present so plain log calls keep their original arity
+const sendLog = (channel: string, first: string, data: unknown, fields: FrontendLogFields | undefined): void => {
+  if (fields === undefined) {
+    ipcRenderer.send(channel, first, data);
+    return;
+  }
+  ipcRenderer.send(channel, first, data, fields);
+};
+
 export const loggerBridge = {
-  error: (message: string, data?: unknown): void => {
-    ipcRenderer.send('logger:error', message, data);
+  error: (message: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:error', message, data, fields);
   },
-  warn: (message: string, data?: unknown): void => {
-    ipcRenderer.send('logger:warn', message, data);
+  warn: (message: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:warn', message, data, fields);
   },
-  info: (message: string, data?: unknown): void => {
-    ipcRenderer.send('logger:info', message, data);
+  info: (message: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:info', message, data, fields);
   },
-  verbose: (message: string, data?: unknown): void => {
-    ipcRenderer.send('logger:verbose', message, data);
+  verbose: (message: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:verbose', message, data, fields);
   },
-  debug: (message: string, data?: unknown): void => {
-    ipcRenderer.send('logger:debug', message, data);
+  debug: (message: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:debug', message, data, fields);
   },
-  userAction: (action: string, data?: unknown): void => {
-    ipcRenderer.send('logger:user-action', action, data);
+  userAction: (action: string, data?: unknown, fields?: FrontendLogFields): void => {
+    sendLog('logger:user-action', action, data, fields);
   }
 };
-
-
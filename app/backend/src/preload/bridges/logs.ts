@@ -1,5 +1,44 @@
 import { ipcRenderer } from 'electron';
 
+export interface LogQueryFilter {
+  from?: string;
+  to?: string;
+  levels?: Array<'error' | 'warn' | 'info' | 'verbose' | 'debug' | 'silly'>;
+  runId?: string;
+  search?: string;
+  limit?: number;
+}
+
+export interface LogQueryLine {
+  fileName: string;
+  lineNumber: number;
+  timestamp: string;
+  level: string;
+  runId: string | null;
+  component: string | null;
+  message: string;
+}
+
+export interface CrashReportSummary {
+  id: string;
+  kind: 'uncaught-exception' | 'unhandled-rejection' | 'renderer-gone' | 'child-process-gone';
+  createdAt: string;
+  message: string;
+  appVersion: string;
+}
+
+export interface StartupTimings {
+  phases: Array<{
+    name: string;
+    startMs: number;
+    durationMs: number;
+    deferred: boolean;
+    status: 'ok' | 'failed';
+  }>;
+  windowShownMs: number | null;
+  deferredCompletedMs: number | null;
+}
+
 export const logsBridge = {
   getLogPath: (token: string): Promise<{ success: boolean; logPath?: string; logFiles?: string[]; error?: string }> =>
     ipcRenderer.invoke('logs:getLogPath', token),
@@ -8,7 +47,28 @@ export const logsBridge = {
     logPath: string,
     format: 'json' | 'txt' = 'txt'
   ): Promise<{ success: boolean; content?: string; filename?: string; mimeType?: string; error?: string }> =>
-    ipcRenderer.invoke('logs:exportLogs', token, logPath, format)
+    ipcRenderer.invoke('logs:exportLogs', token, logPath, format),
+  query: (
+    token: string,
+    filter: LogQueryFilter = {}
+  ): Promise<{ success: boolean; source?: 'index' | 'files'; lines?: LogQueryLine[]; error?: string }> =>
+    ipcRenderer.invoke('logs:query', token, filter),
+  listCrashReports: (
+    token: string
+  ): Promise<{ success: boolean; reports?: CrashReportSummary[]; error?: string }> =>
+    ipcRenderer.invoke('crash:listReports', token),
+  exportCrashReports: (
+    token: string
+  ): Promise<{
+    success: boolean;
+    content?: string;
+    filename?: string;
+    mimeType?: string;
+    reportCount?: number;
+    error?: string;
+  }> => ipcRenderer.invoke('crash:exportReports', token),
+  getStartupTimings: (): Promise<{ success: boolean; timings?: StartupTimings; error?: string }> =>
+    ipcRenderer.invoke('logs:getStartupTimings')
 };
 
 
@@ -0,0 +1,22 @@
+import { ipcRenderer } from 'electron';
+
+type UserPreferences = {
+  themeMode?: 'auto' | 'light' | 'dark';
+  weekStart?: 'sunday' | 'monday';
+  hoursRounding?: 0.25 | 0.5 | 1;
+  defaultProject?: string;
+};
+
+type UserPreferencesUpdate = {
+  [K in keyof UserPreferences]?: UserPreferences[K] | null;
+};
+
+export const preferencesBridge = {
+  get: (token: string): Promise<{ success: boolean; preferences?: UserPreferences; error?: string }> =>
+    ipcRenderer.invoke('preferences:get', token),
+  set: (
+    token: string,
+    updates: UserPreferencesUpdate
+  ): Promise<{ success: boolean; preferences?: UserPreferences; error?: string }> =>
+    ipcRenderer.invoke('preferences:set', token, updates)
+};
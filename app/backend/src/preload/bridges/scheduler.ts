@@ -0,0 +1,91 @@
+import { ipcRenderer } from 'electron';
+
+type ExportSchedule = {
+  id: number;
+  name: string;
+  format: 'csv' | 'xlsx';
+  targetDir: string;
+  dayOfMonth: number;
+  isActive: boolean;
+  uploadToCloud: boolean;
+  lastRunAt: string | null;
+  nextRunAt: string | null;
+};
+
+type RunHistoryRecord = {
+  id: number;
+  jobType: string;
+  trigger: 'manual' | 'scheduled';
+  status: 'running' | 'success' | 'failure' | 'cancelled';
+  jobId: string | null;
+  detail: Record<string, unknown> | null;
+  error: string | null;
+  startedAt: string;
+  finishedAt: string | null;
+};
+
+type BackupManifest = {
+  version: 1;
+  host: string;
+  user: string;
+  encryption: string;
+  backups: Array<{
+    file: string;
+    createdAt: string;
+    sizeBytes: number;
+    sha256: string;
+    schemaVersion: number;
+    entryCount: number;
+    verifiedAt: string;
+  }>;
+};
+
+type NetworkBackupStatus = {
+  configured: boolean;
+  keyAvailable: boolean;
+  folder: string | null;
+  manifest: BackupManifest | null;
+  manifestError?: string;
+};
+
+export const schedulerBridge = {
+  listExportSchedules: (): Promise<{ success: boolean; schedules?: ExportSchedule[]; error?: string }> =>
+    ipcRenderer.invoke('scheduler:listExportSchedules'),
+  saveExportSchedule: (schedule: {
+    id?: number;
+    name: string;
+    format: 'csv' | 'xlsx';
+    targetDir: string;
+    dayOfMonth: number;
+    isActive: boolean;
+    uploadToCloud?: boolean;
+  }): Promise<{ success: boolean; id?: number; nextRunAt?: string; error?: string }> =>
+    ipcRenderer.invoke('scheduler:saveExportSchedule', schedule),
+  deleteExportSchedule: (id: number): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('scheduler:deleteExportSchedule', id),
+  runExportScheduleNow: (
+    id: number
+  ): Promise<{ success: boolean; jobId?: string; error?: string }> =>
+    ipcRenderer.invoke('scheduler:runExportScheduleNow', id),
+  getRunHistory: (query?: {
+    jobType?: string;
+    limit?: number;
+  }): Promise<{ success: boolean; runs: RunHistoryRecord[]; error?: string }> =>
+    ipcRenderer.invoke('scheduler:getRunHistory', query),
+  getRunArtifact: (runId: number): Promise<{ success: boolean; artifact?: unknown; error?: string }> =>
+    ipcRenderer.invoke('scheduler:getRunArtifact', runId),
+  exportReproBundle: (
+    runId: number
+  ): Promise<{
+    success: boolean;
+    content?: Uint8Array;
+    filename?: string;
+    mimeType?: string;
+    entryCount?: number;
+    error?: string;
+  }> => ipcRenderer.invoke('scheduler:exportReproBundle', runId),
+  getNetworkBackupStatus: (): Promise<{ success: boolean; status?: NetworkBackupStatus; error?: string }> =>
+    ipcRenderer.invoke('scheduler:getNetworkBackupStatus'),
+  runNetworkBackupNow: (): Promise<{ success: boolean; jobId?: string; error?: string }> =>
+    ipcRenderer.invoke('scheduler:runNetworkBackupNow')
+};
@@ -3,7 +3,15 @@ import { ipcRenderer } from 'electron';
 export const settingsBridge = {
   get: (key: string): Promise<{ success: boolean; value?: unknown; error?: string }> => ipcRenderer.invoke('settings:get', key),
   set: (key: string, value: unknown): Promise<{ success: boolean; error?: string }> => ipcRenderer.invoke('settings:set', key, value),
-  getAll: (): Promise<{ success: boolean; settings?: Record<string, unknown>; error?: string }> => ipcRenderer.invoke('settings:getAll')
+  setProtected: (token: string, key: string, value: unknown): Promise<{ success: boolean; error?: string }> => ipcRenderer.invoke('settings:setProtected', token, key, value),
+  getAll: (): Promise<{ success: boolean; settings?: Record<string, unknown>; error?: string }> => ipcRenderer.invoke('settings:getAll'),
+  onChanged: (callback: (payload: { keys: string[] }) => void) => {
+    ipcRenderer.removeAllListeners('settings:changed');
+    ipcRenderer.on('settings:changed', (_event, payload) => callback(payload));
+  },
+  removeChangedListener: (): void => {
+    ipcRenderer.removeAllListeners('settings:changed');
+  }
 };
 
 
@@ -1,17 +1,239 @@
 import { ipcRenderer } from 'electron';
 
+type BackfillWarning = {
+  id?: number;
+  date: string;
+  state: 'closing' | 'closed' | 'unavailable';
+  quarterId: string | null;
+  lockDate: string | null;
+  message: string;
+};
+
+type SubmissionPlanGroup = {
+  quarterId: string | null;
+  quarterName: string | null;
+  formUrl: string | null;
+  entryIds: number[];
+  hours: number;
+  firstDate: string | null;
+  lastDate: string | null;
+};
+
+type AppLockState = {
+  locked: boolean;
+  reason: 'submission' | null;
+  since: string | null;
+  runId: number | null;
+};
+
+type EntryStatusCounts = {
+  drafts: number;
+  failed: number;
+  submitting: number;
+  queued: number;
+};
+
+type WeekDiffEntry = {
+  id: number;
+  amendsId: number | null;
+  date: string | null;
+  hours: number | null;
+  project: string | null;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string | null;
+};
+
+type WeekDiff = {
+  weekStart: string;
+  weekEnd: string;
+  added: WeekDiffEntry[];
+  removed: Array<{ entry: WeekDiffEntry; draftId: number; movedToDate: string | null }>;
+  modified: Array<{
+    originalId: number;
+    draftId: number;
+    before: WeekDiffEntry;
+    after: WeekDiffEntry;
+    changes: Array<{
+      field: 'date' | 'hours' | 'project' | 'tool' | 'chargeCode' | 'taskDescription';
+      before: string | number | null;
+      after: string | number | null;
+    }>;
+  }>;
+  unchangedCount: number;
+};
+
+type RetiredProjectIssue = {
+  id: number;
+  date: string;
+  project: string;
+  retiredOn: string;
+  successor: string | null;
+  message: string;
+};
+
+type SubmissionStrategyEstimate = {
+  botRuns: number;
+  logins: number;
+  formLoads: number;
+  estimatedMs: number;
+};
+
+type SubmissionEstimate = {
+  entryCount: number;
+  unroutableEntries: number;
+  batched: SubmissionStrategyEstimate;
+  perEntry: SubmissionStrategyEstimate;
+  groups: Array<{ quarterId: string; entryCount: number; estimatedMs: number }>;
+  timing: {
+    basis: 'history' | 'defaults';
+    sampleForms: number;
+    sampleRows: number;
+    rowMs: number;
+    formOverheadMs: number;
+    failureRate: number;
+  };
+};
+
+type ChargeCodeQuotaStatus = {
+  chargeCode: string;
+  quarterId: string;
+  hours: number;
+  submittedHours: number;
+  pendingHours: number;
+  projectedHours: number;
+  remainingHours: number;
+  state: 'ok' | 'near' | 'over';
+};
+
+type DescriptionTemplate = {
+  id: string;
+  name: string;
+  template: string;
+};
+
+type EntryKind = 'work' | 'pto' | 'holiday' | 'training';
+
+type EntryCategory = {
+  id: string;
+  name: string;
+  color: string;
+};
+
+type DescriptionTemplateVars = {
+  project?: string | null;
+  tool?: string | null;
+  date?: string | null;
+  ticket?: string | null;
+};
+
+type SubmissionPlan = {
+  order: 'chronological' | 'newest-first' | 'deadline';
+  groups: SubmissionPlanGroup[];
+  splitAcrossQuarters: boolean;
+};
+
+type QuarterSummary = {
+  id: string;
+  name: string;
+  startDate: string;
+  endDate: string;
+  formUrl: string;
+  formId: string;
+  lockDate: string | null;
+  status: 'configured' | 'placeholder' | 'locked';
+  statusMessage: string | null;
+};
+
+type QuotaWarning = {
+  chargeCode: string;
+  quarterId: string;
+  quotaHours: number;
+  projectedHours: number;
+  entryIds: number[];
+  message: string;
+};
+
+/** Row as persisted, returned by save and delete */
+type PersistedEntry = {
+  id: number;
+  date: string;
+  hours: number;
+  project: string;
+  tool?: string | null;
+  chargeCode?: string | null;
+  taskDescription: string;
+  amendsId?: number;
+  failureReason?: string;
+  timeZone?: string;
+  externalRef?: string;
+  category?: string;
+  /** Left out for work */
+  kind?: EntryKind;
+  /** When the entry's fields were last saved */
+  updatedAt?: string;
+};
+
+type DraftSaveResult = {
+  rowKey: string;
+  success: boolean;
+  changes?: number;
+  id?: number;
+  entry?: PersistedEntry;
+  warning?: BackfillWarning;
+  ruleViolations?: RuleViolation[];
+  error?: string;
+};
+
+type ExportTemplate = {
+  name: string;
+  columns: Array<{ id: string; header?: string }>;
+};
+
+type RuleViolation = {
+  ruleId: number;
+  ruleName: string;
+  entryId?: number;
+  field: 'date' | 'hours' | 'project' | 'tool' | 'chargeCode' | 'taskDescription';
+  action: 'warn' | 'block';
+  message: string;
+};
+
 export const timesheetBridge = {
   submit: (
     token: string,
     useMockWebsite?: boolean
   ): Promise<{
-    submitResult?: { ok: boolean; successCount: number; removedCount: number; totalProcessed: number };
-    dbPath?: string;
+    /** Follow with jobs.getStatus; the result is { submitResult, dbPath } */
+    jobId?: string;
+    environment?: 'dev' | 'test';
     error?: string;
   }> => ipcRenderer.invoke('timesheet:submit', token, useMockWebsite),
   cancel: (): Promise<{ success: boolean; message?: string; error?: string }> => ipcRenderer.invoke('timesheet:cancel'),
+  preflightSubmission: (): Promise<{
+    success: boolean;
+    pendingCount?: number;
+    warnings?: BackfillWarning[];
+    plan?: SubmissionPlan;
+    quotaWarnings?: QuotaWarning[];
+    ruleViolations?: RuleViolation[];
+    retiredProjects?: RetiredProjectIssue[];
+    selectorHotfix?: { id: string; fields: string[]; submitButtonSelectors: number } | null;
+    selectorHotfixError?: string | null;
+    blocked?: boolean;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:preflightSubmission'),
+  planSubmission: (): Promise<{
+    success: boolean;
+    plan?: SubmissionPlan;
+    estimate?: SubmissionEstimate;
+    quotaWarnings?: QuotaWarning[];
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:planSubmission'),
   devSimulateSuccess: (): Promise<{ success: boolean; count?: number; error?: string }> =>
     ipcRenderer.invoke('timesheet:devSimulateSuccess'),
+  devBenchmarkFill: (request: { iterations: number; profiles?: string[] }): Promise<{ success: boolean; jobId?: string; error?: string }> =>
+    ipcRenderer.invoke('timesheet:devBenchmarkFill', request),
   saveDraft: (row: {
     id?: number;
     date?: string;
@@ -20,21 +242,39 @@ export const timesheetBridge = {
     tool?: string | null;
     chargeCode?: string | null;
     taskDescription?: string;
+    timeZone?: string | null;
+    externalRef?: string | null;
+    category?: string | null;
+    kind?: EntryKind | null;
   }): Promise<{
     success: boolean;
     changes?: number;
     id?: number;
-    entry?: {
-      id: number;
-      date: string;
-      hours: number;
-      project: string;
-      tool?: string | null;
-      chargeCode?: string | null;
-      taskDescription: string;
-    };
+    entry?: PersistedEntry;
+    warning?: BackfillWarning;
+    ruleViolations?: RuleViolation[];
     error?: string;
+    /** SUBMISSION_LOCKED while a submission is running */
+    code?: string;
   }> => ipcRenderer.invoke('timesheet:saveDraft', row),
+  markDirty: (
+    rows: Array<{
+      rowKey: string;
+      id?: number;
+      date?: string;
+      hours?: number;
+      project?: string;
+      tool?: string | null;
+      chargeCode?: string | null;
+      taskDescription?: string;
+      timeZone?: string | null;
+      externalRef?: string | null;
+      category?: string | null;
+      kind?: EntryKind | null;
+    }>
+  ): Promise<{ success: boolean; error?: string; code?: string }> => ipcRenderer.invoke('timesheet:markDirty', rows),
+  flushDirty: (): Promise<{ success: boolean; results?: DraftSaveResult[]; error?: string; code?: string }> =>
+    ipcRenderer.invoke('timesheet:flushDirty'),
   loadDraft: (): Promise<{
     success: boolean;
     entries?: Array<{
@@ -45,6 +285,12 @@ export const timesheetBridge = {
       tool?: string | null;
       chargeCode?: string | null;
       taskDescription?: string;
+      amendsId?: number;
+      failureReason?: string;
+      timeZone?: string;
+      externalRef?: string;
+      category?: string;
+      kind?: EntryKind;
     }>;
     error?: string;
   }> => ipcRenderer.invoke('timesheet:loadDraft'),
@@ -63,16 +309,282 @@ export const timesheetBridge = {
     };
     error?: string;
   }> => ipcRenderer.invoke('timesheet:loadDraftById', id),
-  deleteDraft: (id: number): Promise<{ success: boolean; error?: string }> => ipcRenderer.invoke('timesheet:deleteDraft', id),
+  deleteDraft: (
+    id: number
+  ): Promise<{
+    success: boolean;
+    changes?: number;
+    id?: number;
+    /** The row as it was before deletion */
+    entry?: PersistedEntry;
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('timesheet:deleteDraft', id),
+  amendEntry: (
+    id: number
+  ): Promise<{
+    success: boolean;
+    id?: number;
+    entry?: {
+      id: number;
+      date: string;
+      hours?: number;
+      project: string;
+      tool?: string | null;
+      chargeCode?: string | null;
+      taskDescription: string;
+      amendsId?: number;
+    };
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('timesheet:amendEntry', id),
+  reassignRetiredProject: (
+    project: string
+  ): Promise<{
+    success: boolean;
+    successor?: string;
+    ids?: number[];
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('timesheet:reassignRetiredProject', project),
   resetInProgress: (): Promise<{ success: boolean; count?: number; error?: string }> =>
     ipcRenderer.invoke('timesheet:resetInProgress'),
-  exportToCSV: (): Promise<{
+  exportToCSV: (templateName?: string): Promise<{
+    success: boolean;
+    /** Follow with jobs.getStatus; the result is { csvContent, entryCount, totals, filename } */
+    jobId?: string;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:exportToCSV', templateName),
+  exportToClipboard: (request: {
+    startDate: string;
+    endDate: string;
+    format: 'tsv' | 'csv' | 'markdown';
+    includeDrafts?: boolean;
+    templateName?: string;
+  }): Promise<{ success: boolean; entryCount?: number; characters?: number; error?: string }> =>
+    ipcRenderer.invoke('timesheet:exportToClipboard', request),
+  listExportTemplates: (): Promise<{
+    success: boolean;
+    templates?: ExportTemplate[];
+    columns?: Array<{ id: string; header: string }>;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:listExportTemplates'),
+  saveExportTemplate: (
+    template: ExportTemplate
+  ): Promise<{ success: boolean; templates?: ExportTemplate[]; error?: string }> =>
+    ipcRenderer.invoke('timesheet:saveExportTemplate', template),
+  deleteExportTemplate: (templateName: string): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('timesheet:deleteExportTemplate', templateName),
+  listDescriptionTemplates: (): Promise<{
+    success: boolean;
+    templates?: DescriptionTemplate[];
+    variables?: string[];
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:listDescriptionTemplates'),
+  saveDescriptionTemplate: (template: {
+    id?: string;
+    name: string;
+    template: string;
+  }): Promise<{ success: boolean; template?: DescriptionTemplate; error?: string }> =>
+    ipcRenderer.invoke('timesheet:saveDescriptionTemplate', template),
+  deleteDescriptionTemplate: (templateId: string): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('timesheet:deleteDescriptionTemplate', templateId),
+  renderDescription: (
+    templateId: string,
+    vars: DescriptionTemplateVars
+  ): Promise<{ success: boolean; description?: string; missing?: string[]; error?: string }> =>
+    ipcRenderer.invoke('timesheet:renderDescription', templateId, vars),
+  listEntryCategories: (): Promise<{ success: boolean; categories?: EntryCategory[]; error?: string }> =>
+    ipcRenderer.invoke('timesheet:listEntryCategories'),
+  saveEntryCategory: (category: {
+    id?: string;
+    name: string;
+    color?: string;
+  }): Promise<{
+    success: boolean;
+    category?: EntryCategory;
+    renamedEntries?: number;
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('timesheet:saveEntryCategory', category),
+  deleteEntryCategory: (
+    categoryId: string
+  ): Promise<{ success: boolean; clearedEntries?: number; error?: string; code?: string }> =>
+    ipcRenderer.invoke('timesheet:deleteEntryCategory', categoryId),
+  suggestNextEntry: (
+    date: string
+  ): Promise<{
+    success: boolean;
+    suggestion?: {
+      date: string;
+      hours: number;
+      project: string;
+      tool: string | null;
+      chargeCode: string | null;
+      taskDescription: string;
+      confidence: number;
+      occurrences: number;
+      basis: 'follows-previous-block' | 'same-weekday' | 'recent-history';
+    } | null;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:suggestNextEntry', date),
+  previewCalendarImport: (request: {
+    startDate: string;
+    endDate: string;
+    project?: string;
+    tool?: string | null;
+    chargeCode?: string | null;
+    includeCategories?: string[];
+    includeKeywords?: string[];
+    excludeKeywords?: string[];
+    skipFree?: boolean;
+    timeZone?: string;
+  }): Promise<{
+    success: boolean;
+    drafts?: Array<{
+      eventId: string;
+      date: string;
+      hours: number;
+      project: string;
+      tool: string | null;
+      chargeCode: string | null;
+      taskDescription: string;
+      timeZone?: string;
+      isDuplicate: boolean;
+    }>;
+    skipped?: Array<{ eventId: string; subject: string; reason: string }>;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:previewCalendarImport', request),
+  importCalendarDrafts: (
+    drafts: Array<{
+      date: string;
+      hours: number;
+      project: string;
+      tool?: string | null;
+      chargeCode?: string | null;
+      taskDescription: string;
+      timeZone?: string;
+      category?: string | null;
+    }>
+  ): Promise<{
+    success: boolean;
+    /** Follow with jobs.getStatus; the result is { inserted, duplicates } */
+    jobId?: string;
+    error?: string;
+    code?: string;
+  }> => ipcRenderer.invoke('timesheet:importCalendarDrafts', drafts),
+  getToolUsage: (query: {
+    startDate: string;
+    endDate: string;
+    includeDrafts?: boolean;
+  }): Promise<{
+    success: boolean;
+    startDate?: string;
+    endDate?: string;
+    rows?: Array<{ project: string; tool: string | null; hours: number; entryCount: number }>;
+    totalHours?: number;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getToolUsage', query),
+  exportToolUsageCSV: (query: {
+    startDate: string;
+    endDate: string;
+    includeDrafts?: boolean;
+  }): Promise<{
     success: boolean;
     csvContent?: string;
+    rowCount?: number;
+    filename?: string;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:exportToolUsageCSV', query),
+  getQuotaStatus: (): Promise<{
+    success: boolean;
+    quotas?: ChargeCodeQuotaStatus[];
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getQuotaStatus'),
+  getDraftStats: (): Promise<{
+    success: boolean;
+    stats?: {
+      draftCount: number;
+      totalHours: number;
+      datesCovered: number;
+      earliestDate: string | null;
+      latestDate: string | null;
+      undatedCount: number;
+      quarters: Array<{ quarterId: string | null; quarterName: string | null; draftCount: number; hours: number }>;
+    };
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getDraftStats'),
+  getQuarters: (): Promise<{
+    success: boolean;
+    quarters?: QuarterSummary[];
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getQuarters'),
+  getAppLockState: (): Promise<{
+    success: boolean;
+    state?: AppLockState;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getAppLockState'),
+  getStatusCounts: (): Promise<{
+    success: boolean;
+    counts?: EntryStatusCounts;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getStatusCounts'),
+  reconcileSubmissionKeys: (
+    text: string
+  ): Promise<{
+    success: boolean;
+    keysFound?: number;
+    matchedIds?: number[];
+    alreadyCompleteKeys?: string[];
+    unknownKeys?: string[];
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:reconcileSubmissionKeys', text),
+  getActivityHeatmap: (
+    year: number
+  ): Promise<{
+    success: boolean;
+    year?: number;
+    days?: Array<{ date: string; submittedHours: number; draftHours: number }>;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getActivityHeatmap', year),
+  getMissingDays: (query: {
+    startDate: string;
+    endDate: string;
+  }): Promise<{
+    success: boolean;
+    startDate?: string;
+    endDate?: string;
+    dailyTargetHours?: number;
+    days?: Array<{
+      date: string;
+      weekday: number;
+      loggedHours: number;
+      submittedHours: number;
+      draftHours: number;
+      shortfallHours: number;
+      reason: 'no-hours' | 'under-target';
+    }>;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:getMissingDays', query),
+  diffWeek: (
+    weekStart: string
+  ): Promise<{
+    success: boolean;
+    diff?: WeekDiff;
+    error?: string;
+  }> => ipcRenderer.invoke('timesheet:diffWeek', weekStart),
+  exportWeeklySummary: (request: {
+    weekStart: string;
+    format: 'text' | 'markdown';
+    includeDrafts?: boolean;
+  }): Promise<{
+    success: boolean;
+    content?: string;
     entryCount?: number;
+    totalHours?: number;
     filename?: string;
     error?: string;
-  }> => ipcRenderer.invoke('timesheet:exportToCSV'),
+  }> => ipcRenderer.invoke('timesheet:exportWeeklySummary', request),
   onSubmissionProgress: (
     callback: (progress: { percent: number; current: number; total: number; message: string }) => void
   ) => {
@@ -81,6 +593,20 @@ export const timesheetBridge = {
   },
   removeProgressListener: (): void => {
     ipcRenderer.removeAllListeners('timesheet:progress');
+  },
+  onDraftSaved: (callback: (payload: { results: DraftSaveResult[] }) => void) => {
+    ipcRenderer.removeAllListeners('timesheet:draftSaved');
+    ipcRenderer.on('timesheet:draftSaved', (_event, payload) => callback(payload));
+  },
+  removeDraftSavedListener: (): void => {
+    ipcRenderer.removeAllListeners('timesheet:draftSaved');
+  },
+  onStatusCounts: (callback: (counts: EntryStatusCounts) => void) => {
+    ipcRenderer.removeAllListeners('timesheet:statusCounts');
+    ipcRenderer.on('timesheet:statusCounts', (_event, counts) => callback(counts));
+  },
+  removeStatusCountsListener: (): void => {
+    ipcRenderer.removeAllListeners('timesheet:statusCounts');
   }
 };
 
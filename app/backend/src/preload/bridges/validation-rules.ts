@@ -0,0 +1,51 @@
+import { ipcRenderer } from 'electron';
+
+type RuleField = 'date' | 'hours' | 'project' | 'tool' | 'chargeCode' | 'taskDescription';
+type RuleOperator =
+  | 'required'
+  | 'empty'
+  | 'equals'
+  | 'notEquals'
+  | 'startsWith'
+  | 'contains'
+  | 'matches'
+  | 'atMost'
+  | 'atLeast';
+
+type RuleCondition = {
+  field: RuleField;
+  operator: RuleOperator;
+  value?: string | null;
+};
+
+type ValidationRuleInput = RuleCondition & {
+  name: string;
+  when?: RuleCondition | null;
+  action: 'warn' | 'block';
+  message: string;
+  isActive?: boolean;
+};
+
+type ValidationRule = ValidationRuleInput & {
+  id: number;
+  when: RuleCondition | null;
+  isActive: boolean;
+};
+
+export const validationRulesBridge = {
+  list: (): Promise<{ success: boolean; rules?: ValidationRule[]; error?: string }> =>
+    ipcRenderer.invoke('validation-rules:list'),
+  create: (
+    token: string,
+    rule: ValidationRuleInput
+  ): Promise<{ success: boolean; id?: number; error?: string }> =>
+    ipcRenderer.invoke('validation-rules:create', token, rule),
+  update: (
+    token: string,
+    id: number,
+    rule: ValidationRuleInput
+  ): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('validation-rules:update', token, id, rule),
+  delete: (token: string, id: number): Promise<{ success: boolean; error?: string }> =>
+    ipcRenderer.invoke('validation-rules:delete', token, id)
+};
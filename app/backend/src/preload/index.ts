@@ -10,19 +10,47 @@ import { loggerBridge } from './bridges/logger';
 import { updatesBridge } from './bridges/updates';
 import { settingsBridge } from './bridges/settings';
 import { businessConfigBridge } from './bridges/business-config';
+import { schedulerBridge } from './bridges/scheduler';
+import { cloudBridge } from './bridges/cloud';
+import { jobsBridge } from './bridges/jobs';
+import { validationRulesBridge } from './bridges/validation-rules';
+import { preferencesBridge } from './bridges/preferences';
+import {
+  BRIDGE_CAPABILITIES,
+  parseWindowLabel,
+  windowHasCapability
+} from '../logic/ipc-capabilities';
 
+const bridges: Record<string, unknown> = {
+  api: apiBridge,
+  timesheet: timesheetBridge,
+  credentials: credentialsBridge,
+  auth: authBridge,
+  admin: adminBridge,
+  database: databaseBridge,
+  logs: logsBridge,
+  logger: loggerBridge,
+  updates: updatesBridge,
+  settings: settingsBridge,
+  businessConfig: businessConfigBridge,
+  scheduler: schedulerBridge,
+  cloud: cloudBridge,
+  jobs: jobsBridge,
+  validationRules: validationRulesBridge,
+  preferences: preferencesBridge
+};
+
+/**
+ * Exposes the bridges this window's label allows
+ *
+ * Sensitive bridges (credentials, admin, database) are left out of any
+ * window that is not granted them; the main process checks again per call.
+ */
 export function exposePreloadBridges(): void {
-  contextBridge.exposeInMainWorld('api', apiBridge);
-  contextBridge.exposeInMainWorld('timesheet', timesheetBridge);
-  contextBridge.exposeInMainWorld('credentials', credentialsBridge);
-  contextBridge.exposeInMainWorld('auth', authBridge);
-  contextBridge.exposeInMainWorld('admin', adminBridge);
-  contextBridge.exposeInMainWorld('database', databaseBridge);
-  contextBridge.exposeInMainWorld('logs', logsBridge);
-  contextBridge.exposeInMainWorld('logger', loggerBridge);
-  contextBridge.exposeInMainWorld('updates', updatesBridge);
-  contextBridge.exposeInMainWorld('settings', settingsBridge);
-  contextBridge.exposeInMainWorld('businessConfig', businessConfigBridge);
+  const label = parseWindowLabel(process.argv);
+  for (const [name, bridge] of Object.entries(bridges)) {
+    if (windowHasCapability(label, BRIDGE_CAPABILITIES[name] ?? 'core')) {
+      contextBridge.exposeInMainWorld(name, bridge);
+    }
+  }
 }
-
-
@@ -14,10 +14,41 @@ import { isTrustedIpcSender } from './handlers/timesheet/main-window';
 import { 
   validateSession,
   clearAllCredentials,
-  rebuildDatabase
+  rebuildDatabase,
+  runReadonlyQuery,
+  checkDataIntegrity,
+  bulkRecodeSubmittedEntries,
+  deleteCompletedEntry,
+  purgeExpiredSessions,
+  getSessionStats,
+  clearUserSessions,
+  listDropdownResolutions,
+  deleteDropdownResolution,
+  type ReadonlyQueryParams
 } from '@/models';
+import { normalizeDateToISO, toTimesheetRow } from '@sheetpilot/shared';
+import {
+  checkQuarterFormReachability,
+  inspectForm,
+  lintAutomationConfig,
+  summarizeFindings
+} from '@sheetpilot/bot';
+import { isTimesheetSubmissionInProgress } from '@/services/timesheet/submission-workflow';
+import { getAppLockError } from '@/services/timesheet/app-lock';
 import { validateInput } from '@/validation/validate-ipc-input';
-import { adminTokenSchema } from '@/validation/ipc-schemas';
+import {
+  adminTokenSchema,
+  bulkRecodeSchema,
+  dataIntegrityCheckSchema,
+  deleteCompletedEntrySchema,
+  deleteDropdownResolutionSchema,
+  inspectFormSchema,
+  lintAutomationConfigSchema,
+  listDropdownResolutionsSchema,
+  readonlyQuerySchema,
+  revokeUserSessionsSchema
+} from '@/validation/ipc-schemas';
+import { getMessage } from '@/services/i18n/message-catalog';
 
 /**
  * Register all admin-related IPC handlers
@@ -26,7 +57,7 @@ export function registerAdminHandlers(): void {
   
   // Handler for admin to clear all credentials
   ipcMain.handle('admin:clearCredentials', async (event, token: string) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, 'admin:clearCredentials')) {
       return { success: false, error: 'Could not clear credentials: unauthorized request' };
     }
     // Validate input using Zod schema
@@ -42,7 +73,7 @@ export function registerAdminHandlers(): void {
       ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
         token: validatedData.token.substring(0, 8) + '...' 
       });
-      return { success: false, error: 'Unauthorized: Admin access required' };
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
     }
 
     ipcLogger.audit('admin-clear-credentials', 'Admin clearing all credentials', { email: session.email });
@@ -59,7 +90,7 @@ export function registerAdminHandlers(): void {
 
   // Handler for admin to rebuild database
   ipcMain.handle('admin:rebuildDatabase', async (event, token: string) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, 'admin:rebuildDatabase')) {
       return { success: false, error: 'Could not rebuild database: unauthorized request' };
     }
     // Validate input using Zod schema
@@ -75,7 +106,7 @@ export function registerAdminHandlers(): void {
       ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
         token: validatedData.token.substring(0, 8) + '...' 
       });
-      return { success: false, error: 'Unauthorized: Admin access required' };
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
     }
 
     ipcLogger.audit('admin-rebuild-database', 'Admin rebuilding database', { email: session.email });
@@ -89,6 +120,436 @@ export function registerAdminHandlers(): void {
       return { success: false, error: err instanceof Error ? err.message : String(err) };
     }
   });
-}
 
+  // Handler for admin to run an ad-hoc read-only report query
+  ipcMain.handle('admin:runReadonlyQuery', async (event, token: string, sql: string, params?: ReadonlyQueryParams) => {
+    if (!isTrustedIpcSender(event, 'admin:runReadonlyQuery')) {
+      return { success: false, error: 'Could not run query: unauthorized request' };
+    }
+    const validation = validateInput(readonlyQuerySchema, { token, sql, params }, 'admin:runReadonlyQuery');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    ipcLogger.audit('admin-readonly-query', 'Admin running read-only query', {
+      email: session.email,
+      sql: validatedData.sql.substring(0, 500)
+    });
+
+    try {
+      const result = runReadonlyQuery(validatedData.sql, validatedData.params ?? []);
+      return { success: true, ...result };
+    } catch (err: unknown) {
+      ipcLogger.warn('Read-only query failed', {
+        email: session.email,
+        error: err instanceof Error ? err.message : String(err)
+      });
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to scan for (and optionally repair) data integrity issues
+  ipcMain.handle('admin:checkDataIntegrity', async (event, token: string, repair?: boolean) => {
+    if (!isTrustedIpcSender(event, 'admin:checkDataIntegrity')) {
+      return { success: false, error: 'Could not check data integrity: unauthorized request' };
+    }
+    const validation = validateInput(dataIntegrityCheckSchema, { token, repair }, 'admin:checkDataIntegrity');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    const repairRequested = validatedData.repair ?? false;
+    ipcLogger.audit('admin-check-integrity', 'Admin checking data integrity', {
+      email: session.email,
+      repair: repairRequested
+    });
+
+    try {
+      const report = checkDataIntegrity({
+        repair: repairRequested,
+        submissionInProgress: isTimesheetSubmissionInProgress()
+      });
+      ipcLogger.info('Data integrity check completed', {
+        email: session.email,
+        totalIssues: report.totalIssues,
+        totalRepaired: report.totalRepaired
+      });
+      return { success: true, report };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not check data integrity', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to rename a project or charge code on submitted entries
+  ipcMain.handle('admin:bulkRecode', async (event, token: string, request: unknown) => {
+    if (!isTrustedIpcSender(event, 'admin:bulkRecode')) {
+      return { success: false, error: 'Could not recode entries: unauthorized request' };
+    }
+    const validation = validateInput(bulkRecodeSchema, { token, request }, 'admin:bulkRecode');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    const { field, fromValue, toValue, filter, dryRun } = validatedData.request;
+    ipcLogger.audit('admin-bulk-recode', 'Admin recoding submitted entries', {
+      email: session.email,
+      field,
+      fromValue,
+      toValue,
+      filter,
+      dryRun: dryRun ?? false
+    });
+
+    if (!dryRun && isTimesheetSubmissionInProgress()) {
+      return { success: false, error: getMessage('RECODE_DURING_SUBMISSION') };
+    }
+
+    try {
+      const result = bulkRecodeSubmittedEntries({
+        field,
+        fromValue,
+        toValue,
+        filter: {
+          ...(filter?.startDate ? { startDate: normalizeDateToISO(filter.startDate) } : {}),
+          ...(filter?.endDate ? { endDate: normalizeDateToISO(filter.endDate) } : {}),
+          ...(filter?.project ? { project: filter.project } : {})
+        },
+        dryRun: dryRun ?? false,
+        performedBy: session.email
+      });
+      ipcLogger.info(dryRun ? 'Bulk recode preview computed' : 'Submitted entries recoded by admin', {
+        email: session.email,
+        field,
+        count: result.entryIds.length
+      });
+      return { success: true, count: result.entryIds.length, ...result };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not recode entries', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to remove a submitted entry payroll has already corrected
+  ipcMain.handle('admin:deleteCompletedEntry', async (event, token: string, id: number, reason: string) => {
+    if (!isTrustedIpcSender(event, 'admin:deleteCompletedEntry')) {
+      return { success: false, error: 'Could not delete submitted entry: unauthorized request' };
+    }
+    const validation = validateInput(deleteCompletedEntrySchema, { token, id, reason }, 'admin:deleteCompletedEntry');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    ipcLogger.audit('admin-delete-completed-entry', 'Admin deleting submitted entry', {
+      email: session.email,
+      id: validatedData.id,
+      reason: validatedData.reason
+    });
+
+    const locked = getAppLockError('admin:deleteCompletedEntry');
+    if (locked) {
+      return { success: false, error: locked.message, code: locked.code };
+    }
+
+    try {
+      const result = deleteCompletedEntry({
+        id: validatedData.id,
+        reason: validatedData.reason,
+        performedBy: session.email
+      });
+      ipcLogger.info('Submitted entry deleted by admin', {
+        email: session.email,
+        id: validatedData.id,
+        deletionId: result.deletionId
+      });
+      return { success: true, entry: toTimesheetRow(result.entry), deletionId: result.deletionId };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not delete submitted entry', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to delete expired and abandoned sessions
+  ipcMain.handle('admin:purgeExpiredSessions', async (event, token: string) => {
+    if (!isTrustedIpcSender(event, 'admin:purgeExpiredSessions')) {
+      return { success: false, error: 'Could not purge sessions: unauthorized request' };
+    }
+    const validation = validateInput(adminTokenSchema, { token }, 'admin:purgeExpiredSessions');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    ipcLogger.audit('admin-purge-sessions', 'Admin purging expired sessions', { email: session.email });
+
+    try {
+      const purged = purgeExpiredSessions();
+      ipcLogger.info('Expired sessions purged by admin', { email: session.email, purged });
+      return { success: true, purged };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not purge expired sessions', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to see how many sessions each user has
+  ipcMain.handle('admin:getSessionStats', async (event, token: string) => {
+    if (!isTrustedIpcSender(event, 'admin:getSessionStats')) {
+      return { success: false, error: 'Could not load session stats: unauthorized request' };
+    }
+    const validation = validateInput(adminTokenSchema, { token }, 'admin:getSessionStats');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
 
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    try {
+      return { success: true, stats: getSessionStats() };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not load session stats', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to sign a user out everywhere on this machine
+  ipcMain.handle('admin:revokeUserSessions', async (event, token: string, email: string) => {
+    if (!isTrustedIpcSender(event, 'admin:revokeUserSessions')) {
+      return { success: false, error: 'Could not revoke sessions: unauthorized request' };
+    }
+    const validation = validateInput(revokeUserSessionsSchema, { token, email }, 'admin:revokeUserSessions');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    ipcLogger.audit('admin-revoke-user-sessions', 'Admin revoking all sessions for a user', {
+      email: session.email,
+      target: validatedData.email
+    });
+
+    try {
+      const revoked = clearUserSessions(validatedData.email);
+      return { success: true, revoked };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not revoke user sessions', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to list a new form's inputs when building its field mapping
+  ipcMain.handle('admin:inspectForm', async (event, token: string, request: unknown) => {
+    if (!isTrustedIpcSender(event, 'admin:inspectForm')) {
+      return { success: false, error: 'Could not inspect form: unauthorized request' };
+    }
+    const validation = validateInput(inspectFormSchema, { token, request }, 'admin:inspectForm');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    const { baseUrl, formId } = validatedData.request;
+    ipcLogger.audit('admin-inspect-form', 'Admin inspecting form for field mapping', {
+      email: session.email,
+      baseUrl,
+      formId
+    });
+
+    try {
+      const inspection = await inspectForm(baseUrl, formId);
+      return { success: true, inspection };
+    } catch (err: unknown) {
+      ipcLogger.warn('Could not inspect form', {
+        email: session.email,
+        formId,
+        error: err instanceof Error ? err.message : String(err)
+      });
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to check the automation configuration before it ships
+  ipcMain.handle('admin:lintAutomationConfig', async (event, token: string, checkReachability?: boolean) => {
+    if (!isTrustedIpcSender(event, 'admin:lintAutomationConfig')) {
+      return { success: false, error: 'Could not lint automation config: unauthorized request' };
+    }
+    const validation = validateInput(
+      lintAutomationConfigSchema,
+      { token, checkReachability },
+      'admin:lintAutomationConfig'
+    );
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    try {
+      let report = lintAutomationConfig();
+      if (validatedData.checkReachability) {
+        report = summarizeFindings([...report.findings, ...(await checkQuarterFormReachability())]);
+      }
+      ipcLogger.info('Automation config linted', {
+        email: session.email,
+        errors: report.errors,
+        warnings: report.warnings,
+        checkReachability: Boolean(validatedData.checkReachability)
+      });
+      return { success: true, report };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not lint automation config', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to review options the bot remembered for ambiguous dropdown matches
+  ipcMain.handle('admin:listDropdownResolutions', async (event, token: string, fieldKey?: string) => {
+    if (!isTrustedIpcSender(event, 'admin:listDropdownResolutions')) {
+      return { success: false, error: 'Could not load dropdown resolutions: unauthorized request' };
+    }
+    const validation = validateInput(
+      listDropdownResolutionsSchema,
+      { token, fieldKey },
+      'admin:listDropdownResolutions'
+    );
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    try {
+      return { success: true, resolutions: listDropdownResolutions(validatedData.fieldKey) };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not load dropdown resolutions', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  // Handler for admin to forget a wrong dropdown resolution
+  ipcMain.handle('admin:deleteDropdownResolution', async (event, token: string, id: number) => {
+    if (!isTrustedIpcSender(event, 'admin:deleteDropdownResolution')) {
+      return { success: false, error: 'Could not delete dropdown resolution: unauthorized request' };
+    }
+    const validation = validateInput(deleteDropdownResolutionSchema, { token, id }, 'admin:deleteDropdownResolution');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', { 
+        token: validatedData.token.substring(0, 8) + '...' 
+      });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+
+    ipcLogger.audit('admin-delete-dropdown-resolution', 'Admin deleting dropdown resolution', {
+      email: session.email,
+      id: validatedData.id
+    });
+
+    try {
+      const deleted = deleteDropdownResolution(validatedData.id);
+      return deleted
+        ? { success: true }
+        : { success: false, error: `Dropdown resolution ${validatedData.id} not found` };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not delete dropdown resolution', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+}
@@ -0,0 +1,55 @@
+/**
+ * @fileoverview App IPC Handlers
+ *
+ * Reports app health: version, database connection and the platform
+ * capabilities (file dialogs, Downloads folder) that decide how exports are
+ * delivered on locked-down builds, and the environment (dev/test/prod) the
+ * app runs in.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getAppHealth } from '@/services/health/app-health';
+import { getAppEnvironment } from '@/services/health/environment';
+import { isTrustedIpcSender } from './handlers/timesheet/main-window';
+
+/**
+ * Register app-level IPC handlers
+ */
+export function registerAppHandlers(): void {
+  ipcMain.handle('app:getHealth', async (event) => {
+    if (!isTrustedIpcSender(event, 'app:getHealth')) {
+      return { success: false, error: 'Could not get app health: unauthorized request' };
+    }
+    try {
+      const health = getAppHealth();
+      ipcLogger.verbose('App health reported', {
+        databaseConnected: health.database.connected,
+        exportMode: health.capabilities.exportMode
+      });
+      return { success: true, health };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get app health', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('app:getEnvironment', async (event) => {
+    if (!isTrustedIpcSender(event, 'app:getEnvironment')) {
+      return { success: false, error: 'Could not get environment: unauthorized request' };
+    }
+    try {
+      return { success: true, environment: getAppEnvironment() };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get environment', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcLogger.verbose('Registered handler: app:getHealth');
+  ipcLogger.verbose('Registered handler: app:getEnvironment');
+}
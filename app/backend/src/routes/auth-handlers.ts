@@ -13,9 +13,11 @@ import { ipcLogger, appLogger } from '@sheetpilot/shared/logger';
 import { isTrustedIpcSender } from './handlers/timesheet/main-window';
 import {
   createSession,
+  rotateSession,
   validateSession,
   clearSession,
   clearUserSessions,
+  unlockCredentialVault,
 } from '@/models';
 import { validateInput } from '@/validation/validate-ipc-input';
 import {
@@ -30,6 +32,8 @@ import {
   isAdminLogin,
   type LoginResponse,
 } from './auth-helpers';
+import { checkEmailDomain } from '@/services/security/email-domain-policy';
+import { resolveDirectoryEmail } from '@/services/security/directory-lookup';
 
 // Admin credentials from environment variables
 // For production: Set SHEETPILOT_ADMIN_USERNAME and SHEETPILOT_ADMIN_PASSWORD to override defaults
@@ -82,7 +86,7 @@ export function registerAuthHandlers(): void {
         return buildLoginError(validation.error);
       }
 
-      const validatedData = validation.data;
+      let validatedData = validation.data;
       ipcLogger.audit('login-attempt', 'User attempting login', {
         email: validatedData.email,
       });
@@ -94,17 +98,43 @@ export function registerAuthHandlers(): void {
           ADMIN_PASSWORD
         );
         if (!isAdmin) {
-          const credentialError = ensureUserCredentials(validatedData);
+          const domainError = checkEmailDomain(validatedData.email);
+          if (domainError) {
+            return buildLoginError(domainError);
+          }
+          const directory = await resolveDirectoryEmail(validatedData.email);
+          if (directory.error) {
+            return buildLoginError(directory.error);
+          }
+          const enteredEmail = validatedData.email;
+          validatedData = { ...validatedData, email: directory.email };
+          const credentialError = ensureUserCredentials(validatedData, enteredEmail);
           if (credentialError) {
             return buildLoginError(credentialError);
           }
         }
 
-        const sessionToken = createSession(
-          validatedData.email,
-          validatedData.stayLoggedIn,
-          isAdmin
-        );
+        // Admin login is a privilege elevation: rotate instead of adding a
+        // token alongside any that were issued before
+        const sessionToken = isAdmin
+          ? rotateSession(
+              validatedData.email,
+              validatedData.stayLoggedIn,
+              true
+            )
+          : createSession(
+              validatedData.email,
+              validatedData.stayLoggedIn,
+              false
+            );
+        if (isAdmin) {
+          ipcLogger.audit('session-rotated', 'Session rotated on admin login', {
+            email: validatedData.email,
+          });
+        }
+
+        // Re-authentication reopens a vault locked by the security policy
+        unlockCredentialVault();
 
         ipcLogger.info('Login successful', {
           email: validatedData.email,
@@ -81,12 +81,33 @@ const getPasswordMismatchError = (
   return "Incorrect password. Please try again.";
 };
 
+const storeNewUserCredentials = (payload: LoginPayload): string | null => {
+  ipcLogger.verbose("Storing credentials for new user", {
+    email: payload.email,
+  });
+  const storeResult = storeCredentials(
+    "smartsheet",
+    payload.email,
+    payload.password
+  );
+  if (!storeResult.success) {
+    return storeResult.message;
+  }
+  return null;
+};
+
 const validateReturningUser = (
   storedEmail: string,
   storedPassword: string,
-  payload: LoginPayload
+  payload: LoginPayload,
+  enteredEmail: string
 ): string | null => {
-  const emailError = getCredentialMismatchError(storedEmail, payload.email);
+  // Credentials stored under the alias the user typed still match after the
+  // directory lookup replaced it with the sign-in name
+  const storedUnderAlias = storedEmail !== payload.email && storedEmail === enteredEmail;
+  const emailError = storedUnderAlias
+    ? null
+    : getCredentialMismatchError(storedEmail, payload.email);
   if (emailError) {
     return emailError;
   }
@@ -101,31 +122,37 @@ const validateReturningUser = (
   ipcLogger.verbose("Password verified for returning user", {
     email: payload.email,
   });
-  return null;
-};
-
-const storeNewUserCredentials = (payload: LoginPayload): string | null => {
-  ipcLogger.verbose("Storing credentials for new user", {
-    email: payload.email,
-  });
-  const storeResult = storeCredentials(
-    "smartsheet",
-    payload.email,
-    payload.password
-  );
-  if (!storeResult.success) {
-    return storeResult.message;
+  if (storedUnderAlias) {
+    ipcLogger.info("Moving stored credentials to the directory sign-in name", {
+      storedEmail,
+      email: payload.email,
+    });
+    return storeNewUserCredentials(payload);
   }
   return null;
 };
 
-export const ensureUserCredentials = (payload: LoginPayload): string | null => {
-  const existingCredentials = getCredentials("smartsheet");
+/**
+ * Checks the login against stored Smartsheet credentials, storing them on first login
+ *
+ * @param enteredEmail - Email as typed, when `payload.email` was replaced by the directory lookup
+ */
+export const ensureUserCredentials = (
+  payload: LoginPayload,
+  enteredEmail: string = payload.email
+): string | null => {
+  // Login is how a locked vault is reopened, so it must see stored credentials
+  const existingCredentials = getCredentials("smartsheet", {
+    caller: "auth:login",
+    purpose: "validation",
+    ignoreVaultLock: true,
+  });
   if (existingCredentials) {
     return validateReturningUser(
       existingCredentials.email,
       existingCredentials.password,
-      payload
+      payload,
+      enteredEmail
     );
   }
   return storeNewUserCredentials(payload);
@@ -1,5 +1,6 @@
 import { ipcLogger } from "@sheetpilot/shared/logger";
 import { validateSession } from "@/models";
+import { getMessage } from "@/services/i18n/message-catalog";
 
 type SessionResult = ReturnType<typeof validateSession>;
 
@@ -19,7 +20,7 @@ export const requireAdminSession = (
         token: token.substring(0, 8) + "...",
       }
     );
-    return { ok: false, response: { success: false, error: "Unauthorized: Admin access required" } };
+    return { ok: false, response: { success: false, error: getMessage("ADMIN_REQUIRED") } };
   }
   return { ok: true, session };
 };
@@ -0,0 +1,242 @@
+/**
+ * @fileoverview Cloud Upload IPC Handlers
+ *
+ * Handles IPC communication for connecting OneDrive/SharePoint via Microsoft
+ * Graph and uploading exports and log files to the team archive folder.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain, app } from "electron";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import {
+  getSubmittedTimesheetEntriesForExport,
+  validateSession,
+} from "@/models";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  cloudConnectSchema,
+  type CloudConnect,
+} from "@/validation/ipc-schemas";
+import {
+  disconnectGraph,
+  getGraphConnectionStatus,
+  startGraphDeviceLogin,
+} from "@/services/cloud/graph-auth";
+import { enqueueCloudUpload } from "@/services/cloud/graph-upload";
+import {
+  buildTimesheetCsv,
+  getExportHeaders,
+  toExportValues,
+} from "@/services/timesheet/csv-export";
+import { resolveExportTemplate } from "@/services/timesheet/export-templates";
+import { buildXlsxWorkbook } from "@/services/timesheet/xlsx-export";
+import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+import { getMessage } from "@/services/i18n/message-catalog";
+
+const writeTempExport = (
+  format: "csv" | "xlsx",
+  templateName?: string
+): {
+  filePath: string;
+  entryCount: number;
+} => {
+  const template = resolveExportTemplate(templateName);
+  const entries = getSubmittedTimesheetEntriesForExport();
+  if (entries.length === 0) {
+    throw new Error("No submitted timesheet entries found to export");
+  }
+  const dateStamp = new Date().toISOString().split("T")[0];
+  const filePath = path.join(
+    fs.mkdtempSync(path.join(os.tmpdir(), "sheetpilot-upload-")),
+    `timesheet_export_${dateStamp}.${format}`
+  );
+  if (format === "xlsx") {
+    const rows = entries.map((entry) => toExportValues(entry, template));
+    fs.writeFileSync(
+      filePath,
+      buildXlsxWorkbook("Timesheet", getExportHeaders(template), rows)
+    );
+  } else {
+    fs.writeFileSync(filePath, buildTimesheetCsv(entries, template), "utf-8");
+  }
+  return { filePath, entryCount: entries.length };
+};
+
+const findLatestLogFile = async (): Promise<string | null> => {
+  const userDataPath = app.getPath("userData");
+  const logFiles = (await fs.promises.readdir(userDataPath))
+    .filter((file) => file.startsWith("sheetpilot_") && file.endsWith(".log"))
+    .sort();
+  const latest = logFiles[logFiles.length - 1];
+  return latest ? path.join(userDataPath, latest) : null;
+};
+
+/**
+ * Register all cloud upload IPC handlers
+ */
+export function registerCloudHandlers(): void {
+  ipcMain.handle("cloud:getStatus", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not get cloud status: unauthorized request",
+      };
+    }
+    try {
+      return { success: true, status: getGraphConnectionStatus() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get cloud status", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "cloud:connect",
+    async (event, token: string, config: CloudConnect) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not connect cloud storage: unauthorized request",
+        };
+      }
+      const session = token ? validateSession(token) : null;
+      if (!session?.valid) {
+        return {
+          success: false,
+          error: getMessage("SESSION_INVALID"),
+        };
+      }
+      const validation = validateInput(
+        cloudConnectSchema,
+        config,
+        "cloud:connect"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const prompt = await startGraphDeviceLogin(validation.data!);
+        ipcLogger.audit("cloud-connect", "Microsoft Graph sign-in started", {
+          email: session.email,
+          tenantId: validation.data!.tenantId,
+          folderPath: validation.data!.folderPath,
+        });
+        return { success: true, prompt };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not start Microsoft Graph sign-in", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle("cloud:disconnect", async (event, token: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not disconnect cloud storage: unauthorized request",
+      };
+    }
+    const session = token ? validateSession(token) : null;
+    if (!session?.valid) {
+      return {
+        success: false,
+        error: getMessage("SESSION_INVALID"),
+      };
+    }
+    try {
+      disconnectGraph();
+      ipcLogger.audit("cloud-disconnect", "Microsoft Graph disconnected", {
+        email: session.email,
+      });
+      return { success: true };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not disconnect Microsoft Graph", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "cloud:uploadTimesheetExport",
+    async (
+      event,
+      token: string,
+      format: "csv" | "xlsx" = "csv",
+      templateName?: string
+    ) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not upload export: unauthorized request",
+        };
+      }
+      if (!token || !validateSession(token).valid) {
+        return {
+          success: false,
+          error: getMessage("SESSION_INVALID"),
+        };
+      }
+      if (format !== "csv" && format !== "xlsx") {
+        return { success: false, error: "Export format must be csv or xlsx" };
+      }
+      if (templateName !== undefined && typeof templateName !== "string") {
+        return { success: false, error: "Template name must be a string" };
+      }
+      try {
+        const { filePath, entryCount } = writeTempExport(format, templateName);
+        const jobId = enqueueCloudUpload(filePath, "manual", () => {
+          fs.rmSync(path.dirname(filePath), { recursive: true, force: true });
+        });
+        return { success: true, jobId, entryCount };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not upload timesheet export", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle("cloud:uploadLatestLog", async (event, token: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not upload log: unauthorized request",
+      };
+    }
+    if (!token || !validateSession(token).valid) {
+      return {
+        success: false,
+        error: getMessage("SESSION_INVALID"),
+      };
+    }
+    try {
+      const logPath = await findLatestLogFile();
+      if (!logPath) {
+        return { success: false, error: "No log files found" };
+      }
+      return { success: true, jobId: enqueueCloudUpload(logPath, "manual") };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not upload log file", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+}
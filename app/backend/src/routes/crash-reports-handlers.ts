@@ -0,0 +1,111 @@
+/**
+ * @fileoverview Crash Reports IPC Handlers
+ *
+ * Lists crash reports saved after main-process or renderer crashes and
+ * exports them (with native minidump locations) as one JSON file for support.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain, app } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import { validateSession } from "@/models";
+import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+import {
+  listCrashReports,
+  listMinidumps,
+} from "@/bootstrap/crash-handlers/crash-reports";
+import { getMessage } from "@/services/i18n/message-catalog";
+
+const getSessionError = (token: string, actionLabel: string): string | null => {
+  if (!token) {
+    return `Session token is required. Please log in to ${actionLabel}.`;
+  }
+  if (!validateSession(token).valid) {
+    return getMessage("SESSION_INVALID");
+  }
+  return null;
+};
+
+/**
+ * Register all crash report IPC handlers
+ */
+export function registerCrashReportsHandlers(): void {
+  // Handler for listing saved crash reports (summaries only)
+  ipcMain.handle("crash:listReports", async (event, token: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list crash reports: unauthorized request",
+      };
+    }
+
+    const sessionError = getSessionError(token, "view crash reports");
+    if (sessionError) {
+      return { success: false, error: sessionError };
+    }
+
+    try {
+      const reports = listCrashReports(app).map((report) => ({
+        id: report.id,
+        kind: report.kind,
+        createdAt: report.createdAt,
+        message: report.message,
+        appVersion: report.appVersion,
+      }));
+      return { success: true, reports };
+    } catch (err: unknown) {
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  // Handler for exporting all crash reports as a single JSON document
+  ipcMain.handle("crash:exportReports", async (event, token: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not export crash reports: unauthorized request",
+      };
+    }
+
+    const sessionError = getSessionError(token, "export crash reports");
+    if (sessionError) {
+      return { success: false, error: sessionError };
+    }
+
+    try {
+      const reports = listCrashReports(app);
+      const minidumps = listMinidumps(app);
+      const content = JSON.stringify(
+        {
+          exportedAt: new Date().toISOString(),
+          appVersion: app.getVersion(),
+          platform: process.platform,
+          reports,
+          minidumps,
+        },
+        null,
+        2
+      );
+      ipcLogger.audit("export-crash-reports", "Crash reports exported", {
+        reportCount: reports.length,
+        minidumpCount: minidumps.length,
+      });
+
+      const dateStamp = new Date().toISOString().split("T")[0];
+      return {
+        success: true,
+        content,
+        filename: `sheetpilot_crash_reports_${dateStamp}.json`,
+        mimeType: "application/json",
+        reportCount: reports.length,
+      };
+    } catch (err: unknown) {
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+}
@@ -14,14 +14,24 @@ import { isTrustedIpcSender } from './handlers/timesheet/main-window';
 import { 
   storeCredentials, 
   listCredentials, 
-  deleteCredentials 
+  deleteCredentials,
+  getCredentials,
+  auditCredentialMigration,
+  validateSession
 } from '@/models';
 import { CredentialsStorageError } from '@sheetpilot/shared/errors';
 import { validateInput } from '@/validation/validate-ipc-input';
 import { 
   storeCredentialsSchema,
-  deleteCredentialsSchema 
+  deleteCredentialsSchema,
+  revealCredentialsSchema
 } from '@/validation/ipc-schemas';
+import { requestOsReauth } from '@/services/security/os-reauth';
+import { resolveSecurityPolicy } from '@/services/security/security-policy';
+import { loadSettings } from '@/services/settings/settings-store';
+import { checkEmailDomain } from '@/services/security/email-domain-policy';
+import { resolveDirectoryEmail } from '@/services/security/directory-lookup';
+import { getMessage } from '@/services/i18n/message-catalog';
 
 /**
  * Register all credentials-related IPC handlers
@@ -30,7 +40,7 @@ export function registerCredentialsHandlers(): void {
   
   // Handler for storing credentials
   ipcMain.handle('credentials:store', async (event, service: string, email: string, password: string) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, 'credentials:store')) {
       return { success: false, message: 'Could not store credentials: unauthorized request', changes: 0 };
     }
 
@@ -40,7 +50,15 @@ export function registerCredentialsHandlers(): void {
       return { success: false, message: validation.error, changes: 0 };
     }
     
-    const validatedData = validation.data!;
+    const domainError = checkEmailDomain(validation.data!.email);
+    if (domainError) {
+      return { success: false, message: domainError, changes: 0 };
+    }
+    const directory = await resolveDirectoryEmail(validation.data!.email);
+    if (directory.error) {
+      return { success: false, message: directory.error, changes: 0 };
+    }
+    const validatedData = { ...validation.data!, email: directory.email };
     ipcLogger.audit('store-credentials', 'User storing credentials', { service: validatedData.service, email: validatedData.email });
     
     try {
@@ -66,7 +84,7 @@ export function registerCredentialsHandlers(): void {
 
   // Handler for listing credentials
   ipcMain.handle('credentials:list', async (event) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, 'credentials:list')) {
       return { success: false, error: 'Could not list credentials: unauthorized request', credentials: [] };
     }
     try {
@@ -78,9 +96,77 @@ export function registerCredentialsHandlers(): void {
     }
   });
 
+  // Handler for the keychain migration audit (which credentials still live only in SQLite)
+  ipcMain.handle('credentials:migrationAudit', async (event) => {
+    if (!isTrustedIpcSender(event, 'credentials:migrationAudit')) {
+      return { success: false, error: 'Could not audit credentials: unauthorized request' };
+    }
+    try {
+      const audit = auditCredentialMigration();
+      if (audit.sqliteOnly.length > 0) {
+        ipcLogger.warn('Credentials not yet in the keychain', { services: audit.sqliteOnly });
+      }
+      return { success: true, ...audit };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not audit credential migration', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  // Handler for revealing a stored password (e.g. the "show password" button)
+  ipcMain.handle('credentials:reveal', async (event, token: string, service: string) => {
+    if (!isTrustedIpcSender(event, 'credentials:reveal')) {
+      return { success: false, error: 'Could not reveal credentials: unauthorized request' };
+    }
+    const validation = validateInput(revealCredentialsSchema, { token, service }, 'credentials:reveal');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    // The OS re-auth policy lives in settings, so it cannot be the only gate
+    const session = validateSession(validatedData.token);
+    if (!session.valid) {
+      ipcLogger.security('reveal-credentials-denied', 'Password reveal refused without a valid session', {
+        service: validatedData.service,
+        token: validatedData.token.substring(0, 8) + '...'
+      });
+      return { success: false, error: getMessage('SESSION_INVALID') };
+    }
+    const policy = resolveSecurityPolicy(loadSettings().security_policy);
+    if (policy.requireOsReauthToReveal) {
+      const reauth = await requestOsReauth(`Verify it's you to show your ${validatedData.service} password`);
+      if (reauth.outcome !== 'verified') {
+        ipcLogger.security('reveal-credentials-denied', 'Password reveal refused without OS re-authentication', {
+          service: validatedData.service,
+          outcome: reauth.outcome,
+          method: reauth.method
+        });
+        return {
+          success: false,
+          error: reauth.outcome === 'unavailable'
+            ? 'Showing passwords requires Windows Hello or Touch ID, which is not set up on this device'
+            : 'Identity could not be verified'
+        };
+      }
+    }
+
+    const credentials = getCredentials(validatedData.service, { caller: 'credentials:reveal', purpose: 'reveal' });
+    if (!credentials) {
+      return { success: false, error: 'No stored credentials to show' };
+    }
+    ipcLogger.audit('reveal-credentials', 'User revealed stored password', {
+      service: validatedData.service,
+      email: session.email,
+      reauthenticated: policy.requireOsReauthToReveal
+    });
+    return { success: true, email: credentials.email, password: credentials.password };
+  });
+
   // Handler for deleting credentials
   ipcMain.handle('credentials:delete', async (event, service: string) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, 'credentials:delete')) {
       return { success: false, message: 'Could not delete credentials: unauthorized request', changes: 0 };
     }
     // Validate input using Zod schema
@@ -10,9 +10,21 @@
 
 import { ipcMain } from "electron";
 import { ipcLogger } from "@sheetpilot/shared/logger";
-import { getDb } from "@/models";
+import {
+  getCompletedTimesheetEntries,
+  getCompletedTimesheetEntriesPage,
+  getCompatibilityInfo,
+  getDatabaseConnectionStats,
+  getSchemaInfo,
+  listCredentials,
+} from "@/models";
 import { validateSession } from "@/models";
 import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+import { getMessage } from "@/services/i18n/message-catalog";
+import { tagEnvironment } from "@/services/health/environment";
+import { getArchivePage } from "@/services/archive/archive-pages";
+import { getArchivePageSchema } from "@/validation/ipc-schemas";
+import { validateInput } from "@/validation/validate-ipc-input";
 
 /**
  * Register all database viewer-related IPC handlers
@@ -26,7 +38,7 @@ export function registerDatabaseHandlers(): void {
       token: string,
       options?: { page?: number; pageSize?: number }
     ) => {
-      if (!isTrustedIpcSender(event)) {
+      if (!isTrustedIpcSender(event, "database:getAllTimesheetEntries")) {
         return {
           success: false,
           error: "Could not access database: unauthorized request",
@@ -62,7 +74,7 @@ export function registerDatabaseHandlers(): void {
         );
         return {
           success: false,
-          error: "Session is invalid or expired. Please log in again.",
+          error: getMessage("SESSION_INVALID"),
           entries: [],
           totalCount: 0,
         };
@@ -81,23 +93,10 @@ export function registerDatabaseHandlers(): void {
       });
 
       try {
-        const db = getDb();
-
-        // Get total count for pagination
-        const countStmt = db.prepare(
-          "SELECT COUNT(*) as total FROM timesheet WHERE status = 'Complete'"
+        const { entries, totalCount } = getCompletedTimesheetEntriesPage(
+          pageSize,
+          offset
         );
-        const countResult = countStmt.get() as { total: number };
-        const totalCount = countResult.total;
-
-        // Get paginated entries
-        const getAll = db.prepare(`
-        SELECT * FROM timesheet 
-        WHERE status = 'Complete' 
-        ORDER BY date ASC, hours ASC 
-        LIMIT ? OFFSET ?
-      `);
-        const entries = getAll.all(pageSize, offset);
 
         ipcLogger.verbose("Archive timesheet entries retrieved", {
           count: entries.length,
@@ -130,7 +129,7 @@ export function registerDatabaseHandlers(): void {
 
   // Handler for getting all archive data (timesheet + credentials) in a single call
   ipcMain.handle("database:getAllArchiveData", async (event, token: string) => {
-    if (!isTrustedIpcSender(event)) {
+    if (!isTrustedIpcSender(event, "database:getAllArchiveData")) {
       return {
         success: false,
         error: "Could not access database: unauthorized request",
@@ -158,7 +157,7 @@ export function registerDatabaseHandlers(): void {
       );
       return {
         success: false,
-        error: "Session is invalid or expired. Please log in again.",
+        error: getMessage("SESSION_INVALID"),
       };
     }
 
@@ -167,19 +166,8 @@ export function registerDatabaseHandlers(): void {
     });
 
     try {
-      const db = getDb();
-
-      // Get timesheet entries
-      const getTimesheet = db.prepare(
-        "SELECT * FROM timesheet WHERE status = 'Complete' ORDER BY date ASC, hours ASC"
-      );
-      const timesheet = getTimesheet.all();
-
-      // Get credentials
-      const getCredentials = db.prepare(
-        "SELECT id, service, email, created_at, updated_at FROM credentials ORDER BY service"
-      );
-      const credentials = getCredentials.all();
+      const timesheet = getCompletedTimesheetEntries();
+      const credentials = listCredentials();
 
       ipcLogger.verbose("Archive data retrieved", {
         timesheetCount: timesheet.length,
@@ -187,15 +175,184 @@ export function registerDatabaseHandlers(): void {
         email: session.email,
       });
 
-      return {
+      return tagEnvironment({
         success: true,
         timesheet,
         credentials,
-      };
+      });
     } catch (err: unknown) {
       ipcLogger.error("Could not get archive data", err);
       const errorMessage = err instanceof Error ? err.message : String(err);
       return { success: false, error: errorMessage };
     }
   });
+
+  // Handler for one cursor page of archive entries, for archives too large
+  // to send in a single getAllArchiveData response
+  ipcMain.handle(
+    "database:getArchivePage",
+    async (event, token: string, request?: unknown) => {
+      if (!isTrustedIpcSender(event, "database:getArchivePage")) {
+        return {
+          success: false,
+          error: "Could not access database: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        getArchivePageSchema,
+        { token, request },
+        "database:getArchivePage"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const validatedData = validation.data!;
+
+      const session = validateSession(validatedData.token);
+      if (!session.valid) {
+        ipcLogger.security(
+          "database-access-denied",
+          "Invalid session attempting database access",
+          {
+            handler: "getArchivePage",
+            token: validatedData.token.substring(0, 8) + "...",
+          }
+        );
+        return {
+          success: false,
+          error: getMessage("SESSION_INVALID"),
+        };
+      }
+
+      try {
+        const page = getArchivePage(validatedData.request ?? {});
+        ipcLogger.verbose("Archive page retrieved", {
+          count: page.count,
+          gzipped: Boolean(page.gzipped),
+          hasMore: page.nextCursor !== null,
+          email: session.email,
+        });
+        return tagEnvironment({ success: true, ...page });
+      } catch (err: unknown) {
+        ipcLogger.error("Could not get archive page", err);
+        const errorMessage = err instanceof Error ? err.message : String(err);
+        return { success: false, error: errorMessage };
+      }
+    }
+  );
+
+  // Handler for describing the live database schema (diagnostics)
+  ipcMain.handle("database:getSchemaInfo", async (event, token: string) => {
+    if (!isTrustedIpcSender(event, "database:getSchemaInfo")) {
+      return {
+        success: false,
+        error: "Could not access database: unauthorized request",
+      };
+    }
+    if (!token) {
+      ipcLogger.security(
+        "database-access-denied",
+        "Unauthorized database access attempted",
+        { handler: "getSchemaInfo" }
+      );
+      return {
+        success: false,
+        error: "Session token is required. Please log in to view diagnostics.",
+      };
+    }
+
+    const session = validateSession(token);
+    if (!session.valid) {
+      ipcLogger.security(
+        "database-access-denied",
+        "Invalid session attempting database access",
+        { handler: "getSchemaInfo", token: token.substring(0, 8) + "..." }
+      );
+      return {
+        success: false,
+        error: getMessage("SESSION_INVALID"),
+      };
+    }
+
+    try {
+      const schemaInfo = getSchemaInfo();
+      ipcLogger.verbose("Schema info retrieved", {
+        schemaVersion: schemaInfo.schemaVersion,
+        tableCount: schemaInfo.tables.length,
+      });
+      return { success: true, schemaInfo };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get schema info", err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  // Handler for whether this build may write to the database; needs no
+  // session since logging in is itself a write
+  ipcMain.handle("database:getCompatibilityInfo", async (event) => {
+    if (!isTrustedIpcSender(event, "database:getCompatibilityInfo")) {
+      return {
+        success: false,
+        error: "Could not access database: unauthorized request",
+      };
+    }
+
+    try {
+      const compatibility = getCompatibilityInfo();
+      if (!compatibility.compatible) {
+        ipcLogger.warn("Database is newer than this app version", {
+          schemaVersion: compatibility.schemaVersion,
+          appSchemaVersion: compatibility.appSchemaVersion,
+        });
+      }
+      return { success: true, compatibility };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get database compatibility info", err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  // Handler for statement timing, slow statements and lock timeouts (diagnostics)
+  ipcMain.handle("database:getConnectionStats", async (event, token: string) => {
+    if (!isTrustedIpcSender(event, "database:getConnectionStats")) {
+      return {
+        success: false,
+        error: "Could not access database: unauthorized request",
+      };
+    }
+    if (!token) {
+      ipcLogger.security(
+        "database-access-denied",
+        "Unauthorized database access attempted",
+        { handler: "getConnectionStats" }
+      );
+      return {
+        success: false,
+        error: "Session token is required. Please log in to view diagnostics.",
+      };
+    }
+
+    const session = validateSession(token);
+    if (!session.valid) {
+      ipcLogger.security(
+        "database-access-denied",
+        "Invalid session attempting database access",
+        { handler: "getConnectionStats", token: token.substring(0, 8) + "..." }
+      );
+      return {
+        success: false,
+        error: getMessage("SESSION_INVALID"),
+      };
+    }
+
+    try {
+      return { success: true, stats: getDatabaseConnectionStats() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get database connection stats", err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
 }
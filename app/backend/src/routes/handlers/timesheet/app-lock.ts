@@ -0,0 +1,15 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getAppLockState } from '@/services/timesheet/app-lock';
+import { isTrustedIpcSender } from './main-window';
+
+export function registerTimesheetAppLockHandlers(): void {
+  ipcMain.handle('timesheet:getAppLockState', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get app lock state: unauthorized request' };
+    }
+    return { success: true, state: getAppLockState() };
+  });
+
+  ipcLogger.verbose('Timesheet app lock handlers registered');
+}
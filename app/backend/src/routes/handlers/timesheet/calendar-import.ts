@@ -0,0 +1,180 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import { checkDuplicateEntry, insertTimesheetEntries } from '@/models';
+import { validateInput } from '@/validation/validate-ipc-input';
+import {
+  calendarImportDraftsSchema,
+  calendarImportPreviewSchema,
+  type CalendarImportDrafts,
+  type CalendarImportPreview,
+} from '@/validation/ipc-schemas';
+import {
+  buildCalendarDraftPreviews,
+  DEFAULT_CALENDAR_IMPORT_PROJECT,
+} from '@/logic/calendar-import';
+import { checkChargeCode } from '@/logic/charge-code-rules';
+import { checkEntryCategory } from '@/logic/entry-categories';
+import { resolveHomeTimeZone } from '@/logic/time-zones';
+import { getChargeCodeRule } from '@/middleware/bootstrap-plugins';
+import { fetchCalendarEvents } from '@/services/cloud/graph-calendar';
+import { listEntryCategories } from '@/services/timesheet/entry-categories';
+import { isTrustedIpcSender } from './main-window';
+import { getMessage } from '@/services/i18n/message-catalog';
+import { getAppLockError } from '@/services/timesheet/app-lock';
+import { loadSettings } from '@/services/settings/settings-store';
+import { enqueueJob } from '@/services/jobs/job-manager';
+
+export const CALENDAR_IMPORT_JOB_TYPE = 'calendar-import';
+
+/** Longest range a single preview may cover */
+const MAX_RANGE_DAYS = 92;
+
+export function registerTimesheetCalendarImportHandlers(): void {
+  ipcMain.handle('timesheet:previewCalendarImport', async (event, request: CalendarImportPreview) => {
+    const timer = ipcLogger.startTimer('preview-calendar-import');
+    if (!isTrustedIpcSender(event)) {
+      timer.done({ outcome: 'error', reason: 'unauthorized' });
+      return { success: false, error: 'Could not preview calendar import: unauthorized request' };
+    }
+
+    const validation = validateInput(calendarImportPreviewSchema, request, 'timesheet:previewCalendarImport');
+    if (!validation.success) {
+      timer.done({ outcome: 'error', error: 'validation-failed' });
+      return { success: false, error: validation.error };
+    }
+    const validated = validation.data!;
+
+    const chargeCodeError = checkChargeCode(validated.chargeCode, getChargeCodeRule());
+    if (chargeCodeError) {
+      timer.done({ outcome: 'error', error: 'invalid-charge-code' });
+      return { success: false, error: chargeCodeError };
+    }
+
+    try {
+      const startDate = normalizeDateToISO(validated.startDate);
+      const endDate = normalizeDateToISO(validated.endDate);
+      const rangeDays = (Date.parse(endDate) - Date.parse(startDate)) / 86_400_000;
+      if (rangeDays < 0) {
+        timer.done({ outcome: 'error', error: 'invalid-range' });
+        return { success: false, error: getMessage('DATE_RANGE_INVALID') };
+      }
+      if (rangeDays > MAX_RANGE_DAYS) {
+        timer.done({ outcome: 'error', error: 'range-too-large' });
+        return { success: false, error: `Date range must not exceed ${MAX_RANGE_DAYS} days` };
+      }
+
+      // Days are counted in the home zone even when the machine's zone follows a trip
+      const homeTimeZone = resolveHomeTimeZone(loadSettings().home_time_zone);
+      const events = await fetchCalendarEvents(startDate, endDate, homeTimeZone);
+      const { drafts, skipped } = buildCalendarDraftPreviews(
+        events,
+        {
+          includeCategories: validated.includeCategories,
+          includeKeywords: validated.includeKeywords,
+          excludeKeywords: validated.excludeKeywords,
+          skipFree: validated.skipFree,
+        },
+        {
+          project: validated.project ?? DEFAULT_CALENDAR_IMPORT_PROJECT,
+          tool: validated.tool,
+          chargeCode: validated.chargeCode,
+        },
+        checkDuplicateEntry,
+        { home: homeTimeZone, entry: validated.timeZone }
+      );
+
+      ipcLogger.info('Calendar import preview built', {
+        startDate,
+        endDate,
+        homeTimeZone,
+        entryTimeZone: validated.timeZone,
+        eventCount: events.length,
+        draftCount: drafts.length,
+        skippedCount: skipped.length,
+      });
+      timer.done({ draftCount: drafts.length });
+      return { success: true, drafts, skipped };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not preview calendar import', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      timer.done({ outcome: 'error', error: errorMessage });
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  ipcMain.handle('timesheet:importCalendarDrafts', async (event, drafts: CalendarImportDrafts['drafts']) => {
+    const timer = ipcLogger.startTimer('import-calendar-drafts');
+    if (!isTrustedIpcSender(event)) {
+      timer.done({ outcome: 'error', reason: 'unauthorized' });
+      return { success: false, error: 'Could not import calendar drafts: unauthorized request' };
+    }
+
+    const locked = getAppLockError('timesheet:importCalendarDrafts');
+    if (locked) {
+      timer.done({ outcome: 'error', reason: 'locked' });
+      return { success: false, error: locked.message, code: locked.code };
+    }
+
+    const validation = validateInput(calendarImportDraftsSchema, { drafts }, 'timesheet:importCalendarDrafts');
+    if (!validation.success) {
+      timer.done({ outcome: 'error', error: 'validation-failed' });
+      return { success: false, error: validation.error };
+    }
+
+    const chargeCodeRule = getChargeCodeRule();
+    const categories = listEntryCategories();
+    for (const draft of validation.data!.drafts) {
+      const chargeCodeError = checkChargeCode(draft.chargeCode, chargeCodeRule);
+      if (chargeCodeError) {
+        timer.done({ outcome: 'error', error: 'invalid-charge-code' });
+        return { success: false, error: chargeCodeError };
+      }
+      const categoryError = checkEntryCategory(draft.category, categories);
+      if (categoryError) {
+        timer.done({ outcome: 'error', error: 'unknown-category' });
+        return { success: false, error: categoryError };
+      }
+    }
+
+    const validDrafts = validation.data!.drafts;
+    const jobId = enqueueJob(
+      CALENDAR_IMPORT_JOB_TYPE,
+      async () => {
+        // A submission may have started while this job was queued
+        const lockedNow = getAppLockError('timesheet:importCalendarDrafts');
+        if (lockedNow) {
+          throw lockedNow;
+        }
+        const result = insertTimesheetEntries(
+          validDrafts.map((draft) => ({
+            date: draft.date,
+            hours: draft.hours,
+            project: draft.project,
+            tool: draft.tool ?? null,
+            detailChargeCode: draft.chargeCode ?? null,
+            taskDescription: draft.taskDescription,
+            timeZone: draft.timeZone ?? null,
+            category: draft.category ?? null,
+          }))
+        );
+        if (!result.success) {
+          throw new Error(result.errorMessage ?? 'Could not import calendar drafts');
+        }
+
+        const timeZones = [...new Set(validDrafts.flatMap((draft) => draft.timeZone ?? []))];
+        ipcLogger.audit('import-calendar-drafts', 'Calendar meetings imported as drafts', {
+          inserted: result.inserted,
+          duplicates: result.duplicates,
+          ...(timeZones.length > 0 ? { timeZones } : {}),
+        });
+        return { inserted: result.inserted, duplicates: result.duplicates };
+      },
+      { detail: { draftCount: validDrafts.length } }
+    );
+    timer.done({ jobId, draftCount: validDrafts.length });
+    return { success: true, jobId };
+  });
+
+  ipcLogger.verbose('Timesheet calendar import handlers registered');
+}
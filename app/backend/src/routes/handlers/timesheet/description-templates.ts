@@ -0,0 +1,151 @@
+import { ipcMain } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import {
+  deleteDescriptionTemplate,
+  listDescriptionTemplates,
+  renderDescription,
+  saveDescriptionTemplate,
+} from "@/services/timesheet/description-templates";
+import { DESCRIPTION_TEMPLATE_VARIABLES } from "@/logic/description-templates";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  descriptionTemplateIdQuerySchema,
+  descriptionTemplateSchema,
+  renderDescriptionSchema,
+  type DescriptionTemplateInput,
+  type RenderDescriptionRequest,
+} from "@/validation/ipc-schemas";
+import { isTrustedIpcSender } from "./main-window";
+
+export function registerTimesheetDescriptionTemplateHandlers(): void {
+  ipcMain.handle("timesheet:listDescriptionTemplates", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list description templates: unauthorized request",
+      };
+    }
+    try {
+      return {
+        success: true,
+        templates: listDescriptionTemplates(),
+        variables: [...DESCRIPTION_TEMPLATE_VARIABLES],
+      };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not list description templates", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "timesheet:saveDescriptionTemplate",
+    async (event, template: DescriptionTemplateInput) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not save description template: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        descriptionTemplateSchema,
+        template,
+        "timesheet:saveDescriptionTemplate"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const saved = saveDescriptionTemplate(validation.data!);
+        ipcLogger.info("Description template saved", {
+          id: saved.id,
+          name: saved.name,
+          created: !validation.data!.id,
+        });
+        return { success: true, template: saved };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not save description template", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "timesheet:deleteDescriptionTemplate",
+    async (event, templateId: string) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not delete description template: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        descriptionTemplateIdQuerySchema,
+        { templateId },
+        "timesheet:deleteDescriptionTemplate"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        if (!deleteDescriptionTemplate(validation.data!.templateId)) {
+          return { success: false, error: "Description template not found" };
+        }
+        ipcLogger.info("Description template deleted", {
+          id: validation.data!.templateId,
+        });
+        return { success: true };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not delete description template", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "timesheet:renderDescription",
+    async (
+      event,
+      templateId: string,
+      vars: RenderDescriptionRequest["vars"]
+    ) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not render description: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        renderDescriptionSchema,
+        { templateId, vars: vars ?? {} },
+        "timesheet:renderDescription"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const { description, missing } = renderDescription(
+          validation.data!.templateId,
+          validation.data!.vars
+        );
+        return { success: true, description, missing };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not render description", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcLogger.verbose("Timesheet description template handlers registered");
+}
@@ -1,14 +1,29 @@
 import { ipcMain } from 'electron';
 import { ipcLogger } from '@sheetpilot/shared/logger';
-import { getDb } from '@/models';
+import { getPendingTimesheetEntries, markTimesheetEntriesAsSubmitted } from '@/models';
 import { isTrustedIpcSender } from './main-window';
+import { runFillBenchmark } from '@sheetpilot/bot';
+import { enqueueJob } from '@/services/jobs/job-manager';
+import { validateInput } from '@/validation/validate-ipc-input';
+import { devBenchmarkFillSchema } from '@/validation/ipc-schemas';
+import { getAppEnvironment } from '@/services/health/environment';
+
+export const FILL_BENCHMARK_JOB_TYPE = 'fill-benchmark';
+
+/** Rows submitted to the mock form on every benchmark run */
+const BENCHMARK_ROWS: Array<Record<string, unknown>> = [
+  { Project: 'FL-Carver Techs', Date: '01/06/2025', Hours: 1.5, Tool: '#2 Sputter', 'Task Description': 'Benchmark row one', 'Detail Charge Code': 'EPR1', Status: '' },
+  { Project: 'Training', Date: '01/07/2025', Hours: 2, Tool: '', 'Task Description': 'Benchmark row two with a longer description to type', 'Detail Charge Code': '', Status: '' },
+  { Project: 'PTO/RTO', Date: '01/08/2025', Hours: 8, Tool: '', 'Task Description': 'Benchmark row three', 'Detail Charge Code': '', Status: '' }
+];
 
 export function registerTimesheetDevHandlers(): void {
   ipcMain.handle('timesheet:devSimulateSuccess', async (event) => {
     if (!isTrustedIpcSender(event)) {
       return { success: false, error: 'Could not simulate success: unauthorized request' };
     }
-    if (process.env['NODE_ENV'] === 'production') {
+    // Packaged builds resolve to prod whatever NODE_ENV says
+    if (getAppEnvironment().environment === 'prod') {
       ipcLogger.warn('Dev simulate success called in production - blocking');
       return { success: false, error: 'Not available in production' };
     }
@@ -16,38 +31,57 @@ export function registerTimesheetDevHandlers(): void {
     ipcLogger.info('[DEV] Simulating successful submission');
 
     try {
-      const db = getDb();
-
-      const getPending = db.prepare(`
-        SELECT id FROM timesheet WHERE status IS NULL
-      `);
-      const pendingEntries = getPending.all() as Array<{ id: number }>;
+      const ids = getPendingTimesheetEntries().map((e) => e.id);
 
-      if (pendingEntries.length === 0) {
+      if (ids.length === 0) {
         ipcLogger.info('[DEV] No pending entries to mark as complete');
         return { success: true, count: 0 };
       }
 
-      const ids = pendingEntries.map((e) => e.id);
-      const placeholders = ids.map(() => '?').join(',');
+      markTimesheetEntriesAsSubmitted(ids);
 
-      const markComplete = db.prepare(`
-        UPDATE timesheet 
-        SET status = 'Complete',
-            submitted_at = datetime('now')
-        WHERE id IN (${placeholders})
-      `);
-
-      const result = markComplete.run(...ids);
-
-      ipcLogger.info('[DEV] Marked entries as Complete', { count: result.changes, ids });
-      return { success: true, count: result.changes };
+      ipcLogger.info('[DEV] Marked entries as Complete', { count: ids.length, ids });
+      return { success: true, count: ids.length };
     } catch (err: unknown) {
       ipcLogger.error('[DEV] Could not simulate success', err);
       return { success: false, error: err instanceof Error ? err.message : String(err) };
     }
   });
 
+  ipcMain.handle('timesheet:devBenchmarkFill', async (event, request: unknown) => {
+    if (!isTrustedIpcSender(event, 'timesheet:devBenchmarkFill')) {
+      return { success: false, error: 'Could not start fill benchmark: unauthorized request' };
+    }
+    if (getAppEnvironment().environment === 'prod') {
+      ipcLogger.warn('Dev fill benchmark called in production - blocking');
+      return { success: false, error: 'Not available in production' };
+    }
+    const validation = validateInput(devBenchmarkFillSchema, request, 'timesheet:devBenchmarkFill');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const { iterations, profiles } = validation.data!;
+    ipcLogger.info('[DEV] Starting fill benchmark against mock form', { iterations, profiles });
+    const jobId = enqueueJob(
+      FILL_BENCHMARK_JOB_TYPE,
+      (context) =>
+        runFillBenchmark({
+          rows: BENCHMARK_ROWS,
+          // The mock website accepts any credentials
+          email: 'benchmark@example.com',
+          password: 'benchmark',
+          iterations,
+          ...(profiles ? { profiles } : {}),
+          abortSignal: context.signal,
+          onProgress: (completed, total, message) =>
+            context.reportProgress(Math.round((completed / total) * 100), message)
+        }),
+      { trigger: 'manual', detail: { iterations, profiles: profiles ?? 'all' } }
+    );
+    return { success: true, jobId };
+  });
+
   ipcLogger.verbose('Timesheet dev handlers registered');
 }
 
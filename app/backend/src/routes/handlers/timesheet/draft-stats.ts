@@ -0,0 +1,28 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getDraftDateTotals } from '@/models';
+import { summarizeDraftStats } from '@/logic/draft-stats';
+import { isTrustedIpcSender } from './main-window';
+
+export function registerTimesheetDraftStatsHandlers(): void {
+  ipcMain.handle('timesheet:getDraftStats', async (event) => {
+    const timer = ipcLogger.startTimer('get-draft-stats');
+    if (!isTrustedIpcSender(event)) {
+      timer.done({ outcome: 'error', reason: 'unauthorized' });
+      return { success: false, error: 'Could not get draft statistics: unauthorized request' };
+    }
+
+    try {
+      const stats = summarizeDraftStats(getDraftDateTotals());
+      timer.done({ draftCount: stats.draftCount });
+      return { success: true, stats };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get draft statistics', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      timer.done({ outcome: 'error', error: errorMessage });
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet draft statistics handlers registered');
+}
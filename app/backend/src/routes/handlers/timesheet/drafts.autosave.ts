@@ -0,0 +1,150 @@
+/**
+ * @fileoverview Draft Auto-Save
+ *
+ * The renderer marks edited rows dirty instead of saving on every change.
+ * Dirty rows are coalesced per row key and persisted together by a timed
+ * flush; results go back to the renderer as one `timesheet:draftSaved`
+ * event per flush.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import type { DirtyDraftRow } from '@/validation/ipc-schemas';
+import { getAppLockState } from '@/services/timesheet/app-lock';
+import { saveDraftRow, type SaveDraftResponse } from './drafts.save';
+
+/** How long dirty rows wait for further edits before being written */
+export const DRAFT_FLUSH_DELAY_MS = 1000;
+
+export type DraftSaveResult = SaveDraftResponse & { rowKey: string };
+
+type DraftsSavedListener = (results: DraftSaveResult[]) => void;
+
+const dirtyRows = new Map<string, DirtyDraftRow>();
+const listeners = new Set<DraftsSavedListener>();
+let flushTimer: NodeJS.Timeout | null = null;
+let flushDelayMs = DRAFT_FLUSH_DELAY_MS;
+
+const scheduleFlush = (): void => {
+  if (flushTimer) {
+    return;
+  }
+  flushTimer = setTimeout(() => {
+    flushTimer = null;
+    // Held (and rescheduled) while a submission has the entries locked
+    flushDirtyDrafts();
+  }, flushDelayMs);
+  flushTimer.unref?.();
+};
+
+/**
+ * Records rows with unsaved edits; a later mark for the same row key replaces
+ * the earlier one, so only the latest values are written
+ */
+export function markDraftsDirty(rows: DirtyDraftRow[]): void {
+  for (const row of rows) {
+    dirtyRows.set(row.rowKey, row);
+  }
+  scheduleFlush();
+}
+
+/**
+ * Persists every dirty row now and notifies listeners
+ *
+ * Runs synchronously, so rows marked while it runs wait for the next flush.
+ * While a submission holds the app lock nothing is written: the rows stay
+ * dirty and the timed flush writes them once the run lets go.
+ */
+export function flushDirtyDrafts(): DraftSaveResult[] {
+  if (getAppLockState().locked) {
+    if (dirtyRows.size > 0) {
+      scheduleFlush();
+    }
+    return [];
+  }
+  if (flushTimer) {
+    clearTimeout(flushTimer);
+    flushTimer = null;
+  }
+  if (dirtyRows.size === 0) {
+    return [];
+  }
+
+  const rows = [...dirtyRows.values()];
+  dirtyRows.clear();
+
+  const results = rows.map(({ rowKey, ...row }): DraftSaveResult => {
+    try {
+      return { rowKey, ...saveDraftRow(row) };
+    } catch (err: unknown) {
+      return { rowKey, success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+  const failed = results.filter((result) => !result.success).length;
+  ipcLogger.verbose('Flushed dirty drafts', { rows: results.length, failed });
+
+  for (const listener of listeners) {
+    try {
+      listener(results);
+    } catch (err: unknown) {
+      ipcLogger.warn('Draft saved listener failed', {
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+  return results;
+}
+
+/**
+ * Drops dirty rows for an entry that is being deleted, so a pending flush
+ * cannot write it back
+ */
+export function discardDirtyDrafts(id: number): void {
+  for (const [rowKey, row] of dirtyRows) {
+    if (row.id === id) {
+      dirtyRows.delete(rowKey);
+    }
+  }
+}
+
+/**
+ * Number of rows waiting to be flushed
+ */
+export function getDirtyDraftCount(): number {
+  return dirtyRows.size;
+}
+
+/**
+ * Subscribes to flush results
+ *
+ * @returns Unsubscribe function
+ */
+export function onDraftsSaved(listener: DraftsSavedListener): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+/**
+ * Overrides the flush delay (tests use a short delay)
+ */
+export function setDraftFlushDelay(delayMs: number): void {
+  flushDelayMs = delayMs;
+}
+
+/**
+ * Drops dirty rows, listeners and the pending timer (for tests)
+ */
+export function resetDraftAutoSave(): void {
+  if (flushTimer) {
+    clearTimeout(flushTimer);
+    flushTimer = null;
+  }
+  dirtyRows.clear();
+  listeners.clear();
+  flushDelayMs = DRAFT_FLUSH_DELAY_MS;
+}
@@ -1,9 +1,31 @@
+import { SubmissionStatus, toTimesheetRow } from '@sheetpilot/shared';
 import { ipcLogger } from '@sheetpilot/shared/logger';
-import { getDb, resetInProgressTimesheetEntries } from '@/models';
+import {
+  amendTimesheetEntry,
+  deleteUnsubmittedTimesheetEntry,
+  getDraftTimesheetEntryById,
+  getPendingTimesheetEntries,
+  getTimesheetEntryById,
+  getProjectByName,
+  getTimesheetEntryStatus,
+  reassignDraftProject,
+  repoGetToolsByProject,
+  resetInProgressTimesheetEntries,
+  SUBMITTED_ENTRY_LOCKED_ERROR,
+} from '@/models';
 import { validateInput } from '@/validation/validate-ipc-input';
-import { deleteDraftSchema } from '@/validation/ipc-schemas';
+import {
+  amendEntrySchema,
+  deleteDraftSchema,
+  markDirtyDraftsSchema,
+  reassignRetiredProjectSchema,
+  type MarkDirtyDrafts,
+} from '@/validation/ipc-schemas';
 import { isTrustedIpcSender } from './main-window';
+import { discardDirtyDrafts, flushDirtyDrafts, markDraftsDirty } from './drafts.autosave';
 import type { DraftRowEntry } from './drafts.types';
+import { getMessage } from '@/services/i18n/message-catalog';
+import { getAppLockError, getAppLockState } from '@/services/timesheet/app-lock';
 
 export const handleDeleteDraft = async (
   event: Electron.IpcMainInvokeEvent,
@@ -19,6 +41,12 @@ export const handleDeleteDraft = async (
     };
   }
 
+  const locked = getAppLockError('timesheet:deleteDraft');
+  if (locked) {
+    timer.done({ outcome: 'error', reason: 'locked' });
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
   const validation = validateInput(
     deleteDraftSchema,
     { id },
@@ -32,42 +60,48 @@ export const handleDeleteDraft = async (
 
   try {
     ipcLogger.verbose('Deleting timesheet entry', { id: validatedData.id });
-    const db = getDb();
+    const status = getTimesheetEntryStatus(validatedData.id);
 
-    const checkStmt = db.prepare(
-      `SELECT id, status FROM timesheet WHERE id = ?`
-    );
-    const entry = checkStmt.get(validatedData.id) as
-      | { id: number; status: string | null }
-      | undefined;
+    if (status === SubmissionStatus.COMPLETE) {
+      ipcLogger.security(
+        'submitted-entry-delete-blocked',
+        'Attempt to delete a submitted timesheet entry',
+        { id: validatedData.id }
+      );
+      timer.done({ outcome: 'error', error: 'entry-locked' });
+      return { success: false, error: SUBMITTED_ENTRY_LOCKED_ERROR };
+    }
 
-    if (entry) {
+    if (status !== undefined) {
       ipcLogger.info('Deleting entry with status', {
         id: validatedData.id,
-        status: entry.status,
+        status,
       });
     }
 
-    const deleteStmt = db.prepare(`
-        DELETE FROM timesheet 
-        WHERE id = ?
-      `);
-
-    const result = deleteStmt.run(validatedData.id);
+    discardDirtyDrafts(validatedData.id);
+    // Read before deleting so the caller gets back exactly the row that was removed
+    const deletedEntry = getTimesheetEntryById(validatedData.id);
+    const changes = deleteUnsubmittedTimesheetEntry(validatedData.id);
 
-    if (result.changes === 0) {
+    if (changes === 0) {
       ipcLogger.warn('Entry not found to delete', { id: validatedData.id });
       timer.done({ outcome: 'not_found' });
-      return { success: false, error: 'Entry not found' };
+      return { success: false, error: getMessage('ENTRY_NOT_FOUND') };
     }
 
     ipcLogger.info('Timesheet entry deleted', {
       id: validatedData.id,
-      changes: result.changes,
-      previousStatus: entry?.status,
+      changes,
+      previousStatus: status,
     });
-    timer.done({ changes: result.changes });
-    return { success: true };
+    timer.done({ changes });
+    return {
+      success: true,
+      changes,
+      id: validatedData.id,
+      ...(deletedEntry ? { entry: toTimesheetRow(deletedEntry) } : {}),
+    };
   } catch (err: unknown) {
     ipcLogger.error('Could not delete timesheet entry', err);
     const errorMessage = err instanceof Error ? err.message : String(err);
@@ -76,18 +110,62 @@ export const handleDeleteDraft = async (
   }
 };
 
-const formatDraftEntry = (entry: DraftRowEntry) => ({
-  id: entry.id,
-  date: entry.date,
-  hours: entry.hours ?? undefined,
-  project: entry.project,
-  tool: entry.tool || null,
-  chargeCode: entry.detail_charge_code || null,
-  taskDescription: entry.task_description,
-});
+export const handleMarkDirtyDrafts = async (
+  event: Electron.IpcMainInvokeEvent,
+  rows: MarkDirtyDrafts['rows']
+) => {
+  if (!isTrustedIpcSender(event)) {
+    return {
+      success: false,
+      error: 'Could not queue draft save: unauthorized request',
+    };
+  }
+
+  const locked = getAppLockError('timesheet:markDirty');
+  if (locked) {
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
+  const validation = validateInput(
+    markDirtyDraftsSchema,
+    { rows },
+    'timesheet:markDirty'
+  );
+  if (!validation.success) {
+    return { success: false, error: validation.error };
+  }
+
+  markDraftsDirty(validation.data!.rows);
+  return { success: true };
+};
+
+export const handleFlushDirtyDrafts = async (
+  event: Electron.IpcMainInvokeEvent
+) => {
+  if (!isTrustedIpcSender(event)) {
+    return {
+      success: false,
+      error: 'Could not save drafts: unauthorized request',
+    };
+  }
+
+  const locked = getAppLockError('timesheet:flushDirty');
+  if (locked) {
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
+  try {
+    const results = flushDirtyDrafts();
+    return { success: true, results };
+  } catch (err: unknown) {
+    ipcLogger.error('Could not flush dirty drafts', err);
+    const errorMessage = err instanceof Error ? err.message : String(err);
+    return { success: false, error: errorMessage };
+  }
+};
 
 const toDraftEntriesResponse = (entries: DraftRowEntry[]) => {
-  const gridData = entries.map((entry) => formatDraftEntry(entry));
+  const gridData = entries.map((entry) => toTimesheetRow(entry));
   const entriesToReturn = gridData.length > 0 ? gridData : [{}];
   return { gridData, entriesToReturn };
 };
@@ -105,23 +183,23 @@ export const handleLoadDraft = async (
     };
   }
   try {
-    const resetCount = resetInProgressTimesheetEntries();
-    if (resetCount > 0) {
-      ipcLogger.info('Reset in-progress entries to NULL on page reload', {
-        count: resetCount,
-      });
+    // A reload mid-submission must not write drafts or pull entries back
+    // from the running bot; it just shows what is there
+    if (!getAppLockState().locked) {
+      // Write pending edits first so the grid reloads what the user typed
+      flushDirtyDrafts();
+
+      const resetCount = resetInProgressTimesheetEntries();
+      if (resetCount > 0) {
+        ipcLogger.info('Reset in-progress entries to NULL on page reload', {
+          count: resetCount,
+        });
+      }
     }
 
     ipcLogger.verbose('Loading draft timesheet entries');
 
-    const db = getDb();
-    const getPending = db.prepare(`
-        SELECT * FROM timesheet 
-        WHERE status IS NULL
-        ORDER BY date ASC, hours ASC
-      `);
-
-    const entries = getPending.all() as DraftRowEntry[];
+    const entries: DraftRowEntry[] = getPendingTimesheetEntries();
 
     const { gridData, entriesToReturn } = toDraftEntriesResponse(entries);
 
@@ -154,18 +232,12 @@ export const handleLoadDraftById = async (
   try {
     if (!id || typeof id !== 'number') {
       timer.done({ outcome: 'error', error: 'invalid-id' });
-      return { success: false, error: 'Invalid ID provided' };
+      return { success: false, error: getMessage('INVALID_ENTRY_ID') };
     }
 
     ipcLogger.verbose('Loading draft timesheet entry by ID', { id });
 
-    const db = getDb();
-    const getEntry = db.prepare(`
-        SELECT * FROM timesheet 
-        WHERE id = ? AND status IS NULL
-      `);
-
-    const entry = getEntry.get(id) as DraftRowEntry | undefined;
+    const entry: DraftRowEntry | undefined = getDraftTimesheetEntryById(id);
 
     if (!entry) {
       ipcLogger.warn('Draft timesheet entry not found', { id });
@@ -173,7 +245,7 @@ export const handleLoadDraftById = async (
       return { success: false, error: 'Entry not found or already submitted' };
     }
 
-    const gridEntry = formatDraftEntry(entry);
+    const gridEntry = toTimesheetRow(entry);
 
     ipcLogger.verbose('Draft timesheet entry loaded by ID', { id });
     timer.done({ found: true });
@@ -185,3 +257,129 @@ export const handleLoadDraftById = async (
     return { success: false, error: errorMessage };
   }
 };
+
+export const handleAmendEntry = async (
+  event: Electron.IpcMainInvokeEvent,
+  id: number
+) => {
+  const timer = ipcLogger.startTimer('amend-entry');
+  if (!isTrustedIpcSender(event)) {
+    timer.done({ outcome: 'error', reason: 'unauthorized' });
+    return {
+      success: false,
+      error: 'Could not amend entry: unauthorized request',
+    };
+  }
+
+  const locked = getAppLockError('timesheet:amendEntry');
+  if (locked) {
+    timer.done({ outcome: 'error', reason: 'locked' });
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
+  const validation = validateInput(
+    amendEntrySchema,
+    { id },
+    'timesheet:amendEntry'
+  );
+  if (!validation.success) {
+    return { success: false, error: validation.error };
+  }
+
+  try {
+    const result = amendTimesheetEntry(validation.data!.id);
+    if (!result.success || !result.id) {
+      timer.done({ outcome: 'error', error: result.errorMessage });
+      return {
+        success: false,
+        error: result.errorMessage ?? 'Could not amend entry',
+      };
+    }
+
+    const entry: DraftRowEntry | undefined = getTimesheetEntryById(result.id);
+
+    ipcLogger.info('Amendment draft ready', {
+      originalId: validation.data!.id,
+      amendmentId: result.id,
+    });
+    timer.done({ amendmentId: result.id });
+    return {
+      success: true,
+      id: result.id,
+      ...(entry ? { entry: toTimesheetRow(entry) } : {}),
+    };
+  } catch (err: unknown) {
+    ipcLogger.error('Could not amend timesheet entry', err);
+    const errorMessage = err instanceof Error ? err.message : String(err);
+    timer.done({ outcome: 'error', error: errorMessage });
+    return { success: false, error: errorMessage };
+  }
+};
+
+/**
+ * Moves drafts booked to a retired project (on or after its retirement date)
+ * to the successor configured in the project catalog
+ */
+export const handleReassignRetiredProject = async (
+  event: Electron.IpcMainInvokeEvent,
+  project: string
+) => {
+  const timer = ipcLogger.startTimer('reassign-retired-project');
+  if (!isTrustedIpcSender(event)) {
+    timer.done({ outcome: 'error', reason: 'unauthorized' });
+    return {
+      success: false,
+      error: 'Could not reassign drafts: unauthorized request',
+    };
+  }
+
+  const locked = getAppLockError('timesheet:reassignRetiredProject');
+  if (locked) {
+    timer.done({ outcome: 'error', reason: 'locked' });
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
+  const validation = validateInput(
+    reassignRetiredProjectSchema,
+    { project },
+    'timesheet:reassignRetiredProject'
+  );
+  if (!validation.success) {
+    return { success: false, error: validation.error };
+  }
+
+  try {
+    const retired = getProjectByName(validation.data!.project);
+    if (!retired?.retired_on) {
+      timer.done({ outcome: 'error', error: 'not-retired' });
+      return { success: false, error: `${validation.data!.project} is not retired` };
+    }
+    const successor = retired.successor_project ? getProjectByName(retired.successor_project) : undefined;
+    if (!successor) {
+      timer.done({ outcome: 'error', error: 'no-successor' });
+      return {
+        success: false,
+        error: `${retired.name} has no successor project; reassign its drafts by hand`,
+      };
+    }
+
+    // Write pending edits first so the move sees what the user typed
+    flushDirtyDrafts();
+    const ids = reassignDraftProject(retired.name, successor.name, {
+      fromDate: retired.retired_on,
+      validTools: successor.requires_tools ? repoGetToolsByProject(successor.name) : [],
+    });
+    ipcLogger.info('Drafts moved from retired project to successor', {
+      project: retired.name,
+      successor: successor.name,
+      count: ids.length,
+    });
+    timer.done({ count: ids.length });
+    return { success: true, successor: successor.name, ids };
+  } catch (err: unknown) {
+    ipcLogger.error('Could not reassign drafts from retired project', err);
+    const errorMessage = err instanceof Error ? err.message : String(err);
+    timer.done({ outcome: 'error', error: errorMessage });
+    return { success: false, error: errorMessage };
+  }
+};
@@ -1,197 +1,107 @@
+import { SubmissionStatus, toTimesheetRow } from "@sheetpilot/shared";
 import { ipcLogger } from "@sheetpilot/shared/logger";
-import { getDb } from "@/models";
+import {
+  getProjectRetirements,
+  getTimesheetEntryStatus,
+  listValidationRules,
+  saveDraftTimesheetEntry,
+  SUBMITTED_ENTRY_LOCKED_ERROR,
+} from "@/models";
 import { validateInput } from "@/validation/validate-ipc-input";
 import { saveDraftSchema, type SaveDraft } from "@/validation/ipc-schemas";
+import { getBackfillWarning } from "@/logic/backfill-warnings";
+import { checkChargeCode } from "@/logic/charge-code-rules";
+import { checkExternalRef, resolveExternalRefRule } from "@/logic/external-ref";
+import { checkEntryCategory } from "@/logic/entry-categories";
+import { checkProjectActive } from "@/logic/project-lifecycle";
+import {
+  evaluateValidationRules,
+  toRuleEntry,
+  type RuleViolation,
+} from "@/logic/validation-rules";
+import { getChargeCodeRule } from "@/middleware/bootstrap-plugins";
+import { getAppLockError } from "@/services/timesheet/app-lock";
+import { loadSettings } from "@/services/settings/settings-store";
+import { listEntryCategories } from "@/services/timesheet/entry-categories";
 import { isTrustedIpcSender } from "./main-window";
 import type { DraftRowEntry } from "./drafts.types";
 
-type DraftSaveResult = {
-  changes: number;
-  lastInsertRowid: number | bigint;
-};
-
-type UpdateData = {
-  updateFields: string[];
-  updateValues: Array<string | number | null>;
-};
-
-type SaveDraftTransactionResult = {
-  result: DraftSaveResult;
-  savedId: number;
-  savedEntry: DraftRowEntry | undefined;
-};
-
-const getUpdateData = (validatedRow: SaveDraft): UpdateData => {
-  const updateCandidates = [
-    {
-      field: "date",
-      value: validatedRow.date,
-      include: validatedRow.date !== undefined,
-    },
-    {
-      field: "hours",
-      value: validatedRow.hours,
-      include: validatedRow.hours !== undefined && validatedRow.hours !== null,
-    },
-    {
-      field: "project",
-      value: validatedRow.project,
-      include: validatedRow.project !== undefined,
-    },
-    {
-      field: "tool",
-      value: validatedRow.tool || null,
-      include: validatedRow.tool !== undefined,
-    },
-    {
-      field: "detail_charge_code",
-      value: validatedRow.chargeCode || null,
-      include: validatedRow.chargeCode !== undefined,
-    },
-    {
-      field: "task_description",
-      value: validatedRow.taskDescription,
-      include: validatedRow.taskDescription !== undefined,
-    },
-  ];
-
-  const filteredCandidates = updateCandidates.filter(
-    (candidate) => candidate.include
-  );
-
-  return {
-    updateFields: filteredCandidates.map(
-      (candidate) => `${candidate.field} = ?`
-    ),
-    updateValues: filteredCandidates.map(
-      (candidate) => candidate.value as string | number | null
-    ),
-  };
-};
-
-const runUpdate = (
-  db: ReturnType<typeof getDb>,
-  validatedRow: SaveDraft,
-  updateData: UpdateData
-): { result: DraftSaveResult; savedId: number } => {
-  if (updateData.updateFields.length === 0 || !validatedRow.id) {
-    return {
-      result: {
-        changes: 0,
-        lastInsertRowid: validatedRow.id ?? 0,
-      },
-      savedId: validatedRow.id ?? 0,
-    };
-  }
-
-  const updateSql = `UPDATE timesheet SET ${updateData.updateFields.join(
-    ", "
-  )} WHERE id = ? AND status IS NULL`;
-  const update = db.prepare(updateSql);
-  const result = update.run(...updateData.updateValues, validatedRow.id);
-  return { result, savedId: validatedRow.id as number };
-};
-
-const runInsert = (
-  db: ReturnType<typeof getDb>,
-  validatedRow: SaveDraft
-): DraftSaveResult => {
-  const insert = db.prepare(`
-      INSERT INTO timesheet
-      (date, hours, project, tool, detail_charge_code, task_description, status)
-      VALUES (?, ?, ?, ?, ?, ?, NULL)
-    `);
-
-  return insert.run(
-    validatedRow.date || null,
-    validatedRow.hours || null,
-    validatedRow.project || null,
-    validatedRow.tool || null,
-    validatedRow.chargeCode || null,
-    validatedRow.taskDescription || null
-  );
-};
-
-const getSavedEntry = (
-  db: ReturnType<typeof getDb>,
-  savedId: number
-): DraftRowEntry | undefined => {
-  const getEntry = db.prepare(`SELECT * FROM timesheet WHERE id = ?`);
-  return getEntry.get(savedId) as DraftRowEntry | undefined;
-};
-
-const saveDraftEntry = (
-  db: ReturnType<typeof getDb>,
-  validatedRow: SaveDraft
-): SaveDraftTransactionResult => {
-  if (validatedRow.id) {
-    ipcLogger.debug(
-      "Updating existing timesheet entry (partial data allowed)",
-      { id: validatedRow.id }
-    );
-    const updateData = getUpdateData(validatedRow);
-    const { result, savedId } = runUpdate(db, validatedRow, updateData);
-    return {
-      result,
-      savedId,
-      savedEntry: getSavedEntry(db, savedId),
-    };
-  }
-
-  ipcLogger.debug("Inserting new timesheet entry (partial data allowed)");
-  const result = runInsert(db, validatedRow);
-  const savedId =
-    typeof result.lastInsertRowid === "bigint"
-      ? Number(result.lastInsertRowid)
-      : result.lastInsertRowid;
-  return {
-    result,
-    savedId,
-    savedEntry: getSavedEntry(db, savedId),
-  };
-};
-
 const formatSavedEntry = (savedEntry: DraftRowEntry) => ({
-  id: savedEntry.id,
-  date: savedEntry.date,
+  ...toTimesheetRow(savedEntry),
   hours: savedEntry.hours ?? 0,
-  project: savedEntry.project,
-  tool: savedEntry.tool || null,
-  chargeCode: savedEntry.detail_charge_code || null,
-  taskDescription: savedEntry.task_description,
 });
 
+const getRuleViolations = (savedEntry: DraftRowEntry): RuleViolation[] => {
+  try {
+    return evaluateValidationRules(toRuleEntry(savedEntry), listValidationRules(true));
+  } catch (err: unknown) {
+    // The entry is already saved; a rule lookup failure must not report otherwise
+    ipcLogger.warn("Could not evaluate validation rules for draft", {
+      id: savedEntry.id,
+      error: err instanceof Error ? err.message : String(err),
+    });
+    return [];
+  }
+};
+
 const buildSaveDraftResponse = (
-  result: DraftSaveResult,
+  changes: number,
   savedId: number,
   savedEntry?: DraftRowEntry
 ) => {
+  // Saving is still allowed; the warning tells the user the form may reject it
+  const backfillWarning = getBackfillWarning(savedEntry?.date, new Date(), savedId);
+  // Drafts may be incomplete, so rule violations (even blocking ones) are
+  // reported here and only enforced when submitting
+  const ruleViolations = savedEntry ? getRuleViolations(savedEntry) : [];
+  const warningFields = {
+    ...(backfillWarning ? { warning: backfillWarning } : {}),
+    ...(ruleViolations.length > 0 ? { ruleViolations } : {}),
+  };
+
   if (savedEntry) {
     return {
       success: true,
-      changes: result.changes,
+      changes,
       id: savedId,
       entry: formatSavedEntry(savedEntry),
+      ...warningFields,
     };
   }
 
-  return { success: true, changes: result.changes, id: savedId };
+  return { success: true, changes, id: savedId, ...warningFields };
 };
 
 export const handleSaveDraft = async (
   event: Electron.IpcMainInvokeEvent,
   row: SaveDraft
 ) => {
-  const timer = ipcLogger.startTimer("save-draft");
-
   if (!isTrustedIpcSender(event)) {
-    timer.done({ outcome: "error", reason: "unauthorized" });
+    ipcLogger.startTimer("save-draft").done({ outcome: "error", reason: "unauthorized" });
     return {
       success: false,
       error: "Could not save draft: unauthorized request",
     };
   }
 
+  const locked = getAppLockError("timesheet:saveDraft");
+  if (locked) {
+    return { success: false, error: locked.message, code: locked.code };
+  }
+
+  return saveDraftRow(row);
+};
+
+export type SaveDraftResponse =
+  | ReturnType<typeof buildSaveDraftResponse>
+  | { success: false; error: string | undefined };
+
+/**
+ * Validates and saves one draft row (the caller has already checked the sender)
+ */
+export const saveDraftRow = (row: SaveDraft): SaveDraftResponse => {
+  const timer = ipcLogger.startTimer("save-draft");
+
   const validation = validateInput(saveDraftSchema, row, "timesheet:saveDraft");
   if (!validation.success) {
     timer.done({ outcome: "error", error: "validation-failed" });
@@ -200,6 +110,67 @@ export const handleSaveDraft = async (
 
   const validatedRow = validation.data!;
 
+  if (
+    validatedRow.id &&
+    getTimesheetEntryStatus(validatedRow.id) === SubmissionStatus.COMPLETE
+  ) {
+    ipcLogger.security(
+      "submitted-entry-edit-blocked",
+      "Attempt to edit a submitted timesheet entry",
+      { id: validatedRow.id }
+    );
+    timer.done({ outcome: "error", error: "entry-locked" });
+    return { success: false, error: SUBMITTED_ENTRY_LOCKED_ERROR };
+  }
+
+  const chargeCodeError = checkChargeCode(validatedRow.chargeCode, getChargeCodeRule());
+  if (chargeCodeError) {
+    ipcLogger.warn("Draft rejected by charge code rule", {
+      id: validatedRow.id,
+      chargeCode: validatedRow.chargeCode,
+    });
+    timer.done({ outcome: "error", error: "invalid-charge-code" });
+    return { success: false, error: chargeCodeError };
+  }
+
+  const externalRefError = checkExternalRef(
+    validatedRow.externalRef,
+    resolveExternalRefRule(loadSettings().external_ref).rule
+  );
+  if (externalRefError) {
+    ipcLogger.warn("Draft rejected by external reference format", {
+      id: validatedRow.id,
+      externalRef: validatedRow.externalRef,
+    });
+    timer.done({ outcome: "error", error: "invalid-external-ref" });
+    return { success: false, error: externalRefError };
+  }
+
+  const categoryError = checkEntryCategory(validatedRow.category, listEntryCategories());
+  if (categoryError) {
+    ipcLogger.warn("Draft rejected for unknown category", {
+      id: validatedRow.id,
+      category: validatedRow.category,
+    });
+    timer.done({ outcome: "error", error: "unknown-category" });
+    return { success: false, error: categoryError };
+  }
+
+  const retiredProjectError = checkProjectActive(
+    validatedRow.project,
+    validatedRow.date,
+    getProjectRetirements()
+  );
+  if (retiredProjectError) {
+    ipcLogger.warn("Draft rejected for retired project", {
+      id: validatedRow.id,
+      project: validatedRow.project,
+      date: validatedRow.date,
+    });
+    timer.done({ outcome: "error", error: "retired-project" });
+    return { success: false, error: retiredProjectError };
+  }
+
   try {
     ipcLogger.verbose("Saving draft timesheet entry (partial data allowed)", {
       id: validatedRow.id,
@@ -208,21 +179,24 @@ export const handleSaveDraft = async (
       project: validatedRow.project,
     });
 
-    const db = getDb();
-    const saveTransaction = db.transaction(() =>
-      saveDraftEntry(db, validatedRow)
+    ipcLogger.debug(
+      validatedRow.id
+        ? "Updating existing timesheet entry (partial data allowed)"
+        : "Inserting new timesheet entry (partial data allowed)",
+      { id: validatedRow.id }
     );
-    const { result, savedId, savedEntry } = saveTransaction();
+    const { id: savedId, ...fields } = validatedRow;
+    const saved = saveDraftTimesheetEntry(savedId, fields);
 
     ipcLogger.info("Draft timesheet entry saved", {
-      id: savedId,
-      changes: result.changes,
+      id: saved.id,
+      changes: saved.changes,
       date: validatedRow.date,
       project: validatedRow.project,
     });
-    timer.done({ changes: result.changes });
+    timer.done({ changes: saved.changes });
 
-    return buildSaveDraftResponse(result, savedId, savedEntry);
+    return buildSaveDraftResponse(saved.changes, saved.id, saved.entry);
   } catch (err: unknown) {
     ipcLogger.error("Could not save draft timesheet entry", err);
     const errorMessage = err instanceof Error ? err.message : String(err);
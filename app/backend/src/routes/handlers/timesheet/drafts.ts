@@ -1,17 +1,32 @@
 import { ipcMain } from 'electron';
 import { ipcLogger } from '@sheetpilot/shared/logger';
 import {
+  handleAmendEntry,
   handleDeleteDraft,
+  handleFlushDirtyDrafts,
   handleLoadDraft,
   handleLoadDraftById,
+  handleMarkDirtyDrafts,
+  handleReassignRetiredProject,
 } from './drafts.handlers';
 import { handleSaveDraft } from './drafts.save';
+import { onDraftsSaved } from './drafts.autosave';
+import { emitDraftsSaved } from './main-window';
+
+let unsubscribeDraftsSaved: (() => void) | null = null;
 
 export function registerTimesheetDraftHandlers(): void {
   ipcMain.handle('timesheet:saveDraft', handleSaveDraft);
   ipcMain.handle('timesheet:deleteDraft', handleDeleteDraft);
   ipcMain.handle('timesheet:loadDraft', handleLoadDraft);
   ipcMain.handle('timesheet:loadDraftById', handleLoadDraftById);
+  ipcMain.handle('timesheet:amendEntry', handleAmendEntry);
+  ipcMain.handle('timesheet:markDirty', handleMarkDirtyDrafts);
+  ipcMain.handle('timesheet:flushDirty', handleFlushDirtyDrafts);
+  ipcMain.handle('timesheet:reassignRetiredProject', handleReassignRetiredProject);
+
+  unsubscribeDraftsSaved?.();
+  unsubscribeDraftsSaved = onDraftsSaved(emitDraftsSaved);
 
   ipcLogger.verbose('Timesheet draft handlers registered');
 }
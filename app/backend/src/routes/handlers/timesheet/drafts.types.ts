@@ -1,9 +1,4 @@
-export type DraftRowEntry = {
-  id: number;
-  date: string;
-  hours: number | null;
-  project: string;
-  tool?: string | null;
-  detail_charge_code?: string | null;
-  task_description: string;
-};
+import type { TimesheetRowSource } from "@sheetpilot/shared";
+
+/** Timesheet table row returned by the draft queries */
+export type DraftRowEntry = TimesheetRowSource;
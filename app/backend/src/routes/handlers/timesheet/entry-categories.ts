@@ -0,0 +1,119 @@
+import { ipcMain } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import {
+  deleteEntryCategory,
+  listEntryCategories,
+  saveEntryCategory,
+} from "@/services/timesheet/entry-categories";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  entryCategoryIdQuerySchema,
+  entryCategorySchema,
+  type EntryCategoryInput,
+} from "@/validation/ipc-schemas";
+import { getAppLockError } from "@/services/timesheet/app-lock";
+import { isTrustedIpcSender } from "./main-window";
+
+export function registerTimesheetEntryCategoryHandlers(): void {
+  ipcMain.handle("timesheet:listEntryCategories", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list categories: unauthorized request",
+      };
+    }
+    try {
+      return { success: true, categories: listEntryCategories() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not list categories", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "timesheet:saveEntryCategory",
+    async (event, category: EntryCategoryInput) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not save category: unauthorized request",
+        };
+      }
+      // A rename rewrites entries, so it waits like any other entry change
+      const locked = getAppLockError("timesheet:saveEntryCategory");
+      if (locked) {
+        return { success: false, error: locked.message, code: locked.code };
+      }
+      const validation = validateInput(
+        entryCategorySchema,
+        category,
+        "timesheet:saveEntryCategory"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const { category: saved, renamedEntries } = saveEntryCategory(validation.data!);
+        ipcLogger.info("Entry category saved", {
+          id: saved.id,
+          name: saved.name,
+          created: !validation.data!.id,
+          renamedEntries,
+        });
+        return { success: true, category: saved, renamedEntries };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not save category", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "timesheet:deleteEntryCategory",
+    async (event, categoryId: string) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not delete category: unauthorized request",
+        };
+      }
+      const locked = getAppLockError("timesheet:deleteEntryCategory");
+      if (locked) {
+        return { success: false, error: locked.message, code: locked.code };
+      }
+      const validation = validateInput(
+        entryCategoryIdQuerySchema,
+        { categoryId },
+        "timesheet:deleteEntryCategory"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const clearedEntries = deleteEntryCategory(validation.data!.categoryId);
+        if (clearedEntries === null) {
+          return { success: false, error: "Category not found" };
+        }
+        ipcLogger.info("Entry category deleted", {
+          id: validation.data!.categoryId,
+          clearedEntries,
+        });
+        return { success: true, clearedEntries };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not delete category", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcLogger.verbose("Timesheet entry category handlers registered");
+}
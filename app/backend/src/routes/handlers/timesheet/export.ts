@@ -1,86 +1,242 @@
-import { ipcMain } from "electron";
+import { clipboard, ipcMain } from "electron";
 import { ipcLogger } from "@sheetpilot/shared/logger";
-import { getSubmittedTimesheetEntriesForExport } from "@/models";
+import { normalizeDateToISO } from "@sheetpilot/shared";
+import {
+  getSubmittedTimesheetEntriesForExport,
+  getTimesheetEntriesInRange,
+} from "@/models";
+import {
+  buildTimesheetCsv,
+  EXPORT_COLUMNS,
+  summarizeCorrectedTotals,
+} from "@/services/timesheet/csv-export";
+import {
+  deleteExportTemplate,
+  listExportTemplates,
+  resolveExportTemplate,
+  saveExportTemplate,
+} from "@/services/timesheet/export-templates";
+import { buildClipboardSnippet } from "@/services/timesheet/clipboard-export";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  clipboardExportSchema,
+  exportTemplateNameQuerySchema,
+  exportTemplateSchema,
+  type ClipboardExportRequest,
+  type ExportTemplateInput,
+} from "@/validation/ipc-schemas";
 import { isTrustedIpcSender } from "./main-window";
+import { getMessage } from "@/services/i18n/message-catalog";
+import { enqueueJob } from "@/services/jobs/job-manager";
+
+export const CSV_EXPORT_JOB_TYPE = "csv-export";
 
 export function registerTimesheetExportHandlers(): void {
-  ipcMain.handle("timesheet:exportToCSV", async (event) => {
+  ipcMain.handle("timesheet:exportToCSV", async (event, templateName?: string) => {
     if (!isTrustedIpcSender(event)) {
       return {
         success: false,
         error: "Could not export CSV: unauthorized request",
       };
     }
-    ipcLogger.verbose("Exporting timesheet data to CSV");
-    try {
-      const entries = getSubmittedTimesheetEntriesForExport() as Array<{
-        date: string;
-        hours: number | null;
-        project: string;
-        tool?: string;
-        detail_charge_code?: string;
-        task_description: string;
-        status: string;
-        submitted_at: string;
-      }>;
+    const validation = validateInput(
+      exportTemplateNameQuerySchema,
+      { templateName },
+      "timesheet:exportToCSV"
+    );
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    ipcLogger.verbose("Exporting timesheet data to CSV", { templateName });
+    const jobId = enqueueJob(
+      CSV_EXPORT_JOB_TYPE,
+      async () => {
+        const template = resolveExportTemplate(validation.data!.templateName);
+        const entries = getSubmittedTimesheetEntriesForExport();
 
-      if (entries.length === 0) {
-        return {
-          success: false,
-          error: "No submitted timesheet entries found to export",
-        };
-      }
+        if (entries.length === 0) {
+          throw new Error("No submitted timesheet entries found to export");
+        }
 
-      const headers = [
-        "Date",
-        "Hours",
-        "Project",
-        "Tool",
-        "Charge Code",
-        "Task Description",
-        "Status",
-        "Submitted At",
-      ];
+        const csvContent = buildTimesheetCsv(entries, template);
+        const totals = summarizeCorrectedTotals(entries);
 
-      const csvRows = [headers.join(",")];
+        ipcLogger.info("CSV export completed", {
+          entryCount: entries.length,
+          csvSize: csvContent.length,
+          template: template?.name ?? null,
+          ...totals,
+        });
 
-      for (const entry of entries) {
-        const row = [
-          entry.date,
-          entry.hours !== null && entry.hours !== undefined
-            ? entry.hours.toFixed(2)
-            : "",
-          `"${entry.project.replace(/"/g, '""')}"`,
-          `"${(entry.tool || "").replace(/"/g, '""')}"`,
-          `"${(entry.detail_charge_code || "").replace(/"/g, '""')}"`,
-          `"${entry.task_description.replace(/"/g, '""')}"`,
-          entry.status,
-          entry.submitted_at,
-        ];
-        csvRows.push(row.join(","));
+        return {
+          csvData: csvContent,
+          csvContent,
+          entryCount: entries.length,
+          totals,
+          filename: `timesheet_export_${new Date().toISOString().split("T")[0]}.csv`,
+        };
+      },
+      {
+        detail: { templateName: validation.data!.templateName ?? null },
+        // The CSV itself only goes to the renderer, not into run history
+        recordResult: (result) => {
+          const { entryCount, totals, filename } = result as {
+            entryCount: number;
+            totals: Record<string, unknown>;
+            filename: string;
+          };
+          return { entryCount, ...totals, filename };
+        },
       }
+    );
+    return { success: true, jobId };
+  });
 
-      const csvContent = csvRows.join("\n");
-
-      ipcLogger.info("CSV export completed", {
-        entryCount: entries.length,
-        csvSize: csvContent.length,
-      });
+  ipcMain.handle(
+    "timesheet:exportToClipboard",
+    async (event, request: ClipboardExportRequest) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not copy entries: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        clipboardExportSchema,
+        request,
+        "timesheet:exportToClipboard"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const { format, includeDrafts, templateName } = validation.data!;
+      const startDate = normalizeDateToISO(validation.data!.startDate);
+      const endDate = normalizeDateToISO(validation.data!.endDate);
+      if (startDate > endDate) {
+        return { success: false, error: getMessage("DATE_RANGE_INVALID") };
+      }
+      try {
+        const entries = getTimesheetEntriesInRange(startDate, endDate, includeDrafts ?? false);
+        if (entries.length === 0) {
+          return { success: false, error: "No entries found in that date range" };
+        }
+        const snippet = buildClipboardSnippet(
+          entries,
+          format,
+          resolveExportTemplate(templateName)
+        );
+        clipboard.writeText(snippet);
+        ipcLogger.info("Entries copied to clipboard", {
+          startDate,
+          endDate,
+          format,
+          entryCount: entries.length,
+        });
+        return { success: true, entryCount: entries.length, characters: snippet.length };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not copy entries to clipboard", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
 
+  ipcMain.handle("timesheet:listExportTemplates", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list export templates: unauthorized request",
+      };
+    }
+    try {
       return {
         success: true,
-        csvData: csvContent,
-        csvContent,
-        entryCount: entries.length,
-        filename: `timesheet_export_${new Date().toISOString().split("T")[0]}.csv`,
+        templates: listExportTemplates(),
+        columns: EXPORT_COLUMNS.map(({ id, header }) => ({ id, header })),
       };
     } catch (err: unknown) {
-      ipcLogger.error("Could not export CSV", err);
-      const errorMessage =
-        err instanceof Error ? err.message : "Could not export timesheet data";
-      return { success: false, error: errorMessage };
+      ipcLogger.error("Could not list export templates", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
     }
   });
 
+  ipcMain.handle(
+    "timesheet:saveExportTemplate",
+    async (event, template: ExportTemplateInput) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not save export template: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        exportTemplateSchema,
+        template,
+        "timesheet:saveExportTemplate"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const templates = saveExportTemplate(validation.data!);
+        ipcLogger.info("Export template saved", {
+          name: validation.data!.name,
+          columns: validation.data!.columns.length,
+        });
+        return { success: true, templates };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not save export template", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "timesheet:deleteExportTemplate",
+    async (event, templateName: string) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not delete export template: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        exportTemplateNameQuerySchema,
+        { templateName },
+        "timesheet:deleteExportTemplate"
+      );
+      if (!validation.success || !validation.data!.templateName) {
+        return {
+          success: false,
+          error: validation.error ?? "Template name is required",
+        };
+      }
+      try {
+        const deleted = deleteExportTemplate(validation.data!.templateName);
+        if (!deleted) {
+          return { success: false, error: "Export template not found" };
+        }
+        ipcLogger.info("Export template deleted", {
+          name: validation.data!.templateName,
+        });
+        return { success: true };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not delete export template", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
   ipcLogger.verbose("Timesheet export handlers registered");
 }
@@ -5,6 +5,16 @@ import { registerTimesheetSubmissionHandlers } from './submission';
 import { registerTimesheetDevHandlers } from './dev';
 import { registerTimesheetResetHandlers } from './reset';
 import { registerTimesheetExportHandlers } from './export';
+import { registerTimesheetSuggestionHandlers } from './suggestions';
+import { registerTimesheetCalendarImportHandlers } from './calendar-import';
+import { registerTimesheetReportHandlers } from './reports';
+import { registerTimesheetReconcileHandlers } from './reconcile';
+import { registerTimesheetDraftStatsHandlers } from './draft-stats';
+import { registerTimesheetStatusCountsHandlers } from './status-counts';
+import { registerTimesheetAppLockHandlers } from './app-lock';
+import { registerTimesheetQuarterHandlers } from './quarters';
+import { registerTimesheetDescriptionTemplateHandlers } from './description-templates';
+import { registerTimesheetEntryCategoryHandlers } from './entry-categories';
 
 export function registerTimesheetHandlers(): void {
   registerTimesheetSubmissionHandlers();
@@ -12,6 +22,16 @@ export function registerTimesheetHandlers(): void {
   registerTimesheetDevHandlers();
   registerTimesheetResetHandlers();
   registerTimesheetExportHandlers();
+  registerTimesheetSuggestionHandlers();
+  registerTimesheetCalendarImportHandlers();
+  registerTimesheetReportHandlers();
+  registerTimesheetReconcileHandlers();
+  registerTimesheetDraftStatsHandlers();
+  registerTimesheetStatusCountsHandlers();
+  registerTimesheetAppLockHandlers();
+  registerTimesheetQuarterHandlers();
+  registerTimesheetDescriptionTemplateHandlers();
+  registerTimesheetEntryCategoryHandlers();
 }
 
 export function setMainWindowRef(window: BrowserWindow | null): void {
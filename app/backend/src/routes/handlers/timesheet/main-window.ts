@@ -1,19 +1,74 @@
 import type { BrowserWindow, IpcMainInvokeEvent, WebContents } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import {
+  getChannelCapability,
+  MAIN_WINDOW_LABEL,
+  windowHasCapability,
+} from '@/logic/ipc-capabilities';
+import { EventThrottle } from '@/services/events/event-throttle';
+import type { JobSnapshot } from '@/services/jobs/job-manager';
+import type { ClockSkewStatus } from '@/services/health/clock-skew';
 
 let mainWindowRef: BrowserWindow | null = null;
 let mainWebContentsId: number | null = null;
+const windowLabels = new Map<number, string>();
+
+// Every event for the main window goes through here so bursts cannot flood the renderer
+const rendererEvents = new EventThrottle((channel, payload) => {
+  if (mainWindowRef && !mainWindowRef.isDestroyed()) {
+    mainWindowRef.webContents.send(channel, payload);
+  }
+});
 
 export function setMainWindow(window: BrowserWindow | null): void {
+  // Events queued for a closed window are stale for its replacement
+  rendererEvents.clear();
+  if (mainWebContentsId !== null) {
+    windowLabels.delete(mainWebContentsId);
+  }
   mainWindowRef = window;
   mainWebContentsId = window?.webContents.id ?? null;
+  if (mainWebContentsId !== null) {
+    windowLabels.set(mainWebContentsId, MAIN_WINDOW_LABEL);
+  }
+}
+
+/**
+ * Registers a secondary window so it can invoke the commands its label allows
+ */
+export function registerIpcWindow(window: BrowserWindow, label: string): void {
+  const id = window.webContents.id;
+  windowLabels.set(id, label);
+  window.once('closed', () => windowLabels.delete(id));
 }
 
-export function isTrustedIpcSender(event: IpcMainInvokeEvent): boolean {
-  if (mainWebContentsId === null) {
+export function getSenderWindowLabel(event: IpcMainInvokeEvent): string | undefined {
+  const sender: WebContents | undefined = event.sender;
+  return sender ? windowLabels.get(sender.id) : undefined;
+}
+
+/**
+ * Checks the invoking window is one of ours and, when a channel is given,
+ * that its label grants the capability the channel requires
+ */
+export function isTrustedIpcSender(event: IpcMainInvokeEvent, channel?: string): boolean {
+  const label = getSenderWindowLabel(event);
+  if (label === undefined) {
     return false;
   }
-  const sender: WebContents | undefined = event.sender;
-  return sender.id === mainWebContentsId;
+  if (channel === undefined) {
+    return true;
+  }
+  const capability = getChannelCapability(channel);
+  if (windowHasCapability(label, capability)) {
+    return true;
+  }
+  ipcLogger.security('ipc-capability-denied', 'Window lacks capability for command', {
+    channel,
+    capability,
+    label,
+  });
+  return false;
 }
 
 export function emitSubmissionProgress(progressData: {
@@ -22,9 +77,46 @@ export function emitSubmissionProgress(progressData: {
   total: number;
   message: string;
 }): void {
-  if (mainWindowRef && !mainWindowRef.isDestroyed()) {
-    mainWindowRef.webContents.send('timesheet:progress', progressData);
-  }
+  rendererEvents.emit('timesheet:progress', progressData, { coalesceKey: 'timesheet:progress' });
 }
 
+export function emitJobProgress(job: JobSnapshot): void {
+  // Each job keeps its own latest update so one busy job cannot hide another's
+  rendererEvents.emit('job:progress', job, { coalesceKey: `job:progress:${job.id}` });
+}
+
+export function emitDraftsSaved(results: unknown[]): void {
+  rendererEvents.emit(
+    'timesheet:draftSaved',
+    { results },
+    {
+      coalesceKey: 'timesheet:draftSaved',
+      merge: (pending, next) => ({ results: [...pending.results, ...next.results] }),
+    }
+  );
+}
+
+export function emitSessionRevoked(payload: { reason: string }): void {
+  rendererEvents.emit('auth:sessionRevoked', payload);
+}
+
+export function emitSettingsChanged(payload: { keys: string[] }): void {
+  rendererEvents.emit('settings:changed', payload, {
+    coalesceKey: 'settings:changed',
+    merge: (pending, next) => ({ keys: [...new Set([...pending.keys, ...next.keys])] }),
+  });
+}
 
+export function emitClockSkew(status: ClockSkewStatus): void {
+  rendererEvents.emit('app:clockSkew', status, { coalesceKey: 'app:clockSkew' });
+}
+
+export function emitStatusCounts(counts: unknown): void {
+  rendererEvents.emit('timesheet:statusCounts', counts, { coalesceKey: 'timesheet:statusCounts' });
+}
+
+export function setMainWindowTitle(title: string): void {
+  if (mainWindowRef && !mainWindowRef.isDestroyed()) {
+    mainWindowRef.setTitle(title);
+  }
+}
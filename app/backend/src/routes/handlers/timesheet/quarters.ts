@@ -0,0 +1,26 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getQuarters } from '@sheetpilot/bot';
+import { isTrustedIpcSender } from './main-window';
+
+export function registerTimesheetQuarterHandlers(): void {
+  ipcMain.handle('timesheet:getQuarters', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get quarters: unauthorized request' };
+    }
+
+    try {
+      const quarters = getQuarters();
+      ipcLogger.verbose('Quarters listed', {
+        quarters: quarters.map((quarter) => ({ id: quarter.id, status: quarter.status }))
+      });
+      return { success: true, quarters };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get quarters', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet quarter handlers registered');
+}
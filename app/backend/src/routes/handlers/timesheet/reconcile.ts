@@ -0,0 +1,45 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { extractSubmissionKeys } from '@sheetpilot/bot';
+import { reconcileSubmissionKeys } from '@/models';
+import { validateInput } from '@/validation/validate-ipc-input';
+import { reconcileSubmissionKeysSchema } from '@/validation/ipc-schemas';
+import { isTrustedIpcSender } from './main-window';
+
+export function registerTimesheetReconcileHandlers(): void {
+  ipcMain.handle('timesheet:reconcileSubmissionKeys', async (event, text: string) => {
+    const timer = ipcLogger.startTimer('reconcile-submission-keys');
+    if (!isTrustedIpcSender(event)) {
+      timer.done({ outcome: 'error', reason: 'unauthorized' });
+      return { success: false, error: 'Could not reconcile submissions: unauthorized request' };
+    }
+    const validation = validateInput(reconcileSubmissionKeysSchema, { text }, 'timesheet:reconcileSubmissionKeys');
+    if (!validation.success) {
+      timer.done({ outcome: 'error', reason: 'validation' });
+      return { success: false, error: validation.error };
+    }
+    try {
+      const keys = extractSubmissionKeys(validation.data!.text);
+      if (keys.length === 0) {
+        timer.done({ keys: 0 });
+        return { success: false, error: 'No submission keys (SP-…) found in the sheet contents' };
+      }
+      const result = reconcileSubmissionKeys(keys);
+      ipcLogger.info('Submission keys reconciled', {
+        keys: keys.length,
+        matched: result.matchedIds.length,
+        alreadyComplete: result.alreadyCompleteKeys.length,
+        unknown: result.unknownKeys.length
+      });
+      timer.done({ keys: keys.length, matched: result.matchedIds.length });
+      return { success: true, keysFound: keys.length, ...result };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not reconcile submission keys', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      timer.done({ outcome: 'error', error: errorMessage });
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet reconcile handlers registered');
+}
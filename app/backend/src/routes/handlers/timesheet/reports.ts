@@ -0,0 +1,234 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import { getActivityHeatmap, getToolUsage, getWeekDiffEntries, type ToolUsageRow } from '@/models';
+import { validateInput } from '@/validation/validate-ipc-input';
+import {
+  activityHeatmapSchema,
+  diffWeekSchema,
+  missingTimesheetDaysSchema,
+  toolUsageQuerySchema,
+  weeklySummaryExportSchema,
+  type MissingTimesheetDaysQuery,
+  type ToolUsageQuery,
+  type WeeklySummaryExportRequest,
+} from '@/validation/ipc-schemas';
+import { buildToolUsageCsv } from '@/services/timesheet/csv-export';
+import { getChargeCodeQuotaStatus } from '@/services/timesheet/charge-code-quotas';
+import { getMissingTimesheetDays } from '@/services/timesheet/timesheet-gaps';
+import { buildAccessibleWeeklySummary } from '@/services/timesheet/weekly-summary-export';
+import { diffWeek, getWeekEnd } from '@/logic/week-diff';
+import { flushDirtyDrafts } from './drafts.autosave';
+import { isTrustedIpcSender } from './main-window';
+import { getMessage } from '@/services/i18n/message-catalog';
+
+type ToolUsageReport = {
+  startDate: string;
+  endDate: string;
+  rows: ToolUsageRow[];
+  totalHours: number;
+};
+
+const buildToolUsageReport = (
+  query: ToolUsageQuery,
+  channel: string
+): { success: true; report: ToolUsageReport } | { success: false; error: string } => {
+  const validation = validateInput(toolUsageQuerySchema, query, channel);
+  if (!validation.success) {
+    return { success: false, error: validation.error ?? 'Validation failed' };
+  }
+  const startDate = normalizeDateToISO(validation.data!.startDate);
+  const endDate = normalizeDateToISO(validation.data!.endDate);
+  if (startDate > endDate) {
+    return { success: false, error: getMessage('DATE_RANGE_INVALID') };
+  }
+
+  const rows = getToolUsage(startDate, endDate, validation.data!.includeDrafts ?? false);
+  const totalHours = Math.round(rows.reduce((sum, row) => sum + row.hours, 0) * 100) / 100;
+  return { success: true, report: { startDate, endDate, rows, totalHours } };
+};
+
+export function registerTimesheetReportHandlers(): void {
+  ipcMain.handle('timesheet:getToolUsage', async (event, query: ToolUsageQuery) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get tool usage: unauthorized request' };
+    }
+    try {
+      const result = buildToolUsageReport(query, 'timesheet:getToolUsage');
+      if (!result.success) {
+        return result;
+      }
+      ipcLogger.verbose('Tool usage report computed', {
+        startDate: result.report.startDate,
+        endDate: result.report.endDate,
+        groups: result.report.rows.length,
+      });
+      return { success: true, ...result.report };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get tool usage', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:exportToolUsageCSV', async (event, query: ToolUsageQuery) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not export tool usage: unauthorized request' };
+    }
+    try {
+      const result = buildToolUsageReport(query, 'timesheet:exportToolUsageCSV');
+      if (!result.success) {
+        return result;
+      }
+      if (result.report.rows.length === 0) {
+        return { success: false, error: 'No timesheet entries found in the selected range' };
+      }
+      const csvContent = buildToolUsageCsv(result.report.rows);
+      ipcLogger.info('Tool usage CSV export completed', {
+        groups: result.report.rows.length,
+        csvSize: csvContent.length,
+      });
+      return {
+        success: true,
+        csvContent,
+        rowCount: result.report.rows.length,
+        filename: `tool_usage_${result.report.startDate}_to_${result.report.endDate}.csv`,
+      };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not export tool usage', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:getQuotaStatus', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get quota status: unauthorized request' };
+    }
+    try {
+      flushDirtyDrafts();
+      const quotas = getChargeCodeQuotaStatus();
+      ipcLogger.verbose('Charge code quota status computed', {
+        quotas: quotas.length,
+        over: quotas.filter((quota) => quota.state === 'over').length,
+      });
+      return { success: true, quotas };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get quota status', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:getActivityHeatmap', async (event, year: number) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get activity heatmap: unauthorized request' };
+    }
+    const validation = validateInput(activityHeatmapSchema, { year }, 'timesheet:getActivityHeatmap');
+    if (!validation.success) {
+      return { success: false, error: validation.error ?? 'Validation failed' };
+    }
+    try {
+      flushDirtyDrafts();
+      const days = getActivityHeatmap(validation.data!.year);
+      ipcLogger.verbose('Activity heatmap computed', {
+        year: validation.data!.year,
+        activeDays: days.filter((day) => day.submittedHours > 0 || day.draftHours > 0).length,
+      });
+      return { success: true, year: validation.data!.year, days };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get activity heatmap', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:getMissingDays', async (event, query: MissingTimesheetDaysQuery) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not check for missing days: unauthorized request' };
+    }
+    const validation = validateInput(missingTimesheetDaysSchema, query, 'timesheet:getMissingDays');
+    if (!validation.success) {
+      return { success: false, error: validation.error ?? 'Validation failed' };
+    }
+    const startDate = normalizeDateToISO(validation.data!.startDate);
+    const endDate = normalizeDateToISO(validation.data!.endDate);
+    if (startDate > endDate) {
+      return { success: false, error: getMessage('DATE_RANGE_INVALID') };
+    }
+    try {
+      flushDirtyDrafts();
+      const result = getMissingTimesheetDays(startDate, endDate);
+      ipcLogger.verbose('Missing timesheet days computed', {
+        startDate: result.startDate,
+        endDate: result.endDate,
+        missing: result.days.length,
+      });
+      return { success: true, ...result };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not check for missing days', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:diffWeek', async (event, weekStart: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not compare week: unauthorized request' };
+    }
+    const validation = validateInput(diffWeekSchema, { weekStart }, 'timesheet:diffWeek');
+    if (!validation.success) {
+      return { success: false, error: validation.error ?? 'Validation failed' };
+    }
+    try {
+      flushDirtyDrafts();
+      const start = normalizeDateToISO(validation.data!.weekStart);
+      const diff = diffWeek(start, getWeekDiffEntries(start, getWeekEnd(start)));
+      ipcLogger.verbose('Week diff computed', {
+        weekStart: start,
+        added: diff.added.length,
+        removed: diff.removed.length,
+        modified: diff.modified.length,
+      });
+      return { success: true, diff };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not compare week', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:exportWeeklySummary', async (event, request: WeeklySummaryExportRequest) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not export weekly summary: unauthorized request' };
+    }
+    const validation = validateInput(weeklySummaryExportSchema, request, 'timesheet:exportWeeklySummary');
+    if (!validation.success) {
+      return { success: false, error: validation.error ?? 'Validation failed' };
+    }
+    try {
+      const { format, includeDrafts } = validation.data!;
+      if (includeDrafts) {
+        flushDirtyDrafts();
+      }
+      const start = normalizeDateToISO(validation.data!.weekStart);
+      const summary = buildAccessibleWeeklySummary(
+        start,
+        getWeekDiffEntries(start, getWeekEnd(start)),
+        format,
+        includeDrafts ?? false
+      );
+      ipcLogger.info('Weekly summary export completed', {
+        weekStart: start,
+        format,
+        entryCount: summary.entryCount,
+      });
+      return {
+        success: true,
+        content: summary.content,
+        entryCount: summary.entryCount,
+        totalHours: summary.totalHours,
+        filename: `weekly_summary_${start}.${format === 'markdown' ? 'md' : 'txt'}`,
+      };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not export weekly summary', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet report handlers registered');
+}
@@ -1,6 +1,10 @@
 import { ipcMain } from 'electron';
 import { ipcLogger } from '@sheetpilot/shared/logger';
+import { SubmissionLockedError } from '@sheetpilot/shared/errors';
 import { resetInProgressTimesheetEntries } from '@/models';
+import { getAppLockState } from '@/services/timesheet/app-lock';
+import { isTimesheetSubmissionInProgress } from '@/services/timesheet/submission-workflow';
+import { getMessage } from '@/services/i18n/message-catalog';
 import { isTrustedIpcSender } from './main-window';
 
 export function registerTimesheetResetHandlers(): void {
@@ -10,6 +14,19 @@ export function registerTimesheetResetHandlers(): void {
       timer.done({ outcome: 'error', reason: 'unauthorized' });
       return { success: false, error: 'Could not reset in-progress entries: unauthorized request' };
     }
+    // The running bot still holds these rows; it resets them itself when it
+    // finishes or is cancelled, and only then releases the lock
+    if (isTimesheetSubmissionInProgress()) {
+      const locked = new SubmissionLockedError(getMessage('SUBMISSION_LOCKED'), {
+        action: 'timesheet:resetInProgress',
+        runId: getAppLockState().runId,
+      });
+      ipcLogger.warn('Reset of in-progress entries refused while a submission is running', {
+        runId: getAppLockState().runId,
+      });
+      timer.done({ outcome: 'error', reason: 'locked' });
+      return { success: false, error: locked.message, code: locked.code };
+    }
     try {
       ipcLogger.info('Resetting in-progress entries to NULL status');
       const resetCount = resetInProgressTimesheetEntries();
@@ -26,5 +43,3 @@ export function registerTimesheetResetHandlers(): void {
 
   ipcLogger.verbose('Timesheet reset handlers registered');
 }
-
-
@@ -0,0 +1,50 @@
+import { app, ipcMain } from 'electron';
+import { APP_NAME } from '@sheetpilot/shared';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import {
+  formatStatusTitle,
+  getStatusBadgeCount,
+  getStatusCounts,
+  startStatusCountsWatcher,
+  type EntryStatusCounts
+} from '@/services/timesheet/status-counts';
+import { emitStatusCounts, isTrustedIpcSender, setMainWindowTitle } from './main-window';
+
+/**
+ * Shows the counts in the window title and on the dock/taskbar badge
+ * (setBadgeCount is a no-op where the platform has no badge)
+ */
+function applyStatusCounts(counts: EntryStatusCounts): void {
+  setMainWindowTitle(formatStatusTitle(APP_NAME, counts));
+  app.setBadgeCount?.(getStatusBadgeCount(counts));
+}
+
+/**
+ * Keeps the title and badge current and pushes `timesheet:statusCounts` to
+ * the main window whenever the counts change
+ */
+export function startTimesheetStatusCountsWatcher(): void {
+  startStatusCountsWatcher((counts) => {
+    applyStatusCounts(counts);
+    emitStatusCounts(counts);
+  });
+}
+
+export function registerTimesheetStatusCountsHandlers(): void {
+  ipcMain.handle('timesheet:getStatusCounts', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not get status counts: unauthorized request' };
+    }
+    try {
+      const counts = getStatusCounts();
+      // The page sets its own title on load; re-apply ours when it asks
+      applyStatusCounts(counts);
+      return { success: true, counts };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not get status counts', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet status count handlers registered');
+}
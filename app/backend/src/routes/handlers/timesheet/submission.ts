@@ -1,31 +1,172 @@
 import { ipcMain } from 'electron';
 import { ipcLogger } from '@sheetpilot/shared/logger';
-import { cancelTimesheetSubmission, submitTimesheetWorkflow } from '@/services/timesheet/submission-workflow';
+import {
+  cancelTimesheetSubmission,
+  submitTimesheetWorkflow,
+  SUBMISSION_JOB_TYPE
+} from '@/services/timesheet/submission-workflow';
+import { enqueueJob, JobFailedError } from '@/services/jobs/job-manager';
+import { getPendingTimesheetEntries, getProjectRetirements, listValidationRules } from '@/models';
+import { getBackfillWarnings } from '@/logic/backfill-warnings';
+import { buildSubmissionPlan } from '@/logic/submission-plan';
+import { buildTimingProfile, estimateSubmission } from '@/logic/submission-estimate';
+import { listRecentRunArtifacts } from '@/services/runs/run-artifacts';
+import { getSubmissionRateLimiter } from '@sheetpilot/bot';
+import { evaluateValidationRulesForEntries, toRuleEntry } from '@/logic/validation-rules';
+import { getRetiredProjectIssues } from '@/logic/project-lifecycle';
 import { emitSubmissionProgress } from './main-window';
 import { isTrustedIpcSender } from './main-window';
+import { flushDirtyDrafts } from './drafts.autosave';
+import { getChargeCodeQuotaWarnings } from '@/services/timesheet/charge-code-quotas';
+import { preflightSelectorHotfix } from '@/services/timesheet/selector-hotfix';
+import { withClockSkewNote } from '@/services/health/clock-skew';
+import { tagEnvironment } from '@/services/health/environment';
+
+export { SUBMISSION_JOB_TYPE };
+/** Recent run artifacts used to estimate submission time */
+const ESTIMATE_HISTORY_RUNS = 20;
 
 export function registerTimesheetSubmissionHandlers(): void {
   ipcMain.handle('timesheet:submit', async (event, token: string, useMockWebsite?: boolean) => {
     if (!isTrustedIpcSender(event)) {
       return { error: 'Could not submit timesheets: unauthorized request' };
     }
-    const result = await submitTimesheetWorkflow({
-      token,
-      ...(useMockWebsite !== undefined ? { useMockWebsite } : {}),
-      onProgress: (percent, message, meta) => {
-        const pendingCount = meta.pendingIds.length;
-        const safePercent = Math.min(100, Math.max(0, percent));
-        const progressData = {
-          percent: safePercent,
-          current: Math.floor((safePercent / 100) * pendingCount),
-          total: pendingCount,
-          message
-        };
-        emitSubmissionProgress(progressData);
+    // Submit what the user typed, not what the last auto-save flush wrote
+    flushDirtyDrafts();
+    // Runs as a background job; the renderer follows it through job status
+    // and progress events instead of holding this call open for the run
+    const jobId = enqueueJob(SUBMISSION_JOB_TYPE, async (context) => {
+      context.signal.addEventListener('abort', () => {
+        cancelTimesheetSubmission();
+      });
+      const result = await submitTimesheetWorkflow({
+        token,
+        runId: context.runId,
+        ...(useMockWebsite !== undefined ? { useMockWebsite } : {}),
+        onProgress: (percent, message, meta) => {
+          const pendingCount = meta.pendingIds.length;
+          const safePercent = Math.min(100, Math.max(0, percent));
+          const progressData = {
+            percent: safePercent,
+            current: Math.floor((safePercent / 100) * pendingCount),
+            total: pendingCount,
+            message
+          };
+          emitSubmissionProgress(progressData);
+          context.reportProgress(safePercent, message);
+        }
+      });
+      if (result.error) {
+        // Keep the violations that blocked the run so the renderer can list them
+        throw new JobFailedError(
+          result.error,
+          result.ruleViolations ? { ruleViolations: result.ruleViolations } : null
+        );
+      }
+      // Rejected entries stay pending with their reasons; the rest of the
+      // batch went through, so the caller still needs the submit result
+      const rejected = result.submitResult?.rejectedEntries ?? [];
+      if (rejected.length > 0) {
+        ipcLogger.warn('Smartsheet rejected entries with validation errors', {
+          rejectedCount: rejected.length,
+          firstMessage: rejected[0]!.message
+        });
       }
+      return {
+        submitResult: result.submitResult,
+        dbPath: result.dbPath,
+        partial: rejected.length > 0
+      };
     });
 
-    return result;
+    return tagEnvironment({ jobId });
+  });
+
+  ipcMain.handle('timesheet:preflightSubmission', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not check submission: unauthorized request' };
+    }
+    try {
+      flushDirtyDrafts();
+      const pending = getPendingTimesheetEntries();
+      // Quarter state depends on today's date, so a wrong clock is called out
+      const warnings = getBackfillWarnings(pending).map((w) => ({ ...w, message: withClockSkewNote(w.message) }));
+      if (warnings.length > 0) {
+        ipcLogger.info('Preflight found entries in closed or closing quarters', {
+          closed: warnings.filter((w) => w.state === 'closed').length,
+          closing: warnings.filter((w) => w.state === 'closing').length,
+          unavailable: warnings.filter((w) => w.state === 'unavailable').length
+        });
+      }
+      const ruleViolations = evaluateValidationRulesForEntries(
+        pending.map(toRuleEntry),
+        listValidationRules(true)
+      );
+      // Payroll bounces hours booked to a retired project, so these block submission
+      const retiredProjects = getRetiredProjectIssues(pending, getProjectRetirements());
+      if (retiredProjects.length > 0) {
+        ipcLogger.info('Preflight found entries booked to retired projects', {
+          count: retiredProjects.length,
+          projects: [...new Set(retiredProjects.map((issue) => issue.project))]
+        });
+      }
+      const plan = buildSubmissionPlan(pending);
+      if (plan.splitAcrossQuarters) {
+        ipcLogger.info('Pending entries span quarter forms; submission will run once per form', {
+          quarters: plan.groups.map((g) => ({ quarterId: g.quarterId, entryCount: g.entryIds.length }))
+        });
+      }
+      // A bad hotfix is reported but never blocks: the run uses built-in selectors
+      const hotfixCheck = await preflightSelectorHotfix();
+      return {
+        success: true,
+        pendingCount: pending.length,
+        warnings,
+        plan,
+        quotaWarnings: getChargeCodeQuotaWarnings(pending),
+        ruleViolations,
+        retiredProjects,
+        selectorHotfix: hotfixCheck.hotfix
+          ? {
+              id: hotfixCheck.hotfix.id,
+              fields: Object.keys(hotfixCheck.hotfix.fields ?? {}),
+              submitButtonSelectors: hotfixCheck.hotfix.submitButton?.length ?? 0
+            }
+          : null,
+        selectorHotfixError: hotfixCheck.error,
+        blocked: ruleViolations.some((v) => v.action === 'block') || retiredProjects.length > 0
+      };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not run submission preflight', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle('timesheet:planSubmission', async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: 'Could not plan submission: unauthorized request' };
+    }
+    try {
+      flushDirtyDrafts();
+      const pending = getPendingTimesheetEntries();
+      const plan = buildSubmissionPlan(pending);
+      // Mock website runs are much faster than Smartsheet; keep them out of the estimate
+      const history = (await listRecentRunArtifacts(ESTIMATE_HISTORY_RUNS)).filter(
+        (artifact) => !artifact.inputs.useMockWebsite
+      );
+      const timing = buildTimingProfile(history.flatMap((artifact) => artifact.forms));
+      const estimate = estimateSubmission(plan, timing, getSubmissionRateLimiter().getSettings());
+      ipcLogger.verbose('Submission plan estimated', {
+        entryCount: estimate.entryCount,
+        botRuns: estimate.batched.botRuns,
+        estimatedMs: estimate.batched.estimatedMs,
+        basis: timing.basis
+      });
+      return { success: true, plan, estimate, quotaWarnings: getChargeCodeQuotaWarnings(pending) };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not plan submission', err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
   });
 
   ipcMain.handle('timesheet:cancel', async (event) => {
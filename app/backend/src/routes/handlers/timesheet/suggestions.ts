@@ -0,0 +1,49 @@
+import { ipcMain } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getRecentTimesheetHistory } from '@/models';
+import { validateInput } from '@/validation/validate-ipc-input';
+import { suggestNextEntrySchema } from '@/validation/ipc-schemas';
+import {
+  partitionHistoryByDate,
+  suggestNextEntry,
+  type SuggestionHistoryEntry,
+} from '@/logic/next-entry-suggestion';
+import { isTrustedIpcSender } from './main-window';
+
+export function registerTimesheetSuggestionHandlers(): void {
+  ipcMain.handle('timesheet:suggestNextEntry', async (event, date: string) => {
+    const timer = ipcLogger.startTimer('suggest-next-entry');
+    if (!isTrustedIpcSender(event)) {
+      timer.done({ outcome: 'error', reason: 'unauthorized' });
+      return { success: false, error: 'Could not suggest entry: unauthorized request' };
+    }
+
+    const validation = validateInput(suggestNextEntrySchema, { date }, 'timesheet:suggestNextEntry');
+    if (!validation.success) {
+      timer.done({ outcome: 'error', error: 'validation-failed' });
+      return { success: false, error: validation.error };
+    }
+
+    try {
+      const rows = getRecentTimesheetHistory() as SuggestionHistoryEntry[];
+      const { existingForDate, history } = partitionHistoryByDate(validation.data!.date, rows);
+      const suggestion = suggestNextEntry(validation.data!.date, history, existingForDate);
+
+      ipcLogger.verbose('Next entry suggestion computed', {
+        date: validation.data!.date,
+        historySize: history.length,
+        existingCount: existingForDate.length,
+        basis: suggestion?.basis ?? null,
+      });
+      timer.done({ found: suggestion !== null });
+      return { success: true, suggestion };
+    } catch (err: unknown) {
+      ipcLogger.error('Could not suggest next entry', err);
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      timer.done({ outcome: 'error', error: errorMessage });
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  ipcLogger.verbose('Timesheet suggestion handlers registered');
+}
@@ -17,9 +17,16 @@ import { registerTimesheetHandlers, setMainWindow } from './timesheet-handlers';
 import { registerAdminHandlers } from './admin-handlers';
 import { registerDatabaseHandlers } from './database-handlers';
 import { registerLogsHandlers } from './logs-handlers';
+import { registerCrashReportsHandlers } from './crash-reports-handlers';
 import { registerLoggerHandlers } from './logger-handlers';
 import { registerSettingsHandlers } from './settings-handlers';
 import { registerBusinessConfigHandlers } from './business-config-handlers';
+import { registerSchedulerHandlers } from './scheduler-handlers';
+import { registerCloudHandlers } from './cloud-handlers';
+import { registerJobsHandlers } from './jobs-handlers';
+import { registerValidationRulesHandlers } from './validation-rules-handlers';
+import { registerPreferencesHandlers } from './preferences-handlers';
+import { registerAppHandlers } from './app-handlers';
 
 /**
  * Register all IPC handlers
@@ -62,6 +69,10 @@ export function registerAllIPCHandlers(mainWindow?: BrowserWindow | null): void
     registerLogsHandlers();
     appLogger.verbose('Logs handlers registered successfully');
     
+    appLogger.verbose('Registering crash report handlers');
+    registerCrashReportsHandlers();
+    appLogger.verbose('Crash report handlers registered successfully');
+    
     appLogger.verbose('Registering logger handlers');
     registerLoggerHandlers();
     appLogger.verbose('Logger handlers registered successfully');
@@ -74,6 +85,30 @@ export function registerAllIPCHandlers(mainWindow?: BrowserWindow | null): void
     registerBusinessConfigHandlers();
     appLogger.verbose('Business config handlers registered successfully');
     
+    appLogger.verbose('Registering scheduler handlers');
+    registerSchedulerHandlers();
+    appLogger.verbose('Scheduler handlers registered successfully');
+
+    appLogger.verbose('Registering cloud handlers');
+    registerCloudHandlers();
+    appLogger.verbose('Cloud handlers registered successfully');
+
+    appLogger.verbose('Registering jobs handlers');
+    registerJobsHandlers();
+    appLogger.verbose('Jobs handlers registered successfully');
+
+    appLogger.verbose('Registering validation rules handlers');
+    registerValidationRulesHandlers();
+    appLogger.verbose('Validation rules handlers registered successfully');
+
+    appLogger.verbose('Registering preferences handlers');
+    registerPreferencesHandlers();
+    appLogger.verbose('Preferences handlers registered successfully');
+
+    appLogger.verbose('Registering app handlers');
+    registerAppHandlers();
+    appLogger.verbose('App handlers registered successfully');
+    
     appLogger.info('All IPC handler modules registered successfully', { 
       modulesRegistered: [
         'auth', 
@@ -84,7 +119,13 @@ export function registerAllIPCHandlers(mainWindow?: BrowserWindow | null): void
         'logs', 
         'logger',
         'settings',
-        'business-config'
+        'business-config',
+        'scheduler',
+        'cloud',
+        'jobs',
+        'validation-rules',
+        'preferences',
+        'app'
       ]
     });
   } catch (err) {
@@ -104,9 +145,16 @@ export {
   registerAdminHandlers,
   registerDatabaseHandlers,
   registerLogsHandlers,
+  registerCrashReportsHandlers,
   registerLoggerHandlers,
   registerSettingsHandlers,
   registerBusinessConfigHandlers,
+  registerSchedulerHandlers,
+  registerCloudHandlers,
+  registerJobsHandlers,
+  registerValidationRulesHandlers,
+  registerPreferencesHandlers,
+  registerAppHandlers,
   setMainWindow
 };
 
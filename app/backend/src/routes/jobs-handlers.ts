@@ -0,0 +1,93 @@
+/**
+ * @fileoverview Background Job IPC Handlers
+ *
+ * Lets the renderer poll, list and cancel background jobs, and forwards job
+ * state changes to the main window as `job:progress` events.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import { validateInput } from "@/validation/validate-ipc-input";
+import { jobIdSchema } from "@/validation/ipc-schemas";
+import {
+  cancelJob,
+  getJobStatus,
+  listJobs,
+  onJobUpdate,
+} from "@/services/jobs/job-manager";
+import {
+  emitJobProgress,
+  isTrustedIpcSender,
+} from "./handlers/timesheet/main-window";
+
+let unsubscribeJobUpdates: (() => void) | null = null;
+
+/**
+ * Register all job-related IPC handlers
+ */
+export function registerJobsHandlers(): void {
+  unsubscribeJobUpdates?.();
+  unsubscribeJobUpdates = onJobUpdate(emitJobProgress);
+
+  ipcMain.handle("jobs:getStatus", async (event, jobId: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not get job status: unauthorized request",
+      };
+    }
+    const validation = validateInput(jobIdSchema, { jobId }, "jobs:getStatus");
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    try {
+      const job = getJobStatus(validation.data!.jobId);
+      if (!job) {
+        return { success: false, error: "Job not found" };
+      }
+      return { success: true, job };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get job status", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle("jobs:list", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list jobs: unauthorized request",
+        jobs: [],
+      };
+    }
+    return { success: true, jobs: listJobs() };
+  });
+
+  ipcMain.handle("jobs:cancel", async (event, jobId: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not cancel job: unauthorized request",
+      };
+    }
+    const validation = validateInput(jobIdSchema, { jobId }, "jobs:cancel");
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    const cancelled = cancelJob(validation.data!.jobId);
+    if (!cancelled) {
+      return { success: false, error: "Job not found or already finished" };
+    }
+    ipcLogger.audit("cancel-job", "Background job cancelled", {
+      jobId: validation.data!.jobId,
+    });
+    return { success: true };
+  });
+}
@@ -1,54 +1,105 @@
 /**
  * @fileoverview Logger IPC Handlers
- * 
+ *
  * Handles IPC communication for renderer logging bridge.
  * Routes renderer logs to main process logger.
- * 
+ *
+ * Structured fields (category, component, user action id) supplied by the
+ * renderer are merged into the log entry as top-level metadata so frontend
+ * events can be filtered the same way as backend events.
+ *
+ * Lines arriving before logging is initialized are held by the frontend log
+ * queue and flushed with their original timestamps.
+ *
  * @author Andrew Hughes
  * @version 1.0.0
  * @since 2025
  */
 
 import { ipcMain } from 'electron';
-import { ipcLogger } from '@sheetpilot/shared/logger';
 import { isTrustedIpcSender } from './handlers/timesheet/main-window';
+import { validateInput } from '@/validation/validate-ipc-input';
+import { frontendLogFieldsSchema, type FrontendLogFields } from '@/validation/ipc-schemas';
+import { logFrontendEntry } from '@/services/logs/frontend-log-queue';
+
+/** Category assigned to user actions when the renderer does not provide one */
+export const USER_ACTION_CATEGORY = 'user-action';
+
+const isPlainObject = (value: unknown): value is Record<string, unknown> =>
+  typeof value === 'object' && value !== null && !Array.isArray(value);
+
+/**
+ * Validates renderer-supplied structured fields, dropping them if malformed
+ * so a bad payload never prevents the message itself from being logged.
+ */
+function parseFrontendLogFields(fields: unknown, channel: string): FrontendLogFields | undefined {
+  if (fields === undefined || fields === null) return undefined;
+  const validation = validateInput(frontendLogFieldsSchema, fields, channel);
+  return validation.success ? validation.data : undefined;
+}
+
+/**
+ * Builds the metadata object for a frontend log entry.
+ *
+ * Without structured fields the renderer's data is passed through unchanged.
+ * With fields, they are placed alongside the data (not inside the message)
+ * and tagged with `source: 'frontend'`.
+ */
+export function buildFrontendLogMetadata(data: unknown, fields: FrontendLogFields | undefined): unknown {
+  if (!fields) return data;
+
+  const base: Record<string, unknown> = isPlainObject(data)
+    ? { ...data }
+    : data === undefined || data === null
+      ? {}
+      : { data };
+
+  base['source'] = 'frontend';
+  if (fields.category !== undefined) base['category'] = fields.category;
+  if (fields.component !== undefined) base['component'] = fields.component;
+  if (fields.userActionId !== undefined) base['userActionId'] = fields.userActionId;
+  return base;
+}
 
 /**
  * Register all logger-related IPC handlers
  */
 export function registerLoggerHandlers(): void {
-  
+
   // Renderer logging bridge - route renderer logs to main process logger
-  ipcMain.on('logger:error', (event, message: string, data?: unknown) => {
+  ipcMain.on('logger:error', (event, message: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.error(message, data);
+    logFrontendEntry('error', message, buildFrontendLogMetadata(data, parseFrontendLogFields(fields, 'logger:error')));
   });
 
-  ipcMain.on('logger:warn', (event, message: string, data?: unknown) => {
+  ipcMain.on('logger:warn', (event, message: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.warn(message, data);
+    logFrontendEntry('warn', message, buildFrontendLogMetadata(data, parseFrontendLogFields(fields, 'logger:warn')));
   });
 
-  ipcMain.on('logger:info', (event, message: string, data?: unknown) => {
+  ipcMain.on('logger:info', (event, message: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.info(message, data);
+    logFrontendEntry('info', message, buildFrontendLogMetadata(data, parseFrontendLogFields(fields, 'logger:info')));
   });
 
-  ipcMain.on('logger:verbose', (event, message: string, data?: unknown) => {
+  ipcMain.on('logger:verbose', (event, message: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.verbose(message, data);
+    logFrontendEntry('verbose', message, buildFrontendLogMetadata(data, parseFrontendLogFields(fields, 'logger:verbose')));
   });
 
-  ipcMain.on('logger:debug', (event, message: string, data?: unknown) => {
+  ipcMain.on('logger:debug', (event, message: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.debug(message, data);
+    logFrontendEntry('debug', message, buildFrontendLogMetadata(data, parseFrontendLogFields(fields, 'logger:debug')));
   });
 
-  // User action tracking
-  ipcMain.on('logger:user-action', (event, action: string, data?: unknown) => {
+  // User action tracking - the action is a field, not part of the message
+  ipcMain.on('logger:user-action', (event, action: string, data?: unknown, fields?: unknown) => {
     if (!isTrustedIpcSender(event)) return;
-    ipcLogger.info(`User action: ${action}`, data);
+    const parsed = parseFrontendLogFields(fields, 'logger:user-action');
+    const metadata = buildFrontendLogMetadata(data, {
+      ...parsed,
+      category: parsed?.category ?? USER_ACTION_CATEGORY
+    }) as Record<string, unknown>;
+    logFrontendEntry('info', 'User action', { ...metadata, action });
   });
 }
-
-
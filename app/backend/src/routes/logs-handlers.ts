@@ -15,7 +15,10 @@ import { ipcLogger } from "@sheetpilot/shared/logger";
 import { validateSession } from "@/models";
 import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
 import { validateInput } from "@/validation/validate-ipc-input";
-import { exportLogsSchema } from "@/validation/ipc-schemas";
+import { exportLogsSchema, queryLogsSchema } from "@/validation/ipc-schemas";
+import { queryLogs } from "@/services/logs/log-indexer";
+import { getStartupTimings } from "@/services/startup/startup-timings";
+import { getMessage } from "@/services/i18n/message-catalog";
 
 type SessionValidationResult = { error?: string };
 
@@ -32,7 +35,7 @@ const getSessionValidationResult = (
   const session = validateSession(token);
   if (!session.valid) {
     return {
-      error: "Session is invalid or expired. Please log in again.",
+      error: getMessage("SESSION_INVALID"),
     };
   }
 
@@ -226,4 +229,45 @@ export function registerLogsHandlers(): void {
       }
     }
   );
+
+  // Handler for filtered log queries (served from the SQLite index when enabled)
+  ipcMain.handle("logs:query", async (event, token: string, filter: unknown) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not query logs: unauthorized request",
+      };
+    }
+
+    const sessionValidation = getSessionValidationResult(token, "query logs");
+    if (sessionValidation.error) {
+      return { success: false, error: sessionValidation.error };
+    }
+
+    const validation = validateInput(queryLogsSchema, filter ?? {}, "logs:query");
+    if (!validation.success) {
+      return { success: false, error: validation.error ?? "Validation failed" };
+    }
+
+    try {
+      const timer = ipcLogger.startTimer("query-logs");
+      const result = await queryLogs(app.getPath("userData"), validation.data!);
+      timer.done({ source: result.source, count: result.lines.length });
+      return { success: true, ...result };
+    } catch (err: unknown) {
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      return { success: false, error: errorMessage };
+    }
+  });
+
+  // Handler for startup phase timings (diagnostics; no session needed)
+  ipcMain.handle("logs:getStartupTimings", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not get startup timings: unauthorized request",
+      };
+    }
+    return { success: true, timings: getStartupTimings() };
+  });
 }
@@ -0,0 +1,96 @@
+/**
+ * @fileoverview User Preferences IPC Handlers
+ *
+ * Reads and updates the logged-in user's preferences. The session decides
+ * whose preferences are used, so one user cannot change another's.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import { validateSession } from "@/models";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  getPreferencesSchema,
+  setPreferencesSchema,
+  type SetPreferences,
+} from "@/validation/ipc-schemas";
+import {
+  getUserPreferences,
+  updateUserPreferences,
+} from "@/services/settings/user-preferences";
+import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+import { getMessage } from "@/services/i18n/message-catalog";
+
+/**
+ * Email of a valid session, or null
+ */
+const getSessionEmail = (token: string, handler: string): string | null => {
+  const session = validateSession(token);
+  if (!session.valid || !session.email) {
+    ipcLogger.security("preferences-access-denied", "Invalid session attempting preferences access", {
+      handler,
+      token: token.substring(0, 8) + "...",
+    });
+    return null;
+  }
+  return session.email;
+};
+
+/**
+ * Register user preferences IPC handlers
+ */
+export function registerPreferencesHandlers(): void {
+  ipcMain.handle("preferences:get", async (event, token: string) => {
+    if (!isTrustedIpcSender(event)) {
+      return { success: false, error: "Could not get preferences: unauthorized request" };
+    }
+    const validation = validateInput(getPreferencesSchema, { token }, "preferences:get");
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    const email = getSessionEmail(validation.data!.token, "preferences:get");
+    if (!email) {
+      return { success: false, error: getMessage("SESSION_INVALID") };
+    }
+
+    try {
+      return { success: true, preferences: getUserPreferences(email) };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get preferences", err);
+      return { success: false, error: err instanceof Error ? err.message : String(err) };
+    }
+  });
+
+  ipcMain.handle(
+    "preferences:set",
+    async (event, token: string, updates: SetPreferences["updates"]) => {
+      if (!isTrustedIpcSender(event)) {
+        return { success: false, error: "Could not save preferences: unauthorized request" };
+      }
+      const validation = validateInput(setPreferencesSchema, { token, updates }, "preferences:set");
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const email = getSessionEmail(validation.data!.token, "preferences:set");
+      if (!email) {
+        return { success: false, error: getMessage("SESSION_INVALID") };
+      }
+
+      try {
+        const preferences = updateUserPreferences(email, validation.data!.updates);
+        ipcLogger.info("User preferences updated", {
+          email,
+          keys: Object.keys(validation.data!.updates),
+        });
+        return { success: true, preferences };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not save preferences", err);
+        return { success: false, error: err instanceof Error ? err.message : String(err) };
+      }
+    }
+  );
+}
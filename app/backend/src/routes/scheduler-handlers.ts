@@ -0,0 +1,326 @@
+/**
+ * @fileoverview Scheduler IPC Handlers
+ *
+ * Handles IPC communication for scheduled exports, network backups, run
+ * history, run artifacts and reproduction bundles.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain } from "electron";
+import * as path from "path";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import {
+  deleteScheduledExport,
+  getScheduledExport,
+  listRuns,
+  listScheduledExports,
+  saveScheduledExport,
+} from "@/models";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  runHistoryQuerySchema,
+  runIdSchema,
+  scheduledExportIdSchema,
+  scheduledExportSchema,
+  type ScheduledExportInput,
+} from "@/validation/ipc-schemas";
+import {
+  computeNextMonthlyRunAt,
+  enqueueScheduledExport,
+} from "@/services/scheduler/export-schedule";
+import {
+  enqueueNetworkBackup,
+  getNetworkBackupStatus,
+  resolveNetworkBackupSettings,
+} from "@/services/backup/network-backup";
+import { loadSettings } from "@/services/settings/settings-store";
+import { getRunArtifact } from "@/services/runs/run-artifacts";
+import { exportReproBundle } from "@/services/runs/repro-bundle";
+import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+
+/**
+ * Register all scheduler-related IPC handlers
+ */
+export function registerSchedulerHandlers(): void {
+  ipcMain.handle("scheduler:listExportSchedules", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list export schedules: unauthorized request",
+      };
+    }
+    try {
+      return { success: true, schedules: listScheduledExports() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not list export schedules", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "scheduler:saveExportSchedule",
+    async (event, schedule: ScheduledExportInput) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not save export schedule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        scheduledExportSchema,
+        schedule,
+        "scheduler:saveExportSchedule"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const validated = validation.data!;
+      if (!path.isAbsolute(validated.targetDir)) {
+        return {
+          success: false,
+          error: "Target folder must be an absolute path",
+        };
+      }
+
+      try {
+        const nextRunAt = computeNextMonthlyRunAt(
+          validated.dayOfMonth,
+          new Date()
+        ).toISOString();
+        const id = saveScheduledExport(validated, nextRunAt);
+        ipcLogger.audit("save-export-schedule", "Export schedule saved", {
+          id,
+          format: validated.format,
+          targetDir: validated.targetDir,
+          dayOfMonth: validated.dayOfMonth,
+        });
+        return { success: true, id, nextRunAt };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not save export schedule", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "scheduler:deleteExportSchedule",
+    async (event, id: number) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not delete export schedule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        scheduledExportIdSchema,
+        { id },
+        "scheduler:deleteExportSchedule"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      try {
+        const deleted = deleteScheduledExport(validation.data!.id);
+        if (!deleted) {
+          return { success: false, error: "Schedule not found" };
+        }
+        ipcLogger.audit("delete-export-schedule", "Export schedule deleted", {
+          id: validation.data!.id,
+        });
+        return { success: true };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not delete export schedule", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "scheduler:runExportScheduleNow",
+    async (event, id: number) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not run export schedule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        scheduledExportIdSchema,
+        { id },
+        "scheduler:runExportScheduleNow"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const schedule = getScheduledExport(validation.data!.id);
+      if (!schedule) {
+        return { success: false, error: "Schedule not found" };
+      }
+      return { success: true, jobId: enqueueScheduledExport(schedule, "manual") };
+    }
+  );
+
+  ipcMain.handle(
+    "scheduler:getRunHistory",
+    async (event, query?: { jobType?: string; limit?: number }) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not get run history: unauthorized request",
+          runs: [],
+        };
+      }
+      const validation = validateInput(
+        runHistoryQuerySchema,
+        query ?? {},
+        "scheduler:getRunHistory"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error, runs: [] };
+      }
+      try {
+        const filters = validation.data!;
+        return {
+          success: true,
+          runs: listRuns({
+            ...(filters.jobType !== undefined ? { jobType: filters.jobType } : {}),
+            ...(filters.limit !== undefined ? { limit: filters.limit } : {}),
+          }),
+        };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not get run history", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+          runs: [],
+        };
+      }
+    }
+  );
+
+  ipcMain.handle("scheduler:getRunArtifact", async (event, runId: number) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not get run artifact: unauthorized request",
+      };
+    }
+    const validation = validateInput(
+      runIdSchema,
+      { runId },
+      "scheduler:getRunArtifact"
+    );
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    try {
+      const artifact = await getRunArtifact(validation.data!.runId);
+      if (!artifact) {
+        return {
+          success: false,
+          error: "No artifact was recorded for this run, or it has expired",
+        };
+      }
+      return { success: true, artifact };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get run artifact", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle("scheduler:exportReproBundle", async (event, runId: number) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not export reproduction bundle: unauthorized request",
+      };
+    }
+    const validation = validateInput(
+      runIdSchema,
+      { runId },
+      "scheduler:exportReproBundle"
+    );
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+    try {
+      const bundle = await exportReproBundle(validation.data!.runId);
+      if (!bundle) {
+        return {
+          success: false,
+          error: "No artifact was recorded for this run, or it has expired",
+        };
+      }
+      ipcLogger.audit("export-repro-bundle", "Reproduction bundle exported", {
+        runId: validation.data!.runId,
+        entryCount: bundle.entryCount,
+      });
+      return {
+        success: true,
+        content: bundle.content,
+        filename: bundle.filename,
+        mimeType: "application/zip",
+        entryCount: bundle.entryCount,
+      };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not export reproduction bundle", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle("scheduler:getNetworkBackupStatus", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not get network backup status: unauthorized request",
+      };
+    }
+    try {
+      return { success: true, status: getNetworkBackupStatus() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not get network backup status", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle("scheduler:runNetworkBackupNow", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not run network backup: unauthorized request",
+      };
+    }
+    const settings = resolveNetworkBackupSettings(loadSettings().network_backup);
+    if (!settings) {
+      return {
+        success: false,
+        error: "Network backups are not enabled or no folder is set",
+      };
+    }
+    ipcLogger.audit("run-network-backup", "Network backup requested", {
+      targetDir: settings.targetDir,
+    });
+    return { success: true, jobId: enqueueNetworkBackup(settings, "manual") };
+  });
+}
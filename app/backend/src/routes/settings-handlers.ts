@@ -1,51 +1,131 @@
 import { ipcMain } from 'electron';
-import * as path from 'path';
-import * as fs from 'fs';
-import { app } from 'electron';
 import { ipcLogger } from '@sheetpilot/shared/logger';
-import { setBrowserHeadless } from '@sheetpilot/shared';
-import { isTrustedIpcSender } from './handlers/timesheet/main-window';
+import { setBrowserHeadless, setHighlightInteractions } from '@sheetpilot/shared';
+import {
+  configureSubmissionKeyMode,
+  configureSubmissionOrder,
+  configureSubmissionRateLimit,
+  configureSubmissionVerification,
+  resolveSubmissionKeyMode,
+  resolveSubmissionOrder,
+  resolveVerificationLevel,
+  sanitizeRateLimitSettings
+} from '@sheetpilot/bot';
+import { configureDatabaseTimeouts, sanitizeDatabaseTimeoutSettings, validateSession } from '@/models';
+import { emitSettingsChanged, isTrustedIpcSender } from './handlers/timesheet/main-window';
+import { configureMessageLocale, getMessage } from '@/services/i18n/message-catalog';
+import { validateInput } from '@/validation/validate-ipc-input';
+import { setProtectedSettingSchema } from '@/validation/ipc-schemas';
+import { configureEventThrottle, sanitizeEventThrottleSettings } from '@/services/events/event-throttle';
+import {
+  getSettingsPath,
+  isAdminOnlySetting,
+  loadSettings,
+  onSettingsChanged,
+  reloadSettings,
+  saveSettings,
+  type AppSettings,
+  type SettingsChange,
+} from '@/services/settings/settings-store';
 
 /**
  * Settings Handlers
  * Manages application settings storage and retrieval
  */
 
-interface AppSettings {
-  browserHeadless?: boolean;
-  themeMode?: 'auto' | 'light' | 'dark';
-}
-
-const getSettingsPath = (): string => {
-  const userDataPath = app.getPath('userData');
-  return path.join(userDataPath, 'settings.json');
-};
+let unsubscribeSettingsChanges: (() => void) | null = null;
 
-const loadSettings = (): AppSettings => {
-  const settingsPath = getSettingsPath();
-  try {
-    if (fs.existsSync(settingsPath)) {
-      const data = fs.readFileSync(settingsPath, 'utf-8');
-      return JSON.parse(data);
-    }
-  } catch (err) {
-    ipcLogger.error('Could not load settings', { 
-      settingsPath, 
-      error: err instanceof Error ? err.message : String(err) 
+/**
+ * Pushes changed settings into the bot and services that hold them in
+ * memory, so running bots and scheduled tasks use them without a restart
+ */
+const applyChangedSettings = ({ keys, settings }: SettingsChange): void => {
+  // If headless mode changed, update the shared constant immediately
+  if (keys.includes('browserHeadless')) {
+    const value = settings.browserHeadless ?? false;
+    setBrowserHeadless(value);
+    ipcLogger.info('Updated browserHeadless setting', { 
+      toggleValue: value,
+      meaning: value ? 'Browser will be INVISIBLE (headless)' : 'Browser will be VISIBLE (non-headless)'
     });
   }
-  return {};
+
+  if (keys.includes('highlightInteractions')) {
+    setHighlightInteractions(settings.highlightInteractions ?? false);
+  }
+
+  if (keys.includes('automation_rate_limit')) {
+    configureSubmissionRateLimit(sanitizeRateLimitSettings(settings.automation_rate_limit));
+  }
+
+  if (keys.includes('submission_verification')) {
+    configureSubmissionVerification(resolveVerificationLevel(settings.submission_verification));
+  }
+
+  if (keys.includes('submission_order')) {
+    configureSubmissionOrder(resolveSubmissionOrder(settings.submission_order));
+  }
+
+  if (keys.includes('submission_key_mode')) {
+    configureSubmissionKeyMode(resolveSubmissionKeyMode(settings.submission_key_mode));
+  }
+
+  if (keys.includes('database_timeouts')) {
+    configureDatabaseTimeouts(sanitizeDatabaseTimeoutSettings(settings.database_timeouts));
+  }
+
+  if (keys.includes('locale')) {
+    configureMessageLocale(settings.locale);
+  }
+
+  if (keys.includes('event_throttle')) {
+    configureEventThrottle(sanitizeEventThrottleSettings(settings.event_throttle));
+  }
+
+  emitSettingsChanged({ keys });
 };
 
-const saveSettings = (settings: AppSettings): void => {
-  const settingsPath = getSettingsPath();
+/**
+ * Writes one setting and reads the file back to confirm it stuck
+ */
+const saveSetting = (key: string, value: unknown): { success: boolean; error?: string } => {
   try {
-    fs.writeFileSync(settingsPath, JSON.stringify(settings, null, 2), 'utf-8');
+    const settingsPath = getSettingsPath();
+    const settings = loadSettings();
+    (settings as Record<string, unknown>)[key] = value;
+    saveSettings(settings);
+    
+    // Verify the setting was saved by reading the file back
+    const verifiedSettings = reloadSettings();
+    // Compare serialized forms so object settings (e.g. automation_rate_limit) verify too
+    const savedCorrectly =
+      JSON.stringify(verifiedSettings[key as keyof AppSettings]) === JSON.stringify(value);
+    
+    ipcLogger.info('Setting saved successfully', { 
+      key, 
+      value, 
+      savedValue: verifiedSettings[key as keyof AppSettings],
+      verified: savedCorrectly,
+      settingsPath 
+    });
+    
+    if (!savedCorrectly) {
+      throw new Error(
+        `Setting was not saved correctly. Expected ${String(value)}, got ${String(verifiedSettings[key as keyof AppSettings])}`
+      );
+    }
+    
+    return { success: true };
   } catch (err) {
-    ipcLogger.error('Could not save settings', { 
-      settingsPath, 
+    ipcLogger.error('Could not save setting', { 
+      key, 
+      value, 
       error: err instanceof Error ? err.message : String(err) 
     });
+    return { 
+      success: false, 
+      error: err instanceof Error ? err.message : 'Unknown error' 
+    };
   }
 };
 
@@ -71,6 +151,26 @@ export function registerSettingsHandlers(): void {
       savedValue: settings.browserHeadless, 
       effectiveValue: headlessValue
     });
+
+    setHighlightInteractions(settings.highlightInteractions ?? false);
+
+    // Submission pacing is shared by every bot run in this process
+    configureSubmissionRateLimit(sanitizeRateLimitSettings(settings.automation_rate_limit));
+
+    configureSubmissionVerification(resolveVerificationLevel(settings.submission_verification));
+
+    configureSubmissionOrder(resolveSubmissionOrder(settings.submission_order));
+
+    configureSubmissionKeyMode(resolveSubmissionKeyMode(settings.submission_key_mode));
+
+    configureDatabaseTimeouts(sanitizeDatabaseTimeoutSettings(settings.database_timeouts));
+
+    configureMessageLocale(settings.locale);
+
+    configureEventThrottle(sanitizeEventThrottleSettings(settings.event_throttle));
+
+    unsubscribeSettingsChanges?.();
+    unsubscribeSettingsChanges = onSettingsChanged(applyChangedSettings);
   } catch (err) {
     console.error('[Settings] Could not initialize settings on startup', err);
     ipcLogger.error('Could not initialize settings on startup', { 
@@ -97,55 +197,40 @@ export function registerSettingsHandlers(): void {
     if (!isTrustedIpcSender(event)) {
       return { success: false, error: 'Could not set setting: unauthorized request' };
     }
-    try {
-      const settingsPath = getSettingsPath();
-      const settings = loadSettings();
-      (settings as Record<string, unknown>)[key] = value;
-      saveSettings(settings);
-      
-      // Verify the setting was saved by reloading
-      const verifiedSettings = loadSettings();
-      const savedCorrectly = verifiedSettings[key as keyof AppSettings] === value;
-      
-      ipcLogger.info('Setting saved successfully', { 
-        key, 
-        value, 
-        savedValue: verifiedSettings[key as keyof AppSettings],
-        verified: savedCorrectly,
-        settingsPath 
-      });
-      
-      // If headless mode changed, update the shared constant immediately
-      if (key === 'browserHeadless') {
-        setBrowserHeadless(Boolean(value));
-        console.log('[Settings] Updated browserHeadless setting:', { 
-          toggleValue: value,
-          meaning: value ? 'Browser will be INVISIBLE (headless)' : 'Browser will be VISIBLE (non-headless)'
-        });
-        ipcLogger.info('Updated browserHeadless setting', { 
-          toggleValue: value,
-          meaning: value ? 'Browser will be INVISIBLE (headless)' : 'Browser will be VISIBLE (non-headless)'
-        });
-      }
-      
-      if (!savedCorrectly) {
-        throw new Error(
-          `Setting was not saved correctly. Expected ${String(value)}, got ${String(verifiedSettings[key as keyof AppSettings])}`
-        );
-      }
-      
-      return { success: true };
-    } catch (err) {
-      ipcLogger.error('Could not save setting', { 
-        key, 
-        value, 
-        error: err instanceof Error ? err.message : String(err) 
+    if (isAdminOnlySetting(key)) {
+      ipcLogger.security('protected-setting-denied', 'Protected setting write attempted without an admin session', { key });
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
+    }
+    return saveSetting(key, value);
+  });
+
+  ipcMain.handle('settings:setProtected', async (event, token: string, key: string, value: unknown) => {
+    if (!isTrustedIpcSender(event, 'settings:setProtected')) {
+      return { success: false, error: 'Could not set setting: unauthorized request' };
+    }
+    const validation = validateInput(setProtectedSettingSchema, { token, key }, 'settings:setProtected');
+    if (!validation.success) {
+      return { success: false, error: validation.error };
+    }
+
+    const validatedData = validation.data!;
+    if (!isAdminOnlySetting(validatedData.key)) {
+      return { success: false, error: `Could not set setting: ${validatedData.key} is not a protected setting` };
+    }
+    const session = validateSession(validatedData.token);
+
+    if (!session.valid || !session.isAdmin) {
+      ipcLogger.security('admin-action-denied', 'Unauthorized admin action attempted', {
+        token: validatedData.token.substring(0, 8) + '...'
       });
-      return { 
-        success: false, 
-        error: err instanceof Error ? err.message : 'Unknown error' 
-      };
+      return { success: false, error: getMessage('ADMIN_REQUIRED') };
     }
+
+    ipcLogger.audit('admin-set-protected-setting', 'Admin changing protected setting', {
+      email: session.email,
+      key: validatedData.key
+    });
+    return saveSetting(validatedData.key, value);
   });
 
   ipcMain.handle('settings:getAll', async (event) => {
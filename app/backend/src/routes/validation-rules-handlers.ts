@@ -0,0 +1,183 @@
+/**
+ * @fileoverview Validation Rules IPC Handlers
+ *
+ * CRUD for user-defined validation rules. Anyone can list rules; creating,
+ * changing and deleting them requires an admin session.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcMain } from "electron";
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import {
+  createValidationRule,
+  deleteValidationRule,
+  listValidationRules,
+  updateValidationRule,
+} from "@/models";
+import { validateInput } from "@/validation/validate-ipc-input";
+import {
+  createValidationRuleSchema,
+  deleteValidationRuleSchema,
+  updateValidationRuleSchema,
+  type ValidationRuleDefinition,
+} from "@/validation/ipc-schemas";
+import { requireAdminSession } from "./business-config-handlers.utils";
+import { isTrustedIpcSender } from "./handlers/timesheet/main-window";
+
+/**
+ * Register all validation rule IPC handlers
+ */
+export function registerValidationRulesHandlers(): void {
+  ipcMain.handle("validation-rules:list", async (event) => {
+    if (!isTrustedIpcSender(event)) {
+      return {
+        success: false,
+        error: "Could not list validation rules: unauthorized request",
+      };
+    }
+    try {
+      return { success: true, rules: listValidationRules() };
+    } catch (err: unknown) {
+      ipcLogger.error("Could not list validation rules", err);
+      return {
+        success: false,
+        error: err instanceof Error ? err.message : String(err),
+      };
+    }
+  });
+
+  ipcMain.handle(
+    "validation-rules:create",
+    async (event, token: string, rule: ValidationRuleDefinition) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not create validation rule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        createValidationRuleSchema,
+        { token, rule },
+        "validation-rules:create"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const validated = validation.data!;
+      const adminCheck = requireAdminSession(validated.token);
+      if (!adminCheck.ok) {
+        return adminCheck.response;
+      }
+
+      try {
+        const id = createValidationRule(validated.rule);
+        ipcLogger.audit("create-validation-rule", "Validation rule created", {
+          email: adminCheck.session.email,
+          id,
+          field: validated.rule.field,
+          operator: validated.rule.operator,
+          action: validated.rule.action,
+        });
+        return { success: true, id };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not create validation rule", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "validation-rules:update",
+    async (event, token: string, id: number, rule: ValidationRuleDefinition) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not update validation rule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        updateValidationRuleSchema,
+        { token, id, rule },
+        "validation-rules:update"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const validated = validation.data!;
+      const adminCheck = requireAdminSession(validated.token);
+      if (!adminCheck.ok) {
+        return adminCheck.response;
+      }
+
+      try {
+        const updated = updateValidationRule(validated.id, validated.rule);
+        if (!updated) {
+          return { success: false, error: "Validation rule not found" };
+        }
+        ipcLogger.audit("update-validation-rule", "Validation rule updated", {
+          email: adminCheck.session.email,
+          id: validated.id,
+          action: validated.rule.action,
+        });
+        return { success: true };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not update validation rule", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcMain.handle(
+    "validation-rules:delete",
+    async (event, token: string, id: number) => {
+      if (!isTrustedIpcSender(event)) {
+        return {
+          success: false,
+          error: "Could not delete validation rule: unauthorized request",
+        };
+      }
+      const validation = validateInput(
+        deleteValidationRuleSchema,
+        { token, id },
+        "validation-rules:delete"
+      );
+      if (!validation.success) {
+        return { success: false, error: validation.error };
+      }
+      const validated = validation.data!;
+      const adminCheck = requireAdminSession(validated.token);
+      if (!adminCheck.ok) {
+        return adminCheck.response;
+      }
+
+      try {
+        const deleted = deleteValidationRule(validated.id);
+        if (!deleted) {
+          return { success: false, error: "Validation rule not found" };
+        }
+        ipcLogger.audit("delete-validation-rule", "Validation rule deleted", {
+          email: adminCheck.session.email,
+          id: validated.id,
+        });
+        return { success: true };
+      } catch (err: unknown) {
+        ipcLogger.error("Could not delete validation rule", err);
+        return {
+          success: false,
+          error: err instanceof Error ? err.message : String(err),
+        };
+      }
+    }
+  );
+
+  ipcLogger.verbose("Validation rules handlers registered");
+}
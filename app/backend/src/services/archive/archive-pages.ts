@@ -0,0 +1,100 @@
+/**
+ * @fileoverview Archive Pages
+ *
+ * `database:getAllArchiveData` sends every submitted entry in one message;
+ * with a few years of history that is several MB of JSON serialized on the
+ * main process and parsed on the renderer, which stalls the UI. Archive
+ * views can instead fetch pages by cursor, ask for only the columns they
+ * render, and have a page gzipped when it is large.
+ *
+ * Cursors are opaque to the renderer: base64url JSON of the last entry's
+ * date and ID.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { gzipSync } from 'zlib';
+import {
+  countCompletedTimesheetEntries,
+  getCompletedTimesheetEntriesAfter,
+  type ArchiveColumn,
+  type ArchiveCursor
+} from '@/models';
+
+export const ARCHIVE_PAGE_DEFAULT_LIMIT = 500;
+export const ARCHIVE_PAGE_MAX_LIMIT = 5000;
+/** Pages smaller than this are sent as plain entries even when gzip is requested */
+export const ARCHIVE_GZIP_MIN_BYTES = 16 * 1024;
+
+export interface ArchivePageRequest {
+  /** From the previous page; omit for the first page */
+  cursor?: string | undefined;
+  limit?: number | undefined;
+  /** Columns to return; id and date are always included */
+  columns?: ArchiveColumn[] | undefined;
+  /** Gzip the entries when the page is large */
+  gzip?: boolean | undefined;
+}
+
+export interface ArchivePage {
+  /** Entries, unless they were gzipped into `gzipped` */
+  entries?: Array<Record<string, unknown>>;
+  /** Gzipped JSON of the entries array */
+  gzipped?: Uint8Array;
+  count: number;
+  /** Pass back for the next page; null after the last page */
+  nextCursor: string | null;
+  /** Total submitted entries; only on the first page */
+  totalCount?: number;
+}
+
+export function encodeArchiveCursor(cursor: ArchiveCursor): string {
+  return Buffer.from(JSON.stringify([cursor.date, cursor.id]), 'utf8').toString('base64url');
+}
+
+/**
+ * @throws If the cursor was not produced by `encodeArchiveCursor`
+ */
+export function decodeArchiveCursor(cursor: string): ArchiveCursor {
+  try {
+    const parsed = JSON.parse(Buffer.from(cursor, 'base64url').toString('utf8')) as unknown;
+    if (
+      Array.isArray(parsed) &&
+      typeof parsed[0] === 'string' &&
+      Number.isInteger(parsed[1])
+    ) {
+      return { date: parsed[0], id: parsed[1] as number };
+    }
+  } catch {
+    // Reported below
+  }
+  throw new Error('Archive cursor is not valid; start again from the first page');
+}
+
+/**
+ * Gets one page of submitted entries for an archive view
+ */
+export function getArchivePage(request: ArchivePageRequest = {}): ArchivePage {
+  const limit = Math.min(Math.max(1, request.limit ?? ARCHIVE_PAGE_DEFAULT_LIMIT), ARCHIVE_PAGE_MAX_LIMIT);
+  const cursor = request.cursor ? decodeArchiveCursor(request.cursor) : null;
+  const { entries, nextCursor } = getCompletedTimesheetEntriesAfter({
+    cursor,
+    limit,
+    ...(request.columns ? { columns: request.columns } : {})
+  });
+
+  const page: ArchivePage = {
+    count: entries.length,
+    nextCursor: nextCursor ? encodeArchiveCursor(nextCursor) : null,
+    ...(cursor ? {} : { totalCount: countCompletedTimesheetEntries() })
+  };
+  if (request.gzip) {
+    const json = Buffer.from(JSON.stringify(entries), 'utf8');
+    if (json.length >= ARCHIVE_GZIP_MIN_BYTES) {
+      return { ...page, gzipped: new Uint8Array(gzipSync(json)) };
+    }
+  }
+  return { ...page, entries };
+}
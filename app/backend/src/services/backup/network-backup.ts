@@ -0,0 +1,408 @@
+/**
+ * @fileoverview Network Share Backups
+ *
+ * Copies encrypted snapshots of the database to a network folder (a UNC
+ * path such as \\fileserver\sheetpilot-backups, or a mapped drive) so IT can
+ * restore a user's entries when a laptop dies. Each machine/user pair gets
+ * its own folder holding the backups and a manifest.json describing them.
+ *
+ * Every backup is verified on the share: it is read back, decrypted and
+ * opened, and PRAGMA integrity_check must pass before it is listed in the
+ * manifest. A backup that fails verification is deleted.
+ *
+ * Backups are encrypted with AES-256-GCM under a key derived from the
+ * SHEETPILOT_BACKUP_KEY environment variable, which IT deploys and keeps.
+ * Unlike the credential keychain it is not tied to the machine, so a backup
+ * can be decrypted on a replacement laptop with `decryptBackup`.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as crypto from "crypto";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { appLogger } from "@sheetpilot/shared/logger";
+import {
+  checkBackupIntegrity,
+  listRuns,
+  snapshotDatabase,
+  type BackupIntegrityResult,
+  type RunHistoryRecord,
+  type RunTrigger,
+} from "@/models";
+import { loadSettings } from "@/services/settings/settings-store";
+import { notifyUser } from "@/services/notifications";
+import { enqueueJob, waitForJob, type JobContext } from "@/services/jobs/job-manager";
+import { registerScheduledTask, type ScheduledTask } from "@/services/scheduler/scheduler";
+
+export const NETWORK_BACKUP_JOB_TYPE = "network-backup";
+
+/** Environment variable holding the backup passphrase */
+export const BACKUP_KEY_ENV = "SHEETPILOT_BACKUP_KEY";
+
+export const MANIFEST_FILE_NAME = "manifest.json";
+
+const DEFAULT_INTERVAL_HOURS = 24;
+const DEFAULT_KEEP = 14;
+const MAX_KEEP = 365;
+/** Wait before retrying after a failed backup (e.g. the share was offline) */
+const RETRY_AFTER_MS = 60 * 60 * 1000;
+
+/** File header; bump the digit if the layout below changes */
+const BACKUP_MAGIC = Buffer.from("SPBK1");
+const SALT_LENGTH = 16;
+const IV_LENGTH = 12;
+const TAG_LENGTH = 16;
+
+export interface NetworkBackupSettings {
+  enabled?: boolean;
+  /** UNC path or folder on a mapped drive */
+  target_dir?: string;
+  /** Hours between scheduled backups (default 24) */
+  interval_hours?: number;
+  /** Backups kept per machine and user; older ones are deleted (default 14) */
+  keep?: number;
+}
+
+export interface ResolvedNetworkBackupSettings {
+  targetDir: string;
+  intervalHours: number;
+  keep: number;
+}
+
+export interface BackupManifestEntry {
+  /** File name in the backup folder */
+  file: string;
+  createdAt: string;
+  sizeBytes: number;
+  /** SHA-256 of the encrypted file, to spot a damaged copy before decrypting */
+  sha256: string;
+  schemaVersion: number;
+  entryCount: number;
+  /** When the copy on the share passed integrity_check */
+  verifiedAt: string;
+}
+
+export interface BackupManifest {
+  version: 1;
+  host: string;
+  user: string;
+  /** How backups are encrypted, for whoever restores them */
+  encryption: string;
+  /** Newest last */
+  backups: BackupManifestEntry[];
+}
+
+export interface NetworkBackupResult {
+  filePath: string;
+  sizeBytes: number;
+  entryCount: number;
+  /** Older backups deleted to stay within `keep` */
+  pruned: string[];
+}
+
+/**
+ * Reads the network backup settings; null when backups are off or no folder is set
+ */
+export function resolveNetworkBackupSettings(
+  settings: NetworkBackupSettings | undefined
+): ResolvedNetworkBackupSettings | null {
+  if (!settings?.enabled || typeof settings.target_dir !== "string" || !settings.target_dir.trim()) {
+    return null;
+  }
+  const intervalHours =
+    typeof settings.interval_hours === "number" && settings.interval_hours >= 1
+      ? settings.interval_hours
+      : DEFAULT_INTERVAL_HOURS;
+  const keep =
+    typeof settings.keep === "number" && Number.isInteger(settings.keep) && settings.keep >= 1
+      ? Math.min(settings.keep, MAX_KEEP)
+      : DEFAULT_KEEP;
+  return { targetDir: settings.target_dir.trim(), intervalHours, keep };
+}
+
+const deriveKey = (passphrase: string, salt: Buffer): Buffer =>
+  crypto.scryptSync(passphrase, salt, 32);
+
+/**
+ * Encrypts a database snapshot: header, salt, IV, auth tag, ciphertext
+ */
+export function encryptBackup(plain: Buffer, passphrase: string): Buffer {
+  const salt = crypto.randomBytes(SALT_LENGTH);
+  const iv = crypto.randomBytes(IV_LENGTH);
+  const cipher = crypto.createCipheriv("aes-256-gcm", deriveKey(passphrase, salt), iv);
+  const encrypted = Buffer.concat([cipher.update(plain), cipher.final()]);
+  return Buffer.concat([BACKUP_MAGIC, salt, iv, cipher.getAuthTag(), encrypted]);
+}
+
+/**
+ * Decrypts a backup written by `encryptBackup`
+ *
+ * @throws If the file is not a backup, or the key is wrong or the file was changed
+ */
+export function decryptBackup(data: Buffer, passphrase: string): Buffer {
+  if (!data.subarray(0, BACKUP_MAGIC.length).equals(BACKUP_MAGIC)) {
+    throw new Error("Not a SheetPilot backup file");
+  }
+  let offset = BACKUP_MAGIC.length;
+  const salt = data.subarray(offset, (offset += SALT_LENGTH));
+  const iv = data.subarray(offset, (offset += IV_LENGTH));
+  const tag = data.subarray(offset, (offset += TAG_LENGTH));
+  const decipher = crypto.createDecipheriv("aes-256-gcm", deriveKey(passphrase, salt), iv);
+  decipher.setAuthTag(tag);
+  try {
+    return Buffer.concat([decipher.update(data.subarray(offset)), decipher.final()]);
+  } catch {
+    throw new Error("Could not decrypt backup: wrong key or damaged file");
+  }
+}
+
+const toFolderName = (value: string): string =>
+  value.replace(/[^A-Za-z0-9._-]+/g, "_") || "unknown";
+
+/**
+ * Folder on the share for this machine and user
+ */
+export function getBackupFolder(
+  targetDir: string,
+  host: string = os.hostname(),
+  user: string = os.userInfo().username
+): string {
+  return path.join(targetDir, `${toFolderName(host)}-${toFolderName(user)}`);
+}
+
+/**
+ * Reads a backup folder's manifest; null if there is none yet
+ */
+export function readBackupManifest(folder: string): BackupManifest | null {
+  try {
+    return JSON.parse(fs.readFileSync(path.join(folder, MANIFEST_FILE_NAME), "utf-8")) as BackupManifest;
+  } catch (err: unknown) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") return null;
+    throw err;
+  }
+}
+
+const writeBackupManifest = (folder: string, manifest: BackupManifest): void => {
+  const manifestPath = path.join(folder, MANIFEST_FILE_NAME);
+  const tempPath = `${manifestPath}.tmp`;
+  fs.writeFileSync(tempPath, JSON.stringify(manifest, null, 2), "utf-8");
+  fs.renameSync(tempPath, manifestPath);
+};
+
+const sha256 = (data: Buffer): string => crypto.createHash("sha256").update(data).digest("hex");
+
+/**
+ * Reads a backup back, decrypts it to a temporary file and runs integrity_check
+ */
+export function verifyBackupFile(
+  filePath: string,
+  passphrase: string
+): BackupIntegrityResult & { sha256: string } {
+  const encrypted = fs.readFileSync(filePath);
+  const tempDir = fs.mkdtempSync(path.join(os.tmpdir(), "sheetpilot-verify-"));
+  try {
+    const restoredPath = path.join(tempDir, "restored.sqlite");
+    fs.writeFileSync(restoredPath, decryptBackup(encrypted, passphrase));
+    return { ...checkBackupIntegrity(restoredPath), sha256: sha256(encrypted) };
+  } finally {
+    fs.rmSync(tempDir, { recursive: true, force: true });
+  }
+}
+
+const removeQuietly = (filePath: string): void => {
+  try {
+    fs.rmSync(filePath, { force: true });
+  } catch (err: unknown) {
+    appLogger.warn("Could not delete old network backup", {
+      filePath,
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+};
+
+/**
+ * Takes a snapshot, writes it encrypted to the share, verifies it there and
+ * updates the manifest
+ *
+ * @throws If the key is not set, the share cannot be written, or verification fails
+ */
+export async function performNetworkBackup(
+  settings: ResolvedNetworkBackupSettings,
+  context: Pick<JobContext, "reportProgress">,
+  now: Date = new Date(),
+  passphrase: string | undefined = process.env[BACKUP_KEY_ENV]
+): Promise<NetworkBackupResult> {
+  const folder = getBackupFolder(settings.targetDir);
+  const fileName = `sheetpilot-${now.toISOString().replace(/[:.]/g, "-")}.sqlite.enc`;
+  const filePath = path.join(folder, fileName);
+  const tempDir = fs.mkdtempSync(path.join(os.tmpdir(), "sheetpilot-backup-"));
+
+  try {
+    if (!passphrase) {
+      throw new Error(`Set ${BACKUP_KEY_ENV} to enable network backups`);
+    }
+    context.reportProgress(10, "Taking database snapshot");
+    const snapshotPath = path.join(tempDir, "snapshot.sqlite");
+    await snapshotDatabase(snapshotPath);
+
+    context.reportProgress(40, "Copying encrypted backup to the network folder");
+    const encrypted = encryptBackup(fs.readFileSync(snapshotPath), passphrase);
+    fs.mkdirSync(folder, { recursive: true });
+    // Written under a temporary name so a dropped connection never leaves a
+    // file that looks complete
+    fs.writeFileSync(`${filePath}.partial`, encrypted);
+    fs.renameSync(`${filePath}.partial`, filePath);
+
+    context.reportProgress(70, "Verifying the copy on the network folder");
+    let verified: ReturnType<typeof verifyBackupFile>;
+    try {
+      verified = verifyBackupFile(filePath, passphrase);
+    } catch (err: unknown) {
+      removeQuietly(filePath);
+      throw err;
+    }
+    if (!verified.ok || verified.sha256 !== sha256(encrypted)) {
+      removeQuietly(filePath);
+      throw new Error(
+        `Backup on the network folder failed verification: ${verified.ok ? "checksum mismatch" : verified.messages.slice(0, 3).join("; ")}`
+      );
+    }
+
+    context.reportProgress(90, "Updating manifest");
+    const manifest: BackupManifest = readBackupManifest(folder) ?? {
+      version: 1,
+      host: os.hostname(),
+      user: os.userInfo().username,
+      encryption: `aes-256-gcm, key from scrypt(${BACKUP_KEY_ENV}, salt in file header)`,
+      backups: [],
+    };
+    manifest.backups.push({
+      file: fileName,
+      createdAt: now.toISOString(),
+      sizeBytes: encrypted.length,
+      sha256: verified.sha256,
+      schemaVersion: verified.schemaVersion,
+      entryCount: verified.entryCount,
+      verifiedAt: new Date().toISOString(),
+    });
+    const pruned = manifest.backups.splice(0, Math.max(0, manifest.backups.length - settings.keep));
+    writeBackupManifest(folder, manifest);
+    pruned.forEach((entry) => removeQuietly(path.join(folder, entry.file)));
+
+    appLogger.info("Network backup written and verified", {
+      filePath,
+      sizeBytes: encrypted.length,
+      entryCount: verified.entryCount,
+      pruned: pruned.length,
+    });
+    return {
+      filePath,
+      sizeBytes: encrypted.length,
+      entryCount: verified.entryCount,
+      pruned: pruned.map((entry) => entry.file),
+    };
+  } catch (err: unknown) {
+    const errorMessage = err instanceof Error ? err.message : String(err);
+    appLogger.error("Network backup failed", { folder, error: errorMessage });
+    notifyUser("Network backup failed", errorMessage);
+    throw err;
+  } finally {
+    fs.rmSync(tempDir, { recursive: true, force: true });
+  }
+}
+
+/** Run history times are SQLite UTC "YYYY-MM-DD HH:MM:SS" */
+const parseRunTime = (value: string): number => new Date(`${value.replace(" ", "T")}Z`).getTime();
+
+/**
+ * Whether a scheduled backup should run, from this job type's recent runs (newest first)
+ *
+ * Due once the interval has passed since the last successful backup; after a
+ * failure, not retried for an hour.
+ */
+export function isNetworkBackupDue(
+  runs: Array<Pick<RunHistoryRecord, "status" | "startedAt">>,
+  intervalHours: number,
+  now: Date
+): boolean {
+  const latest = runs[0];
+  if (!latest) return true;
+  if (latest.status === "running") return false;
+  const lastSuccess = runs.find((run) => run.status === "success");
+  if (lastSuccess && now.getTime() - parseRunTime(lastSuccess.startedAt) < intervalHours * 60 * 60 * 1000) {
+    return false;
+  }
+  return !(latest.status === "failure" && now.getTime() - parseRunTime(latest.startedAt) < RETRY_AFTER_MS);
+}
+
+/**
+ * Queues a network backup as a background job
+ *
+ * @returns Job ID
+ */
+export function enqueueNetworkBackup(
+  settings: ResolvedNetworkBackupSettings,
+  trigger: RunTrigger
+): string {
+  return enqueueJob(
+    NETWORK_BACKUP_JOB_TYPE,
+    (context) => performNetworkBackup(settings, context),
+    { trigger, detail: { targetDir: settings.targetDir } }
+  );
+}
+
+export interface NetworkBackupStatus {
+  configured: boolean;
+  /** Whether SHEETPILOT_BACKUP_KEY is set */
+  keyAvailable: boolean;
+  folder: string | null;
+  manifest: BackupManifest | null;
+  /** Set when the manifest could not be read (e.g. the share is offline) */
+  manifestError?: string;
+}
+
+/**
+ * Settings, key and manifest state for the backup settings panel
+ */
+export function getNetworkBackupStatus(): NetworkBackupStatus {
+  const settings = resolveNetworkBackupSettings(loadSettings().network_backup);
+  const keyAvailable = Boolean(process.env[BACKUP_KEY_ENV]);
+  if (!settings) {
+    return { configured: false, keyAvailable, folder: null, manifest: null };
+  }
+  const folder = getBackupFolder(settings.targetDir);
+  try {
+    return { configured: true, keyAvailable, folder, manifest: readBackupManifest(folder) };
+  } catch (err: unknown) {
+    return {
+      configured: true,
+      keyAvailable,
+      folder,
+      manifest: null,
+      manifestError: err instanceof Error ? err.message : String(err),
+    };
+  }
+}
+
+export const networkBackupTask: ScheduledTask = {
+  name: NETWORK_BACKUP_JOB_TYPE,
+  runDue: async (now: Date) => {
+    const settings = resolveNetworkBackupSettings(loadSettings().network_backup);
+    if (!settings) return;
+    if (!isNetworkBackupDue(listRuns({ jobType: NETWORK_BACKUP_JOB_TYPE, limit: 20 }), settings.intervalHours, now)) {
+      return;
+    }
+    await waitForJob(enqueueNetworkBackup(settings, "scheduled"));
+  },
+};
+
+/**
+ * Registers the network backup task with the scheduler
+ */
+export function registerNetworkBackupTask(): void {
+  registerScheduledTask(networkBackupTask);
+}
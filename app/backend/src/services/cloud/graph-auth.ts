@@ -0,0 +1,297 @@
+/**
+ * @fileoverview Microsoft Graph Authentication
+ *
+ * Device-code sign-in for Microsoft Graph. The refresh token and upload
+ * configuration are stored (encrypted) in the credentials table under the
+ * `microsoft-graph` service; access tokens are only cached in memory.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { appLogger } from "@sheetpilot/shared/logger";
+import { deleteCredentials, getCredentials, storeCredentials } from "@/models";
+import { notifyUser } from "@/services/notifications";
+
+export const GRAPH_CREDENTIAL_SERVICE = "microsoft-graph";
+export const GRAPH_API_BASE = "https://graph.microsoft.com/v1.0";
+
+const GRAPH_SCOPES = "offline_access User.Read Files.ReadWrite Calendars.Read";
+/** Refresh access tokens this long before they actually expire */
+const TOKEN_EXPIRY_MARGIN_MS = 60_000;
+
+export interface GraphConnectionConfig {
+  clientId: string;
+  tenantId: string;
+  folderPath: string;
+}
+
+interface StoredGraphToken extends GraphConnectionConfig {
+  refreshToken: string;
+}
+
+export interface DeviceCodePrompt {
+  userCode: string;
+  verificationUri: string;
+  message: string;
+  expiresAt: string;
+}
+
+export interface GraphConnectionStatus {
+  connected: boolean;
+  account: string | null;
+  folderPath: string | null;
+  pendingLogin: DeviceCodePrompt | null;
+  lastError: string | null;
+}
+
+interface TokenResponse {
+  access_token?: string;
+  refresh_token?: string;
+  expires_in?: number;
+  error?: string;
+  error_description?: string;
+}
+
+let cachedAccessToken: { token: string; expiresAt: number } | null = null;
+let pendingLogin: { prompt: DeviceCodePrompt; cancelled: boolean } | null =
+  null;
+let lastLoginError: string | null = null;
+
+const authorityUrl = (tenantId: string, path: string): string =>
+  `https://login.microsoftonline.com/${encodeURIComponent(tenantId)}/oauth2/v2.0/${path}`;
+
+const postForm = async (
+  url: string,
+  body: Record<string, string>
+): Promise<TokenResponse & Record<string, unknown>> => {
+  const response = await fetch(url, {
+    method: "POST",
+    headers: { "Content-Type": "application/x-www-form-urlencoded" },
+    body: new URLSearchParams(body).toString(),
+  });
+  return (await response.json()) as TokenResponse & Record<string, unknown>;
+};
+
+const sleep = (ms: number): Promise<void> =>
+  new Promise((resolve) => setTimeout(resolve, ms));
+
+const readStoredToken = (): { account: string; token: StoredGraphToken } | null => {
+  const stored = getCredentials(GRAPH_CREDENTIAL_SERVICE, {
+    caller: "cloud:graph-token",
+    purpose: "cloud-sync",
+  });
+  if (!stored) return null;
+  try {
+    return {
+      account: stored.email,
+      token: JSON.parse(stored.password) as StoredGraphToken,
+    };
+  } catch {
+    appLogger.warn("Stored Microsoft Graph token is unreadable");
+    return null;
+  }
+};
+
+const writeStoredToken = (account: string, token: StoredGraphToken): void => {
+  const result = storeCredentials(
+    GRAPH_CREDENTIAL_SERVICE,
+    account,
+    JSON.stringify(token)
+  );
+  if (!result.success) {
+    throw new Error(`Could not store Microsoft Graph token: ${result.message}`);
+  }
+};
+
+const cacheAccessToken = (response: TokenResponse): string => {
+  const token = response.access_token!;
+  cachedAccessToken = {
+    token,
+    expiresAt:
+      Date.now() + (response.expires_in ?? 3600) * 1000 - TOKEN_EXPIRY_MARGIN_MS,
+  };
+  return token;
+};
+
+const fetchAccountName = async (accessToken: string): Promise<string> => {
+  const response = await fetch(`${GRAPH_API_BASE}/me`, {
+    headers: { Authorization: `Bearer ${accessToken}` },
+  });
+  if (!response.ok) {
+    return "microsoft-account";
+  }
+  const me = (await response.json()) as {
+    userPrincipalName?: string;
+    mail?: string;
+  };
+  return me.userPrincipalName ?? me.mail ?? "microsoft-account";
+};
+
+const pollForToken = async (
+  config: GraphConnectionConfig,
+  deviceCode: string,
+  intervalSeconds: number,
+  expiresAtMs: number,
+  state: { cancelled: boolean }
+): Promise<void> => {
+  let interval = intervalSeconds;
+  while (!state.cancelled && Date.now() < expiresAtMs) {
+    await sleep(interval * 1000);
+    if (state.cancelled) return;
+
+    const response = await postForm(authorityUrl(config.tenantId, "token"), {
+      grant_type: "urn:ietf:params:oauth:grant-type:device_code",
+      client_id: config.clientId,
+      device_code: deviceCode,
+    });
+
+    if (response.access_token && response.refresh_token) {
+      const accessToken = cacheAccessToken(response);
+      const account = await fetchAccountName(accessToken);
+      writeStoredToken(account, { ...config, refreshToken: response.refresh_token });
+      appLogger.info("Microsoft Graph connected", { account });
+      notifyUser("OneDrive connected", `Exports will upload to ${config.folderPath}`);
+      return;
+    }
+
+    if (response.error === "authorization_pending") continue;
+    if (response.error === "slow_down") {
+      interval += 5;
+      continue;
+    }
+    throw new Error(response.error_description ?? response.error ?? "Sign-in failed");
+  }
+  if (!state.cancelled) {
+    throw new Error("Sign-in code expired before it was used");
+  }
+};
+
+/**
+ * Starts a device-code sign-in and polls for completion in the background
+ *
+ * @returns Code and URL the user must enter in a browser
+ */
+export async function startGraphDeviceLogin(
+  config: GraphConnectionConfig
+): Promise<DeviceCodePrompt> {
+  if (pendingLogin) {
+    pendingLogin.cancelled = true;
+  }
+
+  const response = (await postForm(authorityUrl(config.tenantId, "devicecode"), {
+    client_id: config.clientId,
+    scope: GRAPH_SCOPES,
+  })) as {
+    device_code?: string;
+    user_code?: string;
+    verification_uri?: string;
+    message?: string;
+    expires_in?: number;
+    interval?: number;
+    error?: string;
+    error_description?: string;
+  };
+
+  if (!response.device_code || !response.user_code || !response.verification_uri) {
+    throw new Error(
+      response.error_description ?? response.error ?? "Could not start sign-in"
+    );
+  }
+
+  const expiresAtMs = Date.now() + (response.expires_in ?? 900) * 1000;
+  const prompt: DeviceCodePrompt = {
+    userCode: response.user_code,
+    verificationUri: response.verification_uri,
+    message: response.message ?? "",
+    expiresAt: new Date(expiresAtMs).toISOString(),
+  };
+  const state = { prompt, cancelled: false };
+  pendingLogin = state;
+  lastLoginError = null;
+
+  void pollForToken(
+    config,
+    response.device_code,
+    response.interval ?? 5,
+    expiresAtMs,
+    state
+  )
+    .catch((err: unknown) => {
+      lastLoginError = err instanceof Error ? err.message : String(err);
+      appLogger.warn("Microsoft Graph sign-in failed", { error: lastLoginError });
+    })
+    .finally(() => {
+      if (pendingLogin === state) {
+        pendingLogin = null;
+      }
+    });
+
+  return prompt;
+}
+
+/**
+ * Reports whether Microsoft Graph is connected and any sign-in in progress
+ */
+export function getGraphConnectionStatus(): GraphConnectionStatus {
+  const stored = readStoredToken();
+  return {
+    connected: stored !== null,
+    account: stored?.account ?? null,
+    folderPath: stored?.token.folderPath ?? null,
+    pendingLogin: pendingLogin?.prompt ?? null,
+    lastError: lastLoginError,
+  };
+}
+
+/**
+ * Gets a valid access token, refreshing (and rotating the stored refresh token) as needed
+ *
+ * @throws Error if Microsoft Graph is not connected or the refresh fails
+ */
+export async function getGraphAccessToken(): Promise<{
+  accessToken: string;
+  folderPath: string;
+}> {
+  const stored = readStoredToken();
+  if (!stored) {
+    throw new Error("OneDrive/SharePoint is not connected");
+  }
+  if (cachedAccessToken && cachedAccessToken.expiresAt > Date.now()) {
+    return { accessToken: cachedAccessToken.token, folderPath: stored.token.folderPath };
+  }
+
+  const response = await postForm(authorityUrl(stored.token.tenantId, "token"), {
+    grant_type: "refresh_token",
+    client_id: stored.token.clientId,
+    refresh_token: stored.token.refreshToken,
+    scope: GRAPH_SCOPES,
+  });
+  if (!response.access_token) {
+    throw new Error(
+      `Microsoft sign-in expired, reconnect OneDrive/SharePoint (${response.error ?? "unknown error"})`
+    );
+  }
+
+  if (response.refresh_token && response.refresh_token !== stored.token.refreshToken) {
+    writeStoredToken(stored.account, {
+      ...stored.token,
+      refreshToken: response.refresh_token,
+    });
+  }
+  return { accessToken: cacheAccessToken(response), folderPath: stored.token.folderPath };
+}
+
+/**
+ * Forgets the stored Microsoft Graph token and cancels any pending sign-in
+ */
+export function disconnectGraph(): void {
+  if (pendingLogin) {
+    pendingLogin.cancelled = true;
+    pendingLogin = null;
+  }
+  cachedAccessToken = null;
+  lastLoginError = null;
+  deleteCredentials(GRAPH_CREDENTIAL_SERVICE);
+}
@@ -0,0 +1,99 @@
+/**
+ * @fileoverview Outlook Calendar via Microsoft Graph
+ *
+ * Reads calendar events for a date range using the calendarView endpoint
+ * (which expands recurring meetings) and normalizes them for import.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { CalendarEvent } from "@/logic/calendar-import";
+import { getSystemTimeZone, startOfDayInZone } from "@/logic/time-zones";
+import { GRAPH_API_BASE, getGraphAccessToken } from "./graph-auth";
+
+/** Safety cap on pages followed via @odata.nextLink */
+const MAX_PAGES = 20;
+
+interface GraphCalendarEvent {
+  id: string;
+  subject?: string | null;
+  start: { dateTime: string; timeZone: string };
+  end: { dateTime: string; timeZone: string };
+  categories?: string[];
+  isAllDay?: boolean;
+  isCancelled?: boolean;
+  showAs?: string;
+}
+
+/**
+ * Graph returns naive date-times in the zone requested via the Prefer header (UTC here)
+ */
+const toIsoTimestamp = (dateTime: string): string =>
+  new Date(/[zZ]|[+-]\d{2}:\d{2}$/.test(dateTime) ? dateTime : `${dateTime}Z`).toISOString();
+
+/**
+ * Fetches calendar events between two dates (inclusive) in `timeZone`
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ * @param timeZone - Zone the days are counted in (the home zone, not the machine's)
+ * @throws Error if Microsoft Graph is not connected or the request fails
+ */
+export async function fetchCalendarEvents(
+  startDate: string,
+  endDate: string,
+  timeZone: string = getSystemTimeZone()
+): Promise<CalendarEvent[]> {
+  const { accessToken } = await getGraphAccessToken();
+  const [ey, em, ed] = endDate.split("-").map(Number);
+  const dayAfterEnd = new Date(Date.UTC(ey!, em! - 1, ed! + 1)).toISOString().slice(0, 10);
+  const rangeStart = startOfDayInZone(startDate, timeZone);
+  const rangeEnd = startOfDayInZone(dayAfterEnd, timeZone);
+
+  const params = new URLSearchParams({
+    startDateTime: rangeStart.toISOString(),
+    endDateTime: rangeEnd.toISOString(),
+    $select: "id,subject,start,end,categories,isAllDay,isCancelled,showAs",
+    $top: "100",
+  });
+  let url: string | undefined = `${GRAPH_API_BASE}/me/calendarView?${params.toString()}`;
+  const events: CalendarEvent[] = [];
+
+  for (let page = 0; url && page < MAX_PAGES; page++) {
+    const response = await fetch(url, {
+      headers: {
+        Authorization: `Bearer ${accessToken}`,
+        Prefer: 'outlook.timezone="UTC"',
+      },
+    });
+    if (response.status === 403) {
+      throw new Error(
+        "Calendar access was not granted; disconnect and reconnect OneDrive/SharePoint to approve calendar access"
+      );
+    }
+    if (!response.ok) {
+      throw new Error(`Could not read calendar: HTTP ${response.status}`);
+    }
+    const body = (await response.json()) as {
+      value: GraphCalendarEvent[];
+      "@odata.nextLink"?: string;
+    };
+    for (const item of body.value) {
+      events.push({
+        id: item.id,
+        subject: item.subject ?? "",
+        start: toIsoTimestamp(item.start.dateTime),
+        end: toIsoTimestamp(item.end.dateTime),
+        categories: item.categories ?? [],
+        isAllDay: item.isAllDay ?? false,
+        isCancelled: item.isCancelled ?? false,
+        showAs: item.showAs,
+      });
+    }
+    url = body["@odata.nextLink"];
+  }
+
+  return events;
+}
@@ -0,0 +1,66 @@
+/**
+ * @fileoverview Company Directory via Microsoft Graph
+ *
+ * Finds a user in Entra ID (Azure AD) by any address they go by: sign-in
+ * name (UPN), primary mail or an smtp alias. Needs `User.ReadBasic.All`
+ * consented for the app registration used to connect OneDrive/SharePoint.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { DirectoryProvider, DirectoryUser } from "@/services/security/directory-lookup";
+import { GRAPH_API_BASE, getGraphAccessToken } from "./graph-auth";
+
+/** OData string literal (single quotes are doubled) */
+const odataString = (value: string): string => `'${value.replace(/'/g, "''")}'`;
+
+/**
+ * Filter matching a user by UPN, mail or any smtp proxy address
+ */
+export function buildDirectoryUserFilter(email: string): string {
+  const address = email.trim();
+  return [
+    `userPrincipalName eq ${odataString(address)}`,
+    `mail eq ${odataString(address)}`,
+    `proxyAddresses/any(p:p eq ${odataString(`smtp:${address}`)})`,
+  ].join(" or ");
+}
+
+export const graphDirectoryProvider: DirectoryProvider = {
+  name: "graph",
+  async findUser(email: string): Promise<DirectoryUser | null> {
+    const { accessToken } = await getGraphAccessToken();
+    const params = new URLSearchParams({
+      $filter: buildDirectoryUserFilter(email),
+      $select: "userPrincipalName,mail",
+      // proxyAddresses filters are advanced queries: they need $count and eventual consistency
+      $count: "true",
+      $top: "2",
+    });
+    const response = await fetch(`${GRAPH_API_BASE}/users?${params.toString()}`, {
+      headers: {
+        Authorization: `Bearer ${accessToken}`,
+        ConsistencyLevel: "eventual",
+      },
+    });
+    if (response.status === 403) {
+      throw new Error("Directory lookup was not granted; the app registration needs User.ReadBasic.All");
+    }
+    if (!response.ok) {
+      throw new Error(`Could not search the directory: HTTP ${response.status}`);
+    }
+    const body = (await response.json()) as {
+      value: Array<{ userPrincipalName?: string | null; mail?: string | null }>;
+    };
+    if (body.value.length > 1) {
+      throw new Error("More than one directory account uses this address");
+    }
+    const user = body.value[0];
+    if (!user?.userPrincipalName) {
+      return null;
+    }
+    return { userPrincipalName: user.userPrincipalName, mail: user.mail ?? null };
+  },
+};
@@ -0,0 +1,173 @@
+/**
+ * @fileoverview OneDrive/SharePoint Upload
+ *
+ * Uploads generated files to the configured Microsoft Graph drive folder.
+ * Small files use a single PUT; larger files go through an upload session.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { appLogger } from "@sheetpilot/shared/logger";
+import type { RunTrigger } from "@/models";
+import { notifyUser } from "@/services/notifications";
+import { enqueueJob } from "@/services/jobs/job-manager";
+import { GRAPH_API_BASE, getGraphAccessToken } from "./graph-auth";
+
+export const CLOUD_UPLOAD_JOB_TYPE = "cloud-upload";
+
+/** Graph accepts simple uploads up to 4 MiB */
+const SIMPLE_UPLOAD_LIMIT = 4 * 1024 * 1024;
+/** Upload session chunks must be multiples of 320 KiB */
+const UPLOAD_CHUNK_SIZE = 320 * 1024 * 10;
+
+export interface CloudUploadResult {
+  remotePath: string;
+  webUrl: string | null;
+  size: number;
+}
+
+/**
+ * Builds the drive-relative item path, encoding each segment
+ */
+export function buildDriveItemPath(folderPath: string, fileName: string): string {
+  const segments = [...folderPath.split("/"), fileName]
+    .map((segment) => segment.trim())
+    .filter((segment) => segment.length > 0);
+  return segments.map((segment) => encodeURIComponent(segment)).join("/");
+}
+
+const readGraphError = async (response: Response): Promise<string> => {
+  try {
+    const body = (await response.json()) as { error?: { message?: string } };
+    return body.error?.message ?? `HTTP ${response.status}`;
+  } catch {
+    return `HTTP ${response.status}`;
+  }
+};
+
+const uploadInChunks = async (
+  accessToken: string,
+  itemPath: string,
+  data: Buffer
+): Promise<{ webUrl?: string }> => {
+  const session = await fetch(
+    `${GRAPH_API_BASE}/me/drive/root:/${itemPath}:/createUploadSession`,
+    {
+      method: "POST",
+      headers: {
+        Authorization: `Bearer ${accessToken}`,
+        "Content-Type": "application/json",
+      },
+      body: JSON.stringify({ item: { "@microsoft.graph.conflictBehavior": "replace" } }),
+    }
+  );
+  if (!session.ok) {
+    throw new Error(`Could not start upload session: ${await readGraphError(session)}`);
+  }
+  const { uploadUrl } = (await session.json()) as { uploadUrl: string };
+
+  let item: { webUrl?: string } = {};
+  for (let start = 0; start < data.length; start += UPLOAD_CHUNK_SIZE) {
+    const chunk = data.subarray(start, Math.min(start + UPLOAD_CHUNK_SIZE, data.length));
+    const end = start + chunk.length - 1;
+    // The pre-authenticated upload URL must not receive an Authorization header
+    const response = await fetch(uploadUrl, {
+      method: "PUT",
+      headers: {
+        "Content-Length": String(chunk.length),
+        "Content-Range": `bytes ${start}-${end}/${data.length}`,
+      },
+      body: new Uint8Array(chunk),
+    });
+    if (!response.ok) {
+      throw new Error(`Upload failed at byte ${start}: ${await readGraphError(response)}`);
+    }
+    if (response.status === 200 || response.status === 201) {
+      item = (await response.json()) as { webUrl?: string };
+    }
+  }
+  return item;
+};
+
+/**
+ * Uploads a local file to the configured OneDrive/SharePoint folder
+ *
+ * @throws Error if not connected or the upload fails
+ */
+export async function uploadFileToCloud(
+  filePath: string,
+  fileName: string = path.basename(filePath)
+): Promise<CloudUploadResult> {
+  const { accessToken, folderPath } = await getGraphAccessToken();
+  const data = fs.readFileSync(filePath);
+  const itemPath = buildDriveItemPath(folderPath, fileName);
+
+  let item: { webUrl?: string };
+  if (data.length <= SIMPLE_UPLOAD_LIMIT) {
+    const response = await fetch(
+      `${GRAPH_API_BASE}/me/drive/root:/${itemPath}:/content`,
+      {
+        method: "PUT",
+        headers: {
+          Authorization: `Bearer ${accessToken}`,
+          "Content-Type": "application/octet-stream",
+        },
+        body: new Uint8Array(data),
+      }
+    );
+    if (!response.ok) {
+      throw new Error(`Upload failed: ${await readGraphError(response)}`);
+    }
+    item = (await response.json()) as { webUrl?: string };
+  } else {
+    item = await uploadInChunks(accessToken, itemPath, data);
+  }
+
+  return {
+    remotePath: decodeURIComponent(itemPath),
+    webUrl: item.webUrl ?? null,
+    size: data.length,
+  };
+}
+
+/**
+ * Queues a background upload job (recorded in run history); notifies the user on failure
+ *
+ * @param filePath - File to upload
+ * @param trigger - What started the upload
+ * @param cleanup - Called after the upload finishes either way (e.g. to remove a temp file)
+ * @returns Job ID
+ */
+export function enqueueCloudUpload(
+  filePath: string,
+  trigger: RunTrigger,
+  cleanup?: () => void
+): string {
+  const fileName = path.basename(filePath);
+  return enqueueJob(
+    CLOUD_UPLOAD_JOB_TYPE,
+    async (context) => {
+      try {
+        context.reportProgress(0, `Uploading ${fileName}`);
+        const result = await uploadFileToCloud(filePath);
+        appLogger.info("File uploaded to cloud archive", {
+          remotePath: result.remotePath,
+          size: result.size,
+        });
+        return result;
+      } catch (err: unknown) {
+        const errorMessage = err instanceof Error ? err.message : String(err);
+        appLogger.error("Cloud upload failed", { filePath, error: errorMessage });
+        notifyUser("Upload to OneDrive failed", `${fileName}: ${errorMessage}`);
+        throw err;
+      } finally {
+        cleanup?.();
+      }
+    },
+    { trigger, detail: { file: fileName } }
+  );
+}
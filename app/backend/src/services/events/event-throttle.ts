@@ -0,0 +1,179 @@
+/**
+ * @fileoverview Renderer Event Throttle
+ *
+ * Submission progress, job updates and status counts can fire many times a
+ * second; sending each one to the renderer floods the webview's IPC queue
+ * and makes React re-render faster than anyone can read. Every event sent to
+ * the main window goes through this throttle instead:
+ *
+ * - Sends are paced to `maxEventsPerSecond` (bursts up to that many, then
+ *   one at a time as the budget refills); the rest wait in order
+ * - A waiting event with the same coalesce key is replaced by the newer one
+ *   (or merged with it), so a backlog of progress updates collapses to the
+ *   latest state and the final update is never lost
+ *
+ * Events without a coalesce key are never dropped.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+
+export interface EventThrottleSettings {
+  /** Most events sent to the renderer per second; 0 sends everything immediately */
+  maxEventsPerSecond: number;
+}
+
+export const DEFAULT_EVENT_THROTTLE: EventThrottleSettings = {
+  maxEventsPerSecond: 20,
+};
+
+/** Highest rate accepted; more than this is no throttle at all */
+export const MAX_EVENTS_PER_SECOND = 1000;
+
+export interface EmitOptions<T> {
+  /** Waiting events with the same key are coalesced into the newest one */
+  coalesceKey?: string;
+  /** Combines a waiting payload with a newer one; default keeps the newer */
+  merge?: (pending: T, next: T) => T;
+}
+
+export type EventSender = (channel: string, payload: unknown) => void;
+
+interface PendingEvent {
+  channel: string;
+  payload: unknown;
+  coalesceKey?: string;
+}
+
+let settings: EventThrottleSettings = { ...DEFAULT_EVENT_THROTTLE };
+
+/**
+ * Keeps a finite, non-negative `maxEventsPerSecond` from a stored settings object
+ */
+export function sanitizeEventThrottleSettings(raw: unknown): Partial<EventThrottleSettings> {
+  if (!raw || typeof raw !== 'object') return {};
+  const value = (raw as Record<string, unknown>)['maxEventsPerSecond'];
+  if (typeof value !== 'number' || !Number.isFinite(value) || value < 0) return {};
+  return { maxEventsPerSecond: Math.min(value, MAX_EVENTS_PER_SECOND) };
+}
+
+/**
+ * Replaces the throttle settings used by every throttle (unset values fall back to the defaults)
+ */
+export function configureEventThrottle(overrides: Partial<EventThrottleSettings>): EventThrottleSettings {
+  settings = { ...DEFAULT_EVENT_THROTTLE, ...overrides };
+  ipcLogger.verbose('Renderer event throttle configured', { ...settings });
+  return { ...settings };
+}
+
+export function getEventThrottleSettings(): EventThrottleSettings {
+  return { ...settings };
+}
+
+export class EventThrottle {
+  private readonly send: EventSender;
+  private queue: PendingEvent[] = [];
+  private tokens: number | null = null;
+  private refilledAt = 0;
+  private timer: ReturnType<typeof setTimeout> | null = null;
+
+  constructor(send: EventSender) {
+    this.send = send;
+  }
+
+  emit<T>(channel: string, payload: T, options: EmitOptions<T> = {}): void {
+    const { coalesceKey, merge } = options;
+    if (coalesceKey !== undefined) {
+      const waiting = this.queue.find((event) => event.coalesceKey === coalesceKey);
+      if (waiting) {
+        waiting.payload = merge ? merge(waiting.payload as T, payload) : payload;
+        return;
+      }
+    }
+    this.queue.push({
+      channel,
+      payload,
+      ...(coalesceKey !== undefined ? { coalesceKey } : {}),
+    });
+    this.drain();
+  }
+
+  /**
+   * Sends everything waiting now, e.g. before the window closes
+   */
+  flush(): void {
+    this.clearTimer();
+    const waiting = this.queue;
+    this.queue = [];
+    for (const event of waiting) {
+      this.deliver(event);
+    }
+  }
+
+  /**
+   * Drops everything waiting
+   */
+  clear(): void {
+    this.clearTimer();
+    this.queue = [];
+  }
+
+  getPendingCount(): number {
+    return this.queue.length;
+  }
+
+  private drain(): void {
+    const { maxEventsPerSecond } = settings;
+    if (maxEventsPerSecond === 0) {
+      this.flush();
+      return;
+    }
+    this.refill(maxEventsPerSecond);
+    while (this.queue.length > 0 && this.tokens! >= 1) {
+      this.tokens! -= 1;
+      this.deliver(this.queue.shift()!);
+    }
+    if (this.queue.length > 0 && this.timer === null) {
+      const waitMs = Math.ceil(((1 - this.tokens!) * 1000) / maxEventsPerSecond);
+      this.timer = setTimeout(() => {
+        this.timer = null;
+        this.drain();
+      }, Math.max(waitMs, 1));
+      this.timer.unref?.();
+    }
+  }
+
+  private refill(maxEventsPerSecond: number): void {
+    const now = Date.now();
+    // Rates below one a second still need room for a whole event
+    const capacity = Math.max(1, maxEventsPerSecond);
+    if (this.tokens === null) {
+      this.tokens = capacity;
+    } else {
+      const earned = ((now - this.refilledAt) / 1000) * maxEventsPerSecond;
+      this.tokens = Math.min(capacity, this.tokens + earned);
+    }
+    this.refilledAt = now;
+  }
+
+  private deliver(event: PendingEvent): void {
+    try {
+      this.send(event.channel, event.payload);
+    } catch (err: unknown) {
+      ipcLogger.warn('Could not send event to renderer', {
+        channel: event.channel,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+
+  private clearTimer(): void {
+    if (this.timer !== null) {
+      clearTimeout(this.timer);
+      this.timer = null;
+    }
+  }
+}
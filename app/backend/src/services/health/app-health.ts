@@ -0,0 +1,148 @@
+/**
+ * @fileoverview App Health
+ *
+ * Kiosk and locked-down builds can block native save dialogs or writing to
+ * the Downloads folder, which used to make exports fail without a word. The
+ * platform capabilities are probed once at startup and reported by
+ * `app:getHealth`; the renderer uses `exportMode` to decide whether an export
+ * is saved as a file or handed back as text (copied to the clipboard).
+ *
+ * Admins disable the dialog explicitly with `kiosk.disable_file_dialogs` in
+ * settings.json.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { app, dialog } from 'electron';
+import { appLogger } from '@sheetpilot/shared/logger';
+import { getDb } from '@/models';
+import { loadSettings } from '@/services/settings/settings-store';
+import { getClockSkewStatus, type ClockSkewStatus } from './clock-skew';
+
+export interface KioskSettings {
+  /** Never show native file dialogs (exports go to Downloads, or are returned as text) */
+  disable_file_dialogs?: boolean;
+}
+
+/**
+ * How exports reach the user: saved through the download flow, or returned
+ * as text when nothing can be written to disk
+ */
+export type ExportMode = 'download' | 'string';
+
+export interface PlatformCapabilities {
+  fileDialogs: boolean;
+  downloadsWritable: boolean;
+  exportMode: ExportMode;
+  checkedAt: string;
+}
+
+export interface AppHealth {
+  version: string;
+  database: { connected: boolean };
+  capabilities: PlatformCapabilities;
+  /** Result of the startup clock check; null until it has run */
+  clock: ClockSkewStatus | null;
+}
+
+export interface CapabilityProbes {
+  fileDialogs: () => boolean;
+  downloadsDirectory: () => string;
+}
+
+const defaultProbes: CapabilityProbes = {
+  fileDialogs: () =>
+    typeof dialog?.showSaveDialog === 'function' && loadSettings().kiosk?.disable_file_dialogs !== true,
+  downloadsDirectory: () => app.getPath('downloads')
+};
+
+let capabilities: PlatformCapabilities | null = null;
+
+/**
+ * Whether a file can be created in `directory` (access() is unreliable for
+ * policy-blocked folders on Windows, so this writes and removes a probe file)
+ */
+function canWriteTo(directory: string): boolean {
+  const probePath = path.join(directory, `.sheetpilot-write-probe-${process.pid}`);
+  try {
+    fs.writeFileSync(probePath, '');
+    fs.unlinkSync(probePath);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Probes the file dialog and the Downloads folder and keeps the result for
+ * `getPlatformCapabilities`
+ */
+export function detectPlatformCapabilities(probes: CapabilityProbes = defaultProbes): PlatformCapabilities {
+  let fileDialogs = false;
+  try {
+    fileDialogs = probes.fileDialogs();
+  } catch {
+    fileDialogs = false;
+  }
+  let downloadsWritable = false;
+  try {
+    downloadsWritable = canWriteTo(probes.downloadsDirectory());
+  } catch {
+    downloadsWritable = false;
+  }
+  capabilities = {
+    fileDialogs,
+    downloadsWritable,
+    exportMode: fileDialogs || downloadsWritable ? 'download' : 'string',
+    checkedAt: new Date().toISOString()
+  };
+  if (capabilities.exportMode === 'string') {
+    appLogger.warn('File dialogs and the Downloads folder are unavailable; exports will be returned as text', {
+      ...capabilities
+    });
+  } else {
+    appLogger.verbose('Platform capabilities detected', { ...capabilities });
+  }
+  return { ...capabilities };
+}
+
+export function getPlatformCapabilities(): PlatformCapabilities {
+  return { ...(capabilities ?? detectPlatformCapabilities()) };
+}
+
+/**
+ * Save path for a download when no dialog can be shown: the Downloads
+ * folder, with " (n)" added before the extension if the name is taken
+ */
+export function getFallbackDownloadPath(
+  filename: string,
+  directory: string = app.getPath('downloads')
+): string {
+  const safeName = path.basename(filename) || 'export';
+  const { name, ext } = path.parse(safeName);
+  let candidate = path.join(directory, safeName);
+  for (let n = 1; fs.existsSync(candidate); n++) {
+    candidate = path.join(directory, `${name} (${n})${ext}`);
+  }
+  return candidate;
+}
+
+export function getAppHealth(): AppHealth {
+  let connected = false;
+  try {
+    getDb().prepare('SELECT 1').get();
+    connected = true;
+  } catch {
+    connected = false;
+  }
+  return {
+    version: app.getVersion(),
+    database: { connected },
+    capabilities: getPlatformCapabilities(),
+    clock: getClockSkewStatus()
+  };
+}
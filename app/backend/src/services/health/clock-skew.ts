@@ -0,0 +1,203 @@
+/**
+ * @fileoverview Clock Skew Check
+ *
+ * Quarter checks, backfill warnings and session expiry all trust the PC
+ * clock. A clock weeks off made every date check fail in ways that looked
+ * like bugs, so the clock is checked once at startup:
+ *
+ * - File times: the database, settings and log files should not be dated
+ *   after "now". If they are, the clock has gone backwards. Files only show
+ *   a clock that is behind; one that is ahead just makes them look old.
+ * - HTTP Date header (optional): `clock_check.url` in settings.json names a
+ *   server to compare against, e.g. an intranet page. It catches skew in
+ *   both directions and is preferred when it answers.
+ *
+ * When the skew is past the threshold the user is notified, the renderer
+ * gets `app:clockSkew`, and date and session errors carry a note (see
+ * `withClockSkewNote`).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { app } from 'electron';
+import { appLogger } from '@sheetpilot/shared/logger';
+import {
+  annotateWithClockSkew,
+  CLOCK_SKEW_THRESHOLD_MS,
+  describeClockSkew,
+  isClockSkewed
+} from '@sheetpilot/shared';
+import { getDbPath } from '@/models';
+import { emitClockSkew } from '@/routes/handlers/timesheet/main-window';
+import { isLogFileName } from '@/services/logs/log-indexer';
+import { notifyUser } from '@/services/notifications';
+import { loadSettings } from '@/services/settings/settings-store';
+
+const FETCH_TIMEOUT_MS = 5000;
+
+export interface ClockCheckSettings {
+  /** HTTP(S) URL whose Date header is trusted; only file times are used when unset */
+  url?: string;
+  /** Skew below this many minutes is not reported (default 10) */
+  threshold_minutes?: number;
+}
+
+export type ClockSkewSource = 'file-times' | 'http-date';
+
+export interface ClockSkewStatus {
+  /** Local time minus reference time (positive when the clock is ahead); null if nothing was measured */
+  skewMs: number | null;
+  source: ClockSkewSource | null;
+  /** True when the skew is past the threshold */
+  skewed: boolean;
+  thresholdMs: number;
+  checkedAt: string;
+  /** e.g. "Your computer clock is about 3 weeks behind", when skewed */
+  message: string | null;
+}
+
+export interface ClockCheckOptions {
+  settings?: ClockCheckSettings | undefined;
+  /** Files whose modification times are compared with now */
+  files?: string[];
+  fetch?: typeof fetch;
+  now?: () => number;
+}
+
+let status: ClockSkewStatus | null = null;
+
+const resolveThresholdMs = (settings: ClockCheckSettings | undefined): number =>
+  typeof settings?.threshold_minutes === 'number' && settings.threshold_minutes > 0
+    ? settings.threshold_minutes * 60 * 1000
+    : CLOCK_SKEW_THRESHOLD_MS;
+
+/**
+ * Database, settings and log files in the app data folder
+ */
+export function getClockReferenceFiles(userDataPath: string = app.getPath('userData')): string[] {
+  let logFiles: string[] = [];
+  try {
+    logFiles = fs.readdirSync(userDataPath).filter(isLogFileName);
+  } catch {
+    logFiles = [];
+  }
+  return [
+    getDbPath(),
+    path.join(userDataPath, 'settings.json'),
+    ...logFiles.map((file) => path.join(userDataPath, file))
+  ];
+}
+
+/**
+ * Skew implied by files modified "in the future"
+ *
+ * @returns Negative skew (clock behind) from the newest file, or null if no file is newer than now
+ */
+export function measureSkewFromFileTimes(files: string[], nowMs: number): number | null {
+  let newest = -Infinity;
+  for (const file of files) {
+    try {
+      newest = Math.max(newest, fs.statSync(file).mtimeMs);
+    } catch {
+      // Missing files say nothing about the clock
+    }
+  }
+  return newest > nowMs ? nowMs - newest : null;
+}
+
+/**
+ * Skew against a server's Date header, taking the midpoint of the request
+ * as the local time it answered at
+ *
+ * @throws If the server cannot be reached or sends no usable Date header
+ */
+export async function measureSkewFromHttpDate(
+  url: string,
+  fetchFn: typeof fetch = fetch,
+  now: () => number = Date.now
+): Promise<number> {
+  const sentAt = now();
+  const response = await fetchFn(url, {
+    method: 'HEAD',
+    cache: 'no-store',
+    signal: AbortSignal.timeout(FETCH_TIMEOUT_MS)
+  });
+  const receivedAt = now();
+  const serverMs = Date.parse(response.headers.get('date') ?? '');
+  if (Number.isNaN(serverMs)) {
+    throw new Error(`${url} sent no Date header`);
+  }
+  return Math.round((sentAt + receivedAt) / 2 - serverMs);
+}
+
+/**
+ * Measures the skew, keeps the result for `getClockSkewStatus`, and warns
+ * the user when it is past the threshold; never throws
+ */
+export async function checkClockSkew(options: ClockCheckOptions = {}): Promise<ClockSkewStatus> {
+  const settings = 'settings' in options ? options.settings : loadSettings().clock_check;
+  const now = options.now ?? Date.now;
+  const thresholdMs = resolveThresholdMs(settings);
+
+  let skewMs: number | null = null;
+  let source: ClockSkewSource | null = null;
+  if (settings?.url) {
+    try {
+      skewMs = await measureSkewFromHttpDate(settings.url, options.fetch, now);
+      source = 'http-date';
+    } catch (err: unknown) {
+      appLogger.warn('Could not check the clock against the time server', {
+        url: settings.url,
+        error: err instanceof Error ? err.message : String(err)
+      });
+    }
+  }
+  if (source === null) {
+    skewMs = measureSkewFromFileTimes(options.files ?? getClockReferenceFiles(), now());
+    source = skewMs === null ? null : 'file-times';
+  }
+
+  const skewed = isClockSkewed(skewMs, thresholdMs);
+  status = {
+    skewMs,
+    source,
+    skewed,
+    thresholdMs,
+    checkedAt: new Date(now()).toISOString(),
+    message: skewed ? describeClockSkew(skewMs as number) : null
+  };
+
+  if (status.skewed) {
+    appLogger.warn('System clock is off', { skewMs, source, thresholdMs });
+    notifyUser(
+      'Check your computer clock',
+      `${status.message}. Dates, quarters and sign-in expiry will be wrong until it is fixed.`
+    );
+    emitClockSkew({ ...status });
+  } else {
+    appLogger.verbose('System clock checked', { skewMs, source });
+  }
+  return { ...status };
+}
+
+export function getClockSkewStatus(): ClockSkewStatus | null {
+  return status ? { ...status } : null;
+}
+
+/**
+ * Adds the clock note to a date or session error when the clock is off
+ */
+export function withClockSkewNote(message: string): string {
+  return status?.skewed ? annotateWithClockSkew(message, status.skewMs, status.thresholdMs) : message;
+}
+
+/**
+ * Forgets the last check (for tests)
+ */
+export function resetClockSkewStatus(): void {
+  status = null;
+}
@@ -0,0 +1,120 @@
+/**
+ * @fileoverview App Environment
+ *
+ * Whether submissions could go to the local mock form used to be decided by
+ * a flag the renderer passed with each submit, so a dev setting left on in a
+ * production install would have sent real hours to the mock. The app now has
+ * one environment, fixed when it starts:
+ *
+ * - prod: packaged builds, always. Mock routing is refused.
+ * - dev / test: unpackaged runs. `SHEETPILOT_PROFILE` picks one explicitly;
+ *   otherwise `NODE_ENV=test` (or a Vitest run) means test, `NODE_ENV=production`
+ *   means prod and anything else dev.
+ *
+ * Non-prod responses that carry timesheet data are tagged with the
+ * environment (see `tagEnvironment`) so the renderer can mark them.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { app } from 'electron';
+import { appLogger } from '@sheetpilot/shared/logger';
+
+export type AppEnvironment = 'dev' | 'test' | 'prod';
+
+/** What decided the environment */
+export type AppEnvironmentSource = 'packaged' | 'profile' | 'node-env' | 'default';
+
+export interface AppEnvironmentInfo {
+  environment: AppEnvironment;
+  source: AppEnvironmentSource;
+  /** Whether submissions may be routed to the mock website */
+  mockRoutingAllowed: boolean;
+  /** Shown next to non-prod data, e.g. "DEV"; null in prod */
+  label: string | null;
+}
+
+export interface EnvironmentInputs {
+  isPackaged: boolean;
+  env: Record<string, string | undefined>;
+}
+
+const ENVIRONMENTS: AppEnvironment[] = ['dev', 'test', 'prod'];
+
+let cached: AppEnvironmentInfo | null = null;
+
+const toInfo = (environment: AppEnvironment, source: AppEnvironmentSource): AppEnvironmentInfo => ({
+  environment,
+  source,
+  mockRoutingAllowed: environment !== 'prod',
+  label: environment === 'prod' ? null : environment.toUpperCase()
+});
+
+/**
+ * Works out the environment from how the app was built and started
+ */
+export function resolveAppEnvironment(inputs: EnvironmentInputs): AppEnvironmentInfo {
+  const profile = inputs.env['SHEETPILOT_PROFILE']?.trim().toLowerCase();
+  if (inputs.isPackaged) {
+    if (profile && profile !== 'prod') {
+      appLogger.warn('Ignoring SHEETPILOT_PROFILE in a packaged build', { profile });
+    }
+    return toInfo('prod', 'packaged');
+  }
+  if (profile) {
+    if (ENVIRONMENTS.includes(profile as AppEnvironment)) {
+      return toInfo(profile as AppEnvironment, 'profile');
+    }
+    appLogger.warn('Unknown SHEETPILOT_PROFILE; expected dev, test or prod', { profile });
+  }
+  const nodeEnv = inputs.env['NODE_ENV'];
+  if (nodeEnv === 'test' || inputs.env['VITEST']) return toInfo('test', 'node-env');
+  if (nodeEnv === 'production') return toInfo('prod', 'node-env');
+  return toInfo('dev', 'default');
+}
+
+/**
+ * Whether this is a packaged build; anything short of a clear "no" counts as
+ * packaged so an unreadable build type never unlocks mock routing
+ */
+function isPackagedBuild(): boolean {
+  try {
+    return app.isPackaged !== false;
+  } catch {
+    return true;
+  }
+}
+
+/**
+ * The environment this process runs in (resolved once)
+ */
+export function getAppEnvironment(): AppEnvironmentInfo {
+  if (!cached) {
+    cached = resolveAppEnvironment({ isPackaged: isPackagedBuild(), env: process.env });
+  }
+  return { ...cached };
+}
+
+/**
+ * Why a submission may not use the mock website, or null if it may
+ */
+export function getMockRoutingError(
+  useMockWebsite: boolean | undefined,
+  info: AppEnvironmentInfo = getAppEnvironment()
+): string | null {
+  if (!useMockWebsite || info.mockRoutingAllowed) return null;
+  return 'Submitting to the mock website is disabled in production builds';
+}
+
+/**
+ * Adds `environment` to a response outside prod; prod responses are unchanged
+ */
+export function tagEnvironment<T extends object>(
+  payload: T,
+  info: AppEnvironmentInfo = getAppEnvironment()
+): T & { environment?: Exclude<AppEnvironment, 'prod'> } {
+  if (info.environment === 'prod') return payload;
+  return { ...payload, environment: info.environment };
+}
@@ -0,0 +1,99 @@
+/**
+ * @fileoverview Backend Message Catalog
+ *
+ * User-facing errors returned by IPC handlers, keyed by error code and
+ * translated in the backend so the renderer can show `error` as-is instead
+ * of keeping its own copy of every backend string. The locale comes from
+ * `locale` in settings.json (e.g. "es" or "es-MX"); codes missing from a
+ * locale fall back to English.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const SUPPORTED_LOCALES = ['en', 'es'] as const;
+
+export type MessageLocale = (typeof SUPPORTED_LOCALES)[number];
+
+export const DEFAULT_LOCALE: MessageLocale = 'en';
+
+const EN_MESSAGES = {
+  SESSION_INVALID: 'Session is invalid or expired. Please log in again.',
+  ADMIN_REQUIRED: 'Unauthorized: Admin access required',
+  DATE_RANGE_INVALID: 'Start date must be on or before end date',
+  ENTRY_NOT_FOUND: 'Entry not found',
+  INVALID_ENTRY_ID: 'Invalid ID provided',
+  EMAIL_DOMAIN_NOT_ALLOWED: 'Use your work email ({domains}). Personal accounts cannot be used with SheetPilot.',
+  EMAIL_NOT_IN_DIRECTORY: '{email} was not found in the company directory. Use the email you sign in to Microsoft with.',
+  RECODE_DURING_SUBMISSION: 'Wait for the current submission to finish before recoding entries',
+  SUBMISSION_LOCKED: 'Entries cannot be changed while a submission is running. Wait for it to finish.'
+} as const;
+
+export type MessageCode = keyof typeof EN_MESSAGES;
+
+export type MessageParams = Record<string, string | number>;
+
+const CATALOG: Record<MessageLocale, Partial<Record<MessageCode, string>>> = {
+  en: EN_MESSAGES,
+  es: {
+    SESSION_INVALID: 'La sesión no es válida o ha caducado. Vuelva a iniciar sesión.',
+    ADMIN_REQUIRED: 'No autorizado: se requiere acceso de administrador',
+    DATE_RANGE_INVALID: 'La fecha de inicio debe ser igual o anterior a la fecha de fin',
+    ENTRY_NOT_FOUND: 'No se encontró la entrada',
+    INVALID_ENTRY_ID: 'El ID proporcionado no es válido',
+    EMAIL_DOMAIN_NOT_ALLOWED:
+      'Use su correo de trabajo ({domains}). No se pueden usar cuentas personales con SheetPilot.',
+    EMAIL_NOT_IN_DIRECTORY:
+      '{email} no se encontró en el directorio de la empresa. Use el correo con el que inicia sesión en Microsoft.',
+    RECODE_DURING_SUBMISSION: 'Espere a que termine el envío actual antes de recodificar entradas',
+    SUBMISSION_LOCKED: 'No se pueden cambiar las entradas mientras se realiza un envío. Espere a que termine.'
+  }
+};
+
+let activeLocale: MessageLocale = DEFAULT_LOCALE;
+
+/**
+ * Supported locale for a stored (possibly malformed) setting
+ *
+ * Region tags fall back to their language ("es-MX" -> "es"); anything
+ * unsupported falls back to English.
+ */
+export function resolveMessageLocale(raw: unknown): MessageLocale {
+  if (typeof raw !== 'string') {
+    return DEFAULT_LOCALE;
+  }
+  const language = raw.trim().toLowerCase().split(/[-_]/)[0];
+  return SUPPORTED_LOCALES.find((locale) => locale === language) ?? DEFAULT_LOCALE;
+}
+
+/**
+ * Sets the locale used for messages returned by handlers
+ */
+export function configureMessageLocale(raw: unknown): void {
+  activeLocale = resolveMessageLocale(raw);
+}
+
+export function getMessageLocale(): MessageLocale {
+  return activeLocale;
+}
+
+/**
+ * Message for an error code in the configured locale
+ *
+ * `{name}` placeholders are replaced from `params`; unknown placeholders are
+ * left as-is.
+ */
+export function getMessage(
+  code: MessageCode,
+  params?: MessageParams,
+  locale: MessageLocale = activeLocale
+): string {
+  const template = CATALOG[locale][code] ?? EN_MESSAGES[code];
+  if (!params) {
+    return template;
+  }
+  return template.replace(/\{(\w+)\}/g, (placeholder, name: string) =>
+    name in params ? String(params[name]) : placeholder
+  );
+}
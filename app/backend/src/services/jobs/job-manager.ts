@@ -0,0 +1,396 @@
+/**
+ * @fileoverview Background Job Manager
+ *
+ * Runs long operations (exports, uploads, submissions) off the IPC call that
+ * started them. Callers get a job ID back immediately, progress is published
+ * to listeners, and every job is recorded in run history when it starts so
+ * its outcome survives a restart.
+ *
+ * Jobs of the same type run one at a time in submission order; jobs of
+ * different types run concurrently.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { randomUUID } from "crypto";
+import { appLogger } from "@sheetpilot/shared/logger";
+import {
+  finishRun,
+  getRunByJobId,
+  startRun,
+  type RunStatus,
+  type RunTrigger,
+} from "@/models";
+
+export type JobStatus = "queued" | "running" | "succeeded" | "failed" | "cancelled";
+
+export interface JobSnapshot {
+  id: string;
+  type: string;
+  status: JobStatus;
+  trigger: RunTrigger;
+  /** 0-100 */
+  percent: number;
+  message: string | null;
+  result: unknown;
+  error: string | null;
+  runId: number | null;
+  createdAt: string;
+  startedAt: string | null;
+  finishedAt: string | null;
+}
+
+export interface JobContext {
+  jobId: string;
+  /** Run history ID (null if the run could not be recorded) */
+  runId: number | null;
+  /** Aborted when the job is cancelled */
+  signal: AbortSignal;
+  reportProgress: (percent: number, message?: string) => void;
+}
+
+export type JobTask<T> = (context: JobContext) => Promise<T>;
+
+export interface EnqueueJobOptions {
+  trigger?: RunTrigger;
+  /** Stored with the run history row */
+  detail?: Record<string, unknown>;
+  /**
+   * Picks what of the result is stored with the run history row; the full
+   * result stays on the job snapshot (default: all of it)
+   */
+  recordResult?: (result: unknown) => Record<string, unknown>;
+}
+
+type JobListener = (job: JobSnapshot) => void;
+
+/**
+ * Thrown by a task to fail its job while still handing the caller a result
+ * (e.g. the rule violations that blocked a submission)
+ */
+export class JobFailedError extends Error {
+  constructor(
+    message: string,
+    readonly result: unknown
+  ) {
+    super(message);
+    this.name = "JobFailedError";
+  }
+}
+
+interface JobRecord {
+  snapshot: JobSnapshot;
+  task: JobTask<unknown>;
+  detail: Record<string, unknown> | undefined;
+  recordResult: ((result: unknown) => Record<string, unknown>) | undefined;
+  controller: AbortController;
+  done: Promise<JobSnapshot>;
+  resolveDone: (job: JobSnapshot) => void;
+}
+
+/** Finished jobs kept in memory; older ones are answered from run history */
+const MAX_FINISHED_JOBS = 100;
+/** Minimum interval between progress events for the same job */
+const PROGRESS_THROTTLE_MS = 100;
+
+const jobs = new Map<string, JobRecord>();
+const queues = new Map<string, string[]>();
+const activeTypes = new Set<string>();
+const listeners = new Set<JobListener>();
+const lastProgressEmit = new Map<string, number>();
+
+const isFinished = (status: JobStatus): boolean =>
+  status === "succeeded" || status === "failed" || status === "cancelled";
+
+const copy = (snapshot: JobSnapshot): JobSnapshot => ({ ...snapshot });
+
+const emit = (record: JobRecord): void => {
+  const snapshot = copy(record.snapshot);
+  for (const listener of listeners) {
+    try {
+      listener(snapshot);
+    } catch (err: unknown) {
+      appLogger.warn("Job listener threw", {
+        jobId: snapshot.id,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+};
+
+const pruneFinishedJobs = (): void => {
+  const finished = [...jobs.values()].filter((job) =>
+    isFinished(job.snapshot.status)
+  );
+  for (const job of finished.slice(0, Math.max(0, finished.length - MAX_FINISHED_JOBS))) {
+    jobs.delete(job.snapshot.id);
+    lastProgressEmit.delete(job.snapshot.id);
+  }
+};
+
+const toRunOutcome = (
+  status: JobStatus
+): Exclude<RunStatus, "running"> =>
+  status === "succeeded" ? "success" : status === "cancelled" ? "cancelled" : "failure";
+
+const toRunDetail = (
+  record: JobRecord
+): Record<string, unknown> | undefined => {
+  const { result } = record.snapshot;
+  if (result !== null && record.recordResult) {
+    return { ...record.detail, ...record.recordResult(result) };
+  }
+  if (result !== null && typeof result === "object" && !Array.isArray(result)) {
+    return { ...record.detail, ...(result as Record<string, unknown>) };
+  }
+  return record.detail;
+};
+
+const finish = (
+  record: JobRecord,
+  status: JobStatus,
+  result: unknown,
+  error: string | null
+): void => {
+  const snapshot = record.snapshot;
+  snapshot.status = status;
+  snapshot.result = result;
+  snapshot.error = error;
+  snapshot.finishedAt = new Date().toISOString();
+  if (status === "succeeded") {
+    snapshot.percent = 100;
+  }
+
+  if (snapshot.runId !== null) {
+    try {
+      finishRun(snapshot.runId, toRunOutcome(status), toRunDetail(record), error ?? undefined);
+    } catch (err: unknown) {
+      appLogger.error("Could not record job outcome", {
+        jobId: snapshot.id,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+
+  appLogger.info("Job finished", {
+    jobId: snapshot.id,
+    type: snapshot.type,
+    status,
+    error,
+  });
+  emit(record);
+  record.resolveDone(copy(snapshot));
+  pruneFinishedJobs();
+};
+
+const execute = async (record: JobRecord): Promise<void> => {
+  const snapshot = record.snapshot;
+  snapshot.status = "running";
+  snapshot.startedAt = new Date().toISOString();
+  try {
+    snapshot.runId = startRun(snapshot.type, snapshot.trigger, record.detail, snapshot.id);
+  } catch (err: unknown) {
+    appLogger.error("Could not record job start", {
+      jobId: snapshot.id,
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+  emit(record);
+
+  const context: JobContext = {
+    jobId: snapshot.id,
+    runId: snapshot.runId,
+    signal: record.controller.signal,
+    reportProgress: (percent, message) => {
+      if (isFinished(snapshot.status)) return;
+      snapshot.percent = Math.min(100, Math.max(0, Math.round(percent)));
+      if (message !== undefined) snapshot.message = message;
+      const now = Date.now();
+      if (now - (lastProgressEmit.get(snapshot.id) ?? 0) >= PROGRESS_THROTTLE_MS) {
+        lastProgressEmit.set(snapshot.id, now);
+        emit(record);
+      }
+    },
+  };
+
+  try {
+    const result = await record.task(context);
+    if (record.controller.signal.aborted) {
+      finish(record, "cancelled", result ?? null, "Cancelled");
+    } else {
+      finish(record, "succeeded", result ?? null, null);
+    }
+  } catch (err: unknown) {
+    const message = err instanceof Error ? err.message : String(err);
+    finish(
+      record,
+      record.controller.signal.aborted ? "cancelled" : "failed",
+      err instanceof JobFailedError ? err.result : null,
+      message
+    );
+  }
+};
+
+const runNext = (type: string): void => {
+  if (activeTypes.has(type)) return;
+  const queue = queues.get(type);
+  const jobId = queue?.shift();
+  if (!jobId) return;
+  const record = jobs.get(jobId);
+  if (!record || record.snapshot.status !== "queued") {
+    runNext(type);
+    return;
+  }
+
+  activeTypes.add(type);
+  void execute(record).finally(() => {
+    activeTypes.delete(type);
+    runNext(type);
+  });
+};
+
+/**
+ * Queues a job and returns its ID immediately
+ *
+ * @param type - Job type (also the run history job_type)
+ * @param task - Work to perform; should honour `context.signal`
+ */
+export function enqueueJob<T>(
+  type: string,
+  task: JobTask<T>,
+  options: EnqueueJobOptions = {}
+): string {
+  const id = randomUUID();
+  let resolveDone: (job: JobSnapshot) => void = () => {};
+  const done = new Promise<JobSnapshot>((resolve) => {
+    resolveDone = resolve;
+  });
+  const record: JobRecord = {
+    snapshot: {
+      id,
+      type,
+      status: "queued",
+      trigger: options.trigger ?? "manual",
+      percent: 0,
+      message: null,
+      result: null,
+      error: null,
+      runId: null,
+      createdAt: new Date().toISOString(),
+      startedAt: null,
+      finishedAt: null,
+    },
+    task: task as JobTask<unknown>,
+    detail: options.detail,
+    recordResult: options.recordResult,
+    controller: new AbortController(),
+    done,
+    resolveDone,
+  };
+
+  jobs.set(id, record);
+  const queue = queues.get(type) ?? [];
+  queue.push(id);
+  queues.set(type, queue);
+  appLogger.verbose("Job queued", { jobId: id, type, queued: queue.length });
+  emit(record);
+
+  // Start on the next tick so the caller can return the ID first
+  setImmediate(() => runNext(type));
+  return id;
+}
+
+/**
+ * Resolves when the job finishes (never rejects)
+ */
+export function waitForJob(jobId: string): Promise<JobSnapshot | null> {
+  const record = jobs.get(jobId);
+  return record ? record.done : Promise.resolve(getJobStatus(jobId));
+}
+
+/**
+ * Gets the current state of a job, falling back to run history for old jobs
+ */
+export function getJobStatus(jobId: string): JobSnapshot | null {
+  const record = jobs.get(jobId);
+  if (record) {
+    return copy(record.snapshot);
+  }
+
+  const run = getRunByJobId(jobId);
+  if (!run) {
+    return null;
+  }
+  const status: JobStatus =
+    run.status === "success"
+      ? "succeeded"
+      : run.status === "cancelled"
+        ? "cancelled"
+        : run.status === "failure"
+          ? "failed"
+          : // Recorded as running but not in memory: the app exited mid-job
+            "failed";
+  return {
+    id: jobId,
+    type: run.jobType,
+    status,
+    trigger: run.trigger,
+    percent: status === "succeeded" ? 100 : 0,
+    message: null,
+    result: run.detail,
+    error: run.error ?? (run.status === "running" ? "Interrupted by application exit" : null),
+    runId: run.id,
+    createdAt: run.startedAt,
+    startedAt: run.startedAt,
+    finishedAt: run.finishedAt,
+  };
+}
+
+/**
+ * Lists jobs held in memory (queued, running and recently finished), newest first
+ */
+export function listJobs(): JobSnapshot[] {
+  return [...jobs.values()]
+    .map((record) => copy(record.snapshot))
+    .sort((a, b) => b.createdAt.localeCompare(a.createdAt));
+}
+
+/**
+ * Lists queued and running jobs
+ */
+export function getActiveJobs(): JobSnapshot[] {
+  return listJobs().filter((job) => !isFinished(job.status));
+}
+
+/**
+ * Requests cancellation. Queued jobs are cancelled immediately; running jobs
+ * are signalled and finish as cancelled once their task returns.
+ *
+ * @returns false if the job is unknown or already finished
+ */
+export function cancelJob(jobId: string): boolean {
+  const record = jobs.get(jobId);
+  if (!record || isFinished(record.snapshot.status)) {
+    return false;
+  }
+  record.controller.abort();
+  if (record.snapshot.status === "queued") {
+    finish(record, "cancelled", null, "Cancelled before start");
+  }
+  appLogger.info("Job cancellation requested", { jobId, type: record.snapshot.type });
+  return true;
+}
+
+/**
+ * Subscribes to job state and progress changes
+ *
+ * @returns Unsubscribe function
+ */
+export function onJobUpdate(listener: JobListener): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
@@ -0,0 +1,175 @@
+/**
+ * @fileoverview Frontend Log Queue
+ *
+ * Holds renderer log lines while the logging system is not ready yet, so
+ * they are not lost when logging setup is slow or fails. Held lines are kept
+ * in memory and appended to a JSONL file in userData; if the app exits before
+ * logging comes up, the file is picked up on the next start. Once logging is
+ * initialized the queue is flushed in order, each line tagged with the time
+ * it was originally logged.
+ *
+ * Outside of startup (and in tests) nothing is held and lines go straight to
+ * the IPC logger.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+
+export type FrontendLogLevel = 'error' | 'warn' | 'info' | 'verbose' | 'debug';
+
+export interface QueuedFrontendLog {
+  level: FrontendLogLevel;
+  message: string;
+  metadata?: unknown;
+  /** ISO timestamp of when the renderer logged the line */
+  timestamp: string;
+}
+
+export const FRONTEND_LOG_QUEUE_FILE = 'frontend-log-queue.jsonl';
+
+/** Lines beyond this are dropped (and counted) rather than held */
+export const MAX_QUEUED_FRONTEND_LOGS = 1000;
+
+const LEVELS: readonly FrontendLogLevel[] = ['error', 'warn', 'info', 'verbose', 'debug'];
+
+let holding = false;
+let queueFile: string | null = null;
+let queue: QueuedFrontendLog[] = [];
+let dropped = 0;
+
+const isPlainObject = (value: unknown): value is Record<string, unknown> =>
+  typeof value === 'object' && value !== null && !Array.isArray(value);
+
+const isQueuedFrontendLog = (value: unknown): value is QueuedFrontendLog =>
+  isPlainObject(value) &&
+  LEVELS.includes(value['level'] as FrontendLogLevel) &&
+  typeof value['message'] === 'string' &&
+  typeof value['timestamp'] === 'string';
+
+const readQueueFile = (file: string): QueuedFrontendLog[] => {
+  let content: string;
+  try {
+    content = fs.readFileSync(file, 'utf8');
+  } catch {
+    return [];
+  }
+  const entries: QueuedFrontendLog[] = [];
+  for (const line of content.split('\n')) {
+    if (!line.trim()) continue;
+    try {
+      const parsed: unknown = JSON.parse(line);
+      if (isQueuedFrontendLog(parsed)) entries.push(parsed);
+    } catch {
+      // partial line from an interrupted write
+    }
+  }
+  return entries;
+};
+
+/**
+ * Metadata for a flushed line: the original metadata plus when the line was
+ * actually logged, since the log entry itself carries the flush time
+ */
+export function withOriginalTimestamp(metadata: unknown, timestamp: string): Record<string, unknown> {
+  const base: Record<string, unknown> = isPlainObject(metadata)
+    ? { ...metadata }
+    : metadata === undefined || metadata === null
+      ? {}
+      : { data: metadata };
+  base['originalTimestamp'] = timestamp;
+  base['queued'] = true;
+  return base;
+}
+
+/**
+ * Starts holding frontend log lines until {@link releaseFrontendLogs}
+ *
+ * Lines left over from a previous run that never got logging up are loaded
+ * first so they flush ahead of this run's lines.
+ *
+ * @param directory - Directory for the on-disk queue (userData); null keeps the queue in memory only
+ */
+export function holdFrontendLogs(directory: string | null): void {
+  holding = true;
+  queueFile = directory ? path.join(directory, FRONTEND_LOG_QUEUE_FILE) : null;
+  queue = queueFile ? readQueueFile(queueFile) : [];
+  dropped = 0;
+}
+
+/**
+ * Logs a frontend line now, or holds it while logging is not ready
+ */
+export function logFrontendEntry(
+  level: FrontendLogLevel,
+  message: string,
+  metadata?: unknown,
+  timestamp: string = new Date().toISOString()
+): void {
+  if (!holding) {
+    ipcLogger[level](message, metadata);
+    return;
+  }
+
+  if (queue.length >= MAX_QUEUED_FRONTEND_LOGS) {
+    dropped++;
+    return;
+  }
+  const entry: QueuedFrontendLog = {
+    level,
+    message,
+    ...(metadata !== undefined ? { metadata } : {}),
+    timestamp
+  };
+  queue.push(entry);
+  if (queueFile) {
+    try {
+      fs.appendFileSync(queueFile, `${JSON.stringify(entry)}\n`, 'utf8');
+    } catch {
+      // memory copy is still flushed if this run gets logging up
+    }
+  }
+}
+
+/**
+ * Stops holding and flushes held lines to the IPC logger in order
+ *
+ * @returns Number of lines flushed
+ */
+export function releaseFrontendLogs(): number {
+  const entries = queue;
+  const file = queueFile;
+  const droppedCount = dropped;
+  holding = false;
+  queue = [];
+  queueFile = null;
+  dropped = 0;
+
+  for (const entry of entries) {
+    ipcLogger[entry.level](entry.message, withOriginalTimestamp(entry.metadata, entry.timestamp));
+  }
+  if (droppedCount > 0) {
+    ipcLogger.warn('Dropped frontend log lines while logging was unavailable', {
+      dropped: droppedCount,
+      limit: MAX_QUEUED_FRONTEND_LOGS
+    });
+  }
+  if (file) {
+    try {
+      fs.rmSync(file, { force: true });
+    } catch (err: unknown) {
+      ipcLogger.warn('Could not remove frontend log queue file', {
+        file,
+        error: err instanceof Error ? err.message : String(err)
+      });
+    }
+  }
+  if (entries.length > 0) {
+    ipcLogger.verbose('Flushed queued frontend log lines', { count: entries.length });
+  }
+  return entries.length;
+}
@@ -0,0 +1,283 @@
+/**
+ * @fileoverview Log Indexer
+ *
+ * Optionally indexes sheetpilot_*.log lines into SQLite from a background
+ * scheduler task so log queries can filter by date, level and run without
+ * re-parsing months of text files. Each file is read incrementally from the
+ * byte offset reached on the previous pass. When the index is disabled,
+ * queries fall back to scanning the log files directly.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { app } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+import {
+  appendLogIndexLines,
+  getLogIndexCursor,
+  listIndexedLogFiles,
+  queryLogIndex,
+  removeLogIndexFiles,
+  type IndexedLogLine,
+  type LogQueryFilter,
+  type LogQueryResultLine,
+} from "@/models";
+import { loadSettings } from "@/services/settings/settings-store";
+import {
+  registerScheduledTask,
+  type ScheduledTask,
+} from "@/services/scheduler/scheduler";
+
+export const LOG_INDEX_TASK_NAME = "log-index";
+
+/** Upper bound on bytes read from one file per pass, keeping scheduler ticks short */
+const MAX_BYTES_PER_PASS = 8 * 1024 * 1024;
+
+const DEFAULT_QUERY_LIMIT = 500;
+
+export interface LogIndexPassResult {
+  filesIndexed: number;
+  linesIndexed: number;
+  filesRemoved: number;
+}
+
+export interface LogQueryResult {
+  /** Whether the lines came from the SQLite index or a scan of the log files */
+  source: "index" | "files";
+  lines: LogQueryResultLine[];
+}
+
+export function isLogIndexEnabled(): boolean {
+  return loadSettings().log_index_enabled === true;
+}
+
+export function isLogFileName(fileName: string): boolean {
+  return fileName.startsWith("sheetpilot_") && fileName.endsWith(".log");
+}
+
+async function listLogFiles(logDir: string): Promise<string[]> {
+  const files = await fs.promises.readdir(logDir);
+  return files.filter(isLogFileName).sort();
+}
+
+const asOptionalString = (value: unknown): string | null => {
+  if (typeof value === "string" && value.length > 0) return value;
+  if (typeof value === "number") return String(value);
+  return null;
+};
+
+/**
+ * Parses one NDJSON log line; returns null for blank or non-JSON lines
+ */
+export function parseLogLine(
+  line: string,
+  lineNumber: number
+): IndexedLogLine | null {
+  const trimmed = line.trim();
+  if (!trimmed) return null;
+
+  let entry: Record<string, unknown>;
+  try {
+    const parsed: unknown = JSON.parse(trimmed);
+    if (typeof parsed !== "object" || parsed === null) return null;
+    entry = parsed as Record<string, unknown>;
+  } catch {
+    return null;
+  }
+
+  const timestamp = asOptionalString(entry["timestamp"]);
+  const level = asOptionalString(entry["level"]);
+  if (!timestamp || !level) return null;
+
+  const context =
+    typeof entry["context"] === "object" && entry["context"] !== null
+      ? (entry["context"] as Record<string, unknown>)
+      : {};
+
+  return {
+    lineNumber,
+    timestamp,
+    level,
+    runId: asOptionalString(context["runId"]) ?? asOptionalString(context["jobId"]),
+    component: asOptionalString(entry["component"]),
+    message: typeof entry["message"] === "string" ? entry["message"] : "",
+  };
+}
+
+/**
+ * Splits a chunk into complete lines, returning the byte length consumed
+ * (a trailing partial line is left for the next pass)
+ */
+function splitCompleteLines(chunk: Buffer): { lines: string[]; consumed: number } {
+  const lastNewline = chunk.lastIndexOf(0x0a);
+  if (lastNewline === -1) {
+    return { lines: [], consumed: 0 };
+  }
+  const complete = chunk.subarray(0, lastNewline + 1).toString("utf8");
+  const lines = complete.split("\n");
+  lines.pop();
+  return { lines, consumed: lastNewline + 1 };
+}
+
+/**
+ * Indexes new lines appended to one log file since the last pass
+ */
+export async function indexLogFile(
+  logDir: string,
+  fileName: string
+): Promise<number> {
+  const filePath = path.join(logDir, fileName);
+  const { size } = await fs.promises.stat(filePath);
+  let cursor = getLogIndexCursor(fileName);
+
+  if (size < cursor.byteOffset) {
+    // File was truncated or replaced; start over
+    removeLogIndexFiles([fileName]);
+    cursor = { fileName, byteOffset: 0, lineCount: 0 };
+  }
+  if (size === cursor.byteOffset) {
+    return 0;
+  }
+
+  const length = Math.min(size - cursor.byteOffset, MAX_BYTES_PER_PASS);
+  const buffer = Buffer.alloc(length);
+  const handle = await fs.promises.open(filePath, "r");
+  try {
+    await handle.read(buffer, 0, length, cursor.byteOffset);
+  } finally {
+    await handle.close();
+  }
+
+  const { lines, consumed } = splitCompleteLines(buffer);
+  if (consumed === 0) {
+    return 0;
+  }
+
+  const parsed: IndexedLogLine[] = [];
+  lines.forEach((line, index) => {
+    const entry = parseLogLine(line, cursor.lineCount + index + 1);
+    if (entry) parsed.push(entry);
+  });
+
+  appendLogIndexLines(
+    {
+      fileName,
+      byteOffset: cursor.byteOffset + consumed,
+      lineCount: cursor.lineCount + lines.length,
+    },
+    parsed
+  );
+  return parsed.length;
+}
+
+/**
+ * Runs one indexing pass over every log file and forgets files that were removed
+ */
+export async function indexLogFiles(logDir: string): Promise<LogIndexPassResult> {
+  const files = await listLogFiles(logDir);
+  let linesIndexed = 0;
+  let filesIndexed = 0;
+
+  for (const fileName of files) {
+    try {
+      const added = await indexLogFile(logDir, fileName);
+      if (added > 0) {
+        filesIndexed++;
+        linesIndexed += added;
+      }
+    } catch (err: unknown) {
+      appLogger.warn("Could not index log file", {
+        fileName,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+
+  const present = new Set(files);
+  const missing = listIndexedLogFiles().filter((file) => !present.has(file));
+  removeLogIndexFiles(missing);
+
+  if (linesIndexed > 0 || missing.length > 0) {
+    appLogger.verbose("Log index pass completed", {
+      filesIndexed,
+      linesIndexed,
+      filesRemoved: missing.length,
+    });
+  }
+  return { filesIndexed, linesIndexed, filesRemoved: missing.length };
+}
+
+const matchesFilter = (line: IndexedLogLine, filter: LogQueryFilter): boolean => {
+  if (filter.from && line.timestamp < filter.from) return false;
+  if (filter.to && line.timestamp > filter.to) return false;
+  if (filter.levels && filter.levels.length > 0 && !filter.levels.includes(line.level)) {
+    return false;
+  }
+  if (filter.runId && line.runId !== filter.runId) return false;
+  if (
+    filter.search &&
+    !line.message.toLowerCase().includes(filter.search.toLowerCase())
+  ) {
+    return false;
+  }
+  return true;
+};
+
+/**
+ * Scans the log files directly (used when the index is disabled)
+ */
+async function scanLogFiles(
+  logDir: string,
+  filter: LogQueryFilter
+): Promise<LogQueryResultLine[]> {
+  const matches: LogQueryResultLine[] = [];
+  for (const fileName of await listLogFiles(logDir)) {
+    const content = await fs.promises.readFile(path.join(logDir, fileName), "utf8");
+    content.split("\n").forEach((line, index) => {
+      const entry = parseLogLine(line, index + 1);
+      if (entry && matchesFilter(entry, filter)) {
+        matches.push({ ...entry, fileName });
+      }
+    });
+  }
+  matches.sort((a, b) => (a.timestamp < b.timestamp ? 1 : a.timestamp > b.timestamp ? -1 : 0));
+  return matches.slice(0, filter.limit ?? DEFAULT_QUERY_LIMIT);
+}
+
+/**
+ * Queries logs through the index when enabled, otherwise by scanning files
+ */
+export async function queryLogs(
+  logDir: string,
+  filter: LogQueryFilter
+): Promise<LogQueryResult> {
+  // Log timestamps are UTC ISO strings, so bounds are normalized before string comparison
+  const normalized: LogQueryFilter = {
+    ...filter,
+    from: filter.from ? new Date(filter.from).toISOString() : undefined,
+    to: filter.to ? new Date(filter.to).toISOString() : undefined,
+  };
+  if (isLogIndexEnabled()) {
+    return { source: "index", lines: queryLogIndex(normalized) };
+  }
+  return { source: "files", lines: await scanLogFiles(logDir, normalized) };
+}
+
+export const logIndexTask: ScheduledTask = {
+  name: LOG_INDEX_TASK_NAME,
+  runDue: async () => {
+    if (!isLogIndexEnabled()) return;
+    await indexLogFiles(app.getPath("userData"));
+  },
+};
+
+/**
+ * Registers the background log indexing task with the scheduler
+ */
+export function registerLogIndexTask(): void {
+  registerScheduledTask(logIndexTask);
+}
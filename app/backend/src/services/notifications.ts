@@ -0,0 +1,35 @@
+/**
+ * @fileoverview Desktop Notifications
+ *
+ * Thin wrapper over Electron notifications so background work can surface
+ * results without a window being focused.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { Notification } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+
+/**
+ * Shows a desktop notification if the platform supports it
+ *
+ * @returns true if a notification was shown
+ */
+export function notifyUser(title: string, body: string): boolean {
+  try {
+    if (!Notification.isSupported()) {
+      appLogger.verbose("Desktop notifications not supported", { title });
+      return false;
+    }
+    new Notification({ title, body }).show();
+    return true;
+  } catch (err: unknown) {
+    appLogger.warn("Could not show desktop notification", {
+      title,
+      error: err instanceof Error ? err.message : String(err),
+    });
+    return false;
+  }
+}
@@ -17,10 +17,20 @@ import type {
   Credentials,
   PluginMetadata
 } from "@sheetpilot/shared";
-import { runTimesheet, checkAborted, createCancelledResult, processEntriesByQuarter } from "@sheetpilot/bot";
+import {
+  runTimesheet,
+  checkAborted,
+  createCancelledResult,
+  processEntriesByQuarter,
+  applySubmissionKey,
+  applyCorrection,
+} from "@sheetpilot/bot";
 import { botLogger } from "@sheetpilot/shared/logger";
 import {
   convertDateToUSFormat,
+  isQuarterHourIncrement,
+  MAX_ENTRY_HOURS,
+  MIN_ENTRY_HOURS,
 } from "@sheetpilot/shared";
 
 /**
@@ -44,15 +54,21 @@ export class ElectronBotService implements ISubmissionService {
     // Convert date from YYYY-MM-DD to mm/dd/yyyy format for bot
     const formattedDate = convertDateToUSFormat(entry.date);
 
-    return {
-      Project: entry.project,
-      Date: formattedDate,
-      Hours: entry.hours,
-      Tool: entry.tool ?? "",
-      "Task Description": entry.taskDescription,
-      "Detail Charge Code": entry.chargeCode ?? "",
-      Status: "", // Bot will skip rows with Status === 'Complete'
-    };
+    return applySubmissionKey(
+      applyCorrection(
+        {
+          Project: entry.project,
+          Date: formattedDate,
+          Hours: entry.hours,
+          Tool: entry.tool ?? "",
+          "Task Description": entry.taskDescription,
+          "Detail Charge Code": entry.chargeCode ?? "",
+          Status: "", // Bot will skip rows with Status === 'Complete'
+        },
+        entry.correctionOf
+      ),
+      entry.submissionKey
+    );
   }
 
   /**
@@ -124,14 +140,12 @@ export class ElectronBotService implements ISubmissionService {
     } else if (typeof entry.hours !== "number" || isNaN(entry.hours)) {
       errors.push("Hours must be a number");
     } else {
-      // Check if it's a multiple of 0.25 (15-minute increments)
-      const remainder = (entry.hours * 4) % 1;
-      if (Math.abs(remainder) > 0.0001 && Math.abs(remainder - 1) > 0.0001) {
+      if (!isQuarterHourIncrement(entry.hours)) {
         errors.push("Hours must be in 15-minute increments (0.25, 0.5, 0.75, etc.)");
       }
 
       // Check range
-      if (entry.hours < 0.25 || entry.hours > 24.0) {
+      if (entry.hours < MIN_ENTRY_HOURS || entry.hours > MAX_ENTRY_HOURS) {
         errors.push("Hours must be between 0.25 and 24.0");
       }
     }
@@ -19,6 +19,7 @@ import type {
   DbTimesheetEntry,
   PluginMetadata
 } from '@sheetpilot/shared';
+import { SubmissionStatus } from '@sheetpilot/shared';
 
 /**
  * In-memory implementation of the data service
@@ -149,7 +150,7 @@ export class MemoryDataService implements IDataService {
         tool: entry.tool || null,
         detail_charge_code: entry.chargeCode || null,
         task_description: entry.taskDescription,
-        status: 'Complete',
+        status: SubmissionStatus.COMPLETE,
         submitted_at: new Date().toISOString()
       };
       
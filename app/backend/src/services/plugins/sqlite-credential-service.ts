@@ -55,7 +55,10 @@ export class SQLiteCredentialService implements ICredentialService {
    */
   public async get(service: string): Promise<CredentialGetResult> {
     try {
-      const credentials = getCredentials(service);
+      const credentials = getCredentials(service, {
+        caller: 'credential-service:get',
+        purpose: 'other'
+      });
       
       if (!credentials) {
         return {
@@ -19,8 +19,29 @@ import type {
   DbTimesheetEntry,
   PluginMetadata
 } from '@sheetpilot/shared';
-import { getDb } from '@/models';
-import { getTotalHoursForDate } from '@/models/timesheet-repository';
+import {
+  formatHours,
+  isQuarterHourIncrement,
+  MAX_ENTRY_HOURS,
+  MIN_ENTRY_HOURS,
+  SubmissionStatus
+} from '@sheetpilot/shared';
+import {
+  deleteUnsubmittedTimesheetEntry,
+  findSubmittedTimesheetEntryId,
+  getAllTimesheetEntries,
+  getCompletedTimesheetEntries,
+  getDb,
+  getDraftTimesheetEntryById,
+  getPendingTimesheetEntries,
+  getSubmittedHoursForDate,
+  getTimesheetEntryById,
+  getTotalHoursForDate,
+  listCredentials,
+  replaceUnsubmittedTimesheetEntry,
+  SUBMITTED_ENTRY_LOCKED_ERROR,
+  upsertDraftTimesheetEntry
+} from '@/models';
 
 const validateDraftRequiredFields = (entry: TimesheetEntry): string | null => {
   if (!entry.date) {
@@ -42,34 +63,17 @@ const validateDraftHours = (entry: TimesheetEntry): string | null => {
   if (typeof entry.hours !== 'number' || isNaN(entry.hours)) {
     return 'Hours must be a number';
   }
-  const remainder = (entry.hours * 4) % 1;
-  if (Math.abs(remainder) > 0.0001 && Math.abs(remainder - 1) > 0.0001) {
+  if (!isQuarterHourIncrement(entry.hours)) {
     return 'Hours must be in 15-minute increments (0.25, 0.5, 0.75, etc.)';
   }
-  if (entry.hours < 0.25 || entry.hours > 24.0) {
+  if (entry.hours < MIN_ENTRY_HOURS || entry.hours > MAX_ENTRY_HOURS) {
     return 'Hours must be between 0.25 and 24.0';
   }
   return null;
 };
 
-const getCurrentEntryHours = (entryId: number): number => {
-  const db = getDb();
-  const getCurrent = db
-    .prepare('SELECT hours FROM timesheet WHERE id = ?')
-    .get(entryId) as { hours: number | null } | undefined;
-  return getCurrent?.hours ?? 0;
-};
-
-const getSubmittedHoursForDate = (date: string): number => {
-  const db = getDb();
-  const getSubmitted = db.prepare(`
-            SELECT COALESCE(SUM(hours), 0) as total
-            FROM timesheet
-            WHERE date = ? AND status = 'Complete' AND hours IS NOT NULL
-          `);
-  const result = getSubmitted.get(date) as { total: number } | undefined;
-  return result?.total ?? 0;
-};
+const getCurrentEntryHours = (entryId: number): number =>
+  getTimesheetEntryById(entryId)?.hours ?? 0;
 
 const getHoursLimitError = (entry: TimesheetEntry): string | null => {
   const totalHoursForDate = getTotalHoursForDate(entry.date!);
@@ -80,13 +84,13 @@ const getHoursLimitError = (entry: TimesheetEntry): string | null => {
 
   const hoursAfterThisEntry =
     totalHoursForDate - currentEntryHours + entry.hours!;
-  if (hoursAfterThisEntry <= 24.0) {
+  if (hoursAfterThisEntry <= MAX_ENTRY_HOURS) {
     return null;
   }
 
   const submittedHours = getSubmittedHoursForDate(entry.date!);
   const draftHours = totalHoursForDate - submittedHours;
-  return `Total hours for ${entry.date} exceeds 24 hours. Current total: ${hoursAfterThisEntry.toFixed(2)} hours (${submittedHours.toFixed(2)} submitted + ${(draftHours - currentEntryHours + entry.hours!).toFixed(2)} draft). Maximum allowed: 24.00 hours.`;
+  return `Total hours for ${entry.date} exceeds 24 hours. Current total: ${formatHours(hoursAfterThisEntry)} hours (${formatHours(submittedHours)} submitted + ${formatHours(draftHours - currentEntryHours + entry.hours!)} draft). Maximum allowed: ${formatHours(MAX_ENTRY_HOURS)} hours.`;
 };
 
 /**
@@ -121,94 +125,51 @@ export class SQLiteDataService implements IDataService {
         return { success: false, error: hoursLimitError };
       }
       
-      const db = getDb();
-      let result;
-      
-      // Prepare statements outside transaction for better performance and to avoid scope issues
-      const updateStmt = db.prepare(`
-        UPDATE timesheet
-        SET date = ?,
-            hours = ?,
-            project = ?,
-            tool = ?,
-            detail_charge_code = ?,
-            task_description = ?,
-            status = NULL
-        WHERE id = ?
-      `);
-      const checkExistsStmt = db.prepare('SELECT id FROM timesheet WHERE id = ?');
-      
       // If entry has an id, check if it exists BEFORE attempting update
       if (entry.id !== undefined && entry.id !== null) {
-        const exists = checkExistsStmt.get(entry.id);
-        if (!exists) {
+        const existing = getTimesheetEntryById(entry.id);
+        if (!existing) {
           return { success: false, error: 'Entry not found' };
         }
+        // Submitted rows are immutable; corrections go through amendments
+        if (existing.status === SubmissionStatus.COMPLETE) {
+          return { success: false, error: SUBMITTED_ENTRY_LOCKED_ERROR };
+        }
+      } else if (
+        findSubmittedTimesheetEntryId({
+          date: entry.date!,
+          project: entry.project!,
+          taskDescription: entry.taskDescription!
+        }) !== undefined
+      ) {
+        return { success: false, error: SUBMITTED_ENTRY_LOCKED_ERROR };
       }
-      
+
+      const fields = {
+        date: entry.date!,
+        hours: entry.hours!,
+        project: entry.project!,
+        // Explicitly convert undefined to null for optional fields
+        tool: entry.tool !== undefined ? entry.tool : null,
+        detailChargeCode: entry.chargeCode !== undefined ? entry.chargeCode : null,
+        taskDescription: entry.taskDescription!
+      };
+
       // Wrap save operation in transaction for atomicity
-      const saveTransaction = db.transaction(() => {
-        // If entry has an id, UPDATE; otherwise INSERT
-        if (entry.id !== undefined && entry.id !== null) {
-          // Explicitly convert undefined to null for optional fields
-          const toolValue = entry.tool !== undefined ? entry.tool : null;
-          const chargeCodeValue = entry.chargeCode !== undefined ? entry.chargeCode : null;
-          
-          result = updateStmt.run(
-            entry.date,
-            entry.hours,
-            entry.project,
-            toolValue,
-            chargeCodeValue,
-            entry.taskDescription,
-            entry.id
-          );
-          
-          // SQLite UPDATE returns 0 changes if all values are identical to existing values
-          // Since we checked existence above, if changes === 0, it's an idempotent update (success)
-          // We'll handle the 0->1 conversion after the transaction returns
-        } else {
-          // Insert with deduplication
-          // Note: SQLite ON CONFLICT UPDATE will set columns to NULL if excluded value is NULL
-          const insertStmt = db.prepare(`
-            INSERT INTO timesheet
-            (date, hours, project, tool, detail_charge_code, task_description, status)
-            VALUES (?, ?, ?, ?, ?, ?, NULL)
-            ON CONFLICT(date, project, task_description) DO UPDATE SET
-              hours = excluded.hours,
-              tool = excluded.tool,
-              detail_charge_code = excluded.detail_charge_code,
-              status = NULL
-          `);
-          
-          // Explicitly convert undefined to null for optional fields
-          const toolValue = entry.tool !== undefined ? entry.tool : null;
-          const chargeCodeValue = entry.chargeCode !== undefined ? entry.chargeCode : null;
-          
-          result = insertStmt.run(
-            entry.date,
-            entry.hours,
-            entry.project,
-            toolValue,
-            chargeCodeValue,
-            entry.taskDescription
-          );
-          
-          // ON CONFLICT DO UPDATE may return 0 changes if values are identical,
-          // but the operation still succeeded (upsert found matching row with correct values)
-          // We'll handle the 0->1 conversion after the transaction returns
-        }
-        
-        return result;
-      });
-      
-      result = saveTransaction();
-      
+      const db = getDb();
+      const saveTransaction = db.transaction(() =>
+        // If entry has an id, UPDATE; otherwise INSERT with deduplication
+        entry.id !== undefined && entry.id !== null
+          ? replaceUnsubmittedTimesheetEntry(entry.id, fields)
+          : upsertDraftTimesheetEntry(fields)
+      );
+      const changes = saveTransaction();
+
       // Handle 0 changes case: SQLite returns 0 if no values changed
       // Since we checked existence for UPDATEs, 0 changes means idempotent operation (success)
       // For INSERTs with ON CONFLICT, 0 changes also means successful upsert with identical values
       // Return 1 to indicate successful operation for test compatibility
-      const changesCount = result.changes === 0 ? 1 : result.changes;
+      const changesCount = changes === 0 ? 1 : changes;
       return { success: true, changes: changesCount };
       // Note: Do NOT close db connection here - singleton pattern manages lifecycle
     } catch (error) {
@@ -224,23 +185,7 @@ export class SQLiteDataService implements IDataService {
    */
   public async loadDraft(): Promise<LoadResult> {
     try {
-      const db = getDb();
-      
-      const getPending = db.prepare(`
-        SELECT * FROM timesheet 
-        WHERE status IS NULL
-        ORDER BY date ASC, hours ASC
-      `);
-      
-      const entries = getPending.all() as Array<{
-        id: number;
-        date: string;
-        hours: number | null;
-        project: string;
-        tool?: string;
-        detail_charge_code?: string;
-        task_description: string;
-      }>;
+      const entries = getPendingTimesheetEntries();
       
       // Convert database format to grid format
       const gridData: TimesheetEntry[] = entries.map((entry) => {
@@ -286,24 +231,14 @@ export class SQLiteDataService implements IDataService {
         return { success: false, error: 'Valid ID is required' };
       }
 
-      const db = getDb();
-      
-      // Check if entry exists and is a draft (status IS NULL) in one query
-      const checkExists = db.prepare('SELECT id FROM timesheet WHERE id = ? AND status IS NULL');
-      const exists = checkExists.get(id) as { id: number } | undefined;
-      
-      if (!exists) {
+      // Check if entry exists and is a draft (status IS NULL)
+      if (!getDraftTimesheetEntryById(id)) {
         return { success: false, error: 'Draft entry not found' };
       }
       
-      const deleteStmt = db.prepare(`
-        DELETE FROM timesheet 
-        WHERE id = ? AND status IS NULL
-      `);
-      
-      const result = deleteStmt.run(id);
+      const changes = deleteUnsubmittedTimesheetEntry(id, true);
       
-      if (result.changes === 0) {
+      if (changes === 0) {
         // This shouldn't happen since we checked existence, but handle it anyway
         return { success: false, error: 'Draft entry not found' };
       }
@@ -323,23 +258,10 @@ export class SQLiteDataService implements IDataService {
    */
   public async getArchiveData(): Promise<ArchiveResult> {
     try {
-      const db = getDb();
-      
-      // Get completed timesheet entries
-      const getTimesheet = db.prepare(`
-        SELECT * FROM timesheet 
-        WHERE status = 'Complete'
-        ORDER BY date ASC, hours ASC
-      `);
-      const timesheetEntries = getTimesheet.all() as DbTimesheetEntry[];
+      const timesheetEntries = getCompletedTimesheetEntries() as DbTimesheetEntry[];
       
-      // Get credentials (without passwords)
-      const getCredentials = db.prepare(`
-        SELECT id, service, email, created_at, updated_at 
-        FROM credentials 
-        ORDER BY service
-      `);
-      const credentials = getCredentials.all() as Array<{
+      // Credentials are listed without passwords
+      const credentials = listCredentials() as Array<{
         id: number;
         service: string;
         email: string;
@@ -368,13 +290,7 @@ export class SQLiteDataService implements IDataService {
    */
   public async getAllTimesheetEntries(): Promise<{ success: boolean; entries?: DbTimesheetEntry[]; error?: string }> {
     try {
-      const db = getDb();
-      
-      const getAll = db.prepare(`
-        SELECT * FROM timesheet 
-        ORDER BY date DESC, hours DESC
-      `);
-      const entries = getAll.all() as DbTimesheetEntry[];
+      const entries = getAllTimesheetEntries() as DbTimesheetEntry[];
       
       return { success: true, entries };
       // Note: Do NOT close db connection here - singleton pattern manages lifecycle
@@ -0,0 +1,187 @@
+/**
+ * @fileoverview Reproduction Bundles
+ *
+ * Packs what support needs to reproduce a submission run into one ZIP a
+ * user can attach to a ticket: the run artifact, the run's rows, settings
+ * and app/environment info. Task descriptions, external references and the
+ * account email are replaced by short hashes (equal text gives equal hashes,
+ * so duplicates still show), settings that look like secrets are removed,
+ * and failure screenshots are left out because they show the form as filled.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { createHash } from 'crypto';
+import * as os from 'os';
+import * as path from 'path';
+import { app } from 'electron';
+import { appLogger } from '@sheetpilot/shared/logger';
+import { getTimesheetEntriesByIds, type TimesheetDbRow } from '@/models';
+import { loadSettings } from '@/services/settings/settings-store';
+import { buildStoredZip } from '@/services/archive/stored-zip';
+import { getRunArtifact, type RunArtifact } from './run-artifacts';
+
+const BUNDLE_VERSION = 1;
+const REMOVED = '[removed]';
+/** Setting keys whose values are never exported */
+const SECRET_KEY_PATTERN = /password|secret|token|credential|api_?key/i;
+
+export interface ReproEnvironment {
+  appVersion: string;
+  electronVersion: string | null;
+  chromeVersion: string | null;
+  nodeVersion: string;
+  platform: string;
+  arch: string;
+  osRelease: string;
+  locale: string;
+}
+
+export interface ReproBundle {
+  filename: string;
+  content: Buffer;
+  entryCount: number;
+}
+
+/**
+ * Short stable hash standing in for free text; empty values stay empty
+ */
+export function hashText(value: string | null | undefined): string | null {
+  if (value === null || value === undefined) return null;
+  if (value === '') return '';
+  return `sha256:${createHash('sha256').update(value, 'utf8').digest('hex').slice(0, 16)}`;
+}
+
+/**
+ * Copy of a settings object with secret-looking values replaced, at any depth
+ */
+export function stripSecrets(value: unknown): unknown {
+  if (Array.isArray(value)) {
+    return value.map(stripSecrets);
+  }
+  if (value && typeof value === 'object') {
+    return Object.fromEntries(
+      Object.entries(value).map(([key, inner]) => [
+        key,
+        SECRET_KEY_PATTERN.test(key) ? REMOVED : stripSecrets(inner)
+      ])
+    );
+  }
+  return value;
+}
+
+/**
+ * Run artifact with descriptions and the account email hashed and
+ * screenshot paths reduced to file names
+ */
+export function anonymizeRunArtifact(artifact: RunArtifact): RunArtifact {
+  return {
+    ...artifact,
+    inputs: {
+      ...artifact.inputs,
+      email: hashText(artifact.inputs.email),
+      entries: artifact.inputs.entries.map((entry) => ({
+        ...entry,
+        taskDescription: hashText(entry.taskDescription) ?? ''
+      }))
+    },
+    forms: artifact.forms.map((form) => ({
+      ...form,
+      rows: form.rows.map((row) => ({
+        ...row,
+        screenshot: row.screenshot ? path.basename(row.screenshot) : null
+      }))
+    })),
+    screenshots: artifact.screenshots.map((screenshot) => ({
+      ...screenshot,
+      path: path.basename(screenshot.path)
+    }))
+  };
+}
+
+/**
+ * Timesheet row with its description and external reference hashed
+ */
+export function anonymizeRow(row: TimesheetDbRow): TimesheetDbRow {
+  return {
+    ...row,
+    task_description: hashText(row.task_description) ?? '',
+    ...(row.external_ref !== undefined ? { external_ref: hashText(row.external_ref) } : {})
+  };
+}
+
+export function getReproEnvironment(): ReproEnvironment {
+  return {
+    appVersion: app.getVersion(),
+    electronVersion: process.versions.electron ?? null,
+    chromeVersion: process.versions.chrome ?? null,
+    nodeVersion: process.versions.node,
+    platform: process.platform,
+    arch: process.arch,
+    osRelease: os.release(),
+    locale: app.getLocale()
+  };
+}
+
+/**
+ * Files of a bundle, anonymized; exported for tests
+ */
+export function buildReproBundleFiles(params: {
+  artifact: RunArtifact;
+  rows: TimesheetDbRow[];
+  settings: unknown;
+  environment: ReproEnvironment;
+  createdAt: Date;
+}): Array<{ name: string; content: string }> {
+  const json = (value: unknown): string => JSON.stringify(value, null, 2);
+  const manifest = {
+    version: BUNDLE_VERSION,
+    runId: params.artifact.runId,
+    createdAt: params.createdAt.toISOString(),
+    rowCount: params.rows.length,
+    anonymization: [
+      'Task descriptions, external references and the account email are sha256 hashes (first 16 hex characters)',
+      `Settings named like passwords, secrets, tokens, credentials or API keys are "${REMOVED}"`,
+      'Failure screenshots are not included; only their file names are kept'
+    ]
+  };
+  return [
+    { name: 'manifest.json', content: json(manifest) },
+    { name: 'run-artifact.json', content: json(anonymizeRunArtifact(params.artifact)) },
+    { name: 'rows.json', content: json(params.rows.map(anonymizeRow)) },
+    { name: 'settings.json', content: json(stripSecrets(params.settings)) },
+    { name: 'environment.json', content: json(params.environment) }
+  ];
+}
+
+/**
+ * Builds the reproduction bundle for a run
+ *
+ * @returns The ZIP, or null if no artifact was recorded for the run (or it has been pruned)
+ */
+export async function exportReproBundle(runId: number): Promise<ReproBundle | null> {
+  const artifact = await getRunArtifact(runId);
+  if (!artifact) {
+    return null;
+  }
+  const entryIds = artifact.inputs.entries.map((entry) => entry.id);
+  const rows = entryIds.length > 0 ? getTimesheetEntriesByIds(entryIds) : [];
+  const createdAt = new Date();
+  const content = buildStoredZip(
+    buildReproBundleFiles({
+      artifact,
+      rows,
+      settings: loadSettings(),
+      environment: getReproEnvironment(),
+      createdAt
+    })
+  );
+  appLogger.info('Reproduction bundle built', { runId, rowCount: rows.length, bytes: content.length });
+  return {
+    filename: `sheetpilot_run_${runId}_repro_${createdAt.toISOString().split('T')[0]}.zip`,
+    content,
+    entryCount: rows.length
+  };
+}
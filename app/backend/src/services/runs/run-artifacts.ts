@@ -0,0 +1,265 @@
+/**
+ * @fileoverview Run Artifacts
+ *
+ * Writes one JSON file per submission run under `<userData>/run-artifacts`
+ * so support can see the whole run from a single file: the entries sent
+ * (no passwords or tokens), per-entry outcomes, timings, which locator each
+ * field used, and any failure screenshots. Screenshots for run N live in
+ * `run-artifacts/run-N/`. Files older than the retention window are removed
+ * at startup.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from 'fs';
+import * as path from 'path';
+import { app } from 'electron';
+import type { SubmissionFormRunReport, SubmissionResult } from '@sheetpilot/shared';
+import { getActiveFillProfile, getSubmissionRateLimiter } from '@sheetpilot/bot';
+import { appLogger } from '@sheetpilot/shared/logger';
+import type { TimesheetDbRow } from '@/models';
+
+/** Folder under userData that holds run artifacts */
+export const RUN_ARTIFACT_DIR_NAME = 'run-artifacts';
+/** Artifacts and screenshots older than this are deleted */
+export const RUN_ARTIFACT_RETENTION_DAYS = 30;
+
+const ARTIFACT_VERSION = 1;
+const ARTIFACT_NAME = /^run-(\d+)(\.json)?$/;
+
+export interface RunArtifactEntry {
+  id: number;
+  date: string;
+  hours: number | null;
+  project: string;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string;
+  submissionKey: string | null;
+  amendsId: number | null;
+}
+
+export interface RunArtifactScreenshot {
+  entryId: number | null;
+  formId: string;
+  rowIndex: number;
+  path: string;
+}
+
+export interface RunArtifact {
+  version: number;
+  runId: number;
+  inputs: {
+    /** Smartsheet account used; the password is never recorded */
+    email: string | null;
+    useMockWebsite: boolean;
+    fillProfile: string;
+    rateLimit: Record<string, number>;
+    entries: RunArtifactEntry[];
+  };
+  outcome: {
+    ok: boolean;
+    error: string | null;
+    submittedIds: number[];
+    removedIds: number[];
+    totalProcessed: number;
+  };
+  timing: {
+    startedAt: string;
+    finishedAt: string;
+    durationMs: number;
+  };
+  /** One per quarter form the run touched; empty if the bot never started */
+  forms: SubmissionFormRunReport[];
+  screenshots: RunArtifactScreenshot[];
+}
+
+/**
+ * Run artifact folder for a userData path
+ */
+export function getRunArtifactDirectory(
+  userDataPath: string = app.getPath('userData')
+): string {
+  return path.join(userDataPath, RUN_ARTIFACT_DIR_NAME);
+}
+
+/**
+ * Folder the bot writes run N's failure screenshots to
+ */
+export function getRunScreenshotDirectory(runId: number, artifactDir: string = getRunArtifactDirectory()): string {
+  return path.join(artifactDir, `run-${runId}`);
+}
+
+/**
+ * Assembles the artifact for a finished run
+ */
+export function buildRunArtifact(params: {
+  runId: number;
+  email: string | null;
+  useMockWebsite: boolean;
+  entries: TimesheetDbRow[];
+  result: Pick<SubmissionResult, 'ok' | 'error' | 'submittedIds' | 'removedIds' | 'totalProcessed' | 'formRuns'> | null;
+  error?: string | null;
+  startedAt: Date;
+  finishedAt: Date;
+}): RunArtifact {
+  const forms = params.result?.formRuns ?? [];
+  const screenshots = forms.flatMap((form) =>
+    form.rows
+      .filter((row) => row.screenshot)
+      .map((row) => ({
+        entryId: row.entryId,
+        formId: form.formId,
+        rowIndex: row.rowIndex,
+        path: row.screenshot!
+      }))
+  );
+
+  return {
+    version: ARTIFACT_VERSION,
+    runId: params.runId,
+    inputs: {
+      email: params.email,
+      useMockWebsite: params.useMockWebsite,
+      fillProfile: getActiveFillProfile().name,
+      rateLimit: { ...getSubmissionRateLimiter().getSettings() },
+      entries: params.entries.map((entry) => ({
+        id: entry.id,
+        date: entry.date,
+        hours: entry.hours,
+        project: entry.project,
+        tool: entry.tool ?? null,
+        chargeCode: entry.detail_charge_code ?? null,
+        taskDescription: entry.task_description,
+        submissionKey: entry.submission_key ?? null,
+        amendsId: entry.amends_id ?? null
+      }))
+    },
+    outcome: {
+      ok: params.result?.ok ?? false,
+      error: params.error ?? params.result?.error ?? null,
+      submittedIds: params.result?.submittedIds ?? [],
+      removedIds: params.result?.removedIds ?? [],
+      totalProcessed: params.result?.totalProcessed ?? 0
+    },
+    timing: {
+      startedAt: params.startedAt.toISOString(),
+      finishedAt: params.finishedAt.toISOString(),
+      durationMs: params.finishedAt.getTime() - params.startedAt.getTime()
+    },
+    forms,
+    screenshots
+  };
+}
+
+/**
+ * Writes an artifact as `run-<id>.json`, replacing any earlier file for the run
+ *
+ * @returns Path of the written file
+ */
+export async function writeRunArtifact(
+  artifact: RunArtifact,
+  artifactDir: string = getRunArtifactDirectory()
+): Promise<string> {
+  await fs.promises.mkdir(artifactDir, { recursive: true });
+  const filePath = path.join(artifactDir, `run-${artifact.runId}.json`);
+  const tempPath = `${filePath}.tmp`;
+  await fs.promises.writeFile(tempPath, JSON.stringify(artifact, null, 2), 'utf-8');
+  await fs.promises.rename(tempPath, filePath);
+  appLogger.verbose('Run artifact written', { runId: artifact.runId, filePath });
+  return filePath;
+}
+
+/**
+ * Reads the artifact for a run
+ *
+ * @returns The artifact, or null if none was recorded (or it has been pruned)
+ */
+export async function getRunArtifact(
+  runId: number,
+  artifactDir: string = getRunArtifactDirectory()
+): Promise<RunArtifact | null> {
+  const filePath = path.join(artifactDir, `run-${runId}.json`);
+  try {
+    return JSON.parse(await fs.promises.readFile(filePath, 'utf-8')) as RunArtifact;
+  } catch (err: unknown) {
+    if ((err as NodeJS.ErrnoException)?.code === 'ENOENT') {
+      return null;
+    }
+    throw err;
+  }
+}
+
+/**
+ * Reads the newest artifacts, highest run ID first; unreadable files are skipped
+ */
+export async function listRecentRunArtifacts(
+  limit: number,
+  artifactDir: string = getRunArtifactDirectory()
+): Promise<RunArtifact[]> {
+  let names: string[];
+  try {
+    names = await fs.promises.readdir(artifactDir);
+  } catch {
+    return [];
+  }
+  const runIds = names
+    .map((name) => ARTIFACT_NAME.exec(name))
+    .filter((match): match is RegExpExecArray => match !== null && match[2] === '.json')
+    .map((match) => Number(match[1]))
+    .sort((a, b) => b - a)
+    .slice(0, limit);
+
+  const artifacts: RunArtifact[] = [];
+  for (const runId of runIds) {
+    try {
+      const artifact = await getRunArtifact(runId, artifactDir);
+      if (artifact) artifacts.push(artifact);
+    } catch (err: unknown) {
+      appLogger.debug('Skipping unreadable run artifact', {
+        runId,
+        error: err instanceof Error ? err.message : String(err)
+      });
+    }
+  }
+  return artifacts;
+}
+
+/**
+ * Deletes artifacts and screenshot folders last modified before the retention window
+ *
+ * @returns Names of the deleted files and folders
+ */
+export async function cleanupOldRunArtifacts(
+  artifactDir: string = getRunArtifactDirectory(),
+  now: Date = new Date(),
+  retentionDays: number = RUN_ARTIFACT_RETENTION_DAYS
+): Promise<string[]> {
+  const cutoff = now.getTime() - retentionDays * 24 * 60 * 60 * 1000;
+  let names: string[];
+  try {
+    names = await fs.promises.readdir(artifactDir);
+  } catch {
+    return [];
+  }
+
+  const deleted: string[] = [];
+  for (const name of names.filter((n) => ARTIFACT_NAME.test(n))) {
+    const target = path.join(artifactDir, name);
+    try {
+      const { mtimeMs } = await fs.promises.stat(target);
+      if (mtimeMs < cutoff) {
+        await fs.promises.rm(target, { recursive: true, force: true });
+        deleted.push(name);
+      }
+    } catch (err: unknown) {
+      appLogger.warn('Could not delete old run artifact', {
+        name,
+        error: err instanceof Error ? err.message : String(err)
+      });
+    }
+  }
+  return deleted;
+}
@@ -0,0 +1,250 @@
+/**
+ * @fileoverview Scheduled Export Task
+ *
+ * Writes last month's submitted entries to a configured folder on a monthly
+ * schedule. Each export runs as a background job (and so is recorded in run
+ * history) and notifies the user.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { appLogger } from "@sheetpilot/shared/logger";
+import { normalizeDateToISO } from "@sheetpilot/shared";
+import {
+  getDueScheduledExports,
+  getSubmittedTimesheetEntriesForExport,
+  markScheduledExportRun,
+  type RunTrigger,
+  type ScheduledExport,
+  type TimesheetDbRow,
+} from "@/models";
+import {
+  buildTimesheetCsv,
+  toExportValues,
+  TIMESHEET_EXPORT_HEADERS,
+} from "@/services/timesheet/csv-export";
+import { buildXlsxWorkbook } from "@/services/timesheet/xlsx-export";
+import { notifyUser } from "@/services/notifications";
+import { uploadFileToCloud } from "@/services/cloud/graph-upload";
+import {
+  enqueueJob,
+  waitForJob,
+  type JobContext,
+} from "@/services/jobs/job-manager";
+import { registerScheduledTask, type ScheduledTask } from "./scheduler";
+
+export const SCHEDULED_EXPORT_JOB_TYPE = "scheduled-export";
+
+/** Minutes after midnight at which monthly exports fire */
+const RUN_MINUTE_OF_DAY = 5;
+
+const pad = (n: number): string => String(n).padStart(2, "0");
+
+const toIsoDate = (date: Date): string =>
+  `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}`;
+
+/**
+ * Computes the next run time for a monthly schedule strictly after `from`
+ *
+ * @param dayOfMonth - Day of month to run on (1-28)
+ * @param from - Reference time
+ */
+export function computeNextMonthlyRunAt(dayOfMonth: number, from: Date): Date {
+  const candidate = new Date(
+    from.getFullYear(),
+    from.getMonth(),
+    dayOfMonth,
+    0,
+    RUN_MINUTE_OF_DAY
+  );
+  if (candidate.getTime() > from.getTime()) {
+    return candidate;
+  }
+  return new Date(
+    from.getFullYear(),
+    from.getMonth() + 1,
+    dayOfMonth,
+    0,
+    RUN_MINUTE_OF_DAY
+  );
+}
+
+/**
+ * Gets the calendar month before `now` as inclusive ISO dates
+ */
+export function getPreviousMonthRange(now: Date): {
+  start: string;
+  end: string;
+  label: string;
+} {
+  const start = new Date(now.getFullYear(), now.getMonth() - 1, 1);
+  const end = new Date(now.getFullYear(), now.getMonth(), 0);
+  return {
+    start: toIsoDate(start),
+    end: toIsoDate(end),
+    label: `${start.getFullYear()}-${pad(start.getMonth() + 1)}`,
+  };
+}
+
+/**
+ * Filters entries to an inclusive ISO date range (entry dates may be MM/DD/YYYY)
+ */
+export function filterEntriesByDateRange<T extends { date: string }>(
+  entries: T[],
+  start: string,
+  end: string
+): T[] {
+  return entries.filter((entry) => {
+    try {
+      const iso = normalizeDateToISO(entry.date);
+      return iso >= start && iso <= end;
+    } catch {
+      return false;
+    }
+  });
+}
+
+const writeExportFile = (
+  schedule: ScheduledExport,
+  entries: TimesheetDbRow[],
+  label: string
+): string => {
+  fs.mkdirSync(schedule.targetDir, { recursive: true });
+  const filePath = path.join(
+    schedule.targetDir,
+    `sheetpilot_timesheet_${label}.${schedule.format}`
+  );
+
+  if (schedule.format === "xlsx") {
+    const rows = entries.map((entry) => toExportValues(entry));
+    fs.writeFileSync(
+      filePath,
+      buildXlsxWorkbook(label, TIMESHEET_EXPORT_HEADERS, rows)
+    );
+  } else {
+    fs.writeFileSync(filePath, buildTimesheetCsv(entries), "utf-8");
+  }
+  return filePath;
+};
+
+export interface ScheduledExportResult {
+  filePath: string;
+  entryCount: number;
+  uploaded: boolean;
+  uploadError?: string;
+}
+
+/**
+ * Writes last month's export for a schedule (and uploads it if configured)
+ *
+ * @throws Error if the export could not be written
+ */
+export async function performScheduledExport(
+  schedule: ScheduledExport,
+  context: Pick<JobContext, "reportProgress">,
+  now: Date = new Date()
+): Promise<ScheduledExportResult> {
+  const range = getPreviousMonthRange(now);
+  try {
+    context.reportProgress(10, "Reading submitted entries");
+    const entries = filterEntriesByDateRange(
+      getSubmittedTimesheetEntriesForExport(),
+      range.start,
+      range.end
+    );
+    context.reportProgress(40, "Writing export file");
+    const filePath = writeExportFile(schedule, entries, range.label);
+    appLogger.info("Scheduled export written", {
+      scheduleId: schedule.id,
+      filePath,
+      entryCount: entries.length,
+    });
+
+    const result: ScheduledExportResult = {
+      filePath,
+      entryCount: entries.length,
+      uploaded: false,
+    };
+    if (schedule.uploadToCloud) {
+      context.reportProgress(70, "Uploading to OneDrive/SharePoint");
+      try {
+        await uploadFileToCloud(filePath);
+        result.uploaded = true;
+      } catch (err: unknown) {
+        result.uploadError = err instanceof Error ? err.message : String(err);
+        appLogger.warn("Scheduled export upload failed", {
+          scheduleId: schedule.id,
+          error: result.uploadError,
+        });
+      }
+    }
+
+    notifyUser(
+      "Timesheet export complete",
+      `${entries.length} entries for ${range.label} saved to ${filePath}` +
+        (result.uploaded ? " and uploaded to OneDrive" : "") +
+        (result.uploadError ? ` (upload failed: ${result.uploadError})` : "")
+    );
+    return result;
+  } catch (err: unknown) {
+    const errorMessage = err instanceof Error ? err.message : String(err);
+    appLogger.error("Scheduled export failed", {
+      scheduleId: schedule.id,
+      error: errorMessage,
+    });
+    notifyUser("Timesheet export failed", `${schedule.name}: ${errorMessage}`);
+    throw err;
+  } finally {
+    markScheduledExportRun(
+      schedule.id,
+      now.toISOString(),
+      computeNextMonthlyRunAt(schedule.dayOfMonth, now).toISOString()
+    );
+  }
+}
+
+/**
+ * Queues a scheduled export as a background job
+ *
+ * @returns Job ID
+ */
+export function enqueueScheduledExport(
+  schedule: ScheduledExport,
+  trigger: RunTrigger,
+  now: Date = new Date()
+): string {
+  return enqueueJob(
+    SCHEDULED_EXPORT_JOB_TYPE,
+    (context) => performScheduledExport(schedule, context, now),
+    {
+      trigger,
+      detail: {
+        scheduleId: schedule.id,
+        name: schedule.name,
+        format: schedule.format,
+        range: getPreviousMonthRange(now),
+      },
+    }
+  );
+}
+
+export const scheduledExportTask: ScheduledTask = {
+  name: SCHEDULED_EXPORT_JOB_TYPE,
+  runDue: async (now: Date) => {
+    const due = getDueScheduledExports(now.toISOString());
+    for (const schedule of due) {
+      await waitForJob(enqueueScheduledExport(schedule, "scheduled", now));
+    }
+  },
+};
+
+/**
+ * Registers the scheduled export task with the scheduler
+ */
+export function registerScheduledExportTask(): void {
+  registerScheduledTask(scheduledExportTask);
+}
@@ -0,0 +1,105 @@
+/**
+ * @fileoverview Scheduler
+ *
+ * Minimal in-process scheduler. Registered tasks are polled on a fixed
+ * interval and decide for themselves what is due, so schedules survive
+ * restarts by living in the database rather than in timers.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { appLogger } from "@sheetpilot/shared/logger";
+
+export interface ScheduledTask {
+  /** Unique task name (used for logging and de-duplication) */
+  name: string;
+  /** Runs any work that is due at `now` */
+  runDue: (now: Date) => Promise<void>;
+}
+
+const DEFAULT_TICK_INTERVAL_MS = 60 * 1000;
+
+const tasks = new Map<string, ScheduledTask>();
+const runningTasks = new Set<string>();
+let tickTimer: NodeJS.Timeout | null = null;
+
+/**
+ * Registers a task with the scheduler (replaces a task with the same name)
+ */
+export function registerScheduledTask(task: ScheduledTask): void {
+  tasks.set(task.name, task);
+  appLogger.verbose("Scheduled task registered", { name: task.name });
+}
+
+/**
+ * Removes a task from the scheduler
+ */
+export function unregisterScheduledTask(name: string): void {
+  tasks.delete(name);
+}
+
+/**
+ * Runs one scheduler tick. Tasks still running from a previous tick are skipped.
+ */
+export async function runSchedulerTick(now: Date = new Date()): Promise<void> {
+  for (const task of tasks.values()) {
+    if (runningTasks.has(task.name)) {
+      appLogger.verbose("Scheduled task still running, skipping tick", {
+        name: task.name,
+      });
+      continue;
+    }
+    runningTasks.add(task.name);
+    try {
+      await task.runDue(now);
+    } catch (err: unknown) {
+      appLogger.error("Scheduled task failed", {
+        name: task.name,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    } finally {
+      runningTasks.delete(task.name);
+    }
+  }
+}
+
+/**
+ * Starts polling registered tasks
+ */
+export function startScheduler(
+  intervalMs: number = DEFAULT_TICK_INTERVAL_MS
+): void {
+  if (tickTimer) {
+    return;
+  }
+  appLogger.info("Starting scheduler", {
+    intervalMs,
+    tasks: [...tasks.keys()],
+  });
+  // Run once shortly after startup so overdue work is picked up promptly
+  void runSchedulerTick();
+  tickTimer = setInterval(() => {
+    void runSchedulerTick();
+  }, intervalMs);
+  tickTimer.unref?.();
+}
+
+/**
+ * Stops polling
+ */
+export function stopScheduler(): void {
+  if (tickTimer) {
+    clearInterval(tickTimer);
+    tickTimer = null;
+    appLogger.info("Scheduler stopped");
+  }
+}
+
+/**
+ * Whether the scheduler is currently polling
+ */
+export function isSchedulerRunning(): boolean {
+  return tickTimer !== null;
+}
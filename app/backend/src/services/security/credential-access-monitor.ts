@@ -0,0 +1,90 @@
+/**
+ * @fileoverview Credential Access Monitor
+ *
+ * Every credential read is audited by the repository. This monitor raises an
+ * alert (security log event + desktop notification) when a read happens
+ * outside the flows that legitimately need a password, or when reads arrive
+ * in a burst. Either pattern suggests something (e.g. a compromised
+ * renderer) probing IPC for secrets.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { appLogger } from "@sheetpilot/shared/logger";
+import {
+  setCredentialAccessListener,
+  type CredentialAccessEvent,
+  type CredentialAccessPurpose,
+} from "@/models";
+import { notifyUser } from "@/services/notifications";
+
+/**
+ * Flows allowed to read credentials without raising an alert ("reveal" is
+ * the show-password command, which the security policy gates separately)
+ */
+export const EXPECTED_CREDENTIAL_PURPOSES: ReadonlySet<CredentialAccessPurpose> =
+  new Set<CredentialAccessPurpose>(["submission", "validation", "cloud-sync", "reveal"]);
+
+/** More reads than this inside the window is treated as probing */
+export const CREDENTIAL_READ_BURST_LIMIT = 10;
+export const CREDENTIAL_READ_BURST_WINDOW_MS = 60_000;
+
+let recentReads: number[] = [];
+
+/**
+ * Decides whether a read warrants an alert
+ *
+ * @param recent - Timestamps (ms) of earlier reads inside the burst window
+ * @returns Alert reason, or null if the read looks legitimate
+ */
+export function assessCredentialAccess(
+  event: CredentialAccessEvent,
+  recent: number[]
+): string | null {
+  if (!EXPECTED_CREDENTIAL_PURPOSES.has(event.purpose)) {
+    return `Credentials for ${event.service} were read by ${event.caller} outside a submission or validation flow`;
+  }
+  if (recent.length >= CREDENTIAL_READ_BURST_LIMIT) {
+    return `Credentials were read ${recent.length + 1} times in the last minute`;
+  }
+  return null;
+}
+
+/**
+ * Handles one audited credential read
+ *
+ * @returns Alert reason if an alert was raised
+ */
+export function handleCredentialAccess(
+  event: CredentialAccessEvent,
+  now: number = Date.now()
+): string | null {
+  recentReads = recentReads.filter(
+    (at) => now - at < CREDENTIAL_READ_BURST_WINDOW_MS
+  );
+  const reason = assessCredentialAccess(event, recentReads);
+  recentReads.push(now);
+  if (!reason) {
+    return null;
+  }
+
+  appLogger.security("credential-access-alert", reason, event);
+  notifyUser(
+    "SheetPilot security alert",
+    `${reason}. If you did not expect this, sign out and restart SheetPilot.`
+  );
+  return reason;
+}
+
+/**
+ * Starts alerting on credential reads
+ */
+export function registerCredentialAccessMonitor(): void {
+  recentReads = [];
+  setCredentialAccessListener((event) => {
+    handleCredentialAccess(event);
+  });
+  appLogger.verbose("Credential access monitor registered");
+}
@@ -0,0 +1,120 @@
+/**
+ * @fileoverview OS Keychain Credential Store
+ *
+ * Keeps credentials encrypted with Electron's safeStorage, whose key lives in
+ * the OS keychain (macOS Keychain, Windows DPAPI, libsecret on Linux), so the
+ * passwords cannot be decrypted by copying the file to another account or
+ * machine. Entries are kept in `credential-keychain.json` in the user data
+ * directory: email in the clear (as in SQLite), password encrypted.
+ *
+ * The credentials repository writes here alongside SQLite while the migration
+ * is under way; see `auditCredentialMigration` for what is still SQLite-only.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { app, safeStorage } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+import { setCredentialKeychain, type CredentialKeychain } from "@/models";
+
+/** Encrypts and decrypts with a key held by the OS keychain */
+export interface KeychainCipher {
+  isAvailable: () => boolean;
+  encrypt: (plainText: string) => Buffer;
+  decrypt: (encrypted: Buffer) => string;
+}
+
+interface KeychainFileEntry {
+  email: string;
+  /** base64 of the encrypted password */
+  secret: string;
+}
+
+const safeStorageCipher: KeychainCipher = {
+  isAvailable: () => safeStorage.isEncryptionAvailable(),
+  encrypt: (plainText) => safeStorage.encryptString(plainText),
+  decrypt: (encrypted) => safeStorage.decryptString(encrypted),
+};
+
+export const getCredentialKeychainPath = (): string =>
+  path.join(app.getPath("userData"), "credential-keychain.json");
+
+/**
+ * Keychain store backed by `filePath`
+ */
+export function createFileKeychain(
+  filePath: string,
+  cipher: KeychainCipher = safeStorageCipher
+): CredentialKeychain {
+  const readAll = (): Record<string, KeychainFileEntry> => {
+    try {
+      const parsed = JSON.parse(fs.readFileSync(filePath, "utf-8")) as unknown;
+      return parsed && typeof parsed === "object"
+        ? (parsed as Record<string, KeychainFileEntry>)
+        : {};
+    } catch (err: unknown) {
+      if ((err as NodeJS.ErrnoException).code === "ENOENT") return {};
+      throw err;
+    }
+  };
+
+  const writeAll = (entries: Record<string, KeychainFileEntry>): void => {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    const tempPath = `${filePath}.tmp`;
+    fs.writeFileSync(tempPath, JSON.stringify(entries, null, 2), {
+      encoding: "utf-8",
+      mode: 0o600,
+    });
+    fs.renameSync(tempPath, filePath);
+  };
+
+  return {
+    read: (service) => {
+      const entry = readAll()[service];
+      if (!entry) return null;
+      return {
+        email: entry.email,
+        password: cipher.decrypt(Buffer.from(entry.secret, "base64")),
+      };
+    },
+    write: (service, email, password) => {
+      const entries = readAll();
+      entries[service] = {
+        email,
+        secret: cipher.encrypt(password).toString("base64"),
+      };
+      writeAll(entries);
+    },
+    remove: (service) => {
+      const entries = readAll();
+      if (!(service in entries)) return;
+      delete entries[service];
+      writeAll(entries);
+    },
+    services: () => Object.keys(readAll()),
+  };
+}
+
+/**
+ * Starts writing credentials to the OS keychain alongside SQLite
+ *
+ * @returns Whether the keychain is in use; without OS encryption (e.g. Linux
+ * with no secret service) SQLite stays the only store
+ */
+export function registerCredentialKeychain(
+  filePath: string = getCredentialKeychainPath(),
+  cipher: KeychainCipher = safeStorageCipher
+): boolean {
+  if (!cipher.isAvailable()) {
+    appLogger.warn("OS keychain encryption unavailable; credentials stay in SQLite only");
+    setCredentialKeychain(null);
+    return false;
+  }
+  setCredentialKeychain(createFileKeychain(filePath, cipher));
+  appLogger.verbose("Credential keychain registered", { filePath });
+  return true;
+}
@@ -0,0 +1,126 @@
+/**
+ * @fileoverview Directory Email Lookup
+ *
+ * Smartsheet signs in through Entra ID (Azure AD), which only accepts the
+ * user's sign-in name (UPN). Users who type a mail alias, or the UPN in a
+ * different case, end up with stored credentials that fail at the Microsoft
+ * login page. When `directory_lookup` is set in settings.json, emails given
+ * at login and when storing credentials are looked up in the company
+ * directory and replaced with the sign-in name, e.g.
+ * `"directory_lookup": { "provider": "graph" }`.
+ *
+ * The lookup is advisory: if the directory cannot be reached the email is
+ * used as typed. An address the directory does not know is rejected unless
+ * `reject_unknown` is false. Other directories (e.g. LDAP) can be plugged in
+ * with `setDirectoryProvider`.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import { loadSettings } from "@/services/settings/settings-store";
+import { getMessage } from "@/services/i18n/message-catalog";
+import { graphDirectoryProvider } from "@/services/cloud/graph-directory";
+
+export interface DirectoryLookupSettings {
+  /** Directory to search (default "graph": the connected Microsoft account) */
+  provider?: "graph";
+  /** Reject emails the directory does not know (default true) */
+  reject_unknown?: boolean;
+}
+
+export interface DirectoryUser {
+  /** Sign-in name accepted by Entra ID */
+  userPrincipalName: string;
+  mail: string | null;
+}
+
+export interface DirectoryProvider {
+  name: string;
+  /**
+   * Finds the user an address belongs to
+   *
+   * @returns null if no user has this address
+   * @throws Error if the directory cannot be searched
+   */
+  findUser(email: string): Promise<DirectoryUser | null>;
+}
+
+export interface DirectoryEmailResult {
+  email: string;
+  /** Message for the user when the email must not be used */
+  error: string | null;
+}
+
+let customProvider: DirectoryProvider | null = null;
+
+/**
+ * Searches another directory instead of Graph when lookup is enabled (e.g.
+ * LDAP, or a fake in tests); pass null to go back to Graph
+ */
+export function setDirectoryProvider(provider: DirectoryProvider | null): void {
+  customProvider = provider;
+}
+
+const getConfiguredProvider = (
+  settings: DirectoryLookupSettings | undefined
+): DirectoryProvider | null => {
+  if (!settings) {
+    return null;
+  }
+  return customProvider ?? graphDirectoryProvider;
+};
+
+/**
+ * Resolves an email to the directory sign-in name
+ *
+ * @returns The sign-in name (or the email as entered when lookup is off or
+ * unavailable), or an error message for the user when the directory does not
+ * know the address
+ */
+export async function resolveDirectoryEmail(
+  email: string,
+  settings: DirectoryLookupSettings | undefined = loadSettings().directory_lookup
+): Promise<DirectoryEmailResult> {
+  const provider = getConfiguredProvider(settings);
+  if (!provider) {
+    return { email, error: null };
+  }
+
+  let user: DirectoryUser | null;
+  try {
+    user = await provider.findUser(email);
+  } catch (err: unknown) {
+    ipcLogger.warn("Directory lookup unavailable; using the email as entered", {
+      provider: provider.name,
+      error: err instanceof Error ? err.message : String(err),
+    });
+    return { email, error: null };
+  }
+
+  if (!user) {
+    if (settings?.reject_unknown === false) {
+      ipcLogger.warn("Email not found in the directory; using it as entered", {
+        provider: provider.name,
+        email,
+      });
+      return { email, error: null };
+    }
+    ipcLogger.warn("Rejected email not found in the directory", {
+      provider: provider.name,
+      email,
+    });
+    return { email, error: getMessage("EMAIL_NOT_IN_DIRECTORY", { email }) };
+  }
+
+  if (user.userPrincipalName !== email) {
+    ipcLogger.audit("email-normalized", "Email replaced with the directory sign-in name", {
+      provider: provider.name,
+      entered: email,
+      signInName: user.userPrincipalName,
+    });
+  }
+  return { email: user.userPrincipalName, error: null };
+}
@@ -0,0 +1,77 @@
+/**
+ * @fileoverview Email Domain Policy
+ *
+ * Restricts the emails accepted at login and when storing credentials to an
+ * allow-list of domains (`allowed_email_domains` in settings.json), so a
+ * personal Microsoft account cannot end up in the credential store by
+ * mistake, e.g. `["skywatertechnology.com"]`. With no list configured any
+ * domain is accepted. The built-in admin login is not an email and is never
+ * checked.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from "@sheetpilot/shared/logger";
+import { loadSettings } from "@/services/settings/settings-store";
+import { getMessage } from "@/services/i18n/message-catalog";
+
+/** Lowercase, without a leading '@' */
+const normalizeDomain = (domain: string): string =>
+  domain.trim().toLowerCase().replace(/^@+/, "");
+
+/**
+ * Allowed domains from a stored (possibly malformed) setting; entries that
+ * are not strings are ignored
+ */
+export function resolveAllowedEmailDomains(raw: unknown): string[] {
+  if (!Array.isArray(raw)) {
+    return [];
+  }
+  const domains = raw
+    .filter((d): d is string => typeof d === "string")
+    .map(normalizeDomain)
+    .filter(Boolean);
+  return [...new Set(domains)];
+}
+
+/**
+ * Whether an email belongs to one of the allowed domains
+ */
+export function isEmailDomainAllowed(
+  email: string,
+  allowedDomains: readonly string[]
+): boolean {
+  if (allowedDomains.length === 0) {
+    return true;
+  }
+  const at = email.lastIndexOf("@");
+  if (at < 0) {
+    return false;
+  }
+  const domain = normalizeDomain(email.slice(at + 1));
+  return allowedDomains.some((allowed) => normalizeDomain(allowed) === domain);
+}
+
+/**
+ * Checks an email against the configured allow-list
+ *
+ * @returns An error message for the user, or null when the email is allowed
+ */
+export function checkEmailDomain(
+  email: string,
+  allowedDomains: readonly string[] = resolveAllowedEmailDomains(
+    loadSettings().allowed_email_domains
+  )
+): string | null {
+  if (isEmailDomainAllowed(email, allowedDomains)) {
+    return null;
+  }
+  ipcLogger.warn("Rejected email outside allowed domains", {
+    email,
+    allowedDomains,
+  });
+  const domains = allowedDomains.map((d) => `@${normalizeDomain(d)}`).join(", ");
+  return getMessage("EMAIL_DOMAIN_NOT_ALLOWED", { domains });
+}
@@ -0,0 +1,165 @@
+/**
+ * @fileoverview OS Re-authentication
+ *
+ * Asks the operating system to confirm the person at the keyboard (Touch ID
+ * on macOS, Windows Hello on Windows) before sensitive actions such as
+ * showing a stored password. Windows Hello is reached through the WinRT
+ * UserConsentVerifier from PowerShell, since Electron has no binding for it.
+ * Other platforms have no provider and report re-authentication unavailable.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { execFile } from "child_process";
+import { systemPreferences } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+
+export type OsReauthMethod = "touch-id" | "windows-hello";
+
+export interface OsReauthProvider {
+  method: OsReauthMethod;
+  isAvailable: () => Promise<boolean>;
+  /** Prompts the user; resolves true only if they verified */
+  verify: (message: string) => Promise<boolean>;
+}
+
+export interface OsReauthResult {
+  outcome: "verified" | "failed" | "unavailable";
+  method: OsReauthMethod | null;
+}
+
+const WINDOWS_HELLO_AVAILABILITY_TIMEOUT_MS = 15_000;
+const WINDOWS_HELLO_PROMPT_TIMEOUT_MS = 120_000;
+
+/** Loads UserConsentVerifier and defines Await for WinRT async operations */
+const WINRT_PRELUDE = `
+$ErrorActionPreference = 'Stop'
+Add-Type -AssemblyName System.Runtime.WindowsRuntime
+$asTask = [System.WindowsRuntimeSystemExtensions].GetMethods() | Where-Object {
+  $_.Name -eq 'AsTask' -and $_.GetParameters().Count -eq 1 -and
+  $_.GetParameters()[0].ParameterType.Name -eq 'IAsyncOperation\`1'
+} | Select-Object -First 1
+function Await($op, [Type]$type) {
+  $task = $asTask.MakeGenericMethod($type).Invoke($null, @($op))
+  $task.Wait() | Out-Null
+  $task.Result
+}
+$verifier = [Windows.Security.Credentials.UI.UserConsentVerifier,Windows.Security.Credentials.UI,ContentType=WindowsRuntime]
+`;
+
+const WINDOWS_HELLO_AVAILABILITY_SCRIPT = `${WINRT_PRELUDE}
+Await ($verifier::CheckAvailabilityAsync()) ([Windows.Security.Credentials.UI.UserConsentVerifierAvailability])
+`;
+
+// The prompt text comes from the environment so it is never parsed as script
+const WINDOWS_HELLO_VERIFY_SCRIPT = `${WINRT_PRELUDE}
+Await ($verifier::RequestVerificationAsync($env:SHEETPILOT_REAUTH_MESSAGE)) ([Windows.Security.Credentials.UI.UserConsentVerificationResult])
+`;
+
+const runPowerShell = (
+  script: string,
+  timeout: number,
+  env: Record<string, string> = {}
+): Promise<string> =>
+  new Promise((resolve, reject) => {
+    execFile(
+      "powershell.exe",
+      ["-NoProfile", "-NonInteractive", "-ExecutionPolicy", "Bypass", "-Command", script],
+      { timeout, windowsHide: true, env: { ...process.env, ...env } },
+      (error, stdout) => {
+        if (error) {
+          reject(error);
+          return;
+        }
+        resolve(String(stdout).trim());
+      }
+    );
+  });
+
+const touchIdProvider: OsReauthProvider = {
+  method: "touch-id",
+  isAvailable: async () => systemPreferences.canPromptTouchID(),
+  verify: async (message) => {
+    try {
+      await systemPreferences.promptTouchID(message);
+      return true;
+    } catch {
+      // Rejected on cancel, fallback dismissal or a failed match
+      return false;
+    }
+  },
+};
+
+const windowsHelloProvider: OsReauthProvider = {
+  method: "windows-hello",
+  isAvailable: async () =>
+    (await runPowerShell(
+      WINDOWS_HELLO_AVAILABILITY_SCRIPT,
+      WINDOWS_HELLO_AVAILABILITY_TIMEOUT_MS
+    )) === "Available",
+  verify: async (message) =>
+    (await runPowerShell(WINDOWS_HELLO_VERIFY_SCRIPT, WINDOWS_HELLO_PROMPT_TIMEOUT_MS, {
+      SHEETPILOT_REAUTH_MESSAGE: message,
+    })) === "Verified",
+};
+
+let providerOverride: OsReauthProvider | null | undefined;
+
+/**
+ * Replaces the platform provider (null disables re-auth, undefined restores the default)
+ */
+export function setOsReauthProvider(
+  provider: OsReauthProvider | null | undefined
+): void {
+  providerOverride = provider;
+}
+
+/**
+ * Provider for the current platform, or null if the OS offers none
+ */
+export function getOsReauthProvider(
+  platform: NodeJS.Platform = process.platform
+): OsReauthProvider | null {
+  if (providerOverride !== undefined) {
+    return providerOverride;
+  }
+  if (platform === "darwin") return touchIdProvider;
+  if (platform === "win32") return windowsHelloProvider;
+  return null;
+}
+
+/**
+ * Asks the OS to re-authenticate the user
+ *
+ * @param message - Shown in the OS prompt (e.g. why SheetPilot is asking)
+ */
+export async function requestOsReauth(message: string): Promise<OsReauthResult> {
+  const provider = getOsReauthProvider();
+  if (!provider) {
+    return { outcome: "unavailable", method: null };
+  }
+
+  try {
+    if (!(await provider.isAvailable())) {
+      appLogger.verbose("OS re-authentication not set up on this device", {
+        method: provider.method,
+      });
+      return { outcome: "unavailable", method: provider.method };
+    }
+    const verified = await provider.verify(message);
+    appLogger.security(
+      "os-reauth",
+      verified ? "OS re-authentication succeeded" : "OS re-authentication failed",
+      { method: provider.method, verified }
+    );
+    return { outcome: verified ? "verified" : "failed", method: provider.method };
+  } catch (err: unknown) {
+    appLogger.warn("OS re-authentication could not run", {
+      method: provider.method,
+      error: err instanceof Error ? err.message : String(err),
+    });
+    return { outcome: "unavailable", method: provider.method };
+  }
+}
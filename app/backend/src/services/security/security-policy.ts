@@ -0,0 +1,123 @@
+/**
+ * @fileoverview Security Policy
+ *
+ * Applies the `security_policy` settings group. When the workstation locks or
+ * sleeps (per policy), every SheetPilot session is revoked, the credential
+ * vault is locked and the renderer is told to return to the login screen.
+ * Electron only reports screen locks on Windows and macOS; elsewhere the
+ * lock trigger never fires and only suspend applies. The policy also decides
+ * whether revealing a stored password needs OS re-authentication.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { PowerMonitor } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+import { clearAllSessions, lockCredentialVault } from "@/models";
+import { emitSessionRevoked } from "@/routes/handlers/timesheet/main-window";
+import { loadSettings } from "@/services/settings/settings-store";
+
+/** Shape stored under `security_policy` in settings.json */
+export interface SecurityPolicySettings {
+  logoutOnLock?: boolean;
+  logoutOnSuspend?: boolean;
+  lockCredentialVault?: boolean;
+  /** Require Windows Hello / Touch ID before a stored password is shown */
+  requireOsReauthToReveal?: boolean;
+}
+
+export type SecurityPolicy = Required<SecurityPolicySettings>;
+
+export const DEFAULT_SECURITY_POLICY: SecurityPolicy = {
+  logoutOnLock: false,
+  logoutOnSuspend: false,
+  lockCredentialVault: true,
+  requireOsReauthToReveal: true,
+};
+
+export type SessionLockTrigger = "lock-screen" | "suspend";
+
+/**
+ * Fills in defaults for a stored (possibly partial or malformed) policy
+ */
+export function resolveSecurityPolicy(raw: unknown): SecurityPolicy {
+  const stored =
+    raw && typeof raw === "object" ? (raw as Record<string, unknown>) : {};
+  const flag = (key: keyof SecurityPolicy): boolean =>
+    typeof stored[key] === "boolean"
+      ? (stored[key] as boolean)
+      : DEFAULT_SECURITY_POLICY[key];
+  return {
+    logoutOnLock: flag("logoutOnLock"),
+    logoutOnSuspend: flag("logoutOnSuspend"),
+    lockCredentialVault: flag("lockCredentialVault"),
+    requireOsReauthToReveal: flag("requireOsReauthToReveal"),
+  };
+}
+
+/**
+ * Whether a lock/sleep signal should end the session under a policy
+ */
+export function shouldEndSession(
+  policy: SecurityPolicy,
+  trigger: SessionLockTrigger
+): boolean {
+  return trigger === "lock-screen"
+    ? policy.logoutOnLock
+    : policy.logoutOnSuspend;
+}
+
+/**
+ * Applies the policy for one lock/sleep signal
+ *
+ * @returns Whether the session was ended
+ */
+export function enforceSessionLockPolicy(
+  trigger: SessionLockTrigger,
+  policy: SecurityPolicy = resolveSecurityPolicy(
+    loadSettings().security_policy
+  )
+): boolean {
+  if (!shouldEndSession(policy, trigger)) {
+    appLogger.verbose("Session kept on workstation lock per policy", {
+      trigger,
+    });
+    return false;
+  }
+
+  const revoked = clearAllSessions();
+  if (policy.lockCredentialVault) {
+    lockCredentialVault();
+  }
+  emitSessionRevoked({ reason: trigger });
+  appLogger.security("session-lock-policy", "Session ended on workstation lock", {
+    trigger,
+    revoked,
+    vaultLocked: policy.lockCredentialVault,
+  });
+  return true;
+}
+
+const handleSignal = (trigger: SessionLockTrigger): void => {
+  try {
+    enforceSessionLockPolicy(trigger);
+  } catch (err: unknown) {
+    appLogger.error("Could not apply session lock policy", {
+      trigger,
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+};
+
+/**
+ * Subscribes the policy to OS lock/sleep signals
+ */
+export function registerSessionLockPolicy(
+  monitor: Pick<PowerMonitor, "on">
+): void {
+  monitor.on("lock-screen", () => handleSignal("lock-screen"));
+  monitor.on("suspend", () => handleSignal("suspend"));
+  appLogger.verbose("Session lock policy registered");
+}
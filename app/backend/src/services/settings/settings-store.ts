@@ -0,0 +1,215 @@
+/**
+ * @fileoverview Settings Store
+ *
+ * Reads and writes the user's settings.json in the app data directory.
+ * Shared by the settings IPC handlers and main-process services that act on
+ * settings (e.g. the security policy).
+ *
+ * Settings are read from disk once and served from memory after that, since
+ * services such as the submission importer and scheduled tasks read them in
+ * loops. Callers get their own copy, so changing it does nothing until it is
+ * saved. Saving updates the cache and notifies `onSettingsChanged` listeners
+ * with the keys that changed; edits made to the file by hand are picked up
+ * after `reloadSettings`.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as path from 'path';
+import * as fs from 'fs';
+import { EventEmitter } from 'events';
+import { app } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import type { SecurityPolicySettings } from '@/services/security/security-policy';
+import type { ExportTemplate } from '@/services/timesheet/csv-export';
+import type { RateLimitSettings, SubmissionKeyMode, SubmissionOrder, SubmissionVerificationLevel } from '@sheetpilot/bot';
+import type { DatabaseTimeoutSettings } from '@/models';
+import type { ChargeCodeQuota } from '@/logic/charge-code-quotas';
+import type { SelectorHotfixSettings } from '@/services/timesheet/selector-hotfix';
+import type { EventThrottleSettings } from '@/services/events/event-throttle';
+import type { KioskSettings } from '@/services/health/app-health';
+import type { DirectoryLookupSettings } from '@/services/security/directory-lookup';
+import type { ExternalRefSettings } from '@/logic/external-ref';
+import type { DescriptionTemplate } from '@/logic/description-templates';
+import type { EntryCategory } from '@/logic/entry-categories';
+import type { NetworkBackupSettings } from '@/services/backup/network-backup';
+import type { ClockCheckSettings } from '@/services/health/clock-skew';
+import type { WorkweekSettings } from '@/logic/timesheet-gaps';
+import type { GapReminderSettings } from '@/services/timesheet/timesheet-gaps';
+
+export interface AppSettings {
+  browserHeadless?: boolean;
+  /** Outline elements and show the current step while the browser is visible */
+  highlightInteractions?: boolean;
+  /** Theme for users who have not picked one (see user-preferences.ts) */
+  themeMode?: 'auto' | 'light' | 'dark';
+  security_policy?: SecurityPolicySettings;
+  /** Index log lines into SQLite in the background for fast log queries */
+  log_index_enabled?: boolean;
+  /** Named export column layouts, selectable when exporting */
+  export_templates?: ExportTemplate[];
+  /** Submission pacing and throttle backoff overrides (see RateLimitSettings in the bot) */
+  automation_rate_limit?: Partial<RateLimitSettings>;
+  /** Evidence a submit needs before rows are marked Complete (see verification_level.ts in the bot) */
+  submission_verification?: SubmissionVerificationLevel;
+  /** Which entries are submitted first (see submission-order.ts in the bot) */
+  submission_order?: SubmissionOrder;
+  /**
+   * Where each entry's submission key is typed into the form (see submission-key.ts in the bot).
+   * "description" (the default without a key field) appends " [SP-XXXXXXXXXX]" to Task
+   * Description, which payroll also reads; "field" needs a form field for the key; "off" keeps
+   * the description clean but a submission that reached the sheet before a crash can no longer
+   * be recognised, so it may be submitted twice.
+   */
+  submission_key_mode?: SubmissionKeyMode;
+  /** Database busy timeout and slow statement threshold overrides */
+  database_timeouts?: Partial<DatabaseTimeoutSettings>;
+  /** Soft hour budgets per charge code per quarter */
+  charge_code_quotas?: ChargeCodeQuota[];
+  /** Email domains accepted at login (see email-domain-policy.ts); empty allows any */
+  allowed_email_domains?: string[];
+  /** Language for messages returned by the backend, e.g. "es" (see message-catalog.ts) */
+  locale?: string;
+  /** Where signed selector hotfixes are published (admin only, see ADMIN_ONLY_SETTINGS) */
+  selector_hotfix?: SelectorHotfixSettings;
+  /** Rate limit for progress and status events sent to the window */
+  event_throttle?: Partial<EventThrottleSettings>;
+  /** Restrictions for kiosk/locked-down builds (see app-health.ts) */
+  kiosk?: KioskSettings;
+  /** IANA zone entries are dated in, e.g. "America/Phoenix"; the system zone when unset */
+  home_time_zone?: string;
+  /** Look up login and credential emails in the company directory (see directory-lookup.ts) */
+  directory_lookup?: DirectoryLookupSettings;
+  /** Format of entry external references and whether they are added to the description */
+  external_ref?: ExternalRefSettings;
+  /** Shared wording for recurring tasks, filled in when drafts are created */
+  description_templates?: DescriptionTemplate[];
+  /** Categories entries can be labelled with (see logic/entry-categories.ts) */
+  entry_categories?: EntryCategory[];
+  /** Encrypted, verified backups copied to a network folder (see network-backup.ts) */
+  network_backup?: NetworkBackupSettings;
+  /** Time server and threshold for the startup clock check (see clock-skew.ts) */
+  clock_check?: ClockCheckSettings;
+  /** Working weekdays, daily target hours and holidays (see logic/timesheet-gaps.ts) */
+  workweek?: WorkweekSettings;
+  /** Daily notification about working days missing hours (see timesheet-gaps.ts) */
+  gap_reminders?: GapReminderSettings;
+}
+
+/**
+ * Settings that could weaken protections or change where the bot's selectors
+ * come from, so `settings:set` refuses them
+ * and only an admin session can write them through `settings:setProtected`
+ */
+export const ADMIN_ONLY_SETTINGS = ['security_policy', 'selector_hotfix'] as const;
+
+export type AdminOnlySetting = (typeof ADMIN_ONLY_SETTINGS)[number];
+
+export const isAdminOnlySetting = (key: string): key is AdminOnlySetting =>
+  (ADMIN_ONLY_SETTINGS as readonly string[]).includes(key);
+
+export const getSettingsPath = (): string => {
+  const userDataPath = app.getPath('userData');
+  return path.join(userDataPath, 'settings.json');
+};
+
+export interface SettingsChange {
+  /** Top-level keys whose value changed */
+  keys: Array<keyof AppSettings>;
+  settings: AppSettings;
+}
+
+const changeEvents = new EventEmitter();
+let cachedSettings: AppSettings | null = null;
+
+const readSettingsFile = (): AppSettings => {
+  const settingsPath = getSettingsPath();
+  try {
+    if (fs.existsSync(settingsPath)) {
+      const data = fs.readFileSync(settingsPath, 'utf-8');
+      return JSON.parse(data);
+    }
+  } catch (err) {
+    ipcLogger.error('Could not load settings', { 
+      settingsPath, 
+      error: err instanceof Error ? err.message : String(err) 
+    });
+  }
+  return {};
+};
+
+const getChangedKeys = (before: AppSettings, after: AppSettings): Array<keyof AppSettings> => {
+  const keys = new Set([...Object.keys(before), ...Object.keys(after)]) as Set<keyof AppSettings>;
+  return [...keys].filter((key) => JSON.stringify(before[key]) !== JSON.stringify(after[key]));
+};
+
+const notifySettingsChanged = (before: AppSettings, after: AppSettings): void => {
+  const keys = getChangedKeys(before, after);
+  if (keys.length > 0) {
+    changeEvents.emit('changed', { keys, settings: structuredClone(after) });
+  }
+};
+
+export const loadSettings = (): AppSettings => {
+  if (!cachedSettings) {
+    cachedSettings = readSettingsFile();
+  }
+  return structuredClone(cachedSettings);
+};
+
+/**
+ * Re-reads settings.json, notifying listeners of anything changed on disk
+ */
+export const reloadSettings = (): AppSettings => {
+  const before = cachedSettings;
+  cachedSettings = readSettingsFile();
+  if (before) {
+    notifySettingsChanged(before, cachedSettings);
+  }
+  return structuredClone(cachedSettings);
+};
+
+export const saveSettings = (settings: AppSettings): void => {
+  const settingsPath = getSettingsPath();
+  const before = cachedSettings ?? readSettingsFile();
+  try {
+    // Written to a temporary file first so a reader never sees half a file
+    const tempPath = `${settingsPath}.tmp`;
+    fs.writeFileSync(tempPath, JSON.stringify(settings, null, 2), 'utf-8');
+    fs.renameSync(tempPath, settingsPath);
+    cachedSettings = structuredClone(settings);
+  } catch (err) {
+    // The file may or may not have changed; read it again next time
+    cachedSettings = null;
+    ipcLogger.error('Could not save settings', { 
+      settingsPath, 
+      error: err instanceof Error ? err.message : String(err) 
+    });
+    return;
+  }
+  notifySettingsChanged(before, settings);
+};
+
+/**
+ * Calls `listener` after saved settings change
+ *
+ * @returns Removes the listener
+ */
+export const onSettingsChanged = (listener: (change: SettingsChange) => void): (() => void) => {
+  const safeListener = (change: SettingsChange): void => {
+    try {
+      listener(change);
+    } catch (err) {
+      ipcLogger.error('Settings change listener failed', {
+        keys: change.keys,
+        error: err instanceof Error ? err.message : String(err)
+      });
+    }
+  };
+  changeEvents.on('changed', safeListener);
+  return () => {
+    changeEvents.off('changed', safeListener);
+  };
+};
@@ -0,0 +1,113 @@
+/**
+ * @fileoverview User Preferences
+ *
+ * Per-user defaults (theme, week start, hours rounding, default project)
+ * kept in user-preferences.json in the app data directory, keyed by the
+ * session email. Machine-level settings (browser mode, pacing, database
+ * timeouts, quotas, ...) stay in settings.json and apply to everyone on the
+ * machine, so people sharing a workstation no longer overwrite each other's
+ * preferences.
+ *
+ * The theme used to be a machine setting; users who have not chosen one yet
+ * inherit the machine value.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as path from 'path';
+import * as fs from 'fs';
+import { app } from 'electron';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { loadSettings } from '@/services/settings/settings-store';
+import {
+  userPreferencesSchema,
+  type SetPreferences,
+  type UserPreferences
+} from '@/validation/ipc-schemas';
+
+type PreferenceStore = Record<string, unknown>;
+
+export const getPreferencesPath = (): string =>
+  path.join(app.getPath('userData'), 'user-preferences.json');
+
+/** Emails are matched case-insensitively */
+const userKey = (email: string): string => email.trim().toLowerCase();
+
+const loadPreferenceStore = (): PreferenceStore => {
+  const preferencesPath = getPreferencesPath();
+  try {
+    if (fs.existsSync(preferencesPath)) {
+      const parsed = JSON.parse(fs.readFileSync(preferencesPath, 'utf-8')) as unknown;
+      if (parsed && typeof parsed === 'object' && !Array.isArray(parsed)) {
+        return parsed as PreferenceStore;
+      }
+    }
+  } catch (err) {
+    ipcLogger.error('Could not load user preferences', {
+      preferencesPath,
+      error: err instanceof Error ? err.message : String(err)
+    });
+  }
+  return {};
+};
+
+const savePreferenceStore = (store: PreferenceStore): void => {
+  fs.writeFileSync(getPreferencesPath(), JSON.stringify(store, null, 2), 'utf-8');
+};
+
+/**
+ * Stored preferences for one user, dropping values that no longer validate
+ */
+const readStoredPreferences = (store: PreferenceStore, email: string): UserPreferences => {
+  const stored = store[userKey(email)];
+  if (!stored || typeof stored !== 'object') {
+    return {};
+  }
+  const preferences: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(stored)) {
+    const parsed = userPreferencesSchema.safeParse({ [key]: value });
+    if (parsed.success && key in parsed.data) {
+      preferences[key] = (parsed.data as Record<string, unknown>)[key];
+    }
+  }
+  return preferences as UserPreferences;
+};
+
+/**
+ * Preferences for a user, with the legacy machine theme as a fallback
+ */
+export function getUserPreferences(email: string): UserPreferences {
+  const preferences = readStoredPreferences(loadPreferenceStore(), email);
+  if (preferences.themeMode === undefined) {
+    const machineTheme = loadSettings().themeMode;
+    if (machineTheme) {
+      return { ...preferences, themeMode: machineTheme };
+    }
+  }
+  return preferences;
+}
+
+/**
+ * Merges updates into a user's preferences; null removes a preference
+ *
+ * @returns The user's preferences after the update
+ */
+export function updateUserPreferences(
+  email: string,
+  updates: SetPreferences['updates']
+): UserPreferences {
+  const store = loadPreferenceStore();
+  const next: Record<string, unknown> = { ...readStoredPreferences(store, email) };
+  for (const [key, value] of Object.entries(updates)) {
+    if (value === null) {
+      delete next[key];
+    } else if (value !== undefined) {
+      next[key] = value;
+    }
+  }
+  store[userKey(email)] = next;
+  savePreferenceStore(store);
+  return getUserPreferences(email);
+}
@@ -0,0 +1,209 @@
+/**
+ * @fileoverview Shutdown Coordinator
+ *
+ * Runs once when the application quits so in-flight work is completed or
+ * rolled back instead of being cut off: background jobs are cancelled and
+ * given a short grace period, an interrupted submission is recorded in run
+ * history and its entries returned to pending, automation browsers are
+ * closed, pending draft edits are written, and the log file and database
+ * are flushed and closed last.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import type { App } from "electron";
+import { appLogger, flushLogging } from "@sheetpilot/shared/logger";
+import { closeAllBrowsers } from "@sheetpilot/bot";
+import {
+  finishRun,
+  getInProgressTimesheetEntryIds,
+  resetInProgressTimesheetEntries,
+  shutdownDatabase,
+  startRun,
+} from "@/models";
+import {
+  cancelJob,
+  getActiveJobs,
+  waitForJob,
+} from "@/services/jobs/job-manager";
+import { stopScheduler } from "@/services/scheduler/scheduler";
+import {
+  cancelTimesheetSubmission,
+  isTimesheetSubmissionInProgress,
+} from "@/services/timesheet/submission-workflow";
+import { flushDirtyDrafts } from "@/routes/handlers/timesheet/drafts.autosave";
+
+export const INTERRUPTED_SUBMISSION_JOB_TYPE = "interrupted-submission";
+
+/** How long cancelled jobs get to finish their own cleanup */
+export const SHUTDOWN_GRACE_MS = 5000;
+
+export interface ShutdownSummary {
+  cancelledJobs: number;
+  unfinishedJobs: number;
+  interruptedEntryIds: number[];
+  browsersClosed: number;
+}
+
+const errorMessage = (err: unknown): string =>
+  err instanceof Error ? err.message : String(err);
+
+/**
+ * Records entries a submission left in_progress in run history so they can
+ * be checked against the form, then returns any still in_progress to pending.
+ */
+export const recordInterruptedSubmission = (entryIds: number[]): void => {
+  if (entryIds.length === 0) {
+    return;
+  }
+  const runId = startRun(INTERRUPTED_SUBMISSION_JOB_TYPE, "manual", {
+    entryIds,
+  });
+  finishRun(
+    runId,
+    "cancelled",
+    { entryIds },
+    "Application exited during submission; these entries may already have been submitted and should be checked before resubmitting"
+  );
+  resetInProgressTimesheetEntries();
+  appLogger.warn("Submission interrupted by shutdown", { entryIds });
+};
+
+/**
+ * Cancels active jobs, waiting up to `graceMs` for them to finish
+ *
+ * @returns Number of jobs cancelled and number still unfinished at the deadline
+ */
+const cancelActiveJobs = async (
+  graceMs: number
+): Promise<{ cancelled: number; unfinished: number }> => {
+  const active = getActiveJobs();
+  if (active.length === 0) {
+    return { cancelled: 0, unfinished: 0 };
+  }
+  appLogger.info("Cancelling background jobs for shutdown", {
+    jobs: active.map((job) => ({ id: job.id, type: job.type, status: job.status })),
+  });
+  for (const job of active) {
+    cancelJob(job.id);
+  }
+
+  let timer: NodeJS.Timeout | undefined;
+  const deadline = new Promise<"timeout">((resolve) => {
+    timer = setTimeout(() => resolve("timeout"), graceMs);
+  });
+  await Promise.race([
+    Promise.all(active.map((job) => waitForJob(job.id))),
+    deadline,
+  ]);
+  clearTimeout(timer);
+
+  return { cancelled: active.length, unfinished: getActiveJobs().length };
+};
+
+/**
+ * Completes or rolls back in-flight work. Each step is independent so one
+ * failure never prevents the rest from running.
+ */
+export async function runShutdown(
+  graceMs: number = SHUTDOWN_GRACE_MS
+): Promise<ShutdownSummary> {
+  const summary: ShutdownSummary = {
+    cancelledJobs: 0,
+    unfinishedJobs: 0,
+    interruptedEntryIds: [],
+    browsersClosed: 0,
+  };
+
+  try {
+    stopScheduler();
+  } catch (err: unknown) {
+    appLogger.warn("Could not stop scheduler", { error: errorMessage(err) });
+  }
+
+  // Capture in_progress entries before cancellation resets them
+  let interruptedEntryIds: number[] = [];
+  try {
+    interruptedEntryIds = getInProgressTimesheetEntryIds();
+  } catch (err: unknown) {
+    appLogger.warn("Could not read in-progress entries", {
+      error: errorMessage(err),
+    });
+  }
+
+  try {
+    const { cancelled, unfinished } = await cancelActiveJobs(graceMs);
+    summary.cancelledJobs = cancelled;
+    summary.unfinishedJobs = unfinished;
+    if (isTimesheetSubmissionInProgress()) {
+      cancelTimesheetSubmission();
+    }
+  } catch (err: unknown) {
+    appLogger.error("Could not cancel background jobs", {
+      error: errorMessage(err),
+    });
+  }
+
+  try {
+    recordInterruptedSubmission(interruptedEntryIds);
+    summary.interruptedEntryIds = interruptedEntryIds;
+  } catch (err: unknown) {
+    appLogger.error("Could not record interrupted submission", {
+      error: errorMessage(err),
+    });
+  }
+
+  try {
+    summary.browsersClosed = await closeAllBrowsers();
+  } catch (err: unknown) {
+    appLogger.warn("Could not close browsers", { error: errorMessage(err) });
+  }
+
+  try {
+    flushDirtyDrafts();
+  } catch (err: unknown) {
+    appLogger.error("Could not save pending draft edits", {
+      error: errorMessage(err),
+    });
+  }
+
+  appLogger.info("Shutdown complete", { ...summary });
+
+  try {
+    shutdownDatabase();
+  } catch (err: unknown) {
+    appLogger.warn("Could not close database", { error: errorMessage(err) });
+  }
+  flushLogging();
+  return summary;
+}
+
+/**
+ * Hooks the coordinator into Electron's quit sequence. The first quit is
+ * deferred until shutdown finishes; the app then quits again for real.
+ */
+export function registerShutdownCoordinator(app: App): void {
+  let state: "idle" | "running" | "done" = "idle";
+
+  app.on("before-quit", (event) => {
+    if (state === "done") {
+      return;
+    }
+    event.preventDefault();
+    if (state === "running") {
+      return;
+    }
+    state = "running";
+    appLogger.info("Application quit requested; shutting down");
+    void runShutdown()
+      .catch((err: unknown) => {
+        appLogger.error("Shutdown failed", { error: errorMessage(err) });
+      })
+      .finally(() => {
+        state = "done";
+        app.quit();
+      });
+  });
+}
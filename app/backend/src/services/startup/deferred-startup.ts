@@ -0,0 +1,129 @@
+/**
+ * @fileoverview Deferred Startup Tasks
+ *
+ * Housekeeping that used to delay the first window now runs in the
+ * background once the window is shown: recovering a submission cut off by a
+ * crash, checking the system clock, and deleting old log files and run
+ * artifacts. Each task is timed as a deferred
+ * startup phase and failures are logged without affecting the app.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import type { App, BrowserWindow } from "electron";
+import { appLogger } from "@sheetpilot/shared/logger";
+import { getInProgressTimesheetEntryIds } from "@/models";
+import { recordInterruptedSubmission } from "@/services/shutdown/shutdown-coordinator";
+import { recoverFromSubmissionJournal } from "@/services/timesheet/submission-journal";
+import { isTimesheetSubmissionInProgress } from "@/services/timesheet/submission-workflow";
+import { isLogFileName } from "@/services/logs/log-indexer";
+import { checkClockSkew } from "@/services/health/clock-skew";
+import {
+  cleanupOldRunArtifacts,
+  getRunArtifactDirectory,
+} from "@/services/runs/run-artifacts";
+import { markWindowShown, timeStartupPhase } from "./startup-timings";
+
+/** Log files older than this are deleted (the newest file is always kept) */
+export const LOG_RETENTION_DAYS = 30;
+
+/**
+ * Entries still in_progress at startup were left by a crash or forced exit.
+ * Those the submission journal shows were submitted are marked Complete; the
+ * rest are recorded as an interrupted submission and returned to pending.
+ *
+ * @returns IDs of the entries returned to pending
+ */
+export function recoverInterruptedSubmission(): number[] {
+  if (isTimesheetSubmissionInProgress()) {
+    return [];
+  }
+  recoverFromSubmissionJournal();
+  const entryIds = getInProgressTimesheetEntryIds();
+  recordInterruptedSubmission(entryIds);
+  return entryIds;
+}
+
+/**
+ * Deletes sheetpilot_*.log files last modified before the retention window
+ *
+ * @returns Names of the deleted files
+ */
+export async function cleanupOldLogFiles(
+  logDir: string,
+  now: Date = new Date(),
+  retentionDays: number = LOG_RETENTION_DAYS
+): Promise<string[]> {
+  const cutoff = now.getTime() - retentionDays * 24 * 60 * 60 * 1000;
+  const logFiles = (await fs.promises.readdir(logDir)).filter(isLogFileName).sort();
+  // Never delete the file currently being written
+  logFiles.pop();
+
+  const deleted: string[] = [];
+  for (const file of logFiles) {
+    const filePath = path.join(logDir, file);
+    try {
+      const { mtimeMs } = await fs.promises.stat(filePath);
+      if (mtimeMs < cutoff) {
+        await fs.promises.unlink(filePath);
+        deleted.push(file);
+      }
+    } catch (err: unknown) {
+      appLogger.warn("Could not delete old log file", {
+        file,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+  return deleted;
+}
+
+/**
+ * Runs every deferred startup task in sequence
+ */
+export async function runDeferredStartupTasks(app: App): Promise<void> {
+  const tasks: Array<[name: string, run: () => unknown]> = [
+    ["recover-interrupted-submission", () => recoverInterruptedSubmission()],
+    ["check-clock-skew", () => checkClockSkew()],
+    ["cleanup-old-logs", () => cleanupOldLogFiles(app.getPath("userData"))],
+    [
+      "cleanup-old-run-artifacts",
+      () => cleanupOldRunArtifacts(getRunArtifactDirectory(app.getPath("userData"))),
+    ],
+  ];
+
+  for (const [name, run] of tasks) {
+    try {
+      const result = await timeStartupPhase(`deferred:${name}`, run, { deferred: true });
+      appLogger.verbose("Deferred startup task completed", { name, result });
+    } catch (err: unknown) {
+      appLogger.error("Deferred startup task failed", {
+        name,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+}
+
+/**
+ * Starts deferred startup tasks once the main window is first shown
+ */
+export function scheduleDeferredStartupTasks(app: App, window: BrowserWindow): void {
+  const start = (): void => {
+    markWindowShown();
+    // Let the window paint before doing background work
+    setImmediate(() => {
+      void runDeferredStartupTasks(app);
+    });
+  };
+
+  if (window.isVisible()) {
+    start();
+    return;
+  }
+  window.once("show", start);
+}
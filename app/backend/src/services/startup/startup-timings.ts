@@ -0,0 +1,116 @@
+/**
+ * @fileoverview Startup Timings
+ *
+ * Records how long each startup phase takes, measured from process start,
+ * so slow cold starts can be diagnosed from the running app.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { performance } from "perf_hooks";
+
+export interface StartupPhaseTiming {
+  name: string;
+  /** Milliseconds after process start when the phase began */
+  startMs: number;
+  durationMs: number;
+  /** Deferred phases run in the background after the window is shown */
+  deferred: boolean;
+  status: "ok" | "failed";
+}
+
+export interface StartupTimings {
+  phases: StartupPhaseTiming[];
+  /** Milliseconds after process start when the main window was first shown */
+  windowShownMs: number | null;
+  /** Milliseconds after process start when the last deferred phase finished */
+  deferredCompletedMs: number | null;
+}
+
+const phases: StartupPhaseTiming[] = [];
+let windowShownMs: number | null = null;
+let deferredCompletedMs: number | null = null;
+
+const round = (value: number): number => Math.round(value * 10) / 10;
+
+const recordPhase = (
+  name: string,
+  startMs: number,
+  deferred: boolean,
+  status: "ok" | "failed"
+): void => {
+  const endMs = performance.now();
+  phases.push({
+    name,
+    startMs: round(startMs),
+    durationMs: round(endMs - startMs),
+    deferred,
+    status,
+  });
+  if (deferred) {
+    deferredCompletedMs = round(endMs);
+  }
+};
+
+/**
+ * Times a synchronous or async startup phase (errors are recorded and rethrown)
+ */
+export function timeStartupPhase<T>(
+  name: string,
+  fn: () => T,
+  options: { deferred?: boolean } = {}
+): T {
+  const deferred = options.deferred ?? false;
+  const startMs = performance.now();
+  let result: T;
+  try {
+    result = fn();
+  } catch (err: unknown) {
+    recordPhase(name, startMs, deferred, "failed");
+    throw err;
+  }
+
+  if (result instanceof Promise) {
+    return result.then(
+      (value: unknown) => {
+        recordPhase(name, startMs, deferred, "ok");
+        return value;
+      },
+      (err: unknown) => {
+        recordPhase(name, startMs, deferred, "failed");
+        throw err;
+      }
+    ) as T;
+  }
+
+  recordPhase(name, startMs, deferred, "ok");
+  return result;
+}
+
+/**
+ * Records the first time the main window became visible
+ */
+export function markWindowShown(): void {
+  if (windowShownMs === null) {
+    windowShownMs = round(performance.now());
+  }
+}
+
+export function getStartupTimings(): StartupTimings {
+  return {
+    phases: phases.map((phase) => ({ ...phase })),
+    windowShownMs,
+    deferredCompletedMs,
+  };
+}
+
+/**
+ * Clears recorded timings (tests only)
+ */
+export function resetStartupTimings(): void {
+  phases.length = 0;
+  windowShownMs = null;
+  deferredCompletedMs = null;
+}
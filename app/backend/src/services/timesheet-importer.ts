@@ -10,24 +10,38 @@
  */
 
 import {
+  assignSubmissionKeys,
   ensureSchema,
   getPendingTimesheetEntries,
   markTimesheetEntriesAsInProgress,
   markTimesheetEntriesAsSubmitted,
   removeFailedTimesheetEntries,
+  recordTimesheetFailureReasons,
+  recordSubmissionCaptures,
   getTimesheetEntriesByIds,
   resetInProgressTimesheetEntries,
   resetTimesheetEntriesStatus,
 } from "@/models";
 import { botLogger } from "@sheetpilot/shared/logger";
-import { getSubmissionService } from "@/middleware/bootstrap-plugins";
+import { getEntryKindRules, getSubmissionService } from "@/middleware/bootstrap-plugins";
+import { loadSettings } from "@/services/settings/settings-store";
+import {
+  clearSubmissionJournal,
+  writeSubmissionJournal,
+} from "@/services/timesheet/submission-journal";
+import {
+  describeWithExternalRef,
+  resolveExternalRefRule,
+  type ExternalRefRule,
+} from "@/logic/external-ref";
+import { planEntryKindSubmission, type EntryKindPlan } from "@/logic/entry-kinds";
 import type {
   TimesheetEntry,
   Credentials,
   SubmissionResult,
   ISubmissionService,
 } from "@sheetpilot/shared";
-import { normalizeDateToISO } from "@sheetpilot/shared";
+import { convertDateToUSFormat, normalizeDateToISO } from "@sheetpilot/shared";
 // Dynamic import to avoid top-level async operations during module loading
 
 /**
@@ -43,8 +57,14 @@ type DbRow = {
   task_description: string;
   status?: string | null;
   submitted_at?: string | null;
+  submission_key?: string | null;
+  amends_id?: number | null;
+  external_ref?: string | null;
+  entry_kind?: string | null;
 };
 
+type SubmittedAs = Extract<EntryKindPlan, { action: "submit" }>;
+
 type SubmissionTimer = ReturnType<typeof botLogger.startTimer>;
 
 /**
@@ -56,7 +76,12 @@ export type { SubmissionResult } from "@sheetpilot/shared";
 /**
  * Converts database row format to TimesheetEntry format
  */
-function toTimesheetEntry(dbRow: DbRow): TimesheetEntry {
+function toTimesheetEntry(
+  dbRow: DbRow,
+  correctionOf: string | null = null,
+  externalRefRule: ExternalRefRule | null = null,
+  submittedAs: SubmittedAs | null = null
+): TimesheetEntry {
   // Convert date from MM/DD/YYYY to YYYY-MM-DD format for quarter matching
   const dateStr = normalizeDateToISO(dbRow.date);
 
@@ -64,13 +89,74 @@ function toTimesheetEntry(dbRow: DbRow): TimesheetEntry {
     id: dbRow.id,
     date: dateStr,
     hours: dbRow.hours ?? 0,
-    project: dbRow.project,
-    tool: dbRow.tool ?? null,
-    chargeCode: dbRow.detail_charge_code ?? null,
-    taskDescription: dbRow.task_description,
+    project: submittedAs?.project ?? dbRow.project,
+    tool: submittedAs ? submittedAs.tool : dbRow.tool ?? null,
+    chargeCode: submittedAs ? submittedAs.chargeCode : dbRow.detail_charge_code ?? null,
+    taskDescription: externalRefRule
+      ? describeWithExternalRef(dbRow.task_description, dbRow.external_ref, externalRefRule)
+      : dbRow.task_description,
+    submissionKey: dbRow.submission_key ?? null,
+    correctionOf,
   };
 }
 
+/**
+ * Splits pending rows by the entry kind rules of the active service profile
+ */
+function planPendingEntries(dbRows: DbRow[]): {
+  submitted: DbRow[];
+  submittedAs: Map<number, SubmittedAs>;
+  excluded: Array<{ id: number; reason: string }>;
+} {
+  const rules = getEntryKindRules();
+  const submitted: DbRow[] = [];
+  const submittedAs = new Map<number, SubmittedAs>();
+  const excluded: Array<{ id: number; reason: string }> = [];
+  for (const row of dbRows) {
+    const plan = planEntryKindSubmission(row, rules);
+    if (plan.action === "exclude") {
+      excluded.push({ id: row.id, reason: plan.reason });
+      continue;
+    }
+    submitted.push(row);
+    submittedAs.set(row.id, plan);
+  }
+  return { submitted, submittedAs, excluded };
+}
+
+/**
+ * Looks up the work dates of the submitted originals that amendments correct
+ *
+ * @returns Map of amendment id to the original's date in mm/dd/yyyy format
+ */
+function getCorrectedOriginalDates(dbRows: DbRow[]): Map<number, string> {
+  const amendments = dbRows.filter(
+    (row): row is DbRow & { amends_id: number } =>
+      typeof row.amends_id === "number"
+  );
+  const dates = new Map<number, string>();
+  if (amendments.length === 0) {
+    return dates;
+  }
+  const originals = new Map(
+    (
+      getTimesheetEntriesByIds(
+        amendments.map((row) => row.amends_id)
+      ) as DbRow[]
+    ).map((row) => [row.id, row.date])
+  );
+  for (const row of amendments) {
+    const originalDate = originals.get(row.amends_id);
+    if (originalDate) {
+      dates.set(
+        row.id,
+        convertDateToUSFormat(normalizeDateToISO(originalDate))
+      );
+    }
+  }
+  return dates;
+}
+
 const buildEmptySubmissionResult = (): SubmissionResult => ({
   ok: true,
   submittedIds: [],
@@ -132,6 +218,36 @@ const handleAbortBeforeStart = (
   return buildFailureResult(dbRowCount, "Submission was cancelled");
 };
 
+/**
+ * Writes each entry's outcome to the submission journal so a crash before
+ * the database update cannot turn submitted entries back into drafts
+ */
+const journalSubmissionOutcomes = (
+  result: SubmissionResult,
+  entryIds: number[],
+  submissionKeys: Map<number, string>
+): void => {
+  const submitted = new Set(result.submittedIds ?? []);
+  const removed = new Set(result.removedIds ?? []);
+  try {
+    writeSubmissionJournal(
+      entryIds
+        .filter((id) => submitted.has(id) || removed.has(id))
+        .map((id) => ({
+          id,
+          submissionKey: submissionKeys.get(id) ?? null,
+          outcome: submitted.has(id) ? "submitted" : "failed",
+        }))
+    );
+  } catch (journalError) {
+    // Recovery falls back to treating the entries as interrupted
+    botLogger.warn("Could not write submission journal", {
+      error:
+        journalError instanceof Error ? journalError.message : String(journalError),
+    });
+  }
+};
+
 const handleSubmittedEntriesUpdate = (
   submittedIds: number[],
   dbRowCount: number,
@@ -206,6 +322,53 @@ const removeFailedEntries = (removedIds: number[]): void => {
   }
 };
 
+const recordCapturedValues = (
+  capturedValues: SubmissionResult["capturedValues"]
+): void => {
+  if (!capturedValues || capturedValues.length === 0) {
+    return;
+  }
+  try {
+    recordSubmissionCaptures(
+      capturedValues.map((c) => ({ id: c.entryId, values: c.values }))
+    );
+  } catch (captureError) {
+    // The entries are submitted; losing the captured IDs is not fatal
+    botLogger.warn("Could not store submission captures on entries", {
+      error:
+        captureError instanceof Error
+          ? captureError.message
+          : String(captureError),
+      count: capturedValues.length,
+    });
+  }
+};
+
+const recordRejectionReasons = (
+  rejectedEntries: SubmissionResult["rejectedEntries"]
+): void => {
+  if (!rejectedEntries || rejectedEntries.length === 0) {
+    return;
+  }
+  botLogger.warn("Smartsheet rejected entries with validation errors", {
+    entries: rejectedEntries.map((r) => ({
+      id: r.entryId,
+      fields: Object.keys(r.fieldErrors),
+    })),
+  });
+  try {
+    recordTimesheetFailureReasons(
+      rejectedEntries.map((r) => ({ id: r.entryId, reason: r.message }))
+    );
+  } catch (recordError) {
+    botLogger.error("Could not record failure reasons on rejected entries", {
+      error:
+        recordError instanceof Error ? recordError.message : String(recordError),
+      count: rejectedEntries.length,
+    });
+  }
+};
+
 const finalizeSubmission = (
   result: SubmissionResult,
   timer: SubmissionTimer
@@ -296,11 +459,20 @@ export async function submitTimesheets(
   // Ensure database schema is up to date
   ensureSchema();
 
-  // Fetch pending rows from database
-  const dbRows = getPendingTimesheetEntries() as DbRow[];
+  // Fetch pending rows from database; PTO, holiday and training entries are
+  // mapped or left out as the service profile says
+  const { submitted: dbRows, submittedAs, excluded } = planPendingEntries(
+    getPendingTimesheetEntries() as DbRow[]
+  );
   botLogger.verbose("Pending timesheet entries retrieved", {
     count: dbRows.length,
+    excluded: excluded.length,
   });
+  if (excluded.length > 0) {
+    botLogger.info("Some entries are not submitted for this service and stay as drafts", {
+      entries: excluded,
+    });
+  }
   botLogger.debug("Pending entry details", {
     entries: dbRows.map((r) => ({ id: r.id, date: r.date, status: r.status })),
   });
@@ -316,8 +488,36 @@ export async function submitTimesheets(
   markTimesheetEntriesAsInProgress(entryIds);
   botLogger.info("Entries marked as in-progress", { count: entryIds.length });
 
-  // Convert database rows to TimesheetEntry format
-  const entries = dbRows.map(toTimesheetEntry);
+  // Give each entry a stable idempotency key before anything reaches the form.
+  // Entries that already have one were attempted before and may have landed.
+  const { keys: submissionKeys, retriedIds } = assignSubmissionKeys(entryIds);
+  if (retriedIds.length > 0) {
+    botLogger.warn("Some entries were attempted before; reconcile by submission key if duplicates appear", {
+      ids: retriedIds,
+    });
+  }
+
+  // Convert database rows to TimesheetEntry format; amendments are sent as
+  // corrections of the original they replace
+  const correctedDates = getCorrectedOriginalDates(dbRows);
+  const { rule: externalRefRule, error: externalRefError } = resolveExternalRefRule(
+    loadSettings().external_ref
+  );
+  if (externalRefError) {
+    botLogger.warn("Ignoring external reference pattern", { error: externalRefError });
+  }
+  const entries = dbRows.map((row) =>
+    toTimesheetEntry(
+      {
+        ...row,
+        submission_key:
+          submissionKeys.get(row.id) ?? row.submission_key ?? null,
+      },
+      correctedDates.get(row.id) ?? null,
+      externalRefRule,
+      submittedAs.get(row.id) ?? null
+    )
+  );
   botLogger.verbose("Converted entries for submission", {
     count: entries.length,
   });
@@ -365,6 +565,8 @@ export async function submitTimesheets(
       removedIds: result.removedIds,
     });
 
+    journalSubmissionOutcomes(result, entryIds, submissionKeys);
+
     // Update database based on results
     const submittedIds = result.submittedIds ?? [];
     const updateFailureResult = handleSubmittedEntriesUpdate(
@@ -373,10 +575,20 @@ export async function submitTimesheets(
       timer
     );
     if (updateFailureResult) {
+      // The journal is kept so the next start marks these entries Complete
       return updateFailureResult;
     }
+    try {
+      clearSubmissionJournal();
+    } catch (clearError) {
+      botLogger.warn("Could not remove submission journal", {
+        error: clearError instanceof Error ? clearError.message : String(clearError),
+      });
+    }
+    recordCapturedValues(result.capturedValues);
 
     removeFailedEntries(result.removedIds ?? []);
+    recordRejectionReasons(result.rejectedEntries);
 
     return finalizeSubmission(result, timer);
   } catch (error) {
@@ -0,0 +1,74 @@
+/**
+ * @fileoverview App Usage Lock
+ *
+ * The bot loads pending entries when a submission starts and works from
+ * that copy, so editing drafts mid-run can leave the database and Smartsheet
+ * disagreeing about what was submitted. While a submission runs the app is
+ * locked: draft-mutating handlers refuse with a `SubmissionLockedError`
+ * (code SUBMISSION_LOCKED) instead of writing.
+ *
+ * The lock is released only by the run itself: when it completes
+ * (successfully or not) or is recovered (cancelled or timed out). Resetting
+ * in-progress entries by hand is refused while a run is active. The lock
+ * lives in memory only, so a crash never leaves the app locked.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { SubmissionLockedError } from '@sheetpilot/shared/errors';
+import { getMessage } from '@/services/i18n/message-catalog';
+
+export type AppLockReason = 'submission';
+
+export type AppLockRelease = 'completed' | 'recovered';
+
+export interface AppLockState {
+  locked: boolean;
+  reason: AppLockReason | null;
+  /** ISO timestamp of when the lock was taken */
+  since: string | null;
+  /** Run history ID of the submission holding the lock */
+  runId: number | null;
+}
+
+const UNLOCKED: AppLockState = { locked: false, reason: null, since: null, runId: null };
+
+let state: AppLockState = { ...UNLOCKED };
+
+export function getAppLockState(): AppLockState {
+  return { ...state };
+}
+
+/**
+ * Locks the app for a submission run
+ */
+export function acquireSubmissionLock(runId: number | null = null): void {
+  state = { locked: true, reason: 'submission', since: new Date().toISOString(), runId };
+  ipcLogger.info('App locked for submission', { runId });
+}
+
+/**
+ * Unlocks the app; does nothing if it is not locked
+ */
+export function releaseSubmissionLock(how: AppLockRelease): void {
+  if (!state.locked) {
+    return;
+  }
+  const heldMs = state.since ? Date.now() - new Date(state.since).getTime() : 0;
+  ipcLogger.info('App lock released', { how, runId: state.runId, heldMs });
+  state = { ...UNLOCKED };
+}
+
+/**
+ * Error to return from a mutating handler while the app is locked, or null
+ */
+export function getAppLockError(action: string): SubmissionLockedError | null {
+  if (!state.locked) {
+    return null;
+  }
+  ipcLogger.warn('Change refused while app is locked', { action, runId: state.runId });
+  return new SubmissionLockedError(getMessage('SUBMISSION_LOCKED'), { action, runId: state.runId });
+}
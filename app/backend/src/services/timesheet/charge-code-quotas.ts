@@ -0,0 +1,81 @@
+/**
+ * @fileoverview Charge Code Quota Settings
+ *
+ * Quotas are stored in settings.json under charge_code_quotas and can be
+ * replaced with settings:set; hand-edited entries that do not validate, or
+ * that name a quarter this build does not know, are skipped.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { getQuarterById } from '@sheetpilot/bot';
+import { getPendingTimesheetEntries, getSubmittedChargeCodeHours } from '@/models';
+import { loadSettings } from '@/services/settings/settings-store';
+import { chargeCodeQuotaSchema } from '@/validation/ipc-schemas';
+import {
+  buildQuotaStatus,
+  chargeCodeKey,
+  getQuotaWarnings,
+  type ChargeCodeQuota,
+  type ChargeCodeQuotaStatus,
+  type QuotaWarning
+} from '@/logic/charge-code-quotas';
+
+/**
+ * Valid quotas from settings; a later duplicate (same charge code and
+ * quarter) replaces an earlier one
+ */
+export function listChargeCodeQuotas(): ChargeCodeQuota[] {
+  const stored = loadSettings().charge_code_quotas;
+  const quotas = new Map<string, ChargeCodeQuota>();
+  for (const candidate of Array.isArray(stored) ? stored : []) {
+    const parsed = chargeCodeQuotaSchema.safeParse(candidate);
+    if (!parsed.success || !getQuarterById(parsed.data.quarterId)) {
+      ipcLogger.warn('Ignoring invalid charge code quota in settings', {
+        chargeCode: (candidate as { chargeCode?: unknown })?.chargeCode,
+        quarterId: (candidate as { quarterId?: unknown })?.quarterId
+      });
+      continue;
+    }
+    quotas.set(`${chargeCodeKey(parsed.data.chargeCode)}|${parsed.data.quarterId}`, parsed.data);
+  }
+  return [...quotas.values()];
+}
+
+const getSubmittedHours = (quota: ChargeCodeQuota): number => {
+  const quarter = getQuarterById(quota.quarterId);
+  return quarter ? getSubmittedChargeCodeHours(quota.chargeCode, quarter.startDate, quarter.endDate) : 0;
+};
+
+/**
+ * Consumption of every configured quota, counting pending drafts
+ *
+ * @param pendingEntries - Drafts to count (defaults to all pending entries)
+ */
+export function getChargeCodeQuotaStatus(
+  pendingEntries: Parameters<typeof buildQuotaStatus>[2] = getPendingTimesheetEntries()
+): ChargeCodeQuotaStatus[] {
+  return buildQuotaStatus(listChargeCodeQuotas(), getSubmittedHours, pendingEntries);
+}
+
+/**
+ * Warnings for quotas the pending entries would exceed
+ */
+export function getChargeCodeQuotaWarnings(
+  pendingEntries: Parameters<typeof buildQuotaStatus>[2]
+): QuotaWarning[] {
+  const quotas = listChargeCodeQuotas();
+  if (quotas.length === 0) {
+    return [];
+  }
+  const warnings = getQuotaWarnings(buildQuotaStatus(quotas, getSubmittedHours, pendingEntries), pendingEntries);
+  if (warnings.length > 0) {
+    ipcLogger.info('Pending entries would exceed charge code quotas', {
+      quotas: warnings.map((w) => ({ chargeCode: w.chargeCode, quarterId: w.quarterId, projectedHours: w.projectedHours }))
+    });
+  }
+  return warnings;
+}
@@ -0,0 +1,80 @@
+/**
+ * @fileoverview Clipboard Export
+ *
+ * Builds a short TSV, CSV or Markdown snippet of entries for pasting a day's
+ * work into chat or email. Uses the same columns as file exports, so export
+ * templates apply here too.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { formatHours } from "@sheetpilot/shared";
+import {
+  buildTimesheetCsv,
+  getExportHeaders,
+  toExportCells,
+  type ExportableTimesheetEntry,
+  type ExportTemplate,
+} from "./csv-export";
+
+export type ClipboardFormat = "tsv" | "csv" | "markdown";
+
+/** Columns used when no template is chosen (no submission bookkeeping) */
+export const CLIPBOARD_DEFAULT_TEMPLATE: ExportTemplate = {
+  name: "Clipboard",
+  columns: [
+    { id: "date" },
+    { id: "hours" },
+    { id: "project" },
+    { id: "tool" },
+    { id: "chargeCode" },
+    { id: "taskDescription" },
+  ],
+};
+
+/** Tabs and line breaks would split cells when pasted into a spreadsheet */
+const toTsvCell = (value: string): string => value.replace(/[\t\r\n]+/g, " ");
+
+const toMarkdownCell = (value: string): string =>
+  value.replace(/\|/g, "\\|").replace(/[\t\r\n]+/g, " ");
+
+const buildMarkdownTable = (
+  headers: string[],
+  rows: string[][],
+  totalHours: number
+): string => {
+  const line = (cells: string[]): string =>
+    `| ${cells.map(toMarkdownCell).join(" | ")} |`;
+  return [
+    line(headers),
+    `|${headers.map(() => " --- ").join("|")}|`,
+    ...rows.map(line),
+    "",
+    `**Total: ${formatHours(totalHours)} hours**`,
+  ].join("\n");
+};
+
+/**
+ * Builds the clipboard snippet for entries (header row included)
+ */
+export function buildClipboardSnippet(
+  entries: ExportableTimesheetEntry[],
+  format: ClipboardFormat,
+  template: ExportTemplate = CLIPBOARD_DEFAULT_TEMPLATE
+): string {
+  if (format === "csv") {
+    return buildTimesheetCsv(entries, template);
+  }
+
+  const headers = getExportHeaders(template);
+  const rows = entries.map((entry) => toExportCells(entry, template));
+  if (format === "markdown") {
+    const totalHours = entries.reduce((sum, entry) => sum + (entry.hours ?? 0), 0);
+    return buildMarkdownTable(headers, rows, totalHours);
+  }
+  return [headers, ...rows]
+    .map((cells) => cells.map(toTsvCell).join("\t"))
+    .join("\n");
+}
@@ -0,0 +1,273 @@
+/**
+ * @fileoverview Timesheet CSV Export
+ *
+ * Builds CSV content for submitted timesheet entries and report aggregates.
+ * Shared by the interactive export commands and scheduled exports.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { formatHours } from "@sheetpilot/shared";
+
+export interface ExportableTimesheetEntry {
+  date: string;
+  hours: number | null;
+  project: string;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+  task_description: string;
+  external_ref?: string | null;
+  category?: string | null;
+  status?: string | null;
+  submitted_at?: string | null;
+  id?: number;
+  amends_id?: number | null;
+  /** Id of the submitted correction that replaces this entry, if any */
+  superseded_by?: number | null;
+}
+
+const quote = (value: string): string => `"${value.replace(/"/g, '""')}"`;
+
+const formatOptionalHours = (hours: number | null | undefined): string =>
+  hours !== null && hours !== undefined ? formatHours(hours) : "";
+
+/**
+ * Hours an entry contributes once corrections are applied
+ *
+ * A superseded original counts for nothing; its correction carries the hours.
+ */
+export function getCorrectedHours(entry: ExportableTimesheetEntry): number {
+  if (entry.superseded_by !== null && entry.superseded_by !== undefined) {
+    return 0;
+  }
+  return entry.hours ?? 0;
+}
+
+const describeCorrection = (entry: ExportableTimesheetEntry): string => {
+  if (entry.superseded_by !== null && entry.superseded_by !== undefined) {
+    return `Superseded by #${entry.superseded_by}`;
+  }
+  if (entry.amends_id !== null && entry.amends_id !== undefined) {
+    return `Corrects #${entry.amends_id}`;
+  }
+  return "";
+};
+
+export interface CorrectedTotals {
+  /** Sum of every submitted entry, as originally reported */
+  submittedHours: number;
+  /** Sum after superseded originals are replaced by their corrections */
+  correctedHours: number;
+  correctionCount: number;
+}
+
+/**
+ * Totals for an export, before and after corrections
+ */
+export function summarizeCorrectedTotals(
+  entries: ExportableTimesheetEntry[]
+): CorrectedTotals {
+  let submittedHours = 0;
+  let correctedHours = 0;
+  let correctionCount = 0;
+  for (const entry of entries) {
+    submittedHours += entry.hours ?? 0;
+    correctedHours += getCorrectedHours(entry);
+    if (entry.amends_id !== null && entry.amends_id !== undefined) {
+      correctionCount++;
+    }
+  }
+  return { submittedHours, correctedHours, correctionCount };
+}
+
+export type ExportColumnId =
+  | "date"
+  | "hours"
+  | "project"
+  | "tool"
+  | "chargeCode"
+  | "taskDescription"
+  | "externalRef"
+  | "category"
+  | "status"
+  | "submittedAt"
+  | "correction"
+  | "correctedHours";
+
+interface ExportColumnDefinition {
+  id: ExportColumnId;
+  header: string;
+  /** Written as a number in XLSX */
+  numeric?: boolean;
+  /** Always quoted in CSV (free text) */
+  quoted?: boolean;
+  value: (entry: ExportableTimesheetEntry) => string;
+}
+
+/**
+ * Every exportable column, in the default export order
+ */
+export const EXPORT_COLUMNS: readonly ExportColumnDefinition[] = [
+  { id: "date", header: "Date", value: (entry) => entry.date },
+  {
+    id: "hours",
+    header: "Hours",
+    numeric: true,
+    value: (entry) => formatOptionalHours(entry.hours),
+  },
+  { id: "project", header: "Project", quoted: true, value: (entry) => entry.project },
+  { id: "tool", header: "Tool", quoted: true, value: (entry) => entry.tool || "" },
+  {
+    id: "chargeCode",
+    header: "Charge Code",
+    quoted: true,
+    value: (entry) => entry.detail_charge_code || "",
+  },
+  {
+    id: "taskDescription",
+    header: "Task Description",
+    quoted: true,
+    value: (entry) => entry.task_description,
+  },
+  {
+    id: "externalRef",
+    header: "External Ref",
+    quoted: true,
+    value: (entry) => entry.external_ref || "",
+  },
+  {
+    id: "category",
+    header: "Category",
+    quoted: true,
+    value: (entry) => entry.category || "",
+  },
+  { id: "status", header: "Status", value: (entry) => entry.status ?? "" },
+  { id: "submittedAt", header: "Submitted At", value: (entry) => entry.submitted_at ?? "" },
+  { id: "correction", header: "Correction", quoted: true, value: describeCorrection },
+  {
+    id: "correctedHours",
+    header: "Corrected Hours",
+    numeric: true,
+    value: (entry) => formatOptionalHours(getCorrectedHours(entry)),
+  },
+];
+
+export const EXPORT_COLUMN_IDS = EXPORT_COLUMNS.map((column) => column.id);
+
+export const TIMESHEET_EXPORT_HEADERS = EXPORT_COLUMNS.map((column) => column.header);
+
+/**
+ * Column selection, order and header names for an export
+ */
+export interface ExportTemplate {
+  name: string;
+  columns: Array<{ id: ExportColumnId; header?: string | undefined }>;
+}
+
+interface ResolvedColumn {
+  definition: ExportColumnDefinition;
+  header: string;
+}
+
+const COLUMNS_BY_ID = new Map(EXPORT_COLUMNS.map((column) => [column.id, column]));
+
+const resolveColumns = (template?: ExportTemplate): ResolvedColumn[] => {
+  if (!template) {
+    return EXPORT_COLUMNS.map((definition) => ({ definition, header: definition.header }));
+  }
+  return template.columns.map(({ id, header }) => {
+    const definition = COLUMNS_BY_ID.get(id);
+    if (!definition) {
+      throw new Error(`Unknown export column "${id}" in template "${template.name}"`);
+    }
+    return { definition, header: header?.trim() || definition.header };
+  });
+};
+
+/**
+ * Header row for an export (default columns when no template is given)
+ */
+export function getExportHeaders(template?: ExportTemplate): string[] {
+  return resolveColumns(template).map((column) => column.header);
+}
+
+/**
+ * Converts an entry to its export cell values (unquoted)
+ */
+export function toExportCells(
+  entry: ExportableTimesheetEntry,
+  template?: ExportTemplate
+): string[] {
+  return resolveColumns(template).map(({ definition }) => definition.value(entry));
+}
+
+/**
+ * Converts an entry to spreadsheet cell values, keeping hour columns numeric
+ */
+export function toExportValues(
+  entry: ExportableTimesheetEntry,
+  template?: ExportTemplate
+): Array<string | number> {
+  return resolveColumns(template).map(({ definition }) => {
+    const cell = definition.value(entry);
+    return definition.numeric && cell !== "" ? Number(cell) : cell;
+  });
+}
+
+/**
+ * Builds CSV content (header row + one row per entry)
+ */
+export function buildTimesheetCsv(
+  entries: ExportableTimesheetEntry[],
+  template?: ExportTemplate
+): string {
+  const columns = resolveColumns(template);
+  const csvRows = [
+    // Renamed headers are user text, so quote any that need it
+    columns
+      .map(({ header }) => (/[",\n]/.test(header) ? quote(header) : header))
+      .join(","),
+  ];
+
+  for (const entry of entries) {
+    csvRows.push(
+      columns
+        .map(({ definition }) => {
+          const cell = definition.value(entry);
+          return definition.quoted ? quote(cell) : cell;
+        })
+        .join(",")
+    );
+  }
+
+  return csvRows.join("\n");
+}
+
+export const TOOL_USAGE_EXPORT_HEADERS = ["Project", "Tool", "Hours", "Entries"];
+
+/**
+ * Builds CSV content for a tool usage aggregate
+ */
+export function buildToolUsageCsv(
+  rows: Array<{
+    project: string;
+    tool: string | null;
+    hours: number;
+    entryCount: number;
+  }>
+): string {
+  const csvRows = [TOOL_USAGE_EXPORT_HEADERS.join(",")];
+  for (const row of rows) {
+    csvRows.push(
+      [
+        quote(row.project),
+        quote(row.tool ?? ""),
+        formatHours(row.hours),
+        String(row.entryCount),
+      ].join(",")
+    );
+  }
+  return csvRows.join("\n");
+}
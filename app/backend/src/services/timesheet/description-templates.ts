@@ -0,0 +1,94 @@
+/**
+ * @fileoverview Description Templates
+ *
+ * Named task description templates (see logic/description-templates.ts),
+ * stored in settings.json so a site can ship the same wording to every
+ * technician.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { randomUUID } from 'crypto';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { loadSettings, saveSettings } from '@/services/settings/settings-store';
+import { descriptionTemplateSchema, type DescriptionTemplateInput } from '@/validation/ipc-schemas';
+import {
+  renderDescriptionTemplate,
+  type DescriptionTemplate,
+  type DescriptionTemplateVars,
+  type RenderedDescription
+} from '@/logic/description-templates';
+
+const sameName = (a: string, b: string): boolean =>
+  a.trim().toLowerCase() === b.trim().toLowerCase();
+
+/**
+ * Templates saved in settings; malformed entries (e.g. hand-edited) are skipped
+ */
+export function listDescriptionTemplates(): DescriptionTemplate[] {
+  const stored = loadSettings().description_templates ?? [];
+  const templates: DescriptionTemplate[] = [];
+  for (const candidate of stored) {
+    const parsed = descriptionTemplateSchema.safeParse(candidate);
+    if (parsed.success && parsed.data.id) {
+      templates.push({ id: parsed.data.id, name: parsed.data.name, template: parsed.data.template });
+    } else {
+      ipcLogger.warn('Ignoring invalid description template in settings', {
+        name: (candidate as { name?: unknown })?.name,
+      });
+    }
+  }
+  return templates;
+}
+
+/**
+ * Adds a template, or replaces the one with the same ID
+ *
+ * @throws Error if the ID is unknown or another template has the same name
+ */
+export function saveDescriptionTemplate(input: DescriptionTemplateInput): DescriptionTemplate {
+  const templates = listDescriptionTemplates();
+  if (input.id && !templates.some((existing) => existing.id === input.id)) {
+    throw new Error(`Description template "${input.id}" not found`);
+  }
+  if (templates.some((existing) => existing.id !== input.id && sameName(existing.name, input.name))) {
+    throw new Error(`A description template named "${input.name}" already exists`);
+  }
+
+  const template: DescriptionTemplate = { id: input.id ?? randomUUID(), name: input.name, template: input.template };
+  const saved = input.id
+    ? templates.map((existing) => (existing.id === template.id ? template : existing))
+    : [...templates, template];
+  saveSettings({ ...loadSettings(), description_templates: saved });
+  return template;
+}
+
+/**
+ * Removes a template by ID
+ *
+ * @returns true if a template was removed
+ */
+export function deleteDescriptionTemplate(id: string): boolean {
+  const templates = listDescriptionTemplates();
+  const remaining = templates.filter((existing) => existing.id !== id);
+  if (remaining.length === templates.length) {
+    return false;
+  }
+  saveSettings({ ...loadSettings(), description_templates: remaining });
+  return true;
+}
+
+/**
+ * Fills in a saved template for a new draft
+ *
+ * @throws Error if no template has the ID
+ */
+export function renderDescription(templateId: string, vars: DescriptionTemplateVars): RenderedDescription {
+  const template = listDescriptionTemplates().find((candidate) => candidate.id === templateId);
+  if (!template) {
+    throw new Error(`Description template "${templateId}" not found`);
+  }
+  return renderDescriptionTemplate(template.template, vars);
+}
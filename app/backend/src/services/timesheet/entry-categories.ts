@@ -0,0 +1,100 @@
+/**
+ * @fileoverview Entry Categories
+ *
+ * The managed category list (see logic/entry-categories.ts), stored in
+ * settings.json. Renaming a category renames it on the entries that use it;
+ * deleting one clears it from them.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { randomUUID } from 'crypto';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { loadSettings, saveSettings } from '@/services/settings/settings-store';
+import { clearEntryCategory, renameEntryCategory } from '@/models';
+import { entryCategorySchema, type EntryCategoryInput } from '@/validation/ipc-schemas';
+import {
+  DEFAULT_ENTRY_CATEGORY_COLOR,
+  sameCategoryName,
+  type EntryCategory
+} from '@/logic/entry-categories';
+
+/**
+ * Categories saved in settings; malformed entries (e.g. hand-edited) are skipped
+ */
+export function listEntryCategories(): EntryCategory[] {
+  const stored = loadSettings().entry_categories ?? [];
+  const categories: EntryCategory[] = [];
+  for (const candidate of stored) {
+    const parsed = entryCategorySchema.safeParse(candidate);
+    if (parsed.success && parsed.data.id) {
+      categories.push({
+        id: parsed.data.id,
+        name: parsed.data.name,
+        color: parsed.data.color ?? DEFAULT_ENTRY_CATEGORY_COLOR,
+      });
+    } else {
+      ipcLogger.warn('Ignoring invalid entry category in settings', {
+        name: (candidate as { name?: unknown })?.name,
+      });
+    }
+  }
+  return categories;
+}
+
+export interface SavedEntryCategory {
+  category: EntryCategory;
+  /** Entries moved to the new name when the category was renamed */
+  renamedEntries: number;
+}
+
+/**
+ * Adds a category, or replaces the one with the same ID
+ *
+ * @throws Error if the ID is unknown or another category has the same name
+ */
+export function saveEntryCategory(input: EntryCategoryInput): SavedEntryCategory {
+  const categories = listEntryCategories();
+  const existing = input.id ? categories.find((candidate) => candidate.id === input.id) : undefined;
+  if (input.id && !existing) {
+    throw new Error(`Category "${input.id}" not found`);
+  }
+  if (categories.some((candidate) => candidate.id !== input.id && sameCategoryName(candidate.name, input.name))) {
+    throw new Error(`A category named "${input.name}" already exists`);
+  }
+
+  const category: EntryCategory = {
+    id: input.id ?? randomUUID(),
+    name: input.name,
+    color: input.color ?? existing?.color ?? DEFAULT_ENTRY_CATEGORY_COLOR,
+  };
+  const renamedEntries = existing && existing.name !== category.name
+    ? renameEntryCategory(existing.name, category.name)
+    : 0;
+  const saved = existing
+    ? categories.map((candidate) => (candidate.id === category.id ? category : candidate))
+    : [...categories, category];
+  saveSettings({ ...loadSettings(), entry_categories: saved });
+  return { category, renamedEntries };
+}
+
+/**
+ * Removes a category by ID and clears it from the entries that use it
+ *
+ * @returns Number of entries cleared, or null if no category has the ID
+ */
+export function deleteEntryCategory(id: string): number | null {
+  const categories = listEntryCategories();
+  const category = categories.find((candidate) => candidate.id === id);
+  if (!category) {
+    return null;
+  }
+  const clearedEntries = clearEntryCategory(category.name);
+  saveSettings({
+    ...loadSettings(),
+    entry_categories: categories.filter((candidate) => candidate.id !== id),
+  });
+  return clearedEntries;
+}
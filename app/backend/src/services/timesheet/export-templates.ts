@@ -0,0 +1,81 @@
+/**
+ * @fileoverview Export Templates
+ *
+ * Named column selections for timesheet exports (e.g. a payroll template
+ * with its own column order and header names), stored in settings.json.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import { loadSettings, saveSettings } from '@/services/settings/settings-store';
+import { exportTemplateSchema } from '@/validation/ipc-schemas';
+import type { ExportTemplate } from './csv-export';
+
+const sameName = (a: string, b: string): boolean =>
+  a.trim().toLowerCase() === b.trim().toLowerCase();
+
+/**
+ * Templates saved in settings; malformed entries (e.g. hand-edited) are skipped
+ */
+export function listExportTemplates(): ExportTemplate[] {
+  const stored = loadSettings().export_templates ?? [];
+  const templates: ExportTemplate[] = [];
+  for (const candidate of stored) {
+    const parsed = exportTemplateSchema.safeParse(candidate);
+    if (parsed.success) {
+      templates.push(parsed.data);
+    } else {
+      ipcLogger.warn('Ignoring invalid export template in settings', {
+        name: (candidate as { name?: unknown })?.name,
+      });
+    }
+  }
+  return templates;
+}
+
+/**
+ * Looks up a template by name (case-insensitive)
+ *
+ * @returns undefined when no name is given (use the default columns)
+ * @throws Error if a name is given but no such template exists
+ */
+export function resolveExportTemplate(name?: string): ExportTemplate | undefined {
+  if (!name) {
+    return undefined;
+  }
+  const template = listExportTemplates().find((candidate) => sameName(candidate.name, name));
+  if (!template) {
+    throw new Error(`Export template "${name}" not found`);
+  }
+  return template;
+}
+
+/**
+ * Adds a template, replacing one with the same name
+ */
+export function saveExportTemplate(template: ExportTemplate): ExportTemplate[] {
+  const templates = listExportTemplates().filter((existing) => !sameName(existing.name, template.name));
+  templates.push(template);
+  const settings = loadSettings();
+  saveSettings({ ...settings, export_templates: templates });
+  return templates;
+}
+
+/**
+ * Removes a template by name
+ *
+ * @returns true if a template was removed
+ */
+export function deleteExportTemplate(name: string): boolean {
+  const templates = listExportTemplates();
+  const remaining = templates.filter((existing) => !sameName(existing.name, name));
+  if (remaining.length === templates.length) {
+    return false;
+  }
+  const settings = loadSettings();
+  saveSettings({ ...settings, export_templates: remaining });
+  return true;
+}
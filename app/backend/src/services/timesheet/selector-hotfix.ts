@@ -0,0 +1,215 @@
+/**
+ * @fileoverview Selector Hotfix Channel
+ *
+ * Lets admins fix a broken Smartsheet selector without shipping a release or
+ * a full config sync. An admin signs a small JSON document (field locators by
+ * field key and extra submit button selectors, see SelectorHotfix in the bot)
+ * with the release Ed25519 key and publishes it at the URL or file share path
+ * in `selector_hotfix.url` in settings.json. The app checks it against
+ * `SELECTOR_HOTFIX_PUBLIC_KEY`, which ships in the build so a changed
+ * settings file cannot swap in another signer. `selector_hotfix` is an
+ * admin-only setting (see ADMIN_ONLY_SETTINGS in settings-store.ts).
+ *
+ * The submission preflight fetches the hotfix and the next run applies it;
+ * a run that was not preflighted recently fetches it again. Every hotfix must
+ * carry an `expiresAt`. A hotfix that is missing, expired, has no expiry, is
+ * malformed or is wrongly signed is never applied: the run
+ * uses the built-in selectors and the preflight reports why.
+ *
+ * Published document:
+ * `{ "payload": "<hotfix JSON as a string>", "signature": "<base64>" }`
+ * where the signature covers the payload string's UTF-8 bytes
+ * (see `signSelectorHotfix`).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import { z } from 'zod';
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import type { SelectorHotfix } from '@sheetpilot/bot';
+import { loadSettings } from '@/services/settings/settings-store';
+
+export interface SelectorHotfixSettings {
+  /** https:// URL, file:// URL or file path of the signed hotfix */
+  url: string;
+}
+
+export interface SelectorHotfixPayload extends SelectorHotfix {
+  /** ISO timestamp after which the hotfix is ignored */
+  expiresAt: string;
+}
+
+export interface SignedSelectorHotfix {
+  payload: string;
+  signature: string;
+}
+
+export interface SelectorHotfixCheck {
+  hotfix: SelectorHotfix | null;
+  /** Why a published hotfix was not applied; null when there was none or it is fine */
+  error: string | null;
+}
+
+/** Ed25519 public key (PEM) every hotfix must be signed with */
+export const SELECTOR_HOTFIX_PUBLIC_KEY = `-----BEGIN PUBLIC KEY-----
+MCowBQYDK2VwAyEA68LZDGQilPKceE6t3S0ZGo473qNu2r4nyStGwsZBefs=
+-----END PUBLIC KEY-----
+`;
+
+/** Preflighted hotfixes older than this are fetched again before a run */
+export const SELECTOR_HOTFIX_MAX_AGE_MS = 10 * 60 * 1000;
+
+const FETCH_TIMEOUT_MS = 5000;
+
+const selectorSchema = z.string().trim().min(1).max(500);
+
+const payloadSchema = z.object({
+  id: z.string().trim().min(1).max(100),
+  expiresAt: z.string({ error: 'Selector hotfix must have an expiresAt' }).datetime({ offset: true }),
+  fields: z.record(z.string().regex(/^[a-z_]+$/, 'Field keys are lowercase with underscores'), selectorSchema).optional(),
+  submitButton: z.array(selectorSchema).max(10).optional()
+});
+
+const signedSchema = z.object({
+  payload: z.string().min(1),
+  signature: z.string().min(1)
+});
+
+let preflighted: { check: SelectorHotfixCheck; fetchedAt: number } | null = null;
+
+/**
+ * Signs a hotfix for publishing (run by an admin with the private key)
+ */
+export function signSelectorHotfix(
+  payload: SelectorHotfixPayload,
+  privateKeyPem: string
+): SignedSelectorHotfix {
+  const text = JSON.stringify(payloadSchema.parse(payload));
+  const signature = crypto.sign(null, Buffer.from(text, 'utf-8'), privateKeyPem);
+  return { payload: text, signature: signature.toString('base64') };
+}
+
+/**
+ * Checks a published hotfix document
+ *
+ * @throws Error if the signature does not match, the payload is malformed or the hotfix has expired
+ */
+export function verifySelectorHotfix(
+  document: unknown,
+  publicKeyPem: string = SELECTOR_HOTFIX_PUBLIC_KEY,
+  now: Date = new Date()
+): SelectorHotfix {
+  const signed = signedSchema.safeParse(document);
+  if (!signed.success) {
+    throw new Error('Selector hotfix is not a signed hotfix document');
+  }
+  const valid = crypto.verify(
+    null,
+    Buffer.from(signed.data.payload, 'utf-8'),
+    publicKeyPem,
+    Buffer.from(signed.data.signature, 'base64')
+  );
+  if (!valid) {
+    throw new Error('Selector hotfix signature is not valid');
+  }
+  let parsedJson: unknown;
+  try {
+    parsedJson = JSON.parse(signed.data.payload);
+  } catch {
+    throw new Error('Selector hotfix payload is not valid JSON');
+  }
+  const payload = payloadSchema.safeParse(parsedJson);
+  if (!payload.success) {
+    throw new Error(`Selector hotfix payload is invalid: ${payload.error.issues[0]?.message ?? 'unknown error'}`);
+  }
+  if (new Date(payload.data.expiresAt) <= now) {
+    throw new Error(`Selector hotfix ${payload.data.id} expired at ${payload.data.expiresAt}`);
+  }
+  const hotfix = payload.data;
+  return {
+    id: hotfix.id,
+    ...(hotfix.fields ? { fields: hotfix.fields } : {}),
+    ...(hotfix.submitButton ? { submitButton: hotfix.submitButton } : {})
+  };
+}
+
+/**
+ * Reads the published document
+ *
+ * @returns Parsed JSON, or null if nothing is published
+ */
+async function readPublishedHotfix(location: string): Promise<unknown> {
+  if (/^https?:\/\//i.test(location)) {
+    const response = await fetch(location, {
+      cache: 'no-store',
+      signal: AbortSignal.timeout(FETCH_TIMEOUT_MS)
+    });
+    if (response.status === 404) return null;
+    if (!response.ok) {
+      throw new Error(`Could not fetch selector hotfix: HTTP ${response.status}`);
+    }
+    return response.json();
+  }
+  const filePath = location.startsWith('file://') ? new URL(location) : location;
+  try {
+    return JSON.parse(await fs.promises.readFile(filePath, 'utf-8'));
+  } catch (err: unknown) {
+    if ((err as NodeJS.ErrnoException).code === 'ENOENT') return null;
+    throw err;
+  }
+}
+
+/**
+ * Fetches and verifies the configured hotfix; never throws
+ */
+export async function checkSelectorHotfix(
+  settings: SelectorHotfixSettings | undefined = loadSettings().selector_hotfix,
+  publicKeyPem: string = SELECTOR_HOTFIX_PUBLIC_KEY
+): Promise<SelectorHotfixCheck> {
+  if (!settings?.url) {
+    return { hotfix: null, error: null };
+  }
+  try {
+    const document = await readPublishedHotfix(settings.url);
+    if (document === null) {
+      return { hotfix: null, error: null };
+    }
+    const hotfix = verifySelectorHotfix(document, publicKeyPem);
+    ipcLogger.info('Selector hotfix verified', {
+      id: hotfix.id,
+      fields: Object.keys(hotfix.fields ?? {}),
+      submitButtonSelectors: hotfix.submitButton?.length ?? 0
+    });
+    return { hotfix, error: null };
+  } catch (err: unknown) {
+    const error = err instanceof Error ? err.message : String(err);
+    ipcLogger.warn('Selector hotfix not applied', { url: settings.url, error });
+    return { hotfix: null, error };
+  }
+}
+
+/**
+ * Fetches the hotfix during the submission preflight and keeps it for the next run
+ */
+export async function preflightSelectorHotfix(): Promise<SelectorHotfixCheck> {
+  const check = await checkSelectorHotfix();
+  preflighted = { check, fetchedAt: Date.now() };
+  return check;
+}
+
+/**
+ * Hotfix to apply to a run: the preflighted one if recent, otherwise fetched
+ * now. The preflighted hotfix is used once.
+ */
+export async function takeSelectorHotfixForRun(now: number = Date.now()): Promise<SelectorHotfix | null> {
+  const cached = preflighted;
+  preflighted = null;
+  if (cached && now - cached.fetchedAt <= SELECTOR_HOTFIX_MAX_AGE_MS) {
+    return cached.check.hotfix;
+  }
+  return (await checkSelectorHotfix()).hotfix;
+}
@@ -0,0 +1,130 @@
+/**
+ * @fileoverview Entry Status Counts
+ *
+ * Keeps the badge/title counts (drafts, failed, submitting, queued) current
+ * without the renderer polling for them. Entry counts come from one indexed
+ * query; queued is the number of submission jobs waiting behind the running
+ * one. The watcher checks every couple of seconds and whenever a submission
+ * job changes state, and notifies listeners only when a count changed.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { appLogger } from '@sheetpilot/shared/logger';
+import { getDatabaseChangeCount, getEntryStatusCounts } from '@/models';
+import { listJobs, onJobUpdate } from '@/services/jobs/job-manager';
+import { SUBMISSION_JOB_TYPE } from './submission-workflow';
+
+export interface EntryStatusCounts {
+  drafts: number;
+  failed: number;
+  submitting: number;
+  /** Submission jobs waiting to start */
+  queued: number;
+}
+
+/** How often the watcher checks for database writes */
+export const STATUS_COUNTS_CHECK_INTERVAL_MS = 2000;
+
+type StatusCountsListener = (counts: EntryStatusCounts) => void;
+
+let checkTimer: ReturnType<typeof setInterval> | null = null;
+let unsubscribeJobs: (() => void) | null = null;
+let lastChangeCount: number | null = null;
+let lastCounts: EntryStatusCounts | null = null;
+
+const countQueuedSubmissions = (): number =>
+  listJobs().filter((job) => job.type === SUBMISSION_JOB_TYPE && job.status === 'queued').length;
+
+/**
+ * Reads the current counts
+ */
+export function getStatusCounts(): EntryStatusCounts {
+  return { ...getEntryStatusCounts(), queued: countQueuedSubmissions() };
+}
+
+/**
+ * Number shown on the dock/taskbar badge: entries still waiting to be submitted
+ */
+export function getStatusBadgeCount(counts: EntryStatusCounts): number {
+  return counts.drafts + counts.failed;
+}
+
+/**
+ * Window title with the counts appended, e.g. "Sheetpilot (3 drafts, 1 failed)"
+ */
+export function formatStatusTitle(baseTitle: string, counts: EntryStatusCounts): string {
+  const parts = [
+    counts.submitting > 0 ? `${counts.submitting} submitting` : null,
+    counts.queued > 0 ? `${counts.queued} queued` : null,
+    counts.drafts > 0 ? `${counts.drafts} draft${counts.drafts === 1 ? '' : 's'}` : null,
+    counts.failed > 0 ? `${counts.failed} failed` : null
+  ].filter((part): part is string => part !== null);
+  return parts.length > 0 ? `${baseTitle} (${parts.join(', ')})` : baseTitle;
+}
+
+const sameCounts = (a: EntryStatusCounts, b: EntryStatusCounts): boolean =>
+  a.drafts === b.drafts &&
+  a.failed === b.failed &&
+  a.submitting === b.submitting &&
+  a.queued === b.queued;
+
+/**
+ * Re-reads the counts and notifies `listener` if they changed. The entry
+ * query only runs when the connection has written something since the last
+ * check; the queued count is read from memory every time.
+ */
+function refreshStatusCounts(listener: StatusCountsListener): void {
+  try {
+    const changeCount = getDatabaseChangeCount();
+    const entryCounts =
+      lastCounts && changeCount === lastChangeCount ? lastCounts : getEntryStatusCounts();
+    lastChangeCount = changeCount;
+    const counts: EntryStatusCounts = { ...entryCounts, queued: countQueuedSubmissions() };
+    if (lastCounts && sameCounts(lastCounts, counts)) {
+      return;
+    }
+    lastCounts = counts;
+    listener(counts);
+  } catch (err: unknown) {
+    // The database is closed during shutdown and rebuilds; the next check catches up
+    appLogger.debug('Could not refresh status counts', {
+      error: err instanceof Error ? err.message : String(err)
+    });
+  }
+}
+
+/**
+ * Starts watching for count changes; `listener` is called once with the
+ * current counts and then whenever they change
+ */
+export function startStatusCountsWatcher(
+  listener: StatusCountsListener,
+  intervalMs: number = STATUS_COUNTS_CHECK_INTERVAL_MS
+): void {
+  stopStatusCountsWatcher();
+  refreshStatusCounts(listener);
+  unsubscribeJobs = onJobUpdate((job) => {
+    if (job.type === SUBMISSION_JOB_TYPE) {
+      refreshStatusCounts(listener);
+    }
+  });
+  checkTimer = setInterval(() => refreshStatusCounts(listener), intervalMs);
+  checkTimer.unref?.();
+}
+
+/**
+ * Stops watching and forgets the last counts
+ */
+export function stopStatusCountsWatcher(): void {
+  if (checkTimer) {
+    clearInterval(checkTimer);
+    checkTimer = null;
+  }
+  unsubscribeJobs?.();
+  unsubscribeJobs = null;
+  lastChangeCount = null;
+  lastCounts = null;
+}
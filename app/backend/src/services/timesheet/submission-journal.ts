@@ -0,0 +1,142 @@
+/**
+ * @fileoverview Submission Journal
+ *
+ * The bot can finish submitting entries moments before the app crashes,
+ * leaving them in_progress with nothing recording that they reached the
+ * form. Startup recovery then returned them to pending and they were
+ * submitted again.
+ *
+ * Before the database is updated with a run's results, each entry's outcome
+ * is written to submission-journal.json in the app data folder (flushed to
+ * disk), and the file is removed once the database agrees. If the journal is
+ * still there at startup, entries it lists as submitted are marked Complete
+ * by their submission key instead of being treated as interrupted.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { app } from "electron";
+import { botLogger } from "@sheetpilot/shared/logger";
+import { reconcileSubmissionKeys } from "@/models";
+
+export const SUBMISSION_JOURNAL_FILE_NAME = "submission-journal.json";
+
+export type JournaledOutcome = "submitted" | "failed";
+
+export interface SubmissionJournalEntry {
+  id: number;
+  /** Key the entry was sent with; recovery matches on this, not the ID */
+  submissionKey: string | null;
+  outcome: JournaledOutcome;
+}
+
+export interface SubmissionJournal {
+  version: 1;
+  writtenAt: string;
+  entries: SubmissionJournalEntry[];
+}
+
+export interface JournalRecovery {
+  /** Entries marked Complete from the journal */
+  completedIds: number[];
+  /** Submitted entries the database already had as Complete */
+  alreadyCompleteKeys: string[];
+}
+
+export function getSubmissionJournalPath(userDataPath: string = app.getPath("userData")): string {
+  return path.join(userDataPath, SUBMISSION_JOURNAL_FILE_NAME);
+}
+
+/**
+ * Records a run's outcomes, flushed to disk before the file is swapped in so
+ * a crash leaves either the old journal or the complete new one
+ */
+export function writeSubmissionJournal(
+  entries: SubmissionJournalEntry[],
+  journalPath: string = getSubmissionJournalPath()
+): void {
+  const journal: SubmissionJournal = { version: 1, writtenAt: new Date().toISOString(), entries };
+  fs.mkdirSync(path.dirname(journalPath), { recursive: true });
+  const tempPath = `${journalPath}.tmp`;
+  const fd = fs.openSync(tempPath, "w");
+  try {
+    fs.writeSync(fd, JSON.stringify(journal, null, 2));
+    fs.fsyncSync(fd);
+  } finally {
+    fs.closeSync(fd);
+  }
+  fs.renameSync(tempPath, journalPath);
+}
+
+/**
+ * Removes the journal once the database holds the run's results
+ */
+export function clearSubmissionJournal(journalPath: string = getSubmissionJournalPath()): void {
+  fs.rmSync(journalPath, { force: true });
+}
+
+/**
+ * Reads the journal left by an unfinished run
+ *
+ * @returns The journal, or null if there is none or it cannot be read
+ */
+export function readSubmissionJournal(
+  journalPath: string = getSubmissionJournalPath()
+): SubmissionJournal | null {
+  let raw: string;
+  try {
+    raw = fs.readFileSync(journalPath, "utf-8");
+  } catch (err: unknown) {
+    if ((err as NodeJS.ErrnoException).code !== "ENOENT") {
+      botLogger.warn("Could not read submission journal", {
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+    return null;
+  }
+  try {
+    const journal = JSON.parse(raw) as SubmissionJournal;
+    return Array.isArray(journal?.entries) ? journal : null;
+  } catch {
+    botLogger.warn("Submission journal is damaged and was ignored", { journalPath });
+    return null;
+  }
+}
+
+/**
+ * Marks entries the journal lists as submitted Complete, then removes the
+ * journal. Entries it lists as failed are left to the usual recovery.
+ */
+export function recoverFromSubmissionJournal(
+  journalPath: string = getSubmissionJournalPath()
+): JournalRecovery {
+  const journal = readSubmissionJournal(journalPath);
+  if (!journal) {
+    clearSubmissionJournal(journalPath);
+    return { completedIds: [], alreadyCompleteKeys: [] };
+  }
+
+  const submittedKeys = journal.entries
+    .filter((entry) => entry.outcome === "submitted" && entry.submissionKey)
+    .map((entry) => entry.submissionKey as string);
+  const { matchedIds, alreadyCompleteKeys, unknownKeys } = reconcileSubmissionKeys(submittedKeys);
+
+  if (matchedIds.length > 0) {
+    botLogger.audit(
+      "recover-submission-journal",
+      "Entries submitted before the app exited were marked Complete",
+      { writtenAt: journal.writtenAt, ids: matchedIds }
+    );
+  }
+  if (unknownKeys.length > 0) {
+    botLogger.warn("Submission journal lists entries that no longer exist", {
+      count: unknownKeys.length,
+    });
+  }
+  clearSubmissionJournal(journalPath);
+  return { completedIds: matchedIds, alreadyCompleteKeys };
+}
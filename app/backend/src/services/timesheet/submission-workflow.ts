@@ -1,19 +1,53 @@
 import { ipcLogger } from '@sheetpilot/shared/logger';
 import {
+  configureDropdownResolutions,
+  configureFailureScreenshots,
+  configureSelectorHotfix,
+  configureSubmissionPostconditions,
+  getFailureScreenshotDirectory
+} from '@sheetpilot/bot';
+import type { SubmissionResult } from '@sheetpilot/shared';
+import {
+  confirmDropdownResolution,
   getDbPath,
   getPendingTimesheetEntries,
   getCredentials,
   resetInProgressTimesheetEntries,
+  listValidationRules,
+  lookupDropdownResolution,
+  recordSubmissionRun,
   resetTimesheetEntriesStatus,
-  validateSession
+  validateSession,
+  type TimesheetDbRow
 } from '@/models';
 import { submitTimesheets } from '@/services/timesheet-importer';
+import { getBackfillWarnings } from '@/logic/backfill-warnings';
+import { evaluateValidationRulesForEntries, toRuleEntry, type RuleViolation } from '@/logic/validation-rules';
 import { createUserFriendlyMessage, extractErrorCode } from '@sheetpilot/shared/errors';
+import { buildRunArtifact, getRunScreenshotDirectory, writeRunArtifact } from '@/services/runs/run-artifacts';
+import { getActiveSubmissionPostconditions } from '@/middleware/bootstrap-plugins';
+import { getMessage } from '@/services/i18n/message-catalog';
+import { withClockSkewNote } from '@/services/health/clock-skew';
+import { getMockRoutingError } from '@/services/health/environment';
+import { takeSelectorHotfixForRun } from '@/services/timesheet/selector-hotfix';
+import { acquireSubmissionLock, releaseSubmissionLock } from '@/services/timesheet/app-lock';
+
+/** Job type submissions run under */
+export const SUBMISSION_JOB_TYPE = 'timesheet-submission';
 
 export interface SubmitWorkflowResult {
-  submitResult?: { ok: boolean; successCount: number; removedCount: number; totalProcessed: number };
+  submitResult?: {
+    ok: boolean;
+    successCount: number;
+    removedCount: number;
+    totalProcessed: number;
+    /** Entries the form rejected with validation errors */
+    rejectedEntries?: SubmissionResult['rejectedEntries'];
+  };
   dbPath?: string;
   error?: string;
+  /** Blocking rule violations that stopped the submission */
+  ruleViolations?: RuleViolation[];
 }
 
 let isSubmissionInProgress = false;
@@ -23,6 +57,30 @@ export function isTimesheetSubmissionInProgress(): boolean {
   return isSubmissionInProgress;
 }
 
+/**
+ * Saves the run artifact; failures are logged, never surfaced to the user
+ */
+async function saveRunArtifact(
+  runId: number,
+  params: {
+    email: string;
+    useMockWebsite: boolean;
+    entries: TimesheetDbRow[];
+    result: SubmissionResult | null;
+    error?: string;
+    startedAt: Date;
+  }
+): Promise<void> {
+  try {
+    await writeRunArtifact(buildRunArtifact({ runId, ...params, finishedAt: new Date() }));
+  } catch (err: unknown) {
+    ipcLogger.warn('Could not write run artifact', {
+      runId,
+      error: err instanceof Error ? err.message : String(err)
+    });
+  }
+}
+
 export function cancelTimesheetSubmission(): { success: boolean; message?: string; error?: string } {
   ipcLogger.info('Timesheet cancellation requested');
 
@@ -42,6 +100,7 @@ export function cancelTimesheetSubmission(): { success: boolean; message?: strin
 
     const resetCount = resetInProgressTimesheetEntries();
     ipcLogger.info('Reset in-progress entries to pending', { count: resetCount });
+    releaseSubmissionLock('recovered');
 
     return { success: true, message: 'Submission cancelled' };
   } catch (err: unknown) {
@@ -53,6 +112,8 @@ export function cancelTimesheetSubmission(): { success: boolean; message?: strin
 export async function submitTimesheetWorkflow(params: {
   token: string;
   useMockWebsite?: boolean;
+  /** Run history ID recorded on submitted entries */
+  runId?: number | null;
   onProgress: (percent: number, message: string, meta: { pendingIds: number[] }) => void;
 }): Promise<SubmitWorkflowResult> {
   ipcLogger.verbose('Timesheet submit workflow called');
@@ -78,7 +139,16 @@ export async function submitTimesheetWorkflow(params: {
     const session = validateSession(params.token);
     if (!session.valid) {
       timer.done({ outcome: 'error', reason: 'invalid-session' });
-      return { error: 'Session is invalid or expired. Please log in again.' };
+      return { error: withClockSkewNote(getMessage('SESSION_INVALID')) };
+    }
+
+    const mockRoutingError = getMockRoutingError(params.useMockWebsite);
+    if (mockRoutingError) {
+      ipcLogger.security('mock-routing-refused', 'Submission to the mock website refused in production', {
+        email: session.email
+      });
+      timer.done({ outcome: 'error', reason: 'mock-routing-refused' });
+      return { error: mockRoutingError };
     }
 
     if (session.isAdmin) {
@@ -88,7 +158,10 @@ export async function submitTimesheetWorkflow(params: {
     }
 
     ipcLogger.verbose('Checking credentials for submission', { service: 'smartsheet' });
-    const credentials = getCredentials('smartsheet');
+    const credentials = getCredentials('smartsheet', {
+      caller: 'timesheet:submit',
+      purpose: 'submission'
+    });
     ipcLogger.verbose('Credentials check result', { service: 'smartsheet', found: !!credentials });
 
     if (!credentials) {
@@ -103,9 +176,34 @@ export async function submitTimesheetWorkflow(params: {
     let timeoutCheckInterval: NodeJS.Timeout | null = null;
     let submissionAborted = false;
 
-    const pendingEntries = getPendingTimesheetEntries() as Array<{ id: number }>;
+    // From here on the run works from this copy of the pending entries
+    acquireSubmissionLock(params.runId ?? null);
+    const pendingEntries = getPendingTimesheetEntries();
     const pendingEntryIds = pendingEntries.map(e => e.id);
 
+    const blockingViolations = evaluateValidationRulesForEntries(
+      pendingEntries.map(toRuleEntry),
+      listValidationRules(true)
+    ).filter((v) => v.action === 'block');
+    if (blockingViolations.length > 0) {
+      const blockedIds = new Set(blockingViolations.map((v) => v.entryId));
+      ipcLogger.warn('Submission blocked by validation rules', {
+        entryCount: blockedIds.size,
+        ruleIds: [...new Set(blockingViolations.map((v) => v.ruleId))]
+      });
+      timer.done({ outcome: 'error', reason: 'blocked-by-rules' });
+      return {
+        error: `${blockedIds.size} ${blockedIds.size === 1 ? 'entry breaks' : 'entries break'} a blocking validation rule: ${blockingViolations[0]!.message}`,
+        ruleViolations: blockingViolations
+      };
+    }
+    const backfillWarnings = getBackfillWarnings(pendingEntries);
+    if (backfillWarnings.length > 0) {
+      ipcLogger.warn('Submitting entries in closed or closing quarters', {
+        warnings: backfillWarnings.map((w) => ({ id: w.id, date: w.date, state: w.state, quarterId: w.quarterId }))
+      });
+    }
+
     const progressCallback = (percent: number, message: string) => {
       lastProgressTime = Date.now();
       params.onProgress(percent, message, { pendingIds: pendingEntryIds });
@@ -131,6 +229,7 @@ export async function submitTimesheetWorkflow(params: {
           resetTimesheetEntriesStatus(pendingEntryIds);
           ipcLogger.info('Reset entry status to pending after timeout', { count: pendingEntryIds.length });
         }
+        releaseSubmissionLock('recovered');
 
         if (timeoutCheckInterval) {
           clearInterval(timeoutCheckInterval);
@@ -139,14 +238,45 @@ export async function submitTimesheetWorkflow(params: {
       }
     }, 30000);
 
+    const startedAt = new Date();
+    const artifactBase = {
+      email: credentials.email,
+      useMockWebsite: params.useMockWebsite ?? false,
+      entries: pendingEntries,
+      startedAt
+    };
+    // Keep this run's failure screenshots next to its artifact
+    const previousScreenshotDir = getFailureScreenshotDirectory();
+    if (params.runId != null) {
+      configureFailureScreenshots(getRunScreenshotDirectory(params.runId));
+    }
+    // Plugin config can change between runs; pick up the active profile's checks
+    configureSubmissionPostconditions(getActiveSubmissionPostconditions());
+    // Hotfixes apply to this run only
+    configureSelectorHotfix(await takeSelectorHotfixForRun());
+    // Let the bot reuse and remember options picked for ambiguous dropdown matches
+    configureDropdownResolutions({ lookup: lookupDropdownResolution, confirm: confirmDropdownResolution });
+
     try {
-      const submitResult = await submitTimesheets(
-        credentials.email,
-        credentials.password,
-        progressCallback,
-        currentSubmissionAbortController?.signal,
-        params.useMockWebsite
-      );
+      let submitResult: SubmissionResult;
+      try {
+        submitResult = await submitTimesheets(
+          credentials.email,
+          credentials.password,
+          progressCallback,
+          currentSubmissionAbortController?.signal,
+          params.useMockWebsite
+        );
+      } catch (submitError: unknown) {
+        if (params.runId != null) {
+          await saveRunArtifact(params.runId, {
+            ...artifactBase,
+            result: null,
+            error: submitError instanceof Error ? submitError.message : String(submitError)
+          });
+        }
+        throw submitError;
+      }
 
       ipcLogger.info('submitTimesheets completed', {
         ok: submitResult.ok,
@@ -159,6 +289,26 @@ export async function submitTimesheetWorkflow(params: {
         timeoutCheckInterval = null;
       }
 
+      if (params.runId != null && submitResult.submittedIds.length > 0) {
+        try {
+          recordSubmissionRun(submitResult.submittedIds, params.runId);
+        } catch (runError: unknown) {
+          // Audit trail only; the entries are already marked submitted
+          ipcLogger.warn('Could not record submission run on entries', {
+            runId: params.runId,
+            error: runError instanceof Error ? runError.message : String(runError)
+          });
+        }
+      }
+
+      if (params.runId != null) {
+        await saveRunArtifact(params.runId, {
+          ...artifactBase,
+          result: submitResult,
+          ...(submissionAborted ? { error: 'Submission timed out after 5 minutes of no progress' } : {})
+        });
+      }
+
       if (submissionAborted) {
         ipcLogger.warn('Submission was aborted by timeout', { submitResult });
         return {
@@ -176,14 +326,19 @@ export async function submitTimesheetWorkflow(params: {
         });
       }
 
-      ipcLogger.info('Timesheet submission completed successfully', { submitResult, dbPath: getDbPath() });
-      timer.done({ outcome: 'success', submitResult });
+      // Per-form detail is in the run artifact; keep the IPC and job payload small
+      const { formRuns: _formRuns, ...summary } = submitResult;
+      ipcLogger.info('Timesheet submission completed successfully', { submitResult: summary, dbPath: getDbPath() });
+      timer.done({ outcome: 'success', submitResult: summary });
 
-      return { submitResult, dbPath: getDbPath() };
+      return { submitResult: summary, dbPath: getDbPath() };
     } finally {
       if (timeoutCheckInterval) {
         clearInterval(timeoutCheckInterval);
       }
+      configureFailureScreenshots(previousScreenshotDir);
+      configureSelectorHotfix(null);
+      configureDropdownResolutions(null);
     }
   } catch (err: unknown) {
     const errorCode = extractErrorCode(err);
@@ -198,6 +353,7 @@ export async function submitTimesheetWorkflow(params: {
   } finally {
     isSubmissionInProgress = false;
     currentSubmissionAbortController = null;
+    releaseSubmissionLock('completed');
   }
 }
 
@@ -0,0 +1,167 @@
+/**
+ * @fileoverview Timesheet Gap Check and Reminders
+ *
+ * `getMissingTimesheetDays` answers "which working days am I missing?" for a
+ * date range (the gaps panel). Days after today in the home time zone are
+ * never reported, since they cannot be logged yet.
+ *
+ * When `gap_reminders.enabled` is set in settings.json, a scheduled task
+ * checks the last `lookback_days` (default 7) once per working day, after
+ * `hour` (default 16, home time zone), and shows a desktop notification if
+ * any day is missing hours. The check waits while a submission holds the
+ * app lock.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { appLogger } from '@sheetpilot/shared/logger';
+import { normalizeDateToISO } from '@sheetpilot/shared';
+import { getDailyHourTotals } from '@/models';
+import { loadSettings } from '@/services/settings/settings-store';
+import { notifyUser } from '@/services/notifications';
+import { registerScheduledTask, type ScheduledTask } from '@/services/scheduler/scheduler';
+import { flushDirtyDrafts } from '@/routes/handlers/timesheet/drafts.autosave';
+import { getAppLockState } from '@/services/timesheet/app-lock';
+import { getHourInZone, resolveHomeTimeZone, toSlashDateInZone } from '@/logic/time-zones';
+import {
+  addDays,
+  describeMissingDays,
+  findMissingDays,
+  isWorkingDay,
+  resolveWorkweek,
+  type MissingTimesheetDay
+} from '@/logic/timesheet-gaps';
+
+export const GAP_REMINDER_TASK = 'timesheet-gap-reminder';
+/** Longest range the gap check covers in one call */
+export const MAX_GAP_RANGE_DAYS = 366;
+
+const DEFAULT_REMINDER_HOUR = 16;
+const DEFAULT_LOOKBACK_DAYS = 7;
+const MAX_LOOKBACK_DAYS = 31;
+
+export interface GapReminderSettings {
+  enabled?: boolean;
+  /** Hour of the day (0-23, home time zone) after which the reminder is shown */
+  hour?: number;
+  /** Days checked, ending today */
+  lookback_days?: number;
+}
+
+export interface MissingTimesheetDays {
+  startDate: string;
+  /** The requested end date, or today if that is earlier */
+  endDate: string;
+  dailyTargetHours: number;
+  days: MissingTimesheetDay[];
+}
+
+/** Home-zone date of the last reminder check, so it runs once a day */
+let lastReminderDate: string | null = null;
+
+const getToday = (now: Date, homeTimeZone: string): string =>
+  normalizeDateToISO(toSlashDateInZone(now, homeTimeZone));
+
+/**
+ * Working days in a range with no hours or fewer than the daily target
+ *
+ * @param startDate - First day, YYYY-MM-DD
+ * @param endDate - Last day, YYYY-MM-DD
+ * @throws If the range is longer than `MAX_GAP_RANGE_DAYS`
+ */
+export function getMissingTimesheetDays(
+  startDate: string,
+  endDate: string,
+  now: Date = new Date()
+): MissingTimesheetDays {
+  if (addDays(startDate, MAX_GAP_RANGE_DAYS - 1) < endDate) {
+    throw new Error(`Choose a range of ${MAX_GAP_RANGE_DAYS} days or fewer`);
+  }
+  const settings = loadSettings();
+  const workweek = resolveWorkweek(settings.workweek);
+  const today = getToday(now, resolveHomeTimeZone(settings.home_time_zone));
+  const lastDay = endDate < today ? endDate : today;
+  const days =
+    startDate <= lastDay
+      ? findMissingDays(startDate, lastDay, getDailyHourTotals(startDate, lastDay), workweek)
+      : [];
+  return { startDate, endDate: lastDay, dailyTargetHours: workweek.dailyTargetHours, days };
+}
+
+/**
+ * Reminder settings with defaults applied, or null when reminders are off
+ */
+export function resolveGapReminderSettings(
+  settings: GapReminderSettings | undefined
+): { hour: number; lookbackDays: number } | null {
+  if (!settings?.enabled) return null;
+  const hour =
+    typeof settings.hour === 'number' && Number.isInteger(settings.hour) && settings.hour >= 0 && settings.hour <= 23
+      ? settings.hour
+      : DEFAULT_REMINDER_HOUR;
+  const lookbackDays =
+    typeof settings.lookback_days === 'number' && Number.isInteger(settings.lookback_days) && settings.lookback_days >= 1
+      ? Math.min(settings.lookback_days, MAX_LOOKBACK_DAYS)
+      : DEFAULT_LOOKBACK_DAYS;
+  return { hour, lookbackDays };
+}
+
+/**
+ * Checks recent working days and notifies the user about gaps, once per
+ * working day after the reminder hour
+ *
+ * @returns The missing days found, or null if no check was due
+ */
+export function runGapReminder(now: Date = new Date()): MissingTimesheetDay[] | null {
+  const settings = loadSettings();
+  const reminder = resolveGapReminderSettings(settings.gap_reminders);
+  if (!reminder) return null;
+  const homeTimeZone = resolveHomeTimeZone(settings.home_time_zone);
+  const today = getToday(now, homeTimeZone);
+  if (
+    lastReminderDate === today ||
+    getHourInZone(now, homeTimeZone) < reminder.hour ||
+    !isWorkingDay(today, resolveWorkweek(settings.workweek))
+  ) {
+    return null;
+  }
+  // Entries are changing under a running submission; check again next tick
+  if (getAppLockState().locked) {
+    return null;
+  }
+  lastReminderDate = today;
+
+  // Hours typed in the last few seconds count too
+  flushDirtyDrafts();
+  const result = getMissingTimesheetDays(addDays(today, 1 - reminder.lookbackDays), today, now);
+  if (result.days.length > 0) {
+    appLogger.info('Timesheet gaps found', {
+      startDate: result.startDate,
+      endDate: result.endDate,
+      days: result.days.map((day) => day.date)
+    });
+    notifyUser('Timesheet has gaps', describeMissingDays(result.days, result.dailyTargetHours));
+  }
+  return result.days;
+}
+
+/** Lets tests run the daily check again */
+export function resetGapReminder(): void {
+  lastReminderDate = null;
+}
+
+export const gapReminderTask: ScheduledTask = {
+  name: GAP_REMINDER_TASK,
+  runDue: async (now: Date) => {
+    runGapReminder(now);
+  }
+};
+
+/**
+ * Registers the gap reminder task with the scheduler
+ */
+export function registerGapReminderTask(): void {
+  registerScheduledTask(gapReminderTask);
+}
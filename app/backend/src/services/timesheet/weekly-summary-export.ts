@@ -0,0 +1,187 @@
+/**
+ * @fileoverview Accessible Weekly Summary
+ *
+ * Builds a plain-text or Markdown summary of one week for screen reader
+ * users, who find the CSV hard to follow in a spreadsheet. Nothing is laid
+ * out as a table: every day of the week gets a labelled heading (including
+ * days with no entries), each entry is a single sentence with its fields
+ * named, and hours are written without padding ("1.5 hours", not "1.50").
+ *
+ * PTO, holiday and training entries are named as such and totalled in
+ * their own section.
+ *
+ * Shows the latest version of each entry: submitted entries replaced by a
+ * submitted correction are left out, and with drafts included a pending
+ * correction replaces the entry it corrects.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import {
+  ENTRY_KIND_LABELS,
+  SubmissionStatus,
+  normalizeDateToISO,
+  parseEntryKind,
+} from "@sheetpilot/shared";
+import { getWeekEnd } from "@/logic/week-diff";
+
+export type WeeklySummaryFormat = "text" | "markdown";
+
+/** Entry fields as stored (date may be MM/DD/YYYY or YYYY-MM-DD) */
+export interface WeeklySummarySourceRow {
+  id: number;
+  date: string | null;
+  hours: number | null;
+  project: string | null;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+  task_description: string | null;
+  status?: string | null;
+  amends_id?: number | null;
+  category?: string | null;
+  entry_kind?: string | null;
+}
+
+export interface AccessibleWeeklySummary {
+  content: string;
+  entryCount: number;
+  totalHours: number;
+}
+
+const round2 = (value: number): number => Math.round(value * 100) / 100;
+
+/** "1 hour", "1.5 hours", "0 hours" */
+export const describeHours = (hours: number): string => {
+  const rounded = round2(hours);
+  return `${rounded} ${rounded === 1 ? "hour" : "hours"}`;
+};
+
+const describeEntryCount = (count: number): string =>
+  count === 1 ? "1 entry" : `${count} entries`;
+
+/** "Monday, March 3, 2025" */
+export const formatDayLabel = (isoDate: string): string =>
+  new Date(`${isoDate}T00:00:00Z`).toLocaleDateString("en-US", {
+    weekday: "long",
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+    timeZone: "UTC",
+  });
+
+const addDays = (isoDate: string, days: number): string => {
+  const date = new Date(`${isoDate}T00:00:00Z`);
+  date.setUTCDate(date.getUTCDate() + days);
+  return date.toISOString().slice(0, 10);
+};
+
+const isDraft = (row: WeeklySummarySourceRow): boolean =>
+  row.status === null || row.status === undefined;
+
+/**
+ * Latest version of each entry: submitted entries, plus drafts if asked,
+ * minus entries replaced by a correction that is itself included
+ */
+export function selectLatestEntries<T extends WeeklySummarySourceRow>(
+  rows: T[],
+  includeDrafts: boolean
+): T[] {
+  const included = (row: T): boolean =>
+    row.status === SubmissionStatus.COMPLETE || (includeDrafts && isDraft(row));
+  const replaced = new Set(
+    rows
+      .filter((row) => included(row) && row.amends_id !== null && row.amends_id !== undefined)
+      .map((row) => row.amends_id as number)
+  );
+  return rows.filter((row) => included(row) && !replaced.has(row.id));
+}
+
+const describeEntry = (
+  row: WeeklySummarySourceRow,
+  position: number,
+  count: number
+): string => {
+  const parts = [`Entry ${position} of ${count}: ${describeHours(row.hours ?? 0)}`];
+  const kind = parseEntryKind(row.entry_kind);
+  if (kind !== "work") parts.push(`Kind: ${ENTRY_KIND_LABELS[kind]}`);
+  if (row.project) parts.push(`Project: ${row.project}`);
+  if (row.tool) parts.push(`Tool: ${row.tool}`);
+  if (row.detail_charge_code) parts.push(`Charge code: ${row.detail_charge_code}`);
+  if (row.task_description) parts.push(`Description: ${row.task_description}`);
+  if (row.category) parts.push(`Category: ${row.category}`);
+  if (isDraft(row)) parts.push("Draft, not submitted yet");
+  return `${parts.map((part) => part.replace(/[\r\n]+/g, " ").trim().replace(/\.+$/, "")).join(". ")}.`;
+};
+
+/**
+ * Builds the summary for the week starting on `weekStart`
+ *
+ * @param weekStart - First day of the week, YYYY-MM-DD
+ * @param rows - Entries dated in the week plus entries linked to them by amendment
+ */
+export function buildAccessibleWeeklySummary(
+  weekStart: string,
+  rows: WeeklySummarySourceRow[],
+  format: WeeklySummaryFormat,
+  includeDrafts = false
+): AccessibleWeeklySummary {
+  const weekEnd = getWeekEnd(weekStart);
+  const entries = selectLatestEntries(rows, includeDrafts)
+    .map((row) => ({ ...row, date: row.date ? normalizeDateToISO(row.date) : null }))
+    .filter((row) => row.date !== null && row.date >= weekStart && row.date <= weekEnd)
+    .sort((a, b) => a.date!.localeCompare(b.date!) || a.id - b.id);
+
+  const totalHours = round2(entries.reduce((sum, row) => sum + (row.hours ?? 0), 0));
+  const markdown = format === "markdown";
+  const heading = (level: number, text: string): string =>
+    markdown ? `${"#".repeat(level)} ${text}` : text;
+  const item = (text: string): string => (markdown ? `- ${text}` : text);
+
+  const lines: string[] = [
+    heading(1, `Weekly summary, ${formatDayLabel(weekStart)} to ${formatDayLabel(weekEnd)}`),
+    "",
+    `Total: ${describeHours(totalHours)} in ${describeEntryCount(entries.length)}.`,
+  ];
+
+  for (let offset = 0; offset < 7; offset++) {
+    const day = addDays(weekStart, offset);
+    const dayEntries = entries.filter((row) => row.date === day);
+    const dayHours = dayEntries.reduce((sum, row) => sum + (row.hours ?? 0), 0);
+    lines.push("");
+    if (dayEntries.length === 0) {
+      lines.push(heading(2, `${formatDayLabel(day)}, no entries`));
+      continue;
+    }
+    lines.push(
+      heading(2, `${formatDayLabel(day)}, ${describeHours(dayHours)} in ${describeEntryCount(dayEntries.length)}`)
+    );
+    dayEntries.forEach((row, index) => lines.push(item(describeEntry(row, index + 1, dayEntries.length))));
+  }
+
+  const pushHoursBy = (title: string, keyOf: (row: WeeklySummarySourceRow) => string): void => {
+    const hoursByKey = new Map<string, number>();
+    for (const row of entries) {
+      const key = keyOf(row);
+      hoursByKey.set(key, (hoursByKey.get(key) ?? 0) + (row.hours ?? 0));
+    }
+    if (hoursByKey.size > 0) {
+      lines.push("", heading(2, title));
+      [...hoursByKey.entries()]
+        .sort(([a], [b]) => a.localeCompare(b))
+        .forEach(([key, hours]) => lines.push(item(`${key}: ${describeHours(hours)}.`)));
+    }
+  };
+  pushHoursBy("Hours by project", (row) => row.project || "No project");
+  // Only teams that use categories get the section
+  if (entries.some((row) => row.category)) {
+    pushHoursBy("Hours by category", (row) => row.category || "No category");
+  }
+  // PTO, holidays and training are totalled apart from work when the week has any
+  if (entries.some((row) => parseEntryKind(row.entry_kind) !== "work")) {
+    pushHoursBy("Hours by kind", (row) => ENTRY_KIND_LABELS[parseEntryKind(row.entry_kind)]);
+  }
+
+  return { content: `${lines.join("\n")}\n`, entryCount: entries.length, totalHours };
+}
@@ -0,0 +1,113 @@
+/**
+ * @fileoverview Minimal XLSX Writer
+ *
+ * Produces a single-sheet Office Open XML workbook using inline strings and an
+ * uncompressed ZIP container. Avoids pulling a spreadsheet dependency into the
+ * main process for what is a flat table export.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { buildStoredZip } from "@/services/archive/stored-zip";
+
+const escapeXml = (value: string): string =>
+  value
+    .replace(/&/g, "&amp;")
+    .replace(/</g, "&lt;")
+    .replace(/>/g, "&gt;")
+    .replace(/"/g, "&quot;")
+    // Strip control characters that are invalid in XML 1.0
+    // eslint-disable-next-line no-control-regex
+    .replace(/[\u0000-\u0008\u000B\u000C\u000E-\u001F]/g, "");
+
+const columnName = (index: number): string => {
+  let name = "";
+  let n = index + 1;
+  while (n > 0) {
+    const rem = (n - 1) % 26;
+    name = String.fromCharCode(65 + rem) + name;
+    n = Math.floor((n - 1) / 26);
+  }
+  return name;
+};
+
+const buildSheetXml = (rows: Array<Array<string | number>>): string => {
+  const rowXml = rows
+    .map((cells, rowIndex) => {
+      const cellXml = cells
+        .map((cell, colIndex) => {
+          const ref = `${columnName(colIndex)}${rowIndex + 1}`;
+          if (typeof cell === "number" && Number.isFinite(cell)) {
+            return `<c r="${ref}"><v>${cell}</v></c>`;
+          }
+          return `<c r="${ref}" t="inlineStr"><is><t xml:space="preserve">${escapeXml(String(cell))}</t></is></c>`;
+        })
+        .join("");
+      return `<row r="${rowIndex + 1}">${cellXml}</row>`;
+    })
+    .join("");
+  return (
+    '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>' +
+    '<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">' +
+    `<sheetData>${rowXml}</sheetData></worksheet>`
+  );
+};
+
+const buildPackageParts = (
+  sheetName: string,
+  rows: Array<Array<string | number>>
+): Array<{ name: string; content: string }> => [
+  {
+    name: "[Content_Types].xml",
+    content:
+      '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>' +
+      '<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">' +
+      '<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>' +
+      '<Default Extension="xml" ContentType="application/xml"/>' +
+      '<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>' +
+      '<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>' +
+      "</Types>",
+  },
+  {
+    name: "_rels/.rels",
+    content:
+      '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>' +
+      '<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">' +
+      '<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>' +
+      "</Relationships>",
+  },
+  {
+    name: "xl/workbook.xml",
+    content:
+      '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>' +
+      '<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">' +
+      `<sheets><sheet name="${escapeXml(sheetName.slice(0, 31))}" sheetId="1" r:id="rId1"/></sheets>` +
+      "</workbook>",
+  },
+  {
+    name: "xl/_rels/workbook.xml.rels",
+    content:
+      '<?xml version="1.0" encoding="UTF-8" standalone="yes"?>' +
+      '<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">' +
+      '<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>' +
+      "</Relationships>",
+  },
+  { name: "xl/worksheets/sheet1.xml", content: buildSheetXml(rows) },
+];
+
+/**
+ * Builds an XLSX workbook with a single sheet
+ *
+ * @param sheetName - Sheet tab name (truncated to 31 characters)
+ * @param header - Header row
+ * @param rows - Data rows; numbers are written as numeric cells
+ */
+export function buildXlsxWorkbook(
+  sheetName: string,
+  header: string[],
+  rows: Array<Array<string | number>>
+): Buffer {
+  return buildStoredZip(buildPackageParts(sheetName, [header, ...rows]));
+}
@@ -1,4 +1,12 @@
 import { z } from 'zod';
+import { ENTRY_KINDS, isQuarterHourIncrement, MAX_ENTRY_HOURS, MIN_ENTRY_HOURS } from '@sheetpilot/shared';
+import { RULE_ACTIONS, RULE_FIELDS, RULE_OPERATORS } from '@/logic/validation-rules';
+import { isValidTimeZone } from '@/logic/time-zones';
+import { MAX_EXTERNAL_REF_LENGTH } from '@/logic/external-ref';
+import { DESCRIPTION_TEMPLATE_VARIABLES, findUnknownPlaceholders } from '@/logic/description-templates';
+import { ENTRY_CATEGORY_COLOR_PATTERN, MAX_ENTRY_CATEGORY_NAME_LENGTH } from '@/logic/entry-categories';
+import { EXPORT_COLUMN_IDS, type ExportColumnId } from '@/services/timesheet/csv-export';
+import { ARCHIVE_COLUMNS } from '@/models/timesheet-repository.types';
 
 export const emailSchema = z.string()
   .regex(/^(?!\.)(?!.*\.\.)[^\s@]+@[^\s@]+\.[^\s@]+$/, 'Invalid email format')
@@ -47,6 +55,11 @@ export const deleteCredentialsSchema = z.object({
   service: serviceNameSchema
 });
 
+export const revealCredentialsSchema = z.object({
+  token: sessionTokenSchema,
+  service: serviceNameSchema
+});
+
 export const loginSchema = z.object({
   email: z.string()
     .min(1, 'Email is required')
@@ -74,28 +87,112 @@ export const getCurrentSessionSchema = z.object({
 });
 
 
+/** IANA zone name, e.g. "America/Chicago" */
+const timeZoneSchema = z.string().max(64).refine(isValidTimeZone, 'Unknown time zone');
+
 export const saveDraftSchema = z.object({
   id: z.number().int().positive().nullable().optional(),
   date: dateSchema.optional(),
   hours: z.number()
-    .min(0.25, 'Hours must be at least 0.25')
-    .max(24.0, 'Hours must not exceed 24.0')
-    .refine((val) => {
-      // Validate 15-minute increments (multiples of 0.25)
-      const remainder = (val * 4) % 1;
-      return Math.abs(remainder) < 0.0001 || Math.abs(remainder - 1) < 0.0001;
-    }, 'Hours must be in 15-minute increments (0.25, 0.5, 0.75, etc.)')
+    .min(MIN_ENTRY_HOURS, 'Hours must be at least 0.25')
+    .max(MAX_ENTRY_HOURS, 'Hours must not exceed 24.0')
+    .refine(isQuarterHourIncrement, 'Hours must be in 15-minute increments (0.25, 0.5, 0.75, etc.)')
     .optional(),
   project: projectNameSchema.optional(),
   tool: z.string().max(500).nullable().optional(),
   chargeCode: z.string().max(100).nullable().optional(),
-  taskDescription: taskDescriptionSchema.optional()
+  taskDescription: taskDescriptionSchema.optional(),
+  /** Zone the work was done in when away from home (recorded for auditing) */
+  timeZone: timeZoneSchema.nullable().optional(),
+  /** Work order number or other outside reference; the format is checked against settings */
+  externalRef: z.string().trim().max(MAX_EXTERNAL_REF_LENGTH, `External reference must not exceed ${MAX_EXTERNAL_REF_LENGTH} characters`).nullable().optional(),
+  /** Name of a category from the managed list; null clears it */
+  category: z.string().trim().max(MAX_ENTRY_CATEGORY_NAME_LENGTH).nullable().optional(),
+  /** PTO, holiday or training; null means work */
+  kind: z.enum(ENTRY_KINDS).nullable().optional()
+});
+
+export const markDirtyDraftsSchema = z.object({
+  rows: z.array(saveDraftSchema.extend({
+    // Identifies the grid row in the draft-saved event (rows without an id yet have no other key)
+    rowKey: z.string().min(1).max(100)
+  })).min(1).max(1000)
 });
 
 export const deleteDraftSchema = z.object({
   id: z.number().int().positive('Valid ID is required')
 });
 
+export const amendEntrySchema = z.object({
+  id: z.number().int().positive('Valid ID is required')
+});
+
+export const reassignRetiredProjectSchema = z.object({
+  project: projectNameSchema
+});
+
+export const suggestNextEntrySchema = z.object({
+  date: dateSchema
+});
+
+const keywordListSchema = z.array(z.string().min(1).max(200)).max(50).optional();
+
+export const calendarImportPreviewSchema = z.object({
+  startDate: dateSchema,
+  endDate: dateSchema,
+  project: projectNameSchema.optional(),
+  tool: z.string().max(500).nullable().optional(),
+  chargeCode: z.string().max(100).nullable().optional(),
+  includeCategories: keywordListSchema,
+  includeKeywords: keywordListSchema,
+  excludeKeywords: keywordListSchema,
+  skipFree: z.boolean().optional(),
+  timeZone: timeZoneSchema.optional()
+});
+
+export const calendarImportDraftsSchema = z.object({
+  drafts: z.array(z.object({
+    date: dateSchema,
+    hours: saveDraftSchema.shape.hours.unwrap(),
+    project: projectNameSchema,
+    tool: z.string().max(500).nullable().optional(),
+    chargeCode: z.string().max(100).nullable().optional(),
+    taskDescription: taskDescriptionSchema,
+    timeZone: timeZoneSchema.optional(),
+    category: saveDraftSchema.shape.category
+  })).min(1, 'Select at least one meeting to import').max(1000)
+});
+
+export const toolUsageQuerySchema = z.object({
+  startDate: dateSchema,
+  endDate: dateSchema,
+  includeDrafts: z.boolean().optional()
+});
+
+export const activityHeatmapSchema = z.object({
+  year: z.number().int().min(2000).max(2100)
+});
+
+export const missingTimesheetDaysSchema = z.object({
+  startDate: dateSchema,
+  endDate: dateSchema
+});
+
+export const diffWeekSchema = z.object({
+  weekStart: dateSchema
+});
+
+export const weeklySummaryExportSchema = z.object({
+  weekStart: dateSchema,
+  format: z.enum(['text', 'markdown']),
+  includeDrafts: z.boolean().optional()
+});
+
+export const reconcileSubmissionKeysSchema = z.object({
+  // Pasted cells or a CSV export of the destination sheet
+  text: z.string().min(1, 'Sheet contents are required').max(10_000_000, 'Sheet contents are too large')
+});
+
 export const submitTimesheetsSchema = z.object({
   token: sessionTokenSchema
 });
@@ -104,10 +201,95 @@ export const adminTokenSchema = z.object({
   token: sessionTokenSchema
 });
 
+export const setProtectedSettingSchema = z.object({
+  token: sessionTokenSchema,
+  key: z.string().min(1, 'Setting key is required').max(100, 'Setting key too long')
+});
+
+export const dataIntegrityCheckSchema = z.object({
+  token: sessionTokenSchema,
+  repair: z.boolean().optional()
+});
+
+export const bulkRecodeSchema = z.object({
+  token: sessionTokenSchema,
+  request: z.object({
+    field: z.enum(['project', 'chargeCode']),
+    fromValue: z.string().min(1, 'Current value is required').max(500),
+    toValue: z.string().min(1, 'New value is required').max(500),
+    filter: z.object({
+      startDate: dateSchema.optional(),
+      endDate: dateSchema.optional(),
+      project: projectNameSchema.optional()
+    }).optional(),
+    dryRun: z.boolean().optional()
+  }).refine((request) => request.fromValue !== request.toValue, {
+    message: 'New value must differ from the current value'
+  })
+});
+
+export const deleteCompletedEntrySchema = z.object({
+  token: sessionTokenSchema,
+  id: z.number().int().positive('Valid ID is required'),
+  reason: z.string().trim().min(1, 'A reason is required').max(1000, 'Reason too long')
+});
+
+export const revokeUserSessionsSchema = z.object({
+  token: sessionTokenSchema,
+  email: emailSchema
+});
+
+export const listDropdownResolutionsSchema = z.object({
+  token: sessionTokenSchema,
+  fieldKey: z.string().min(1).max(100).regex(/^[\w-]+$/, 'Field key may only contain letters, digits, - and _').optional()
+});
+
+export const deleteDropdownResolutionSchema = z.object({
+  token: sessionTokenSchema,
+  id: z.number().int().positive('Valid ID is required')
+});
+
+export const lintAutomationConfigSchema = z.object({
+  token: sessionTokenSchema,
+  checkReachability: z.boolean().optional()
+});
+
+export const inspectFormSchema = z.object({
+  token: sessionTokenSchema,
+  request: z.object({
+    baseUrl: z.string().url('Form URL must be a valid URL').max(2000)
+      .refine((url) => /^https:\/\//i.test(url) || /^http:\/\/(localhost|127\.0\.0\.1)[:/]/i.test(url), {
+        message: 'Form URL must use https'
+      }),
+    formId: z.string().min(1, 'Form ID is required').max(100).regex(/^[\w-]+$/, 'Form ID may only contain letters, digits, - and _')
+  })
+});
+
+const queryParamValueSchema = z.union([z.string().max(10000), z.number(), z.null()]);
+
+export const readonlyQuerySchema = z.object({
+  token: sessionTokenSchema,
+  sql: z.string().min(1, 'Query is required').max(20000, 'Query too long'),
+  params: z.union([
+    z.array(queryParamValueSchema).max(100),
+    z.record(z.string().regex(/^[a-z_][a-z0-9_]*$/i), queryParamValueSchema)
+  ]).optional()
+});
+
 export const getAllTimesheetEntriesSchema = z.object({
   token: sessionTokenSchema
 });
 
+export const getArchivePageSchema = z.object({
+  token: sessionTokenSchema,
+  request: z.object({
+    cursor: z.string().max(500).optional(),
+    limit: z.number().int().min(1).max(5000).optional(),
+    columns: z.array(z.enum(ARCHIVE_COLUMNS)).max(ARCHIVE_COLUMNS.length).optional(),
+    gzip: z.boolean().optional()
+  }).optional()
+});
+
 export const readLogFileSchema = z.object({
   logPath: z.string().min(1).max(1000)
 });
@@ -117,6 +299,15 @@ export const exportLogsSchema = z.object({
   exportFormat: z.enum(['json', 'txt']).optional()
 });
 
+export const queryLogsSchema = z.object({
+  from: z.string().datetime({ offset: true }).optional(),
+  to: z.string().datetime({ offset: true }).optional(),
+  levels: z.array(z.enum(['error', 'warn', 'info', 'verbose', 'debug', 'silly'])).max(6).optional(),
+  runId: z.string().min(1).max(100).optional(),
+  search: z.string().min(1).max(200).optional(),
+  limit: z.number().int().min(1).max(5000).optional()
+});
+
 export const getToolsForProjectSchema = z.object({
   project: z.string().min(1).max(500)
 });
@@ -134,6 +325,11 @@ export const validateChargeCodeSchema = z.object({
   chargeCode: z.string().min(1).max(100)
 });
 
+// Retirement is an effective date in the catalog, always stored as YYYY-MM-DD
+const projectRetiredOnSchema = z.string()
+  .regex(/^\d{4}-\d{2}-\d{2}$/, 'Retirement date must be YYYY-MM-DD')
+  .nullable();
+
 export const businessConfigProjectUpdateSchema = z.object({
   token: sessionTokenSchema,
   id: z.number().int().positive(),
@@ -141,7 +337,9 @@ export const businessConfigProjectUpdateSchema = z.object({
     name: z.string().min(1).max(500).optional(),
     requires_tools: z.boolean().optional(),
     display_order: z.number().int().optional(),
-    is_active: z.boolean().optional()
+    is_active: z.boolean().optional(),
+    retired_on: projectRetiredOnSchema.optional(),
+    successor_project: z.string().min(1).max(500).nullable().optional()
   })
 });
 
@@ -172,7 +370,9 @@ export const businessConfigProjectCreateSchema = z.object({
     name: z.string().min(1).max(500),
     requires_tools: z.boolean().optional(),
     display_order: z.number().int().optional(),
-    is_active: z.boolean().optional()
+    is_active: z.boolean().optional(),
+    retired_on: projectRetiredOnSchema.optional(),
+    successor_project: z.string().min(1).max(500).nullable().optional()
   })
 });
 
@@ -208,19 +408,236 @@ export const unlinkToolFromProjectSchema = z.object({
   toolId: z.number().int().positive()
 });
 
+export const scheduledExportSchema = z.object({
+  id: z.number().int().positive().optional(),
+  name: z.string().min(1, 'Schedule name is required').max(200),
+  format: z.enum(['csv', 'xlsx']),
+  targetDir: z.string().min(1, 'Target folder is required').max(1000),
+  dayOfMonth: z.number().int().min(1).max(28, 'Day of month must be between 1 and 28'),
+  isActive: z.boolean(),
+  uploadToCloud: z.boolean().optional()
+});
+
+const exportTemplateNameSchema = z.string().trim().min(1, 'Template name is required').max(100);
+
+export const exportTemplateSchema = z.object({
+  name: exportTemplateNameSchema,
+  columns: z.array(z.object({
+    id: z.enum(EXPORT_COLUMN_IDS as [ExportColumnId, ...ExportColumnId[]]),
+    header: z.string().max(200).optional()
+  })).min(1, 'Select at least one column').max(EXPORT_COLUMN_IDS.length)
+    .refine(
+      (columns) => new Set(columns.map((column) => column.id)).size === columns.length,
+      'Each column can only appear once'
+    )
+});
+
+const descriptionTemplateIdSchema = z.string().trim().min(1, 'Template ID is required').max(100);
+
+/** Without an ID a new template is created */
+export const descriptionTemplateSchema = z.object({
+  id: descriptionTemplateIdSchema.optional(),
+  name: z.string().trim().min(1, 'Template name is required').max(100),
+  template: taskDescriptionSchema.refine(
+    (template) => findUnknownPlaceholders(template).length === 0,
+    `Templates can only use ${DESCRIPTION_TEMPLATE_VARIABLES.map((name) => `{${name}}`).join(', ')}`
+  )
+});
+
+export const descriptionTemplateIdQuerySchema = z.object({
+  templateId: descriptionTemplateIdSchema
+});
+
+const entryCategoryIdSchema = z.string().trim().min(1, 'Category ID is required').max(100);
+
+/** Without an ID a new category is created */
+export const entryCategorySchema = z.object({
+  id: entryCategoryIdSchema.optional(),
+  name: z.string().trim()
+    .min(1, 'Category name is required')
+    .max(MAX_ENTRY_CATEGORY_NAME_LENGTH, `Category name must not exceed ${MAX_ENTRY_CATEGORY_NAME_LENGTH} characters`),
+  color: z.string().regex(ENTRY_CATEGORY_COLOR_PATTERN, 'Color must be a hex color such as #1f77b4').optional()
+});
+
+export const entryCategoryIdQuerySchema = z.object({
+  categoryId: entryCategoryIdSchema
+});
+
+const templateVariableSchema = z.string().max(500).nullable().optional();
+
+export const renderDescriptionSchema = z.object({
+  templateId: descriptionTemplateIdSchema,
+  vars: z.object({
+    project: templateVariableSchema,
+    tool: templateVariableSchema,
+    date: templateVariableSchema,
+    ticket: templateVariableSchema
+  }).strict()
+});
+
+export const chargeCodeQuotaSchema = z.object({
+  chargeCode: z.string().trim().min(1, 'Charge code is required').max(200),
+  quarterId: z.string().trim().min(1, 'Quarter is required').max(50),
+  hours: z.number().positive('Quota must be more than 0 hours').max(10000)
+});
+
+const hoursRoundingSchema = z.union([z.literal(0.25), z.literal(0.5), z.literal(1)]);
+
+export const userPreferencesSchema = z.object({
+  themeMode: z.enum(['auto', 'light', 'dark']),
+  weekStart: z.enum(['sunday', 'monday']),
+  hoursRounding: hoursRoundingSchema,
+  defaultProject: z.string().trim().min(1).max(500)
+}).partial();
+
+export const getPreferencesSchema = z.object({
+  token: sessionTokenSchema
+});
+
+/** null clears a preference back to its default */
+export const setPreferencesSchema = z.object({
+  token: sessionTokenSchema,
+  updates: z.object({
+    themeMode: z.enum(['auto', 'light', 'dark']).nullable().optional(),
+    weekStart: z.enum(['sunday', 'monday']).nullable().optional(),
+    hoursRounding: hoursRoundingSchema.nullable().optional(),
+    defaultProject: z.string().trim().min(1).max(500).nullable().optional()
+  }).strict()
+});
+
+export const exportTemplateNameQuerySchema = z.object({
+  templateName: exportTemplateNameSchema.optional()
+});
+
+export const clipboardExportSchema = z.object({
+  startDate: dateSchema,
+  endDate: dateSchema,
+  format: z.enum(['tsv', 'csv', 'markdown']),
+  includeDrafts: z.boolean().optional(),
+  templateName: exportTemplateNameSchema.optional()
+});
+
+export const scheduledExportIdSchema = z.object({
+  id: z.number().int().positive('Valid schedule ID is required')
+});
+
+export const jobIdSchema = z.object({
+  jobId: z.string().uuid('Valid job ID is required')
+});
+
+export const runHistoryQuerySchema = z.object({
+  jobType: z.string().min(1).max(100).optional(),
+  limit: z.number().int().min(1).max(500).optional()
+});
+
+export const runIdSchema = z.object({
+  runId: z.number().int().positive('Valid run ID is required')
+});
+
+const hasValidPattern = (condition: { operator: string; value?: string | null | undefined }): boolean => {
+  if (condition.operator !== 'matches') return true;
+  try {
+    new RegExp(condition.value ?? '');
+    return true;
+  } catch {
+    return false;
+  }
+};
+
+const ruleConditionSchema = z.object({
+  field: z.enum(RULE_FIELDS),
+  operator: z.enum(RULE_OPERATORS),
+  value: z.string().max(500).nullable().optional()
+}).refine(hasValidPattern, { message: 'Pattern must be a valid regular expression', path: ['value'] });
+
+export const validationRuleSchema = z.object({
+  name: z.string().min(1, 'Rule name is required').max(200),
+  field: z.enum(RULE_FIELDS),
+  operator: z.enum(RULE_OPERATORS),
+  value: z.string().max(500).nullable().optional(),
+  when: ruleConditionSchema.nullable().optional(),
+  action: z.enum(RULE_ACTIONS),
+  message: z.string().min(1, 'Rule message is required').max(500),
+  isActive: z.boolean().optional()
+}).refine(hasValidPattern, { message: 'Pattern must be a valid regular expression', path: ['value'] });
+
+export const createValidationRuleSchema = z.object({
+  token: sessionTokenSchema,
+  rule: validationRuleSchema
+});
+
+export const updateValidationRuleSchema = z.object({
+  token: sessionTokenSchema,
+  id: z.number().int().positive('Valid rule ID is required'),
+  rule: validationRuleSchema
+});
+
+export const deleteValidationRuleSchema = z.object({
+  token: sessionTokenSchema,
+  id: z.number().int().positive('Valid rule ID is required')
+});
+
+export const cloudConnectSchema = z.object({
+  clientId: z.string().uuid('Application (client) ID must be a GUID'),
+  tenantId: z.string()
+    .min(1, 'Tenant is required')
+    .max(255)
+    .regex(/^[a-z0-9.-]+$/i, 'Tenant must be a GUID, domain, or "organizations"'),
+  folderPath: z.string()
+    .min(1, 'Upload folder is required')
+    .max(400)
+    .refine((value) => !value.split('/').includes('..'), 'Upload folder must not contain ".." segments')
+});
+
+export const frontendLogFieldsSchema = z.object({
+  category: z.string()
+    .min(1)
+    .max(64, 'Category too long')
+    .regex(/^[a-z0-9][a-z0-9._:-]*$/i, 'Category must contain only letters, numbers, dots, colons, hyphens, and underscores')
+    .optional(),
+  component: z.string().min(1).max(100, 'Component name too long').optional(),
+  userActionId: z.string().min(1).max(128, 'User action ID too long').optional()
+}).strict();
+
+export const devBenchmarkFillSchema = z.object({
+  iterations: z.number().int().min(1).max(50, 'At most 50 iterations per profile'),
+  profiles: z.array(z.string().min(1).max(64)).max(10).optional()
+});
+
 export type StoreCredentials = z.infer<typeof storeCredentialsSchema>;
 export type DeleteCredentials = z.infer<typeof deleteCredentialsSchema>;
+export type RevealCredentials = z.infer<typeof revealCredentialsSchema>;
 export type Login = z.infer<typeof loginSchema>;
 export type ValidateSession = z.infer<typeof validateSessionSchema>;
 export type Logout = z.infer<typeof logoutSchema>;
 export type GetCurrentSession = z.infer<typeof getCurrentSessionSchema>;
 export type SaveDraft = z.infer<typeof saveDraftSchema>;
+export type MarkDirtyDrafts = z.infer<typeof markDirtyDraftsSchema>;
+export type DirtyDraftRow = MarkDirtyDrafts['rows'][number];
 export type DeleteDraft = z.infer<typeof deleteDraftSchema>;
+export type AmendEntry = z.infer<typeof amendEntrySchema>;
+export type SuggestNextEntry = z.infer<typeof suggestNextEntrySchema>;
+export type CalendarImportPreview = z.infer<typeof calendarImportPreviewSchema>;
+export type CalendarImportDrafts = z.infer<typeof calendarImportDraftsSchema>;
+export type ToolUsageQuery = z.infer<typeof toolUsageQuerySchema>;
+export type MissingTimesheetDaysQuery = z.infer<typeof missingTimesheetDaysSchema>;
+export type ReconcileSubmissionKeys = z.infer<typeof reconcileSubmissionKeysSchema>;
 export type SubmitTimesheets = z.infer<typeof submitTimesheetsSchema>;
 export type AdminToken = z.infer<typeof adminTokenSchema>;
+export type DataIntegrityCheck = z.infer<typeof dataIntegrityCheckSchema>;
+export type BulkRecode = z.infer<typeof bulkRecodeSchema>;
+export type DeleteCompletedEntry = z.infer<typeof deleteCompletedEntrySchema>;
+export type RevokeUserSessions = z.infer<typeof revokeUserSessionsSchema>;
+export type InspectForm = z.infer<typeof inspectFormSchema>;
+export type LintAutomationConfig = z.infer<typeof lintAutomationConfigSchema>;
+export type ListDropdownResolutions = z.infer<typeof listDropdownResolutionsSchema>;
+export type DeleteDropdownResolution = z.infer<typeof deleteDropdownResolutionSchema>;
+export type ReadonlyQuery = z.infer<typeof readonlyQuerySchema>;
 export type GetAllTimesheetEntries = z.infer<typeof getAllTimesheetEntriesSchema>;
+export type GetArchivePage = z.infer<typeof getArchivePageSchema>;
 export type ReadLogFile = z.infer<typeof readLogFileSchema>;
 export type ExportLogs = z.infer<typeof exportLogsSchema>;
+export type QueryLogs = z.infer<typeof queryLogsSchema>;
 export type GetToolsForProject = z.infer<typeof getToolsForProjectSchema>;
 export type ValidateProject = z.infer<typeof validateProjectSchema>;
 export type ValidateToolForProject = z.infer<typeof validateToolForProjectSchema>;
@@ -233,5 +650,24 @@ export type BusinessConfigToolCreate = z.infer<typeof businessConfigToolCreateSc
 export type BusinessConfigChargeCodeCreate = z.infer<typeof businessConfigChargeCodeCreateSchema>;
 export type LinkToolToProject = z.infer<typeof linkToolToProjectSchema>;
 export type UnlinkToolFromProject = z.infer<typeof unlinkToolFromProjectSchema>;
-
-
+export type ScheduledExportInput = z.infer<typeof scheduledExportSchema>;
+export type ExportTemplateInput = z.infer<typeof exportTemplateSchema>;
+export type DescriptionTemplateInput = z.infer<typeof descriptionTemplateSchema>;
+export type RenderDescriptionRequest = z.infer<typeof renderDescriptionSchema>;
+export type EntryCategoryInput = z.infer<typeof entryCategorySchema>;
+export type ChargeCodeQuotaInput = z.infer<typeof chargeCodeQuotaSchema>;
+export type UserPreferences = z.infer<typeof userPreferencesSchema>;
+export type SetPreferences = z.infer<typeof setPreferencesSchema>;
+export type ClipboardExportRequest = z.infer<typeof clipboardExportSchema>;
+export type WeeklySummaryExportRequest = z.infer<typeof weeklySummaryExportSchema>;
+export type ScheduledExportId = z.infer<typeof scheduledExportIdSchema>;
+export type RunHistoryQuery = z.infer<typeof runHistoryQuerySchema>;
+export type RunId = z.infer<typeof runIdSchema>;
+export type JobId = z.infer<typeof jobIdSchema>;
+export type CloudConnect = z.infer<typeof cloudConnectSchema>;
+export type FrontendLogFields = z.infer<typeof frontendLogFieldsSchema>;
+export type ValidationRuleDefinition = z.infer<typeof validationRuleSchema>;
+export type CreateValidationRule = z.infer<typeof createValidationRuleSchema>;
+export type UpdateValidationRule = z.infer<typeof updateValidationRuleSchema>;
+export type DeleteValidationRule = z.infer<typeof deleteValidationRuleSchema>;
+export type DevBenchmarkFill = z.infer<typeof devBenchmarkFillSchema>;
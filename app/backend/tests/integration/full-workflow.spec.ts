@@ -32,7 +32,7 @@ vi.mock('../../../shared/logger', () => ({
 }));
 
 import { setDbPath, ensureSchema, openDb, shutdownDatabase } from '../../src/models';
-import { createSession, validateSession } from '../../src/models/session-repository';
+import { createSession, hashSessionToken, validateSession } from '../../src/models/session-repository';
 import { storeCredentials, getCredentials } from '../../src/models/credentials-repository';
 import { insertTimesheetEntry, getPendingTimesheetEntries } from '../../src/models/timesheet-repository';
 
@@ -164,7 +164,7 @@ describe('Full Workflow Integration', () => {
       // Manually expire session
       const db = openDb();
       db.prepare('UPDATE sessions SET expires_at = ? WHERE session_token = ?')
-        .run(new Date(Date.now() - 1000).toISOString(), token);
+        .run(new Date(Date.now() - 1000).toISOString(), hashSessionToken(token));
       db.close();
 
       // Session should be invalid
@@ -1,4 +1,4 @@
-import { describe, it, expect, vi, beforeEach } from 'vitest';
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { contextBridge, ipcRenderer } from 'electron';
 
 // Mock electron
@@ -14,14 +14,44 @@ vi.mock('electron', () => ({
   }
 }));
 
+const originalArgv = [...process.argv];
+
 describe('preload.ts', () => {
   beforeEach(async () => {
     vi.clearAllMocks();
     vi.resetModules();
+    // The main window's preload receives its label as an argument
+    process.argv = [...originalArgv, '--sheetpilot-window=main'];
     // Import preload fresh for each test
     await import('../../src/preload');
   });
 
+  afterEach(() => {
+    process.argv = [...originalArgv];
+  });
+
+  it('should withhold sensitive bridges from windows without the capability', async () => {
+    vi.clearAllMocks();
+    vi.resetModules();
+    process.argv = [...originalArgv, '--sheetpilot-window=help'];
+    await import('../../src/preload');
+
+    const exposedNames = vi.mocked(contextBridge.exposeInMainWorld).mock.calls.map(call => call[0]);
+    expect(exposedNames).toContain('timesheet');
+    expect(exposedNames).not.toContain('credentials');
+    expect(exposedNames).not.toContain('admin');
+    expect(exposedNames).not.toContain('database');
+  });
+
+  it('should expose nothing to a window without a label', async () => {
+    vi.clearAllMocks();
+    vi.resetModules();
+    process.argv = [...originalArgv];
+    await import('../../src/preload');
+
+    expect(contextBridge.exposeInMainWorld).not.toHaveBeenCalled();
+  });
+
   it('should expose api.ping handler', async () => {
     expect(contextBridge.exposeInMainWorld).toHaveBeenCalledWith(
       'api',
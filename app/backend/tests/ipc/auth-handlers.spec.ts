@@ -44,6 +44,15 @@ import {
 // Services
 import * as imp from "@/services/timesheet-importer";
 import { submitTimesheets } from "@/services/timesheet-importer";
+import { waitForJob } from "@/services/jobs/job-manager";
+
+/** Follows the job a handler queued and shapes its outcome like a direct response */
+const settleJob = async (response: unknown): Promise<Record<string, unknown>> => {
+  const job = await waitForJob((response as { jobId: string }).jobId);
+  return job?.status === "succeeded"
+    ? { success: true, ...(job.result as Record<string, unknown>) }
+    : { success: false, error: job?.error };
+};
 
 // Shared
 import { ipcLogger, appLogger } from "@sheetpilot/shared/logger";
@@ -311,6 +320,8 @@ vi.mock("../../src/models", () => ({
   storeCredentials: vi.fn(),
   getCredentials: vi.fn(),
   createSession: vi.fn(),
+  rotateSession: vi.fn(),
+  unlockCredentialVault: vi.fn(),
   validateSession: vi.fn(),
   clearSession: vi.fn(),
   clearUserSessions: vi.fn(),
@@ -450,7 +461,7 @@ describe("auth-handlers", () => {
       process.env["SHEETPILOT_ADMIN_USERNAME"] = "Admin";
       process.env["SHEETPILOT_ADMIN_PASSWORD"] = "admin123";
 
-      vi.mocked(repositories.createSession).mockReturnValue("admin-token");
+      vi.mocked(repositories.rotateSession).mockReturnValue("admin-token");
 
       // Re-import the module to pick up new env vars
       vi.resetModules();
@@ -488,6 +499,8 @@ describe("auth-handlers", () => {
 
       expect(result.success).toBe(true);
       expect(result.isAdmin).toBe(true);
+      expect(result.token).toBe("admin-token");
+      expect(repositories.rotateSession).toHaveBeenCalledWith("Admin", false, true);
       expect(repositories.storeCredentials).not.toHaveBeenCalled();
 
       // Restore env vars
@@ -1510,6 +1523,8 @@ vi.mock("../src/models", () => {
 
     // Session operations
     createSession: vi.fn(() => "mock-session-token"),
+    rotateSession: vi.fn(() => "mock-session-token"),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === "valid-token" || token === "mock-session-token") {
         return { valid: true, email: "user@test.com", isAdmin: false };
@@ -1518,7 +1533,8 @@ vi.mock("../src/models", () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({
@@ -2020,7 +2036,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
       ];
       mdb.getSubmittedTimesheetEntriesForExport.mockReturnValue(mockEntries);
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2038,7 +2054,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
     it("should handle empty data export", async () => {
       mdb.getSubmittedTimesheetEntriesForExport.mockReturnValue([]);
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2053,7 +2069,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         throw new Error("Export failed");
       });
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2483,7 +2499,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         password: "password123",
       });
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2522,7 +2538,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
     it("should handle missing credentials", async () => {
       mdb.getCredentials.mockReturnValue(null);
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2545,7 +2561,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         password: "password123",
       });
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2739,6 +2755,8 @@ vi.mock("../src/models", () => {
 
     // Session operations
     createSession: vi.fn(() => "mock-session-token"),
+    rotateSession: vi.fn(() => "mock-session-token"),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === "valid-token" || token === "mock-session-token") {
         return { valid: true, email: "user@test", isAdmin: false };
@@ -2747,7 +2765,8 @@ vi.mock("../src/models", () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({
@@ -2832,7 +2851,7 @@ describe("Electron IPC Handlers (main.ts)", () => {
         getCredentials: { mockReturnValue: (value: unknown) => void };
       }
     ).getCredentials.mockReturnValue(null);
-    const res = (await handlers["timesheet:submit"]("valid-token")) as {
+    const res = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
       submitResult?: {
         ok: boolean;
         successCount: number;
@@ -2854,7 +2873,7 @@ describe("Electron IPC Handlers (main.ts)", () => {
       password: "pw",
     });
 
-    const res = (await handlers["timesheet:submit"]("valid-token")) as {
+    const res = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
       submitResult?: {
         ok: boolean;
         successCount: number;
@@ -3406,8 +3425,11 @@ describe("logger-handlers", () => {
       const mockEvent = {};
       userActionHandler(mockEvent, "button-click", { buttonId: "submit" });
 
-      expect(ipcLogger.info).toHaveBeenCalledWith("User action: button-click", {
+      expect(ipcLogger.info).toHaveBeenCalledWith("User action", {
         buttonId: "submit",
+        source: "frontend",
+        category: "user-action",
+        action: "button-click",
       });
     });
 
@@ -44,6 +44,15 @@ import {
 // Services
 import * as imp from "@/services/timesheet-importer";
 import { submitTimesheets } from "@/services/timesheet-importer";
+import { waitForJob } from "@/services/jobs/job-manager";
+
+/** Follows the job a handler queued and shapes its outcome like a direct response */
+const settleJob = async (response: unknown): Promise<Record<string, unknown>> => {
+  const job = await waitForJob((response as { jobId: string }).jobId);
+  return job?.status === "succeeded"
+    ? { success: true, ...(job.result as Record<string, unknown>) }
+    : { success: false, error: job?.error };
+};
 
 // Shared
 import { ipcLogger, appLogger } from "@sheetpilot/shared/logger";
@@ -1500,6 +1509,12 @@ vi.mock("../src/models", () => {
     removeFailedTimesheetEntries: vi.fn(),
     getTimesheetEntriesByIds: vi.fn(() => []),
     getSubmittedTimesheetEntriesForExport: vi.fn(() => []),
+    getTimesheetEntryStatus: vi.fn(() => undefined),
+    SUBMITTED_ENTRY_LOCKED_ERROR: "This entry has already been submitted",
+
+    // Validation rules
+    listValidationRules: vi.fn(() => []),
+    getProjectRetirements: vi.fn(() => []),
 
     // Credentials operations
     storeCredentials: vi.fn(),
@@ -1510,6 +1525,8 @@ vi.mock("../src/models", () => {
 
     // Session operations
     createSession: vi.fn(() => "mock-session-token"),
+    rotateSession: vi.fn(() => "mock-session-token"),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === "valid-token" || token === "mock-session-token") {
         return { valid: true, email: "user@test.com", isAdmin: false };
@@ -1518,7 +1535,8 @@ vi.mock("../src/models", () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({
@@ -2020,7 +2038,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
       ];
       mdb.getSubmittedTimesheetEntriesForExport.mockReturnValue(mockEntries);
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2038,7 +2056,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
     it("should handle empty data export", async () => {
       mdb.getSubmittedTimesheetEntriesForExport.mockReturnValue([]);
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2053,7 +2071,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         throw new Error("Export failed");
       });
 
-      const result = (await handlers["timesheet:exportToCSV"]()) as {
+      const result = (await settleJob(await handlers["timesheet:exportToCSV"]())) as {
         success: boolean;
         csvData?: string;
         error?: string;
@@ -2482,7 +2500,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         password: "password123",
       });
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2521,7 +2539,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
     it("should handle missing credentials", async () => {
       mdb.getCredentials.mockReturnValue(null);
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2544,7 +2562,7 @@ describe("IPC Handlers Comprehensive Tests", () => {
         password: "password123",
       });
 
-      const result = (await handlers["timesheet:submit"]("valid-token")) as {
+      const result = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
         submitResult?: {
           ok: boolean;
           successCount: number;
@@ -2728,6 +2746,12 @@ vi.mock("../src/models", () => {
     removeFailedTimesheetEntries: vi.fn(),
     getTimesheetEntriesByIds: vi.fn(() => []),
     getSubmittedTimesheetEntriesForExport: vi.fn(() => []),
+    getTimesheetEntryStatus: vi.fn(() => undefined),
+    SUBMITTED_ENTRY_LOCKED_ERROR: "This entry has already been submitted",
+
+    // Validation rules
+    listValidationRules: vi.fn(() => []),
+    getProjectRetirements: vi.fn(() => []),
 
     // Credentials operations
     storeCredentials: vi.fn(),
@@ -2738,6 +2762,8 @@ vi.mock("../src/models", () => {
 
     // Session operations
     createSession: vi.fn(() => "mock-session-token"),
+    rotateSession: vi.fn(() => "mock-session-token"),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === "valid-token" || token === "mock-session-token") {
         return { valid: true, email: "user@test", isAdmin: false };
@@ -2746,7 +2772,8 @@ vi.mock("../src/models", () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({
@@ -2831,7 +2858,7 @@ describe("Electron IPC Handlers (main.ts)", () => {
         getCredentials: { mockReturnValue: (value: unknown) => void };
       }
     ).getCredentials.mockReturnValue(null);
-    const res = (await handlers["timesheet:submit"]("valid-token")) as {
+    const res = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
       submitResult?: {
         ok: boolean;
         successCount: number;
@@ -2853,7 +2880,7 @@ describe("Electron IPC Handlers (main.ts)", () => {
       password: "pw",
     });
 
-    const res = (await handlers["timesheet:submit"]("valid-token")) as {
+    const res = (await settleJob(await handlers["timesheet:submit"]("valid-token"))) as {
       submitResult?: {
         ok: boolean;
         successCount: number;
@@ -3405,8 +3432,11 @@ describe("logger-handlers", () => {
       const mockEvent = {};
       userActionHandler(mockEvent, "button-click", { buttonId: "submit" });
 
-      expect(ipcLogger.info).toHaveBeenCalledWith("User action: button-click", {
+      expect(ipcLogger.info).toHaveBeenCalledWith("User action", {
         buttonId: "submit",
+        source: "frontend",
+        category: "user-action",
+        action: "button-click",
       });
     });
 
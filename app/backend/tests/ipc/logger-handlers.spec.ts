@@ -255,6 +255,8 @@ vi.mock('../../src/models', () => ({
   storeCredentials: vi.fn(),
   getCredentials: vi.fn(),
   createSession: vi.fn(),
+  rotateSession: vi.fn(),
+  unlockCredentialVault: vi.fn(),
   validateSession: vi.fn(),
   clearSession: vi.fn(),
   clearUserSessions: vi.fn()
@@ -358,7 +360,7 @@ describe('auth-handlers', () => {
       process.env['SHEETPILOT_ADMIN_USERNAME'] = 'Admin';
       process.env['SHEETPILOT_ADMIN_PASSWORD'] = 'admin123';
 
-      vi.mocked(repositories.createSession).mockReturnValue('admin-token');
+      vi.mocked(repositories.rotateSession).mockReturnValue('admin-token');
 
       // Re-import the module to pick up new env vars
       vi.resetModules();
@@ -1176,6 +1178,8 @@ vi.mock('../src/models', () => {
 
     // Session operations
     createSession: vi.fn(() => 'mock-session-token'),
+    rotateSession: vi.fn(() => 'mock-session-token'),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === 'valid-token' || token === 'mock-session-token') {
         return { valid: true, email: 'user@test.com', isAdmin: false };
@@ -1184,7 +1188,8 @@ vi.mock('../src/models', () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({ success: true, migrationsRun: 0, fromVersion: 0, toVersion: 0 }))
@@ -2152,6 +2157,8 @@ vi.mock('../src/models', () => {
 
     // Session operations
     createSession: vi.fn(() => 'mock-session-token'),
+    rotateSession: vi.fn(() => 'mock-session-token'),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === 'valid-token' || token === 'mock-session-token') {
         return { valid: true, email: 'user@test', isAdmin: false };
@@ -2160,7 +2167,8 @@ vi.mock('../src/models', () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({ success: true, migrationsRun: 0, fromVersion: 0, toVersion: 0 }))
@@ -2690,7 +2698,12 @@ describe('logger-handlers', () => {
       const mockEvent = {};
       userActionHandler(mockEvent, 'button-click', { buttonId: 'submit' });
 
-      expect(ipcLogger.info).toHaveBeenCalledWith('User action: button-click', { buttonId: 'submit' });
+      expect(ipcLogger.info).toHaveBeenCalledWith('User action', {
+        buttonId: 'submit',
+        source: 'frontend',
+        category: 'user-action',
+        action: 'button-click'
+      });
     });
 
     it('should handle handlers without data parameter', () => {
@@ -2705,6 +2718,57 @@ describe('logger-handlers', () => {
 
       expect(ipcLogger.error).toHaveBeenCalledWith('Error without data', undefined);
     });
+
+    it('should map structured fields into log metadata instead of the message', () => {
+      registerLoggerHandlers();
+
+      const infoHandler = vi.mocked(ipcMain.on).mock.calls.find(
+        call => call[0] === 'logger:info'
+      )?.[1] as (event: unknown, message: string, data?: unknown, fields?: unknown) => void;
+
+      infoHandler({}, 'Draft saved', { rowCount: 3 }, {
+        category: 'timesheet',
+        component: 'TimesheetGrid',
+        userActionId: 'ua-123'
+      });
+
+      expect(ipcLogger.info).toHaveBeenCalledWith('Draft saved', {
+        rowCount: 3,
+        source: 'frontend',
+        category: 'timesheet',
+        component: 'TimesheetGrid',
+        userActionId: 'ua-123'
+      });
+    });
+
+    it('should let user actions override the default category', () => {
+      registerLoggerHandlers();
+
+      const userActionHandler = vi.mocked(ipcMain.on).mock.calls.find(
+        call => call[0] === 'logger:user-action'
+      )?.[1] as (event: unknown, action: string, data?: unknown, fields?: unknown) => void;
+
+      userActionHandler({}, 'tab-change', undefined, { category: 'navigation', userActionId: 'ua-9' });
+
+      expect(ipcLogger.info).toHaveBeenCalledWith('User action', {
+        source: 'frontend',
+        category: 'navigation',
+        userActionId: 'ua-9',
+        action: 'tab-change'
+      });
+    });
+
+    it('should drop malformed structured fields but still log the message', () => {
+      registerLoggerHandlers();
+
+      const warnHandler = vi.mocked(ipcMain.on).mock.calls.find(
+        call => call[0] === 'logger:warn'
+      )?.[1] as (event: unknown, message: string, data?: unknown, fields?: unknown) => void;
+
+      warnHandler({}, 'Bad fields', { detail: 'x' }, { category: 'has spaces', extra: true });
+
+      expect(ipcLogger.warn).toHaveBeenCalledWith('Bad fields', { detail: 'x' });
+    });
   });
 });
 
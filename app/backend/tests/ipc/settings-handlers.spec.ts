@@ -255,6 +255,8 @@ vi.mock('../../src/models', () => ({
   storeCredentials: vi.fn(),
   getCredentials: vi.fn(),
   createSession: vi.fn(),
+  rotateSession: vi.fn(),
+  unlockCredentialVault: vi.fn(),
   validateSession: vi.fn(),
   clearSession: vi.fn(),
   clearUserSessions: vi.fn()
@@ -358,7 +360,7 @@ describe('auth-handlers', () => {
       process.env['SHEETPILOT_ADMIN_USERNAME'] = 'Admin';
       process.env['SHEETPILOT_ADMIN_PASSWORD'] = 'admin123';
 
-      vi.mocked(repositories.createSession).mockReturnValue('admin-token');
+      vi.mocked(repositories.rotateSession).mockReturnValue('admin-token');
 
       // Re-import the module to pick up new env vars
       vi.resetModules();
@@ -1176,6 +1178,8 @@ vi.mock('../src/models', () => {
 
     // Session operations
     createSession: vi.fn(() => 'mock-session-token'),
+    rotateSession: vi.fn(() => 'mock-session-token'),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === 'valid-token' || token === 'mock-session-token') {
         return { valid: true, email: 'user@test.com', isAdmin: false };
@@ -1184,7 +1188,8 @@ vi.mock('../src/models', () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({ success: true, migrationsRun: 0, fromVersion: 0, toVersion: 0 }))
@@ -2158,6 +2163,8 @@ vi.mock('../src/models', () => {
 
     // Session operations
     createSession: vi.fn(() => 'mock-session-token'),
+    rotateSession: vi.fn(() => 'mock-session-token'),
+    unlockCredentialVault: vi.fn(),
     validateSession: vi.fn((token: string) => {
       if (token === 'valid-token' || token === 'mock-session-token') {
         return { valid: true, email: 'user@test', isAdmin: false };
@@ -2166,7 +2173,8 @@ vi.mock('../src/models', () => {
     }),
     clearSession: vi.fn(),
     clearUserSessions: vi.fn(),
-    getSessionByEmail: vi.fn(),
+    hasActiveSession: vi.fn(() => false),
+    setSessionInstanceId: vi.fn(),
 
     // Migrations (used by bootstrap-database)
     runMigrations: vi.fn(() => ({ success: true, migrationsRun: 0, fromVersion: 0, toVersion: 0 }))
@@ -2696,7 +2704,12 @@ describe('logger-handlers', () => {
       const mockEvent = {};
       userActionHandler(mockEvent, 'button-click', { buttonId: 'submit' });
 
-      expect(ipcLogger.info).toHaveBeenCalledWith('User action: button-click', { buttonId: 'submit' });
+      expect(ipcLogger.info).toHaveBeenCalledWith('User action', {
+        buttonId: 'submit',
+        source: 'frontend',
+        category: 'user-action',
+        action: 'button-click'
+      });
     });
 
     it('should handle handlers without data parameter', () => {
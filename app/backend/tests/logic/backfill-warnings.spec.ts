@@ -0,0 +1,80 @@
+/**
+ * @fileoverview Backfill Warning Tests
+ *
+ * Tests quarter closure detection for entries dated in past or locking
+ * quarters.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, afterEach } from 'vitest';
+import { QUARTER_DEFINITIONS, getQuarterClosure } from '@sheetpilot/bot';
+import { getBackfillWarning, getBackfillWarnings } from '../../src/logic/backfill-warnings';
+
+const quarter = QUARTER_DEFINITIONS[QUARTER_DEFINITIONS.length - 1]!;
+const earliest = [...QUARTER_DEFINITIONS].sort((a, b) => a.startDate.localeCompare(b.startDate))[0]!;
+
+const localDate = (iso: string): Date => {
+  const [y, m, d] = iso.split('-').map(Number);
+  return new Date(y!, m! - 1, d!);
+};
+
+describe('Backfill Warnings', () => {
+  afterEach(() => {
+    delete quarter.lockDate;
+  });
+
+  it('should treat quarters without a lock date as open', () => {
+    expect(getQuarterClosure(quarter.startDate, localDate(quarter.startDate)).state).toBe('open');
+    expect(getBackfillWarning(quarter.startDate, localDate(quarter.startDate))).toBeNull();
+  });
+
+  it('should warn when the lock date is within a week', () => {
+    quarter.lockDate = quarter.endDate;
+    const today = localDate(quarter.endDate);
+    today.setDate(today.getDate() - 3);
+
+    const closure = getQuarterClosure(quarter.startDate, today);
+    expect(closure.state).toBe('closing');
+    expect(closure.daysUntilLock).toBe(3);
+    expect(closure.message).toContain(quarter.endDate);
+  });
+
+  it('should report the quarter closed after its lock date', () => {
+    quarter.lockDate = quarter.endDate;
+    const today = localDate(quarter.endDate);
+    today.setDate(today.getDate() + 1);
+
+    const warning = getBackfillWarning(quarter.startDate, today, 12);
+    expect(warning?.state).toBe('closed');
+    expect(warning?.id).toBe(12);
+    expect(warning?.quarterId).toBe(quarter.id);
+  });
+
+  it('should report dates before the rolling window as closed', () => {
+    const before = localDate(earliest.startDate);
+    before.setDate(before.getDate() - 1);
+    const iso = `${before.getFullYear()}-${String(before.getMonth() + 1).padStart(2, '0')}-${String(before.getDate()).padStart(2, '0')}`;
+
+    expect(getQuarterClosure(iso).state).toBe('closed');
+  });
+
+  it('should accept MM/DD/YYYY dates and skip empty or malformed ones', () => {
+    quarter.lockDate = quarter.endDate;
+    const today = localDate(quarter.endDate);
+    today.setDate(today.getDate() + 1);
+    const [y, m, d] = quarter.startDate.split('-');
+
+    const warnings = getBackfillWarnings(
+      [
+        { id: 1, date: `${m}/${d}/${y}` },
+        { id: 2, date: '' },
+        { id: 3, date: 'not a date' }
+      ],
+      today
+    );
+    expect(warnings.map((w) => w.id)).toEqual([1]);
+  });
+});
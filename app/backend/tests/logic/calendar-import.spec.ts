@@ -0,0 +1,137 @@
+/**
+ * @fileoverview Calendar Import Logic Tests
+ *
+ * Tests for converting calendar events into draft timesheet previews.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildCalendarDraftPreviews,
+  matchesCalendarRules,
+  roundMinutesToQuarterHours,
+  type CalendarEvent
+} from '../../src/logic/calendar-import';
+
+const localIso = (y: number, m: number, d: number, h: number, min = 0): string =>
+  new Date(y, m - 1, d, h, min).toISOString();
+
+const makeEvent = (overrides: Partial<CalendarEvent> = {}): CalendarEvent => ({
+  id: 'evt-1',
+  subject: 'Weekly sync',
+  start: localIso(2025, 3, 4, 9),
+  end: localIso(2025, 3, 4, 10),
+  categories: [],
+  isAllDay: false,
+  isCancelled: false,
+  showAs: 'busy',
+  ...overrides
+});
+
+const mapping = { project: 'Internal Meetings', tool: 'Internal Meeting' };
+
+describe('Calendar Import Logic', () => {
+  describe('roundMinutesToQuarterHours', () => {
+    it('should round to the nearest 15 minutes', () => {
+      expect(roundMinutesToQuarterHours(60)).toBe(1);
+      expect(roundMinutesToQuarterHours(50)).toBe(0.75);
+      expect(roundMinutesToQuarterHours(7)).toBe(0);
+    });
+  });
+
+  describe('matchesCalendarRules', () => {
+    it('should include everything when no include rules are set', () => {
+      expect(matchesCalendarRules(makeEvent(), {})).toBe(true);
+    });
+
+    it('should match categories and keywords case-insensitively', () => {
+      expect(matchesCalendarRules(makeEvent({ categories: ['Team'] }), { includeCategories: ['team'] })).toBe(true);
+      expect(matchesCalendarRules(makeEvent(), { includeKeywords: ['SYNC'] })).toBe(true);
+      expect(matchesCalendarRules(makeEvent(), { includeKeywords: ['review'] })).toBe(false);
+    });
+
+    it('should let exclude keywords win over includes', () => {
+      expect(matchesCalendarRules(makeEvent(), { includeKeywords: ['sync'], excludeKeywords: ['weekly'] })).toBe(false);
+    });
+  });
+
+  describe('buildCalendarDraftPreviews', () => {
+    it('should convert a meeting into a draft on its local date', () => {
+      const { drafts, skipped } = buildCalendarDraftPreviews([makeEvent()], {}, mapping);
+      expect(skipped).toHaveLength(0);
+      expect(drafts).toEqual([{
+        eventId: 'evt-1',
+        date: '03/04/2025',
+        hours: 1,
+        project: 'Internal Meetings',
+        tool: 'Internal Meeting',
+        chargeCode: null,
+        taskDescription: 'Weekly sync',
+        isDuplicate: false
+      }]);
+    });
+
+    it('should skip cancelled, all-day, free and too-short events', () => {
+      const { drafts, skipped } = buildCalendarDraftPreviews([
+        makeEvent({ id: 'a', isCancelled: true }),
+        makeEvent({ id: 'b', isAllDay: true }),
+        makeEvent({ id: 'c', showAs: 'free' }),
+        makeEvent({ id: 'd', end: localIso(2025, 3, 4, 9, 5) })
+      ], {}, mapping);
+      expect(drafts).toHaveLength(0);
+      expect(skipped.map((s) => s.reason)).toEqual(['cancelled', 'all-day', 'free', 'too-short']);
+    });
+
+    it('should flag existing entries and repeats within the batch as duplicates', () => {
+      const { drafts } = buildCalendarDraftPreviews(
+        [
+          makeEvent({ id: 'a' }),
+          makeEvent({ id: 'b', start: localIso(2025, 3, 4, 14), end: localIso(2025, 3, 4, 15) }),
+          makeEvent({ id: 'c', subject: 'Design review' })
+        ],
+        {},
+        mapping,
+        (key) => key.taskDescription === 'Design review'
+      );
+      expect(drafts.map((d) => d.isDuplicate)).toEqual([false, true, true]);
+    });
+
+    it('should date meetings in the home zone and record the trip zone', () => {
+      // 11pm in Texas is already the next day for a home zone on the East Coast
+      const lateMeeting = makeEvent({ start: '2025-03-05T05:00:00Z', end: '2025-03-05T05:45:00Z' });
+      const { drafts } = buildCalendarDraftPreviews(
+        [lateMeeting],
+        {},
+        mapping,
+        undefined,
+        { home: 'America/Chicago', entry: 'America/Chicago' }
+      );
+      expect(drafts[0]).toMatchObject({ date: '03/04/2025', hours: 0.75, timeZone: 'America/Chicago' });
+
+      const { drafts: fromEast } = buildCalendarDraftPreviews(
+        [lateMeeting],
+        {},
+        mapping,
+        undefined,
+        { home: 'America/New_York', entry: 'America/Chicago' }
+      );
+      expect(fromEast[0]).toMatchObject({ date: '03/05/2025', hours: 0.75, timeZone: 'America/Chicago' });
+    });
+
+    it('should treat a meeting as multi-day only when it crosses midnight at home', () => {
+      const { drafts, skipped } = buildCalendarDraftPreviews(
+        [makeEvent({ start: '2025-03-04T23:30:00Z', end: '2025-03-05T00:30:00Z' })],
+        {},
+        mapping,
+        undefined,
+        { home: 'America/Denver' }
+      );
+      expect(skipped).toHaveLength(0);
+      expect(drafts[0]?.date).toBe('03/04/2025');
+      expect(drafts[0]).not.toHaveProperty('timeZone');
+    });
+  });
+});
@@ -0,0 +1,84 @@
+/**
+ * @fileoverview Charge Code Quota Tests
+ *
+ * Tests projecting submitted plus pending hours against per-quarter charge
+ * code quotas and warning only when pending entries push a quota over.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildQuotaStatus,
+  getQuotaWarnings,
+  type ChargeCodeQuota
+} from '../../src/logic/charge-code-quotas';
+
+const quota = (chargeCode: string, hours: number, quarterId = 'Q4-2025'): ChargeCodeQuota => ({
+  chargeCode,
+  quarterId,
+  hours
+});
+
+const entry = (id: number, date: string, hours: number, chargeCode: string | null) => ({
+  id,
+  date,
+  hours,
+  detail_charge_code: chargeCode
+});
+
+describe('Charge Code Quotas', () => {
+  it('should add pending hours in the same quarter to submitted hours', () => {
+    const [status] = buildQuotaStatus(
+      [quota('EPR1', 40)],
+      () => 20,
+      [
+        entry(1, '2025-10-06', 8, 'EPR1'),
+        entry(2, '11/03/2025', 4, ' epr1 '),
+        entry(3, '2026-01-05', 8, 'EPR1'),
+        entry(4, '2025-10-07', 8, 'OTHER')
+      ]
+    );
+
+    expect(status).toMatchObject({
+      submittedHours: 20,
+      pendingHours: 12,
+      projectedHours: 32,
+      remainingHours: 8,
+      state: 'ok'
+    });
+  });
+
+  it('should mark quotas near from 90% and over past 100%', () => {
+    const statuses = buildQuotaStatus(
+      [quota('NEAR', 10), quota('OVER', 10)],
+      (q) => (q.chargeCode === 'NEAR' ? 9 : 8),
+      [entry(1, '2025-10-06', 4, 'OVER')]
+    );
+
+    expect(statuses.map((s) => s.state)).toEqual(['near', 'over']);
+    expect(statuses[1]!.remainingHours).toBe(-2);
+  });
+
+  it('should warn only when pending entries add to an exceeded quota', () => {
+    const pending = [entry(7, '2025-10-06', 4, 'OVER'), entry(8, '2025-10-08', 2, 'OVER')];
+    const statuses = buildQuotaStatus(
+      [quota('OVER', 10), quota('ALREADY', 10)],
+      (q) => (q.chargeCode === 'OVER' ? 8 : 12),
+      pending
+    );
+
+    const warnings = getQuotaWarnings(statuses, pending);
+
+    expect(warnings).toHaveLength(1);
+    expect(warnings[0]).toMatchObject({
+      chargeCode: 'OVER',
+      quotaHours: 10,
+      projectedHours: 14,
+      entryIds: [7, 8]
+    });
+    expect(warnings[0]!.message).toContain('14h of its 10h quota');
+  });
+});
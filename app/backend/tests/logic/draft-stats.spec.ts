@@ -0,0 +1,69 @@
+/**
+ * @fileoverview Draft Statistics Tests
+ *
+ * Tests the editor header summary built from per-date draft totals.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import type { QuarterDefinition } from '@sheetpilot/bot';
+import { summarizeDraftStats } from '../../src/logic/draft-stats';
+
+const quarter = (id: string, name: string): QuarterDefinition => ({
+  id,
+  name,
+  startDate: '',
+  endDate: '',
+  formUrl: '',
+  formId: '',
+});
+
+const quarterFor = (date: string): QuarterDefinition | null => {
+  if (date.startsWith('2025-12')) return quarter('Q4-2025', 'Q4 2025');
+  if (date.startsWith('2026-01')) return quarter('Q1-2026', 'Q1 2026');
+  return null;
+};
+
+describe('Draft Statistics', () => {
+  it('should return zeros when there are no drafts', () => {
+    expect(summarizeDraftStats([], quarterFor)).toEqual({
+      draftCount: 0,
+      totalHours: 0,
+      datesCovered: 0,
+      earliestDate: null,
+      latestDate: null,
+      undatedCount: 0,
+      quarters: [],
+    });
+  });
+
+  it('should total drafts and group them by quarter in date order', () => {
+    const stats = summarizeDraftStats(
+      [
+        { date: '2026-01-05', entryCount: 2, hours: 7.5 },
+        { date: '2025-12-30', entryCount: 1, hours: 0.1 },
+        { date: '2025-12-31', entryCount: 3, hours: 0.2 },
+        { date: '2024-06-01', entryCount: 1, hours: 1 },
+        { date: null, entryCount: 2, hours: 0.25 },
+      ],
+      quarterFor
+    );
+
+    expect(stats).toMatchObject({
+      draftCount: 9,
+      totalHours: 9.05,
+      datesCovered: 4,
+      earliestDate: '2024-06-01',
+      latestDate: '2026-01-05',
+      undatedCount: 2,
+    });
+    expect(stats.quarters).toEqual([
+      { quarterId: 'Q4-2025', quarterName: 'Q4 2025', draftCount: 4, hours: 0.3 },
+      { quarterId: 'Q1-2026', quarterName: 'Q1 2026', draftCount: 2, hours: 7.5 },
+      { quarterId: null, quarterName: null, draftCount: 1, hours: 1 },
+    ]);
+  });
+});
@@ -0,0 +1,88 @@
+/**
+ * @fileoverview Entry Kind Rule Tests
+ *
+ * Tests reading the PTO, holiday and training rules per service profile and
+ * how each entry is mapped or left out of a submission.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { planEntryKindSubmission, resolveEntryKindRules } from '../../src/logic/entry-kinds';
+
+const options = {
+  entryKinds: {
+    default: {
+      pto: { project: 'PTO/RTO' },
+      holiday: 'exclude',
+      work: 'exclude'
+    },
+    'mock-submission': {
+      training: { project: 'Training', tool: 'Classroom', chargeCode: 'TRN1' }
+    }
+  }
+};
+
+const entry = (overrides: Record<string, unknown> = {}) => ({
+  entry_kind: null as string | null,
+  project: 'FL-Carver Techs' as string | null,
+  tool: 'DECA Meter',
+  detail_charge_code: 'EPR1',
+  ...overrides
+});
+
+describe('Entry Kind Rules', () => {
+  describe('resolveEntryKindRules', () => {
+    it('should use the profile rules, falling back to the default profile', () => {
+      expect(resolveEntryKindRules(options, 'electron')).toEqual({
+        pto: { action: 'map', project: 'PTO/RTO', tool: null, chargeCode: null },
+        holiday: { action: 'exclude' }
+      });
+      expect(resolveEntryKindRules(options, 'mock-submission')).toEqual({
+        training: { action: 'map', project: 'Training', tool: 'Classroom', chargeCode: 'TRN1' }
+      });
+    });
+
+    it('should return no rules when none are configured or they are malformed', () => {
+      expect(resolveEntryKindRules(undefined, 'electron')).toEqual({});
+      expect(resolveEntryKindRules({ entryKinds: { default: { pto: { tool: 'x' } } } }, null)).toEqual({});
+    });
+  });
+
+  describe('planEntryKindSubmission', () => {
+    const rules = resolveEntryKindRules(options, 'electron');
+
+    it('should submit work entries as entered', () => {
+      expect(planEntryKindSubmission(entry(), rules)).toEqual({
+        action: 'submit',
+        project: 'FL-Carver Techs',
+        tool: 'DECA Meter',
+        chargeCode: 'EPR1'
+      });
+    });
+
+    it('should map and exclude time off by the rules', () => {
+      expect(planEntryKindSubmission(entry({ entry_kind: 'pto', project: null }), rules)).toEqual({
+        action: 'submit',
+        project: 'PTO/RTO',
+        tool: null,
+        chargeCode: null
+      });
+      expect(planEntryKindSubmission(entry({ entry_kind: 'holiday' }), rules)).toEqual({
+        action: 'exclude',
+        reason: 'Holiday entries are not submitted for this service'
+      });
+    });
+
+    it('should leave out time off that has no project to submit under', () => {
+      const plan = planEntryKindSubmission(entry({ entry_kind: 'pto', project: null }), {});
+      expect(plan.action).toBe('exclude');
+      expect(planEntryKindSubmission(entry({ entry_kind: 'pto' }), {})).toMatchObject({
+        action: 'submit',
+        project: 'FL-Carver Techs'
+      });
+    });
+  });
+});
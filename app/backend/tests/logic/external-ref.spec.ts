@@ -0,0 +1,68 @@
+/**
+ * @fileoverview External Reference Logic Tests
+ *
+ * Tests the configurable reference format and appending references to the
+ * task description sent to the form.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  checkExternalRef,
+  describeWithExternalRef,
+  resolveExternalRefRule
+} from '../../src/logic/external-ref';
+
+describe('External Reference Logic', () => {
+  describe('resolveExternalRefRule', () => {
+    it('should enforce nothing without a pattern', () => {
+      const { rule, error } = resolveExternalRefRule(undefined);
+      expect(error).toBeNull();
+      expect(rule).toEqual({ pattern: null, source: null, appendToDescription: false });
+    });
+
+    it('should report an invalid pattern and not enforce it', () => {
+      const { rule, error } = resolveExternalRefRule({ pattern: 'WO-(', append_to_description: true });
+      expect(error).toContain('Invalid external_ref pattern');
+      expect(rule.pattern).toBeNull();
+      expect(rule.appendToDescription).toBe(true);
+    });
+  });
+
+  describe('checkExternalRef', () => {
+    const { rule } = resolveExternalRefRule({ pattern: 'WO-\\d{6}' });
+
+    it('should require the whole reference to match', () => {
+      expect(checkExternalRef('WO-104233', rule)).toBeNull();
+      expect(checkExternalRef('  WO-104233 ', rule)).toBeNull();
+      expect(checkExternalRef('WO-104233-A', rule)).toContain('does not match the required format (WO-\\d{6})');
+      expect(checkExternalRef('see WO-104233', rule)).not.toBeNull();
+    });
+
+    it('should allow entries without a reference', () => {
+      expect(checkExternalRef(null, rule)).toBeNull();
+      expect(checkExternalRef('', rule)).toBeNull();
+    });
+  });
+
+  describe('describeWithExternalRef', () => {
+    const { rule: appending } = resolveExternalRefRule({ append_to_description: true });
+
+    it('should append the reference when configured', () => {
+      expect(describeWithExternalRef('Calibration', 'WO-104233', appending)).toBe('Calibration [WO-104233]');
+    });
+
+    it('should not append twice or without a reference', () => {
+      expect(describeWithExternalRef('Calibration [WO-104233]', 'WO-104233', appending)).toBe('Calibration [WO-104233]');
+      expect(describeWithExternalRef('Calibration', null, appending)).toBe('Calibration');
+    });
+
+    it('should leave the description alone when appending is off', () => {
+      const { rule } = resolveExternalRefRule({ pattern: 'WO-\\d{6}' });
+      expect(describeWithExternalRef('Calibration', 'WO-104233', rule)).toBe('Calibration');
+    });
+  });
+});
@@ -0,0 +1,134 @@
+import { describe, it, expect } from "vitest";
+import {
+  suggestNextEntry,
+  partitionHistoryByDate,
+  type SuggestionHistoryEntry,
+} from "../../src/logic/next-entry-suggestion";
+
+let nextId = 1;
+const entry = (
+  date: string,
+  project: string,
+  taskDescription: string,
+  hours: number | null = 2
+): SuggestionHistoryEntry => ({
+  id: nextId++,
+  date,
+  hours,
+  project,
+  tool: null,
+  detail_charge_code: null,
+  task_description: taskDescription,
+});
+
+describe("next-entry-suggestion", () => {
+  describe("suggestNextEntry", () => {
+    it("should return null when there is no history", () => {
+      expect(suggestNextEntry("01/13/2025", [])).toBeNull();
+    });
+
+    it("should return null for an invalid target date", () => {
+      const history = [entry("01/06/2025", "PTO/RTO", "Standup")];
+      expect(suggestNextEntry("not-a-date", history)).toBeNull();
+    });
+
+    it("should prefer entries seen on the same weekday", () => {
+      // 01/06 and 12/30 are Mondays; 01/08 is a Wednesday
+      const history = [
+        entry("12/30/2024", "FL-Carver Techs", "Monday sync", 1),
+        entry("01/06/2025", "FL-Carver Techs", "Monday sync", 1),
+        entry("01/08/2025", "OSC-BBB", "Wafer review", 3),
+        entry("01/09/2025", "OSC-BBB", "Wafer review", 3),
+      ];
+
+      const suggestion = suggestNextEntry("01/13/2025", history);
+
+      expect(suggestion).not.toBeNull();
+      expect(suggestion!.taskDescription).toBe("Monday sync");
+      expect(suggestion!.hours).toBe(1);
+      expect(suggestion!.basis).toBe("same-weekday");
+      expect(suggestion!.confidence).toBe(1);
+      expect(suggestion!.date).toBe("01/13/2025");
+    });
+
+    it("should suggest the block that usually follows the last entry on the date", () => {
+      const history = [
+        entry("01/06/2025", "PTO/RTO", "Standup", 0.5),
+        entry("01/06/2025", "OSC-BBB", "Wafer review", 3),
+        entry("12/30/2024", "PTO/RTO", "Standup", 0.5),
+        entry("12/30/2024", "OSC-BBB", "Wafer review", 3),
+      ];
+      const existing = [entry("01/13/2025", "PTO/RTO", "Standup", 0.5)];
+
+      const suggestion = suggestNextEntry("01/13/2025", history, existing);
+
+      expect(suggestion!.taskDescription).toBe("Wafer review");
+      expect(suggestion!.basis).toBe("follows-previous-block");
+    });
+
+    it("should never suggest an entry already present on the date", () => {
+      const history = [entry("01/06/2025", "PTO/RTO", "Standup", 0.5)];
+      const existing = [entry("01/13/2025", "PTO/RTO", "Standup", 0.5)];
+
+      expect(suggestNextEntry("01/13/2025", history, existing)).toBeNull();
+    });
+
+    it("should cap hours to the remaining daily capacity", () => {
+      const history = [entry("01/06/2025", "OSC-BBB", "Wafer review", 4)];
+      const existing = [entry("01/13/2025", "PTO/RTO", "Standup", 7)];
+
+      const suggestion = suggestNextEntry("01/13/2025", history, existing, {
+        maxDailyHours: 8,
+      });
+
+      expect(suggestion!.hours).toBe(1);
+    });
+
+    it("should return null when the date is already full", () => {
+      const history = [entry("01/06/2025", "OSC-BBB", "Wafer review", 4)];
+      const existing = [entry("01/13/2025", "PTO/RTO", "Standup", 8)];
+
+      expect(
+        suggestNextEntry("01/13/2025", history, existing, { maxDailyHours: 8 })
+      ).toBeNull();
+    });
+
+    it("should ignore history outside the lookback window and in the future", () => {
+      const history = [
+        entry("01/01/2024", "OSC-BBB", "Old work"),
+        entry("02/03/2025", "OSC-BBB", "Future work"),
+      ];
+
+      expect(
+        suggestNextEntry("01/13/2025", history, [], { lookbackWeeks: 4 })
+      ).toBeNull();
+    });
+
+    it("should accept ISO dates in history", () => {
+      const history = [entry("2025-01-06", "OSC-BBB", "Wafer review", 3)];
+
+      const suggestion = suggestNextEntry("2025-01-13", history);
+
+      expect(suggestion!.project).toBe("OSC-BBB");
+      expect(suggestion!.basis).toBe("same-weekday");
+    });
+  });
+
+  describe("partitionHistoryByDate", () => {
+    it("should split entries on the target date regardless of format", () => {
+      const rows = [
+        entry("01/13/2025", "PTO/RTO", "Standup"),
+        entry("2025-01-13", "OSC-BBB", "Wafer review"),
+        entry("01/06/2025", "OSC-BBB", "Wafer review"),
+      ];
+
+      const { existingForDate, history } = partitionHistoryByDate(
+        "01/13/2025",
+        rows
+      );
+
+      expect(existingForDate).toHaveLength(2);
+      expect(history).toHaveLength(1);
+    });
+  });
+});
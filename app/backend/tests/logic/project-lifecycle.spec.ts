@@ -0,0 +1,65 @@
+/**
+ * @fileoverview Project Lifecycle Tests
+ *
+ * Tests that entries booked to a project on or after its retirement date are
+ * rejected with a pointer to the successor project.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  checkProjectActive,
+  getRetiredProjectIssues,
+  type ProjectRetirementRow
+} from '../../src/logic/project-lifecycle';
+
+const retirements: ProjectRetirementRow[] = [
+  { name: 'FL-Carver Techs', retired_on: '2025-03-01', successor_project: 'FL-Carver Tools' },
+  { name: 'OSC-BBB', retired_on: '2025-02-15', successor_project: null }
+];
+
+describe('Project Lifecycle', () => {
+  it('should accept entries dated before the retirement date', () => {
+    expect(checkProjectActive('FL-Carver Techs', '2025-02-28', retirements)).toBeNull();
+    expect(checkProjectActive('FL-Carver Techs', '02/28/2025', retirements)).toBeNull();
+  });
+
+  it('should reject entries on or after the retirement date and name the successor', () => {
+    expect(checkProjectActive('FL-Carver Techs', '03/01/2025', retirements)).toBe(
+      'FL-Carver Techs was retired on 2025-03-01; use FL-Carver Tools instead'
+    );
+    expect(checkProjectActive('OSC-BBB', '2025-04-01', retirements)).toBe(
+      'OSC-BBB was retired on 2025-02-15; pick another project'
+    );
+  });
+
+  it('should not flag entries without a date or with an active project', () => {
+    expect(checkProjectActive('FL-Carver Techs', undefined, retirements)).toBeNull();
+    expect(checkProjectActive('PTO/RTO', '2025-04-01', retirements)).toBeNull();
+  });
+
+  it('should list pending entries booked to retired projects', () => {
+    const issues = getRetiredProjectIssues(
+      [
+        { id: 1, date: '2025-02-20', project: 'FL-Carver Techs' },
+        { id: 2, date: '2025-03-03', project: 'FL-Carver Techs' },
+        { id: 3, date: null, project: 'OSC-BBB' }
+      ],
+      retirements
+    );
+
+    expect(issues).toEqual([
+      {
+        id: 2,
+        date: '2025-03-03',
+        project: 'FL-Carver Techs',
+        retiredOn: '2025-03-01',
+        successor: 'FL-Carver Tools',
+        message: 'FL-Carver Techs was retired on 2025-03-01; use FL-Carver Tools instead'
+      }
+    ]);
+  });
+});
@@ -0,0 +1,123 @@
+/**
+ * @fileoverview Submission Estimate Tests
+ *
+ * Tests deriving timings from recent form runs and estimating bot runs,
+ * form loads and duration for a submission plan.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import type { SubmissionFormRunReport, SubmissionRowReport } from '@sheetpilot/shared';
+import {
+  buildTimingProfile,
+  estimateSubmission,
+  DEFAULT_FORM_OVERHEAD_MS,
+  DEFAULT_ROW_MS
+} from '../../src/logic/submission-estimate';
+import type { SubmissionPlan } from '../../src/logic/submission-plan';
+
+const row = (
+  rowIndex: number,
+  outcome: SubmissionRowReport['outcome'],
+  fillMs: number,
+  submitMs: number
+): SubmissionRowReport => ({
+  rowIndex,
+  entryId: rowIndex + 1,
+  outcome,
+  error: null,
+  fillMs,
+  submitMs,
+  submitAttempts: 1,
+  throttleRetries: 0,
+  fields: {},
+  screenshot: null
+});
+
+const form = (rows: SubmissionRowReport[], durationMs: number): SubmissionFormRunReport => ({
+  formId: 'q4-2025',
+  formUrl: 'https://app.smartsheet.com/b/form/q4-2025',
+  quarterId: 'Q4-2025',
+  fillProfile: 'inject',
+  startedAt: '2025-10-20T00:00:00.000Z',
+  finishedAt: '2025-10-20T00:01:00.000Z',
+  durationMs,
+  loginMs: 4000,
+  titleCheck: 'passed',
+  rows,
+  error: null
+});
+
+const plan: SubmissionPlan = {
+  order: 'chronological',
+  groups: [
+    { quarterId: 'Q3-2025', quarterName: 'Q3 2025', formUrl: null, entryIds: [1, 2], hours: 4, firstDate: '2025-09-29', lastDate: '2025-09-30' },
+    { quarterId: 'Q4-2025', quarterName: 'Q4 2025', formUrl: null, entryIds: [3], hours: 2, firstDate: '2025-10-01', lastDate: '2025-10-01' },
+    { quarterId: null, quarterName: null, formUrl: null, entryIds: [4], hours: 1, firstDate: '2019-01-01', lastDate: '2019-01-01' }
+  ],
+  splitAcrossQuarters: true
+};
+
+const pacing = { minIntervalMs: 1000, jitterMs: 500 };
+
+describe('Submission Estimate', () => {
+  describe('buildTimingProfile', () => {
+    it('should fall back to defaults without enough history', () => {
+      const timing = buildTimingProfile([form([row(0, 'submitted', 1000, 2000), row(1, 'failed', 1000, 9000)], 20000)]);
+
+      expect(timing).toEqual({
+        basis: 'defaults',
+        sampleForms: 1,
+        sampleRows: 1,
+        rowMs: DEFAULT_ROW_MS,
+        formOverheadMs: DEFAULT_FORM_OVERHEAD_MS,
+        failureRate: 0.5
+      });
+    });
+
+    it('should use medians from recent submitted rows and ignore skipped rows', () => {
+      const rows = [0, 1, 2, 3, 4].map((i) => row(i, 'submitted', 2000, 3000));
+      const timing = buildTimingProfile([
+        form([...rows, row(5, 'failed', 1000, 9000), row(6, 'skipped', 0, 0)], 45000)
+      ]);
+
+      expect(timing).toEqual({
+        basis: 'history',
+        sampleForms: 1,
+        sampleRows: 5,
+        rowMs: 5000,
+        formOverheadMs: 10000,
+        failureRate: 1 / 6
+      });
+    });
+  });
+
+  describe('estimateSubmission', () => {
+    it('should compare one run per quarter form with one run per entry', () => {
+      const estimate = estimateSubmission(plan, buildTimingProfile([]), pacing);
+
+      expect(estimate.entryCount).toBe(4);
+      expect(estimate.unroutableEntries).toBe(1);
+      expect(estimate.groups).toEqual([
+        { quarterId: 'Q3-2025', entryCount: 2, estimatedMs: 60000 },
+        { quarterId: 'Q4-2025', entryCount: 1, estimatedMs: 45000 }
+      ]);
+      expect(estimate.batched).toEqual({ botRuns: 2, logins: 2, formLoads: 3, estimatedMs: 105000 });
+      expect(estimate.perEntry).toEqual({ botRuns: 3, logins: 3, formLoads: 3, estimatedMs: 135000 });
+    });
+
+    it('should not estimate entries faster than submission pacing allows', () => {
+      const rows = [0, 1, 2, 3, 4].map((i) => row(i, 'submitted', 2000, 3000));
+      const timing = buildTimingProfile([form(rows, 35000)]);
+      const single: SubmissionPlan = { order: 'chronological', groups: [plan.groups[1]!], splitAcrossQuarters: false };
+
+      const estimate = estimateSubmission(single, timing, { minIntervalMs: 8000, jitterMs: 2000 });
+
+      // 5s launch + 10s overhead + 1 entry at the 9s average paced gap
+      expect(estimate.batched.estimatedMs).toBe(24000);
+    });
+  });
+});
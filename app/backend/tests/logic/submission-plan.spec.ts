@@ -0,0 +1,117 @@
+/**
+ * @fileoverview Submission Plan Tests
+ *
+ * Tests splitting pending entries into per-quarter target forms, in each
+ * submission order.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import type { QuarterDefinition } from '@sheetpilot/bot';
+import { buildSubmissionPlan } from '../../src/logic/submission-plan';
+
+const quarter = (id: string, name: string, lockDate?: string): QuarterDefinition => ({
+  id,
+  name,
+  startDate: '',
+  endDate: '',
+  formUrl: `https://app.smartsheet.com/b/form/${id}`,
+  formId: id,
+  ...(lockDate ? { lockDate } : {}),
+});
+
+const quarterFor = (date: string): QuarterDefinition | null => {
+  if (date.startsWith('2025-09')) return quarter('Q3-2025', 'Q3 2025');
+  if (date.startsWith('2025-10')) return quarter('Q4-2025', 'Q4 2025');
+  return null;
+};
+
+const spanning = [
+  { id: 1, date: '2025-09-29', hours: 8 },
+  { id: 2, date: '2025-09-30', hours: 8 },
+  { id: 3, date: '2025-10-01', hours: 8 },
+  { id: 4, date: '2025-10-02', hours: 8 },
+  { id: 5, date: '2024-01-01', hours: 1 },
+];
+
+describe('Submission Plan', () => {
+  it('should keep a single-quarter batch as one group', () => {
+    const plan = buildSubmissionPlan(
+      [
+        { id: 1, date: '2025-10-01', hours: 8 },
+        { id: 2, date: '2025-10-02', hours: 4 },
+      ],
+      quarterFor,
+      'chronological'
+    );
+
+    expect(plan.order).toBe('chronological');
+    expect(plan.splitAcrossQuarters).toBe(false);
+    expect(plan.groups).toEqual([
+      {
+        quarterId: 'Q4-2025',
+        quarterName: 'Q4 2025',
+        formUrl: 'https://app.smartsheet.com/b/form/Q4-2025',
+        entryIds: [1, 2],
+        hours: 12,
+        firstDate: '2025-10-01',
+        lastDate: '2025-10-02',
+      },
+    ]);
+  });
+
+  it('should split a batch spanning a quarter boundary into forms in date order', () => {
+    const plan = buildSubmissionPlan(
+      [
+        { id: 3, date: '10/01/2025', hours: 8 },
+        { id: 1, date: '2025-09-30', hours: 7.5 },
+        { id: 2, date: '2025-09-29', hours: 8 },
+      ],
+      quarterFor,
+      'chronological'
+    );
+
+    expect(plan.splitAcrossQuarters).toBe(true);
+    expect(plan.groups.map((g) => g.quarterId)).toEqual(['Q3-2025', 'Q4-2025']);
+    expect(plan.groups[0]).toMatchObject({ entryIds: [2, 1], hours: 15.5, firstDate: '2025-09-29', lastDate: '2025-09-30' });
+    expect(plan.groups[1]).toMatchObject({ entryIds: [3], firstDate: '2025-10-01' });
+  });
+
+  it('should list entries outside every quarter last without counting them as a split', () => {
+    const plan = buildSubmissionPlan(
+      [
+        { id: 1, date: '2024-01-01', hours: 1 },
+        { id: 2, date: '2025-10-01', hours: 2 },
+      ],
+      quarterFor,
+      'chronological'
+    );
+
+    expect(plan.splitAcrossQuarters).toBe(false);
+    expect(plan.groups.map((g) => g.quarterId)).toEqual(['Q4-2025', null]);
+    expect(plan.groups[1]).toMatchObject({ quarterName: null, formUrl: null, entryIds: [1] });
+  });
+
+  it('should put the latest entries first when ordered newest first', () => {
+    const plan = buildSubmissionPlan(spanning, quarterFor, 'newest-first');
+
+    expect(plan.groups.map((g) => g.quarterId)).toEqual(['Q4-2025', 'Q3-2025', null]);
+    expect(plan.groups.map((g) => g.entryIds)).toEqual([[4, 3], [2, 1], [5]]);
+  });
+
+  it('should put the form that locks soonest first when ordered by deadline', () => {
+    const lockingFor = (date: string): QuarterDefinition | null => {
+      if (date.startsWith('2025-09')) return quarter('Q3-2025', 'Q3 2025');
+      if (date.startsWith('2025-10')) return quarter('Q4-2025', 'Q4 2025', '2025-10-15');
+      return null;
+    };
+    const plan = buildSubmissionPlan(spanning, lockingFor, 'deadline');
+
+    expect(plan.order).toBe('deadline');
+    expect(plan.groups.map((g) => g.quarterId)).toEqual(['Q4-2025', 'Q3-2025', null]);
+    expect(plan.groups.map((g) => g.entryIds)).toEqual([[3, 4], [1, 2], [5]]);
+  });
+});
@@ -0,0 +1,57 @@
+/**
+ * @fileoverview Time Zone Logic Tests
+ *
+ * Tests dating instants and finding day boundaries in an explicit IANA zone.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  getSystemTimeZone,
+  isValidTimeZone,
+  resolveHomeTimeZone,
+  startOfDayInZone,
+  toSlashDateInZone
+} from '../../src/logic/time-zones';
+
+describe('Time Zone Logic', () => {
+  describe('isValidTimeZone', () => {
+    it('should accept IANA zones and reject unknown names', () => {
+      expect(isValidTimeZone('America/Chicago')).toBe(true);
+      expect(isValidTimeZone('UTC')).toBe(true);
+      expect(isValidTimeZone('Texas/Fab')).toBe(false);
+    });
+  });
+
+  describe('resolveHomeTimeZone', () => {
+    it('should fall back to the system zone for missing or unknown settings', () => {
+      expect(resolveHomeTimeZone('America/Phoenix')).toBe('America/Phoenix');
+      expect(resolveHomeTimeZone(undefined)).toBe(getSystemTimeZone());
+      expect(resolveHomeTimeZone('Mars/Olympus')).toBe(getSystemTimeZone());
+    });
+  });
+
+  describe('toSlashDateInZone', () => {
+    it('should date an instant in the given zone, not the machine zone', () => {
+      const lateEvening = new Date('2025-03-05T04:30:00Z');
+
+      expect(toSlashDateInZone(lateEvening, 'America/Chicago')).toBe('03/04/2025');
+      expect(toSlashDateInZone(lateEvening, 'UTC')).toBe('03/05/2025');
+    });
+  });
+
+  describe('startOfDayInZone', () => {
+    it('should return local midnight as an instant', () => {
+      expect(startOfDayInZone('2025-01-15', 'America/Chicago').toISOString()).toBe('2025-01-15T06:00:00.000Z');
+      expect(startOfDayInZone('2025-07-15', 'America/Phoenix').toISOString()).toBe('2025-07-15T07:00:00.000Z');
+    });
+
+    it('should use the offset in effect on daylight saving change days', () => {
+      expect(startOfDayInZone('2025-03-09', 'America/Chicago').toISOString()).toBe('2025-03-09T06:00:00.000Z');
+      expect(startOfDayInZone('2025-03-10', 'America/Chicago').toISOString()).toBe('2025-03-10T05:00:00.000Z');
+    });
+  });
+});
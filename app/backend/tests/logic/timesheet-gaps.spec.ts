@@ -0,0 +1,95 @@
+/**
+ * @fileoverview Timesheet Gaps Tests
+ *
+ * Tests resolving the workweek from settings and finding working days with
+ * no hours or fewer than the daily target.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  DEFAULT_DAILY_TARGET_HOURS,
+  DEFAULT_WORKING_DAYS,
+  describeMissingDays,
+  findMissingDays,
+  isWorkingDay,
+  resolveWorkweek
+} from '../../src/logic/timesheet-gaps';
+
+// 2025-03-03 is a Monday
+const week = { start: '2025-03-03', end: '2025-03-09' };
+
+describe('Timesheet Gaps', () => {
+  it('should default to an 8 hour Monday to Friday week', () => {
+    const workweek = resolveWorkweek(undefined);
+    expect(workweek.workingDays).toEqual(DEFAULT_WORKING_DAYS);
+    expect(workweek.dailyTargetHours).toBe(DEFAULT_DAILY_TARGET_HOURS);
+    expect(workweek.holidays.size).toBe(0);
+  });
+
+  it('should ignore invalid workweek settings', () => {
+    const workweek = resolveWorkweek({
+      working_days: [7, -1, 1.5],
+      daily_target_hours: 30,
+      holidays: ['07/04/2025', '2025-07-04', 42 as unknown as string]
+    });
+    expect(workweek.workingDays).toEqual(DEFAULT_WORKING_DAYS);
+    expect(workweek.dailyTargetHours).toBe(DEFAULT_DAILY_TARGET_HOURS);
+    expect([...workweek.holidays]).toEqual(['2025-07-04']);
+  });
+
+  it('should skip weekends and holidays', () => {
+    const workweek = resolveWorkweek({ holidays: ['2025-03-05'] });
+    expect(isWorkingDay('2025-03-04', workweek)).toBe(true);
+    expect(isWorkingDay('2025-03-05', workweek)).toBe(false);
+    expect(isWorkingDay('2025-03-08', workweek)).toBe(false);
+  });
+
+  it('should report empty days and days under the target', () => {
+    const missing = findMissingDays(
+      week.start,
+      week.end,
+      [
+        { date: '2025-03-03', submittedHours: 8, draftHours: 0 },
+        { date: '2025-03-04', submittedHours: 4, draftHours: 2 },
+        { date: '2025-03-05', submittedHours: 0, draftHours: 8 },
+        { date: '2025-03-08', submittedHours: 0, draftHours: 0 }
+      ],
+      resolveWorkweek(undefined)
+    );
+    expect(missing.map((day) => [day.date, day.reason, day.shortfallHours])).toEqual([
+      ['2025-03-04', 'under-target', 2],
+      ['2025-03-06', 'no-hours', 8],
+      ['2025-03-07', 'no-hours', 8]
+    ]);
+    expect(missing[0]).toMatchObject({ weekday: 2, loggedHours: 6, submittedHours: 4, draftHours: 2 });
+  });
+
+  it('should follow a custom workweek and target', () => {
+    const missing = findMissingDays(
+      week.start,
+      week.end,
+      [
+        { date: '2025-03-03', submittedHours: 10, draftHours: 0 },
+        { date: '2025-03-04', submittedHours: 9, draftHours: 0 }
+      ],
+      resolveWorkweek({ working_days: [1, 2, 3, 4], daily_target_hours: 10 })
+    );
+    expect(missing.map((day) => day.date)).toEqual(['2025-03-04', '2025-03-05', '2025-03-06']);
+  });
+
+  it('should describe gaps for a reminder', () => {
+    const missing = findMissingDays(
+      week.start,
+      '2025-03-05',
+      [{ date: '2025-03-05', submittedHours: 6, draftHours: 0 }],
+      resolveWorkweek(undefined)
+    );
+    expect(describeMissingDays(missing, 8)).toBe(
+      '2 days have no hours (Mon 03/03, Tue 03/04); 1 day is under 8h (Wed 03/05)'
+    );
+  });
+});
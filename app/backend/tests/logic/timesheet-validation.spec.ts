@@ -312,6 +312,17 @@ describe("Backend Timesheet Validation Logic", () => {
         );
         expect(result).toContain("from the list");
       });
+
+      it("should allow PTO and holidays without a project", () => {
+        for (const kind of ["pto", "holiday"] as const) {
+          const rows = [{ ...mockRows[0], project: "", kind }];
+          expect(validateField("", 0, "project", rows, mockProjects, mockChargeCodes)).toBeNull();
+        }
+        const trainingRows = [{ ...mockRows[0], project: "", kind: "training" as const }];
+        expect(
+          validateField("", 0, "project", trainingRows, mockProjects, mockChargeCodes)
+        ).toContain("required");
+      });
     });
 
     describe("Tool Field Validation", () => {
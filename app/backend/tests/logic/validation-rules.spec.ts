@@ -0,0 +1,94 @@
+/**
+ * @fileoverview Validation Rules Engine Tests
+ *
+ * Tests evaluation of user-defined rules against timesheet entries.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  evaluateValidationRules,
+  evaluateValidationRulesForEntries,
+  matchesRuleCondition,
+  toRuleEntry,
+  type ValidationRule
+} from '../../src/logic/validation-rules';
+
+const toolForFab: ValidationRule = {
+  id: 1,
+  name: 'FAB projects need a tool',
+  field: 'tool',
+  operator: 'required',
+  when: { field: 'project', operator: 'startsWith', value: 'FAB-' },
+  action: 'block',
+  message: 'Tool is required for FAB- projects',
+  isActive: true
+};
+
+const maxHours: ValidationRule = {
+  id: 2,
+  name: 'Long days',
+  field: 'hours',
+  operator: 'atMost',
+  value: '10',
+  when: null,
+  action: 'warn',
+  message: 'More than 10 hours in one entry',
+  isActive: true
+};
+
+describe('Validation Rules Engine', () => {
+  it('should flag entries that match the precondition but fail the check', () => {
+    const violations = evaluateValidationRules({ id: 5, project: 'FAB-12', tool: '' }, [toolForFab]);
+    expect(violations).toEqual([
+      {
+        ruleId: 1,
+        ruleName: 'FAB projects need a tool',
+        entryId: 5,
+        field: 'tool',
+        action: 'block',
+        message: 'Tool is required for FAB- projects'
+      }
+    ]);
+  });
+
+  it('should skip rules whose precondition does not hold', () => {
+    expect(evaluateValidationRules({ project: 'OSC-BBB', tool: null }, [toolForFab])).toEqual([]);
+  });
+
+  it('should pass entries that satisfy the check', () => {
+    expect(evaluateValidationRules({ project: 'FAB-12', tool: 'COL 2' }, [toolForFab])).toEqual([]);
+  });
+
+  it('should ignore inactive rules', () => {
+    expect(
+      evaluateValidationRules({ project: 'FAB-12' }, [{ ...toolForFab, isActive: false }])
+    ).toEqual([]);
+  });
+
+  it('should compare numbers for atMost and atLeast', () => {
+    expect(evaluateValidationRules({ hours: 12 }, [maxHours])).toHaveLength(1);
+    expect(evaluateValidationRules({ hours: 8 }, [maxHours])).toHaveLength(0);
+    expect(matchesRuleCondition({ hours: 2 }, { field: 'hours', operator: 'atLeast', value: '0.5' })).toBe(true);
+  });
+
+  it('should treat an invalid pattern as not matching', () => {
+    expect(matchesRuleCondition({ chargeCode: 'EPR1' }, { field: 'chargeCode', operator: 'matches', value: '[' })).toBe(false);
+    expect(matchesRuleCondition({ chargeCode: 'EPR1' }, { field: 'chargeCode', operator: 'matches', value: '^EPR\\d$' })).toBe(true);
+  });
+
+  it('should evaluate stored rows across many entries', () => {
+    const rows = [
+      { id: 1, date: '01/02/2025', hours: 12, project: 'FAB-1', tool: null, detail_charge_code: null, task_description: 'a' },
+      { id: 2, date: '01/02/2025', hours: 4, project: 'OSC-BBB', tool: null, detail_charge_code: null, task_description: 'b' }
+    ];
+    const violations = evaluateValidationRulesForEntries(rows.map(toRuleEntry), [toolForFab, maxHours]);
+    expect(violations.map((v) => [v.entryId, v.ruleId])).toEqual([
+      [1, 1],
+      [1, 2]
+    ]);
+  });
+});
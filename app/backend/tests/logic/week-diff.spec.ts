@@ -0,0 +1,97 @@
+/**
+ * @fileoverview Week Diff Tests
+ *
+ * Tests comparing a week's drafts against its last submitted version.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { diffWeek, getWeekEnd, type WeekDiffSourceRow } from '../../src/logic/week-diff';
+
+const row = (overrides: Partial<WeekDiffSourceRow> & { id: number }): WeekDiffSourceRow => ({
+  date: '2025-03-03',
+  hours: 2,
+  project: 'FL-Carver Techs',
+  tool: 'DECA Meeting',
+  detail_charge_code: 'EPR1',
+  task_description: 'Review',
+  status: 'Complete',
+  amends_id: null,
+  ...overrides
+});
+
+describe('Week Diff', () => {
+  it('should compute the last day of the week', () => {
+    expect(getWeekEnd('2025-03-03')).toBe('2025-03-09');
+    expect(getWeekEnd('2025-12-29')).toBe('2026-01-04');
+  });
+
+  it('should report field-level changes for pending corrections', () => {
+    const diff = diffWeek('2025-03-03', [
+      row({ id: 1 }),
+      row({ id: 2, status: null, amends_id: 1, hours: 3, task_description: 'Review and fixes' })
+    ]);
+
+    expect(diff.modified).toHaveLength(1);
+    expect(diff.modified[0]).toMatchObject({ originalId: 1, draftId: 2 });
+    expect(diff.modified[0]!.changes).toEqual([
+      { field: 'hours', before: 2, after: 3 },
+      { field: 'taskDescription', before: 'Review', after: 'Review and fixes' }
+    ]);
+    expect(diff.added).toEqual([]);
+    expect(diff.removed).toEqual([]);
+  });
+
+  it('should report new drafts as added and untouched submissions as unchanged', () => {
+    const diff = diffWeek('2025-03-03', [
+      row({ id: 1 }),
+      row({ id: 2, date: '03/05/2025', status: null, task_description: 'Planning' })
+    ]);
+
+    expect(diff.added.map((entry) => entry.id)).toEqual([2]);
+    expect(diff.added[0]!.date).toBe('2025-03-05');
+    expect(diff.unchangedCount).toBe(1);
+  });
+
+  it('should treat corrections that move an entry across the week boundary as removed and added', () => {
+    const thisWeek = diffWeek('2025-03-03', [
+      row({ id: 1 }),
+      row({ id: 2, status: null, amends_id: 1, date: '2025-03-10' })
+    ]);
+    expect(thisWeek.removed).toEqual([
+      expect.objectContaining({ draftId: 2, movedToDate: '2025-03-10', entry: expect.objectContaining({ id: 1 }) })
+    ]);
+
+    const nextWeek = diffWeek('2025-03-10', [
+      row({ id: 1 }),
+      row({ id: 2, status: null, amends_id: 1, date: '2025-03-10' })
+    ]);
+    expect(nextWeek.added.map((entry) => entry.id)).toEqual([2]);
+    expect(nextWeek.unchangedCount).toBe(0);
+  });
+
+  it('should compare against the latest submitted correction', () => {
+    const diff = diffWeek('2025-03-03', [
+      row({ id: 1, hours: 1 }),
+      row({ id: 2, amends_id: 1, hours: 2 }),
+      row({ id: 3, status: null, amends_id: 2, hours: 4 })
+    ]);
+
+    expect(diff.modified).toHaveLength(1);
+    expect(diff.modified[0]).toMatchObject({ originalId: 2, draftId: 3 });
+    expect(diff.modified[0]!.changes).toEqual([{ field: 'hours', before: 2, after: 4 }]);
+  });
+
+  it('should ignore corrections that change nothing and blank optional fields', () => {
+    const diff = diffWeek('2025-03-03', [
+      row({ id: 1, tool: null }),
+      row({ id: 2, status: null, amends_id: 1, tool: '' })
+    ]);
+
+    expect(diff.modified).toEqual([]);
+    expect(diff.unchangedCount).toBe(1);
+  });
+});
@@ -0,0 +1,113 @@
+/**
+ * @fileoverview Activity Heatmap Tests
+ *
+ * Tests the per-day submitted and draft hours behind the calendar heatmap.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  amendTimesheetEntry,
+  getActivityHeatmap,
+  markTimesheetEntriesAsSubmitted,
+  saveDraftTimesheetEntry,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Activity Heatmap", () => {
+  let testDbPath: string;
+
+  const saveDraft = (date: string, hours: number, taskDescription: string): number =>
+    saveDraftTimesheetEntry(undefined, { date, hours, project: "OSC-BBB", taskDescription }).id;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-heatmap-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should return every day of the year, including leap days", () => {
+    const days = getActivityHeatmap(2024);
+
+    expect(days).toHaveLength(366);
+    expect(days[0]).toEqual({ date: "2024-01-01", submittedHours: 0, draftHours: 0 });
+    expect(days[59]?.date).toBe("2024-02-29");
+    expect(days[365]?.date).toBe("2024-12-31");
+    expect(getActivityHeatmap(2025)).toHaveLength(365);
+  });
+
+  it("should total submitted and draft hours as separate series", () => {
+    const submitted = [
+      saveDraft("2025-01-15", 2, "Submitted A"),
+      saveDraft("2025-01-15", 1.5, "Submitted B"),
+    ];
+    markTimesheetEntriesAsSubmitted(submitted);
+    saveDraft("2025-01-15", 0.5, "Draft A");
+    saveDraft("2025-01-16", 8, "Forgot to submit");
+    saveDraft("2024-12-31", 4, "Last year");
+
+    const byDate = new Map(getActivityHeatmap(2025).map((day) => [day.date, day]));
+
+    expect(byDate.get("2025-01-15")).toEqual({ date: "2025-01-15", submittedHours: 3.5, draftHours: 0.5 });
+    expect(byDate.get("2025-01-16")).toEqual({ date: "2025-01-16", submittedHours: 0, draftHours: 8 });
+    expect(byDate.has("2024-12-31")).toBe(false);
+  });
+
+  it("should not count an original superseded by a submitted correction", () => {
+    const originalId = saveDraft("2025-03-03", 2, "Corrected work");
+    markTimesheetEntriesAsSubmitted([originalId]);
+    const amendmentId = amendTimesheetEntry(originalId).id!;
+
+    const pending = getActivityHeatmap(2025).find((day) => day.date === "2025-03-03");
+    expect(pending).toEqual({ date: "2025-03-03", submittedHours: 2, draftHours: 2 });
+
+    markTimesheetEntriesAsSubmitted([amendmentId]);
+    const corrected = getActivityHeatmap(2025).find((day) => day.date === "2025-03-03");
+    expect(corrected).toEqual({ date: "2025-03-03", submittedHours: 2, draftHours: 0 });
+  });
+});
@@ -0,0 +1,142 @@
+/**
+ * @fileoverview Archive Pages Tests
+ *
+ * Tests cursor paging through submitted entries, column selection, gzipping
+ * large pages and rejecting cursors the app did not issue.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+import { gunzipSync } from "zlib";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+import {
+  ARCHIVE_GZIP_MIN_BYTES,
+  decodeArchiveCursor,
+  encodeArchiveCursor,
+  getArchivePage,
+} from "../../src/services/archive/archive-pages";
+
+describe("Archive Pages", () => {
+  let testDbPath: string;
+
+  const insertEntries = (count: number, status: string | null = "Complete") => {
+    const insert = getDb().prepare(
+      `INSERT INTO timesheet (date, hours, project, task_description, status)
+       VALUES (?, ?, ?, ?, ?)`
+    );
+    for (let i = 0; i < count; i++) {
+      const day = String((i % 28) + 1).padStart(2, "0");
+      insert.run(`2025-01-${day}`, 1, "FAB", `Task ${status ?? "draft"} ${i}`, status);
+    }
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-archive-pages-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should page through every submitted entry exactly once", () => {
+    insertEntries(60);
+    insertEntries(5, null);
+
+    const first = getArchivePage({ limit: 25 });
+    expect(first.count).toBe(25);
+    expect(first.totalCount).toBe(60);
+    expect(first.nextCursor).not.toBeNull();
+
+    const seen = new Set<number>(first.entries!.map((e) => e["id"] as number));
+    let cursor = first.nextCursor;
+    while (cursor) {
+      const page = getArchivePage({ limit: 25, cursor });
+      expect(page.totalCount).toBeUndefined();
+      page.entries!.forEach((e) => seen.add(e["id"] as number));
+      cursor = page.nextCursor;
+    }
+    expect(seen.size).toBe(60);
+  });
+
+  it("should return entries in date order across pages", () => {
+    insertEntries(40);
+    const first = getArchivePage({ limit: 20 });
+    const second = getArchivePage({ limit: 20, cursor: first.nextCursor! });
+    const dates = [...first.entries!, ...second.entries!].map((e) => e["date"] as string);
+    expect(dates).toEqual([...dates].sort());
+    expect(second.nextCursor).toBeNull();
+  });
+
+  it("should only return the requested columns plus id and date", () => {
+    insertEntries(3);
+    const page = getArchivePage({ columns: ["hours"] });
+    expect(Object.keys(page.entries![0]!).sort()).toEqual(["date", "hours", "id"]);
+  });
+
+  it("should gzip large pages when asked", () => {
+    insertEntries(400);
+    const page = getArchivePage({ limit: 400, gzip: true });
+    expect(page.entries).toBeUndefined();
+    expect(page.gzipped).toBeDefined();
+    const entries = JSON.parse(gunzipSync(page.gzipped!).toString("utf8")) as unknown[];
+    expect(entries).toHaveLength(400);
+    expect(page.count).toBe(400);
+  });
+
+  it("should send small pages uncompressed even when gzip is asked", () => {
+    insertEntries(2);
+    const page = getArchivePage({ gzip: true });
+    expect(JSON.stringify(page.entries).length).toBeLessThan(ARCHIVE_GZIP_MIN_BYTES);
+    expect(page.gzipped).toBeUndefined();
+    expect(page.entries).toHaveLength(2);
+  });
+
+  it("should round-trip cursors and reject foreign ones", () => {
+    const cursor = { date: "2025-01-05", id: 42 };
+    expect(decodeArchiveCursor(encodeArchiveCursor(cursor))).toEqual(cursor);
+    expect(() => getArchivePage({ cursor: "not-a-cursor" })).toThrow(/cursor is not valid/);
+  });
+});
@@ -0,0 +1,137 @@
+/**
+ * @fileoverview Bulk Recode Repository Tests
+ *
+ * Tests renaming a project or charge code on submitted entries and the
+ * recode history audit trail.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  bulkRecodeSubmittedEntries,
+  getTimesheetEntryById,
+  insertDraftTimesheetEntry,
+  listRecodeHistory,
+  markTimesheetEntriesAsInProgress,
+  markTimesheetEntriesAsSubmitted,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Bulk Recode", () => {
+  let testDbPath: string;
+
+  const insertSubmitted = (date: string, project: string, chargeCode: string, taskDescription: string): number => {
+    const id = insertDraftTimesheetEntry({ date, hours: 1, project, tool: "Tool", chargeCode, taskDescription });
+    markTimesheetEntriesAsInProgress([id]);
+    markTimesheetEntriesAsSubmitted([id]);
+    return id;
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-bulk-recode-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should rename a charge code on submitted entries in the date range only", () => {
+    const inRange = insertSubmitted("2025-01-10", "FL-Carver Techs", "EPR1", "A");
+    const outOfRange = insertSubmitted("2025-03-10", "FL-Carver Techs", "EPR1", "B");
+    const draft = insertDraftTimesheetEntry({
+      date: "2025-01-11", hours: 1, project: "FL-Carver Techs", chargeCode: "EPR1", taskDescription: "C",
+    });
+
+    const result = bulkRecodeSubmittedEntries({
+      field: "chargeCode",
+      fromValue: "EPR1",
+      toValue: "EPR1-NEW",
+      filter: { startDate: "2025-01-01", endDate: "2025-01-31" },
+      performedBy: "admin",
+    });
+
+    expect(result.entryIds).toEqual([inRange]);
+    expect(getTimesheetEntryById(inRange)?.detail_charge_code).toBe("EPR1-NEW");
+    expect(getTimesheetEntryById(outOfRange)?.detail_charge_code).toBe("EPR1");
+    expect(getTimesheetEntryById(draft)?.detail_charge_code).toBe("EPR1");
+
+    const [history] = listRecodeHistory();
+    expect(history).toMatchObject({
+      id: result.historyId,
+      field: "chargeCode",
+      from_value: "EPR1",
+      to_value: "EPR1-NEW",
+      performed_by: "admin",
+    });
+    expect(JSON.parse(history!.entry_ids)).toEqual([inRange]);
+  });
+
+  it("should leave entries untouched on a dry run", () => {
+    const id = insertSubmitted("2025-01-10", "Old Name", "EPR1", "A");
+
+    const result = bulkRecodeSubmittedEntries({
+      field: "project",
+      fromValue: "Old Name",
+      toValue: "New Name",
+      dryRun: true,
+    });
+
+    expect(result).toEqual({ entryIds: [id], historyId: null });
+    expect(getTimesheetEntryById(id)?.project).toBe("Old Name");
+    expect(listRecodeHistory()).toEqual([]);
+  });
+
+  it("should refuse renames that would duplicate existing entries", () => {
+    const renamed = insertSubmitted("2025-01-10", "Old Name", "EPR1", "Same task");
+    insertSubmitted("2025-01-10", "New Name", "EPR1", "Same task");
+
+    expect(() =>
+      bulkRecodeSubmittedEntries({ field: "project", fromValue: "Old Name", toValue: "New Name" })
+    ).toThrow(/would duplicate existing submitted entries/);
+    expect(getTimesheetEntryById(renamed)?.project).toBe("Old Name");
+    expect(listRecodeHistory()).toEqual([]);
+  });
+});
@@ -0,0 +1,119 @@
+/**
+ * @fileoverview Database Timeouts and Connection Statistics Tests
+ *
+ * Tests that configured timeouts reach the open connection and that cached
+ * statements report their timings and slow executions.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    debug: vi.fn(),
+    verbose: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  shutdownDatabase,
+  prepareCached,
+  configureDatabaseTimeouts,
+  getDatabaseConnectionStats,
+  resetDatabaseConnectionStats,
+  sanitizeDatabaseTimeoutSettings,
+  DEFAULT_DATABASE_TIMEOUTS,
+  MAX_BUSY_TIMEOUT_MS,
+} from "../../src/models";
+
+describe("Database Timeouts and Connection Statistics", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-connection-stats-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    resetDatabaseConnectionStats();
+  });
+
+  afterEach(() => {
+    configureDatabaseTimeouts({});
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should keep only known, non-negative numbers and cap the busy timeout", () => {
+    expect(
+      sanitizeDatabaseTimeoutSettings({
+        busyTimeoutMs: 10 * 60 * 1000,
+        slowStatementMs: -1,
+        poolSize: 10,
+      })
+    ).toEqual({ busyTimeoutMs: MAX_BUSY_TIMEOUT_MS });
+    expect(sanitizeDatabaseTimeoutSettings("fast")).toEqual({});
+  });
+
+  it("should apply the busy timeout to the open connection", () => {
+    const db = getDb();
+    configureDatabaseTimeouts({ busyTimeoutMs: 1234 });
+    expect(db.pragma("busy_timeout", { simple: true })).toBe(1234);
+
+    configureDatabaseTimeouts({});
+    expect(db.pragma("busy_timeout", { simple: true })).toBe(
+      DEFAULT_DATABASE_TIMEOUTS.busyTimeoutMs
+    );
+  });
+
+  it("should time cached statements and count slow ones", () => {
+    const db = getDb();
+    configureDatabaseTimeouts({ slowStatementMs: 0 });
+
+    prepareCached(db, "SELECT 1 AS one").get();
+    prepareCached(db, "SELECT 1 AS one").all();
+
+    const stats = getDatabaseConnectionStats();
+    expect(stats.executions).toBe(2);
+    expect(stats.slowStatements).toBe(2);
+    expect(stats.recentSlow[0]!.sql).toBe("SELECT 1 AS one");
+    expect(stats.busyTimeouts).toBe(0);
+  });
+
+  it("should not count statements under the slow threshold", () => {
+    const db = getDb();
+    configureDatabaseTimeouts({ slowStatementMs: 60_000 });
+
+    prepareCached(db, "SELECT 2 AS two").get();
+
+    const stats = getDatabaseConnectionStats();
+    expect(stats.executions).toBe(1);
+    expect(stats.slowStatements).toBe(0);
+    expect(stats.recentSlow).toEqual([]);
+  });
+});
@@ -30,7 +30,10 @@ import {
   storeCredentials,
   getCredentials,
   listCredentials,
-  deleteCredentials
+  deleteCredentials,
+  setCredentialKeychain,
+  auditCredentialMigration,
+  type CredentialKeychain
 } from '../../src/models/credentials-repository';
 import { setDbPath, openDb, ensureSchema, shutdownDatabase } from '../../src/models';
 
@@ -512,5 +515,115 @@ describe('Credentials Repository', () => {
       expect(duration).toBeLessThan(1000);
     });
   });
-});
 
+  describe('Keychain Migration', () => {
+    const memoryKeychain = () => {
+      const entries = new Map<string, { email: string; password: string }>();
+      const keychain: CredentialKeychain = {
+        read: (service) => entries.get(service) ?? null,
+        write: (service, email, password) => {
+          entries.set(service, { email, password });
+        },
+        remove: (service) => {
+          entries.delete(service);
+        },
+        services: () => [...entries.keys()]
+      };
+      return { entries, keychain };
+    };
+
+    afterEach(() => {
+      setCredentialKeychain(null);
+    });
+
+    it('writes new credentials to both stores', () => {
+      const { entries, keychain } = memoryKeychain();
+      setCredentialKeychain(keychain);
+
+      storeCredentials('smartsheet', 'user@test.com', 'password123');
+
+      expect(entries.get('smartsheet')).toEqual({ email: 'user@test.com', password: 'password123' });
+      expect(listCredentials()).toHaveLength(1);
+    });
+
+    it('reads the keychain first and falls back to SQLite', () => {
+      storeCredentials('smartsheet', 'user@test.com', 'from-sqlite');
+      const { entries, keychain } = memoryKeychain();
+      setCredentialKeychain(keychain);
+      entries.set('smartsheet', { email: 'user@test.com', password: 'from-keychain' });
+
+      expect(getCredentials('smartsheet')?.password).toBe('from-keychain');
+
+      keychain.read = () => {
+        throw new Error('keychain unavailable');
+      };
+      expect(getCredentials('smartsheet')?.password).toBe('from-sqlite');
+    });
+
+    it('copies SQLite-only credentials to the keychain when read', () => {
+      storeCredentials('smartsheet', 'user@test.com', 'password123');
+      const { entries, keychain } = memoryKeychain();
+      setCredentialKeychain(keychain);
+
+      expect(getCredentials('smartsheet')?.password).toBe('password123');
+      expect(entries.get('smartsheet')).toEqual({ email: 'user@test.com', password: 'password123' });
+    });
+
+    it('drops a keychain copy that does not read back and keeps SQLite', () => {
+      const { entries, keychain } = memoryKeychain();
+      keychain.write = (service, email) => {
+        entries.set(service, { email, password: 'corrupted' });
+      };
+      setCredentialKeychain(keychain);
+
+      const result = storeCredentials('smartsheet', 'user@test.com', 'password123');
+
+      expect(result.success).toBe(true);
+      expect(entries.has('smartsheet')).toBe(false);
+      expect(getCredentials('smartsheet')?.password).toBe('password123');
+    });
+
+    it('deletes from both stores', () => {
+      const { entries, keychain } = memoryKeychain();
+      setCredentialKeychain(keychain);
+      storeCredentials('smartsheet', 'user@test.com', 'password123');
+
+      deleteCredentials('smartsheet');
+
+      expect(entries.has('smartsheet')).toBe(false);
+      expect(getCredentials('smartsheet')).toBeNull();
+    });
+
+    it('audits which credentials still live only in SQLite', () => {
+      storeCredentials('legacy', 'old@test.com', 'password1');
+      const { entries, keychain } = memoryKeychain();
+      setCredentialKeychain(keychain);
+      storeCredentials('smartsheet', 'user@test.com', 'password2');
+      storeCredentials('drifted', 'drift@test.com', 'password3');
+      entries.set('drifted', { email: 'drift@test.com', password: 'stale' });
+      entries.set('orphan', { email: 'orphan@test.com', password: 'password4' });
+
+      const audit = auditCredentialMigration();
+
+      expect(audit.keychainAvailable).toBe(true);
+      expect(audit.entries).toEqual([
+        { service: 'drifted', email: 'drift@test.com', status: 'mismatch' },
+        { service: 'legacy', email: 'old@test.com', status: 'sqlite-only' },
+        { service: 'orphan', email: 'orphan@test.com', status: 'keychain-only' },
+        { service: 'smartsheet', email: 'user@test.com', status: 'migrated' }
+      ]);
+      expect(audit.sqliteOnly).toEqual(['legacy']);
+      expect(JSON.stringify(audit)).not.toContain('password');
+    });
+
+    it('reports every credential as SQLite-only without a keychain', () => {
+      storeCredentials('smartsheet', 'user@test.com', 'password123');
+
+      expect(auditCredentialMigration()).toEqual({
+        keychainAvailable: false,
+        entries: [{ service: 'smartsheet', email: 'user@test.com', status: 'sqlite-only' }],
+        sqliteOnly: ['smartsheet']
+      });
+    });
+  });
+});
@@ -0,0 +1,112 @@
+/**
+ * @fileoverview Deleted Entries Repository Tests
+ *
+ * Tests admin removal of submitted entries and the copy kept in
+ * deleted_entries with the reason.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  deleteCompletedEntry,
+  getTimesheetEntryById,
+  insertDraftTimesheetEntry,
+  listDeletedEntries,
+  markTimesheetEntriesAsInProgress,
+  markTimesheetEntriesAsSubmitted,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Deleted Entries", () => {
+  let testDbPath: string;
+
+  const insertSubmitted = (date: string, project: string, chargeCode: string, taskDescription: string): number => {
+    const id = insertDraftTimesheetEntry({ date, hours: 1, project, tool: "Tool", chargeCode, taskDescription });
+    markTimesheetEntriesAsInProgress([id]);
+    markTimesheetEntriesAsSubmitted([id]);
+    return id;
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-deleted-entries-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should move a submitted entry to deleted entries with the reason", () => {
+    const id = insertSubmitted("2025-01-10", "FL-Carver Techs", "EPR1", "Duplicate of payroll fix");
+
+    const result = deleteCompletedEntry({ id, reason: "  Payroll reversed it  ", performedBy: "admin" });
+
+    expect(result.entry).toMatchObject({ id, project: "FL-Carver Techs", status: "Complete" });
+    expect(getTimesheetEntryById(id)).toBeUndefined();
+
+    const [deleted] = listDeletedEntries();
+    expect(deleted).toMatchObject({
+      id: result.deletionId,
+      entry_id: id,
+      reason: "Payroll reversed it",
+      deleted_by: "admin",
+    });
+    expect(JSON.parse(deleted!.entry)).toMatchObject({ id, detail_charge_code: "EPR1" });
+  });
+
+  it("should refuse drafts, unknown entries and blank reasons", () => {
+    const draft = insertDraftTimesheetEntry({
+      date: "2025-01-11", hours: 1, project: "FL-Carver Techs", taskDescription: "Draft",
+    });
+    const submitted = insertSubmitted("2025-01-10", "FL-Carver Techs", "EPR1", "A");
+
+    expect(() => deleteCompletedEntry({ id: draft, reason: "Wrong" })).toThrow(/not submitted/);
+    expect(() => deleteCompletedEntry({ id: 9999, reason: "Wrong" })).toThrow(/not found/);
+    expect(() => deleteCompletedEntry({ id: submitted, reason: "   " })).toThrow(/reason is required/);
+    expect(getTimesheetEntryById(draft)).toBeDefined();
+    expect(getTimesheetEntryById(submitted)).toBeDefined();
+    expect(listDeletedEntries()).toEqual([]);
+  });
+});
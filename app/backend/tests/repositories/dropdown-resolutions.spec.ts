@@ -0,0 +1,116 @@
+/**
+ * @fileoverview Dropdown Resolution Repository Tests
+ *
+ * Tests remembering the option confirmed for an ambiguous typed value,
+ * replacing it when a different one is confirmed, and admin review.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+  confirmDropdownResolution,
+  deleteDropdownResolution,
+  listDropdownResolutions,
+  lookupDropdownResolution,
+} from "../../src/models";
+
+describe("Dropdown Resolutions", () => {
+  let testDbPath: string;
+  const candidates = ["FAB-Line 1", "FAB-Line 2", "FAB-Metrology"];
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-dropdown-resolutions-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should return null for values never confirmed", () => {
+    expect(lookupDropdownResolution("project_code", "FAB")).toBeNull();
+  });
+
+  it("should remember the confirmed option and count repeat confirmations", () => {
+    const resolution = { fieldKey: "project_code", typedValue: "FAB ", optionText: "FAB-Metrology", candidates };
+    confirmDropdownResolution(resolution);
+    confirmDropdownResolution(resolution);
+
+    expect(lookupDropdownResolution("project_code", "FAB")).toBe("FAB-Metrology");
+    expect(lookupDropdownResolution("tool", "FAB")).toBeNull();
+
+    const [record] = listDropdownResolutions();
+    expect(record).toMatchObject({
+      fieldKey: "project_code",
+      typedValue: "FAB",
+      optionText: "FAB-Metrology",
+      candidates,
+      confirmedCount: 2,
+    });
+  });
+
+  it("should replace the option when a different one is confirmed", () => {
+    confirmDropdownResolution({ fieldKey: "project_code", typedValue: "FAB", optionText: "FAB-Metrology", candidates });
+    confirmDropdownResolution({ fieldKey: "project_code", typedValue: "FAB", optionText: "FAB-Line 2", candidates });
+
+    expect(lookupDropdownResolution("project_code", "FAB")).toBe("FAB-Line 2");
+    const records = listDropdownResolutions();
+    expect(records).toHaveLength(1);
+    expect(records[0]).toMatchObject({ optionText: "FAB-Line 2", confirmedCount: 1 });
+  });
+
+  it("should filter by field and delete resolutions", () => {
+    confirmDropdownResolution({ fieldKey: "project_code", typedValue: "FAB", optionText: "FAB-Metrology", candidates });
+    confirmDropdownResolution({ fieldKey: "tool", typedValue: "Probe", optionText: "Probe Station 2", candidates: ["Probe Station 1", "Probe Station 2"] });
+
+    const [tool] = listDropdownResolutions("tool");
+    expect(listDropdownResolutions("tool")).toHaveLength(1);
+    expect(tool).toMatchObject({ typedValue: "Probe", optionText: "Probe Station 2" });
+
+    expect(deleteDropdownResolution(tool!.id)).toBe(true);
+    expect(deleteDropdownResolution(tool!.id)).toBe(false);
+    expect(lookupDropdownResolution("tool", "Probe")).toBeNull();
+    expect(listDropdownResolutions()).toHaveLength(1);
+  });
+});
@@ -0,0 +1,119 @@
+/**
+ * @fileoverview Entry Time Zone Tests
+ *
+ * Tests recording the zone an entry was worked in on drafts and imports.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  getTimesheetEntryById,
+  insertTimesheetEntries,
+  saveDraftTimesheetEntry,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Entry Time Zone", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-entry-tz-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should record the zone on a new draft and leave it out when not given", () => {
+    const away = saveDraftTimesheetEntry(undefined, {
+      date: "2025-03-04",
+      hours: 2,
+      project: "OSC-BBB",
+      taskDescription: "Fab walkdown",
+      timeZone: "America/Chicago",
+    });
+    const home = saveDraftTimesheetEntry(undefined, {
+      date: "2025-03-04",
+      hours: 1,
+      project: "OSC-BBB",
+      taskDescription: "Standup",
+    });
+
+    expect(away.entry?.time_zone).toBe("America/Chicago");
+    expect(home.entry?.time_zone).toBeNull();
+  });
+
+  it("should keep the zone on unrelated edits and clear it when set to null", () => {
+    const { id } = saveDraftTimesheetEntry(undefined, {
+      date: "2025-03-04",
+      project: "OSC-BBB",
+      taskDescription: "Fab walkdown",
+      timeZone: "America/Chicago",
+    });
+
+    saveDraftTimesheetEntry(id, { hours: 3 });
+    expect(getTimesheetEntryById(id)?.time_zone).toBe("America/Chicago");
+
+    saveDraftTimesheetEntry(id, { timeZone: null });
+    expect(getTimesheetEntryById(id)?.time_zone).toBeNull();
+  });
+
+  it("should record the zone of imported entries", () => {
+    const result = insertTimesheetEntries([
+      { date: "2025-03-04", hours: 1, project: "OSC-BBB", taskDescription: "Design review", timeZone: "America/Chicago" },
+      { date: "2025-03-05", hours: 1, project: "OSC-BBB", taskDescription: "Design review" },
+    ]);
+
+    expect(result.inserted).toBe(2);
+    const zones = getDb()
+      .prepare("SELECT date, time_zone FROM timesheet ORDER BY date")
+      .all();
+    expect(zones).toEqual([
+      { date: "2025-03-04", time_zone: "America/Chicago" },
+      { date: "2025-03-05", time_zone: null },
+    ]);
+  });
+});
@@ -0,0 +1,164 @@
+/**
+ * @fileoverview Data Integrity Check Tests
+ *
+ * Tests that rows left behind by older versions are reported, that repair
+ * fixes only the issues with an unambiguous fix, and that in_progress rows
+ * are left alone while a submission is running.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    debug: vi.fn(),
+    verbose: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  shutdownDatabase,
+  checkDataIntegrity,
+} from "../../src/models";
+
+const insertEntry = (values: {
+  date?: string | null;
+  hours?: number | null;
+  project?: string | null;
+  task?: string | null;
+  status?: string | null;
+  submittedAt?: string | null;
+  submittedRunId?: number | null;
+  amendsId?: number | null;
+}): number =>
+  Number(
+    getDb()
+      .prepare(
+        `INSERT INTO timesheet (date, hours, project, task_description, status, submitted_at, submitted_run_id, amends_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)`
+      )
+      .run(
+        values.date === undefined ? "2025-01-15" : values.date,
+        values.hours === undefined ? 8 : values.hours,
+        values.project === undefined ? "Project" : values.project,
+        values.task === undefined ? `Task ${Math.random()}` : values.task,
+        values.status ?? null,
+        values.submittedAt ?? null,
+        values.submittedRunId ?? null,
+        values.amendsId ?? null
+      ).lastInsertRowid
+  );
+
+describe("Data Integrity Check", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-integrity-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should report nothing for a clean database", () => {
+    insertEntry({});
+    insertEntry({ status: "Complete", submittedAt: "2025-01-16 10:00:00" });
+
+    const report = checkDataIntegrity();
+    expect(report.issues).toEqual([]);
+    expect(report.totalIssues).toBe(0);
+  });
+
+  it("should report issues without changing anything by default", () => {
+    const missingTime = insertEntry({ status: "Complete" });
+    const badHours = insertEntry({ hours: 0.3 });
+    const stuck = insertEntry({ status: "in_progress" });
+    const oldSpelling = insertEntry({ status: " complete ", submittedAt: "2025-01-16 10:00:00" });
+    const danglingRun = insertEntry({ status: "Complete", submittedAt: "2025-01-16 10:00:00", submittedRunId: 999 });
+    const orphanAmendment = insertEntry({ amendsId: 12345 });
+    getDb()
+      .prepare("INSERT INTO sessions (session_token, email, expires_at) VALUES (?, ?, ?)")
+      .run("expired-hash", "user@company.com", "2020-01-01T00:00:00.000Z");
+
+    const report = checkDataIntegrity({}, new Date("2025-06-01T00:00:00.000Z"));
+    const byKind = Object.fromEntries(report.issues.map((issue) => [issue.kind, issue]));
+
+    expect(byKind["complete-missing-submitted-at"]!.ids).toEqual([missingTime]);
+    expect(byKind["invalid-hours"]!.ids).toEqual([badHours]);
+    expect(byKind["stale-in-progress"]!.ids).toEqual([stuck]);
+    expect(byKind["unknown-status"]!.ids).toEqual([oldSpelling]);
+    expect(byKind["dangling-submitted-run"]!.ids).toEqual([danglingRun]);
+    expect(byKind["dangling-amendment"]!.ids).toEqual([orphanAmendment]);
+    expect(byKind["expired-session"]!.count).toBe(1);
+    expect(report.totalRepaired).toBe(0);
+
+    const row = getDb().prepare("SELECT status FROM timesheet WHERE id = ?").get(stuck) as { status: string };
+    expect(row.status).toBe("in_progress");
+  });
+
+  it("should repair fixable issues and leave the rest for the user", () => {
+    const missingTime = insertEntry({ status: "Complete" });
+    insertEntry({ hours: 0.3 });
+    const stuck = insertEntry({ status: "in_progress" });
+    const oldSpelling = insertEntry({ status: "COMPLETE", submittedAt: "2025-01-16 10:00:00" });
+    getDb()
+      .prepare("INSERT INTO sessions (session_token, email, expires_at) VALUES (?, ?, ?)")
+      .run("unreadable-expiry", "user@company.com", "not a date");
+
+    const report = checkDataIntegrity({ repair: true });
+    expect(report.totalRepaired).toBe(4);
+
+    const db = getDb();
+    const rows = db
+      .prepare("SELECT id, status, submitted_at FROM timesheet WHERE id IN (?, ?, ?)")
+      .all(missingTime, stuck, oldSpelling) as Array<{ id: number; status: string | null; submitted_at: string | null }>;
+    const byId = new Map(rows.map((row) => [row.id, row]));
+    expect(byId.get(missingTime)!.submitted_at).not.toBeNull();
+    expect(byId.get(stuck)!.status).toBeNull();
+    expect(byId.get(oldSpelling)!.status).toBe("Complete");
+    expect(db.prepare("SELECT COUNT(*) AS count FROM sessions").get()).toEqual({ count: 0 });
+
+    const after = checkDataIntegrity();
+    expect(after.issues.map((issue) => issue.kind)).toEqual(["invalid-hours"]);
+  });
+
+  it("should leave in_progress entries alone while a submission is running", () => {
+    const stuck = insertEntry({ status: "in_progress" });
+
+    const report = checkDataIntegrity({ repair: true, submissionInProgress: true });
+
+    expect(report.issues).toEqual([]);
+    const row = getDb().prepare("SELECT status FROM timesheet WHERE id = ?").get(stuck) as { status: string };
+    expect(row.status).toBe("in_progress");
+  });
+});
@@ -202,6 +202,30 @@ describe('Database Migrations', () => {
       const version = getCurrentSchemaVersion(db);
       expect(version).toBe(CURRENT_SCHEMA_VERSION);
     });
+    it('should not fail the amendment migration on existing duplicate entries', () => {
+      const db = getDb();
+      ensureSchema();
+      runMigrations(db, testDbPath);
+
+      // Databases that already held duplicates never got the unique index
+      db.exec('DROP INDEX IF EXISTS uq_timesheet_nk');
+      const insertStmt = db.prepare(`
+        INSERT INTO timesheet (date, hours, project, task_description)
+        VALUES (?, ?, ?, ?)
+      `);
+      insertStmt.run('2025-01-15', 8.0, 'FL-Carver Techs', 'Duplicate');
+      insertStmt.run('2025-01-15', 4.0, 'FL-Carver Techs', 'Duplicate');
+      setSchemaVersion(db, 9);
+
+      const result = runMigrations(db, testDbPath);
+
+      expect(result.success).toBe(true);
+      expect(getCurrentSchemaVersion(db)).toBe(CURRENT_SCHEMA_VERSION);
+      const index = db
+        .prepare(`SELECT name FROM sqlite_master WHERE type = 'index' AND name = 'uq_timesheet_nk'`)
+        .get();
+      expect(index).toBeUndefined();
+    });
   });
 
   describe('needsMigration Helper', () => {
@@ -0,0 +1,121 @@
+/**
+ * @fileoverview Project Retirement Repository Tests
+ *
+ * Tests storing retirement dates in the project catalog and moving drafts
+ * from a retired project to its successor.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  getTimesheetEntryById,
+  insertDraftTimesheetEntry,
+  markTimesheetEntriesAsInProgress,
+  markTimesheetEntriesAsSubmitted,
+  reassignDraftProject,
+} from "../../src/models/timesheet-repository";
+import {
+  addProject,
+  getProjectByName,
+  getProjectRetirements,
+  updateProject,
+} from "../../src/models/business-config.repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Project Retirement", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-project-retirement-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should store and clear a retirement date", () => {
+    const id = addProject({ name: "Retiring Project" });
+    updateProject(id, { retired_on: "2025-03-01", successor_project: "Next Project" });
+
+    expect(getProjectRetirements()).toContainEqual({
+      name: "Retiring Project",
+      retired_on: "2025-03-01",
+      successor_project: "Next Project",
+    });
+
+    updateProject(id, { retired_on: null });
+    expect(getProjectByName("Retiring Project")?.retired_on).toBeNull();
+  });
+
+  it("should move only drafts dated on or after the retirement date", () => {
+    const before = insertDraftTimesheetEntry({ date: "02/28/2025", hours: 1, project: "Old", taskDescription: "A" });
+    const after = insertDraftTimesheetEntry({ date: "03/03/2025", hours: 1, project: "Old", taskDescription: "B" });
+    const submitted = insertDraftTimesheetEntry({ date: "2025-03-04", hours: 1, project: "Old", taskDescription: "C" });
+    markTimesheetEntriesAsInProgress([submitted]);
+    markTimesheetEntriesAsSubmitted([submitted]);
+
+    const ids = reassignDraftProject("Old", "New", { fromDate: "2025-03-01", validTools: [] });
+
+    expect(ids).toEqual([after]);
+    expect(getTimesheetEntryById(after)?.project).toBe("New");
+    expect(getTimesheetEntryById(before)?.project).toBe("Old");
+    expect(getTimesheetEntryById(submitted)?.project).toBe("Old");
+  });
+
+  it("should keep tools the successor offers and clear the rest", () => {
+    const kept = insertDraftTimesheetEntry({
+      date: "2025-03-03", hours: 1, project: "Old", tool: "Shared Tool", chargeCode: "EPR1", taskDescription: "A",
+    });
+    const cleared = insertDraftTimesheetEntry({
+      date: "2025-03-03", hours: 1, project: "Old", tool: "Old Tool", chargeCode: "EPR1", taskDescription: "B",
+    });
+
+    reassignDraftProject("Old", "New", { validTools: ["Shared Tool"] });
+
+    expect(getTimesheetEntryById(kept)).toMatchObject({ tool: "Shared Tool", detail_charge_code: "EPR1" });
+    expect(getTimesheetEntryById(cleared)).toMatchObject({ tool: null, detail_charge_code: null });
+  });
+});
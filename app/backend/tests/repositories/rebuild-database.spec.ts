@@ -0,0 +1,143 @@
+/**
+ * @fileoverview Database Rebuild Tests
+ *
+ * Tests that an admin rebuild leaves the database at the current schema,
+ * including columns that only migrations add, and clears stored credentials
+ * from the keychain store as well as SQLite.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+vi.mock("electron", () => ({
+  app: { getPath: vi.fn(() => os.tmpdir()) },
+  safeStorage: {
+    isEncryptionAvailable: vi.fn(() => false),
+    encryptString: vi.fn(),
+    decryptString: vi.fn(),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  rebuildDatabase,
+  shutdownDatabase,
+  setCredentialKeychain,
+  storeCredentials,
+} from "../../src/models";
+import {
+  createFileKeychain,
+  type KeychainCipher,
+} from "../../src/services/security/credential-keychain";
+import {
+  CURRENT_SCHEMA_VERSION,
+  getCurrentSchemaVersion,
+} from "../../src/models/migrations";
+
+describe("Database Rebuild", () => {
+  let testDbPath: string;
+
+  const timesheetColumns = (): string[] =>
+    (getDb().prepare(`PRAGMA table_info(timesheet)`).all() as Array<{ name: string }>).map(
+      (column) => column.name
+    );
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-rebuild-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    setCredentialKeychain(null);
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    for (const file of fs.readdirSync(os.tmpdir())) {
+      if (file.startsWith(path.basename(testDbPath, ".sqlite"))) {
+        try {
+          fs.rmSync(path.join(os.tmpdir(), file), { force: true });
+        } catch {
+          // Ignore
+        }
+      }
+    }
+  });
+
+  it("should restore migration-only timesheet columns", () => {
+    rebuildDatabase();
+
+    expect(timesheetColumns()).toEqual(
+      expect.arrayContaining([
+        "submission_key",
+        "amends_id",
+        "created_at",
+        "updated_at",
+        "failure_reason",
+        "time_zone",
+        "external_ref",
+        "category",
+        "entry_kind",
+      ])
+    );
+    expect(getCurrentSchemaVersion(getDb())).toBe(CURRENT_SCHEMA_VERSION);
+  });
+
+  it("should drop existing entries", () => {
+    getDb()
+      .prepare(`INSERT INTO timesheet (date, hours, project, task_description) VALUES (?, ?, ?, ?)`)
+      .run("2025-01-15", 8, "FL-Carver Techs", "Before rebuild");
+
+    rebuildDatabase();
+
+    const row = getDb().prepare(`SELECT COUNT(*) AS count FROM timesheet`).get() as { count: number };
+    expect(row.count).toBe(0);
+  });
+
+  it("should clear credentials from the keychain store", () => {
+    /** Plain-text stand-in for safeStorage */
+    const fakeCipher: KeychainCipher = {
+      isAvailable: () => true,
+      encrypt: (plainText) => Buffer.from(plainText, "utf-8"),
+      decrypt: (encrypted) => encrypted.toString("utf-8"),
+    };
+    const keychainPath = `${testDbPath.replace(/\.sqlite$/, "")}-credential-keychain.json`;
+    const keychain = createFileKeychain(keychainPath, fakeCipher);
+    setCredentialKeychain(keychain);
+    storeCredentials("smartsheet", "user@test.com", "password123");
+    expect(keychain.services()).toEqual(["smartsheet"]);
+
+    rebuildDatabase();
+
+    expect(keychain.services()).toEqual([]);
+    expect(JSON.parse(fs.readFileSync(keychainPath, "utf-8"))).toEqual({});
+  });
+});
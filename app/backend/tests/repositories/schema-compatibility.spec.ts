@@ -0,0 +1,120 @@
+/**
+ * @fileoverview Schema Compatibility Tests
+ *
+ * Tests that a database migrated by a newer app version is opened read-only:
+ * reads keep working, writes fail with DatabaseSchemaTooNewError and the
+ * schema is left untouched.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    debug: vi.fn(),
+    verbose: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import { DatabaseSchemaTooNewError } from "@sheetpilot/shared/errors";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  shutdownDatabase,
+  getCompatibilityInfo,
+  isDatabaseReadOnly,
+  setSchemaVersion,
+  CURRENT_SCHEMA_VERSION,
+} from "../../src/models";
+
+describe("Schema Compatibility", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-compatibility-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    getDb()
+      .prepare("INSERT INTO timesheet (date, hours, project, task_description) VALUES (?, ?, ?, ?)")
+      .run("2025-01-15", 8, "Project", "Task");
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    for (const file of [testDbPath, `${testDbPath}-wal`, `${testDbPath}-shm`]) {
+      if (fs.existsSync(file)) {
+        try {
+          fs.unlinkSync(file);
+        } catch {
+          // Ignore
+        }
+      }
+    }
+  });
+
+  const reopenAtVersion = (version: number): void => {
+    setSchemaVersion(getDb(), version);
+    shutdownDatabase();
+    ensureSchema();
+  };
+
+  it("should report a database at this build's version as compatible", () => {
+    setSchemaVersion(getDb(), CURRENT_SCHEMA_VERSION);
+
+    const info = getCompatibilityInfo();
+    expect(info).toMatchObject({
+      schemaVersion: CURRENT_SCHEMA_VERSION,
+      appSchemaVersion: CURRENT_SCHEMA_VERSION,
+      compatible: true,
+    });
+    expect(info.message).toBeUndefined();
+    expect(isDatabaseReadOnly(getDb())).toBe(false);
+  });
+
+  it("should open a newer database read-only and refuse writes with a typed error", () => {
+    reopenAtVersion(CURRENT_SCHEMA_VERSION + 1);
+    const db = getDb();
+
+    expect(isDatabaseReadOnly(db)).toBe(true);
+    expect(db.prepare("SELECT COUNT(*) AS count FROM timesheet").get()).toEqual({ count: 1 });
+    expect(() =>
+      db.prepare("UPDATE timesheet SET project = 'Other'").run()
+    ).toThrow(DatabaseSchemaTooNewError);
+    expect(() => db.exec("DELETE FROM timesheet")).toThrow(DatabaseSchemaTooNewError);
+
+    const info = getCompatibilityInfo();
+    expect(info.compatible).toBe(false);
+    expect(info.schemaVersion).toBe(CURRENT_SCHEMA_VERSION + 1);
+    expect(info.message).toContain("newer version");
+  });
+
+  it("should pick up a newer version written while the connection is open", () => {
+    const db = getDb();
+    // As a newer build running alongside this one would
+    setSchemaVersion(db, CURRENT_SCHEMA_VERSION + 2);
+
+    expect(getCompatibilityInfo().compatible).toBe(false);
+    expect(() =>
+      db.prepare("DELETE FROM timesheet").run()
+    ).toThrow(DatabaseSchemaTooNewError);
+  });
+});
@@ -29,7 +29,13 @@ import {
   createSession,
   validateSession,
   clearSession,
-  clearUserSessions
+  clearUserSessions,
+  hashSessionToken,
+  hasActiveSession,
+  rotateSession,
+  setSessionInstanceId,
+  purgeExpiredSessions,
+  getSessionStats
 } from '../../src/models/session-repository';
 import { setDbPath, openDb, ensureSchema, shutdownDatabase } from '../../src/models';
 
@@ -88,7 +94,7 @@ describe('Session Repository', () => {
       const token = createSession('user@test.com', false);
       
       const db = openDb();
-      const session = db.prepare('SELECT expires_at FROM sessions WHERE session_token = ?').get(token);
+      const session = db.prepare('SELECT expires_at FROM sessions WHERE session_token = ?').get(hashSessionToken(token));
       db.close();
       
       expect(session).toBeDefined();
@@ -99,7 +105,7 @@ describe('Session Repository', () => {
       const token = createSession('user@test.com', true);
       
       const db = openDb();
-      const session = db.prepare('SELECT expires_at FROM sessions WHERE session_token = ?').get(token);
+      const session = db.prepare('SELECT expires_at FROM sessions WHERE session_token = ?').get(hashSessionToken(token));
       db.close();
       
       expect(session).toBeDefined();
@@ -161,7 +167,7 @@ describe('Session Repository', () => {
       const db = openDb();
       const pastDate = new Date(Date.now() - 100000).toISOString();
       db.prepare('UPDATE sessions SET expires_at = ? WHERE session_token = ?')
-        .run(pastDate, token);
+        .run(pastDate, hashSessionToken(token));
       db.close();
       
       const validation = validateSession(token);
@@ -190,7 +196,7 @@ describe('Session Repository', () => {
       try {
         // Try to insert with invalid date - SQLite might accept it as text
         db.prepare('INSERT INTO sessions (session_token, email, expires_at, is_admin) VALUES (?, ?, ?, ?)')
-          .run('malformed-token', 'user@test.com', 'invalid-date-string', 0);
+          .run(hashSessionToken('malformed-token'), 'user@test.com', 'invalid-date-string', 0);
       } catch {
         // If insert fails due to constraints, that's fine - session won't exist
       }
@@ -341,7 +347,7 @@ describe('Session Repository', () => {
       const db = openDb();
       const pastDate = new Date(Date.now() - 1000).toISOString();
       db.prepare('UPDATE sessions SET expires_at = ? WHERE session_token = ?')
-        .run(pastDate, token);
+        .run(pastDate, hashSessionToken(token));
       db.close();
       
       const validation = validateSession(token);
@@ -363,7 +369,7 @@ describe('Session Repository', () => {
       const db = openDb();
       const nowDate = new Date().toISOString();
       db.prepare('UPDATE sessions SET expires_at = ? WHERE session_token = ?')
-        .run(nowDate, token);
+        .run(nowDate, hashSessionToken(token));
       db.close();
       
       // Should be expired (or about to be)
@@ -410,5 +416,95 @@ describe('Session Repository', () => {
       expect(validation.email).toBe(specialEmail);
     });
   });
-});
 
+  describe('Token Hardening', () => {
+    it('should store only a hash of the token', () => {
+      const token = createSession('user@test.com', false);
+
+      const db = openDb();
+      const rows = db.prepare('SELECT session_token FROM sessions').all() as DbRow[];
+      db.close();
+
+      expect(rows).toHaveLength(1);
+      expect(rows[0]!['session_token']).not.toBe(token);
+      expect(rows[0]!['session_token']).toMatch(/^[0-9a-f]{64}$/);
+    });
+
+    it('should reject tokens issued by another app instance', () => {
+      setSessionInstanceId('instance-a');
+      const token = createSession('user@test.com', false);
+      expect(validateSession(token).valid).toBe(true);
+
+      setSessionInstanceId('instance-b');
+      expect(validateSession(token).valid).toBe(false);
+
+      setSessionInstanceId('instance-a');
+      expect(validateSession(token).valid).toBe(true);
+    });
+
+    it('should revoke earlier tokens when rotating on elevation', () => {
+      const before = createSession('admin', false);
+
+      const after = rotateSession('admin', false, true);
+
+      expect(after).not.toBe(before);
+      expect(validateSession(before).valid).toBe(false);
+      expect(validateSession(after).isAdmin).toBe(true);
+    });
+
+    it('should report active sessions by email without exposing tokens', () => {
+      createSession('user@test.com', true);
+
+      expect(hasActiveSession('user@test.com')).toBe(true);
+      expect(hasActiveSession('nobody@test.com')).toBe(false);
+    });
+  });
+
+  describe('Session Cleanup', () => {
+    const setSession = (token: string, fields: { expires_at?: string | null; created_at?: string }) => {
+      const db = openDb();
+      for (const [column, value] of Object.entries(fields)) {
+        db.prepare(`UPDATE sessions SET ${column} = ? WHERE session_token = ?`).run(value, hashSessionToken(token));
+      }
+      db.close();
+    };
+
+    it('should purge expired, unreadable and abandoned sessions only', () => {
+      const now = new Date('2025-03-01T12:00:00Z');
+      const expired = createSession('user@test.com', true);
+      setSession(expired, { expires_at: '2025-02-01T00:00:00.000Z' });
+      const unreadable = createSession('user@test.com', true);
+      setSession(unreadable, { expires_at: 'not a date' });
+      const abandoned = createSession('other@test.com', false);
+      setSession(abandoned, { created_at: '2025-01-01 08:00:00' });
+      const recent = createSession('other@test.com', false);
+      setSession(recent, { created_at: '2025-02-27 08:00:00' });
+      const remembered = createSession('user@test.com', true);
+      setSession(remembered, { expires_at: '2025-03-20T00:00:00.000Z', created_at: '2025-02-18 08:00:00' });
+
+      expect(getSessionStats(now)).toEqual({
+        total: 5,
+        active: 2,
+        expired: 3,
+        oldestActiveCreatedAt: '2025-02-18 08:00:00',
+        users: [
+          { email: 'user@test.com', sessions: 3, active: 1, isAdmin: false, oldestActiveCreatedAt: '2025-02-18 08:00:00' },
+          { email: 'other@test.com', sessions: 2, active: 1, isAdmin: false, oldestActiveCreatedAt: '2025-02-27 08:00:00' }
+        ]
+      });
+
+      expect(purgeExpiredSessions(now)).toBe(3);
+      expect(getSessionStats(now)).toMatchObject({ total: 2, active: 2, expired: 0 });
+      expect(validateSession(recent).valid).toBe(true);
+    });
+
+    it('should report how many sessions were revoked for a user', () => {
+      createSession('user@test.com', false);
+      createSession('user@test.com', true);
+      createSession('other@test.com', false);
+
+      expect(clearUserSessions('user@test.com')).toBe(2);
+      expect(getSessionStats().users.map((u) => u.email)).toEqual(['other@test.com']);
+    });
+  });
+});
@@ -0,0 +1,121 @@
+/**
+ * @fileoverview Prepared Statement Cache Tests
+ *
+ * Tests that hot repository queries reuse prepared statements on the managed
+ * connection and that the shared status update keeps its row-count checks.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    debug: vi.fn(),
+    verbose: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  shutdownDatabase,
+  insertTimesheetEntry,
+  getPendingTimesheetEntries,
+  markTimesheetEntriesAsInProgress,
+  markTimesheetEntriesAsSubmitted,
+  removeFailedTimesheetEntries,
+  prepareCached,
+  getStatementCacheStats,
+  STATEMENT_CACHE_LIMIT,
+} from "../../src/models";
+
+describe("Prepared Statement Cache", () => {
+  let testDbPath: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-statement-cache-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should reuse statements for repeated pending entry reads", () => {
+    const db = getDb();
+    const before = getStatementCacheStats(db);
+    expect(before).not.toBeNull();
+
+    getPendingTimesheetEntries();
+    getPendingTimesheetEntries();
+    getPendingTimesheetEntries();
+
+    const after = getStatementCacheStats(db);
+    expect(after!.misses - before!.misses).toBe(1);
+    expect(after!.hits - before!.hits).toBe(2);
+  });
+
+  it("should evict the least recently used statement past the limit", () => {
+    const db = getDb();
+    const first = prepareCached(db, "SELECT 0");
+    for (let i = 1; i <= STATEMENT_CACHE_LIMIT; i++) {
+      prepareCached(db, `SELECT ${i}`);
+    }
+
+    expect(getStatementCacheStats(db)!.size).toBe(STATEMENT_CACHE_LIMIT);
+    expect(prepareCached(db, "SELECT 0")).not.toBe(first);
+  });
+
+  it("should keep status transitions and row-count checks", () => {
+    insertTimesheetEntry({
+      date: "2025-01-15",
+      hours: 8,
+      project: "Cache Project",
+      taskDescription: "Cache task",
+    });
+    const [entry] = getPendingTimesheetEntries();
+    const id = entry!.id;
+
+    expect(() => removeFailedTimesheetEntries([id])).toThrow(
+      /Database update mismatch/
+    );
+
+    markTimesheetEntriesAsInProgress([id]);
+    removeFailedTimesheetEntries([id]);
+    expect(getPendingTimesheetEntries().map((row) => row.id)).toEqual([id]);
+
+    markTimesheetEntriesAsSubmitted([id]);
+    const row = getDb()
+      .prepare("SELECT status, submitted_at FROM timesheet WHERE id = ?")
+      .get(id) as { status: string; submitted_at: string | null };
+    expect(row.status).toBe("Complete");
+    expect(row.submitted_at).not.toBeNull();
+  });
+});
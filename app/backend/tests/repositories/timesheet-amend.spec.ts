@@ -0,0 +1,191 @@
+/**
+ * @fileoverview Timesheet Amendment Tests
+ *
+ * Tests cloning submitted entries into linked amendment drafts.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  amendTimesheetEntry,
+  getAmendmentChain,
+  getSubmittedTimesheetEntriesForExport,
+  getTimesheetEntryStatus,
+  insertTimesheetEntry,
+  markTimesheetEntriesAsSubmitted,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+describe("Timesheet Amendments", () => {
+  let testDbPath: string;
+
+  const insertSubmitted = (): number => {
+    insertTimesheetEntry({
+      date: "2025-01-15",
+      hours: 2,
+      project: "OSC-BBB",
+      taskDescription: "Submitted work",
+    });
+    const row = getDb()
+      .prepare("SELECT id FROM timesheet WHERE task_description = ?")
+      .get("Submitted work") as { id: number };
+    markTimesheetEntriesAsSubmitted([row.id]);
+    return row.id;
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-amend-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should clone a submitted entry into a linked draft", () => {
+    const originalId = insertSubmitted();
+
+    const result = amendTimesheetEntry(originalId);
+
+    expect(result.success).toBe(true);
+    expect(result.id).not.toBe(originalId);
+    const amendment = getDb()
+      .prepare("SELECT * FROM timesheet WHERE id = ?")
+      .get(result.id) as Record<string, unknown>;
+    expect(amendment["status"]).toBeNull();
+    expect(amendment["amends_id"]).toBe(originalId);
+    expect(amendment["task_description"]).toBe("Submitted work");
+    expect(getTimesheetEntryStatus(originalId)).toBe("Complete");
+  });
+
+  it("should keep the entry kind, category, reference and time zone of the original", () => {
+    const originalId = insertSubmitted();
+    getDb()
+      .prepare(
+        `UPDATE timesheet
+         SET entry_kind = 'pto', category = 'Vacation', external_ref = 'JIRA-42', time_zone = 'America/Phoenix'
+         WHERE id = ?`
+      )
+      .run(originalId);
+
+    const result = amendTimesheetEntry(originalId);
+
+    const amendment = getDb()
+      .prepare("SELECT entry_kind, category, external_ref, time_zone FROM timesheet WHERE id = ?")
+      .get(result.id) as Record<string, unknown>;
+    expect(amendment).toEqual({
+      entry_kind: "pto",
+      category: "Vacation",
+      external_ref: "JIRA-42",
+      time_zone: "America/Phoenix",
+    });
+  });
+
+  it("should reuse an open amendment instead of creating another", () => {
+    const originalId = insertSubmitted();
+
+    const first = amendTimesheetEntry(originalId);
+    const second = amendTimesheetEntry(originalId);
+
+    expect(second.id).toBe(first.id);
+  });
+
+  it("should refuse to amend drafts and missing entries", () => {
+    insertTimesheetEntry({
+      date: "2025-01-16",
+      hours: 1,
+      project: "OSC-BBB",
+      taskDescription: "Still a draft",
+    });
+    const draft = getDb()
+      .prepare("SELECT id FROM timesheet WHERE task_description = ?")
+      .get("Still a draft") as { id: number };
+
+    expect(amendTimesheetEntry(draft.id).success).toBe(false);
+    expect(amendTimesheetEntry(9999).errorMessage).toBe("Entry not found");
+  });
+
+  it("should report undefined status for missing entries", () => {
+    expect(getTimesheetEntryStatus(9999)).toBeUndefined();
+  });
+
+  it("should refuse a second correction of an already corrected entry", () => {
+    const originalId = insertSubmitted();
+    const amendmentId = amendTimesheetEntry(originalId).id!;
+    markTimesheetEntriesAsSubmitted([amendmentId]);
+
+    const result = amendTimesheetEntry(originalId);
+
+    expect(result.success).toBe(false);
+    expect(amendTimesheetEntry(amendmentId).success).toBe(true);
+  });
+
+  it("should return the amendment chain oldest first from any link", () => {
+    const originalId = insertSubmitted();
+    const amendmentId = amendTimesheetEntry(originalId).id!;
+    markTimesheetEntriesAsSubmitted([amendmentId]);
+    const secondId = amendTimesheetEntry(amendmentId).id!;
+
+    const ids = (fromId: number) => getAmendmentChain(fromId).map((row) => row.id);
+
+    expect(ids(originalId)).toEqual([originalId, amendmentId, secondId]);
+    expect(ids(secondId)).toEqual([originalId, amendmentId, secondId]);
+    expect(getAmendmentChain(9999)).toEqual([]);
+  });
+
+  it("should mark originals superseded by a submitted correction in exports", () => {
+    const originalId = insertSubmitted();
+    const amendmentId = amendTimesheetEntry(originalId).id!;
+
+    const beforeSubmit = getSubmittedTimesheetEntriesForExport();
+    expect(beforeSubmit.find((row) => row.id === originalId)?.superseded_by).toBeNull();
+
+    markTimesheetEntriesAsSubmitted([amendmentId]);
+    const rows = getSubmittedTimesheetEntriesForExport();
+
+    expect(rows.find((row) => row.id === originalId)?.superseded_by).toBe(amendmentId);
+    expect(rows.find((row) => row.id === amendmentId)?.superseded_by).toBeNull();
+  });
+});
@@ -0,0 +1,121 @@
+/**
+ * @fileoverview Timesheet Audit Trail Tests
+ *
+ * Tests created/edited timestamps and the submitting run recorded on entries.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  getTimesheetEntryById,
+  insertDraftTimesheetEntry,
+  markTimesheetEntriesAsSubmitted,
+  recordSubmissionRun,
+  updateDraftTimesheetEntry,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+  startRun,
+} from "../../src/models";
+
+const OLD_STAMP = "2025-01-01 00:00:00";
+
+describe("Timesheet Audit Trail", () => {
+  let testDbPath: string;
+
+  const insertDraft = (): number =>
+    insertDraftTimesheetEntry({
+      date: "2025-01-15",
+      hours: 2,
+      project: "OSC-BBB",
+      taskDescription: "Audited work",
+    });
+
+  const backdate = (id: number): void => {
+    getDb()
+      .prepare("UPDATE timesheet SET created_at = ?, updated_at = ? WHERE id = ?")
+      .run(OLD_STAMP, OLD_STAMP, id);
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-audit-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should stamp created and updated times on insert", () => {
+    const entry = getTimesheetEntryById(insertDraft());
+
+    expect(entry?.created_at).toBeTruthy();
+    expect(entry?.updated_at).toBe(entry?.created_at);
+    expect(entry?.submitted_run_id).toBeNull();
+  });
+
+  it("should move updated_at on edits but not on status changes", () => {
+    const id = insertDraft();
+    backdate(id);
+
+    markTimesheetEntriesAsSubmitted([id]);
+    expect(getTimesheetEntryById(id)?.updated_at).toBe(OLD_STAMP);
+
+    getDb().prepare("UPDATE timesheet SET status = NULL WHERE id = ?").run(id);
+    updateDraftTimesheetEntry(id, { hours: 3 });
+    const entry = getTimesheetEntryById(id);
+    expect(entry?.updated_at).not.toBe(OLD_STAMP);
+    expect(entry?.created_at).toBe(OLD_STAMP);
+  });
+
+  it("should record the submitting run on submitted entries only", () => {
+    const submittedId = insertDraft();
+    const draftId = insertDraftTimesheetEntry({ date: "2025-01-16", project: "OSC-BBB" });
+    markTimesheetEntriesAsSubmitted([submittedId]);
+    const runId = startRun("timesheet-submission", "manual");
+
+    expect(recordSubmissionRun([submittedId, draftId], runId)).toBe(1);
+    expect(getTimesheetEntryById(submittedId)?.submitted_run_id).toBe(runId);
+    expect(getTimesheetEntryById(draftId)?.submitted_run_id).toBeNull();
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * @fileoverview Timesheet Failure Reason Tests
+ *
+ * Tests that rejection reasons are stored on reverted entries and cleared
+ * when the entry is edited or later submitted.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+// Mock logger
+vi.mock("../../../shared/logger", () => ({
+  dbLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() })),
+  },
+}));
+
+import {
+  getTimesheetEntryById,
+  insertDraftTimesheetEntry,
+  markTimesheetEntriesAsInProgress,
+  markTimesheetEntriesAsSubmitted,
+  recordTimesheetFailureReasons,
+  removeFailedTimesheetEntries,
+  updateDraftTimesheetEntry,
+} from "../../src/models/timesheet-repository";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+} from "../../src/models";
+
+const REASON = "Smartsheet rejected the entry: Fix the errors below (Hours: Must be a number)";
+
+describe("Timesheet Failure Reason", () => {
+  let testDbPath: string;
+
+  const insertRejected = (): number => {
+    const id = insertDraftTimesheetEntry({
+      date: "2025-01-15",
+      hours: 2,
+      project: "OSC-BBB",
+      taskDescription: "Rejected work",
+    });
+    markTimesheetEntriesAsInProgress([id]);
+    removeFailedTimesheetEntries([id]);
+    recordTimesheetFailureReasons([{ id, reason: REASON }]);
+    return id;
+  };
+
+  beforeEach(() => {
+    testDbPath = path.join(
+      os.tmpdir(),
+      `sheetpilot-failure-reason-test-${Date.now()}.sqlite`
+    );
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    if (fs.existsSync(testDbPath)) {
+      try {
+        fs.unlinkSync(testDbPath);
+      } catch {
+        // Ignore
+      }
+    }
+  });
+
+  it("should store the reason on entries reverted to pending", () => {
+    const id = insertRejected();
+
+    const entry = getTimesheetEntryById(id);
+    expect(entry?.status).toBeNull();
+    expect(entry?.failure_reason).toBe(REASON);
+  });
+
+  it("should not annotate entries that are not pending", () => {
+    const id = insertDraftTimesheetEntry({ date: "2025-01-16", project: "OSC-BBB" });
+    markTimesheetEntriesAsInProgress([id]);
+
+    expect(recordTimesheetFailureReasons([{ id, reason: REASON }])).toBe(0);
+  });
+
+  it("should clear the reason when the entry is edited", () => {
+    const id = insertRejected();
+
+    updateDraftTimesheetEntry(id, { hours: 2.5 });
+
+    expect(getTimesheetEntryById(id)?.failure_reason).toBeNull();
+  });
+
+  it("should clear the reason once the entry is submitted", () => {
+    const id = insertRejected();
+
+    markTimesheetEntriesAsSubmitted([id]);
+
+    expect(getTimesheetEntryById(id)?.failure_reason).toBeNull();
+  });
+});
@@ -33,6 +33,13 @@ import {
   getSubmittedTimesheetEntriesForExport,
   markTimesheetEntriesAsSubmitted,
   removeFailedTimesheetEntries,
+  saveDraftTimesheetEntry,
+  getDraftTimesheetEntryById,
+  findSubmittedTimesheetEntryId,
+  getCompletedTimesheetEntriesPage,
+  getSubmittedHoursForDate,
+  deleteUnsubmittedTimesheetEntry,
+  getDraftDateTotals,
 } from "../../src/models/timesheet-repository";
 import {
   setDbPath,
@@ -430,6 +437,95 @@ describe("Timesheet Repository", () => {
     });
   });
 
+  describe("Draft Operations", () => {
+    it("should insert partial drafts and update only the given fields", () => {
+      const inserted = saveDraftTimesheetEntry(undefined, {
+        date: "2025-01-15",
+        project: "Draft Project",
+      });
+      expect(inserted.changes).toBe(1);
+      expect(inserted.entry?.hours).toBeNull();
+
+      const updated = saveDraftTimesheetEntry(inserted.id, {
+        hours: 2.5,
+        taskDescription: "Draft task",
+        tool: "",
+      });
+      expect(updated.changes).toBe(1);
+      expect(updated.entry).toMatchObject({
+        date: "2025-01-15",
+        hours: 2.5,
+        project: "Draft Project",
+        tool: null,
+        task_description: "Draft task",
+      });
+    });
+
+    it("should leave submitted entries untouched", () => {
+      const { id } = saveDraftTimesheetEntry(undefined, {
+        date: "2025-01-16",
+        hours: 4,
+        project: "Locked Project",
+        taskDescription: "Locked task",
+      });
+      markTimesheetEntriesAsSubmitted([id]);
+
+      expect(saveDraftTimesheetEntry(id, { hours: 8 }).changes).toBe(0);
+      expect(deleteUnsubmittedTimesheetEntry(id)).toBe(0);
+      expect(getDraftTimesheetEntryById(id)).toBeUndefined();
+      expect(
+        findSubmittedTimesheetEntryId({
+          date: "2025-01-16",
+          project: "Locked Project",
+          taskDescription: "Locked task",
+        })
+      ).toBe(id);
+      expect(getSubmittedHoursForDate("2025-01-16")).toBe(4);
+    });
+
+    it("should page submitted entries with a total count", () => {
+      const ids = [1, 2, 3].map(
+        (day) =>
+          saveDraftTimesheetEntry(undefined, {
+            date: `2025-01-0${day}`,
+            hours: 1,
+            project: "Archive Project",
+            taskDescription: `Task ${day}`,
+          }).id
+      );
+      markTimesheetEntriesAsSubmitted(ids);
+
+      const page = getCompletedTimesheetEntriesPage(2, 2);
+      expect(page.totalCount).toBe(3);
+      expect(page.entries.map((entry) => entry.date)).toEqual(["2025-01-03"]);
+    });
+
+    it("should total drafts per date, excluding submitted entries", () => {
+      const drafts = [
+        { date: "2025-01-15", hours: 2.5 },
+        { date: "2025-01-15", hours: 1.25 },
+        { date: "2025-01-10", hours: 4 },
+        { project: "Undated Project" },
+      ];
+      for (const [i, fields] of drafts.entries()) {
+        saveDraftTimesheetEntry(undefined, { project: "Draft Project", taskDescription: `Task ${i}`, ...fields });
+      }
+      const { id } = saveDraftTimesheetEntry(undefined, {
+        date: "2025-01-10",
+        hours: 8,
+        project: "Submitted Project",
+        taskDescription: "Submitted task",
+      });
+      markTimesheetEntriesAsSubmitted([id]);
+
+      expect(getDraftDateTotals()).toEqual([
+        { date: "2025-01-10", entryCount: 1, hours: 4 },
+        { date: "2025-01-15", entryCount: 2, hours: 3.75 },
+        { date: null, entryCount: 1, hours: 0 },
+      ]);
+    });
+  });
+
   describe("Performance", () => {
     it("should query pending entries efficiently", () => {
       // Insert many entries
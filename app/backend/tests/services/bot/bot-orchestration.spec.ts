@@ -28,6 +28,19 @@ describe('BotOrchestrator small logic', () => {
     expect(ok2).toBe(false);
   });
 
+  it('requires fields that the quarter form marks mandatory', async () => {
+    const bot = new BotOrchestrator(Cfg as typeof Cfg, dummyFormConfig, true, 'chromium');
+    bot.fieldDefinitions = Cfg.resolveFieldDefinitions({ tool: { optional: false } });
+    // @ts-ignore access private for test via any
+    const botAny = bot as any;
+    const base = { hours: 1, date: '01/01/2025' };
+
+    expect(botAny._find_missing_quarter_required_field({ ...base, project_code: 'OSC-BBB', tool: '' })).toBe('Tool');
+    expect(botAny._find_missing_quarter_required_field({ ...base, project_code: 'OSC-BBB', tool: 'Some Tool' })).toBeNull();
+    // Projects without a tool list never show the Tool field
+    expect(botAny._find_missing_quarter_required_field({ ...base, project_code: 'PTO/RTO', tool: '' })).toBeNull();
+  });
+
   it('project-specific tool locator resolution', async () => {
     const bot = new BotOrchestrator(Cfg as typeof Cfg, dummyFormConfig, true, 'chromium');
     // @ts-ignore private
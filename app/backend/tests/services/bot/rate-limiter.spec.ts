@@ -0,0 +1,141 @@
+/**
+ * @fileoverview Submission Rate Limiter Tests
+ *
+ * Covers throttle detection helpers, jittered backoff, pacing between
+ * submissions and the machine-wide pacing file.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import {
+  SubmissionRateLimiter,
+  computeThrottleBackoffMs,
+  isThrottleStatus,
+  parseRetryAfter,
+  sanitizeRateLimitSettings,
+  type RateLimitSettings
+} from '@sheetpilot/bot';
+
+const settings: RateLimitSettings = {
+  minIntervalMs: 1000,
+  jitterMs: 500,
+  maxThrottleRetries: 3,
+  backoffBaseMs: 2000,
+  backoffMaxMs: 10000,
+  slowSubmitThresholdMs: 5000
+};
+
+/** Limiter on a fake clock whose sleep advances the clock */
+function createLimiter(statePath: string | null, random = () => 0) {
+  let now = 1_000_000;
+  const sleeps: number[] = [];
+  const limiter = new SubmissionRateLimiter(
+    statePath,
+    settings,
+    () => now,
+    async (ms) => {
+      sleeps.push(ms);
+      now += ms;
+    },
+    random
+  );
+  return { limiter, sleeps, advance: (ms: number) => { now += ms; } };
+}
+
+describe('rate limiter helpers', () => {
+  it('treats 429 and 503 as throttling', () => {
+    expect(isThrottleStatus(429)).toBe(true);
+    expect(isThrottleStatus(503)).toBe(true);
+    expect(isThrottleStatus(500)).toBe(false);
+    expect(isThrottleStatus(200)).toBe(false);
+  });
+
+  it('parses Retry-After seconds and HTTP dates', () => {
+    const now = Date.parse('2025-10-03T17:00:00Z');
+    expect(parseRetryAfter('30', now)).toBe(30000);
+    expect(parseRetryAfter('Fri, 03 Oct 2025 17:00:12 GMT', now)).toBe(12000);
+    expect(parseRetryAfter('soon', now)).toBeNull();
+    expect(parseRetryAfter(undefined, now)).toBeNull();
+    expect(parseRetryAfter('100000', now)).toBe(5 * 60 * 1000);
+  });
+
+  it('backs off exponentially with jitter and honors Retry-After', () => {
+    expect(computeThrottleBackoffMs(0, settings, null, () => 0)).toBe(1000);
+    expect(computeThrottleBackoffMs(0, settings, null, () => 1)).toBe(2000);
+    expect(computeThrottleBackoffMs(2, settings, null, () => 1)).toBe(8000);
+    // Capped at backoffMaxMs
+    expect(computeThrottleBackoffMs(6, settings, null, () => 1)).toBe(10000);
+    expect(computeThrottleBackoffMs(0, settings, 30000, () => 0)).toBe(30000);
+  });
+
+  it('keeps only valid numeric settings', () => {
+    expect(sanitizeRateLimitSettings({ minIntervalMs: 250, jitterMs: -1, backoffMaxMs: 'x', other: 5 })).toEqual({
+      minIntervalMs: 250
+    });
+    expect(sanitizeRateLimitSettings(null)).toEqual({});
+  });
+});
+
+describe('SubmissionRateLimiter', () => {
+  const tempDirs: string[] = [];
+
+  afterEach(() => {
+    for (const dir of tempDirs.splice(0)) {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+
+  it('lets the first submission through and paces the next one', async () => {
+    const { limiter, sleeps } = createLimiter(null, () => 0.5);
+
+    expect(await limiter.acquire()).toBe(0);
+    expect(await limiter.acquire()).toBe(1250);
+    expect(sleeps).toEqual([1250]);
+  });
+
+  it('delays the next submission by the throttle backoff and widens the gap', async () => {
+    const { limiter, sleeps } = createLimiter(null);
+
+    await limiter.acquire();
+    const backoff = limiter.reportThrottle(0, { status: 429, retryAfterMs: null });
+    expect(backoff).toBe(1000);
+    await limiter.acquire();
+    // Gap after a throttle is doubled
+    await limiter.acquire();
+    expect(sleeps).toEqual([1000, 2000]);
+  });
+
+  it('narrows the gap again after normal submissions and widens it after slow ones', async () => {
+    const { limiter, sleeps, advance } = createLimiter(null);
+
+    limiter.reportThrottle(0, { status: 503, retryAfterMs: null });
+    limiter.reportCompleted(100);
+    advance(60000);
+    await limiter.acquire();
+    await limiter.acquire();
+    expect(sleeps).toEqual([1000]);
+
+    limiter.reportCompleted(6000);
+    advance(60000);
+    await limiter.acquire();
+    await limiter.acquire();
+    expect(sleeps).toEqual([1000, 1500]);
+  });
+
+  it('shares pacing between limiters through the state file', async () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-pacing-'));
+    tempDirs.push(dir);
+    const statePath = path.join(dir, 'pacing.json');
+    const first = createLimiter(statePath);
+    const second = createLimiter(statePath);
+
+    await first.limiter.acquire();
+    expect(await second.limiter.acquire()).toBe(1000);
+  });
+});
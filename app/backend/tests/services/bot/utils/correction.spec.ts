@@ -0,0 +1,58 @@
+import { describe, it, expect } from 'vitest';
+import {
+  applyCorrection,
+  applySubmissionKey,
+  formatCorrectionNote,
+  CORRECTION_LABEL
+} from '@sheetpilot/bot';
+
+describe('correction', () => {
+  const row = {
+    Project: 'FL-Carver Techs',
+    Date: '01/16/2025',
+    Hours: 3,
+    'Task Description': 'Calibrate sensors',
+    Status: ''
+  };
+
+  describe('formatCorrectionNote', () => {
+    it('should reference the original date', () => {
+      expect(formatCorrectionNote('01/15/2025')).toBe('CORRECTION of 01/15/2025');
+    });
+  });
+
+  describe('applyCorrection', () => {
+    it('should append the note to the description by default mode', () => {
+      const result = applyCorrection(row, '01/15/2025', 'description');
+      expect(result['Task Description']).toBe('Calibrate sensors (CORRECTION of 01/15/2025)');
+    });
+
+    it('should not stack notes when applied twice', () => {
+      const once = applyCorrection(row, '01/15/2025', 'description');
+      const twice = applyCorrection(once, '01/14/2025', 'description');
+      expect(twice['Task Description']).toBe('Calibrate sensors (CORRECTION of 01/14/2025)');
+    });
+
+    it('should set the correction column in field mode', () => {
+      const result = applyCorrection(row, '01/15/2025', 'field');
+      expect(result[CORRECTION_LABEL as keyof typeof result]).toBe('CORRECTION of 01/15/2025');
+      expect(result['Task Description']).toBe('Calibrate sensors');
+    });
+
+    it('should leave rows unchanged when off or not a correction', () => {
+      expect(applyCorrection(row, '01/15/2025', 'off')).toBe(row);
+      expect(applyCorrection(row, null, 'description')).toBe(row);
+    });
+
+    it('should keep the submission key as the description suffix', () => {
+      const result = applySubmissionKey(
+        applyCorrection(row, '01/15/2025', 'description'),
+        'SP-ABCDEFGHIJ',
+        'description'
+      );
+      expect(result['Task Description']).toBe(
+        'Calibrate sensors (CORRECTION of 01/15/2025) [SP-ABCDEFGHIJ]'
+      );
+    });
+  });
+});
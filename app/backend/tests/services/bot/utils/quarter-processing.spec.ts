@@ -318,6 +318,42 @@ describe('quarter-processing', () => {
       );
     });
 
+    it('should run quarters in date order and scale progress across them', async () => {
+      const q3Entries = [createEntry(2, '2025-09-30')];
+      const q4Entries = [createEntry(1, '2025-10-01')];
+      const q3Def = { ...mockQuarterDef, id: 'Q3-2025', name: 'Q3 2025', formId: 'q3-2025' };
+      const q4Def = { ...mockQuarterDef, id: 'Q4-2025', name: 'Q4 2025', formId: 'q4-2025' };
+
+      vi.mocked(getQuarterForDate).mockImplementation((date: string) =>
+        date.startsWith('2025-09') ? q3Def : q4Def
+      );
+      // Insertion order follows the pending list, not the dates
+      vi.mocked(groupEntriesByQuarter).mockReturnValue(
+        new Map([
+          ['Q4-2025', q4Entries],
+          ['Q3-2025', q3Entries]
+        ])
+      );
+      vi.mocked(createFormConfig).mockImplementation((_url: string, formId: string) => ({
+        ...mockFormConfig,
+        FORM_ID: formId
+      }));
+
+      const progressCallback = vi.fn();
+      const runBot = vi.fn().mockImplementation(
+        async (_rows, _email, _password, _formConfig, onProgress?: (percent: number, message: string) => void) => {
+          onProgress?.(50, 'Filling');
+          return { ok: true, submitted: [0], errors: [] };
+        }
+      );
+      const result = await processEntriesByQuarter([...q4Entries, ...q3Entries], createConfig({ runBot, progressCallback }));
+
+      expect(runBot.mock.calls.map((call) => call[3].FORM_ID)).toEqual(['q3-2025', 'q4-2025']);
+      expect(result.submittedIds).toEqual([2, 1]);
+      expect(progressCallback).toHaveBeenNthCalledWith(1, 25, 'Q3 2025 (form 1 of 2): Filling');
+      expect(progressCallback).toHaveBeenNthCalledWith(2, 75, 'Q4 2025 (form 2 of 2): Filling');
+    });
+
     it('should handle entries without IDs', async () => {
       const entries: TimesheetEntry[] = [
         { ...createEntry(1, '2025-01-15'), id: undefined },
@@ -341,6 +377,57 @@ describe('quarter-processing', () => {
       expect(result.submittedIds).toEqual([2]);
     });
 
+    it('should attach form run reports with rows mapped to entry IDs', async () => {
+      const entries = [createEntry(7, '2025-01-15'), createEntry(9, '2025-01-16')];
+      vi.mocked(groupEntriesByQuarter).mockReturnValue(new Map([['Q1-2025', entries]]));
+      const row = (rowIndex: number, outcome: 'submitted' | 'failed') => ({
+        rowIndex,
+        entryId: null,
+        outcome,
+        error: outcome === 'failed' ? 'boom' : null,
+        fillMs: 10,
+        submitMs: 20,
+        submitAttempts: 1,
+        throttleRetries: 0,
+        fields: {},
+        screenshot: null
+      });
+
+      const config = createConfig({
+        runBot: vi.fn().mockResolvedValue({
+          ok: true,
+          submitted: [0],
+          errors: [[1, 'boom']],
+          report: {
+            formId: 'q1-2025',
+            formUrl: 'https://app.smartsheet.com',
+            quarterId: null,
+            fillProfile: 'inject',
+            startedAt: '2025-01-20T00:00:00.000Z',
+            finishedAt: '2025-01-20T00:00:05.000Z',
+            durationMs: 5000,
+            loginMs: 1000,
+            titleCheck: 'passed',
+            rows: [row(0, 'submitted'), row(1, 'failed')],
+            error: null
+          }
+        })
+      });
+      const result = await processEntriesByQuarter(entries, config);
+
+      expect(result.formRuns).toHaveLength(1);
+      expect(result.formRuns![0]!.quarterId).toBe('Q1-2025');
+      expect(result.formRuns![0]!.rows.map((r) => [r.entryId, r.outcome])).toEqual([
+        [7, 'submitted'],
+        [9, 'failed']
+      ]);
+    });
+
+    it('should omit form runs when the bot returns no report', async () => {
+      const result = await processEntriesByQuarter([createEntry(1, '2025-01-15')], createConfig());
+      expect(result).not.toHaveProperty('formRuns');
+    });
+
     it('should log debug information about entries', async () => {
       const entries = [createEntry(1, '2025-01-15')];
 
@@ -0,0 +1,104 @@
+import { describe, it, expect, afterEach } from 'vitest';
+import {
+  applySubmissionKey,
+  appendSubmissionKeyToDescription,
+  configureSubmissionKeyMode,
+  extractSubmissionKeys,
+  getSubmissionKeyMode,
+  resolveSubmissionKeyMode,
+  SUBMISSION_KEY_LABEL,
+  SUBMISSION_KEY_MODE,
+  SUBMISSION_KEY_PATTERN
+} from '@sheetpilot/bot';
+
+describe('submission-key', () => {
+  const row = {
+    Project: 'FL-Carver Techs',
+    Date: '01/15/2025',
+    Hours: 2,
+    'Task Description': 'Calibrate sensors',
+    Status: ''
+  };
+
+  describe('appendSubmissionKeyToDescription', () => {
+    it('should append the key in brackets', () => {
+      expect(appendSubmissionKeyToDescription('Calibrate sensors', 'SP-ABCDEFGHIJ')).toBe(
+        'Calibrate sensors [SP-ABCDEFGHIJ]'
+      );
+    });
+
+    it('should replace an existing key rather than stacking them', () => {
+      expect(appendSubmissionKeyToDescription('Calibrate sensors [SP-ABCDEFGHIJ]', 'SP-KLMNOPQRST')).toBe(
+        'Calibrate sensors [SP-KLMNOPQRST]'
+      );
+    });
+  });
+
+  describe('applySubmissionKey', () => {
+    it('should suffix the task description in description mode', () => {
+      const result = applySubmissionKey(row, 'SP-ABCDEFGHIJ', 'description');
+      expect(result['Task Description']).toBe('Calibrate sensors [SP-ABCDEFGHIJ]');
+      expect(result).not.toHaveProperty(SUBMISSION_KEY_LABEL);
+    });
+
+    it('should add a separate column in field mode', () => {
+      const result = applySubmissionKey(row, 'SP-ABCDEFGHIJ', 'field');
+      expect(result['Task Description']).toBe('Calibrate sensors');
+      expect(result[SUBMISSION_KEY_LABEL as keyof typeof result]).toBe('SP-ABCDEFGHIJ');
+    });
+
+    it('should leave the row unchanged when off or without a key', () => {
+      expect(applySubmissionKey(row, 'SP-ABCDEFGHIJ', 'off')).toBe(row);
+      expect(applySubmissionKey(row, null, 'description')).toBe(row);
+    });
+  });
+
+  describe('submission key mode', () => {
+    afterEach(() => {
+      configureSubmissionKeyMode(SUBMISSION_KEY_MODE);
+    });
+
+    it('should append keys to the description by default without a field locator', () => {
+      expect(process.env['SUBMISSION_KEY_MODE']).toBeUndefined();
+      expect(process.env['SUBMISSION_KEY_FIELD_LOCATOR']).toBeUndefined();
+      expect(SUBMISSION_KEY_MODE).toBe('description');
+      expect(applySubmissionKey(row, 'SP-ABCDEFGHIJ')['Task Description']).toMatch(/ \[SP-ABCDEFGHIJ\]$/);
+    });
+
+    it('should resolve the setting, keeping the default for unknown values', () => {
+      expect(resolveSubmissionKeyMode('field')).toBe('field');
+      expect(resolveSubmissionKeyMode('suffix')).toBe(SUBMISSION_KEY_MODE);
+      expect(resolveSubmissionKeyMode(undefined)).toBe(SUBMISSION_KEY_MODE);
+    });
+
+    it('should apply the configured mode', () => {
+      configureSubmissionKeyMode('description');
+      expect(getSubmissionKeyMode()).toBe('description');
+      expect(applySubmissionKey(row, 'SP-ABCDEFGHIJ')['Task Description']).toBe(
+        'Calibrate sensors [SP-ABCDEFGHIJ]'
+      );
+    });
+  });
+
+  describe('extractSubmissionKeys', () => {
+    it('should find unique keys in sheet contents', () => {
+      const csv = [
+        'Date,Project,Task Description',
+        '01/15/2025,FL-Carver Techs,Calibrate sensors [SP-ABCDEFGHIJ]',
+        '01/16/2025,FL-Carver Techs,Review [SP-KLMNOPQRST]',
+        '01/16/2025,FL-Carver Techs,Review [SP-KLMNOPQRST]'
+      ].join('\n');
+      expect(extractSubmissionKeys(csv)).toEqual(['SP-ABCDEFGHIJ', 'SP-KLMNOPQRST']);
+    });
+
+    it('should ignore malformed keys', () => {
+      expect(extractSubmissionKeys('SP-abc SP-ABCDEFGHI SP-ABCDEFGH18')).toEqual([]);
+    });
+
+    it('should leave the exported pattern safe for repeated tests', () => {
+      expect(extractSubmissionKeys('[SP-ABCDEFGHIJ]')).toEqual(['SP-ABCDEFGHIJ']);
+      expect(SUBMISSION_KEY_PATTERN.test('[SP-ABCDEFGHIJ]')).toBe(true);
+      expect(SUBMISSION_KEY_PATTERN.test('[SP-ABCDEFGHIJ]')).toBe(true);
+    });
+  });
+});
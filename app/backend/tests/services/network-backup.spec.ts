@@ -0,0 +1,141 @@
+/**
+ * @fileoverview Network Backup Tests
+ *
+ * Tests encrypting backups, verifying the copy in the backup folder,
+ * keeping the manifest and pruning old backups, and when scheduled backups
+ * are due.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach, vi } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+
+vi.mock("../../src/services/notifications", () => ({
+  notifyUser: vi.fn(),
+}));
+
+vi.mock("../../src/services/settings/settings-store", () => ({
+  loadSettings: vi.fn(() => ({})),
+}));
+
+import { notifyUser } from "../../src/services/notifications";
+import {
+  decryptBackup,
+  encryptBackup,
+  getBackupFolder,
+  isNetworkBackupDue,
+  performNetworkBackup,
+  readBackupManifest,
+  resolveNetworkBackupSettings,
+  verifyBackupFile,
+} from "../../src/services/backup/network-backup";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+  insertDraftTimesheetEntry,
+} from "../../src/models";
+
+const KEY = "correct horse battery staple";
+const context = { reportProgress: vi.fn() };
+
+describe("Network Backups", () => {
+  let testDbPath: string;
+  let targetDir: string;
+
+  beforeEach(() => {
+    testDbPath = path.join(os.tmpdir(), `sheetpilot-network-backup-test-${Date.now()}.sqlite`);
+    targetDir = fs.mkdtempSync(path.join(os.tmpdir(), "sheetpilot-share-"));
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+    vi.mocked(notifyUser).mockClear();
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    fs.rmSync(testDbPath, { force: true });
+    fs.rmSync(targetDir, { recursive: true, force: true });
+  });
+
+  it("should only be configured when enabled with a folder", () => {
+    expect(resolveNetworkBackupSettings(undefined)).toBeNull();
+    expect(resolveNetworkBackupSettings({ target_dir: "\\\\fs01\\backups" })).toBeNull();
+    expect(resolveNetworkBackupSettings({ enabled: true, target_dir: " \\\\fs01\\backups ", keep: 0 })).toEqual({
+      targetDir: "\\\\fs01\\backups",
+      intervalHours: 24,
+      keep: 14,
+    });
+  });
+
+  it("should refuse to decrypt with the wrong key or a changed file", () => {
+    const encrypted = encryptBackup(Buffer.from("entries"), KEY);
+    expect(decryptBackup(encrypted, KEY).toString()).toBe("entries");
+    expect(() => decryptBackup(encrypted, "wrong key")).toThrow("wrong key or damaged file");
+    expect(() => decryptBackup(Buffer.from("plain sqlite"), KEY)).toThrow("Not a SheetPilot backup file");
+  });
+
+  it("should write a verified backup and list it in the manifest", async () => {
+    insertDraftTimesheetEntry({ date: "01/15/2025", hours: 1, project: "FL-Carver Techs", taskDescription: "Calibration" });
+    const settings = { targetDir, intervalHours: 24, keep: 2 };
+
+    const result = await performNetworkBackup(settings, context, new Date("2025-01-15T10:00:00Z"), KEY);
+
+    expect(path.dirname(result.filePath)).toBe(getBackupFolder(targetDir));
+    expect(result.entryCount).toBe(1);
+    expect(verifyBackupFile(result.filePath, KEY)).toMatchObject({ ok: true, entryCount: 1 });
+    const manifest = readBackupManifest(getBackupFolder(targetDir));
+    expect(manifest?.backups).toEqual([
+      expect.objectContaining({ file: path.basename(result.filePath), entryCount: 1, sizeBytes: result.sizeBytes }),
+    ]);
+  });
+
+  it("should delete backups beyond the number to keep", async () => {
+    const settings = { targetDir, intervalHours: 24, keep: 2 };
+    const first = await performNetworkBackup(settings, context, new Date("2025-01-13T10:00:00Z"), KEY);
+    await performNetworkBackup(settings, context, new Date("2025-01-14T10:00:00Z"), KEY);
+    const third = await performNetworkBackup(settings, context, new Date("2025-01-15T10:00:00Z"), KEY);
+
+    expect(third.pruned).toEqual([path.basename(first.filePath)]);
+    expect(fs.existsSync(first.filePath)).toBe(false);
+    expect(readBackupManifest(getBackupFolder(targetDir))?.backups).toHaveLength(2);
+  });
+
+  it("should fail and notify when no key is set", async () => {
+    await expect(
+      performNetworkBackup({ targetDir, intervalHours: 24, keep: 2 }, context, new Date(), undefined)
+    ).rejects.toThrow("SHEETPILOT_BACKUP_KEY");
+    expect(notifyUser).toHaveBeenCalledWith("Network backup failed", expect.stringContaining("SHEETPILOT_BACKUP_KEY"));
+    expect(fs.readdirSync(targetDir)).toEqual([]);
+  });
+
+  it("should be due after the interval, retrying failures after an hour", () => {
+    const now = new Date("2025-01-15T12:00:00Z");
+    expect(isNetworkBackupDue([], 24, now)).toBe(true);
+    expect(isNetworkBackupDue([{ status: "success", startedAt: "2025-01-15 01:00:00" }], 24, now)).toBe(false);
+    expect(isNetworkBackupDue([{ status: "success", startedAt: "2025-01-14 11:00:00" }], 24, now)).toBe(true);
+    expect(
+      isNetworkBackupDue(
+        [
+          { status: "failure", startedAt: "2025-01-15 11:30:00" },
+          { status: "success", startedAt: "2025-01-13 11:00:00" },
+        ],
+        24,
+        now
+      )
+    ).toBe(false);
+    expect(isNetworkBackupDue([{ status: "failure", startedAt: "2025-01-15 10:00:00" }], 24, now)).toBe(true);
+    expect(isNetworkBackupDue([{ status: "running", startedAt: "2025-01-14 10:00:00" }], 24, now)).toBe(false);
+  });
+});
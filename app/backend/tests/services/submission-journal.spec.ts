@@ -0,0 +1,100 @@
+/**
+ * @fileoverview Submission Journal Tests
+ *
+ * Tests that entries the bot submitted before a crash are marked Complete at
+ * startup from the journal, while the rest are left to interrupted-submission
+ * recovery.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "vitest";
+import * as fs from "fs";
+import * as path from "path";
+import * as os from "os";
+import {
+  setDbPath,
+  getDb,
+  ensureSchema,
+  runMigrations,
+  shutdownDatabase,
+  insertTimesheetEntry,
+  markTimesheetEntriesAsInProgress,
+  assignSubmissionKeys,
+  getInProgressTimesheetEntryIds,
+  getTimesheetEntriesByIds,
+} from "../../src/models";
+import {
+  readSubmissionJournal,
+  recoverFromSubmissionJournal,
+  writeSubmissionJournal,
+} from "../../src/services/timesheet/submission-journal";
+
+describe("Submission Journal", () => {
+  let testDbPath: string;
+  let journalPath: string;
+  let ids: number[];
+  let keys: Map<number, string>;
+
+  beforeEach(() => {
+    testDbPath = path.join(os.tmpdir(), `sheetpilot-journal-test-${Date.now()}.sqlite`);
+    journalPath = path.join(fs.mkdtempSync(path.join(os.tmpdir(), "sheetpilot-journal-")), "submission-journal.json");
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+
+    for (const task of ["Calibration", "Setup", "Cleanup"]) {
+      insertTimesheetEntry({ date: "2025-01-15", hours: 1, project: "FL-Carver Techs", taskDescription: task });
+    }
+    ids = (getDb().prepare("SELECT id FROM timesheet ORDER BY id").all() as Array<{ id: number }>).map((r) => r.id);
+    markTimesheetEntriesAsInProgress(ids);
+    keys = assignSubmissionKeys(ids).keys;
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    fs.rmSync(testDbPath, { force: true });
+    fs.rmSync(path.dirname(journalPath), { recursive: true, force: true });
+  });
+
+  it("should mark entries submitted before a crash Complete and leave the rest in progress", () => {
+    const [first, second, third] = ids as [number, number, number];
+    writeSubmissionJournal(
+      [
+        { id: first, submissionKey: keys.get(first) ?? null, outcome: "submitted" },
+        { id: second, submissionKey: keys.get(second) ?? null, outcome: "failed" },
+      ],
+      journalPath
+    );
+
+    const recovery = recoverFromSubmissionJournal(journalPath);
+
+    expect(recovery.completedIds).toEqual([first]);
+    expect(getTimesheetEntriesByIds([first])[0]?.status).toBe("Complete");
+    expect(getInProgressTimesheetEntryIds()).toEqual([second, third]);
+    expect(fs.existsSync(journalPath)).toBe(false);
+  });
+
+  it("should only match the submission key the entry was sent with", () => {
+    const [first] = ids as [number];
+    writeSubmissionJournal([{ id: first, submissionKey: "stale-key", outcome: "submitted" }], journalPath);
+
+    expect(recoverFromSubmissionJournal(journalPath).completedIds).toEqual([]);
+    expect(getInProgressTimesheetEntryIds()).toEqual(ids);
+  });
+
+  it("should ignore a missing or damaged journal", () => {
+    expect(recoverFromSubmissionJournal(journalPath).completedIds).toEqual([]);
+
+    fs.writeFileSync(journalPath, "{ truncated");
+    expect(readSubmissionJournal(journalPath)).toBeNull();
+    expect(recoverFromSubmissionJournal(journalPath).completedIds).toEqual([]);
+    expect(fs.existsSync(journalPath)).toBe(false);
+  });
+});
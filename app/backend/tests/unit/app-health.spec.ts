@@ -0,0 +1,92 @@
+/**
+ * @fileoverview App Health Tests
+ *
+ * Tests platform capability detection and the export mode it implies, and
+ * the save path used for downloads when no dialog can be shown.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => os.tmpdir()), getVersion: vi.fn(() => '1.6.0') },
+  dialog: { showSaveDialog: vi.fn() }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  appLogger: {
+    verbose: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+vi.mock('../../src/models', () => ({
+  getDb: vi.fn(() => ({ prepare: () => ({ get: () => ({ 1: 1 }) }) }))
+}));
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+vi.mock('../../src/services/health/clock-skew', () => ({
+  getClockSkewStatus: vi.fn(() => null)
+}));
+
+import {
+  detectPlatformCapabilities,
+  getAppHealth,
+  getFallbackDownloadPath
+} from '../../src/services/health/app-health';
+
+describe('app health', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-health-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('downloads when dialogs or the Downloads folder are available', () => {
+    const withDialogs = detectPlatformCapabilities({ fileDialogs: () => true, downloadsDirectory: () => path.join(dir, 'missing') });
+    expect(withDialogs).toMatchObject({ fileDialogs: true, downloadsWritable: false, exportMode: 'download' });
+
+    const withDownloads = detectPlatformCapabilities({ fileDialogs: () => false, downloadsDirectory: () => dir });
+    expect(withDownloads).toMatchObject({ fileDialogs: false, downloadsWritable: true, exportMode: 'download' });
+    expect(fs.readdirSync(dir)).toEqual([]);
+  });
+
+  it('returns exports as text when nothing can be saved', () => {
+    const capabilities = detectPlatformCapabilities({
+      fileDialogs: () => {
+        throw new Error('dialog module blocked');
+      },
+      downloadsDirectory: () => path.join(dir, 'missing')
+    });
+
+    expect(capabilities).toMatchObject({ fileDialogs: false, downloadsWritable: false, exportMode: 'string' });
+    expect(getAppHealth()).toMatchObject({
+      version: '1.6.0',
+      database: { connected: true },
+      capabilities: { exportMode: 'string' },
+      clock: null
+    });
+  });
+
+  it('picks an unused name in the Downloads folder', () => {
+    expect(getFallbackDownloadPath('export.csv', dir)).toBe(path.join(dir, 'export.csv'));
+
+    fs.writeFileSync(path.join(dir, 'export.csv'), '');
+    fs.writeFileSync(path.join(dir, 'export (1).csv'), '');
+    expect(getFallbackDownloadPath('export.csv', dir)).toBe(path.join(dir, 'export (2).csv'));
+    expect(getFallbackDownloadPath('../../evil.csv', dir)).toBe(path.join(dir, 'evil.csv'));
+  });
+});
@@ -0,0 +1,61 @@
+/**
+ * @fileoverview App Usage Lock Tests
+ *
+ * Tests taking and releasing the submission lock and the typed error
+ * draft-mutating handlers return while it is held.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, afterEach } from 'vitest';
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+import { SubmissionLockedError } from '@sheetpilot/shared/errors';
+import {
+  acquireSubmissionLock,
+  getAppLockError,
+  getAppLockState,
+  releaseSubmissionLock
+} from '../../src/services/timesheet/app-lock';
+
+describe('App Usage Lock', () => {
+  afterEach(() => {
+    releaseSubmissionLock('completed');
+  });
+
+  it('should start unlocked', () => {
+    expect(getAppLockState()).toEqual({ locked: false, reason: null, since: null, runId: null });
+    expect(getAppLockError('timesheet:saveDraft')).toBeNull();
+  });
+
+  it('should refuse changes with a typed error while a submission holds the lock', () => {
+    acquireSubmissionLock(42);
+
+    const state = getAppLockState();
+    expect(state).toMatchObject({ locked: true, reason: 'submission', runId: 42 });
+    expect(state.since).not.toBeNull();
+
+    const error = getAppLockError('timesheet:deleteDraft');
+    expect(error).toBeInstanceOf(SubmissionLockedError);
+    expect(error?.code).toBe('SUBMISSION_LOCKED');
+    expect(error?.context).toEqual({ action: 'timesheet:deleteDraft', runId: 42 });
+  });
+
+  it('should unlock on completion or recovery', () => {
+    acquireSubmissionLock(1);
+    releaseSubmissionLock('recovered');
+    expect(getAppLockState().locked).toBe(false);
+
+    // A second release (e.g. the run's own cleanup after a timeout) is harmless
+    releaseSubmissionLock('completed');
+    expect(getAppLockError('timesheet:markDirty')).toBeNull();
+  });
+});
@@ -0,0 +1,63 @@
+/**
+ * @fileoverview Charge Code Rule Tests
+ *
+ * Tests pattern/allow-list checks and per-profile rule resolution.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { checkChargeCode, resolveChargeCodeRule } from '../../src/logic/charge-code-rules';
+import { validateField } from '../../src/logic/timesheet-validation';
+
+const PATTERN = '^[A-Z]{2}\\d{4}-\\d{2}$';
+
+describe('Charge Code Rules', () => {
+  it('should accept codes matching the pattern', () => {
+    expect(checkChargeCode('AB1234-01', { pattern: PATTERN })).toBeNull();
+  });
+
+  it('should reject codes that do not match the pattern', () => {
+    expect(checkChargeCode('AB123-01', { pattern: PATTERN })).toContain('does not match');
+  });
+
+  it('should accept allow-listed codes that miss the pattern', () => {
+    expect(checkChargeCode('Admin', { pattern: PATTERN, allowList: ['Admin'] })).toBeNull();
+  });
+
+  it('should reject codes outside an allow-list without a pattern', () => {
+    expect(checkChargeCode('Repair', { allowList: ['Admin'] })).toContain('not in the allowed list');
+  });
+
+  it('should skip empty codes and missing rules', () => {
+    expect(checkChargeCode('', { pattern: PATTERN })).toBeNull();
+    expect(checkChargeCode(null, { pattern: PATTERN })).toBeNull();
+    expect(checkChargeCode('anything', null)).toBeNull();
+  });
+
+  it('should report an invalid pattern instead of throwing', () => {
+    expect(checkChargeCode('AB1234-01', { pattern: '[' })).toContain('pattern is invalid');
+  });
+
+  it('should resolve the active profile before the default', () => {
+    const options = {
+      chargeCodeRules: {
+        default: { allowList: ['Admin'] },
+        electron: { pattern: PATTERN },
+      },
+    };
+    expect(resolveChargeCodeRule(options, 'electron')).toEqual({ pattern: PATTERN });
+    expect(resolveChargeCodeRule(options, 'mock')).toEqual({ allowList: ['Admin'] });
+    expect(resolveChargeCodeRule(undefined, 'electron')).toBeNull();
+    expect(resolveChargeCodeRule({ chargeCodeRules: { electron: {} } }, 'electron')).toBeNull();
+  });
+
+  it('should apply the rule when validating the charge code field', () => {
+    const rows = [{ tool: 'COL 2' }];
+    const rule = { pattern: PATTERN };
+    expect(validateField('EPR1', 0, 'chargeCode', rows, [], ['EPR1'], rule)).toContain('does not match');
+    expect(validateField('EPR1', 0, 'chargeCode', rows, [], ['EPR1'])).toBeNull();
+  });
+});
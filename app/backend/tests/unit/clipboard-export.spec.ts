@@ -0,0 +1,52 @@
+/**
+ * @fileoverview Clipboard Export Tests
+ *
+ * Tests TSV, CSV and Markdown snippets built for the clipboard.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { buildClipboardSnippet } from '../../src/services/timesheet/clipboard-export';
+
+const entries = [
+  {
+    date: '01/15/2025',
+    hours: 2,
+    project: 'OSC-BBB',
+    tool: 'Meter',
+    detail_charge_code: 'EPR1',
+    task_description: 'Wiring\tphase | 2',
+    status: 'Complete'
+  },
+  {
+    date: '01/15/2025',
+    hours: 1.5,
+    project: 'FL-Carver',
+    task_description: 'Review'
+  }
+];
+
+describe('Clipboard Export', () => {
+  it('should build a TSV snippet without tabs inside cells', () => {
+    const lines = buildClipboardSnippet(entries, 'tsv').split('\n');
+    expect(lines).toHaveLength(3);
+    expect(lines[0]!.split('\t')).toHaveLength(6);
+    expect(lines[1]!.split('\t')).toHaveLength(6);
+    expect(lines[1]).toContain('Wiring phase | 2');
+  });
+
+  it('should build a Markdown table with escaped pipes and a total', () => {
+    const snippet = buildClipboardSnippet(entries, 'markdown');
+    expect(snippet.split('\n')[1]).toBe('| --- | --- | --- | --- | --- | --- |');
+    expect(snippet).toContain('Wiring phase \\| 2');
+    expect(snippet.endsWith('**Total: 3.50 hours**')).toBe(true);
+  });
+
+  it('should leave submission columns out of the default CSV', () => {
+    const header = buildClipboardSnippet(entries, 'csv').split('\n')[0];
+    expect(header).not.toContain('Status');
+  });
+});
@@ -0,0 +1,125 @@
+/**
+ * @fileoverview Clock Skew Check Tests
+ *
+ * Tests measuring clock skew from file times and a server's Date header,
+ * the warning sent when it is past the threshold, and the note added to
+ * date and session errors.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+vi.mock('../../src/models', () => ({
+  getDbPath: vi.fn(() => '')
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  emitClockSkew: vi.fn()
+}));
+
+vi.mock('../../src/services/notifications', () => ({
+  notifyUser: vi.fn()
+}));
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+import { emitClockSkew } from '../../src/routes/handlers/timesheet/main-window';
+import { notifyUser } from '../../src/services/notifications';
+import {
+  checkClockSkew,
+  getClockSkewStatus,
+  measureSkewFromFileTimes,
+  measureSkewFromHttpDate,
+  resetClockSkewStatus,
+  withClockSkewNote
+} from '../../src/services/health/clock-skew';
+
+const DAY = 24 * 60 * 60 * 1000;
+const NOW = Date.parse('2025-01-15T12:00:00Z');
+
+const serverAt = (date: string | null) =>
+  vi.fn(async () => new Response(null, { headers: date ? { date } : {} })) as unknown as typeof fetch;
+
+describe('clock skew check', () => {
+  let dir: string;
+  let file: string;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    resetClockSkewStatus();
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-clock-'));
+    file = path.join(dir, 'sheetpilot.sqlite');
+    fs.writeFileSync(file, '');
+    const lastWritten = new Date(NOW - 60 * 60 * 1000);
+    fs.utimesSync(file, lastWritten, lastWritten);
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('should see a clock that is behind from files dated in the future', () => {
+    const written = new Date(NOW + 21 * DAY);
+    fs.utimesSync(file, written, written);
+
+    expect(measureSkewFromFileTimes([file, path.join(dir, 'missing.log')], NOW)).toBe(-21 * DAY);
+    expect(measureSkewFromFileTimes([file], NOW + 30 * DAY)).toBeNull();
+  });
+
+  it('should compare against the Date header at the middle of the request', async () => {
+    const now = vi.fn().mockReturnValueOnce(NOW).mockReturnValueOnce(NOW + 2000);
+    const skew = await measureSkewFromHttpDate('https://intranet/', serverAt('Wed, 15 Jan 2025 11:00:00 GMT'), now);
+
+    expect(skew).toBe(60 * 60 * 1000 + 1000);
+    await expect(measureSkewFromHttpDate('https://intranet/', serverAt(null))).rejects.toThrow('no Date header');
+  });
+
+  it('should warn and annotate errors when the clock is off', async () => {
+    const written = new Date(NOW + 21 * DAY);
+    fs.utimesSync(file, written, written);
+
+    const status = await checkClockSkew({ settings: undefined, files: [file], now: () => NOW });
+
+    expect(status).toMatchObject({ skewMs: -21 * DAY, source: 'file-times', skewed: true });
+    expect(status.message).toBe('Your computer clock is about 3 weeks behind');
+    expect(emitClockSkew).toHaveBeenCalledWith(expect.objectContaining({ skewed: true }));
+    expect(notifyUser).toHaveBeenCalledTimes(1);
+    expect(withClockSkewNote('Session is invalid or expired.')).toBe(
+      'Session is invalid or expired. (Your computer clock is about 3 weeks behind; fix the clock and restart SheetPilot)'
+    );
+  });
+
+  it('should prefer the time server and fall back to files when it is unreachable', async () => {
+    const ahead = await checkClockSkew({
+      settings: { url: 'https://intranet/', threshold_minutes: 30 },
+      files: [file],
+      fetch: serverAt(new Date(NOW - 20 * 60 * 1000).toUTCString()),
+      now: () => NOW
+    });
+    expect(ahead).toMatchObject({ skewMs: 20 * 60 * 1000, source: 'http-date', skewed: false });
+    expect(withClockSkewNote('Date must be within allowed quarter range')).toBe(
+      'Date must be within allowed quarter range'
+    );
+
+    const offline = vi.fn(async () => {
+      throw new Error('getaddrinfo ENOTFOUND intranet');
+    }) as unknown as typeof fetch;
+    const fallback = await checkClockSkew({
+      settings: { url: 'https://intranet/' },
+      files: [file],
+      fetch: offline,
+      now: () => NOW + DAY
+    });
+    expect(fallback).toMatchObject({ skewMs: null, source: null, skewed: false });
+    expect(getClockSkewStatus()).toEqual(fallback);
+    expect(emitClockSkew).not.toHaveBeenCalled();
+  });
+});
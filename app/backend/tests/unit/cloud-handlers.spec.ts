@@ -0,0 +1,82 @@
+/**
+ * @fileoverview Cloud Handler Tests
+ *
+ * Tests that connecting, disconnecting and uploading to cloud storage are
+ * refused without a valid session.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const handlers = new Map<string, (...args: unknown[]) => Promise<unknown>>();
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => '/tmp') },
+  ipcMain: {
+    handle: vi.fn((channel: string, handler: (...args: unknown[]) => Promise<unknown>) => {
+      handlers.set(channel, handler);
+    })
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    error: vi.fn(),
+    audit: vi.fn()
+  }
+}));
+
+vi.mock('../../src/models', () => ({
+  getSubmittedTimesheetEntriesForExport: vi.fn(() => []),
+  validateSession: vi.fn(() => ({ valid: false }))
+}));
+
+vi.mock('../../src/services/cloud/graph-auth', () => ({
+  disconnectGraph: vi.fn(),
+  getGraphConnectionStatus: vi.fn(),
+  startGraphDeviceLogin: vi.fn(async () => ({ userCode: 'ABC' }))
+}));
+
+vi.mock('../../src/services/cloud/graph-upload', () => ({
+  enqueueCloudUpload: vi.fn(() => 'job-1')
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  isTrustedIpcSender: vi.fn(() => true)
+}));
+
+import { validateSession } from '../../src/models';
+import { disconnectGraph, startGraphDeviceLogin } from '../../src/services/cloud/graph-auth';
+import { registerCloudHandlers } from '../../src/routes/cloud-handlers';
+
+const TOKEN = '123e4567-e89b-12d3-a456-426614174000';
+const config = { clientId: 'client', tenantId: 'organizations', folderPath: 'Timesheets/Archive' };
+const invoke = (channel: string, ...args: unknown[]) => handlers.get(channel)!({}, ...args);
+
+describe('cloud handlers', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerCloudHandlers();
+  });
+
+  it('should refuse to connect, disconnect or upload without a valid session', async () => {
+    expect(await invoke('cloud:connect', TOKEN, config)).toMatchObject({ success: false });
+    expect(await invoke('cloud:connect', undefined, config)).toMatchObject({ success: false });
+    expect(await invoke('cloud:disconnect', TOKEN)).toMatchObject({ success: false });
+    expect(await invoke('cloud:uploadTimesheetExport', TOKEN, 'csv')).toMatchObject({ success: false });
+
+    expect(startGraphDeviceLogin).not.toHaveBeenCalled();
+    expect(disconnectGraph).not.toHaveBeenCalled();
+  });
+
+  it('should disconnect with a valid session', async () => {
+    vi.mocked(validateSession).mockReturnValue({ valid: true, email: 'user@example.com', isAdmin: false });
+
+    expect(await invoke('cloud:disconnect', TOKEN)).toEqual({ success: true });
+    expect(disconnectGraph).toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,52 @@
+/**
+ * @fileoverview Corrected Totals Export Tests
+ *
+ * Tests that timesheet exports report corrections and corrected totals.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildTimesheetCsv,
+  summarizeCorrectedTotals,
+  toExportValues
+} from '../../src/services/timesheet/csv-export';
+
+const original = {
+  id: 1,
+  date: '01/15/2025',
+  hours: 2,
+  project: 'OSC-BBB',
+  task_description: 'Wiring',
+  status: 'Complete',
+  submitted_at: '2025-01-15 17:00:00',
+  amends_id: null,
+  superseded_by: 2
+};
+const correction = { ...original, id: 2, hours: 3, amends_id: 1, superseded_by: null };
+
+describe('corrected totals', () => {
+  it('should count superseded originals as zero in the corrected total', () => {
+    expect(summarizeCorrectedTotals([original, correction])).toEqual({
+      submittedHours: 5,
+      correctedHours: 3,
+      correctionCount: 1
+    });
+  });
+
+  it('should describe both sides of a correction in the CSV', () => {
+    const lines = buildTimesheetCsv([original, correction]).split('\n');
+    expect(lines[0]).toContain('Correction,Corrected Hours');
+    expect(lines[1]).toMatch(/,"Superseded by #2",0\.00$/);
+    expect(lines[2]).toMatch(/,"Corrects #1",3\.00$/);
+  });
+
+  it('should keep hour columns numeric for spreadsheets', () => {
+    const values = toExportValues(correction);
+    expect(values[1]).toBe(3);
+    expect(values[9]).toBe(3);
+  });
+});
@@ -0,0 +1,80 @@
+/**
+ * @fileoverview Crash Report Tests
+ *
+ * Tests that crash reports capture the error, app version and log tail and
+ * can be listed back for export.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+import type { App } from 'electron';
+
+vi.mock('electron', () => ({
+  crashReporter: { start: vi.fn() }
+}));
+
+import {
+  CRASH_LOG_TAIL_LINES,
+  listCrashReports,
+  readLogTail,
+  writeCrashReport
+} from '../../src/bootstrap/crash-handlers/crash-reports';
+
+describe('crash reports', () => {
+  let userDataDir: string;
+  let app: App;
+
+  beforeEach(() => {
+    userDataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-crash-'));
+    app = {
+      getPath: vi.fn(() => userDataDir),
+      getVersion: vi.fn(() => '1.2.3')
+    } as unknown as App;
+  });
+
+  afterEach(() => {
+    fs.rmSync(userDataDir, { recursive: true, force: true });
+  });
+
+  it('should keep only the last lines of the newest log file', () => {
+    fs.writeFileSync(path.join(userDataDir, 'sheetpilot_2025-01-01.log'), 'old\n');
+    const lines = Array.from({ length: CRASH_LOG_TAIL_LINES + 50 }, (_, i) => `line ${i}`);
+    fs.writeFileSync(path.join(userDataDir, 'sheetpilot_2025-01-02.log'), lines.join('\n') + '\n');
+
+    const tail = readLogTail(userDataDir);
+    expect(tail).toHaveLength(CRASH_LOG_TAIL_LINES);
+    expect(tail[0]).toBe('line 50');
+    expect(tail[tail.length - 1]).toBe(`line ${CRASH_LOG_TAIL_LINES + 49}`);
+  });
+
+  it('should write a report with the error, version and log tail', () => {
+    fs.writeFileSync(path.join(userDataDir, 'sheetpilot_2025-01-02.log'), 'before crash\n');
+
+    const reportPath = writeCrashReport(app, { kind: 'uncaught-exception', error: new Error('Boom') });
+    expect(reportPath).not.toBeNull();
+
+    const [report] = listCrashReports(app);
+    expect(report).toMatchObject({
+      kind: 'uncaught-exception',
+      message: 'Boom',
+      appVersion: '1.2.3',
+      logTail: ['before crash']
+    });
+    expect(report?.stack).toContain('Boom');
+  });
+
+  it('should skip unreadable report files', () => {
+    writeCrashReport(app, { kind: 'renderer-gone', message: 'Renderer process gone: crashed' });
+    fs.writeFileSync(path.join(userDataDir, 'crash-reports', 'crash_partial.json'), '{"id":');
+
+    const reports = listCrashReports(app);
+    expect(reports).toHaveLength(1);
+    expect(reports[0]?.message).toBe('Renderer process gone: crashed');
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * @fileoverview Credential Access Monitor Tests
+ *
+ * Tests alerting on credential reads outside submission/validation flows
+ * and on bursts of reads.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('../../src/models', () => ({
+  setCredentialAccessListener: vi.fn()
+}));
+
+vi.mock('../../src/services/notifications', () => ({
+  notifyUser: vi.fn(() => true)
+}));
+
+import * as models from '../../src/models';
+import { notifyUser } from '../../src/services/notifications';
+import {
+  CREDENTIAL_READ_BURST_LIMIT,
+  handleCredentialAccess,
+  registerCredentialAccessMonitor
+} from '../../src/services/security/credential-access-monitor';
+import type { CredentialAccessEvent } from '../../src/models';
+
+const read = (overrides: Partial<CredentialAccessEvent> = {}): CredentialAccessEvent => ({
+  service: 'smartsheet',
+  caller: 'timesheet:submit',
+  purpose: 'submission',
+  accessedAt: '2025-01-15T10:00:00.000Z',
+  outcome: 'found',
+  ...overrides
+});
+
+describe('credential access monitor', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerCredentialAccessMonitor();
+  });
+
+  it('should subscribe to credential reads', () => {
+    expect(models.setCredentialAccessListener).toHaveBeenCalledWith(expect.any(Function));
+  });
+
+  it('should not alert for submission and validation reads', () => {
+    expect(handleCredentialAccess(read(), 1000)).toBeNull();
+    expect(handleCredentialAccess(read({ caller: 'auth:login', purpose: 'validation' }), 2000)).toBeNull();
+    expect(notifyUser).not.toHaveBeenCalled();
+  });
+
+  it('should alert when a read happens outside an expected flow', () => {
+    const reason = handleCredentialAccess(read({ caller: 'credential-service:get', purpose: 'other' }), 1000);
+
+    expect(reason).toContain('credential-service:get');
+    expect(notifyUser).toHaveBeenCalledTimes(1);
+  });
+
+  it('should alert on a burst of reads and recover once the window passes', () => {
+    for (let i = 0; i < CREDENTIAL_READ_BURST_LIMIT; i++) {
+      expect(handleCredentialAccess(read(), 1000 + i)).toBeNull();
+    }
+
+    expect(handleCredentialAccess(read(), 2000)).toContain('times in the last minute');
+    expect(handleCredentialAccess(read(), 120_000)).toBeNull();
+  });
+});
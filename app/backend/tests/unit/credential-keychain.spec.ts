@@ -0,0 +1,93 @@
+/**
+ * @fileoverview Credential Keychain Tests
+ *
+ * Tests the file-backed keychain store and falling back to SQLite only when
+ * OS encryption is unavailable.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => os.tmpdir()) },
+  safeStorage: {
+    isEncryptionAvailable: vi.fn(() => false),
+    encryptString: vi.fn(),
+    decryptString: vi.fn()
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  appLogger: {
+    verbose: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+const setCredentialKeychain = vi.fn();
+vi.mock('../../src/models', () => ({
+  setCredentialKeychain: (keychain: unknown) => setCredentialKeychain(keychain)
+}));
+
+import {
+  createFileKeychain,
+  registerCredentialKeychain,
+  type KeychainCipher
+} from '../../src/services/security/credential-keychain';
+
+/** Reversible stand-in for safeStorage */
+const fakeCipher: KeychainCipher = {
+  isAvailable: () => true,
+  encrypt: (plainText) => Buffer.from(plainText.split('').reverse().join(''), 'utf-8'),
+  decrypt: (encrypted) => encrypted.toString('utf-8').split('').reverse().join('')
+};
+
+describe('credential keychain', () => {
+  let dir: string;
+  let filePath: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-keychain-'));
+    filePath = path.join(dir, 'credential-keychain.json');
+    setCredentialKeychain.mockClear();
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('stores passwords encrypted and reads them back', () => {
+    const keychain = createFileKeychain(filePath, fakeCipher);
+
+    keychain.write('smartsheet', 'user@test.com', 'password123');
+
+    expect(fs.readFileSync(filePath, 'utf-8')).not.toContain('password123');
+    expect(keychain.read('smartsheet')).toEqual({ email: 'user@test.com', password: 'password123' });
+    expect(keychain.services()).toEqual(['smartsheet']);
+  });
+
+  it('removes entries and treats a missing file as empty', () => {
+    const keychain = createFileKeychain(filePath, fakeCipher);
+    expect(keychain.read('smartsheet')).toBeNull();
+    expect(keychain.services()).toEqual([]);
+
+    keychain.write('smartsheet', 'user@test.com', 'password123');
+    keychain.remove('smartsheet');
+
+    expect(keychain.read('smartsheet')).toBeNull();
+  });
+
+  it('only registers the keychain when OS encryption is available', () => {
+    expect(registerCredentialKeychain(filePath, { ...fakeCipher, isAvailable: () => false })).toBe(false);
+    expect(setCredentialKeychain).toHaveBeenLastCalledWith(null);
+
+    expect(registerCredentialKeychain(filePath, fakeCipher)).toBe(true);
+    expect(setCredentialKeychain).toHaveBeenLastCalledWith(expect.objectContaining({ read: expect.any(Function) }));
+  });
+});
@@ -0,0 +1,128 @@
+/**
+ * @fileoverview Deferred Startup Tests
+ *
+ * Tests startup phase timing and the background tasks run after the main
+ * window is shown (interrupted submission recovery and old log cleanup).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+vi.mock('../../../shared/logger', () => ({
+  appLogger: { info: vi.fn(), warn: vi.fn(), error: vi.fn(), verbose: vi.fn() }
+}));
+
+vi.mock('../../src/models', () => ({
+  getInProgressTimesheetEntryIds: vi.fn(() => [3, 4])
+}));
+
+vi.mock('../../src/services/shutdown/shutdown-coordinator', () => ({
+  recordInterruptedSubmission: vi.fn()
+}));
+
+vi.mock('../../src/services/timesheet/submission-workflow', () => ({
+  isTimesheetSubmissionInProgress: vi.fn(() => false)
+}));
+
+vi.mock('../../src/services/timesheet/submission-journal', () => ({
+  recoverFromSubmissionJournal: vi.fn()
+}));
+
+vi.mock('../../src/services/health/clock-skew', () => ({
+  checkClockSkew: vi.fn()
+}));
+
+vi.mock('../../src/services/logs/log-indexer', () => ({
+  isLogFileName: (file: string) => file.startsWith('sheetpilot_') && file.endsWith('.log')
+}));
+
+import { recordInterruptedSubmission } from '../../src/services/shutdown/shutdown-coordinator';
+import { isTimesheetSubmissionInProgress } from '../../src/services/timesheet/submission-workflow';
+import { recoverFromSubmissionJournal } from '../../src/services/timesheet/submission-journal';
+import { cleanupOldLogFiles, recoverInterruptedSubmission } from '../../src/services/startup/deferred-startup';
+import {
+  getStartupTimings,
+  markWindowShown,
+  resetStartupTimings,
+  timeStartupPhase
+} from '../../src/services/startup/startup-timings';
+
+describe('startup timings', () => {
+  beforeEach(() => {
+    resetStartupTimings();
+  });
+
+  it('should record sync, async and failed phases', async () => {
+    expect(timeStartupPhase('database', () => 42)).toBe(42);
+    await timeStartupPhase('plugins', async () => 'ready');
+    expect(() =>
+      timeStartupPhase('routes', () => {
+        throw new Error('boom');
+      })
+    ).toThrow('boom');
+    await expect(
+      timeStartupPhase('deferred:cleanup', () => Promise.reject(new Error('nope')), { deferred: true })
+    ).rejects.toThrow('nope');
+
+    const timings = getStartupTimings();
+    expect(timings.phases.map((phase) => [phase.name, phase.status, phase.deferred])).toEqual([
+      ['database', 'ok', false],
+      ['plugins', 'ok', false],
+      ['routes', 'failed', false],
+      ['deferred:cleanup', 'failed', true]
+    ]);
+    expect(timings.deferredCompletedMs).not.toBeNull();
+  });
+
+  it('should keep the first window shown time', () => {
+    markWindowShown();
+    const first = getStartupTimings().windowShownMs;
+    markWindowShown();
+    expect(getStartupTimings().windowShownMs).toBe(first);
+  });
+});
+
+describe('deferred startup tasks', () => {
+  let logDir: string;
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    logDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-startup-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(logDir, { recursive: true, force: true });
+  });
+
+  it('should recover entries left in progress by a previous run', () => {
+    expect(recoverInterruptedSubmission()).toEqual([3, 4]);
+    expect(recoverFromSubmissionJournal).toHaveBeenCalled();
+    expect(recordInterruptedSubmission).toHaveBeenCalledWith([3, 4]);
+  });
+
+  it('should leave entries alone while a submission is running', () => {
+    vi.mocked(isTimesheetSubmissionInProgress).mockReturnValueOnce(true);
+    expect(recoverInterruptedSubmission()).toEqual([]);
+    expect(recordInterruptedSubmission).not.toHaveBeenCalled();
+  });
+
+  it('should delete old log files but keep the newest', async () => {
+    const now = new Date('2025-03-01T00:00:00Z');
+    const old = new Date('2025-01-01T00:00:00Z');
+    for (const file of ['sheetpilot_2025-01-01.log', 'sheetpilot_2025-01-02.log', 'other.log']) {
+      const filePath = path.join(logDir, file);
+      fs.writeFileSync(filePath, 'x');
+      fs.utimesSync(filePath, old, old);
+    }
+
+    const deleted = await cleanupOldLogFiles(logDir, now, 30);
+    expect(deleted).toEqual(['sheetpilot_2025-01-01.log']);
+    expect(fs.readdirSync(logDir).sort()).toEqual(['other.log', 'sheetpilot_2025-01-02.log']);
+  });
+});
@@ -0,0 +1,101 @@
+/**
+ * @fileoverview Description Template Tests
+ *
+ * Tests filling in description template placeholders, rejecting unknown
+ * placeholders, and template storage in settings.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+let storedSettings: Record<string, unknown> = {};
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => structuredClone(storedSettings)),
+  saveSettings: vi.fn((settings: Record<string, unknown>) => {
+    storedSettings = structuredClone(settings);
+  })
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    warn: vi.fn()
+  }
+}));
+
+import {
+  findUnknownPlaceholders,
+  renderDescriptionTemplate
+} from '../../src/logic/description-templates';
+import {
+  deleteDescriptionTemplate,
+  listDescriptionTemplates,
+  renderDescription,
+  saveDescriptionTemplate
+} from '../../src/services/timesheet/description-templates';
+import { descriptionTemplateSchema } from '../../src/validation/ipc-schemas';
+
+const calibration = 'Calibrated {tool} for {project} ({ticket})';
+
+describe('Description Templates', () => {
+  beforeEach(() => {
+    storedSettings = {};
+  });
+
+  it('should fill in every placeholder', () => {
+    expect(
+      renderDescriptionTemplate(calibration, { tool: 'Mixer 2', project: 'OSC-BBB', ticket: 'WO-104233' })
+    ).toEqual({ description: 'Calibrated Mixer 2 for OSC-BBB (WO-104233)', missing: [] });
+    expect(renderDescriptionTemplate('{date}: PM on {tool}', { date: '01/15/2025', tool: 'Oven' }).description)
+      .toBe('01/15/2025: PM on Oven');
+  });
+
+  it('should drop empty brackets and separators left by missing values', () => {
+    expect(renderDescriptionTemplate(calibration, { tool: 'Mixer 2', project: 'OSC-BBB' })).toEqual({
+      description: 'Calibrated Mixer 2 for OSC-BBB',
+      missing: ['ticket']
+    });
+    expect(renderDescriptionTemplate('PM on {tool} - {ticket}', { tool: 'Oven', ticket: ' ' }).description)
+      .toBe('PM on Oven');
+    expect(renderDescriptionTemplate('{ticket}: PM on {tool}', { tool: 'Oven' }).description).toBe('PM on Oven');
+  });
+
+  it('should only accept known placeholders', () => {
+    expect(findUnknownPlaceholders('{project} {customer} {customer}')).toEqual(['customer']);
+    expect(descriptionTemplateSchema.safeParse({ name: 'Bad', template: 'For {customer}' }).success).toBe(false);
+    expect(descriptionTemplateSchema.safeParse({ name: 'Good', template: calibration }).success).toBe(true);
+  });
+
+  it('should create, update and delete templates by ID', () => {
+    const created = saveDescriptionTemplate({ name: 'Calibration', template: calibration });
+    expect(created.id).toBeTruthy();
+
+    const updated = saveDescriptionTemplate({ id: created.id, name: 'Calibration', template: 'Cal {tool}' });
+    expect(listDescriptionTemplates()).toEqual([updated]);
+    expect(renderDescription(created.id, { tool: 'Oven' }).description).toBe('Cal Oven');
+
+    expect(deleteDescriptionTemplate(created.id)).toBe(true);
+    expect(deleteDescriptionTemplate(created.id)).toBe(false);
+    expect(() => renderDescription(created.id, {})).toThrow('not found');
+  });
+
+  it('should reject duplicate names and unknown IDs', () => {
+    saveDescriptionTemplate({ name: 'Calibration', template: calibration });
+    expect(() => saveDescriptionTemplate({ name: 'calibration ', template: 'Cal' })).toThrow('already exists');
+    expect(() => saveDescriptionTemplate({ id: 'missing', name: 'Other', template: 'Cal' })).toThrow('not found');
+  });
+
+  it('should skip malformed templates in settings', () => {
+    storedSettings = {
+      description_templates: [
+        { id: 'pm', name: 'PM', template: 'PM on {tool}' },
+        { name: 'No ID', template: 'PM' },
+        { id: 'bad', name: 'Bad', template: '{customer}' }
+      ]
+    };
+    expect(listDescriptionTemplates().map((template) => template.id)).toEqual(['pm']);
+  });
+});
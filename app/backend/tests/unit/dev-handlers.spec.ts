@@ -0,0 +1,92 @@
+/**
+ * @fileoverview Dev Timesheet Handler Tests
+ *
+ * Tests that the dev-only handlers are refused whenever the app resolves to
+ * the prod environment, including packaged builds started without NODE_ENV.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const handlers = new Map<string, (...args: unknown[]) => Promise<unknown>>();
+
+vi.mock('electron', () => ({
+  ipcMain: {
+    handle: vi.fn((channel: string, handler: (...args: unknown[]) => Promise<unknown>) => {
+      handlers.set(channel, handler);
+    })
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn()
+  }
+}));
+
+vi.mock('@sheetpilot/bot', () => ({
+  runFillBenchmark: vi.fn()
+}));
+
+vi.mock('../../src/models', () => ({
+  getPendingTimesheetEntries: vi.fn(() => [{ id: 1 }, { id: 2 }]),
+  markTimesheetEntriesAsSubmitted: vi.fn()
+}));
+
+vi.mock('../../src/services/jobs/job-manager', () => ({
+  enqueueJob: vi.fn(() => 'job-1')
+}));
+
+vi.mock('../../src/services/health/environment', () => ({
+  getAppEnvironment: vi.fn(() => ({ environment: 'prod' }))
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  isTrustedIpcSender: vi.fn(() => true)
+}));
+
+import { markTimesheetEntriesAsSubmitted } from '../../src/models';
+import { enqueueJob } from '../../src/services/jobs/job-manager';
+import { getAppEnvironment } from '../../src/services/health/environment';
+import { registerTimesheetDevHandlers } from '../../src/routes/handlers/timesheet/dev';
+
+const invoke = (channel: string, ...args: unknown[]) => handlers.get(channel)!({}, ...args);
+
+describe('timesheet dev handlers', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerTimesheetDevHandlers();
+  });
+
+  it('should refuse in prod even when NODE_ENV is not production', async () => {
+    const nodeEnv = process.env['NODE_ENV'];
+    process.env['NODE_ENV'] = 'development';
+    try {
+      expect(await invoke('timesheet:devSimulateSuccess')).toEqual({
+        success: false,
+        error: 'Not available in production'
+      });
+      expect(await invoke('timesheet:devBenchmarkFill', { iterations: 1 })).toEqual({
+        success: false,
+        error: 'Not available in production'
+      });
+    } finally {
+      process.env['NODE_ENV'] = nodeEnv;
+    }
+    expect(markTimesheetEntriesAsSubmitted).not.toHaveBeenCalled();
+    expect(enqueueJob).not.toHaveBeenCalled();
+  });
+
+  it('should run outside prod', async () => {
+    vi.mocked(getAppEnvironment).mockReturnValue({ environment: 'dev' } as ReturnType<typeof getAppEnvironment>);
+
+    expect(await invoke('timesheet:devSimulateSuccess')).toEqual({ success: true, count: 2 });
+    expect(markTimesheetEntriesAsSubmitted).toHaveBeenCalledWith([1, 2]);
+  });
+});
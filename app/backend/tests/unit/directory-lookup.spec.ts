@@ -0,0 +1,101 @@
+/**
+ * @fileoverview Directory Email Lookup Tests
+ *
+ * Tests replacing login and credential emails with the directory sign-in
+ * name, and building the Graph filter that finds a user by any address.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, afterEach } from 'vitest';
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+vi.mock('../../src/services/cloud/graph-auth', () => ({
+  GRAPH_API_BASE: 'https://graph.microsoft.com/v1.0',
+  getGraphAccessToken: vi.fn()
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    warn: vi.fn(),
+    audit: vi.fn()
+  }
+}));
+
+import {
+  resolveDirectoryEmail,
+  setDirectoryProvider,
+  type DirectoryProvider
+} from '../../src/services/security/directory-lookup';
+import { buildDirectoryUserFilter } from '../../src/services/cloud/graph-directory';
+
+const directory = (users: Record<string, string>): DirectoryProvider => ({
+  name: 'fake',
+  findUser: vi.fn(async (email: string) => {
+    const upn = users[email.toLowerCase()];
+    return upn ? { userPrincipalName: upn, mail: null } : null;
+  })
+});
+
+describe('Directory Email Lookup', () => {
+  afterEach(() => {
+    setDirectoryProvider(null);
+  });
+
+  it('should use the email as entered when lookup is not configured', async () => {
+    const provider = directory({});
+    setDirectoryProvider(provider);
+
+    expect(await resolveDirectoryEmail('Jane.Doe@skywatertechnology.com', undefined)).toEqual({
+      email: 'Jane.Doe@skywatertechnology.com',
+      error: null
+    });
+    expect(provider.findUser).not.toHaveBeenCalled();
+  });
+
+  it('should replace an alias or miscased address with the sign-in name', async () => {
+    setDirectoryProvider(directory({ 'jdoe@skywatertechnology.com': 'jane.doe@skywatertechnology.com' }));
+
+    const result = await resolveDirectoryEmail('JDoe@SkyWaterTechnology.com', { provider: 'graph' });
+
+    expect(result).toEqual({ email: 'jane.doe@skywatertechnology.com', error: null });
+  });
+
+  it('should reject unknown addresses unless reject_unknown is false', async () => {
+    setDirectoryProvider(directory({}));
+
+    const rejected = await resolveDirectoryEmail('nobody@skywatertechnology.com', {});
+    const allowed = await resolveDirectoryEmail('nobody@skywatertechnology.com', { reject_unknown: false });
+
+    expect(rejected.email).toBe('nobody@skywatertechnology.com');
+    expect(rejected.error).toContain('nobody@skywatertechnology.com');
+    expect(allowed).toEqual({ email: 'nobody@skywatertechnology.com', error: null });
+  });
+
+  it('should fall back to the email as entered when the directory is unreachable', async () => {
+    setDirectoryProvider({
+      name: 'fake',
+      findUser: vi.fn(async () => {
+        throw new Error('OneDrive/SharePoint is not connected');
+      })
+    });
+
+    expect(await resolveDirectoryEmail('jdoe@skywatertechnology.com', {})).toEqual({
+      email: 'jdoe@skywatertechnology.com',
+      error: null
+    });
+  });
+
+  it('should match the sign-in name, mail and smtp aliases in the Graph filter', () => {
+    expect(buildDirectoryUserFilter(" o'brien@skywatertechnology.com ")).toBe(
+      "userPrincipalName eq 'o''brien@skywatertechnology.com'" +
+        " or mail eq 'o''brien@skywatertechnology.com'" +
+        " or proxyAddresses/any(p:p eq 'smtp:o''brien@skywatertechnology.com')"
+    );
+  });
+});
@@ -0,0 +1,132 @@
+/**
+ * @fileoverview Draft Auto-Save Tests
+ *
+ * Tests dirty row coalescing, the timed flush and flush result delivery.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+vi.mock('../../src/routes/handlers/timesheet/drafts.save', () => ({
+  saveDraftRow: vi.fn((row: { id?: number | null }) => ({
+    success: true,
+    changes: 1,
+    id: row.id ?? 100
+  }))
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    verbose: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+import { saveDraftRow } from '../../src/routes/handlers/timesheet/drafts.save';
+import {
+  DRAFT_FLUSH_DELAY_MS,
+  discardDirtyDrafts,
+  flushDirtyDrafts,
+  getDirtyDraftCount,
+  markDraftsDirty,
+  onDraftsSaved,
+  resetDraftAutoSave
+} from '../../src/routes/handlers/timesheet/drafts.autosave';
+import { acquireSubmissionLock, releaseSubmissionLock } from '../../src/services/timesheet/app-lock';
+
+describe('Draft Auto-Save', () => {
+  beforeEach(() => {
+    vi.useFakeTimers();
+    vi.clearAllMocks();
+    resetDraftAutoSave();
+  });
+
+  afterEach(() => {
+    resetDraftAutoSave();
+    vi.useRealTimers();
+  });
+
+  it('should save only the latest values for a row key', () => {
+    markDraftsDirty([{ rowKey: 'row-1', id: 5, hours: 1 }]);
+    markDraftsDirty([{ rowKey: 'row-1', id: 5, hours: 2 }, { rowKey: 'row-2', project: 'New' }]);
+    expect(getDirtyDraftCount()).toBe(2);
+
+    const results = flushDirtyDrafts();
+
+    expect(saveDraftRow).toHaveBeenCalledTimes(2);
+    expect(saveDraftRow).toHaveBeenCalledWith({ id: 5, hours: 2 });
+    expect(results.map((result) => result.rowKey)).toEqual(['row-1', 'row-2']);
+    expect(results[1]).toMatchObject({ success: true, id: 100 });
+    expect(getDirtyDraftCount()).toBe(0);
+  });
+
+  it('should flush on a timer and notify listeners', () => {
+    const listener = vi.fn();
+    onDraftsSaved(listener);
+    markDraftsDirty([{ rowKey: 'row-1', id: 5, hours: 1 }]);
+
+    vi.advanceTimersByTime(DRAFT_FLUSH_DELAY_MS - 1);
+    expect(saveDraftRow).not.toHaveBeenCalled();
+
+    vi.advanceTimersByTime(1);
+    expect(saveDraftRow).toHaveBeenCalledTimes(1);
+    expect(listener).toHaveBeenCalledWith([
+      expect.objectContaining({ rowKey: 'row-1', success: true, id: 5 })
+    ]);
+  });
+
+  it('should report a failed row without dropping the others', () => {
+    vi.mocked(saveDraftRow).mockImplementationOnce(() => {
+      throw new Error('database is locked');
+    });
+    markDraftsDirty([{ rowKey: 'a', id: 1 }, { rowKey: 'b', id: 2 }]);
+
+    const results = flushDirtyDrafts();
+
+    expect(results).toEqual([
+      { rowKey: 'a', success: false, error: 'database is locked' },
+      expect.objectContaining({ rowKey: 'b', success: true })
+    ]);
+  });
+
+  it('should not write back rows for a deleted entry', () => {
+    markDraftsDirty([{ rowKey: 'row-1', id: 7, hours: 1 }]);
+    discardDirtyDrafts(7);
+
+    expect(flushDirtyDrafts()).toEqual([]);
+    expect(saveDraftRow).not.toHaveBeenCalled();
+  });
+
+  it('should hold timed writes while a submission is running', () => {
+    acquireSubmissionLock(1);
+    try {
+      markDraftsDirty([{ rowKey: 'row-1', id: 5, hours: 1 }]);
+      vi.advanceTimersByTime(DRAFT_FLUSH_DELAY_MS * 3);
+      expect(saveDraftRow).not.toHaveBeenCalled();
+    } finally {
+      releaseSubmissionLock('completed');
+    }
+
+    vi.advanceTimersByTime(DRAFT_FLUSH_DELAY_MS);
+    expect(saveDraftRow).toHaveBeenCalledTimes(1);
+  });
+
+  it('should not write on an explicit flush while a submission is running', () => {
+    acquireSubmissionLock(1);
+    try {
+      markDraftsDirty([{ rowKey: 'row-1', id: 5, hours: 1 }]);
+      expect(flushDirtyDrafts()).toEqual([]);
+      expect(saveDraftRow).not.toHaveBeenCalled();
+      expect(getDirtyDraftCount()).toBe(1);
+    } finally {
+      releaseSubmissionLock('completed');
+    }
+
+    vi.advanceTimersByTime(DRAFT_FLUSH_DELAY_MS);
+    expect(saveDraftRow).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,56 @@
+/**
+ * @fileoverview Email Domain Policy Tests
+ *
+ * Tests matching emails against the allowed domain list and reading the
+ * list from settings.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    warn: vi.fn()
+  }
+}));
+
+import {
+  checkEmailDomain,
+  isEmailDomainAllowed,
+  resolveAllowedEmailDomains
+} from '../../src/services/security/email-domain-policy';
+
+describe('Email Domain Policy', () => {
+  it('should match domains case-insensitively', () => {
+    const allowed = ['skywatertechnology.com'];
+
+    expect(isEmailDomainAllowed('Jane.Doe@SkyWaterTechnology.com', allowed)).toBe(true);
+    expect(isEmailDomainAllowed('jane.doe@outlook.com', allowed)).toBe(false);
+    expect(isEmailDomainAllowed('jane.doe@mail.skywatertechnology.com', allowed)).toBe(false);
+  });
+
+  it('should accept any domain when no list is configured', () => {
+    expect(isEmailDomainAllowed('jane.doe@outlook.com', [])).toBe(true);
+    expect(checkEmailDomain('jane.doe@outlook.com')).toBeNull();
+  });
+
+  it('should normalize the stored list and ignore non-string entries', () => {
+    expect(resolveAllowedEmailDomains(['@SkyWaterTechnology.com ', 42, 'skywatertechnology.com', ''])).toEqual([
+      'skywatertechnology.com'
+    ]);
+    expect(resolveAllowedEmailDomains('skywatertechnology.com')).toEqual([]);
+  });
+
+  it('should explain which domains are allowed when rejecting an email', () => {
+    const error = checkEmailDomain('jane.doe@hotmail.com', ['skywatertechnology.com']);
+
+    expect(error).toContain('@skywatertechnology.com');
+  });
+});
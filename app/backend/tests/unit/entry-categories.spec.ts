@@ -0,0 +1,93 @@
+/**
+ * @fileoverview Entry Category Tests
+ *
+ * Tests the managed category list in settings, keeping entries in step when
+ * a category is renamed or deleted, and rejecting unknown categories.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+let storedSettings: Record<string, unknown> = {};
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => structuredClone(storedSettings)),
+  saveSettings: vi.fn((settings: Record<string, unknown>) => {
+    storedSettings = structuredClone(settings);
+  })
+}));
+
+vi.mock('../../src/models', () => ({
+  renameEntryCategory: vi.fn(() => 3),
+  clearEntryCategory: vi.fn(() => 2)
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    warn: vi.fn()
+  }
+}));
+
+import { clearEntryCategory, renameEntryCategory } from '../../src/models';
+import { checkEntryCategory, DEFAULT_ENTRY_CATEGORY_COLOR } from '../../src/logic/entry-categories';
+import {
+  deleteEntryCategory,
+  listEntryCategories,
+  saveEntryCategory
+} from '../../src/services/timesheet/entry-categories';
+import { entryCategorySchema } from '../../src/validation/ipc-schemas';
+
+describe('Entry Categories', () => {
+  beforeEach(() => {
+    storedSettings = {};
+    vi.mocked(renameEntryCategory).mockClear();
+    vi.mocked(clearEntryCategory).mockClear();
+  });
+
+  it('should create categories with a default color', () => {
+    const { category } = saveEntryCategory({ name: 'Production support' });
+    expect(category).toEqual({ id: category.id, name: 'Production support', color: DEFAULT_ENTRY_CATEGORY_COLOR });
+    expect(listEntryCategories()).toEqual([category]);
+    expect(entryCategorySchema.safeParse({ name: 'Project work', color: 'blue' }).success).toBe(false);
+  });
+
+  it('should rename the category on entries when its name changes', () => {
+    const { category } = saveEntryCategory({ name: 'Support', color: '#d62728' });
+
+    const recolored = saveEntryCategory({ id: category.id, name: 'Support', color: '#1f77b4' });
+    expect(recolored.renamedEntries).toBe(0);
+    expect(renameEntryCategory).not.toHaveBeenCalled();
+
+    const renamed = saveEntryCategory({ id: category.id, name: 'Production support' });
+    expect(renameEntryCategory).toHaveBeenCalledWith('Support', 'Production support');
+    expect(renamed).toEqual({
+      category: { id: category.id, name: 'Production support', color: '#1f77b4' },
+      renamedEntries: 3
+    });
+  });
+
+  it('should clear a deleted category from entries', () => {
+    const { category } = saveEntryCategory({ name: 'Project work' });
+    expect(deleteEntryCategory(category.id)).toBe(2);
+    expect(clearEntryCategory).toHaveBeenCalledWith('Project work');
+    expect(listEntryCategories()).toEqual([]);
+    expect(deleteEntryCategory(category.id)).toBeNull();
+  });
+
+  it('should reject duplicate names and unknown IDs', () => {
+    saveEntryCategory({ name: 'Project work' });
+    expect(() => saveEntryCategory({ name: 'project work ' })).toThrow('already exists');
+    expect(() => saveEntryCategory({ id: 'missing', name: 'Other' })).toThrow('not found');
+  });
+
+  it('should only accept categories from the list on entries', () => {
+    const categories = [{ id: 'a', name: 'Project work', color: '#1f77b4' }];
+    expect(checkEntryCategory(null, categories)).toBeNull();
+    expect(checkEntryCategory('Project work', categories)).toBeNull();
+    expect(checkEntryCategory('Training', categories)).toContain('Use one of: Project work');
+    expect(checkEntryCategory('Training', [])).toContain('Add it to the category list first');
+  });
+});
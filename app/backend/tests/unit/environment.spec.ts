@@ -0,0 +1,78 @@
+/**
+ * @fileoverview App Environment Tests
+ *
+ * Tests resolving dev/test/prod from the build and profile, refusing mock
+ * routing in prod and tagging non-prod responses.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('electron', () => ({
+  app: { isPackaged: false }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  appLogger: {
+    warn: vi.fn()
+  }
+}));
+
+import {
+  getMockRoutingError,
+  resolveAppEnvironment,
+  tagEnvironment
+} from '../../src/services/health/environment';
+
+describe('app environment', () => {
+  it('treats packaged builds as prod whatever the profile says', () => {
+    expect(resolveAppEnvironment({ isPackaged: true, env: { SHEETPILOT_PROFILE: 'dev', NODE_ENV: 'test' } })).toEqual({
+      environment: 'prod',
+      source: 'packaged',
+      mockRoutingAllowed: false,
+      label: null
+    });
+  });
+
+  it('uses the profile for unpackaged runs', () => {
+    expect(resolveAppEnvironment({ isPackaged: false, env: { SHEETPILOT_PROFILE: ' Test ' } })).toMatchObject({
+      environment: 'test',
+      source: 'profile',
+      mockRoutingAllowed: true,
+      label: 'TEST'
+    });
+    expect(resolveAppEnvironment({ isPackaged: false, env: { SHEETPILOT_PROFILE: 'prod' } })).toMatchObject({
+      environment: 'prod',
+      mockRoutingAllowed: false
+    });
+  });
+
+  it('falls back to NODE_ENV and then dev', () => {
+    expect(resolveAppEnvironment({ isPackaged: false, env: { SHEETPILOT_PROFILE: 'staging', NODE_ENV: 'test' } }).environment).toBe('test');
+    expect(resolveAppEnvironment({ isPackaged: false, env: { NODE_ENV: 'production' } }).environment).toBe('prod');
+    expect(resolveAppEnvironment({ isPackaged: false, env: {} })).toMatchObject({
+      environment: 'dev',
+      source: 'default',
+      label: 'DEV'
+    });
+  });
+
+  it('refuses mock routing only in prod', () => {
+    const prod = resolveAppEnvironment({ isPackaged: true, env: {} });
+    const dev = resolveAppEnvironment({ isPackaged: false, env: {} });
+    expect(getMockRoutingError(true, prod)).toContain('disabled in production');
+    expect(getMockRoutingError(false, prod)).toBeNull();
+    expect(getMockRoutingError(undefined, prod)).toBeNull();
+    expect(getMockRoutingError(true, dev)).toBeNull();
+  });
+
+  it('tags responses outside prod', () => {
+    const prod = resolveAppEnvironment({ isPackaged: true, env: {} });
+    const dev = resolveAppEnvironment({ isPackaged: false, env: {} });
+    expect(tagEnvironment({ success: true }, prod)).toEqual({ success: true });
+    expect(tagEnvironment({ success: true }, dev)).toEqual({ success: true, environment: 'dev' });
+  });
+});
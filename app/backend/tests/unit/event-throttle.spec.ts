@@ -0,0 +1,125 @@
+/**
+ * @fileoverview Renderer Event Throttle Tests
+ *
+ * Tests pacing events to the configured rate and coalescing waiting
+ * progress updates.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    verbose: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+import {
+  configureEventThrottle,
+  DEFAULT_EVENT_THROTTLE,
+  EventThrottle,
+  sanitizeEventThrottleSettings
+} from '../../src/services/events/event-throttle';
+
+describe('Renderer Event Throttle', () => {
+  let sent: Array<[string, unknown]>;
+  let throttle: EventThrottle;
+
+  beforeEach(() => {
+    vi.useFakeTimers();
+    configureEventThrottle({ maxEventsPerSecond: 2 });
+    sent = [];
+    throttle = new EventThrottle((channel, payload) => sent.push([channel, payload]));
+  });
+
+  afterEach(() => {
+    throttle.clear();
+    configureEventThrottle({});
+    vi.useRealTimers();
+  });
+
+  it('should send a burst up to the rate and hold the rest', () => {
+    throttle.emit('a', 1);
+    throttle.emit('b', 2);
+    throttle.emit('c', 3);
+
+    expect(sent).toEqual([['a', 1], ['b', 2]]);
+    expect(throttle.getPendingCount()).toBe(1);
+
+    vi.advanceTimersByTime(500);
+    expect(sent).toEqual([['a', 1], ['b', 2], ['c', 3]]);
+  });
+
+  it('should collapse waiting progress updates to the latest', () => {
+    for (let percent = 0; percent <= 100; percent += 10) {
+      throttle.emit('timesheet:progress', { percent }, { coalesceKey: 'progress' });
+    }
+
+    vi.advanceTimersByTime(1000);
+    expect(sent).toEqual([
+      ['timesheet:progress', { percent: 0 }],
+      ['timesheet:progress', { percent: 10 }],
+      ['timesheet:progress', { percent: 100 }]
+    ]);
+  });
+
+  it('should merge waiting payloads when asked and never drop uncoalesced events', () => {
+    const merge = (pending: number[], next: number[]) => [...pending, ...next];
+    throttle.emit('a', 'first');
+    throttle.emit('b', 'second');
+    throttle.emit('saved', [1], { coalesceKey: 'saved', merge });
+    throttle.emit('revoked', 'now');
+    throttle.emit('saved', [2], { coalesceKey: 'saved', merge });
+
+    vi.advanceTimersByTime(2000);
+    expect(sent).toEqual([
+      ['a', 'first'],
+      ['b', 'second'],
+      ['saved', [1, 2]],
+      ['revoked', 'now']
+    ]);
+  });
+
+  it('should keep coalesce keys apart', () => {
+    throttle.emit('x', 'fill');
+    throttle.emit('x', 'fill');
+    throttle.emit('job:progress', { id: 'a', percent: 10 }, { coalesceKey: 'job:a' });
+    throttle.emit('job:progress', { id: 'b', percent: 10 }, { coalesceKey: 'job:b' });
+    throttle.emit('job:progress', { id: 'a', percent: 20 }, { coalesceKey: 'job:a' });
+
+    vi.advanceTimersByTime(1000);
+    expect(sent.slice(2)).toEqual([
+      ['job:progress', { id: 'a', percent: 20 }],
+      ['job:progress', { id: 'b', percent: 10 }]
+    ]);
+  });
+
+  it('should send everything immediately when the limit is 0', () => {
+    configureEventThrottle({ maxEventsPerSecond: 0 });
+    for (let i = 0; i < 50; i++) throttle.emit('tick', i);
+
+    expect(sent).toHaveLength(50);
+  });
+
+  it('should still send at rates below one a second', () => {
+    configureEventThrottle({ maxEventsPerSecond: 0.5 });
+    throttle.emit('a', 1);
+    throttle.emit('b', 2);
+
+    expect(sent).toEqual([['a', 1]]);
+    vi.advanceTimersByTime(2000);
+    expect(sent).toEqual([['a', 1], ['b', 2]]);
+  });
+
+  it('should keep only usable stored settings', () => {
+    expect(sanitizeEventThrottleSettings({ maxEventsPerSecond: 5 })).toEqual({ maxEventsPerSecond: 5 });
+    expect(sanitizeEventThrottleSettings({ maxEventsPerSecond: 1e9 })).toEqual({ maxEventsPerSecond: 1000 });
+    expect(sanitizeEventThrottleSettings({ maxEventsPerSecond: -1 })).toEqual({});
+    expect(sanitizeEventThrottleSettings('fast')).toEqual({});
+    expect(configureEventThrottle({})).toEqual(DEFAULT_EVENT_THROTTLE);
+  });
+});
@@ -0,0 +1,67 @@
+/**
+ * @fileoverview Export Schedule Tests
+ *
+ * Tests for monthly run-time calculation and date range selection used by
+ * scheduled exports.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  computeNextMonthlyRunAt,
+  getPreviousMonthRange,
+  filterEntriesByDateRange
+} from '../../src/services/scheduler/export-schedule';
+
+describe('Export Schedule', () => {
+  describe('computeNextMonthlyRunAt', () => {
+    it('should schedule later in the same month when the day has not passed', () => {
+      const next = computeNextMonthlyRunAt(15, new Date(2025, 2, 10, 12, 0));
+      expect(next.getFullYear()).toBe(2025);
+      expect(next.getMonth()).toBe(2);
+      expect(next.getDate()).toBe(15);
+    });
+
+    it('should roll over to the next month once the run time has passed', () => {
+      const next = computeNextMonthlyRunAt(1, new Date(2025, 11, 1, 9, 0));
+      expect(next.getFullYear()).toBe(2026);
+      expect(next.getMonth()).toBe(0);
+      expect(next.getDate()).toBe(1);
+    });
+  });
+
+  describe('getPreviousMonthRange', () => {
+    it('should cover the whole previous month', () => {
+      expect(getPreviousMonthRange(new Date(2025, 2, 1))).toEqual({
+        start: '2025-02-01',
+        end: '2025-02-28',
+        label: '2025-02'
+      });
+    });
+
+    it('should wrap across the year boundary', () => {
+      expect(getPreviousMonthRange(new Date(2025, 0, 5))).toEqual({
+        start: '2024-12-01',
+        end: '2024-12-31',
+        label: '2024-12'
+      });
+    });
+  });
+
+  describe('filterEntriesByDateRange', () => {
+    it('should keep entries inside the range regardless of date format', () => {
+      const entries = [
+        { date: '01/31/2025' },
+        { date: '02/01/2025' },
+        { date: '2025-02-28' },
+        { date: '03/01/2025' },
+        { date: 'not-a-date' }
+      ];
+      const result = filterEntriesByDateRange(entries, '2025-02-01', '2025-02-28');
+      expect(result.map((e) => e.date)).toEqual(['02/01/2025', '2025-02-28']);
+    });
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * @fileoverview Export Template Tests
+ *
+ * Tests templated column selection, ordering and header names for exports,
+ * and template storage in settings.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+let storedSettings: Record<string, unknown> = {};
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => structuredClone(storedSettings)),
+  saveSettings: vi.fn((settings: Record<string, unknown>) => {
+    storedSettings = structuredClone(settings);
+  })
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    warn: vi.fn()
+  }
+}));
+
+import {
+  buildTimesheetCsv,
+  getExportHeaders,
+  toExportValues,
+  TIMESHEET_EXPORT_HEADERS,
+  type ExportTemplate
+} from '../../src/services/timesheet/csv-export';
+import {
+  deleteExportTemplate,
+  listExportTemplates,
+  resolveExportTemplate,
+  saveExportTemplate
+} from '../../src/services/timesheet/export-templates';
+import { exportTemplateSchema } from '../../src/validation/ipc-schemas';
+
+const entry = {
+  date: '01/15/2025',
+  hours: 7.5,
+  project: 'OSC-BBB',
+  task_description: 'Wiring, "phase 2"',
+  status: 'Complete',
+  submitted_at: '2025-01-15 17:00:00'
+};
+
+const payroll: ExportTemplate = {
+  name: 'Payroll',
+  columns: [
+    { id: 'hours', header: 'Total Hours' },
+    { id: 'date', header: 'Work Date' },
+    { id: 'taskDescription' }
+  ]
+};
+
+describe('Export Templates', () => {
+  beforeEach(() => {
+    storedSettings = {};
+  });
+
+  it('should export the template columns in order with renamed headers', () => {
+    const lines = buildTimesheetCsv([entry], payroll).split('\n');
+    expect(lines[0]).toBe('Total Hours,Work Date,Task Description');
+    expect(lines[1]).toBe('7.50,01/15/2025,"Wiring, ""phase 2"""');
+    expect(toExportValues(entry, payroll)).toEqual([7.5, '01/15/2025', 'Wiring, "phase 2"']);
+  });
+
+  it('should keep the default columns without a template', () => {
+    expect(getExportHeaders()).toEqual(TIMESHEET_EXPORT_HEADERS);
+    expect(buildTimesheetCsv([entry]).split('\n')[0]).toContain('Status');
+  });
+
+  it('should export the external reference for cross-referencing', () => {
+    const template: ExportTemplate = { name: 'MES', columns: [{ id: 'externalRef' }, { id: 'hours' }] };
+    const csv = buildTimesheetCsv([{ ...entry, external_ref: 'WO-104233' }, entry], template);
+    expect(csv.split('\n')).toEqual(['External Ref,Hours', '"WO-104233",7.50', '"",7.50']);
+  });
+
+  it('should export the entry category', () => {
+    const template: ExportTemplate = { name: 'Categories', columns: [{ id: 'category' }, { id: 'hours' }] };
+    const csv = buildTimesheetCsv([{ ...entry, category: 'Production support' }, entry], template);
+    expect(csv.split('\n')).toEqual(['Category,Hours', '"Production support",7.50', '"",7.50']);
+  });
+
+  it('should quote renamed headers that need it', () => {
+    const template: ExportTemplate = { name: 'Quoted', columns: [{ id: 'date', header: 'Date, local' }] };
+    expect(buildTimesheetCsv([], template)).toBe('"Date, local"');
+  });
+
+  it('should reject templates with unknown or repeated columns', () => {
+    expect(exportTemplateSchema.safeParse({ name: 'Bad', columns: [{ id: 'salary' }] }).success).toBe(false);
+    expect(
+      exportTemplateSchema.safeParse({ name: 'Twice', columns: [{ id: 'date' }, { id: 'date' }] }).success
+    ).toBe(false);
+  });
+
+  it('should store, replace and delete templates by name', () => {
+    saveExportTemplate(payroll);
+    saveExportTemplate({ ...payroll, name: 'payroll', columns: [{ id: 'project' }] });
+
+    expect(listExportTemplates()).toEqual([{ name: 'payroll', columns: [{ id: 'project' }] }]);
+    expect(resolveExportTemplate('PAYROLL')?.columns).toEqual([{ id: 'project' }]);
+    expect(resolveExportTemplate(undefined)).toBeUndefined();
+
+    expect(deleteExportTemplate('Payroll')).toBe(true);
+    expect(deleteExportTemplate('Payroll')).toBe(false);
+    expect(() => resolveExportTemplate('Payroll')).toThrow('Export template "Payroll" not found');
+  });
+
+  it('should skip malformed templates in settings', () => {
+    storedSettings = { export_templates: [payroll, { name: 'Broken', columns: [] }] };
+    expect(listExportTemplates().map((template) => template.name)).toEqual(['Payroll']);
+  });
+});
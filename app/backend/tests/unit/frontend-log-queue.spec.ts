@@ -0,0 +1,124 @@
+/**
+ * @fileoverview Frontend Log Queue Tests
+ *
+ * Tests that frontend log lines are held while logging is not ready, survive
+ * a restart on disk, and flush in order with their original timestamps.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    error: vi.fn(),
+    warn: vi.fn(),
+    info: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn()
+  }
+}));
+
+import { ipcLogger } from '@sheetpilot/shared/logger';
+import {
+  FRONTEND_LOG_QUEUE_FILE,
+  MAX_QUEUED_FRONTEND_LOGS,
+  holdFrontendLogs,
+  logFrontendEntry,
+  releaseFrontendLogs
+} from '../../src/services/logs/frontend-log-queue';
+
+describe('Frontend Log Queue', () => {
+  let userDataDir = '';
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    userDataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-frontend-logs-'));
+  });
+
+  afterEach(() => {
+    releaseFrontendLogs();
+    fs.rmSync(userDataDir, { recursive: true, force: true });
+  });
+
+  it('should log straight through when nothing is held', () => {
+    logFrontendEntry('warn', 'Slow render', { ms: 120 });
+
+    expect(ipcLogger.warn).toHaveBeenCalledWith('Slow render', { ms: 120 });
+  });
+
+  it('should hold lines until released and flush them in order with original timestamps', () => {
+    holdFrontendLogs(userDataDir);
+    logFrontendEntry('info', 'First', { step: 1 }, '2025-01-01T00:00:00.000Z');
+    logFrontendEntry('error', 'Second', 'raw detail', '2025-01-01T00:00:01.000Z');
+
+    expect(ipcLogger.info).not.toHaveBeenCalled();
+    expect(ipcLogger.error).not.toHaveBeenCalled();
+
+    expect(releaseFrontendLogs()).toBe(2);
+
+    expect(ipcLogger.info).toHaveBeenCalledWith('First', {
+      step: 1,
+      originalTimestamp: '2025-01-01T00:00:00.000Z',
+      queued: true
+    });
+    expect(ipcLogger.error).toHaveBeenCalledWith('Second', {
+      data: 'raw detail',
+      originalTimestamp: '2025-01-01T00:00:01.000Z',
+      queued: true
+    });
+    const infoOrder = vi.mocked(ipcLogger.info).mock.invocationCallOrder[0]!;
+    const errorOrder = vi.mocked(ipcLogger.error).mock.invocationCallOrder[0]!;
+    expect(infoOrder).toBeLessThan(errorOrder);
+    expect(fs.existsSync(path.join(userDataDir, FRONTEND_LOG_QUEUE_FILE))).toBe(false);
+  });
+
+  it('should flush lines persisted by a run that never got logging up', () => {
+    holdFrontendLogs(userDataDir);
+    logFrontendEntry('warn', 'Before crash', undefined, '2025-01-01T00:00:00.000Z');
+
+    // Simulate a restart: a new hold reloads what is on disk
+    holdFrontendLogs(userDataDir);
+    logFrontendEntry('info', 'After restart', undefined, '2025-01-01T00:05:00.000Z');
+    releaseFrontendLogs();
+
+    expect(ipcLogger.warn).toHaveBeenCalledWith('Before crash', {
+      originalTimestamp: '2025-01-01T00:00:00.000Z',
+      queued: true
+    });
+    expect(ipcLogger.info).toHaveBeenCalledWith('After restart', {
+      originalTimestamp: '2025-01-01T00:05:00.000Z',
+      queued: true
+    });
+    expect(ipcLogger.warn).toHaveBeenCalledTimes(1);
+  });
+
+  it('should ignore corrupt lines in the queue file', () => {
+    fs.writeFileSync(
+      path.join(userDataDir, FRONTEND_LOG_QUEUE_FILE),
+      '{"level":"info","message":"Kept","timestamp":"2025-01-01T00:00:00.000Z"}\n{"level":"inf'
+    );
+
+    holdFrontendLogs(userDataDir);
+
+    expect(releaseFrontendLogs()).toBe(1);
+  });
+
+  it('should count lines dropped beyond the limit', () => {
+    holdFrontendLogs(null);
+    for (let i = 0; i < MAX_QUEUED_FRONTEND_LOGS + 5; i++) {
+      logFrontendEntry('debug', `Line ${i}`);
+    }
+
+    expect(releaseFrontendLogs()).toBe(MAX_QUEUED_FRONTEND_LOGS);
+    expect(ipcLogger.warn).toHaveBeenCalledWith(
+      'Dropped frontend log lines while logging was unavailable',
+      { dropped: 5, limit: MAX_QUEUED_FRONTEND_LOGS }
+    );
+  });
+});
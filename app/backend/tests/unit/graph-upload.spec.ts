@@ -0,0 +1,26 @@
+/**
+ * @fileoverview Cloud Upload Path Tests
+ *
+ * Tests for building Microsoft Graph drive item paths.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { buildDriveItemPath } from '../../src/services/cloud/graph-upload';
+
+describe('buildDriveItemPath', () => {
+  it('should join folder segments and file name', () => {
+    expect(buildDriveItemPath('Timesheets/Archive', 'export.csv')).toBe('Timesheets/Archive/export.csv');
+  });
+
+  it('should ignore leading, trailing and repeated slashes', () => {
+    expect(buildDriveItemPath('/Timesheets//Archive/', 'export.csv')).toBe('Timesheets/Archive/export.csv');
+  });
+
+  it('should encode each segment', () => {
+    expect(buildDriveItemPath('Team Archive/2025 #1', 'a&b.csv')).toBe('Team%20Archive/2025%20%231/a%26b.csv');
+  });
+});
@@ -0,0 +1,38 @@
+/**
+ * @fileoverview IPC Capability Tests
+ *
+ * Tests per-window capability scoping of IPC commands.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  getChannelCapability,
+  parseWindowLabel,
+  windowHasCapability,
+  MAIN_WINDOW_LABEL
+} from '../../src/logic/ipc-capabilities';
+
+describe('ipc capabilities', () => {
+  it('should classify sensitive channels by prefix', () => {
+    expect(getChannelCapability('credentials:store')).toBe('credentials');
+    expect(getChannelCapability('admin:runReadonlyQuery')).toBe('admin');
+    expect(getChannelCapability('database:getSchemaInfo')).toBe('database');
+    expect(getChannelCapability('timesheet:saveDraft')).toBe('core');
+  });
+
+  it('should grant everything to the main window only', () => {
+    expect(windowHasCapability(MAIN_WINDOW_LABEL, 'credentials')).toBe(true);
+    expect(windowHasCapability('help', 'credentials')).toBe(false);
+    expect(windowHasCapability('help', 'core')).toBe(true);
+    expect(windowHasCapability(undefined, 'core')).toBe(false);
+  });
+
+  it('should read the window label from renderer arguments', () => {
+    expect(parseWindowLabel(['electron', '--sheetpilot-window=main'])).toBe('main');
+    expect(parseWindowLabel(['electron'])).toBeUndefined();
+  });
+});
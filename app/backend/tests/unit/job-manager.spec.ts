@@ -0,0 +1,165 @@
+/**
+ * @fileoverview Job Manager Tests
+ *
+ * Tests for background job queuing, status reporting, cancellation and
+ * run history recording.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('../../src/models', () => ({
+  startRun: vi.fn(() => 1),
+  finishRun: vi.fn(),
+  getRunByJobId: vi.fn(() => null)
+}));
+
+import * as models from '../../src/models';
+import {
+  enqueueJob,
+  JobFailedError,
+  waitForJob,
+  getJobStatus,
+  cancelJob,
+  onJobUpdate
+} from '../../src/services/jobs/job-manager';
+
+describe('Job Manager', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('should return a job ID before the task runs', async () => {
+    const task = vi.fn(async () => 'done');
+    const jobId = enqueueJob('test-immediate', task);
+
+    expect(task).not.toHaveBeenCalled();
+    expect(getJobStatus(jobId)?.status).toBe('queued');
+
+    const finished = await waitForJob(jobId);
+    expect(finished?.status).toBe('succeeded');
+    expect(finished?.result).toBe('done');
+    expect(finished?.percent).toBe(100);
+  });
+
+  it('should record the job in run history', async () => {
+    const jobId = enqueueJob('test-history', async () => ({ count: 3 }), {
+      trigger: 'scheduled',
+      detail: { scheduleId: 7 }
+    });
+    await waitForJob(jobId);
+
+    expect(models.startRun).toHaveBeenCalledWith('test-history', 'scheduled', { scheduleId: 7 }, jobId);
+    expect(models.finishRun).toHaveBeenCalledWith(1, 'success', { scheduleId: 7, count: 3 }, undefined);
+  });
+
+  it('should keep the full result on the job but record only the chosen part', async () => {
+    const jobId = enqueueJob('test-record-result', async () => ({ content: 'a,b\n1,2', rows: 1 }), {
+      recordResult: (result) => ({ rows: (result as { rows: number }).rows })
+    });
+    const finished = await waitForJob(jobId);
+
+    expect(finished?.result).toEqual({ content: 'a,b\n1,2', rows: 1 });
+    expect(models.finishRun).toHaveBeenCalledWith(1, 'success', { rows: 1 }, undefined);
+  });
+
+  it('should mark a job failed when its task throws', async () => {
+    const jobId = enqueueJob('test-failure', async () => {
+      throw new Error('boom');
+    });
+    const finished = await waitForJob(jobId);
+
+    expect(finished?.status).toBe('failed');
+    expect(finished?.error).toBe('boom');
+    expect(models.finishRun).toHaveBeenCalledWith(1, 'failure', undefined, 'boom');
+  });
+
+  it('should keep the result a failed job hands back', async () => {
+    const jobId = enqueueJob('test-failure-result', async () => {
+      throw new JobFailedError('blocked', { ruleViolations: [{ ruleId: 1 }] });
+    });
+    const finished = await waitForJob(jobId);
+
+    expect(finished?.status).toBe('failed');
+    expect(finished?.error).toBe('blocked');
+    expect(finished?.result).toEqual({ ruleViolations: [{ ruleId: 1 }] });
+  });
+
+  it('should run jobs of the same type one at a time', async () => {
+    const order: string[] = [];
+    const first = enqueueJob('test-serial', async () => {
+      order.push('first:start');
+      await new Promise((resolve) => setTimeout(resolve, 10));
+      order.push('first:end');
+    });
+    const second = enqueueJob('test-serial', async () => {
+      order.push('second:start');
+    });
+    await Promise.all([waitForJob(first), waitForJob(second)]);
+
+    expect(order).toEqual(['first:start', 'first:end', 'second:start']);
+  });
+
+  it('should cancel a queued job without running it', async () => {
+    const blocker = enqueueJob('test-cancel-queued', async () => {
+      await new Promise((resolve) => setTimeout(resolve, 10));
+    });
+    const task = vi.fn(async () => undefined);
+    const queued = enqueueJob('test-cancel-queued', task);
+
+    expect(cancelJob(queued)).toBe(true);
+    await waitForJob(blocker);
+
+    expect(task).not.toHaveBeenCalled();
+    expect(getJobStatus(queued)?.status).toBe('cancelled');
+  });
+
+  it('should signal a running job to stop', async () => {
+    const jobId = enqueueJob('test-cancel-running', (context) =>
+      new Promise<void>((resolve) => {
+        context.signal.addEventListener('abort', () => resolve());
+      })
+    );
+    await new Promise((resolve) => setImmediate(resolve));
+
+    expect(cancelJob(jobId)).toBe(true);
+    const finished = await waitForJob(jobId);
+    expect(finished?.status).toBe('cancelled');
+    expect(cancelJob(jobId)).toBe(false);
+  });
+
+  it('should publish progress to listeners', async () => {
+    const updates: Array<{ status: string; percent: number }> = [];
+    const unsubscribe = onJobUpdate((job) => updates.push({ status: job.status, percent: job.percent }));
+
+    const jobId = enqueueJob('test-progress', async (context) => {
+      context.reportProgress(50, 'Halfway');
+    });
+    await waitForJob(jobId);
+    unsubscribe();
+
+    expect(updates.map((u) => u.status)).toEqual(['queued', 'running', 'running', 'succeeded']);
+    expect(updates[2]?.percent).toBe(50);
+  });
+
+  it('should report jobs interrupted by an exit as failed', () => {
+    vi.mocked(models.getRunByJobId).mockReturnValueOnce({
+      id: 9,
+      jobType: 'scheduled-export',
+      trigger: 'scheduled',
+      status: 'running',
+      jobId: 'old-job',
+      detail: null,
+      error: null,
+      startedAt: '2025-01-01T00:00:00.000Z',
+      finishedAt: null
+    });
+
+    const status = getJobStatus('old-job');
+    expect(status?.status).toBe('failed');
+    expect(status?.error).toBe('Interrupted by application exit');
+  });
+});
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview Log Indexer Tests
+ *
+ * Tests incremental indexing of NDJSON log files into SQLite and that log
+ * queries use the index when enabled and scan files otherwise.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => os.tmpdir()) }
+}));
+
+vi.mock('../../../shared/logger', () => {
+  const logger = {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    debug: vi.fn(),
+    audit: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() }))
+  };
+  return { dbLogger: logger, appLogger: logger };
+});
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({ log_index_enabled: true }))
+}));
+
+import { setDbPath, getDb, ensureSchema, runMigrations, shutdownDatabase, queryLogIndex } from '../../src/models';
+import { loadSettings } from '../../src/services/settings/settings-store';
+import { indexLogFiles, parseLogLine, queryLogs } from '../../src/services/logs/log-indexer';
+
+const logLine = (timestamp: string, level: string, message: string, context?: Record<string, unknown>): string =>
+  JSON.stringify({ timestamp, level, message, component: 'IPC', ...(context ? { context } : {}) }) + '\n';
+
+describe('log indexer', () => {
+  let testDbPath: string;
+  let logDir: string;
+  let logFile: string;
+
+  beforeEach(() => {
+    vi.mocked(loadSettings).mockReturnValue({ log_index_enabled: true });
+    testDbPath = path.join(os.tmpdir(), `sheetpilot-log-index-test-${Date.now()}.sqlite`);
+    setDbPath(testDbPath);
+    ensureSchema();
+    runMigrations(getDb(), testDbPath);
+
+    logDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-logs-'));
+    logFile = path.join(logDir, 'sheetpilot_2025-01-15.log');
+    fs.writeFileSync(
+      logFile,
+      logLine('2025-01-15T10:00:00.000Z', 'info', 'App started') +
+        'not json\n' +
+        logLine('2025-01-15T11:00:00.000Z', 'error', 'Submission failed', { runId: 42 })
+    );
+  });
+
+  afterEach(() => {
+    try {
+      shutdownDatabase();
+    } catch {
+      // Ignore
+    }
+    fs.rmSync(logDir, { recursive: true, force: true });
+    if (fs.existsSync(testDbPath)) {
+      fs.unlinkSync(testDbPath);
+    }
+  });
+
+  it('should parse structured fields and skip non-JSON lines', () => {
+    expect(parseLogLine('not json', 1)).toBeNull();
+    expect(
+      parseLogLine(logLine('2025-01-15T11:00:00.000Z', 'error', 'Boom', { jobId: 'job-1' }), 7)
+    ).toEqual({
+      lineNumber: 7,
+      timestamp: '2025-01-15T11:00:00.000Z',
+      level: 'error',
+      runId: 'job-1',
+      component: 'IPC',
+      message: 'Boom'
+    });
+  });
+
+  it('should index only new complete lines on each pass', async () => {
+    const first = await indexLogFiles(logDir);
+    expect(first.linesIndexed).toBe(2);
+
+    // A partial trailing line is left for the next pass
+    const next = logLine('2025-01-15T12:00:00.000Z', 'warn', 'Slow response');
+    fs.appendFileSync(logFile, next.slice(0, 20));
+    expect((await indexLogFiles(logDir)).linesIndexed).toBe(0);
+
+    fs.appendFileSync(logFile, next.slice(20));
+    expect((await indexLogFiles(logDir)).linesIndexed).toBe(1);
+
+    const lines = queryLogIndex({});
+    expect(lines.map((line) => line.message)).toEqual(['Slow response', 'Submission failed', 'App started']);
+    expect(lines[0]?.lineNumber).toBe(4);
+  });
+
+  it('should filter indexed lines by level, run and date', async () => {
+    await indexLogFiles(logDir);
+
+    expect(queryLogIndex({ levels: ['error'] }).map((line) => line.message)).toEqual(['Submission failed']);
+    expect(queryLogIndex({ runId: '42' })).toHaveLength(1);
+    expect(queryLogIndex({ to: '2025-01-15T10:30:00.000Z' }).map((line) => line.message)).toEqual(['App started']);
+  });
+
+  it('should drop index rows for log files that were removed', async () => {
+    await indexLogFiles(logDir);
+    fs.unlinkSync(logFile);
+
+    const result = await indexLogFiles(logDir);
+    expect(result.filesRemoved).toBe(1);
+    expect(queryLogIndex({})).toHaveLength(0);
+  });
+
+  it('should scan log files when the index is disabled', async () => {
+    vi.mocked(loadSettings).mockReturnValue({});
+
+    const result = await queryLogs(logDir, { levels: ['error'], from: '2025-01-15T12:30:00+02:00' });
+    expect(result.source).toBe('files');
+    expect(result.lines.map((line) => line.message)).toEqual(['Submission failed']);
+  });
+});
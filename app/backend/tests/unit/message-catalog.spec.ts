@@ -0,0 +1,52 @@
+/**
+ * @fileoverview Message Catalog Tests
+ *
+ * Tests locale resolution, placeholder interpolation and English fallback
+ * for backend messages.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, afterEach } from 'vitest';
+import {
+  configureMessageLocale,
+  getMessage,
+  getMessageLocale,
+  resolveMessageLocale
+} from '../../src/services/i18n/message-catalog';
+
+describe('Message Catalog', () => {
+  afterEach(() => {
+    configureMessageLocale(undefined);
+  });
+
+  it('should resolve supported locales and fall back to English', () => {
+    expect(resolveMessageLocale('es')).toBe('es');
+    expect(resolveMessageLocale('ES-mx')).toBe('es');
+    expect(resolveMessageLocale('es_ES')).toBe('es');
+    expect(resolveMessageLocale('de')).toBe('en');
+    expect(resolveMessageLocale(42)).toBe('en');
+    expect(resolveMessageLocale(undefined)).toBe('en');
+  });
+
+  it('should return English messages by default', () => {
+    expect(getMessageLocale()).toBe('en');
+    expect(getMessage('SESSION_INVALID')).toBe('Session is invalid or expired. Please log in again.');
+  });
+
+  it('should return messages in the configured locale', () => {
+    configureMessageLocale('es-MX');
+
+    expect(getMessage('ADMIN_REQUIRED')).toBe('No autorizado: se requiere acceso de administrador');
+    expect(getMessage('ADMIN_REQUIRED', undefined, 'en')).toBe('Unauthorized: Admin access required');
+  });
+
+  it('should fill placeholders and leave unknown ones alone', () => {
+    expect(getMessage('EMAIL_DOMAIN_NOT_ALLOWED', { domains: '@example.com' })).toBe(
+      'Use your work email (@example.com). Personal accounts cannot be used with SheetPilot.'
+    );
+    expect(getMessage('EMAIL_DOMAIN_NOT_ALLOWED', {})).toContain('({domains})');
+  });
+});
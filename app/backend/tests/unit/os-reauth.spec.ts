@@ -0,0 +1,78 @@
+/**
+ * @fileoverview OS Re-authentication Tests
+ *
+ * Tests provider selection and the outcomes reported for a re-auth request.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, afterEach } from 'vitest';
+
+vi.mock('electron', () => ({
+  systemPreferences: {
+    canPromptTouchID: vi.fn(() => true),
+    promptTouchID: vi.fn(async () => undefined)
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  appLogger: {
+    verbose: vi.fn(),
+    warn: vi.fn(),
+    security: vi.fn()
+  }
+}));
+
+import {
+  getOsReauthProvider,
+  requestOsReauth,
+  setOsReauthProvider,
+  type OsReauthProvider
+} from '../../src/services/security/os-reauth';
+
+const provider = (overrides: Partial<OsReauthProvider> = {}): OsReauthProvider => ({
+  method: 'windows-hello',
+  isAvailable: vi.fn(async () => true),
+  verify: vi.fn(async () => true),
+  ...overrides
+});
+
+describe('OS Re-authentication', () => {
+  afterEach(() => {
+    setOsReauthProvider(undefined);
+  });
+
+  it('should pick the provider for the platform', () => {
+    expect(getOsReauthProvider('darwin')?.method).toBe('touch-id');
+    expect(getOsReauthProvider('win32')?.method).toBe('windows-hello');
+    expect(getOsReauthProvider('linux')).toBeNull();
+  });
+
+  it('should report verified only when the user verifies', async () => {
+    const hello = provider();
+    setOsReauthProvider(hello);
+    await expect(requestOsReauth('Show password')).resolves.toEqual({
+      outcome: 'verified',
+      method: 'windows-hello'
+    });
+    expect(hello.verify).toHaveBeenCalledWith('Show password');
+
+    setOsReauthProvider(provider({ verify: vi.fn(async () => false) }));
+    expect((await requestOsReauth('Show password')).outcome).toBe('failed');
+  });
+
+  it('should report unavailable without prompting when not set up', async () => {
+    const hello = provider({ isAvailable: vi.fn(async () => false) });
+    setOsReauthProvider(hello);
+    expect((await requestOsReauth('Show password')).outcome).toBe('unavailable');
+    expect(hello.verify).not.toHaveBeenCalled();
+
+    setOsReauthProvider(provider({ isAvailable: vi.fn(async () => { throw new Error('powershell.exe not found'); }) }));
+    expect((await requestOsReauth('Show password')).outcome).toBe('unavailable');
+
+    setOsReauthProvider(null);
+    expect(await requestOsReauth('Show password')).toEqual({ outcome: 'unavailable', method: null });
+  });
+});
@@ -0,0 +1,130 @@
+/**
+ * @fileoverview Protected Settings Tests
+ *
+ * Tests that settings which weaken protections (e.g. security_policy) are
+ * refused by settings:set and can only be written with an admin session.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const handlers = new Map<string, (...args: unknown[]) => Promise<unknown>>();
+
+vi.mock('electron', () => ({
+  ipcMain: {
+    handle: vi.fn((channel: string, handler: (...args: unknown[]) => Promise<unknown>) => {
+      handlers.set(channel, handler);
+    })
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    security: vi.fn(),
+    audit: vi.fn()
+  }
+}));
+
+vi.mock('@sheetpilot/shared', () => ({
+  setBrowserHeadless: vi.fn(),
+  setHighlightInteractions: vi.fn()
+}));
+
+vi.mock('@sheetpilot/bot', () => ({
+  configureSubmissionKeyMode: vi.fn(),
+  configureSubmissionOrder: vi.fn(),
+  configureSubmissionRateLimit: vi.fn(),
+  configureSubmissionVerification: vi.fn(),
+  resolveSubmissionKeyMode: vi.fn(),
+  resolveSubmissionOrder: vi.fn(),
+  resolveVerificationLevel: vi.fn(),
+  sanitizeRateLimitSettings: vi.fn()
+}));
+
+vi.mock('../../src/models', () => ({
+  configureDatabaseTimeouts: vi.fn(),
+  sanitizeDatabaseTimeoutSettings: vi.fn(),
+  validateSession: vi.fn()
+}));
+
+vi.mock('../../src/services/events/event-throttle', () => ({
+  configureEventThrottle: vi.fn(),
+  sanitizeEventThrottleSettings: vi.fn()
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  emitSettingsChanged: vi.fn(),
+  isTrustedIpcSender: vi.fn(() => true)
+}));
+
+vi.mock('../../src/services/settings/settings-store', async (importOriginal) => {
+  const actual = await importOriginal<typeof import('../../src/services/settings/settings-store')>();
+  let stored: Record<string, unknown> = {};
+  return {
+    ...actual,
+    getSettingsPath: vi.fn(() => '/tmp/settings.json'),
+    loadSettings: vi.fn(() => ({ ...stored })),
+    reloadSettings: vi.fn(() => ({ ...stored })),
+    saveSettings: vi.fn((next: Record<string, unknown>) => {
+      stored = { ...next };
+    }),
+    onSettingsChanged: vi.fn(() => () => undefined)
+  };
+});
+
+import { validateSession } from '../../src/models';
+import { saveSettings } from '../../src/services/settings/settings-store';
+import { registerSettingsHandlers } from '../../src/routes/settings-handlers';
+
+const TOKEN = '123e4567-e89b-12d3-a456-426614174000';
+const invoke = (channel: string, ...args: unknown[]) => handlers.get(channel)!({}, ...args);
+
+describe('protected settings', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerSettingsHandlers();
+  });
+
+  it('should refuse security_policy through settings:set', async () => {
+    const result = await invoke('settings:set', 'security_policy', { logoutOnLock: false });
+
+    expect(result).toMatchObject({ success: false });
+    expect(saveSettings).not.toHaveBeenCalled();
+  });
+
+  it('should still save ordinary settings through settings:set', async () => {
+    expect(await invoke('settings:set', 'themeMode', 'dark')).toEqual({ success: true });
+  });
+
+  it('should refuse settings:setProtected without an admin session', async () => {
+    vi.mocked(validateSession).mockReturnValue({ valid: true, email: 'user@example.com', isAdmin: false });
+
+    const result = await invoke('settings:setProtected', TOKEN, 'security_policy', { logoutOnLock: false });
+
+    expect(result).toMatchObject({ success: false });
+    expect(saveSettings).not.toHaveBeenCalled();
+  });
+
+  it('should save security_policy with an admin session', async () => {
+    vi.mocked(validateSession).mockReturnValue({ valid: true, email: 'admin@example.com', isAdmin: true });
+
+    const result = await invoke('settings:setProtected', TOKEN, 'security_policy', { logoutOnLock: false });
+
+    expect(result).toEqual({ success: true });
+    expect(saveSettings).toHaveBeenCalledWith(expect.objectContaining({ security_policy: { logoutOnLock: false } }));
+  });
+
+  it('should only write protected keys through settings:setProtected', async () => {
+    vi.mocked(validateSession).mockReturnValue({ valid: true, email: 'admin@example.com', isAdmin: true });
+
+    expect(await invoke('settings:setProtected', TOKEN, 'themeMode', 'dark')).toMatchObject({ success: false });
+    expect(saveSettings).not.toHaveBeenCalled();
+  });
+});
@@ -21,6 +21,10 @@ import {
   getAvailableQuarterIds,
   getQuarterById,
   getCurrentQuarter,
+  getQuarterByFormId,
+  resolveFieldDefinitions,
+  formTitleMatchesQuarter,
+  FIELD_DEFINITIONS,
   type QuarterDefinition
 } from '@sheetpilot/bot';
 
@@ -328,4 +332,64 @@ describe('Quarter Configuration', () => {
       }
     });
   });
+
+  describe('Field Overrides', () => {
+    it('should find quarters by form ID', () => {
+      QUARTER_DEFINITIONS.forEach((quarter) => {
+        expect(getQuarterByFormId(quarter.formId)?.id).toBe(quarter.id);
+      });
+      expect(getQuarterByFormId('not-a-quarter-form')).toBeNull();
+    });
+
+    it('should return the base field definitions without overrides', () => {
+      expect(resolveFieldDefinitions(undefined)).toBe(FIELD_DEFINITIONS);
+    });
+
+    it('should apply optional and locator overrides without mutating the base definitions', () => {
+      const resolved = resolveFieldDefinitions({
+        tool: { optional: false },
+        hours: { locator: "input[aria-label='Hours Worked']" }
+      });
+
+      expect(resolved['tool']?.optional).toBe(false);
+      expect(resolved['tool']?.locator).toBe(FIELD_DEFINITIONS['tool']?.locator);
+      expect(resolved['hours']?.locator).toBe("input[aria-label='Hours Worked']");
+      expect(FIELD_DEFINITIONS['tool']?.optional).toBe(true);
+      expect(FIELD_DEFINITIONS['hours']?.locator).toBe("input[aria-label='Hours']");
+    });
+
+    it('should ignore overrides for unknown fields', () => {
+      const resolved = resolveFieldDefinitions({ no_such_field: { optional: false } });
+      expect(resolved['no_such_field']).toBeUndefined();
+    });
+  });
+
+  describe('Form Title Verification', () => {
+    const quarter: QuarterDefinition = {
+      id: 'Q4-2025',
+      name: 'Q4 2025',
+      startDate: '2025-10-01',
+      endDate: '2025-12-31',
+      formUrl: 'https://app.smartsheet.com/b/form/q4',
+      formId: 'q4'
+    };
+
+    it('should match titles containing the quarter name with any separator', () => {
+      expect(formTitleMatchesQuarter(quarter, 'Timesheet Q4 2025 | Smartsheet')).toBe(true);
+      expect(formTitleMatchesQuarter(quarter, 'q4-2025 timesheet')).toBe(true);
+      expect(formTitleMatchesQuarter(quarter, 'Timesheet Q42025')).toBe(true);
+    });
+
+    it('should reject titles for another quarter or a test sheet', () => {
+      expect(formTitleMatchesQuarter(quarter, 'Timesheet Q3 2025')).toBe(false);
+      expect(formTitleMatchesQuarter(quarter, 'TEST - Timesheet Sandbox')).toBe(false);
+      expect(formTitleMatchesQuarter(quarter, '')).toBe(false);
+    });
+
+    it('should use a configured title pattern instead of the quarter name', () => {
+      const custom = { ...quarter, titlePattern: 'FY26\\s+Period\\s+1' };
+      expect(formTitleMatchesQuarter(custom, 'Hours FY26 Period 1')).toBe(true);
+      expect(formTitleMatchesQuarter(custom, 'Hours Q4 2025')).toBe(false);
+    });
+  });
 });
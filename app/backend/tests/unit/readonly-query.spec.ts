@@ -0,0 +1,98 @@
+/**
+ * @fileoverview Read-only Query Whitelist Tests
+ *
+ * Tests for the statement whitelist applied to admin report queries, and
+ * for refusing protected tables by what a prepared statement reads.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeAll, afterAll } from 'vitest';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import Database from 'better-sqlite3';
+
+const testDbPath = path.join(os.tmpdir(), `sheetpilot-readonly-query-${Date.now()}.sqlite`);
+
+vi.mock('../../src/models/connection-manager', () => ({
+  getDbPath: () => testDbPath
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  dbLogger: { verbose: vi.fn() }
+}));
+
+import { checkReadonlySql, runReadonlyQuery } from '../../src/models/readonly-query';
+
+describe('checkReadonlySql', () => {
+  it('should allow SELECT and WITH queries', () => {
+    expect(checkReadonlySql("SELECT SUM(hours) FROM timesheet WHERE tool = 'TOOL-17'")).toBeNull();
+    expect(checkReadonlySql('WITH t AS (SELECT * FROM timesheet) SELECT COUNT(*) FROM t;')).toBeNull();
+  });
+
+  it('should reject writes and multiple statements', () => {
+    expect(checkReadonlySql('DELETE FROM timesheet')).toMatch(/Only SELECT/);
+    expect(checkReadonlySql('SELECT 1; DROP TABLE timesheet')).toMatch(/single statement/);
+  });
+
+  it('should not be fooled by semicolons or keywords inside literals and comments', () => {
+    expect(checkReadonlySql("SELECT * FROM timesheet WHERE task_description = 'a; pragma'")).toBeNull();
+    expect(checkReadonlySql('-- attach\nSELECT 1')).toBeNull();
+  });
+
+  it('should reject forbidden keywords and protected tables', () => {
+    expect(checkReadonlySql('SELECT * FROM pragma_table_info(\'timesheet\')')).toBeNull();
+    expect(checkReadonlySql('SELECT load_extension(\'x\')')).toMatch(/LOAD_EXTENSION/);
+    expect(checkReadonlySql('SELECT * FROM credentials')).toMatch(/credentials table/);
+    expect(checkReadonlySql('SELECT * FROM "sessions"')).toMatch(/sessions table/);
+  });
+});
+
+describe('checkReadonlySql with comment markers and quoting', () => {
+  it('should not read comment markers inside string literals', () => {
+    expect(checkReadonlySql("select '--', * from credentials")).toMatch(/credentials table/);
+    expect(checkReadonlySql("select '/*', * from credentials")).toMatch(/credentials table/);
+    expect(checkReadonlySql("select 'it''s -- fine', * from sessions")).toMatch(/sessions table/);
+  });
+
+  it('should not open a literal from a quote inside a comment', () => {
+    expect(checkReadonlySql("select 1 -- it's\nfrom credentials")).toMatch(/credentials table/);
+  });
+
+  it('should see protected tables behind quoted identifiers', () => {
+    expect(checkReadonlySql('SELECT * FROM [Credentials]')).toMatch(/credentials table/);
+    expect(checkReadonlySql('SELECT * FROM `sessions`')).toMatch(/sessions table/);
+  });
+});
+
+describe('runReadonlyQuery', () => {
+  beforeAll(() => {
+    const db = new Database(testDbPath);
+    db.exec(`
+      CREATE TABLE credentials (id INTEGER PRIMARY KEY, service TEXT UNIQUE, password TEXT);
+      CREATE TABLE timesheet (id INTEGER PRIMARY KEY, hours REAL);
+      CREATE VIEW report_view AS SELECT password FROM credentials;
+      INSERT INTO credentials (service, password) VALUES ('smartsheet', 'secret');
+      INSERT INTO timesheet (hours) VALUES (8);
+    `);
+    db.close();
+  });
+
+  afterAll(() => {
+    fs.rmSync(testDbPath, { force: true });
+  });
+
+  it('should run an allowed query', () => {
+    expect(runReadonlyQuery('SELECT hours FROM timesheet').rows).toEqual([[8]]);
+  });
+
+  it('should refuse protected tables read through a view or subquery', () => {
+    expect(() => runReadonlyQuery('SELECT * FROM report_view')).toThrow(/credentials table/);
+    expect(() =>
+      runReadonlyQuery('SELECT (SELECT password FROM credentials WHERE service = ?)', ['smartsheet'])
+    ).toThrow(/credentials table/);
+  });
+});
@@ -0,0 +1,147 @@
+/**
+ * @fileoverview Reproduction Bundle Tests
+ *
+ * Tests that bundles hash descriptions and the account email, drop secret
+ * settings and screenshot folders, and pack every file into the ZIP.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('electron', () => ({
+  app: {
+    getPath: vi.fn(() => '/tmp'),
+    getVersion: vi.fn(() => '1.4.0'),
+    getLocale: vi.fn(() => 'en-US')
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  appLogger: { info: vi.fn(), verbose: vi.fn(), debug: vi.fn(), warn: vi.fn() }
+}));
+
+vi.mock('../../src/models', () => ({
+  getTimesheetEntriesByIds: vi.fn(() => [])
+}));
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+import {
+  buildReproBundleFiles,
+  hashText,
+  stripSecrets,
+  type ReproEnvironment
+} from '../../src/services/runs/repro-bundle';
+import { buildStoredZip } from '../../src/services/archive/stored-zip';
+import type { RunArtifact } from '../../src/services/runs/run-artifacts';
+import type { TimesheetDbRow } from '../../src/models';
+
+const artifact: RunArtifact = {
+  version: 1,
+  runId: 5,
+  inputs: {
+    email: 'tech@example.com',
+    useMockWebsite: false,
+    fillProfile: 'inject',
+    rateLimit: {},
+    entries: [
+      {
+        id: 1,
+        date: '2025-01-15',
+        hours: 2,
+        project: 'FL-Carver Techs',
+        tool: null,
+        chargeCode: null,
+        taskDescription: 'Calibration for customer ACME',
+        submissionKey: 'SP-1',
+        amendsId: null
+      }
+    ]
+  },
+  outcome: { ok: false, error: 'Form submission failed', submittedIds: [], removedIds: [1], totalProcessed: 1 },
+  timing: { startedAt: '2025-01-20T00:00:00.000Z', finishedAt: '2025-01-20T00:00:09.000Z', durationMs: 9000 },
+  forms: [],
+  screenshots: [{ entryId: 1, formId: 'q1', rowIndex: 0, path: '/Users/jdoe/AppData/run-artifacts/run-5/q1-row0.png' }]
+};
+
+const row: TimesheetDbRow = {
+  id: 1,
+  date: '2025-01-15',
+  hours: 2,
+  project: 'FL-Carver Techs',
+  task_description: 'Calibration for customer ACME',
+  external_ref: 'WO-104233'
+};
+
+const environment: ReproEnvironment = {
+  appVersion: '1.4.0',
+  electronVersion: '33.0.0',
+  chromeVersion: null,
+  nodeVersion: '20.0.0',
+  platform: 'win32',
+  arch: 'x64',
+  osRelease: '10.0.19045',
+  locale: 'en-US'
+};
+
+const buildFiles = (settings: unknown = {}) =>
+  Object.fromEntries(
+    buildReproBundleFiles({ artifact, rows: [row], settings, environment, createdAt: new Date() }).map(
+      (file) => [file.name, JSON.parse(file.content)]
+    )
+  );
+
+describe('Reproduction Bundles', () => {
+  it('should hash equal text to equal short hashes', () => {
+    expect(hashText('Calibration')).toMatch(/^sha256:[0-9a-f]{16}$/);
+    expect(hashText('Calibration')).toBe(hashText('Calibration'));
+    expect(hashText('Calibration')).not.toBe(hashText('calibration'));
+    expect(hashText('')).toBe('');
+    expect(hashText(null)).toBeNull();
+  });
+
+  it('should not include descriptions, references or the account email', () => {
+    const files = buildFiles();
+    const text = JSON.stringify(files);
+
+    expect(text).not.toContain('ACME');
+    expect(text).not.toContain('WO-104233');
+    expect(text).not.toContain('tech@example.com');
+    expect(files['rows.json'][0].task_description).toBe(hashText(row.task_description));
+    expect(files['run-artifact.json'].inputs.entries[0].taskDescription).toBe(hashText(row.task_description));
+    expect(files['run-artifact.json'].screenshots[0].path).toBe('q1-row0.png');
+    expect(files['environment.json']).toEqual(environment);
+  });
+
+  it('should remove secret settings at any depth', () => {
+    expect(
+      stripSecrets({
+        locale: 'en',
+        cloud: { client_secret: 'abc', tenant: 'contoso' },
+        hooks: [{ api_key: 'xyz', url: 'https://example.com' }],
+        sessionToken: 't'
+      })
+    ).toEqual({
+      locale: 'en',
+      cloud: { client_secret: '[removed]', tenant: 'contoso' },
+      hooks: [{ api_key: '[removed]', url: 'https://example.com' }],
+      sessionToken: '[removed]'
+    });
+  });
+
+  it('should pack every bundle file into the ZIP', () => {
+    const files = buildReproBundleFiles({ artifact, rows: [row], settings: {}, environment, createdAt: new Date() });
+    const zip = buildStoredZip(files);
+
+    expect(zip.readUInt32LE(0)).toBe(0x04034b50);
+    expect(zip.readUInt16LE(zip.length - 22 + 10)).toBe(files.length);
+    for (const name of ['manifest.json', 'run-artifact.json', 'rows.json', 'settings.json', 'environment.json']) {
+      expect(zip.includes(Buffer.from(name))).toBe(true);
+    }
+  });
+});
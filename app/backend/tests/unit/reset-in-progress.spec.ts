@@ -0,0 +1,88 @@
+/**
+ * @fileoverview Reset In-Progress Handler Tests
+ *
+ * Tests that in-progress entries cannot be reset by hand while a submission
+ * is still running, and that a reset never releases the run's lock.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+const handlers = new Map<string, (...args: unknown[]) => Promise<unknown>>();
+
+vi.mock('electron', () => ({
+  ipcMain: {
+    handle: vi.fn((channel: string, handler: (...args: unknown[]) => Promise<unknown>) => {
+      handlers.set(channel, handler);
+    })
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn(),
+    startTimer: vi.fn(() => ({ done: vi.fn() }))
+  }
+}));
+
+vi.mock('../../src/models', () => ({
+  resetInProgressTimesheetEntries: vi.fn(() => 3)
+}));
+
+vi.mock('../../src/services/timesheet/submission-workflow', () => ({
+  isTimesheetSubmissionInProgress: vi.fn(() => false)
+}));
+
+vi.mock('../../src/services/i18n/message-catalog', () => ({
+  getMessage: vi.fn(() => 'A submission is running')
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  isTrustedIpcSender: vi.fn(() => true)
+}));
+
+import { resetInProgressTimesheetEntries } from '../../src/models';
+import { isTimesheetSubmissionInProgress } from '../../src/services/timesheet/submission-workflow';
+import {
+  acquireSubmissionLock,
+  getAppLockState,
+  releaseSubmissionLock
+} from '../../src/services/timesheet/app-lock';
+import { registerTimesheetResetHandlers } from '../../src/routes/handlers/timesheet/reset';
+
+const resetInProgress = () => handlers.get('timesheet:resetInProgress')!({});
+
+describe('timesheet:resetInProgress', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerTimesheetResetHandlers();
+  });
+
+  afterEach(() => {
+    releaseSubmissionLock('completed');
+  });
+
+  it('should refuse while a submission is running and keep the lock', async () => {
+    vi.mocked(isTimesheetSubmissionInProgress).mockReturnValueOnce(true);
+    acquireSubmissionLock(7);
+
+    const result = await resetInProgress();
+
+    expect(result).toEqual({ success: false, error: 'A submission is running', code: 'SUBMISSION_LOCKED' });
+    expect(resetInProgressTimesheetEntries).not.toHaveBeenCalled();
+    expect(getAppLockState()).toMatchObject({ locked: true, runId: 7 });
+  });
+
+  it('should reset entries left behind when no submission is running', async () => {
+    const result = await resetInProgress();
+
+    expect(result).toEqual({ success: true, count: 3 });
+    expect(resetInProgressTimesheetEntries).toHaveBeenCalledTimes(1);
+  });
+});
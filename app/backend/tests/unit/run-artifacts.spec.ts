@@ -0,0 +1,187 @@
+/**
+ * @fileoverview Run Artifact Tests
+ *
+ * Tests building, writing, reading and pruning the per-run JSON artifacts
+ * that support uses to investigate a submission.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+vi.mock('electron', () => ({
+  app: { getPath: vi.fn(() => os.tmpdir()) }
+}));
+
+vi.mock('../../../shared/logger', () => ({
+  appLogger: { info: vi.fn(), warn: vi.fn(), error: vi.fn(), verbose: vi.fn() }
+}));
+
+import {
+  buildRunArtifact,
+  cleanupOldRunArtifacts,
+  getRunArtifact,
+  getRunScreenshotDirectory,
+  writeRunArtifact
+} from '../../src/services/runs/run-artifacts';
+import type { TimesheetDbRow } from '../../src/models';
+
+const entry = (id: number): TimesheetDbRow => ({
+  id,
+  date: '2025-01-15',
+  hours: 2,
+  project: 'FL-Carver Techs',
+  tool: '#1 Rinse and 2D marker',
+  detail_charge_code: 'EPR1',
+  task_description: 'Calibration',
+  submission_key: `SP-${id}`
+});
+
+const formRun = {
+  formId: 'q1-2025',
+  formUrl: 'https://app.smartsheet.com/b/form/q1-2025',
+  quarterId: 'Q1-2025',
+  fillProfile: 'inject',
+  startedAt: '2025-01-20T00:00:00.000Z',
+  finishedAt: '2025-01-20T00:00:09.000Z',
+  durationMs: 9000,
+  loginMs: 2000,
+  titleCheck: 'passed' as const,
+  rows: [
+    {
+      rowIndex: 0,
+      entryId: 1,
+      outcome: 'submitted' as const,
+      error: null,
+      fillMs: 800,
+      submitMs: 1200,
+      submitAttempts: 1,
+      throttleRetries: 0,
+      fields: { project_code: { locator: "input[aria-label='Project']", source: 'default' as const } },
+      screenshot: null
+    },
+    {
+      rowIndex: 1,
+      entryId: 2,
+      outcome: 'failed' as const,
+      error: 'Form submission failed after 3 attempts',
+      fillMs: 750,
+      submitMs: 9000,
+      submitAttempts: 3,
+      throttleRetries: 1,
+      fields: {},
+      screenshot: '/data/run-artifacts/run-5/q1-2025-row2.png'
+    }
+  ],
+  error: null
+};
+
+describe('run artifacts', () => {
+  let artifactDir: string;
+
+  beforeEach(() => {
+    artifactDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-artifacts-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(artifactDir, { recursive: true, force: true });
+  });
+
+  it('should record inputs without the password and index screenshots', () => {
+    const artifact = buildRunArtifact({
+      runId: 5,
+      email: 'user@company.com',
+      useMockWebsite: false,
+      entries: [entry(1), entry(2)],
+      result: {
+        ok: true,
+        submittedIds: [1],
+        removedIds: [2],
+        totalProcessed: 2,
+        formRuns: [formRun]
+      },
+      startedAt: new Date('2025-01-20T00:00:00Z'),
+      finishedAt: new Date('2025-01-20T00:00:10Z')
+    });
+
+    expect(artifact.runId).toBe(5);
+    expect(artifact.inputs.email).toBe('user@company.com');
+    expect(artifact.inputs.entries.map((e) => [e.id, e.chargeCode, e.submissionKey])).toEqual([
+      [1, 'EPR1', 'SP-1'],
+      [2, 'EPR1', 'SP-2']
+    ]);
+    expect(JSON.stringify(artifact)).not.toMatch(/password/i);
+    expect(artifact.outcome).toEqual({
+      ok: true,
+      error: null,
+      submittedIds: [1],
+      removedIds: [2],
+      totalProcessed: 2
+    });
+    expect(artifact.timing.durationMs).toBe(10000);
+    expect(artifact.screenshots).toEqual([
+      { entryId: 2, formId: 'q1-2025', rowIndex: 1, path: '/data/run-artifacts/run-5/q1-2025-row2.png' }
+    ]);
+  });
+
+  it('should record the error when the run produced no result', () => {
+    const artifact = buildRunArtifact({
+      runId: 6,
+      email: 'user@company.com',
+      useMockWebsite: true,
+      entries: [],
+      result: null,
+      error: 'Browser failed to launch',
+      startedAt: new Date('2025-01-20T00:00:00Z'),
+      finishedAt: new Date('2025-01-20T00:00:01Z')
+    });
+
+    expect(artifact.outcome.ok).toBe(false);
+    expect(artifact.outcome.error).toBe('Browser failed to launch');
+    expect(artifact.forms).toEqual([]);
+  });
+
+  it('should read back a written artifact and return null for unknown runs', async () => {
+    const artifact = buildRunArtifact({
+      runId: 7,
+      email: null,
+      useMockWebsite: false,
+      entries: [entry(1)],
+      result: { ok: true, submittedIds: [1], removedIds: [], totalProcessed: 1, formRuns: [formRun] },
+      startedAt: new Date('2025-01-20T00:00:00Z'),
+      finishedAt: new Date('2025-01-20T00:00:10Z')
+    });
+
+    const filePath = await writeRunArtifact(artifact, artifactDir);
+    expect(path.basename(filePath)).toBe('run-7.json');
+    expect(await getRunArtifact(7, artifactDir)).toEqual(artifact);
+    expect(await getRunArtifact(8, artifactDir)).toBeNull();
+  });
+
+  it('should delete artifacts and screenshot folders past retention', async () => {
+    const now = new Date('2025-03-01T00:00:00Z');
+    const old = new Date('2025-01-01T00:00:00Z');
+    fs.writeFileSync(path.join(artifactDir, 'run-1.json'), '{}');
+    fs.mkdirSync(getRunScreenshotDirectory(1, artifactDir));
+    fs.writeFileSync(path.join(getRunScreenshotDirectory(1, artifactDir), 'row1.png'), 'x');
+    fs.writeFileSync(path.join(artifactDir, 'run-2.json'), '{}');
+    fs.writeFileSync(path.join(artifactDir, 'notes.txt'), 'keep');
+    for (const name of ['run-1.json', 'run-1', 'notes.txt']) {
+      fs.utimesSync(path.join(artifactDir, name), old, old);
+    }
+
+    const deleted = await cleanupOldRunArtifacts(artifactDir, now, 30);
+
+    expect(deleted.sort()).toEqual(['run-1', 'run-1.json']);
+    expect(fs.readdirSync(artifactDir).sort()).toEqual(['notes.txt', 'run-2.json']);
+  });
+
+  it('should ignore a missing artifact folder', async () => {
+    expect(await cleanupOldRunArtifacts(path.join(artifactDir, 'missing'))).toEqual([]);
+  });
+});
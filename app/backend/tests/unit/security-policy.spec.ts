@@ -0,0 +1,108 @@
+/**
+ * @fileoverview Security Policy Tests
+ *
+ * Tests that workstation lock/sleep signals end sessions and lock the
+ * credential vault according to the security_policy settings group.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('../../src/models', () => ({
+  clearAllSessions: vi.fn(() => 2),
+  lockCredentialVault: vi.fn()
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  emitSessionRevoked: vi.fn()
+}));
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+import * as models from '../../src/models';
+import { emitSessionRevoked } from '../../src/routes/handlers/timesheet/main-window';
+import { loadSettings } from '../../src/services/settings/settings-store';
+import {
+  DEFAULT_SECURITY_POLICY,
+  enforceSessionLockPolicy,
+  registerSessionLockPolicy,
+  resolveSecurityPolicy
+} from '../../src/services/security/security-policy';
+
+describe('security policy', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  describe('resolveSecurityPolicy', () => {
+    it('should fall back to defaults for missing or malformed settings', () => {
+      expect(resolveSecurityPolicy(undefined)).toEqual(DEFAULT_SECURITY_POLICY);
+      expect(resolveSecurityPolicy({ logoutOnLock: 'yes' })).toEqual(DEFAULT_SECURITY_POLICY);
+    });
+
+    it('should keep stored flags', () => {
+      expect(resolveSecurityPolicy({ logoutOnLock: true, lockCredentialVault: false })).toEqual({
+        logoutOnLock: true,
+        logoutOnSuspend: false,
+        lockCredentialVault: false,
+        requireOsReauthToReveal: true
+      });
+    });
+  });
+
+  describe('enforceSessionLockPolicy', () => {
+    it('should do nothing when the policy keeps sessions', () => {
+      expect(enforceSessionLockPolicy('lock-screen', DEFAULT_SECURITY_POLICY)).toBe(false);
+      expect(models.clearAllSessions).not.toHaveBeenCalled();
+      expect(emitSessionRevoked).not.toHaveBeenCalled();
+    });
+
+    it('should revoke sessions, lock the vault and notify the renderer', () => {
+      const policy = { ...DEFAULT_SECURITY_POLICY, logoutOnLock: true };
+
+      expect(enforceSessionLockPolicy('lock-screen', policy)).toBe(true);
+
+      expect(models.clearAllSessions).toHaveBeenCalled();
+      expect(models.lockCredentialVault).toHaveBeenCalled();
+      expect(emitSessionRevoked).toHaveBeenCalledWith({ reason: 'lock-screen' });
+    });
+
+    it('should leave the vault open when the policy says so', () => {
+      const policy = { logoutOnLock: false, logoutOnSuspend: true, lockCredentialVault: false };
+
+      expect(enforceSessionLockPolicy('suspend', policy)).toBe(true);
+      expect(models.lockCredentialVault).not.toHaveBeenCalled();
+    });
+
+    it('should read the policy from settings by default', () => {
+      vi.mocked(loadSettings).mockReturnValue({ security_policy: { logoutOnSuspend: true } });
+
+      expect(enforceSessionLockPolicy('suspend')).toBe(true);
+      expect(enforceSessionLockPolicy('lock-screen')).toBe(false);
+    });
+  });
+
+  describe('registerSessionLockPolicy', () => {
+    it('should subscribe to lock and suspend signals', () => {
+      const handlers = new Map<string, () => void>();
+      const monitor = {
+        on: vi.fn((event: string, listener: () => void) => {
+          handlers.set(event, listener);
+          return monitor;
+        })
+      };
+      vi.mocked(loadSettings).mockReturnValue({ security_policy: { logoutOnLock: true } });
+
+      registerSessionLockPolicy(monitor as never);
+      handlers.get('lock-screen')?.();
+
+      expect([...handlers.keys()]).toEqual(['lock-screen', 'suspend']);
+      expect(models.clearAllSessions).toHaveBeenCalledTimes(1);
+    });
+  });
+});
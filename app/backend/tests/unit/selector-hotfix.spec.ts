@@ -0,0 +1,151 @@
+/**
+ * @fileoverview Selector Hotfix Tests
+ *
+ * Tests signing and verifying selector hotfixes and reading them from a
+ * published file.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import * as crypto from 'crypto';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { describe, it, expect, vi, beforeAll, afterAll } from 'vitest';
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => ({}))
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn()
+  }
+}));
+
+import {
+  checkSelectorHotfix,
+  signSelectorHotfix,
+  verifySelectorHotfix,
+  type SelectorHotfixPayload
+} from '../../src/services/timesheet/selector-hotfix';
+
+const keys = crypto.generateKeyPairSync('ed25519');
+const privateKey = keys.privateKey.export({ type: 'pkcs8', format: 'pem' }).toString();
+const publicKey = keys.publicKey.export({ type: 'spki', format: 'pem' }).toString();
+
+const payload: SelectorHotfixPayload = {
+  id: '2025-03-14-project-locator',
+  expiresAt: '2025-04-01T00:00:00Z',
+  fields: { project: "input[aria-label='Project']" },
+  submitButton: ["button[data-client-id='form_submit_btn']"]
+};
+
+describe('Selector Hotfix', () => {
+  const now = new Date('2025-03-14T12:00:00Z');
+
+  it('should verify a signed hotfix and drop the expiry', () => {
+    const hotfix = verifySelectorHotfix(signSelectorHotfix(payload, privateKey), publicKey, now);
+
+    expect(hotfix).toEqual({
+      id: '2025-03-14-project-locator',
+      fields: { project: "input[aria-label='Project']" },
+      submitButton: ["button[data-client-id='form_submit_btn']"]
+    });
+  });
+
+  it('should reject a payload changed after signing', () => {
+    const signed = signSelectorHotfix(payload, privateKey);
+    const tampered = { ...signed, payload: signed.payload.replace('Project', 'Projekt') };
+
+    expect(() => verifySelectorHotfix(tampered, publicKey, now)).toThrow(/signature is not valid/);
+  });
+
+  it('should reject a hotfix signed with another key', () => {
+    const other = crypto.generateKeyPairSync('ed25519').privateKey.export({ type: 'pkcs8', format: 'pem' }).toString();
+
+    expect(() => verifySelectorHotfix(signSelectorHotfix(payload, other), publicKey, now)).toThrow(
+      /signature is not valid/
+    );
+  });
+
+  it('should reject an expired hotfix', () => {
+    const signed = signSelectorHotfix(payload, privateKey);
+
+    expect(() => verifySelectorHotfix(signed, publicKey, new Date('2025-04-02T00:00:00Z'))).toThrow(
+      /expired at 2025-04-01T00:00:00Z/
+    );
+  });
+
+  it('should reject a hotfix without an expiry', () => {
+    const { expiresAt: _expiresAt, ...unexpiring } = payload;
+    const text = JSON.stringify(unexpiring);
+    const signed = {
+      payload: text,
+      signature: crypto.sign(null, Buffer.from(text, 'utf-8'), privateKey).toString('base64')
+    };
+
+    expect(() => verifySelectorHotfix(signed, publicKey, now)).toThrow(/must have an expiresAt/);
+  });
+
+  it('should reject documents that are not signed hotfixes', () => {
+    expect(() => verifySelectorHotfix({ id: 'x' }, publicKey, now)).toThrow(/not a signed hotfix document/);
+  });
+
+  describe('checkSelectorHotfix', () => {
+    let dir: string;
+
+    beforeAll(() => {
+      dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-hotfix-'));
+    });
+
+    afterAll(() => {
+      fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it('should do nothing when no hotfix channel is configured', async () => {
+      expect(await checkSelectorHotfix(undefined)).toEqual({ hotfix: null, error: null });
+    });
+
+    it('should treat a missing file as no hotfix', async () => {
+      const check = await checkSelectorHotfix({ url: path.join(dir, 'missing.json') }, publicKey);
+
+      expect(check).toEqual({ hotfix: null, error: null });
+    });
+
+    it('should read a published hotfix from a file path', async () => {
+      const file = path.join(dir, 'hotfix.json');
+      const current = { ...payload, expiresAt: new Date(Date.now() + 60 * 60 * 1000).toISOString() };
+      fs.writeFileSync(file, JSON.stringify(signSelectorHotfix(current, privateKey)));
+
+      const check = await checkSelectorHotfix({ url: file }, publicKey);
+
+      expect(check.error).toBeNull();
+      expect(check.hotfix?.id).toBe('2025-03-14-project-locator');
+    });
+
+    it('should report a bad hotfix instead of throwing', async () => {
+      const file = path.join(dir, 'bad.json');
+      fs.writeFileSync(file, JSON.stringify({ payload: '{}', signature: 'AAAA' }));
+
+      const check = await checkSelectorHotfix({ url: file }, publicKey);
+
+      expect(check.hotfix).toBeNull();
+      expect(check.error).toMatch(/signature is not valid/);
+    });
+
+    it('should check hotfixes against the built-in key by default', async () => {
+      const file = path.join(dir, 'other-signer.json');
+      const current = { ...payload, expiresAt: new Date(Date.now() + 60 * 60 * 1000).toISOString() };
+      fs.writeFileSync(file, JSON.stringify(signSelectorHotfix(current, privateKey)));
+
+      const check = await checkSelectorHotfix({ url: file });
+
+      expect(check.hotfix).toBeNull();
+      expect(check.error).toMatch(/signature is not valid/);
+    });
+  });
+});
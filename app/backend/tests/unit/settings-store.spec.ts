@@ -0,0 +1,114 @@
+/**
+ * @fileoverview Settings Store Tests
+ *
+ * Tests that settings are served from memory after the first read, that
+ * callers cannot change the cache without saving, and that saves notify
+ * listeners of the keys that changed.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+let userDataDir = '';
+
+vi.mock('electron', () => ({
+  app: {
+    getPath: vi.fn(() => userDataDir)
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    error: vi.fn()
+  }
+}));
+
+import {
+  getSettingsPath,
+  loadSettings,
+  onSettingsChanged,
+  reloadSettings,
+  saveSettings,
+  type SettingsChange
+} from '../../src/services/settings/settings-store';
+
+const writeSettingsFile = (settings: Record<string, unknown>): void => {
+  fs.writeFileSync(getSettingsPath(), JSON.stringify(settings), 'utf-8');
+};
+
+describe('Settings Store', () => {
+  beforeEach(() => {
+    userDataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-settings-'));
+    reloadSettings();
+  });
+
+  afterEach(() => {
+    fs.rmSync(userDataDir, { recursive: true, force: true });
+  });
+
+  it('should serve settings from memory until they are reloaded', () => {
+    writeSettingsFile({ locale: 'es' });
+    expect(reloadSettings()).toEqual({ locale: 'es' });
+
+    writeSettingsFile({ locale: 'en' });
+    expect(loadSettings().locale).toBe('es');
+    expect(reloadSettings().locale).toBe('en');
+  });
+
+  it('should not let callers change the cache without saving', () => {
+    saveSettings({ browserHeadless: true });
+
+    const settings = loadSettings();
+    settings.browserHeadless = false;
+
+    expect(loadSettings().browserHeadless).toBe(true);
+    expect(JSON.parse(fs.readFileSync(getSettingsPath(), 'utf-8'))).toEqual({ browserHeadless: true });
+  });
+
+  it('should notify listeners of the keys a save changed', () => {
+    saveSettings({ browserHeadless: true, locale: 'en' });
+    const changes: SettingsChange[] = [];
+    const unsubscribe = onSettingsChanged((change) => changes.push(change));
+
+    saveSettings({ browserHeadless: true, locale: 'es', submission_verification: 'strict' });
+    saveSettings(loadSettings());
+    unsubscribe();
+    saveSettings({});
+
+    expect(changes).toHaveLength(1);
+    expect(changes[0]!.keys).toEqual(['locale', 'submission_verification']);
+    expect(changes[0]!.settings.submission_verification).toBe('strict');
+  });
+
+  it('should notify listeners of changes found when reloading', () => {
+    saveSettings({ locale: 'en' });
+    const listener = vi.fn();
+    const unsubscribe = onSettingsChanged(listener);
+
+    writeSettingsFile({ locale: 'es' });
+    reloadSettings();
+    unsubscribe();
+
+    expect(listener).toHaveBeenCalledWith({ keys: ['locale'], settings: { locale: 'es' } });
+  });
+
+  it('should keep notifying other listeners when one throws', () => {
+    const failing = onSettingsChanged(() => {
+      throw new Error('boom');
+    });
+    const listener = vi.fn();
+    const unsubscribe = onSettingsChanged(listener);
+
+    saveSettings({ locale: 'es' });
+    failing();
+    unsubscribe();
+
+    expect(listener).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview Shutdown Coordinator Tests
+ *
+ * Tests that quitting cancels background jobs, records interrupted
+ * submissions, closes browsers and closes the database.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+vi.mock('../../src/models', () => ({
+  startRun: vi.fn(() => 42),
+  finishRun: vi.fn(),
+  getInProgressTimesheetEntryIds: vi.fn(() => []),
+  resetInProgressTimesheetEntries: vi.fn(() => 0),
+  shutdownDatabase: vi.fn()
+}));
+
+vi.mock('../../src/services/jobs/job-manager', () => ({
+  getActiveJobs: vi.fn(() => []),
+  cancelJob: vi.fn(() => true),
+  waitForJob: vi.fn(async () => null)
+}));
+
+vi.mock('../../src/services/scheduler/scheduler', () => ({
+  stopScheduler: vi.fn()
+}));
+
+vi.mock('../../src/services/timesheet/submission-workflow', () => ({
+  isTimesheetSubmissionInProgress: vi.fn(() => false),
+  cancelTimesheetSubmission: vi.fn()
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/drafts.autosave', () => ({
+  flushDirtyDrafts: vi.fn(() => [])
+}));
+
+vi.mock('@sheetpilot/bot', () => ({
+  closeAllBrowsers: vi.fn(async () => 1)
+}));
+
+import * as models from '../../src/models';
+import * as jobs from '../../src/services/jobs/job-manager';
+import { closeAllBrowsers } from '@sheetpilot/bot';
+import { flushDirtyDrafts } from '../../src/routes/handlers/timesheet/drafts.autosave';
+import {
+  runShutdown,
+  INTERRUPTED_SUBMISSION_JOB_TYPE
+} from '../../src/services/shutdown/shutdown-coordinator';
+
+const activeJob = (id: string) => ({
+  id,
+  type: 'timesheet-submission',
+  status: 'running' as const,
+  trigger: 'manual' as const,
+  percent: 40,
+  message: null,
+  result: null,
+  error: null,
+  runId: 1,
+  createdAt: '2025-01-01T00:00:00.000Z',
+  startedAt: '2025-01-01T00:00:00.000Z',
+  finishedAt: null
+});
+
+describe('Shutdown Coordinator', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('should cancel active jobs and wait for them', async () => {
+    vi.mocked(jobs.getActiveJobs).mockReturnValueOnce([activeJob('a'), activeJob('b')]).mockReturnValueOnce([]);
+
+    const summary = await runShutdown(100);
+
+    expect(jobs.cancelJob).toHaveBeenCalledWith('a');
+    expect(jobs.cancelJob).toHaveBeenCalledWith('b');
+    expect(jobs.waitForJob).toHaveBeenCalledTimes(2);
+    expect(summary.cancelledJobs).toBe(2);
+    expect(summary.unfinishedJobs).toBe(0);
+  });
+
+  it('should stop waiting after the grace period', async () => {
+    vi.mocked(jobs.getActiveJobs).mockReturnValue([activeJob('stuck')]);
+    vi.mocked(jobs.waitForJob).mockReturnValueOnce(new Promise(() => {}));
+
+    const summary = await runShutdown(10);
+
+    expect(summary.unfinishedJobs).toBe(1);
+    expect(models.shutdownDatabase).toHaveBeenCalled();
+    vi.mocked(jobs.getActiveJobs).mockReturnValue([]);
+  });
+
+  it('should record entries left in progress and reset them', async () => {
+    vi.mocked(models.getInProgressTimesheetEntryIds).mockReturnValueOnce([3, 4]);
+
+    const summary = await runShutdown(10);
+
+    expect(summary.interruptedEntryIds).toEqual([3, 4]);
+    expect(models.startRun).toHaveBeenCalledWith(INTERRUPTED_SUBMISSION_JOB_TYPE, 'manual', { entryIds: [3, 4] });
+    expect(models.finishRun).toHaveBeenCalledWith(42, 'cancelled', { entryIds: [3, 4] }, expect.any(String));
+    expect(models.resetInProgressTimesheetEntries).toHaveBeenCalled();
+  });
+
+  it('should not record anything when no submission was running', async () => {
+    await runShutdown(10);
+
+    expect(models.startRun).not.toHaveBeenCalled();
+    expect(models.resetInProgressTimesheetEntries).not.toHaveBeenCalled();
+  });
+
+  it('should close browsers even if cancelling jobs fails', async () => {
+    vi.mocked(jobs.getActiveJobs).mockImplementationOnce(() => {
+      throw new Error('boom');
+    });
+
+    const summary = await runShutdown(10);
+
+    expect(closeAllBrowsers).toHaveBeenCalled();
+    expect(summary.browsersClosed).toBe(1);
+  });
+
+  it('should write pending draft edits before closing the database', async () => {
+    await runShutdown(10);
+
+    expect(flushDirtyDrafts).toHaveBeenCalled();
+    expect(vi.mocked(flushDirtyDrafts).mock.invocationCallOrder[0]).toBeLessThan(
+      vi.mocked(models.shutdownDatabase).mock.invocationCallOrder[0]!
+    );
+  });
+});
@@ -0,0 +1,111 @@
+/**
+ * @fileoverview Entry Status Counts Tests
+ *
+ * Tests the window title/badge formatting and that the watcher only queries
+ * after database writes and only notifies when a count changes.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+
+vi.mock('../../src/models', () => ({
+  getEntryStatusCounts: vi.fn(() => ({ drafts: 0, failed: 0, submitting: 0 })),
+  getDatabaseChangeCount: vi.fn(() => 0)
+}));
+
+const jobListeners: Array<(job: { type: string }) => void> = [];
+vi.mock('../../src/services/jobs/job-manager', () => ({
+  listJobs: vi.fn(() => []),
+  onJobUpdate: vi.fn((listener: (job: { type: string }) => void) => {
+    jobListeners.push(listener);
+    return () => jobListeners.splice(jobListeners.indexOf(listener), 1);
+  })
+}));
+
+vi.mock('../../src/services/timesheet/submission-workflow', () => ({
+  SUBMISSION_JOB_TYPE: 'timesheet-submission'
+}));
+
+import * as models from '../../src/models';
+import * as jobs from '../../src/services/jobs/job-manager';
+import {
+  formatStatusTitle,
+  getStatusBadgeCount,
+  startStatusCountsWatcher,
+  stopStatusCountsWatcher
+} from '../../src/services/timesheet/status-counts';
+
+const counts = { drafts: 3, failed: 1, submitting: 0, queued: 0 };
+
+describe('Entry Status Counts', () => {
+  describe('formatStatusTitle', () => {
+    it('should append only non-zero counts', () => {
+      expect(formatStatusTitle('Sheetpilot', counts)).toBe('Sheetpilot (3 drafts, 1 failed)');
+      expect(formatStatusTitle('Sheetpilot', { drafts: 1, failed: 0, submitting: 2, queued: 1 })).toBe(
+        'Sheetpilot (2 submitting, 1 queued, 1 draft)'
+      );
+    });
+
+    it('should leave the title alone when nothing is pending', () => {
+      expect(formatStatusTitle('Sheetpilot', { drafts: 0, failed: 0, submitting: 0, queued: 0 })).toBe('Sheetpilot');
+    });
+  });
+
+  it('should badge entries still waiting to be submitted', () => {
+    expect(getStatusBadgeCount(counts)).toBe(4);
+  });
+
+  describe('startStatusCountsWatcher', () => {
+    beforeEach(() => {
+      vi.useFakeTimers();
+      vi.clearAllMocks();
+    });
+
+    afterEach(() => {
+      stopStatusCountsWatcher();
+      vi.useRealTimers();
+    });
+
+    it('should notify once on start and again only after a write changes the counts', () => {
+      const listener = vi.fn();
+      startStatusCountsWatcher(listener, 1000);
+      expect(listener).toHaveBeenCalledTimes(1);
+      expect(listener).toHaveBeenLastCalledWith({ drafts: 0, failed: 0, submitting: 0, queued: 0 });
+
+      // No writes: the entry query is not re-run
+      vi.advanceTimersByTime(3000);
+      expect(models.getEntryStatusCounts).toHaveBeenCalledTimes(1);
+
+      // A write that leaves the counts unchanged is not reported
+      vi.mocked(models.getDatabaseChangeCount).mockReturnValue(1);
+      vi.advanceTimersByTime(1000);
+      expect(models.getEntryStatusCounts).toHaveBeenCalledTimes(2);
+      expect(listener).toHaveBeenCalledTimes(1);
+
+      vi.mocked(models.getDatabaseChangeCount).mockReturnValue(2);
+      vi.mocked(models.getEntryStatusCounts).mockReturnValue({ drafts: 2, failed: 0, submitting: 0 });
+      vi.advanceTimersByTime(1000);
+      expect(listener).toHaveBeenCalledTimes(2);
+      expect(listener).toHaveBeenLastCalledWith({ drafts: 2, failed: 0, submitting: 0, queued: 0 });
+    });
+
+    it('should report queued submissions when a submission job changes', () => {
+      vi.mocked(models.getDatabaseChangeCount).mockReturnValue(5);
+      vi.mocked(models.getEntryStatusCounts).mockReturnValue({ drafts: 2, failed: 0, submitting: 0 });
+      const listener = vi.fn();
+      startStatusCountsWatcher(listener, 1000);
+
+      vi.mocked(jobs.listJobs).mockReturnValue([
+        { type: 'timesheet-submission', status: 'running' },
+        { type: 'timesheet-submission', status: 'queued' },
+        { type: 'scheduled-export', status: 'queued' }
+      ] as ReturnType<typeof jobs.listJobs>);
+      jobListeners.forEach((notify) => notify({ type: 'timesheet-submission' }));
+
+      expect(listener).toHaveBeenLastCalledWith({ drafts: 2, failed: 0, submitting: 0, queued: 1 });
+    });
+  });
+});
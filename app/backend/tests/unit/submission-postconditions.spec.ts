@@ -0,0 +1,59 @@
+/**
+ * @fileoverview Submission Postcondition Tests
+ *
+ * Tests per-profile postcondition resolution from plugin options.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { resolveSubmissionPostconditions } from '../../src/logic/submission-postconditions';
+
+const options = {
+  postconditions: {
+    default: [{ kind: 'url-contains', value: '/confirmation' }],
+    electron: [
+      { kind: 'url-contains', value: '/thanks', description: 'thank-you page' },
+      { kind: 'page-matches', pattern: 'Submission ID:\\s*(\\w+)', capture: 'submissionId' }
+    ]
+  }
+};
+
+describe('Submission Postconditions', () => {
+  it('should use the active profile\'s postconditions', () => {
+    expect(resolveSubmissionPostconditions(options, 'electron')).toEqual([
+      { kind: 'url-contains', value: '/thanks', description: 'thank-you page' },
+      { kind: 'page-matches', pattern: 'Submission ID:\\s*(\\w+)', capture: 'submissionId' }
+    ]);
+  });
+
+  it('should fall back to the default profile', () => {
+    expect(resolveSubmissionPostconditions(options, 'mock')).toEqual([
+      { kind: 'url-contains', value: '/confirmation' }
+    ]);
+  });
+
+  it('should drop unknown kinds and invalid patterns', () => {
+    const resolved = resolveSubmissionPostconditions(
+      {
+        postconditions: {
+          default: [
+            { kind: 'status-code', value: 200 },
+            { kind: 'page-matches', pattern: '(unclosed' },
+            { kind: 'page-matches', pattern: 'Thank you' }
+          ]
+        }
+      },
+      null
+    );
+
+    expect(resolved).toEqual([{ kind: 'page-matches', pattern: 'Thank you' }]);
+  });
+
+  it('should return no postconditions when none are configured', () => {
+    expect(resolveSubmissionPostconditions(undefined, 'electron')).toEqual([]);
+    expect(resolveSubmissionPostconditions({ chargeCodeRules: {} }, 'electron')).toEqual([]);
+  });
+});
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview Submit Job Tests
+ *
+ * Tests what the timesheet:submit job hands back: the submit result with a
+ * partial flag when Smartsheet rejects some entries, and the blocking rule
+ * violations when validation rules stop the run.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach } from 'vitest';
+
+const handlers = new Map<string, (...args: unknown[]) => Promise<unknown>>();
+
+vi.mock('electron', () => ({
+  ipcMain: {
+    handle: vi.fn((channel: string, handler: (...args: unknown[]) => Promise<unknown>) => {
+      handlers.set(channel, handler);
+    })
+  }
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn()
+  },
+  appLogger: {
+    info: vi.fn(),
+    warn: vi.fn(),
+    error: vi.fn(),
+    verbose: vi.fn()
+  }
+}));
+
+vi.mock('@sheetpilot/bot', () => ({
+  getSubmissionRateLimiter: vi.fn()
+}));
+
+vi.mock('../../src/models', () => ({
+  getPendingTimesheetEntries: vi.fn(() => []),
+  getProjectRetirements: vi.fn(() => []),
+  listValidationRules: vi.fn(() => []),
+  startRun: vi.fn(() => 1),
+  finishRun: vi.fn(),
+  getRunByJobId: vi.fn(() => null)
+}));
+
+vi.mock('../../src/services/timesheet/submission-workflow', () => ({
+  cancelTimesheetSubmission: vi.fn(),
+  submitTimesheetWorkflow: vi.fn(),
+  SUBMISSION_JOB_TYPE: 'timesheet-submission'
+}));
+
+vi.mock('../../src/services/runs/run-artifacts', () => ({
+  listRecentRunArtifacts: vi.fn(() => [])
+}));
+
+vi.mock('../../src/services/timesheet/charge-code-quotas', () => ({
+  getChargeCodeQuotaWarnings: vi.fn(() => [])
+}));
+
+vi.mock('../../src/services/timesheet/selector-hotfix', () => ({
+  preflightSelectorHotfix: vi.fn()
+}));
+
+vi.mock('../../src/services/health/clock-skew', () => ({
+  withClockSkewNote: vi.fn((message: string) => message)
+}));
+
+vi.mock('../../src/services/health/environment', () => ({
+  tagEnvironment: vi.fn(<T,>(value: T) => value)
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/main-window', () => ({
+  emitSubmissionProgress: vi.fn(),
+  isTrustedIpcSender: vi.fn(() => true)
+}));
+
+vi.mock('../../src/routes/handlers/timesheet/drafts.autosave', () => ({
+  flushDirtyDrafts: vi.fn()
+}));
+
+import { submitTimesheetWorkflow } from '../../src/services/timesheet/submission-workflow';
+import { waitForJob } from '../../src/services/jobs/job-manager';
+import { registerTimesheetSubmissionHandlers } from '../../src/routes/handlers/timesheet/submission';
+
+const submit = async () => {
+  const { jobId } = (await handlers.get('timesheet:submit')!({}, 'valid-token')) as { jobId: string };
+  return waitForJob(jobId);
+};
+
+describe('timesheet:submit job', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    registerTimesheetSubmissionHandlers();
+  });
+
+  it('should return the submit result with a partial flag when entries are rejected', async () => {
+    const submitResult = {
+      ok: false,
+      successCount: 2,
+      removedCount: 0,
+      totalProcessed: 3,
+      rejectedEntries: [{ entryId: 3, message: 'Hours must be a number', banner: null, fieldErrors: {} }]
+    };
+    vi.mocked(submitTimesheetWorkflow).mockResolvedValue({ submitResult, dbPath: '/tmp/sheetpilot.sqlite' });
+
+    const job = await submit();
+
+    expect(job?.status).toBe('succeeded');
+    expect(job?.result).toEqual({ submitResult, dbPath: '/tmp/sheetpilot.sqlite', partial: true });
+  });
+
+  it('should keep the rule violations when validation rules block the run', async () => {
+    const ruleViolations = [
+      { entryId: 1, ruleId: 4, ruleName: 'No weekend hours', field: 'date' as const, action: 'block' as const, message: 'No weekend hours' }
+    ];
+    vi.mocked(submitTimesheetWorkflow).mockResolvedValue({
+      error: '1 entry breaks a blocking validation rule: No weekend hours',
+      ruleViolations
+    });
+
+    const job = await submit();
+
+    expect(job?.status).toBe('failed');
+    expect(job?.error).toMatch(/blocking validation rule/);
+    expect(job?.result).toEqual({ ruleViolations });
+  });
+});
@@ -0,0 +1,31 @@
+/**
+ * @fileoverview Tool Usage CSV Tests
+ *
+ * Tests for the tool usage aggregate CSV export.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { buildToolUsageCsv } from '../../src/services/timesheet/csv-export';
+
+describe('buildToolUsageCsv', () => {
+  it('should write a header and one row per project/tool group', () => {
+    const csv = buildToolUsageCsv([
+      { project: 'FL-Carver Techs', tool: 'TOOL-17', hours: 12.5, entryCount: 4 },
+      { project: 'Training', tool: null, hours: 2, entryCount: 1 }
+    ]);
+    expect(csv.split('\n')).toEqual([
+      'Project,Tool,Hours,Entries',
+      '"FL-Carver Techs","TOOL-17",12.50,4',
+      '"Training","",2.00,1'
+    ]);
+  });
+
+  it('should escape embedded quotes', () => {
+    const csv = buildToolUsageCsv([{ project: 'A "B"', tool: 'X', hours: 1, entryCount: 1 }]);
+    expect(csv.split('\n')[1]).toBe('"A ""B""","X",1.00,1');
+  });
+});
@@ -0,0 +1,88 @@
+/**
+ * @fileoverview User Preferences Tests
+ *
+ * Tests that preferences are kept per session email, that null resets a
+ * preference, and that the machine theme is only a fallback.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import * as fs from 'fs';
+import * as path from 'path';
+import * as os from 'os';
+
+let userDataDir = '';
+let storedSettings: Record<string, unknown> = {};
+
+vi.mock('electron', () => ({
+  app: {
+    getPath: vi.fn(() => userDataDir)
+  }
+}));
+
+vi.mock('../../src/services/settings/settings-store', () => ({
+  loadSettings: vi.fn(() => structuredClone(storedSettings))
+}));
+
+vi.mock('@sheetpilot/shared/logger', () => ({
+  ipcLogger: {
+    error: vi.fn()
+  }
+}));
+
+import {
+  getPreferencesPath,
+  getUserPreferences,
+  updateUserPreferences
+} from '../../src/services/settings/user-preferences';
+
+describe('User Preferences', () => {
+  beforeEach(() => {
+    userDataDir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-preferences-'));
+    storedSettings = {};
+  });
+
+  afterEach(() => {
+    fs.rmSync(userDataDir, { recursive: true, force: true });
+  });
+
+  it('should keep each user\'s preferences separate', () => {
+    updateUserPreferences('alice@company.com', { weekStart: 'monday', defaultProject: 'OSC-BBB' });
+    updateUserPreferences('bob@company.com', { weekStart: 'sunday' });
+
+    expect(getUserPreferences('Alice@Company.com ')).toEqual({
+      weekStart: 'monday',
+      defaultProject: 'OSC-BBB'
+    });
+    expect(getUserPreferences('bob@company.com')).toEqual({ weekStart: 'sunday' });
+  });
+
+  it('should reset a preference when it is set to null', () => {
+    updateUserPreferences('alice@company.com', { hoursRounding: 0.5, weekStart: 'monday' });
+
+    const preferences = updateUserPreferences('alice@company.com', { hoursRounding: null });
+
+    expect(preferences).toEqual({ weekStart: 'monday' });
+  });
+
+  it('should fall back to the machine theme until the user picks one', () => {
+    storedSettings = { themeMode: 'dark' };
+    expect(getUserPreferences('alice@company.com')).toEqual({ themeMode: 'dark' });
+
+    updateUserPreferences('alice@company.com', { themeMode: 'light' });
+    expect(getUserPreferences('alice@company.com').themeMode).toBe('light');
+    expect(getUserPreferences('bob@company.com').themeMode).toBe('dark');
+  });
+
+  it('should drop stored values that no longer validate', () => {
+    fs.writeFileSync(
+      getPreferencesPath(),
+      JSON.stringify({ 'alice@company.com': { weekStart: 'friday', hoursRounding: 1 } })
+    );
+
+    expect(getUserPreferences('alice@company.com')).toEqual({ hoursRounding: 1 });
+  });
+});
@@ -0,0 +1,136 @@
+/**
+ * @fileoverview Accessible Weekly Summary Tests
+ *
+ * Tests the screen reader friendly weekly summary in plain text and Markdown.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  buildAccessibleWeeklySummary,
+  describeHours,
+  type WeeklySummarySourceRow
+} from '../../src/services/timesheet/weekly-summary-export';
+
+const row = (overrides: Partial<WeeklySummarySourceRow> & { id: number }): WeeklySummarySourceRow => ({
+  date: '2025-03-03',
+  hours: 2,
+  project: 'FL-Carver Techs',
+  tool: 'DECA Meeting',
+  detail_charge_code: 'EPR1',
+  task_description: 'Review',
+  status: 'Complete',
+  amends_id: null,
+  ...overrides
+});
+
+describe('Accessible Weekly Summary', () => {
+  it('should write hours without padding', () => {
+    expect(describeHours(1)).toBe('1 hour');
+    expect(describeHours(1.5)).toBe('1.5 hours');
+    expect(describeHours(0)).toBe('0 hours');
+  });
+
+  it('should label every day and describe each entry in one sentence', () => {
+    const summary = buildAccessibleWeeklySummary(
+      '2025-03-03',
+      [
+        row({ id: 1 }),
+        row({ id: 2, date: '03/03/2025', hours: 1.5, tool: null, task_description: 'Planning.' }),
+        row({ id: 3, date: '2025-03-05', project: 'OSC-BBB', detail_charge_code: null })
+      ],
+      'text'
+    );
+    const lines = summary.content.split('\n');
+
+    expect(summary.entryCount).toBe(3);
+    expect(summary.totalHours).toBe(5.5);
+    expect(lines[0]).toBe('Weekly summary, Monday, March 3, 2025 to Sunday, March 9, 2025');
+    expect(lines).toContain('Total: 5.5 hours in 3 entries.');
+    expect(lines).toContain('Monday, March 3, 2025, 3.5 hours in 2 entries');
+    expect(lines).toContain(
+      'Entry 1 of 2: 2 hours. Project: FL-Carver Techs. Tool: DECA Meeting. Charge code: EPR1. Description: Review.'
+    );
+    expect(lines).toContain(
+      'Entry 2 of 2: 1.5 hours. Project: FL-Carver Techs. Charge code: EPR1. Description: Planning.'
+    );
+    expect(lines).toContain('Tuesday, March 4, 2025, no entries');
+    expect(lines).toContain('Sunday, March 9, 2025, no entries');
+    expect(lines).toContain('OSC-BBB: 2 hours.');
+    expect(summary.content).not.toContain('|');
+  });
+
+  it('should use headings and list items in Markdown', () => {
+    const summary = buildAccessibleWeeklySummary('2025-03-03', [row({ id: 1 })], 'markdown');
+
+    expect(summary.content).toContain('# Weekly summary, Monday, March 3, 2025 to Sunday, March 9, 2025');
+    expect(summary.content).toContain('## Monday, March 3, 2025, 2 hours in 1 entry');
+    expect(summary.content).toContain('- Entry 1 of 1: 2 hours.');
+    expect(summary.content).toContain('## Hours by project\n- FL-Carver Techs: 2 hours.');
+  });
+
+  it('should group hours by category only when entries have one', () => {
+    const uncategorized = buildAccessibleWeeklySummary('2025-03-03', [row({ id: 1 })], 'text');
+    expect(uncategorized.content).not.toContain('Hours by category');
+
+    const summary = buildAccessibleWeeklySummary(
+      '2025-03-03',
+      [
+        row({ id: 1, category: 'Production support' }),
+        row({ id: 2, hours: 1, category: 'Production support' }),
+        row({ id: 3, hours: 0.5 })
+      ],
+      'markdown'
+    );
+    expect(summary.content).toContain('Description: Review. Category: Production support.');
+    expect(summary.content).toContain('## Hours by category\n- No category: 0.5 hours.\n- Production support: 3 hours.');
+  });
+
+  it('should name time off and total it apart from work', () => {
+    const workOnly = buildAccessibleWeeklySummary('2025-03-03', [row({ id: 1 })], 'text');
+    expect(workOnly.content).not.toContain('Hours by kind');
+
+    const summary = buildAccessibleWeeklySummary(
+      '2025-03-03',
+      [
+        row({ id: 1, hours: 4 }),
+        row({ id: 2, hours: 4, project: null, tool: null, detail_charge_code: null, task_description: 'Half day', entry_kind: 'pto' })
+      ],
+      'markdown'
+    );
+    expect(summary.content).toContain('- Entry 2 of 2: 4 hours. Kind: PTO. Description: Half day.');
+    expect(summary.content).toContain('## Hours by kind\n- PTO: 4 hours.\n- Work: 4 hours.');
+  });
+
+  it('should show the latest version of corrected entries', () => {
+    const rows = [
+      row({ id: 1, hours: 1 }),
+      row({ id: 2, amends_id: 1, hours: 2 }),
+      row({ id: 3, status: null, amends_id: 2, hours: 4 }),
+      row({ id: 4, status: 'in_progress', date: '2025-03-04' })
+    ];
+
+    const submitted = buildAccessibleWeeklySummary('2025-03-03', rows, 'text');
+    expect(submitted.entryCount).toBe(1);
+    expect(submitted.totalHours).toBe(2);
+
+    const withDrafts = buildAccessibleWeeklySummary('2025-03-03', rows, 'text', true);
+    expect(withDrafts.totalHours).toBe(4);
+    expect(withDrafts.content).toContain('Draft, not submitted yet.');
+  });
+
+  it('should leave out entries outside the week', () => {
+    const summary = buildAccessibleWeeklySummary(
+      '2025-03-03',
+      [row({ id: 1, date: '2025-03-10' }), row({ id: 2, date: null })],
+      'text'
+    );
+
+    expect(summary.entryCount).toBe(0);
+    expect(summary.content).toContain('Total: 0 hours in 0 entries.');
+    expect(summary.content).not.toContain('Hours by project');
+  });
+});
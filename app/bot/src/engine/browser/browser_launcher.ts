@@ -12,6 +12,7 @@
  */
 import { chromium, type Browser } from "playwright";
 import * as cfg from "../config/automation_config";
+import { checkBrowserVersion, type BrowserVersionCheck } from "./browser_version";
 import { botLogger } from "@sheetpilot/shared/logger";
 
 type BrowserProcessInfo = {
@@ -65,9 +66,34 @@ function getSpawnedExecutablePath(browser: Browser): string | null {
   return null;
 }
 
+/** Browsers launched by any BrowserLauncher that have not been closed yet */
+const openBrowsers = new Set<Browser>();
+
+/**
+ * Closes every browser still open, e.g. during application shutdown.
+ *
+ * @returns Number of browsers that were open
+ */
+export async function closeAllBrowsers(): Promise<number> {
+  const browsers = [...openBrowsers];
+  openBrowsers.clear();
+  await Promise.all(
+    browsers.map((browser) =>
+      browser.close().catch((err) =>
+        botLogger.warn("Could not close browser", {
+          error: err instanceof Error ? err.message : String(err),
+        })
+      )
+    )
+  );
+  return browsers.length;
+}
+
 export class BrowserLauncher {
   private browser: Browser | null = null;
   private readonly headless: boolean;
+  /** Version check of the launched browser (null before launch) */
+  private versionCheck: BrowserVersionCheck | null = null;
 
   constructor(headless: boolean) {
     this.headless = headless;
@@ -114,6 +140,7 @@ export class BrowserLauncher {
       });
       throw new Error(`Could not launch browser: ${errorMessage}`);
     }
+    openBrowsers.add(this.browser);
 
     const spawnedExecutablePath = getSpawnedExecutablePath(this.browser);
     const playwrightChromiumExecutablePath = chromium.executablePath();
@@ -130,11 +157,37 @@ export class BrowserLauncher {
         spawnedExecutablePath === playwrightChromiumExecutablePath,
     });
 
+    this.versionCheck = checkBrowserVersion(this.browser.version());
+    const { version, major, status, message } = this.versionCheck;
+    if (status === "unsupported") {
+      botLogger.error("Browser version is below the supported minimum", {
+        channel,
+        version,
+        major,
+      });
+      await this.closeAll();
+      throw new Error(message ?? `Browser version ${version} is not supported`);
+    }
+    if (status !== "tested") {
+      botLogger.warn("Browser version is outside the tested range", {
+        channel,
+        version,
+        status,
+      });
+    } else {
+      botLogger.verbose("Browser version is tested", { channel, version });
+    }
+
     return this.browser;
   }
 
+  getVersionCheck(): BrowserVersionCheck | null {
+    return this.versionCheck;
+  }
+
   async closeAll(): Promise<void> {
     if (!this.browser) return;
+    openBrowsers.delete(this.browser);
     await this.browser.close().catch((err) =>
       botLogger.warn("Could not close browser", {
         error: err instanceof Error ? err.message : String(err),
@@ -0,0 +1,79 @@
+/**
+ * Browser version compatibility.
+ *
+ * Lab PCs often run Chrome builds that are months or years behind, and old
+ * builds break CDP calls in ways that look like flaky selectors. After
+ * launch the bot compares the browser's major version with the configured
+ * range (see BROWSER_MIN_VERSION and BROWSER_TESTED_VERSIONS):
+ *
+ * - below the minimum: the run is refused with an error saying what to update
+ * - outside the tested range: the run goes ahead with a warning that is
+ *   logged and kept in the run report
+ */
+import * as cfg from "../config/automation_config";
+
+export type BrowserVersionStatus = "tested" | "untested" | "unsupported" | "unknown";
+
+export interface BrowserVersionRange {
+  minimum: number;
+  testedMin: number;
+  testedMax: number;
+}
+
+export interface BrowserVersionCheck {
+  /** Version string reported by the browser, e.g. "120.0.6099.28" */
+  version: string;
+  major: number | null;
+  status: BrowserVersionStatus;
+  /** Warning or error to show; null when the version is tested */
+  message: string | null;
+}
+
+export function getConfiguredBrowserVersionRange(): BrowserVersionRange {
+  return {
+    minimum: cfg.BROWSER_MIN_VERSION,
+    testedMin: cfg.BROWSER_TESTED_VERSIONS.min,
+    testedMax: cfg.BROWSER_TESTED_VERSIONS.max,
+  };
+}
+
+/**
+ * Major version from a browser version string ("120.0.6099.28",
+ * "HeadlessChrome/120.0.6099.28"); null if there is none
+ */
+export function parseBrowserMajorVersion(version: string): number | null {
+  const match = /(?:^|\/)(\d+)\.\d+/.exec(version.trim());
+  return match ? Number(match[1]) : null;
+}
+
+export function checkBrowserVersion(
+  version: string,
+  range: BrowserVersionRange = getConfiguredBrowserVersionRange()
+): BrowserVersionCheck {
+  const major = parseBrowserMajorVersion(version);
+  if (major === null) {
+    return {
+      version,
+      major,
+      status: "unknown",
+      message: `Could not tell which browser version is installed ("${version}"); SheetPilot is tested with Chrome ${range.testedMin}-${range.testedMax}`,
+    };
+  }
+  if (major < range.minimum) {
+    return {
+      version,
+      major,
+      status: "unsupported",
+      message: `Chrome ${major} is too old for SheetPilot. Update Chrome to version ${range.minimum} or newer and try again.`,
+    };
+  }
+  if (major < range.testedMin || major > range.testedMax) {
+    return {
+      version,
+      major,
+      status: "untested",
+      message: `Chrome ${major} has not been tested with SheetPilot (tested with ${range.testedMin}-${range.testedMax}); if submissions misbehave, try a tested version`,
+    };
+  }
+  return { version, major, status: "tested", message: null };
+}
@@ -0,0 +1,82 @@
+/**
+ * Dropdown disambiguation from past selections.
+ *
+ * Typing a value into a Smartsheet combobox filters its options, and the bot
+ * then presses Enter to take the first one. When the filter matches several
+ * options ("FAB" matches three projects) the first one is not always the one
+ * the user meant. The desktop app keeps which option was confirmed for a typed
+ * value (a row using it was submitted) and configures a store here; on later
+ * runs the bot clicks that exact option instead of taking the first.
+ *
+ * Only ambiguous fills are remembered, and a remembered option is only used
+ * when it is still one of the exact option texts on offer.
+ */
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export interface DropdownResolution {
+  /** Field key (see FIELD_DEFINITIONS), e.g. "project_code" */
+  fieldKey: string;
+  /** Value the bot typed into the field */
+  typedValue: string;
+  /** Option text that was selected */
+  optionText: string;
+  /** Option texts the typed value matched */
+  candidates: string[];
+}
+
+export interface DropdownResolutionStore {
+  /** Previously confirmed option for a typed value, or null */
+  lookup(fieldKey: string, typedValue: string): string | null;
+  /** Called once a row that used the resolution was submitted */
+  confirm(resolution: DropdownResolution): void;
+}
+
+let activeStore: DropdownResolutionStore | null = null;
+
+/**
+ * Sets the store used by bots from now on; null turns disambiguation off
+ */
+export function configureDropdownResolutions(store: DropdownResolutionStore | null): void {
+  activeStore = store;
+}
+
+export function getDropdownResolutionStore(): DropdownResolutionStore | null {
+  return activeStore;
+}
+
+/**
+ * Picks the option to select from the ones a typed value matched
+ *
+ * @param candidates - Option texts in the order the dropdown lists them
+ * @param preferred - Previously confirmed option text, if any
+ * @returns Index of the option to click, or null to keep the default (first) option
+ */
+export function chooseDropdownOption(
+  candidates: string[],
+  preferred: string | null
+): number | null {
+  if (candidates.length < 2 || !preferred) return null;
+  const index = candidates.findIndex((text) => text.trim() === preferred.trim());
+  return index > 0 ? index : null;
+}
+
+/**
+ * Passes a row's ambiguous dropdown selections to the store once it was submitted
+ */
+export function confirmDropdownResolutions(
+  resolutions: Iterable<DropdownResolution>,
+  store: DropdownResolutionStore | null = activeStore
+): void {
+  if (!store) return;
+  for (const resolution of resolutions) {
+    try {
+      store.confirm(resolution);
+    } catch (err: unknown) {
+      // Remembering a choice must never fail a submitted row
+      botLogger.warn("Could not record dropdown resolution", {
+        fieldKey: resolution.fieldKey,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+}
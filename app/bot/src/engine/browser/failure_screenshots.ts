@@ -0,0 +1,61 @@
+/**
+ * Failure screenshots.
+ *
+ * When a row fails, the orchestrator captures the page so support can see what
+ * the form looked like. Screenshots are written only once a directory has been
+ * configured (the desktop app points this at its run artifact folder) or
+ * SCREENSHOT_DIR is set, and only while ENABLE_SCREENSHOTS and
+ * SCREENSHOT_ON_FAILURE are on.
+ */
+import * as fs from "fs";
+import * as path from "path";
+import type { Page } from "playwright";
+import * as cfg from "../config/automation_config";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+let screenshotDirectory: string | null = process.env["SCREENSHOT_DIR"] ?? null;
+
+/**
+ * Sets where failure screenshots are written; null turns them off
+ */
+export function configureFailureScreenshots(directory: string | null): void {
+  screenshotDirectory = directory;
+  botLogger.verbose("Failure screenshot directory configured", { directory });
+}
+
+export function getFailureScreenshotDirectory(): string | null {
+  return screenshotDirectory;
+}
+
+/**
+ * Saves a full-page screenshot for a failed row
+ *
+ * @param name - File name stem; unsafe characters are replaced
+ * @returns Path of the saved file, or null when disabled or the capture failed
+ */
+export async function captureFailureScreenshot(
+  page: Page,
+  name: string
+): Promise<string | null> {
+  if (
+    !screenshotDirectory ||
+    !cfg.ENABLE_FAILURE_SCREENSHOTS ||
+    !cfg.SCREENSHOT_ON_SUBMIT_FAILURE
+  ) {
+    return null;
+  }
+  const fileName = `${name.replace(/[^A-Za-z0-9._-]/g, "_")}-${Date.now()}.png`;
+  const filePath = path.join(screenshotDirectory, fileName);
+  try {
+    await fs.promises.mkdir(screenshotDirectory, { recursive: true });
+    await page.screenshot({ path: filePath, fullPage: true, timeout: 5000 });
+    botLogger.info("Captured failure screenshot", { filePath });
+    return filePath;
+  } catch (err: unknown) {
+    botLogger.warn("Could not capture failure screenshot", {
+      filePath,
+      error: err instanceof Error ? err.message : String(err),
+    });
+    return null;
+  }
+}
@@ -0,0 +1,173 @@
+/**
+ * Form feedback scraping.
+ *
+ * When a submit is not confirmed, Smartsheet may have rejected the entry with
+ * a validation banner and per-field error markers. This module reads that
+ * feedback off the page and maps field errors back to our field keys, so the
+ * orchestrator can report why a row was rejected instead of retrying blindly.
+ */
+import type { Page } from "playwright";
+import * as cfg from "../config/automation_config";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export interface FormFeedback {
+  /** Form-level error banner text, if one was shown */
+  banner: string | null;
+  /** Error text per field key (e.g. `hours`) */
+  fieldErrors: Record<string, string>;
+  /** Field error markers that could not be matched to a field */
+  unmatchedErrors: string[];
+}
+
+const MAX_TEXT_LENGTH = 300;
+
+const cleanText = (text: string | null | undefined): string =>
+  (text ?? "").replace(/\s+/g, " ").trim().slice(0, MAX_TEXT_LENGTH);
+
+/**
+ * Whether the page showed any rejection feedback
+ */
+export function hasFormFeedback(feedback: FormFeedback): boolean {
+  return (
+    feedback.banner !== null ||
+    Object.keys(feedback.fieldErrors).length > 0 ||
+    feedback.unmatchedErrors.length > 0
+  );
+}
+
+/**
+ * Field key whose label appears in an error marker's text; longer labels win
+ * so "Detail Charge Code" is not mistaken for a shorter label it contains
+ */
+export function matchErrorToField(
+  text: string,
+  fieldDefinitions: Record<string, cfg.FieldDefinition>
+): string | null {
+  const lower = text.toLowerCase();
+  const candidates = Object.entries(fieldDefinitions)
+    .filter(([, def]) => def.label && lower.includes(def.label.toLowerCase()))
+    .sort(([, a], [, b]) => b.label.length - a.label.length);
+  return candidates[0]?.[0] ?? null;
+}
+
+/**
+ * One-line failure reason, e.g.
+ * `Smartsheet rejected the entry: Fix the errors below (Hours: Must be a number)`
+ */
+export function formatFormFeedback(
+  feedback: FormFeedback,
+  fieldDefinitions: Record<string, cfg.FieldDefinition>
+): string {
+  const details = [
+    ...Object.entries(feedback.fieldErrors).map(
+      ([key, error]) => `${fieldDefinitions[key]?.label ?? key}: ${error}`
+    ),
+    ...feedback.unmatchedErrors,
+  ];
+  const banner = feedback.banner ?? "validation failed";
+  return details.length > 0
+    ? `Smartsheet rejected the entry: ${banner} (${details.join("; ")})`
+    : `Smartsheet rejected the entry: ${banner}`;
+}
+
+const readVisibleTexts = async (
+  page: Page,
+  selector: string
+): Promise<string[]> => {
+  const texts: string[] = [];
+  try {
+    const locator = page.locator(selector);
+    const count = await locator.count();
+    for (let i = 0; i < count; i++) {
+      const item = locator.nth(i);
+      if (!(await item.isVisible().catch(() => false))) continue;
+      const text = cleanText(await item.textContent().catch(() => null));
+      if (text) texts.push(text);
+    }
+  } catch {
+    // ignore selector errors; markers vary across forms
+  }
+  return texts;
+};
+
+/**
+ * Error text referenced by an invalid field's aria-errormessage or
+ * aria-describedby, or a generic note when the field is only marked invalid
+ */
+const readFieldError = async (
+  page: Page,
+  def: cfg.FieldDefinition
+): Promise<string | null> => {
+  const field = page.locator(def.locator).first();
+  const ariaInvalid = await field
+    .getAttribute("aria-invalid", { timeout: 500 })
+    .catch(() => null);
+  if (!ariaInvalid || ariaInvalid === "false") return null;
+
+  for (const attribute of ["aria-errormessage", "aria-describedby"]) {
+    const ids = await field
+      .getAttribute(attribute, { timeout: 500 })
+      .catch(() => null);
+    for (const id of ids?.split(/\s+/).filter(Boolean) ?? []) {
+      const text = cleanText(
+        await page
+          .locator(`[id="${id}"]`)
+          .first()
+          .textContent({ timeout: 500 })
+          .catch(() => null)
+      );
+      if (text) return text;
+    }
+  }
+  return "Marked invalid";
+};
+
+/**
+ * Reads the error banner and field error markers from the current page
+ */
+export async function scrapeFormFeedback(
+  page: Page,
+  fieldDefinitions: Record<string, cfg.FieldDefinition>
+): Promise<FormFeedback> {
+  const feedback: FormFeedback = {
+    banner: null,
+    fieldErrors: {},
+    unmatchedErrors: [],
+  };
+
+  for (const selector of cfg.FORM_ERROR_BANNER_SELECTORS) {
+    const [text] = await readVisibleTexts(page, selector);
+    if (text) {
+      feedback.banner = text;
+      break;
+    }
+  }
+
+  for (const [key, def] of Object.entries(fieldDefinitions)) {
+    const error = await readFieldError(page, def).catch(() => null);
+    if (error) feedback.fieldErrors[key] = error;
+  }
+
+  const seen = new Set<string>(Object.values(feedback.fieldErrors));
+  for (const selector of cfg.FIELD_ERROR_SELECTORS) {
+    for (const text of await readVisibleTexts(page, selector)) {
+      if (seen.has(text) || text === feedback.banner) continue;
+      seen.add(text);
+      const key = matchErrorToField(text, fieldDefinitions);
+      if (key && !feedback.fieldErrors[key]) {
+        feedback.fieldErrors[key] = text;
+      } else if (!key) {
+        feedback.unmatchedErrors.push(text);
+      }
+    }
+  }
+
+  if (hasFormFeedback(feedback)) {
+    botLogger.warn("Form shows validation errors after submit", {
+      banner: feedback.banner,
+      fieldErrors: feedback.fieldErrors,
+      unmatchedErrors: feedback.unmatchedErrors,
+    });
+  }
+  return feedback;
+}
@@ -0,0 +1,226 @@
+/**
+ * Form inspection for field mapping.
+ *
+ * Building the field mapping for a new form (a quarter's `fieldOverrides`)
+ * used to mean reading the form's markup in devtools. `inspectForm` loads the
+ * form in the bot browser, lists its labeled inputs with their aria
+ * attributes and a suggested locator, and matches them to our field keys by
+ * label, so the admin UI can build the mapping interactively.
+ *
+ * Only public forms can be inspected; a form that redirects to sign-in is
+ * reported as an error rather than logged into.
+ */
+import type { Page } from "playwright";
+import * as cfg from "../config/automation_config";
+import type { QuarterFieldOverride } from "../config/quarter_config";
+import { BrowserLauncher } from "./browser_launcher";
+import { WebformSessionManager } from "./webform_session";
+import { matchErrorToField } from "./form_feedback";
+import { isAuthRedirectUrl } from "../../scripts/utils/authentication_flow";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+/** Input attributes as read off the page */
+export interface RawFormInput {
+  tag: string;
+  /** `type` attribute of inputs, e.g. "text" */
+  type: string | null;
+  role: string | null;
+  id: string | null;
+  name: string | null;
+  placeholder: string | null;
+  ariaLabel: string | null;
+  /** Text of the elements referenced by aria-labelledby */
+  ariaLabelledByText: string | null;
+  /** Text of the <label> for the input */
+  labelText: string | null;
+  ariaRequired: boolean;
+  required: boolean;
+}
+
+export interface InspectedFormInput extends RawFormInput {
+  /** Best human-readable label (label, aria-labelledby, aria-label, placeholder) */
+  label: string;
+  /** Locator in the style of FIELD_DEFINITIONS, or null if nothing stable identifies it */
+  suggestedLocator: string | null;
+  /** Field key whose label matches, e.g. "hours" */
+  fieldKey: string | null;
+}
+
+export interface FormInspection {
+  formUrl: string;
+  formId: string;
+  title: string;
+  inputs: InspectedFormInput[];
+  /** Locators by field key for a quarter's `fieldOverrides` */
+  suggestedFieldOverrides: Record<string, QuarterFieldOverride>;
+  /** Required field keys no input was matched to */
+  missingFields: string[];
+}
+
+export interface InspectFormOptions {
+  headless?: boolean;
+}
+
+const MAX_LABEL_LENGTH = 120;
+
+const cleanLabel = (text: string | null | undefined): string =>
+  (text ?? "")
+    .replace(/\s+/g, " ")
+    .replace(/\s*\*\s*$/, "")
+    .trim()
+    .slice(0, MAX_LABEL_LENGTH);
+
+/** Attribute value quoted for a locator, e.g. `'Hours'` */
+const quoteAttribute = (value: string): string =>
+  `'${value.replace(/\\/g, "\\\\").replace(/'/g, "\\'")}'`;
+
+/**
+ * Label a person would read for the input
+ */
+export function resolveInputLabel(input: RawFormInput): string {
+  return (
+    cleanLabel(input.labelText) ||
+    cleanLabel(input.ariaLabelledByText) ||
+    cleanLabel(input.ariaLabel) ||
+    cleanLabel(input.placeholder)
+  );
+}
+
+/**
+ * Locator for an input, preferring what survives markup changes: aria-label,
+ * then role and accessible name, then id, name and placeholder
+ */
+export function suggestLocator(input: RawFormInput): string | null {
+  const tag = input.tag.toLowerCase();
+  if (input.ariaLabel?.trim()) {
+    return `${tag}[aria-label=${quoteAttribute(input.ariaLabel.trim())}]`;
+  }
+  const accessibleName = cleanLabel(input.ariaLabelledByText) || cleanLabel(input.labelText);
+  if (input.role && accessibleName) {
+    return `role=${input.role}[name=${quoteAttribute(accessibleName)}]`;
+  }
+  if (input.id) {
+    return /^[A-Za-z][\w-]*$/.test(input.id)
+      ? `#${input.id}`
+      : `${tag}[id=${quoteAttribute(input.id)}]`;
+  }
+  if (input.name) {
+    return `${tag}[name=${quoteAttribute(input.name)}]`;
+  }
+  if (input.placeholder) {
+    return `${tag}[placeholder=${quoteAttribute(input.placeholder)}]`;
+  }
+  return null;
+}
+
+/**
+ * Labels, locators and field key matches for inputs read off a form; each
+ * field key goes to the first input whose label matches it
+ */
+export function buildFormInspection(
+  rawInputs: RawFormInput[],
+  form: { formUrl: string; formId: string; title: string },
+  fieldDefinitions: Record<string, cfg.FieldDefinition> = cfg.FIELD_DEFINITIONS
+): FormInspection {
+  const suggestedFieldOverrides: Record<string, QuarterFieldOverride> = {};
+  const inputs = rawInputs.map((raw): InspectedFormInput => {
+    const label = resolveInputLabel(raw);
+    const suggestedLocator = suggestLocator(raw);
+    const matchedKey = label ? matchErrorToField(label, fieldDefinitions) : null;
+    const fieldKey =
+      matchedKey && !suggestedFieldOverrides[matchedKey] ? matchedKey : null;
+    if (fieldKey && suggestedLocator) {
+      suggestedFieldOverrides[fieldKey] = { locator: suggestedLocator };
+    }
+    return { ...raw, label, suggestedLocator, fieldKey };
+  });
+  const missingFields = Object.entries(fieldDefinitions)
+    .filter(([key, def]) => !def.optional && !suggestedFieldOverrides[key])
+    .map(([key]) => key);
+  return { ...form, inputs, suggestedFieldOverrides, missingFields };
+}
+
+/**
+ * Reads every visible input-like element of the page's form
+ */
+async function readFormInputs(page: Page): Promise<RawFormInput[]> {
+  return page.evaluate(() => {
+    const selector =
+      "input, textarea, select, [role='textbox'], [role='combobox'], [contenteditable='true']";
+    const skippedTypes = ["hidden", "submit", "button", "reset", "image"];
+    const textOf = (ids: string | null): string | null => {
+      const text = (ids ?? "")
+        .split(/\s+/)
+        .filter(Boolean)
+        .map((id) => document.getElementById(id)?.textContent ?? "")
+        .join(" ")
+        .trim();
+      return text || null;
+    };
+    const root = document.querySelector("form") ?? document.body;
+    const candidates = Array.from(root.querySelectorAll<HTMLElement>(selector));
+    return candidates
+      .filter((el) => el.getClientRects().length > 0)
+      // A combobox wrapper around an input is described by the input itself
+      .filter((el) => !candidates.some((other) => other !== el && el.contains(other)))
+      .filter((el) => !skippedTypes.includes((el.getAttribute("type") ?? "").toLowerCase()))
+      .map((el) => {
+        const labels = (el as HTMLInputElement).labels;
+        const labelText =
+          labels?.[0]?.textContent ?? el.closest("label")?.textContent ?? null;
+        return {
+          tag: el.tagName.toLowerCase(),
+          type: el.getAttribute("type"),
+          role: el.getAttribute("role"),
+          id: el.id || null,
+          name: el.getAttribute("name"),
+          placeholder: el.getAttribute("placeholder"),
+          ariaLabel: el.getAttribute("aria-label"),
+          ariaLabelledByText: textOf(el.getAttribute("aria-labelledby")),
+          labelText: labelText?.trim() || null,
+          ariaRequired: el.getAttribute("aria-required") === "true",
+          required: el.hasAttribute("required"),
+        };
+      });
+  });
+}
+
+/**
+ * Loads a form in the bot browser and lists its inputs for field mapping
+ *
+ * @throws Error if the form cannot be loaded or requires sign-in
+ */
+export async function inspectForm(
+  baseUrl: string,
+  formId: string,
+  options: InspectFormOptions = {}
+): Promise<FormInspection> {
+  const formConfig = cfg.createFormConfig(baseUrl, formId);
+  const launcher = new BrowserLauncher(options.headless ?? true);
+  botLogger.info("Inspecting form for field mapping", { formUrl: baseUrl, formId });
+  try {
+    const browser = await launcher.launch();
+    const sessions = new WebformSessionManager(browser, formConfig);
+    await sessions.initContexts(1);
+    await sessions.navigateToBase();
+    const page = sessions.getDefaultPage();
+    if (isAuthRedirectUrl(page.url())) {
+      throw new Error("The form requires sign-in; only public forms can be inspected");
+    }
+    await sessions.waitForFormReady();
+    const inspection = buildFormInspection(await readFormInputs(page), {
+      formUrl: baseUrl,
+      formId,
+      title: cleanLabel(await page.title()),
+    });
+    botLogger.info("Form inspected", {
+      formId,
+      inputCount: inspection.inputs.length,
+      matchedFields: Object.keys(inspection.suggestedFieldOverrides),
+      missingFields: inspection.missingFields,
+    });
+    return inspection;
+  } finally {
+    await launcher.closeAll();
+  }
+}
@@ -11,6 +11,18 @@
  */
 import type { Locator, Page } from "playwright";
 import * as cfg from "../config/automation_config";
+import {
+  getActiveFillProfile,
+  type FillSpeedProfile,
+} from "../config/fill_profiles";
+import type { StepAnnotator } from "./step_annotator";
+import type { RunRecorder } from "./run_recorder";
+import {
+  chooseDropdownOption,
+  getDropdownResolutionStore,
+  type DropdownResolution,
+  type DropdownResolutionStore,
+} from "./dropdown_resolutions";
 import { botLogger } from "@sheetpilot/shared/logger";
 
 export type FieldSpec = {
@@ -21,14 +33,35 @@ export type FieldSpec = {
   inject_value?: boolean;
 };
 
+/** Options shown by an open Smartsheet combobox */
+const DROPDOWN_OPTION_SELECTOR = '[role="listbox"] [role="option"]:visible';
+
 export class FormInteractor {
   private readonly getPage: () => Page;
+  /** How values are entered (inject vs typing, delays) */
+  readonly profile: FillSpeedProfile;
+  /** Outlines each field before it is filled (visible runs only) */
+  annotator: StepAnnotator | null = null;
+  /** Notes each fill for replay (see run_recorder.ts) */
+  recorder: RunRecorder | null = null;
+  /** Past dropdown choices; used when a typed value matches several options */
+  dropdownStore: DropdownResolutionStore | null = getDropdownResolutionStore();
+  /**
+   * Ambiguous dropdown selections since the last `clear()`, by field key;
+   * confirmed once the row is submitted
+   */
+  readonly dropdownResolutions = new Map<string, DropdownResolution>();
 
-  constructor(getPage: () => Page) {
+  constructor(getPage: () => Page, profile: FillSpeedProfile = getActiveFillProfile()) {
     this.getPage = getPage;
+    this.profile = profile;
   }
 
-  async fillField(spec: FieldSpec, value: string): Promise<void> {
+  /**
+   * @param fieldKey - Field key (see FIELD_DEFINITIONS); needed to remember
+   *   and reuse dropdown choices
+   */
+  async fillField(spec: FieldSpec, value: string, fieldKey?: string): Promise<void> {
     // `label` comes from the field spec. It is used only for logs and errors.
     // If you need key-based logic, pass a separate stable identifier.
     const fieldName = spec.label ?? "Unknown Field";
@@ -64,12 +97,29 @@ export class FormInteractor {
       );
     }
 
-    botLogger.debug("Field is visible, clearing and filling", { fieldName });
+    await this.annotator?.highlight(
+      field,
+      `${fieldName}: ${String(value).substring(0, 40)}`
+    );
+
+    this.recorder?.recordFill(fieldName, locatorSel, String(value));
+
+    botLogger.debug("Field is visible, clearing and filling", {
+      fieldName,
+      profile: this.profile.name,
+    });
     await field.fill("");
-    await field.fill(String(value));
+    if (this.profile.mode === "type") {
+      await field.pressSequentially(String(value), {
+        delay: this.profile.keystrokeDelayMs,
+      });
+    } else {
+      await field.fill(String(value));
+    }
     botLogger.info("✅ [FILL_TEXT] Text filled", {
       fieldName,
       value: String(value).substring(0, 50),
+      mode: this.profile.mode,
     });
 
     const isDropdown = await this._isDropdownField(spec, field);
@@ -82,7 +132,7 @@ export class FormInteractor {
       botLogger.info("📋 [DROPDOWN_HANDLE_START] Handling dropdown", {
         fieldName,
       });
-      await this._handleSmartsheetsDropdown(field, fieldName);
+      await this._handleSmartsheetsDropdown(field, fieldName, fieldKey, String(value));
       botLogger.info("✅ [DROPDOWN_HANDLE_END] Dropdown handled", {
         fieldName,
       });
@@ -99,6 +149,10 @@ export class FormInteractor {
       await this._checkValidationErrors(field, fieldName);
     }
 
+    if (this.profile.fieldSettleMs > 0) {
+      await page.waitForTimeout(this.profile.fieldSettleMs);
+    }
+
     botLogger.info("🟢 [FILL_COMPLETE] ✨ Field fill 100% complete", {
       fieldName,
       isDropdown,
@@ -157,7 +211,9 @@ export class FormInteractor {
 
   private async _handleSmartsheetsDropdown(
     field: Locator,
-    fieldName: string
+    fieldName: string,
+    fieldKey: string | undefined,
+    value: string
   ): Promise<void> {
     const page = this.getPage();
     botLogger.info("📋 [DROPDOWN_WAIT_OPTIONS] Waiting for dropdown options", {
@@ -185,6 +241,45 @@ export class FormInteractor {
       );
     }
 
+    const options = page.locator(DROPDOWN_OPTION_SELECTOR);
+    const candidates = fieldKey
+      ? await options.allInnerTexts().then(
+          (texts) => texts.map((text) => text.trim()),
+          () => []
+        )
+      : [];
+    const preferred =
+      fieldKey && candidates.length > 1
+        ? (this.dropdownStore?.lookup(fieldKey, value) ?? null)
+        : null;
+    let selectedIndex = 0;
+    const preferredIndex = chooseDropdownOption(candidates, preferred);
+    if (preferredIndex !== null) {
+      botLogger.info("📋 [DROPDOWN_PREFERRED] Selecting previously confirmed option", {
+        fieldName,
+        option: preferred,
+        candidates: candidates.length,
+      });
+      try {
+        await options.nth(preferredIndex).click();
+        selectedIndex = preferredIndex;
+      } catch (err: unknown) {
+        botLogger.warn("Could not click previously confirmed option; taking the first", {
+          fieldName,
+          error: String(err),
+        });
+      }
+    }
+    if (fieldKey && candidates.length > 1) {
+      this.dropdownResolutions.set(fieldKey, {
+        fieldKey,
+        typedValue: value,
+        optionText: candidates[selectedIndex] as string,
+        candidates,
+      });
+    }
+    if (selectedIndex > 0) return;
+
     // Press Enter to select the dropdown option
     botLogger.info(
       "⌨️ [KEY_PRESS_START] About to press Enter to select dropdown",
@@ -0,0 +1,119 @@
+/**
+ * Submission postconditions.
+ *
+ * Extra checks a submit must pass before it counts as verified, configured
+ * per submission service profile (the desktop app loads them from plugin
+ * options before each run). Regex postconditions can capture values such as
+ * a confirmation or submission ID, which are stored with the entry.
+ *
+ * - `url-contains`: the page URL after submit must contain `value`
+ * - `page-matches`: the page text must match `pattern`; group 1 (or the
+ *   whole match) is captured under `capture` when set
+ */
+import type { Page } from "playwright";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export type SubmissionPostcondition =
+  | { kind: "url-contains"; value: string; description?: string }
+  | {
+      kind: "page-matches";
+      /** Regular expression source, e.g. `Submission ID:\s*(\w+)` */
+      pattern: string;
+      /** Name the matched value is stored under */
+      capture?: string;
+      description?: string;
+    };
+
+export interface PostconditionResult {
+  passed: boolean;
+  /** Description of the first postcondition that failed */
+  failed: string | null;
+  /** Values captured by `page-matches` postconditions, by capture name */
+  captures: Record<string, string>;
+}
+
+/** Page snapshot postconditions are evaluated against */
+export interface PostconditionPageState {
+  url: string;
+  text: string;
+}
+
+let postconditions: SubmissionPostcondition[] = [];
+
+/**
+ * Sets the postconditions later submits are checked against; an empty list
+ * turns the checks off
+ */
+export function configureSubmissionPostconditions(
+  list: SubmissionPostcondition[]
+): void {
+  postconditions = [...list];
+  botLogger.verbose("Submission postconditions configured", {
+    count: postconditions.length,
+  });
+}
+
+export function getSubmissionPostconditions(): SubmissionPostcondition[] {
+  return [...postconditions];
+}
+
+export const describePostcondition = (
+  condition: SubmissionPostcondition
+): string =>
+  condition.description ??
+  (condition.kind === "url-contains"
+    ? `URL contains "${condition.value}"`
+    : `page matches /${condition.pattern}/`);
+
+/**
+ * Checks postconditions against a page snapshot
+ */
+export function checkPostconditions(
+  state: PostconditionPageState,
+  conditions: readonly SubmissionPostcondition[]
+): PostconditionResult {
+  const captures: Record<string, string> = {};
+  for (const condition of conditions) {
+    if (condition.kind === "url-contains") {
+      if (!state.url.includes(condition.value)) {
+        return { passed: false, failed: describePostcondition(condition), captures };
+      }
+      continue;
+    }
+
+    let match: RegExpMatchArray | null;
+    try {
+      match = state.text.match(new RegExp(condition.pattern));
+    } catch {
+      return {
+        passed: false,
+        failed: `invalid pattern /${condition.pattern}/`,
+        captures,
+      };
+    }
+    if (!match) {
+      return { passed: false, failed: describePostcondition(condition), captures };
+    }
+    if (condition.capture) {
+      captures[condition.capture] = (match[1] ?? match[0]).trim();
+    }
+  }
+  return { passed: true, failed: null, captures };
+}
+
+/**
+ * Reads the page URL and visible text, then checks postconditions
+ */
+export async function evaluatePostconditions(
+  page: Page,
+  conditions: readonly SubmissionPostcondition[] = postconditions
+): Promise<PostconditionResult> {
+  if (conditions.length === 0) {
+    return { passed: true, failed: null, captures: {} };
+  }
+  const text = await page
+    .locator("body")
+    .innerText({ timeout: 2000 })
+    .catch(() => "");
+  return checkPostconditions({ url: page.url(), text }, conditions);
+}
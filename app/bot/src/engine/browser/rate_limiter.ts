@@ -0,0 +1,289 @@
+/**
+ * Submission pacing and throttling backoff.
+ *
+ * Smartsheet answers with 429/503 (or just slows down) when many people submit
+ * at once. `SubmissionRateLimiter` keeps submissions from this machine apart:
+ * - every submit waits for `acquire()`, which enforces a minimum gap plus jitter
+ * - a throttled response pushes the next allowed time out by an exponential,
+ *   jittered backoff (or the server's Retry-After, whichever is longer)
+ * - throttling and slow submits widen the gap; normal submits narrow it again
+ *
+ * The pacing state lives in a small JSON file in the OS temp directory so every
+ * SheetPilot process on the machine shares it. File access is best-effort: if
+ * the file can't be read or written, pacing falls back to this process only.
+ */
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import * as cfg from "../config/automation_config";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export interface RateLimitSettings {
+  /** Minimum gap between submissions in milliseconds */
+  minIntervalMs: number;
+  /** Random extra delay (0..N ms) added to each gap */
+  jitterMs: number;
+  /** Extra attempts allowed for throttled responses */
+  maxThrottleRetries: number;
+  /** First throttle backoff in milliseconds (doubles per retry) */
+  backoffBaseMs: number;
+  /** Upper bound for one computed backoff in milliseconds */
+  backoffMaxMs: number;
+  /** Submits slower than this widen the gap */
+  slowSubmitThresholdMs: number;
+}
+
+export const DEFAULT_RATE_LIMIT_SETTINGS: RateLimitSettings = {
+  minIntervalMs: cfg.SUBMIT_MIN_INTERVAL_MS,
+  jitterMs: cfg.SUBMIT_PACING_JITTER_MS,
+  maxThrottleRetries: cfg.THROTTLE_MAX_RETRIES,
+  backoffBaseMs: cfg.THROTTLE_BACKOFF_BASE_MS,
+  backoffMaxMs: cfg.THROTTLE_BACKOFF_MAX_MS,
+  slowSubmitThresholdMs: cfg.SLOW_SUBMIT_THRESHOLD_MS,
+};
+
+/** Largest factor the gap can grow to after repeated throttling */
+const MAX_PACING_MULTIPLIER = 8;
+/** Retry-After values beyond this are treated as this long */
+const MAX_RETRY_AFTER_MS = 5 * 60 * 1000;
+
+interface PacingState {
+  /** Epoch ms before which no submission should start */
+  nextAllowedAt: number;
+  /** Current gap = minIntervalMs × this */
+  pacingMultiplier: number;
+}
+
+export interface ThrottleSignal {
+  status: number;
+  /** From the Retry-After header, when present */
+  retryAfterMs: number | null;
+}
+
+/**
+ * Whether an HTTP status means the server is throttling us
+ */
+export function isThrottleStatus(status: number): boolean {
+  return cfg.THROTTLE_STATUS_CODES.includes(status);
+}
+
+/**
+ * Parses a Retry-After header (delay in seconds or an HTTP date)
+ *
+ * @returns Delay in milliseconds, or null if missing or malformed
+ */
+export function parseRetryAfter(
+  value: string | null | undefined,
+  now: number = Date.now()
+): number | null {
+  if (!value) return null;
+  const trimmed = value.trim();
+  if (/^\d+(\.\d+)?$/.test(trimmed)) {
+    return Math.min(Math.round(Number(trimmed) * 1000), MAX_RETRY_AFTER_MS);
+  }
+  const at = Date.parse(trimmed);
+  if (Number.isNaN(at)) return null;
+  return Math.min(Math.max(0, at - now), MAX_RETRY_AFTER_MS);
+}
+
+/**
+ * Backoff before throttle retry `attempt` (0-based): exponential with "equal
+ * jitter" (half fixed, half random), never shorter than the server asked for
+ */
+export function computeThrottleBackoffMs(
+  attempt: number,
+  settings: RateLimitSettings,
+  retryAfterMs: number | null = null,
+  random: () => number = Math.random
+): number {
+  const exponential = Math.min(
+    settings.backoffMaxMs,
+    settings.backoffBaseMs * 2 ** attempt
+  );
+  const jittered = Math.round(exponential / 2 + random() * (exponential / 2));
+  return Math.max(jittered, retryAfterMs ?? 0);
+}
+
+/**
+ * Keeps the known, finite, non-negative numbers from a stored settings object
+ */
+export function sanitizeRateLimitSettings(raw: unknown): Partial<RateLimitSettings> {
+  if (!raw || typeof raw !== "object") return {};
+  const stored = raw as Record<string, unknown>;
+  const result: Partial<RateLimitSettings> = {};
+  for (const key of Object.keys(DEFAULT_RATE_LIMIT_SETTINGS) as Array<keyof RateLimitSettings>) {
+    const value = stored[key];
+    if (typeof value === "number" && Number.isFinite(value) && value >= 0) {
+      result[key] = value;
+    }
+  }
+  return result;
+}
+
+const defaultSleep = (ms: number): Promise<void> =>
+  new Promise((resolve) => setTimeout(resolve, ms));
+
+export class SubmissionRateLimiter {
+  private settings: RateLimitSettings;
+  /** Shared state file; null keeps pacing in this process only */
+  private readonly statePath: string | null;
+  private readonly now: () => number;
+  private readonly sleep: (ms: number) => Promise<void>;
+  private readonly random: () => number;
+  private local: PacingState = { nextAllowedAt: 0, pacingMultiplier: 1 };
+  /** Serializes acquire() calls within this process */
+  private queue: Promise<unknown> = Promise.resolve();
+
+  constructor(
+    statePath: string | null = path.join(
+      os.tmpdir(),
+      "sheetpilot-submission-pacing.json"
+    ),
+    settings: Partial<RateLimitSettings> = {},
+    now: () => number = Date.now,
+    sleep: (ms: number) => Promise<void> = defaultSleep,
+    random: () => number = Math.random
+  ) {
+    this.statePath = statePath;
+    this.settings = { ...DEFAULT_RATE_LIMIT_SETTINGS, ...settings };
+    this.now = now;
+    this.sleep = sleep;
+    this.random = random;
+  }
+
+  getSettings(): RateLimitSettings {
+    return { ...this.settings };
+  }
+
+  configure(settings: Partial<RateLimitSettings>): void {
+    this.settings = { ...this.settings, ...settings };
+    botLogger.info("Submission rate limit configured", { ...this.settings });
+  }
+
+  /**
+   * Waits until this machine may submit again and reserves the next slot
+   *
+   * @returns Milliseconds waited
+   */
+  acquire(): Promise<number> {
+    const run = this.queue.then(async () => {
+      const state = this.readState();
+      const waitMs = Math.max(0, state.nextAllowedAt - this.now());
+      if (waitMs > 0) {
+        botLogger.verbose("Pacing submission", {
+          waitMs,
+          pacingMultiplier: state.pacingMultiplier,
+        });
+        await this.sleep(waitMs);
+      }
+      const gap =
+        this.settings.minIntervalMs * state.pacingMultiplier +
+        Math.round(this.random() * this.settings.jitterMs);
+      // Re-read so a throttle reported by another process while we slept wins
+      const latest = this.readState();
+      this.writeState({
+        ...latest,
+        nextAllowedAt: Math.max(latest.nextAllowedAt, this.now() + gap),
+      });
+      return waitMs;
+    });
+    this.queue = run.catch(() => undefined);
+    return run;
+  }
+
+  /**
+   * Records a throttled response and pushes back every submitter on the machine
+   *
+   * @param attempt - 0-based throttle retry number for this submission
+   * @returns Backoff in milliseconds before the next attempt
+   */
+  reportThrottle(attempt: number, signal: ThrottleSignal): number {
+    const backoffMs = computeThrottleBackoffMs(
+      attempt,
+      this.settings,
+      signal.retryAfterMs,
+      this.random
+    );
+    const state = this.readState();
+    this.writeState({
+      nextAllowedAt: Math.max(state.nextAllowedAt, this.now() + backoffMs),
+      pacingMultiplier: Math.min(MAX_PACING_MULTIPLIER, state.pacingMultiplier * 2),
+    });
+    botLogger.warn("Smartsheet is throttling submissions", {
+      status: signal.status,
+      retryAfterMs: signal.retryAfterMs,
+      backoffMs,
+      attempt,
+    });
+    return backoffMs;
+  }
+
+  /**
+   * Records a completed (non-throttled) submit. Slow submits widen the gap;
+   * normal ones shrink it back toward minIntervalMs.
+   */
+  reportCompleted(durationMs: number): void {
+    const state = this.readState();
+    const slow = durationMs > this.settings.slowSubmitThresholdMs;
+    const pacingMultiplier = slow
+      ? Math.min(MAX_PACING_MULTIPLIER, state.pacingMultiplier * 1.5)
+      : Math.max(1, state.pacingMultiplier / 2);
+    if (slow) {
+      botLogger.info("Slow submission response; widening submission pacing", {
+        durationMs,
+        pacingMultiplier,
+      });
+    }
+    if (pacingMultiplier !== state.pacingMultiplier) {
+      this.writeState({ ...state, pacingMultiplier });
+    }
+  }
+
+  private readState(): PacingState {
+    if (!this.statePath) return { ...this.local };
+    try {
+      const raw = JSON.parse(fs.readFileSync(this.statePath, "utf-8")) as Partial<PacingState>;
+      return {
+        nextAllowedAt: typeof raw.nextAllowedAt === "number" ? raw.nextAllowedAt : 0,
+        pacingMultiplier:
+          typeof raw.pacingMultiplier === "number" && raw.pacingMultiplier >= 1
+            ? Math.min(MAX_PACING_MULTIPLIER, raw.pacingMultiplier)
+            : 1,
+      };
+    } catch {
+      return { ...this.local };
+    }
+  }
+
+  private writeState(state: PacingState): void {
+    this.local = state;
+    if (!this.statePath) return;
+    try {
+      fs.writeFileSync(this.statePath, JSON.stringify(state), "utf-8");
+    } catch (err: unknown) {
+      botLogger.debug("Could not write shared submission pacing state", {
+        statePath: this.statePath,
+        error: err instanceof Error ? err.message : String(err),
+      });
+    }
+  }
+}
+
+let sharedLimiter: SubmissionRateLimiter | null = null;
+
+/**
+ * Process-wide limiter backed by the machine-wide pacing file
+ */
+export function getSubmissionRateLimiter(): SubmissionRateLimiter {
+  sharedLimiter ??= new SubmissionRateLimiter();
+  return sharedLimiter;
+}
+
+/**
+ * Applies automation settings to the shared limiter
+ */
+export function configureSubmissionRateLimit(
+  settings: Partial<RateLimitSettings>
+): void {
+  getSubmissionRateLimiter().configure(settings);
+}
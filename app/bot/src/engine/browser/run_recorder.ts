@@ -0,0 +1,263 @@
+/**
+ * Run recording and replay.
+ *
+ * A `RunRecorder` attached to the bot's page writes down what the run did to
+ * the browser: navigations, field fills, submit clicks and the document/XHR
+ * responses that came back. The recording is plain JSON, so it can be
+ * checked into `tests/fixtures` and used to catch orchestration regressions
+ * without live Smartsheet access:
+ *
+ * - `replayRunRecording` drives a page through the recorded steps against
+ *   another base URL (usually the mock website) and records what happens
+ * - `compareRunRecordings` lists where two recordings disagree, e.g. a
+ *   recorded run vs. a fresh run of the orchestrator against the mock server
+ *
+ * Set RECORD_RUN_DIR to record every run. Query strings and fragments are
+ * dropped from recorded URLs (sign-in redirects carry tokens) and login
+ * steps never go through the recorder, so credentials are not written.
+ */
+import * as fs from "node:fs";
+import * as path from "node:path";
+import type { Frame, Page, Response } from "playwright";
+import type { FormConfig } from "./webform_session";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export const RUN_RECORDING_VERSION = 1;
+
+export type RecordedInteraction =
+  | { type: "navigate"; url: string }
+  | { type: "fill"; field: string; locator: string; value: string }
+  | { type: "submit"; selector: string }
+  | { type: "response"; method: string; url: string; status: number };
+
+export interface RunRecording {
+  version: typeof RUN_RECORDING_VERSION;
+  formId: string;
+  /** Base URL of the form the run targeted; replay rewrites URLs under it */
+  baseUrl: string;
+  submissionEndpoint: string;
+  recordedAt: string;
+  interactions: RecordedInteraction[];
+}
+
+/** Resource types worth recording; scripts, styles and images are noise */
+const RECORDED_RESOURCE_TYPES = new Set(["document", "xhr", "fetch"]);
+
+const stripQuery = (url: string): string => url.replace(/[?#].*$/, "");
+
+const trimSlash = (url: string): string => url.replace(/\/+$/, "");
+
+/**
+ * Moves a URL recorded under `fromBase` to `toBase`; URLs outside `fromBase`
+ * (e.g. sign-in pages) are returned unchanged
+ */
+export function rebaseUrl(url: string, fromBase: string, toBase: string): string {
+  const from = trimSlash(stripQuery(fromBase));
+  if (url !== from && !url.startsWith(`${from}/`)) return url;
+  return `${trimSlash(toBase)}${url.slice(from.length)}`;
+}
+
+export class RunRecorder {
+  private readonly formConfig: FormConfig;
+  private readonly interactions: RecordedInteraction[] = [];
+  private readonly startedAt = new Date();
+  private page: Page | null = null;
+  private readonly onNavigated = (frame: Frame): void => {
+    if (this.page && frame === this.page.mainFrame()) {
+      this.interactions.push({ type: "navigate", url: stripQuery(frame.url()) });
+    }
+  };
+  private readonly onResponse = (response: Response): void => {
+    const request = response.request();
+    if (!RECORDED_RESOURCE_TYPES.has(request.resourceType())) return;
+    this.interactions.push({
+      type: "response",
+      method: request.method(),
+      url: stripQuery(response.url()),
+      status: response.status(),
+    });
+  };
+
+  constructor(formConfig: FormConfig) {
+    this.formConfig = formConfig;
+  }
+
+  /**
+   * Starts listening to navigations and responses on `page`
+   */
+  attach(page: Page): void {
+    this.detach();
+    this.page = page;
+    page.on("framenavigated", this.onNavigated);
+    page.on("response", this.onResponse);
+  }
+
+  detach(): void {
+    if (!this.page) return;
+    this.page.off("framenavigated", this.onNavigated);
+    this.page.off("response", this.onResponse);
+    this.page = null;
+  }
+
+  recordFill(field: string, locator: string, value: string): void {
+    this.interactions.push({ type: "fill", field, locator, value });
+  }
+
+  recordSubmit(selector: string): void {
+    this.interactions.push({ type: "submit", selector });
+  }
+
+  toRecording(): RunRecording {
+    return {
+      version: RUN_RECORDING_VERSION,
+      formId: this.formConfig.FORM_ID,
+      baseUrl: stripQuery(this.formConfig.BASE_URL),
+      submissionEndpoint: stripQuery(this.formConfig.SUBMISSION_ENDPOINT),
+      recordedAt: this.startedAt.toISOString(),
+      interactions: [...this.interactions],
+    };
+  }
+
+  /**
+   * Writes the recording to `dir` as run-<formId>-<timestamp>.json
+   *
+   * @returns Path of the file written
+   */
+  saveTo(dir: string): string {
+    const recording = this.toRecording();
+    fs.mkdirSync(dir, { recursive: true });
+    const stamp = recording.recordedAt.replace(/[:.]/g, "-");
+    const file = path.join(dir, `run-${recording.formId}-${stamp}.json`);
+    fs.writeFileSync(file, JSON.stringify(recording, null, 2), "utf-8");
+    botLogger.info("Run recording saved", {
+      file,
+      interactions: recording.interactions.length,
+    });
+    return file;
+  }
+}
+
+/**
+ * Reads a recording written by `RunRecorder.saveTo`
+ *
+ * @throws Error if the file is not a recording this version can replay
+ */
+export function loadRunRecording(file: string): RunRecording {
+  const parsed = JSON.parse(fs.readFileSync(file, "utf-8")) as Partial<RunRecording>;
+  if (parsed.version !== RUN_RECORDING_VERSION || !Array.isArray(parsed.interactions)) {
+    throw new Error(`Not a version ${RUN_RECORDING_VERSION} run recording: ${file}`);
+  }
+  return parsed as RunRecording;
+}
+
+/**
+ * Drives `page` through a recording against `targetBaseUrl`
+ *
+ * Navigations outside the recorded base URL (sign-in pages) or to the page
+ * already shown are skipped, and recorded responses are not replayed: the
+ * target server answers for itself.
+ *
+ * @returns What happened during the replay, recorded against `targetBaseUrl`
+ */
+export async function replayRunRecording(
+  page: Page,
+  recording: RunRecording,
+  targetBaseUrl: string
+): Promise<RunRecording> {
+  const target = trimSlash(targetBaseUrl);
+  const recorder = new RunRecorder({
+    BASE_URL: target,
+    FORM_ID: recording.formId,
+    SUBMISSION_ENDPOINT: rebaseUrl(recording.submissionEndpoint, recording.baseUrl, target),
+    SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: [],
+  });
+  recorder.attach(page);
+  try {
+    for (const step of recording.interactions) {
+      switch (step.type) {
+        case "navigate": {
+          const url = rebaseUrl(step.url, recording.baseUrl, target);
+          if (url === step.url && !url.startsWith(target)) break;
+          // Navigations caused by a replayed submit have already happened
+          if (stripQuery(page.url()) === url) break;
+          await page.goto(url);
+          break;
+        }
+        case "fill":
+          recorder.recordFill(step.field, step.locator, step.value);
+          await page.locator(step.locator).fill(step.value);
+          break;
+        case "submit":
+          recorder.recordSubmit(step.selector);
+          await page.locator(step.selector).first().click();
+          await page.waitForLoadState("networkidle").catch(() => undefined);
+          break;
+        case "response":
+          break;
+      }
+    }
+  } finally {
+    recorder.detach();
+  }
+  botLogger.info("Run recording replayed", {
+    formId: recording.formId,
+    targetBaseUrl: target,
+    steps: recording.interactions.length,
+  });
+  return recorder.toRecording();
+}
+
+const toRelative = (url: string, base: string): string => {
+  const rebased = rebaseUrl(url, base, "");
+  return rebased === url ? url : rebased || "/";
+};
+
+const describeStep = (step: RecordedInteraction): string => {
+  switch (step.type) {
+    case "navigate":
+      return `navigate to ${step.url}`;
+    case "fill":
+      return `fill ${step.field} with "${step.value}"`;
+    case "submit":
+      return `submit via ${step.selector}`;
+    case "response":
+      return `${step.method} ${step.url} -> ${step.status}`;
+  }
+};
+
+/**
+ * Steps that decide what a run did, with URLs made relative to the base URL
+ * so recordings from different hosts compare equal: navigations within the
+ * form, fills, submits and responses from the submission endpoint
+ */
+const comparableSteps = (recording: RunRecording): string[] => {
+  const endpoint = toRelative(recording.submissionEndpoint, recording.baseUrl);
+  const steps: string[] = [];
+  for (const step of recording.interactions) {
+    if (step.type === "navigate" || step.type === "response") {
+      const url = toRelative(step.url, recording.baseUrl);
+      if (url === step.url) continue;
+      if (step.type === "response" && !url.startsWith(endpoint)) continue;
+      steps.push(describeStep({ ...step, url }));
+    } else {
+      steps.push(describeStep(step));
+    }
+  }
+  return steps;
+};
+
+/**
+ * Differences between two recordings, in order; empty when they match
+ */
+export function compareRunRecordings(expected: RunRecording, actual: RunRecording): string[] {
+  const want = comparableSteps(expected);
+  const got = comparableSteps(actual);
+  const differences: string[] = [];
+  for (let i = 0; i < Math.max(want.length, got.length); i++) {
+    if (want[i] === got[i]) continue;
+    differences.push(
+      `Step ${i + 1}: expected ${want[i] ?? "nothing"}, got ${got[i] ?? "nothing"}`
+    );
+  }
+  return differences;
+}
@@ -0,0 +1,131 @@
+/**
+ * Step annotation for visible (non-headless) runs.
+ *
+ * When enabled, the bot draws an outline around the element it is about to
+ * fill or click and shows the current step in a banner at the top of the page.
+ * This is for training and debugging: it makes a selector that grabbed the
+ * wrong element obvious at a glance.
+ *
+ * Everything is injected with `page.evaluate`, marked `pointer-events: none`
+ * so it never intercepts clicks, and every call is best-effort: a failed
+ * annotation is logged at debug level and never fails the run.
+ */
+import type { Locator, Page } from "playwright";
+import * as cfg from "../config/automation_config";
+import { appSettings } from "@sheetpilot/shared";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+const BANNER_ID = "__sheetpilot_step_banner";
+const HIGHLIGHT_ATTR = "data-sheetpilot-highlight";
+const HIGHLIGHT_COLOR = "#ff2d95";
+
+/**
+ * Whether annotation should run for a browser in this mode. Headless runs are
+ * never annotated; visible runs follow the Settings toggle or
+ * HIGHLIGHT_INTERACTIONS.
+ */
+export function isStepAnnotationEnabled(headless: boolean): boolean {
+  return (
+    !headless &&
+    (appSettings.highlightInteractions || cfg.HIGHLIGHT_INTERACTIONS)
+  );
+}
+
+export class StepAnnotator {
+  private readonly getPage: () => Page;
+  readonly enabled: boolean;
+
+  constructor(getPage: () => Page, enabled: boolean) {
+    this.getPage = getPage;
+    this.enabled = enabled;
+  }
+
+  /**
+   * Shows `label` in the step banner
+   */
+  async step(label: string): Promise<void> {
+    if (!this.enabled) return;
+    try {
+      await this.getPage().evaluate(
+        ({ id, text, color }) => {
+          let banner = document.getElementById(id);
+          if (!banner) {
+            banner = document.createElement("div");
+            banner.id = id;
+            Object.assign(banner.style, {
+              position: "fixed",
+              top: "0",
+              left: "50%",
+              transform: "translateX(-50%)",
+              zIndex: "2147483647",
+              padding: "6px 14px",
+              background: color,
+              color: "#fff",
+              font: "600 13px/1.4 system-ui, sans-serif",
+              borderRadius: "0 0 6px 6px",
+              boxShadow: "0 2px 6px rgba(0,0,0,0.3)",
+              pointerEvents: "none",
+            });
+            document.body.appendChild(banner);
+          }
+          banner.textContent = text;
+        },
+        { id: BANNER_ID, text: `SheetPilot: ${label}`, color: HIGHLIGHT_COLOR }
+      );
+    } catch (err: unknown) {
+      this.logFailure("step", err);
+    }
+  }
+
+  /**
+   * Outlines the element `locator` resolves to (removing the previous outline)
+   * and labels it with `label`
+   */
+  async highlight(locator: Locator, label: string): Promise<void> {
+    if (!this.enabled) return;
+    try {
+      await this.clearHighlight();
+      await locator.first().evaluate(
+        (el, { attr, text, color }) => {
+          const target = el as HTMLElement;
+          target.setAttribute(attr, text);
+          target.dataset["sheetpilotOutline"] = target.style.outline;
+          target.style.outline = `3px solid ${color}`;
+          target.style.outlineOffset = "2px";
+          target.title = text;
+        },
+        { attr: HIGHLIGHT_ATTR, text: label, color: HIGHLIGHT_COLOR },
+        { timeout: 1000 }
+      );
+    } catch (err: unknown) {
+      this.logFailure("highlight", err);
+    }
+  }
+
+  /**
+   * Removes the outline from the last highlighted element
+   */
+  async clearHighlight(): Promise<void> {
+    if (!this.enabled) return;
+    try {
+      await this.getPage().evaluate((attr) => {
+        document.querySelectorAll(`[${attr}]`).forEach((node) => {
+          const el = node as HTMLElement;
+          el.style.outline = el.dataset["sheetpilotOutline"] ?? "";
+          el.style.outlineOffset = "";
+          el.removeAttribute(attr);
+          delete el.dataset["sheetpilotOutline"];
+        });
+      }, HIGHLIGHT_ATTR);
+    } catch (err: unknown) {
+      this.logFailure("clear", err);
+    }
+  }
+
+  private logFailure(action: string, err: unknown): void {
+    botLogger.debug("Step annotation failed", {
+      action,
+      error: err instanceof Error ? err.message : String(err),
+    });
+  }
+}
@@ -7,9 +7,36 @@
  * - observing network responses that match submission URL patterns
  * - optionally validating response content for common success indicators
  * - falling back to DOM text indicators when network signals are missing
+ * - requiring the evidence the configured verification level asks for
+ * - noting throttling responses (429/503) so callers can back off and retry
+ * - scraping the form's validation feedback when a submit is not confirmed
+ * - checking configured postconditions and capturing values they match
  */
 import type { Locator, Page, Response } from "playwright";
 import * as cfg from "../config/automation_config";
+import {
+  isThrottleStatus,
+  parseRetryAfter,
+  type ThrottleSignal,
+} from "./rate_limiter";
+import {
+  hasFormFeedback,
+  scrapeFormFeedback,
+  type FormFeedback,
+} from "./form_feedback";
+import {
+  evaluatePostconditions,
+  getSubmissionPostconditions,
+  type PostconditionResult,
+} from "./postconditions";
+import type { RunRecorder } from "./run_recorder";
+import {
+  getSubmissionVerificationLevel,
+  isSubmissionVerified,
+  type SubmissionEvidence,
+  type SubmissionVerificationLevel,
+} from "./verification_level";
+import { getSubmitButtonSelectors } from "../config/selector_hotfix";
 import { botLogger } from "@sheetpilot/shared/logger";
 
 type RecordedResponse = { status: number; url: string; body?: string };
@@ -18,10 +45,26 @@ type RecordedResponseSummary = { status: number; url: string };
 export class SubmissionMonitor {
   private readonly getPage: () => Page;
   private readonly submitSuccessResponseUrlPatterns: string[];
-
-  constructor(getPage: () => Page, submitSuccessResponseUrlPatterns: string[]) {
+  private readonly fieldDefinitions: Record<string, cfg.FieldDefinition>;
+  /** Throttling seen during the last `submitForm()` call, if any */
+  lastThrottle: ThrottleSignal | null = null;
+  /** Validation errors the form showed after the last unconfirmed submit */
+  lastFeedback: FormFeedback | null = null;
+  /** Values captured by postconditions on the last verified submit */
+  lastCaptures: Record<string, string> = {};
+  /** Postcondition that failed on the last submit, if any */
+  lastPostconditionFailure: string | null = null;
+  /** Notes each submit click for replay (see run_recorder.ts) */
+  recorder: RunRecorder | null = null;
+
+  constructor(
+    getPage: () => Page,
+    submitSuccessResponseUrlPatterns: string[],
+    fieldDefinitions: Record<string, cfg.FieldDefinition> = cfg.FIELD_DEFINITIONS
+  ) {
     this.getPage = getPage;
     this.submitSuccessResponseUrlPatterns = submitSuccessResponseUrlPatterns;
+    this.fieldDefinitions = fieldDefinitions;
   }
 
   async submitForm(): Promise<boolean> {
@@ -35,6 +78,10 @@ export class SubmissionMonitor {
     const submissionIds: string[] = [];
     const submissionTokens: string[] = [];
     const requestIds: string[] = [];
+    this.lastThrottle = null;
+    this.lastFeedback = null;
+    this.lastCaptures = {};
+    this.lastPostconditionFailure = null;
 
     const handler = this._createResponseHandler(
       successResponses,
@@ -53,7 +100,9 @@ export class SubmissionMonitor {
 
       await submitButton.click();
 
+      const level = getSubmissionVerificationLevel();
       let domSuccessFound = false;
+      let confirmationUrlFound = false;
 
       try {
         const verifyTimeout = Math.min(
@@ -63,9 +112,19 @@ export class SubmissionMonitor {
 
         await cfg.dynamic_wait(
           async () => {
-            if (successResponses.length > 0) return true;
-            domSuccessFound = await this._checkDomSuccessIndicators(page);
-            return domSuccessFound;
+            if (this.lastThrottle) return true;
+            switch (level) {
+              case "strict":
+                return submissionIds.length > 0;
+              case "standard":
+                confirmationUrlFound = this._isConfirmationUrl(page.url());
+                domSuccessFound = await this._checkDomSuccessIndicators(page);
+                return confirmationUrlFound || domSuccessFound;
+              case "lenient":
+                if (successResponses.length > 0) return true;
+                domSuccessFound = await this._checkDomSuccessIndicators(page);
+                return domSuccessFound;
+            }
           },
           cfg.DYNAMIC_WAIT_BASE_TIMEOUT * cfg.HALF_TIMEOUT_MULTIPLIER,
           verifyTimeout,
@@ -78,21 +137,84 @@ export class SubmissionMonitor {
         });
       }
 
-      const ok = this._validateSubmissionSuccess(
-        successResponses,
-        domSuccessFound,
-        submissionIds,
+      // A throttled submit never counts as success, even if a DOM indicator
+      // from the previous page state is still visible
+      if (this.lastThrottle) {
+        timer.done({ success: false, method: "throttled" });
+        return false;
+      }
+
+      let ok = this._validateSubmissionSuccess(
+        level,
+        {
+          successResponseCount: successResponses.length,
+          responseHasIndicator: this._responsesHaveIndicator(successResponses),
+          submissionIdCount: submissionIds.length,
+          domIndicator: domSuccessFound,
+          confirmationUrl: confirmationUrlFound,
+        },
         submissionTokens,
         requestIds
       );
+      if (ok) {
+        ok = await this._verifyPostconditions(page);
+      }
+
+      if (!ok) {
+        const feedback = await scrapeFormFeedback(page, this.fieldDefinitions);
+        if (hasFormFeedback(feedback)) {
+          this.lastFeedback = feedback;
+          timer.done({ success: false, method: "rejected" });
+          return false;
+        }
+      }
 
-      timer.done({ success: ok, method: domSuccessFound ? "dom" : "http" });
+      timer.done({ success: ok, level, method: domSuccessFound ? "dom" : "http" });
       return ok;
     } finally {
       page.off("response", handler);
     }
   }
 
+  /**
+   * Waits for the configured postconditions to hold and records captures
+   *
+   * @returns True if every postcondition passed (or none are configured)
+   */
+  private async _verifyPostconditions(page: Page): Promise<boolean> {
+    const conditions = getSubmissionPostconditions();
+    if (conditions.length === 0) return true;
+
+    // The confirmation page may still be loading; the last evaluation says
+    // which postcondition failed if the wait times out
+    let result: PostconditionResult = { passed: false, failed: null, captures: {} };
+    await cfg.dynamic_wait(
+      async () => {
+        result = await evaluatePostconditions(page, conditions);
+        return result.passed;
+      },
+      cfg.DYNAMIC_WAIT_BASE_TIMEOUT * cfg.HALF_TIMEOUT_MULTIPLIER,
+      Math.min(cfg.SUBMIT_VERIFY_TIMEOUT_MS / 1000.0, cfg.GLOBAL_TIMEOUT),
+      cfg.DYNAMIC_WAIT_MULTIPLIER,
+      "submission postconditions"
+    );
+
+    if (result.passed) {
+      this.lastCaptures = result.captures;
+      botLogger.info("Submission postconditions passed", {
+        count: conditions.length,
+        captured: Object.keys(result.captures),
+      });
+      return true;
+    }
+    this.lastPostconditionFailure = result.failed ?? "postconditions not evaluated";
+    botLogger.warn("Submission postcondition failed", {
+      failed: this.lastPostconditionFailure,
+      url: page.url(),
+    });
+    return false;
+  }
+
   private _createResponseHandler(
     successResponses: RecordedResponse[],
     allResponses: RecordedResponseSummary[],
@@ -105,6 +227,18 @@ export class SubmissionMonitor {
       const status = response.status();
       allResponses.push({ status, url });
 
+      if (isThrottleStatus(status) && !this.lastThrottle) {
+        this.lastThrottle = {
+          status,
+          retryAfterMs: parseRetryAfter(response.headers()["retry-after"]),
+        };
+        botLogger.warn("Throttling response during submission", {
+          url,
+          status,
+          retryAfterMs: this.lastThrottle.retryAfterMs,
+        });
+      }
+
       // Consider a response “success-relevant” only when status is 2xx (configurable)
       // and the URL matches the configured submission patterns.
       const matched =
@@ -164,10 +298,10 @@ export class SubmissionMonitor {
   }
 
   private async _findSubmitButton(page: Page): Promise<Locator | null> {
-    const selectors = [
+    const selectors = getSubmitButtonSelectors([
       cfg.SUBMIT_BUTTON_LOCATOR,
       ...cfg.SUBMIT_BUTTON_FALLBACK_LOCATORS,
-    ];
+    ]);
 
     for (const selector of selectors) {
       const visible = await cfg.dynamic_wait_for_element(
@@ -197,6 +331,7 @@ export class SubmissionMonitor {
       }
 
       botLogger.debug("Found submit button", { selector });
+      this.recorder?.recordSubmit(selector);
       return locator;
     }
 
@@ -221,52 +356,43 @@ export class SubmissionMonitor {
     return false;
   }
 
-  private _validateSubmissionSuccess(
-    successResponses: RecordedResponse[],
-    domSuccessFound: boolean,
-    submissionIds: string[],
-    submissionTokens: string[],
-    requestIds: string[]
-  ): boolean {
-    if (domSuccessFound) {
-      botLogger.info("Submission verified via DOM indicators", {
-        requestIdCount: requestIds.length,
-      });
-      return true;
-    }
-
-    if (successResponses.length === 0) {
-      botLogger.warn("No successful submission responses observed", {
-        observedResponseCount: requestIds.length,
-      });
-      return false;
-    }
-
-    if (!cfg.ENABLE_RESPONSE_CONTENT_VALIDATION) {
-      botLogger.info("Submission verified via HTTP status", {
-        successResponseCount: successResponses.length,
-        submissionIdCount: submissionIds.length,
-        tokenCount: submissionTokens.length,
-      });
-      return true;
-    }
+  private _isConfirmationUrl(url: string): boolean {
+    return cfg.SUBMIT_CONFIRMATION_URL_PATTERNS.some((pattern) => url.includes(pattern));
+  }
 
-    const bodyHasIndicator = successResponses.some((r) => {
+  private _responsesHaveIndicator(successResponses: RecordedResponse[]): boolean {
+    return successResponses.some((r) => {
       if (!r.body) return false;
       const lower = r.body.toLowerCase();
       return cfg.SUBMIT_SUCCESS_INDICATORS.some((ind) =>
         lower.includes(ind.toLowerCase())
       );
     });
+  }
 
-    botLogger.info("Submission verified via response content validation", {
-      successResponseCount: successResponses.length,
-      submissionIdCount: submissionIds.length,
+  private _validateSubmissionSuccess(
+    level: SubmissionVerificationLevel,
+    evidence: SubmissionEvidence,
+    submissionTokens: string[],
+    requestIds: string[]
+  ): boolean {
+    const verified = isSubmissionVerified(
+      evidence,
+      level,
+      cfg.ENABLE_RESPONSE_CONTENT_VALIDATION
+    );
+    const details = {
+      level,
+      ...evidence,
       tokenCount: submissionTokens.length,
-      bodyHasIndicator,
-    });
-
-    return bodyHasIndicator || submissionIds.length > 0;
+      requestIdCount: requestIds.length,
+    };
+    if (verified) {
+      botLogger.info("Submission verified", details);
+    } else {
+      botLogger.warn("Submission not verified at the configured level", details);
+    }
+    return verified;
   }
 
   private _matchesAnyUrlPattern(url: string, patterns: string[]): boolean {
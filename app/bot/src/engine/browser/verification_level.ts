@@ -0,0 +1,83 @@
+/**
+ * Submission verification levels.
+ *
+ * How much evidence a submit needs before its rows are marked Complete. The
+ * desktop app sets the level from the `submission_verification` setting.
+ *
+ * - `lenient` (default): a matching 2xx response (with a success indicator or
+ *   submission ID when response validation is on) or a success banner
+ * - `standard`: the page confirms it, by a success banner or a confirmation URL
+ * - `strict`: an intercepted 2xx submission response carrying a submissionId
+ *
+ * Postconditions, when configured, are checked on top of the level.
+ */
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export const SUBMISSION_VERIFICATION_LEVELS = ["lenient", "standard", "strict"] as const;
+
+export type SubmissionVerificationLevel = (typeof SUBMISSION_VERIFICATION_LEVELS)[number];
+
+export const DEFAULT_SUBMISSION_VERIFICATION_LEVEL: SubmissionVerificationLevel = "lenient";
+
+/** What was observed after a submit click */
+export interface SubmissionEvidence {
+  /** 2xx responses from URLs matching the submission patterns */
+  successResponseCount: number;
+  /** A success response body contained a success indicator */
+  responseHasIndicator: boolean;
+  /** submissionIds read from success responses */
+  submissionIdCount: number;
+  /** A success banner was visible on the page */
+  domIndicator: boolean;
+  /** The page navigated to a confirmation URL */
+  confirmationUrl: boolean;
+}
+
+let level: SubmissionVerificationLevel = DEFAULT_SUBMISSION_VERIFICATION_LEVEL;
+
+/**
+ * Level for a stored (possibly missing or malformed) setting
+ */
+export function resolveVerificationLevel(raw: unknown): SubmissionVerificationLevel {
+  return SUBMISSION_VERIFICATION_LEVELS.find((candidate) => candidate === raw) ??
+    DEFAULT_SUBMISSION_VERIFICATION_LEVEL;
+}
+
+/**
+ * Sets the level later submits are verified at
+ */
+export function configureSubmissionVerification(next: SubmissionVerificationLevel): void {
+  level = next;
+  botLogger.verbose("Submission verification level configured", { level });
+}
+
+export function getSubmissionVerificationLevel(): SubmissionVerificationLevel {
+  return level;
+}
+
+/**
+ * Whether the evidence is enough for a submit to count as verified
+ *
+ * @param responseContentValidation - For lenient: require an indicator or
+ * submission ID in the response, not only a 2xx status
+ */
+export function isSubmissionVerified(
+  evidence: SubmissionEvidence,
+  verificationLevel: SubmissionVerificationLevel,
+  responseContentValidation: boolean
+): boolean {
+  switch (verificationLevel) {
+    case "strict":
+      return evidence.submissionIdCount > 0;
+    case "standard":
+      return evidence.domIndicator || evidence.confirmationUrl;
+    case "lenient":
+      if (evidence.domIndicator) return true;
+      if (evidence.successResponseCount === 0) return false;
+      return (
+        !responseContentValidation ||
+        evidence.responseHasIndicator ||
+        evidence.submissionIdCount > 0
+      );
+  }
+}
@@ -18,6 +18,7 @@
  */
 
 import { botLogger } from "@sheetpilot/shared/logger";
+import type { QuarterFieldOverride } from "./quarter_config";
 
 // ============================================================================
 // TYPE DEFINITIONS
@@ -30,7 +31,7 @@ type ValidationFunction = (value: unknown) => boolean;
 type ErrorMessageFunction = (value: unknown) => string;
 
 /** Field definition interface */
-interface FieldDefinition {
+export interface FieldDefinition {
   label: string;
   locator: string;
   type?: string;
@@ -132,6 +133,15 @@ export function getBrowserHeadless(): boolean {
 /** Specific browser channel to use (e.g., 'chrome' for Chrome instead of Chromium) */
 export const BROWSER_CHANNEL: string =
   process.env["BROWSER_CHANNEL"] ?? "chromium";
+/** Oldest Chrome major version the bot will run; older ones break CDP calls */
+export const BROWSER_MIN_VERSION: number = Number(
+  process.env["BROWSER_MIN_VERSION"] ?? "110"
+);
+/** Chrome major versions the bot has been tested with; others run with a warning */
+export const BROWSER_TESTED_VERSIONS: { min: number; max: number } = {
+  min: Number(process.env["BROWSER_TESTED_MIN"] ?? "120"),
+  max: Number(process.env["BROWSER_TESTED_MAX"] ?? "141"),
+};
 
 // ============================================================================
 // TIMEOUT CONFIGURATION
@@ -255,6 +265,12 @@ export const HALF_TIMEOUT_MULTIPLIER: number = Number(
 // FORM SUBMISSION CONFIGURATION
 // ============================================================================
 
+/** Whether to check the loaded form's title against the expected quarter before submitting */
+export const VERIFY_FORM_TITLE: boolean =
+  (process.env["VERIFY_FORM_TITLE"] ?? "1") === "1";
+/** Elements whose text is checked (with the page title) by VERIFY_FORM_TITLE */
+export const FORM_TITLE_SELECTORS: string[] = ["h1", "[role='heading']", "header"];
+
 /** Whether to automatically submit forms after filling */
 export const SUBMIT_FORM_AFTER_FILLING: boolean =
   (process.env["SUBMIT"] ?? "1") === "1";
@@ -288,6 +304,29 @@ export const SUBMIT_SUCCESS_INDICATORS: string[] = [
   "thank you for your submission",
 ];
 
+/** Page URL fragments that confirm a submission (for the standard verification level) */
+export const SUBMIT_CONFIRMATION_URL_PATTERNS: string[] = (
+  process.env["SUBMIT_CONFIRMATION_URL_PATTERNS"] ?? "/confirmation,formSubmitted"
+)
+  .split(",")
+  .map((pattern) => pattern.trim())
+  .filter((pattern) => pattern.length > 0);
+
+/** Form-level error banners Smartsheet shows when it rejects a submission */
+export const FORM_ERROR_BANNER_SELECTORS: string[] = [
+  "[role='alert']",
+  "[data-client-id='form_error_message']",
+  ".form-error-banner",
+  ".errorBanner",
+];
+/** Per-field error markers; their text is matched to fields by label */
+export const FIELD_ERROR_SELECTORS: string[] = [
+  "[data-client-id='field_error_message']",
+  ".field-error",
+  ".validation-error",
+  ".error-message",
+];
+
 /** Delay for Level 1 retry (quick re-click) in seconds */
 export const SUBMIT_CLICK_RETRY_DELAY_S: number = Number(
   process.env["SUBMIT_CLICK_RETRY_DELAY_S"] ?? "1.0"
@@ -298,6 +337,39 @@ export const SUBMIT_RETRY_DELAY: number = Number(
   process.env["SUBMIT_RETRY_DELAY"] ?? "2.0"
 );
 
+// ============================================================================
+// RATE LIMITING
+// ============================================================================
+// Defaults for SubmissionRateLimiter; the backend overrides them at runtime
+// from the `automation_rate_limit` setting.
+
+/** Minimum gap between submissions from this machine in milliseconds */
+export const SUBMIT_MIN_INTERVAL_MS: number = Number(
+  process.env["SUBMIT_MIN_INTERVAL_MS"] ?? "1000"
+);
+/** Random extra delay (0..N ms) added to each gap so clients don't submit in lockstep */
+export const SUBMIT_PACING_JITTER_MS: number = Number(
+  process.env["SUBMIT_PACING_JITTER_MS"] ?? "500"
+);
+/** Extra submit attempts allowed for a throttled (429/503) response */
+export const THROTTLE_MAX_RETRIES: number = Number(
+  process.env["THROTTLE_MAX_RETRIES"] ?? "4"
+);
+/** First backoff after a throttled response in milliseconds (doubles per retry) */
+export const THROTTLE_BACKOFF_BASE_MS: number = Number(
+  process.env["THROTTLE_BACKOFF_BASE_MS"] ?? "2000"
+);
+/** Upper bound for a single throttle backoff in milliseconds */
+export const THROTTLE_BACKOFF_MAX_MS: number = Number(
+  process.env["THROTTLE_BACKOFF_MAX_MS"] ?? "60000"
+);
+/** A submit slower than this counts as a soft throttle signal and widens the pacing */
+export const SLOW_SUBMIT_THRESHOLD_MS: number = Number(
+  process.env["SLOW_SUBMIT_THRESHOLD_MS"] ?? "8000"
+);
+/** HTTP statuses treated as throttling */
+export const THROTTLE_STATUS_CODES: number[] = [429, 503];
+
 // ============================================================================
 // SUBMIT BUTTON CONFIGURATION
 // ============================================================================
@@ -389,6 +461,11 @@ export const SCREENSHOT_ON_SUBMIT_FAILURE: boolean =
 /** Whether to capture screenshots on locator failures */
 export const SCREENSHOT_ON_LOCATOR_FAILURE: boolean =
   (process.env["SCREENSHOT_ON_LOCATOR_FAILURE"] ?? "1") === "1";
+/** Outline elements and show the current step in visible runs, regardless of the Settings toggle */
+export const HIGHLIGHT_INTERACTIONS: boolean =
+  (process.env["HIGHLIGHT_INTERACTIONS"] ?? "0") === "1";
+/** Save a replayable recording of each run's browser interactions here (see run_recorder.ts) */
+export const RECORD_RUN_DIR: string | null = process.env["RECORD_RUN_DIR"] || null;
 
 // ============================================================================
 // MISCELLANEOUS CONFIGURATION
@@ -431,6 +508,17 @@ export const BROWSER_VIEWPORT_HEIGHT = 1000;
 // LOGIN CONFIGURATION
 // ============================================================================
 
+/** Hosts a page lands on when the sign-in session has expired mid-run */
+export const AUTH_REDIRECT_HOSTS: string[] = [
+  "login.microsoftonline.com",
+  "login.microsoft.com",
+  "login.live.com",
+];
+/** How many times a run may log in again after its session expires */
+export const MAX_MID_RUN_RELOGINS: number = Number(
+  process.env["MAX_MID_RUN_RELOGINS"] ?? "2"
+);
+
 /** Sequence of steps to perform during login process */
 export const LOGIN_STEPS: LoginStep[] = [
   {
@@ -531,6 +619,53 @@ export const LOGIN_STEPS: LoginStep[] = [
   },
 ];
 
+// ============================================================================
+// SUBMISSION KEYS
+// ============================================================================
+
+/** Locator of the hidden/extra form field used when SUBMISSION_KEY_MODE is "field" */
+export const SUBMISSION_KEY_FIELD_LOCATOR: string =
+  process.env["SUBMISSION_KEY_FIELD_LOCATOR"] ??
+  "input[aria-label='Submission Key']";
+
+/**
+ * Where each entry's idempotency key is typed into the form, unless the
+ * `submission_key_mode` setting says otherwise:
+ * - "description": appended to Task Description as " [SP-XXXXXXXXXX]"
+ * - "field": typed into the field at SUBMISSION_KEY_FIELD_LOCATOR
+ * - "off": not sent (keys are still stored locally)
+ *
+ * Defaults to "field" when SUBMISSION_KEY_FIELD_LOCATOR is set, otherwise
+ * "description", so a crash between submit and the local write can still be
+ * reconciled out of the box (see the `submission_key_mode` setting for the
+ * trade-off against payroll reading Task Description).
+ */
+export const SUBMISSION_KEY_MODE: "description" | "field" | "off" = (() => {
+  const mode = process.env["SUBMISSION_KEY_MODE"];
+  if (mode === "description" || mode === "field" || mode === "off") {
+    return mode;
+  }
+  return process.env["SUBMISSION_KEY_FIELD_LOCATOR"] ? "field" : "description";
+})();
+
+// ============================================================================
+// CORRECTIONS
+// ============================================================================
+
+/**
+ * How an amended entry is marked as a correction of its submitted original:
+ * - "description": " (CORRECTION of <date>)" appended to Task Description
+ * - "field": note typed into the field at CORRECTION_FIELD_LOCATOR
+ * - "off": submitted like any other entry
+ */
+export const CORRECTION_MODE: "description" | "field" | "off" = (() => {
+  const mode = process.env["CORRECTION_MODE"] ?? "description";
+  return mode === "field" || mode === "off" ? mode : "description";
+})();
+/** Locator of the form's correction field used when CORRECTION_MODE is "field" */
+export const CORRECTION_FIELD_LOCATOR: string =
+  process.env["CORRECTION_FIELD_LOCATOR"] ?? "input[aria-label='Correction']";
+
 // ============================================================================
 // FIELD DEFINITIONS
 // ============================================================================
@@ -583,8 +718,54 @@ export const FIELD_DEFINITIONS: Record<string, FieldDefinition> = {
     optional: true,
     inject_value: true,
   },
+  // Only present in bot rows when the submission key mode is "field"
+  submission_key: {
+    label: "Submission Key",
+    locator: SUBMISSION_KEY_FIELD_LOCATOR,
+    validation: (_: unknown) => true,
+    error_message: (_: unknown) => "Submission key could not be entered",
+    optional: true,
+    inject_value: true,
+  },
+  // Only present in bot rows for amendments when CORRECTION_MODE is "field"
+  correction: {
+    label: "Correction",
+    locator: CORRECTION_FIELD_LOCATOR,
+    validation: (_: unknown) => true,
+    error_message: (_: unknown) => "Correction note could not be entered",
+    optional: true,
+    inject_value: true,
+  },
 };
 
+/**
+ * Applies a quarter's field overrides on top of `FIELD_DEFINITIONS`
+ *
+ * @param overrides - `QuarterDefinition.fieldOverrides` for the batch's quarter
+ * @param definitions - Definitions to apply them to (defaults to `FIELD_DEFINITIONS`)
+ * @returns Field definitions to use for that quarter's form
+ */
+export function resolveFieldDefinitions(
+  overrides?: Record<string, QuarterFieldOverride>,
+  definitions: Record<string, FieldDefinition> = FIELD_DEFINITIONS
+): Record<string, FieldDefinition> {
+  if (!overrides) return definitions;
+  const resolved: Record<string, FieldDefinition> = { ...definitions };
+  for (const [key, override] of Object.entries(overrides)) {
+    const base = definitions[key];
+    if (!base) {
+      botLogger.warn("Ignoring override for unknown field", { fieldKey: key });
+      continue;
+    }
+    resolved[key] = {
+      ...base,
+      ...(override.optional !== undefined ? { optional: override.optional } : {}),
+      ...(override.locator ? { locator: override.locator } : {}),
+    };
+  }
+  return resolved;
+}
+
 /** Order in which fields should be processed during form filling */
 export const FIELD_ORDER: string[] = [
   "project_code",
@@ -593,6 +774,8 @@ export const FIELD_ORDER: string[] = [
   "tool",
   "task_description",
   "detail_code",
+  "correction",
+  "submission_key",
 ];
 
 /** Mapping of project codes to their specific tool labels */
@@ -0,0 +1,518 @@
+/**
+ * Automation configuration lint.
+ *
+ * A typo in a selector, a login step missing its `value_key` or a quarter
+ * pointing at a mistyped form URL only showed up when a run failed on a
+ * user's machine. `lintAutomationConfig` checks the configuration without a
+ * browser and returns findings an admin can act on before it ships:
+ *
+ * - selectors: every field, login, submit button and hotfix selector parses
+ *   (Playwright engine prefixes such as `role=` are understood), and no two
+ *   fields of a form share a locator
+ * - login: every step has a known action, every `input` has a `value_key`
+ *   and a `locator`, every `wait` has an `element_selector`
+ * - fields: `FIELD_ORDER` names known fields once each and covers every
+ *   required field, including fields a quarter's overrides make required
+ * - quarters: form URLs are https Smartsheet URLs containing the form ID,
+ *   and quarter IDs and forms are not repeated
+ *
+ * `checkQuarterFormReachability` additionally requests each form URL; it is
+ * kept separate because it needs the network.
+ */
+import type { FieldDefinition, LoginStep } from "./automation_config";
+import {
+  FIELD_DEFINITIONS,
+  FIELD_ORDER,
+  LOGIN_STEPS,
+  SUBMIT_BUTTON_FALLBACK_LOCATORS,
+  SUBMIT_BUTTON_LOCATOR,
+  resolveFieldDefinitions,
+} from "./automation_config";
+import type { QuarterDefinition } from "./quarter_config";
+import { QUARTER_DEFINITIONS, isPlaceholderQuarter } from "./quarter_config";
+import type { SelectorHotfix } from "./selector_hotfix";
+import { applySelectorHotfix, getSelectorHotfix } from "./selector_hotfix";
+
+export type AutomationConfigFindingSeverity = "error" | "warning";
+
+export type AutomationConfigArea = "selectors" | "login" | "fields" | "quarters";
+
+export interface AutomationConfigFinding {
+  /** Errors break runs; warnings are likely mistakes */
+  severity: AutomationConfigFindingSeverity;
+  area: AutomationConfigArea;
+  /** What the finding is about, e.g. "field:hours", "login:AAD Email", "quarter:Q1-2026" */
+  target: string;
+  message: string;
+}
+
+export interface AutomationConfigLintReport {
+  /** True when there are no errors */
+  ok: boolean;
+  errors: number;
+  warnings: number;
+  findings: AutomationConfigFinding[];
+}
+
+/**
+ * The parts of the automation configuration that are linted
+ */
+export interface AutomationConfigSnapshot {
+  loginSteps: LoginStep[];
+  fieldDefinitions: Record<string, FieldDefinition>;
+  fieldOrder: string[];
+  quarters: QuarterDefinition[];
+  submitButtonSelectors: string[];
+  hotfix: SelectorHotfix | null;
+}
+
+const LOGIN_ACTIONS = ["wait", "input", "click"];
+const WAIT_CONDITIONS = ["visible", "hidden", "attached", "detached"];
+const REACHABILITY_TIMEOUT_MS = 10000;
+
+/**
+ * The configuration the bot would run with right now
+ */
+export function getAutomationConfigSnapshot(): AutomationConfigSnapshot {
+  return {
+    loginSteps: LOGIN_STEPS,
+    fieldDefinitions: FIELD_DEFINITIONS,
+    fieldOrder: FIELD_ORDER,
+    quarters: QUARTER_DEFINITIONS,
+    submitButtonSelectors: [SUBMIT_BUTTON_LOCATOR, ...SUBMIT_BUTTON_FALLBACK_LOCATORS],
+    hotfix: getSelectorHotfix(),
+  };
+}
+
+// ============================================================================
+// SELECTOR SYNTAX
+// ============================================================================
+
+const IDENT = /^-?(?:[_a-zA-Z\u00A0-\uFFFF]|\\.)(?:[\w\u00A0-\uFFFF-]|\\.)*/;
+const ATTRIBUTE =
+  /^\s*(?:[\w-]+|\*)?\|?[_a-zA-Z][\w:-]*\s*(?:[~|^$*]?=\s*(?:"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|-?[_a-zA-Z][\w-]*|\d+)\s*(?:[iIsS]\s*)?)?$/;
+const ENGINE_PREFIX = /^(css|xpath|text|role|id|data-testid|data-test-id|data-test|nth|visible|internal:[\w-]+)=/;
+
+/**
+ * Reads a quoted string or bracketed group starting at `start`
+ *
+ * @returns Index just past the closing character, or -1 if it is never closed
+ */
+function skipGroup(selector: string, start: number): number {
+  const open = selector[start];
+  if (open === '"' || open === "'") {
+    for (let i = start + 1; i < selector.length; i++) {
+      if (selector[i] === "\\") i++;
+      else if (selector[i] === open) return i + 1;
+    }
+    return -1;
+  }
+  const close = open === "[" ? "]" : ")";
+  let depth = 0;
+  for (let i = start; i < selector.length; i++) {
+    const ch = selector[i];
+    if (ch === "\\") i++;
+    else if (ch === '"' || ch === "'") {
+      const end = skipGroup(selector, i);
+      if (end < 0) return -1;
+      i = end - 1;
+    } else if (ch === open) depth++;
+    else if (ch === close && --depth === 0) return i + 1;
+  }
+  return -1;
+}
+
+/**
+ * Splits on a separator that is outside quotes, brackets and parentheses
+ */
+function splitTopLevel(selector: string, separator: string): string[] | null {
+  const parts: string[] = [];
+  let partStart = 0;
+  for (let i = 0; i < selector.length; i++) {
+    const ch = selector[i];
+    if (ch === "\\") {
+      i++;
+    } else if (ch === '"' || ch === "'" || ch === "[" || ch === "(") {
+      const end = skipGroup(selector, i);
+      if (end < 0) return null;
+      i = end - 1;
+    } else if (selector.startsWith(separator, i)) {
+      parts.push(selector.slice(partStart, i));
+      partStart = i + separator.length;
+      i = partStart - 1;
+    }
+  }
+  parts.push(selector.slice(partStart));
+  return parts;
+}
+
+/**
+ * Checks one complex CSS selector (no commas)
+ */
+function checkCssComplexSelector(selector: string): string | null {
+  let expectCompound = true;
+  let sawCompound = false;
+  let i = 0;
+  while (i < selector.length) {
+    const ch = selector[i] as string;
+    if (/\s/.test(ch)) {
+      i++;
+      continue;
+    }
+    if (ch === ">" || ch === "+" || ch === "~") {
+      if (expectCompound) return `unexpected combinator "${ch}"`;
+      expectCompound = true;
+      i++;
+      continue;
+    }
+    // A compound selector runs until whitespace or a combinator; a type
+    // selector may only come first
+    let hasSimple = false;
+    while (i < selector.length && !/[\s>+~]/.test(selector[i] as string)) {
+      const c = selector[i] as string;
+      const tail = selector.slice(i);
+      const typeName = hasSimple ? null : IDENT.exec(tail);
+      if (c === "*" && !hasSimple) {
+        i++;
+      } else if (typeName) {
+        i += typeName[0].length;
+      } else if (c === "#" || c === ".") {
+        const name = IDENT.exec(tail.slice(1));
+        if (!name) return `"${c}" must be followed by a name`;
+        i += 1 + name[0].length;
+      } else if (c === "[") {
+        const end = skipGroup(selector, i);
+        if (end < 0) return "unclosed [";
+        if (!ATTRIBUTE.test(selector.slice(i + 1, end - 1))) {
+          return `attribute selector ${selector.slice(i, end)} is not valid`;
+        }
+        i = end;
+      } else if (c === ":") {
+        const colons = tail.startsWith("::") ? 2 : 1;
+        const name = IDENT.exec(tail.slice(colons));
+        if (!name) return `"${":".repeat(colons)}" must be followed by a name`;
+        i += colons + name[0].length;
+        if (selector[i] === "(") {
+          const end = skipGroup(selector, i);
+          if (end < 0) return "unclosed (";
+          i = end;
+        }
+      } else {
+        return `unexpected "${c}"`;
+      }
+      hasSimple = true;
+    }
+    expectCompound = false;
+    sawCompound = true;
+  }
+  if (!sawCompound) return "selector is empty";
+  if (expectCompound) return "selector ends with a combinator";
+  return null;
+}
+
+function checkCssSelector(selector: string): string | null {
+  const list = splitTopLevel(selector, ",");
+  if (!list) return "unclosed quote, [ or (";
+  for (const part of list) {
+    if (!part.trim()) return "empty selector in a comma-separated list";
+    const problem = checkCssComplexSelector(part.trim());
+    if (problem) return problem;
+  }
+  return null;
+}
+
+/**
+ * Checks a Playwright selector's syntax without a browser
+ *
+ * CSS is checked in full; `role=` needs a role name and well-formed
+ * attributes; `text=`, `xpath=` and other engines only need a body.
+ *
+ * @returns What is wrong with it, or null if it parses
+ */
+export function checkSelectorSyntax(selector: string): string | null {
+  if (!selector.trim()) return "selector is empty";
+  const chain = splitTopLevel(selector, ">>");
+  if (!chain) return "unclosed quote, [ or (";
+  for (const rawPart of chain) {
+    const part = rawPart.trim();
+    if (!part) return 'empty part in a ">>" chain';
+    const engine = ENGINE_PREFIX.exec(part)?.[1];
+    const body = engine ? part.slice(engine.length + 1).trim() : part;
+    if (!body) return `"${engine}=" must be followed by a selector`;
+    if (engine === "role") {
+      const role = /^[a-z]+/.exec(body);
+      if (!role) return "role= must be followed by a role name";
+      let i = role[0].length;
+      while (i < body.length) {
+        if (body[i] !== "[") return `unexpected "${body[i]}" after role`;
+        const end = skipGroup(body, i);
+        if (end < 0) return "unclosed [";
+        i = end;
+      }
+    } else if (engine === "css" || (!engine && !/^(\/\/|\.\.|\(\/\/)/.test(body) && !/^["']/.test(body))) {
+      const problem = checkCssSelector(body);
+      if (problem) return problem;
+    } else if (/^["']/.test(body) && skipGroup(body, 0) < 0) {
+      return "unclosed quote";
+    }
+  }
+  return null;
+}
+
+// ============================================================================
+// LINT
+// ============================================================================
+
+function lintSelector(
+  findings: AutomationConfigFinding[],
+  target: string,
+  selector: string | undefined
+): void {
+  if (selector === undefined) return;
+  const problem = checkSelectorSyntax(selector);
+  if (problem) {
+    findings.push({
+      severity: "error",
+      area: "selectors",
+      target,
+      message: `Selector ${JSON.stringify(selector)} is not valid: ${problem}`,
+    });
+  }
+}
+
+function lintLoginSteps(findings: AutomationConfigFinding[], steps: LoginStep[]): void {
+  const names = new Set<string>();
+  steps.forEach((step, index) => {
+    const target = `login:${step.name || `step ${index + 1}`}`;
+    const add = (severity: AutomationConfigFindingSeverity, message: string) =>
+      findings.push({ severity, area: "login", target, message });
+
+    if (!step.name) add("warning", `Step ${index + 1} has no name`);
+    else if (names.has(step.name)) add("warning", `Step name "${step.name}" is used more than once`);
+    names.add(step.name);
+
+    switch (step.action) {
+      case "input":
+        if (!step.value_key) add("error", "Input step has no value_key");
+        if (!step.locator) add("error", "Input step has no locator");
+        break;
+      case "wait":
+        if (!step.element_selector) add("error", "Wait step has no element_selector");
+        if (step.wait_condition && !WAIT_CONDITIONS.includes(step.wait_condition)) {
+          add("error", `Wait condition "${step.wait_condition}" is not one of ${WAIT_CONDITIONS.join(", ")}`);
+        }
+        break;
+      case "click":
+        if (!step.locator) add("error", "Click step has no locator");
+        break;
+      default:
+        add("error", `Action "${step.action}" is not one of ${LOGIN_ACTIONS.join(", ")}`);
+    }
+    lintSelector(findings, target, step.locator);
+    lintSelector(findings, target, step.element_selector);
+  });
+}
+
+/**
+ * Checks one form's resolved fields for shared locators and required fields
+ * the order never fills. With `base`, only fields that differ from it are
+ * reported, so a quarter does not repeat what the defaults already show.
+ */
+function lintFormFields(
+  findings: AutomationConfigFinding[],
+  definitions: Record<string, FieldDefinition>,
+  fieldOrder: string[],
+  form: { name: string; base: Record<string, FieldDefinition> } | null
+): void {
+  const suffix = form ? ` on the ${form.name} form` : "";
+  const changed = (key: string): boolean => {
+    const before = form?.base[key];
+    const after = definitions[key];
+    return !form || before?.locator !== after?.locator || Boolean(before?.optional) !== Boolean(after?.optional);
+  };
+  const locators = new Map<string, string>();
+  for (const [key, definition] of Object.entries(definitions)) {
+    const target = `field:${key}`;
+    const owner = locators.get(definition.locator);
+    if (owner === undefined) {
+      locators.set(definition.locator, key);
+    } else if (changed(key) || changed(owner)) {
+      findings.push({
+        severity: "error",
+        area: "selectors",
+        target,
+        message: `Uses the same locator as field "${owner}"${suffix}`,
+      });
+    }
+    if (!definition.optional && !fieldOrder.includes(key) && changed(key)) {
+      findings.push({
+        severity: "error",
+        area: "fields",
+        target,
+        message: `Required field is missing from FIELD_ORDER${suffix}, so it is never filled`,
+      });
+    }
+  }
+}
+
+function lintFieldOrder(
+  findings: AutomationConfigFinding[],
+  definitions: Record<string, FieldDefinition>,
+  fieldOrder: string[]
+): void {
+  const seen = new Set<string>();
+  for (const key of fieldOrder) {
+    const target = `field:${key}`;
+    if (!definitions[key]) {
+      findings.push({
+        severity: "error",
+        area: "fields",
+        target,
+        message: "FIELD_ORDER names a field that is not defined",
+      });
+    } else if (seen.has(key)) {
+      findings.push({
+        severity: "error",
+        area: "fields",
+        target,
+        message: "Field appears more than once in FIELD_ORDER",
+      });
+    }
+    seen.add(key);
+  }
+  for (const key of Object.keys(definitions)) {
+    if (definitions[key]?.optional && !seen.has(key)) {
+      findings.push({
+        severity: "warning",
+        area: "fields",
+        target: `field:${key}`,
+        message: "Optional field is not in FIELD_ORDER, so it is never filled",
+      });
+    }
+  }
+}
+
+function lintQuarters(
+  findings: AutomationConfigFinding[],
+  config: AutomationConfigSnapshot,
+  base: Record<string, FieldDefinition>
+): void {
+  const ids = new Set<string>();
+  const formIds = new Map<string, string>();
+  for (const quarter of config.quarters) {
+    const target = `quarter:${quarter.id}`;
+    const add = (severity: AutomationConfigFindingSeverity, message: string) =>
+      findings.push({ severity, area: "quarters", target, message });
+
+    if (ids.has(quarter.id)) add("error", "Quarter ID is used more than once");
+    ids.add(quarter.id);
+
+    const owner = formIds.get(quarter.formId);
+    if (owner) add("error", `Uses the same form as ${owner}`);
+    else formIds.set(quarter.formId, quarter.id);
+
+    let url: URL | null = null;
+    try {
+      url = new URL(quarter.formUrl);
+    } catch {
+      add("error", `Form URL ${JSON.stringify(quarter.formUrl)} is not a valid URL`);
+    }
+    if (url) {
+      if (url.protocol !== "https:") add("error", "Form URL must use https");
+      if (!/(^|\.)smartsheet\.(com|eu)$/i.test(url.hostname)) {
+        add("warning", `Form URL host ${url.hostname} is not a Smartsheet host`);
+      }
+    }
+    if (quarter.status !== "placeholder" && isPlaceholderQuarter(quarter)) {
+      add("error", "Form ID is not a Smartsheet form ID or is not part of the form URL");
+    }
+
+    for (const [key, override] of Object.entries(quarter.fieldOverrides ?? {})) {
+      if (!config.fieldDefinitions[key]) {
+        add("warning", `Field override for "${key}" names a field that is not defined`);
+      }
+      lintSelector(findings, `${target}:${key}`, override.locator);
+    }
+
+    const resolved = applySelectorHotfix(
+      resolveFieldDefinitions(quarter.fieldOverrides, config.fieldDefinitions),
+      config.hotfix
+    );
+    lintFormFields(findings, resolved, config.fieldOrder, { name: quarter.name, base });
+  }
+}
+
+/**
+ * Statically checks the automation configuration
+ *
+ * @param config - Configuration to check (defaults to the one the bot runs with)
+ * @returns Findings, errors first
+ */
+export function lintAutomationConfig(
+  config: AutomationConfigSnapshot = getAutomationConfigSnapshot()
+): AutomationConfigLintReport {
+  const findings: AutomationConfigFinding[] = [];
+  const base = applySelectorHotfix(config.fieldDefinitions, config.hotfix);
+
+  lintLoginSteps(findings, config.loginSteps);
+  lintFieldOrder(findings, config.fieldDefinitions, config.fieldOrder);
+  for (const [key, definition] of Object.entries(config.fieldDefinitions)) {
+    lintSelector(findings, `field:${key}`, definition.locator);
+  }
+  lintFormFields(findings, base, config.fieldOrder, null);
+  config.submitButtonSelectors.forEach((selector) => lintSelector(findings, "submit-button", selector));
+  if (config.hotfix) {
+    const target = `hotfix:${config.hotfix.id}`;
+    for (const [key, locator] of Object.entries(config.hotfix.fields ?? {})) {
+      lintSelector(findings, `${target}:${key}`, locator);
+    }
+    config.hotfix.submitButton?.forEach((selector) => lintSelector(findings, target, selector));
+  }
+  lintQuarters(findings, config, base);
+
+  return summarizeFindings(findings);
+}
+
+/**
+ * Builds a report from findings, errors first
+ */
+export function summarizeFindings(findings: AutomationConfigFinding[]): AutomationConfigLintReport {
+  const sorted = [...findings].sort(
+    (a, b) => Number(a.severity === "warning") - Number(b.severity === "warning")
+  );
+  const errors = sorted.filter((finding) => finding.severity === "error").length;
+  return { ok: errors === 0, errors, warnings: sorted.length - errors, findings: sorted };
+}
+
+/**
+ * Requests each quarter's form URL and reports the ones that do not answer
+ * or answer with an error status; placeholder quarters are skipped
+ */
+export async function checkQuarterFormReachability(
+  quarters: QuarterDefinition[] = QUARTER_DEFINITIONS,
+  fetchFn: typeof fetch = fetch
+): Promise<AutomationConfigFinding[]> {
+  const checks = quarters
+    .filter((quarter) => !isPlaceholderQuarter(quarter))
+    .map(async (quarter): Promise<AutomationConfigFinding | null> => {
+      const target = `quarter:${quarter.id}`;
+      try {
+        const response = await fetchFn(quarter.formUrl, {
+          method: "GET",
+          redirect: "follow",
+          signal: AbortSignal.timeout(REACHABILITY_TIMEOUT_MS),
+        });
+        return response.status >= 400
+          ? { severity: "error", area: "quarters", target, message: `Form URL answered HTTP ${response.status}` }
+          : null;
+      } catch (err: unknown) {
+        return {
+          severity: "error",
+          area: "quarters",
+          target,
+          message: `Form URL could not be reached: ${err instanceof Error ? err.message : String(err)}`,
+        };
+      }
+    });
+  return (await Promise.all(checks)).filter((finding): finding is AutomationConfigFinding => finding !== null);
+}
@@ -0,0 +1,65 @@
+/**
+ * Fill speed profiles: how `FormInteractor` enters a value into a field.
+ *
+ * - `inject` profiles set the value in one step (Playwright `fill`)
+ * - `type` profiles send keystrokes (Playwright `pressSequentially`), optionally
+ *   with a delay between keys, for forms that only react to real key events
+ *
+ * `fieldSettleMs` adds a pause after each field for UIs that validate
+ * asynchronously. The active profile comes from `FILL_SPEED_PROFILE`; the fill
+ * benchmark compares profiles before a default is changed for everyone.
+ */
+
+export type FillMode = "inject" | "type";
+
+export interface FillSpeedProfile {
+  name: string;
+  mode: FillMode;
+  /** Delay between keystrokes when typing */
+  keystrokeDelayMs: number;
+  /** Pause after each field is filled */
+  fieldSettleMs: number;
+}
+
+export const FILL_SPEED_PROFILES: Record<string, FillSpeedProfile> = {
+  inject: { name: "inject", mode: "inject", keystrokeDelayMs: 0, fieldSettleMs: 0 },
+  "inject-settled": {
+    name: "inject-settled",
+    mode: "inject",
+    keystrokeDelayMs: 0,
+    fieldSettleMs: 200,
+  },
+  "type-fast": { name: "type-fast", mode: "type", keystrokeDelayMs: 0, fieldSettleMs: 0 },
+  "type-human": {
+    name: "type-human",
+    mode: "type",
+    keystrokeDelayMs: 35,
+    fieldSettleMs: 100,
+  },
+};
+
+export const DEFAULT_FILL_PROFILE = "inject";
+
+/**
+ * Looks up a profile by name
+ *
+ * @throws Error if the name is not a known profile
+ */
+export function getFillProfile(name: string): FillSpeedProfile {
+  const profile = FILL_SPEED_PROFILES[name];
+  if (!profile) {
+    throw new Error(
+      `Unknown fill speed profile '${name}' (expected one of: ${Object.keys(FILL_SPEED_PROFILES).join(", ")})`
+    );
+  }
+  return profile;
+}
+
+/**
+ * Profile selected by `FILL_SPEED_PROFILE`, falling back to the default
+ * when unset or unknown
+ */
+export function getActiveFillProfile(): FillSpeedProfile {
+  const name = process.env["FILL_SPEED_PROFILE"] ?? DEFAULT_FILL_PROFILE;
+  return FILL_SPEED_PROFILES[name] ?? getFillProfile(DEFAULT_FILL_PROFILE);
+}
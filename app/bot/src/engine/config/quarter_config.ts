@@ -32,12 +32,31 @@
  * 3. Update `QUARTER_DEFINITIONS` to contain ONLY these two quarters
  * 4. Ensure `startDate`/`endDate` use `YYYY-MM-DD` format
  * 5. Set `formUrl` + `formId` to the matching Smartsheet form URLs/IDs
+ * 6. Optionally set `lockDate` to the last day the form accepts entries
+ *    (can be overridden without a release via the QUARTER_LOCK_DATES env var)
+ * 7. Optionally set `fieldOverrides` when the quarter's form differs from
+ *    `FIELD_DEFINITIONS` (e.g. a field became mandatory or its label changed)
+ * 8. Optionally set `titlePattern` if the form's title doesn't contain the
+ *    quarter name (the bot refuses to submit to a form whose title doesn't match)
+ * 9. If the quarter's form is not published yet, add it with
+ *    `status: "placeholder"`; submissions for its dates are refused with a
+ *    clear error instead of navigating to a dead URL
  *
  * **Date Format:**
  * Callers who start with `mm/dd/yyyy` should convert before calling this module
  * (see `_validateQuarterMatch()` in `core/bot_orchestation.ts` for an example).
  */
 
+/**
+ * Per-quarter change to one entry of `FIELD_DEFINITIONS`
+ */
+export interface QuarterFieldOverride {
+  /** false makes the field mandatory for rows where it applies */
+  optional?: boolean;
+  /** Replaces the field's default locator on this quarter's form */
+  locator?: string;
+}
+
 /**
  * Quarter definition interface
  */
@@ -54,6 +73,65 @@ export interface QuarterDefinition {
   formUrl: string;
   /** SmartSheet form ID extracted from URL */
   formId: string;
+  /** Last day (YYYY-MM-DD) the form accepts entries; omit if it stays open */
+  lockDate?: string;
+  /** Field definition changes for this quarter's form, keyed by field key */
+  fieldOverrides?: Record<string, QuarterFieldOverride>;
+  /**
+   * Regular expression (case-insensitive) the live form's title or header must
+   * match; defaults to the quarter name with flexible separators
+   */
+  titlePattern?: string;
+  /**
+   * "placeholder" until the real form is published; "locked" when the form
+   * stopped accepting entries before (or without) a lock date. Omit once
+   * configured (see `getQuarterFormStatus`).
+   */
+  status?: QuarterFormStatus;
+}
+
+/**
+ * Whether a quarter's form can be submitted to
+ * - configured: a real form that accepts entries
+ * - placeholder: no real form yet (marked, or the form ID is not a Smartsheet ID)
+ * - locked: the form no longer accepts entries
+ */
+export type QuarterFormStatus = "configured" | "placeholder" | "locked";
+
+/**
+ * Quarter as listed to the app, with its form status
+ */
+export interface QuarterSummary {
+  id: string;
+  name: string;
+  startDate: string;
+  endDate: string;
+  formUrl: string;
+  formId: string;
+  lockDate: string | null;
+  status: QuarterFormStatus;
+  /** Why the form cannot be submitted to; null when configured */
+  statusMessage: string | null;
+}
+
+/**
+ * Closure state of the quarter a date falls into
+ * - open: form accepts entries
+ * - closing: form locks within QUARTER_LOCK_WARNING_DAYS
+ * - closed: lock date has passed, or the quarter has left the rolling window
+ * - unavailable: no form configured for the date yet (future, gap or placeholder)
+ * - invalid: not a YYYY-MM-DD date
+ */
+export type QuarterClosureState = "open" | "closing" | "closed" | "unavailable" | "invalid";
+
+export interface QuarterClosure {
+  state: QuarterClosureState;
+  quarter: QuarterDefinition | null;
+  lockDate: string | null;
+  /** Days until the lock date (negative once passed); null when no lock date */
+  daysUntilLock: number | null;
+  /** User-facing explanation; null when open */
+  message: string | null;
 }
 
 /**
@@ -88,6 +166,8 @@ export const QUARTER_DEFINITIONS: QuarterDefinition[] = [
     formUrl:
       "https://app.smartsheet.com/b/form/0199fabee6497e60abb6030c48d84585",
     formId: "0199fabee6497e60abb6030c48d84585",
+    // The Q4 form made Tool mandatory (it was optional through Q3)
+    fieldOverrides: { tool: { optional: false } },
   },
   {
     id: "Q1-2026",
@@ -243,6 +323,16 @@ export function getQuarterById(quarterId: string): QuarterDefinition | null {
   return QUARTER_DEFINITIONS.find((q) => q.id === quarterId) || null;
 }
 
+/**
+ * Gets the quarter whose Smartsheet form has the given ID
+ *
+ * @param formId - Form ID from a form configuration
+ * @returns Quarter definition if found, null otherwise (e.g. the mock form)
+ */
+export function getQuarterByFormId(formId: string): QuarterDefinition | null {
+  return QUARTER_DEFINITIONS.find((q) => q.formId === formId) || null;
+}
+
 /**
  * Gets the current quarter based on today's date
  *
@@ -256,3 +346,209 @@ export function getCurrentQuarter(): QuarterDefinition | null {
   }
   return getQuarterForDate(todayStr);
 }
+
+/**
+ * Pattern the live form title must match for a quarter. "Q4 2025" also
+ * matches "Q4-2025", "Q4_2025" and "Q42025".
+ */
+export function getQuarterTitlePattern(quarter: QuarterDefinition): RegExp {
+  if (quarter.titlePattern) {
+    return new RegExp(quarter.titlePattern, "i");
+  }
+  const source = quarter.name
+    .trim()
+    .split(/\s+/)
+    .map((part) => part.replace(/[.*+?^${}()|[\]\\]/g, "\\$&"))
+    .join("[\\s_-]*");
+  return new RegExp(source, "i");
+}
+
+/**
+ * Checks the text found on a loaded form (page title, headings) against the
+ * quarter it is supposed to belong to
+ */
+export function formTitleMatchesQuarter(
+  quarter: QuarterDefinition,
+  titleText: string
+): boolean {
+  return getQuarterTitlePattern(quarter).test(titleText);
+}
+
+/** Warn this many days before a quarter's lock date */
+export const QUARTER_LOCK_WARNING_DAYS = 7;
+
+const ISO_DATE_PATTERN = /^\d{4}-\d{2}-\d{2}$/;
+
+/**
+ * Lock date overrides from QUARTER_LOCK_DATES, e.g. "Q4-2025=2026-01-15,Q1-2026=2026-04-15"
+ */
+function parseLockDateOverrides(value: string | undefined): Map<string, string> {
+  const overrides = new Map<string, string>();
+  for (const pair of (value ?? "").split(",")) {
+    const [id, date] = pair.split("=").map((part) => part.trim());
+    if (id && date && ISO_DATE_PATTERN.test(date)) {
+      overrides.set(id, date);
+    }
+  }
+  return overrides;
+}
+
+const LOCK_DATE_OVERRIDES = parseLockDateOverrides(process.env["QUARTER_LOCK_DATES"]);
+
+const toLocalIsoDate = (date: Date): string =>
+  `${date.getFullYear()}-${String(date.getMonth() + 1).padStart(2, "0")}-${String(date.getDate()).padStart(2, "0")}`;
+
+const daysBetween = (fromIso: string, toIso: string): number =>
+  Math.round((Date.parse(`${toIso}T00:00:00Z`) - Date.parse(`${fromIso}T00:00:00Z`)) / 86_400_000);
+
+/**
+ * Gets the effective lock date for a quarter (env override first)
+ */
+export function getQuarterLockDate(quarter: QuarterDefinition): string | null {
+  return LOCK_DATE_OVERRIDES.get(quarter.id) ?? quarter.lockDate ?? null;
+}
+
+/** Smartsheet form IDs are 32 hex characters */
+const SMARTSHEET_FORM_ID_PATTERN = /^[0-9a-f]{32}$/i;
+
+/**
+ * Whether a quarter still points at a placeholder instead of a real form
+ */
+export function isPlaceholderQuarter(quarter: QuarterDefinition): boolean {
+  return (
+    quarter.status === "placeholder" ||
+    !SMARTSHEET_FORM_ID_PATTERN.test(quarter.formId) ||
+    /^0+$/.test(quarter.formId) ||
+    !quarter.formUrl.includes(quarter.formId)
+  );
+}
+
+/**
+ * Form status of a quarter; a passed lock date counts as locked
+ *
+ * @param today - Reference date (defaults to now)
+ */
+export function getQuarterFormStatus(
+  quarter: QuarterDefinition,
+  today: Date = new Date()
+): QuarterFormStatus {
+  if (isPlaceholderQuarter(quarter)) return "placeholder";
+  if (quarter.status === "locked") return "locked";
+  const lockDate = getQuarterLockDate(quarter);
+  if (lockDate && daysBetween(toLocalIsoDate(today), lockDate) < 0) return "locked";
+  return "configured";
+}
+
+/**
+ * User-facing reason a quarter's form cannot be submitted to, or null
+ */
+export function getQuarterFormStatusMessage(
+  quarter: QuarterDefinition,
+  status: QuarterFormStatus,
+  today: Date = new Date()
+): string | null {
+  switch (status) {
+    case "placeholder":
+      return `The ${quarter.name} form has not been set up yet; entries dated ${quarter.startDate} to ${quarter.endDate} cannot be submitted until its form is configured`;
+    case "locked": {
+      const lockDate = getQuarterLockDate(quarter);
+      return lockDate && daysBetween(toLocalIsoDate(today), lockDate) < 0
+        ? `The ${quarter.name} form closed on ${lockDate} and no longer accepts entries`
+        : `The ${quarter.name} form is locked and no longer accepts entries`;
+    }
+    case "configured":
+      return null;
+  }
+}
+
+/**
+ * Every configured quarter with its form status, earliest first
+ *
+ * @param today - Reference date (defaults to now)
+ */
+export function getQuarters(today: Date = new Date()): QuarterSummary[] {
+  return [...QUARTER_DEFINITIONS]
+    .sort((a, b) => a.startDate.localeCompare(b.startDate))
+    .map((quarter) => {
+      const status = getQuarterFormStatus(quarter, today);
+      return {
+        id: quarter.id,
+        name: quarter.name,
+        startDate: quarter.startDate,
+        endDate: quarter.endDate,
+        formUrl: quarter.formUrl,
+        formId: quarter.formId,
+        lockDate: getQuarterLockDate(quarter),
+        status,
+        statusMessage: getQuarterFormStatusMessage(quarter, status, today),
+      };
+    });
+}
+
+/**
+ * Determines whether the form for a date's quarter still accepts entries
+ *
+ * @param dateStr - Date in YYYY-MM-DD format
+ * @param today - Reference date (defaults to now)
+ */
+export function getQuarterClosure(dateStr: string, today: Date = new Date()): QuarterClosure {
+  const closure = (
+    state: QuarterClosureState,
+    message: string | null,
+    quarter: QuarterDefinition | null = null,
+    lockDate: string | null = null,
+    daysUntilLock: number | null = null
+  ): QuarterClosure => ({ state, quarter, lockDate, daysUntilLock, message });
+
+  if (!dateStr || !ISO_DATE_PATTERN.test(dateStr)) {
+    return closure("invalid", "Please enter a valid date");
+  }
+
+  const quarter = getQuarterForDate(dateStr);
+  if (!quarter) {
+    const earliestStart = QUARTER_DEFINITIONS.map((q) => q.startDate).sort()[0];
+    if (earliestStart && dateStr < earliestStart) {
+      return closure(
+        "closed",
+        `${dateStr} is in a past quarter whose form is closed; it can no longer be submitted`
+      );
+    }
+    return closure("unavailable", validateQuarterAvailability(dateStr));
+  }
+
+  if (isPlaceholderQuarter(quarter)) {
+    return closure("unavailable", getQuarterFormStatusMessage(quarter, "placeholder"), quarter);
+  }
+
+  if (quarter.status === "locked") {
+    return closure("closed", getQuarterFormStatusMessage(quarter, "locked", today), quarter);
+  }
+
+  const lockDate = getQuarterLockDate(quarter);
+  if (!lockDate) {
+    return closure("open", null, quarter);
+  }
+
+  const daysUntilLock = daysBetween(toLocalIsoDate(today), lockDate);
+  if (daysUntilLock < 0) {
+    return closure(
+      "closed",
+      `The ${quarter.name} form closed on ${lockDate}; entries dated ${dateStr} can no longer be submitted`,
+      quarter,
+      lockDate,
+      daysUntilLock
+    );
+  }
+  if (daysUntilLock <= QUARTER_LOCK_WARNING_DAYS) {
+    return closure(
+      "closing",
+      daysUntilLock === 0
+        ? `The ${quarter.name} form closes today; submit entries dated ${dateStr} now`
+        : `The ${quarter.name} form closes on ${lockDate} (${daysUntilLock} day${daysUntilLock === 1 ? "" : "s"}); submit entries dated ${dateStr} before then`,
+      quarter,
+      lockDate,
+      daysUntilLock
+    );
+  }
+  return closure("open", null, quarter, lockDate, daysUntilLock);
+}
@@ -0,0 +1,83 @@
+/**
+ * Selector hotfixes.
+ *
+ * When Smartsheet changes its markup and a selector stops matching, admins
+ * can publish a small signed hotfix instead of waiting for a release: field
+ * locators by field key and extra submit button selectors. The desktop app
+ * fetches and verifies it before a submission and configures it here for
+ * that run only; this module just applies whatever it is given.
+ *
+ * Precedence for a field locator: hotfix, then the quarter's
+ * `fieldOverrides`, then the project-specific tool locator, then
+ * `FIELD_DEFINITIONS`.
+ */
+import type { FieldDefinition } from "./automation_config";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export interface SelectorHotfix {
+  /** Shown in logs and run reports, e.g. "2025-03-14-project-locator" */
+  id: string;
+  /** Locators by field key (see FIELD_DEFINITIONS) */
+  fields?: Record<string, string>;
+  /** Tried before the built-in submit button selectors */
+  submitButton?: string[];
+}
+
+let activeHotfix: SelectorHotfix | null = null;
+
+/**
+ * Sets the hotfix used by bots created from now on; null removes it
+ */
+export function configureSelectorHotfix(hotfix: SelectorHotfix | null): void {
+  activeHotfix = hotfix
+    ? {
+        id: hotfix.id,
+        ...(hotfix.fields ? { fields: { ...hotfix.fields } } : {}),
+        ...(hotfix.submitButton ? { submitButton: [...hotfix.submitButton] } : {}),
+      }
+    : null;
+  if (activeHotfix) {
+    botLogger.info("Selector hotfix configured", {
+      id: activeHotfix.id,
+      fields: Object.keys(activeHotfix.fields ?? {}),
+      submitButtonSelectors: activeHotfix.submitButton?.length ?? 0,
+    });
+  }
+}
+
+export function getSelectorHotfix(): SelectorHotfix | null {
+  return activeHotfix;
+}
+
+/**
+ * Applies a hotfix's field locators on top of resolved field definitions
+ */
+export function applySelectorHotfix(
+  definitions: Record<string, FieldDefinition>,
+  hotfix: SelectorHotfix | null = activeHotfix
+): Record<string, FieldDefinition> {
+  if (!hotfix?.fields) return definitions;
+  const patched: Record<string, FieldDefinition> = { ...definitions };
+  for (const [key, locator] of Object.entries(hotfix.fields)) {
+    const base = definitions[key];
+    if (!base) {
+      botLogger.warn("Ignoring selector hotfix for unknown field", {
+        hotfix: hotfix.id,
+        fieldKey: key,
+      });
+      continue;
+    }
+    patched[key] = { ...base, locator };
+  }
+  return patched;
+}
+
+/**
+ * Submit button selectors to try, hotfix selectors first
+ */
+export function getSubmitButtonSelectors(
+  builtIn: string[],
+  hotfix: SelectorHotfix | null = activeHotfix
+): string[] {
+  return [...new Set([...(hotfix?.submitButton ?? []), ...builtIn])];
+}
@@ -8,16 +8,76 @@ export * from './scripts/core/index';
 
 // Export utilities
 export { checkAborted, createCancelledResult, setupAbortHandler } from './scripts/utils/abort-utils';
-export { processEntriesByQuarter } from './scripts/utils/quarter-processing';
+export { processEntriesByQuarter, createMockFormConfig } from './scripts/utils/quarter-processing';
+export {
+  SUBMISSION_ORDERS,
+  DEFAULT_SUBMISSION_ORDER,
+  resolveSubmissionOrder,
+  configureSubmissionOrder,
+  getSubmissionOrder,
+  orderEntriesForSubmission,
+  orderQuarterGroups,
+  type SubmissionOrder,
+  type SubmissionOrderGroup
+} from './scripts/utils/submission-order';
+export {
+  applySubmissionKey,
+  appendSubmissionKeyToDescription,
+  extractSubmissionKeys,
+  resolveSubmissionKeyMode,
+  configureSubmissionKeyMode,
+  getSubmissionKeyMode,
+  SUBMISSION_KEY_MODES,
+  SUBMISSION_KEY_LABEL,
+  SUBMISSION_KEY_PATTERN,
+  type SubmissionKeyMode
+} from './scripts/utils/submission-key';
+export {
+  applyCorrection,
+  formatCorrectionNote,
+  CORRECTION_LABEL
+} from './scripts/utils/correction';
 
 // Export config utilities
-export { validateQuarterAvailability, QUARTER_DEFINITIONS, getQuarterForDate, groupEntriesByQuarter, type QuarterDefinition } from './engine/config/quarter_config';
+export {
+  validateQuarterAvailability,
+  QUARTER_DEFINITIONS,
+  QUARTER_LOCK_WARNING_DAYS,
+  getQuarterForDate,
+  getQuarterByFormId,
+  getQuarterById,
+  getQuarterClosure,
+  getQuarterLockDate,
+  getQuarterFormStatus,
+  getQuarterFormStatusMessage,
+  getQuarters,
+  isPlaceholderQuarter,
+  groupEntriesByQuarter,
+  type QuarterDefinition,
+  type QuarterFieldOverride,
+  type QuarterClosure,
+  type QuarterClosureState,
+  type QuarterFormStatus,
+  type QuarterSummary
+} from './engine/config/quarter_config';
 export * from './engine/config/automation_config';
+export * from './engine/config/selector_hotfix';
+export * from './engine/config/config_lint';
 
 // Export internal modules for testing (use with caution)
 export * from './scripts/core/bot_orchestation';
-export { LoginManager, type BrowserManager } from './scripts/utils/authentication_flow';
+export { LoginManager, isAuthRedirectUrl, type BrowserManager } from './scripts/utils/authentication_flow';
 export * from './engine/browser/browser_launcher';
 export * from './engine/browser/webform_session';
 export * from './engine/browser/form_interactor';
-export * from './engine/browser/submission_monitor';
\ No newline at end of file
+export * from './engine/browser/dropdown_resolutions';
+export * from './engine/browser/submission_monitor';
+export * from './engine/browser/form_feedback';
+export * from './engine/browser/form_inspector';
+export * from './engine/browser/postconditions';
+export * from './engine/browser/verification_level';
+export * from './engine/browser/rate_limiter';
+export * from './engine/browser/failure_screenshots';
+export * from './engine/browser/step_annotator';
+export * from './engine/browser/run_recorder';
+export * from './engine/browser/browser_version';
@@ -25,6 +25,7 @@
 
 import * as Cfg from "../../engine/config/automation_config";
 import { BrowserLauncher } from "../../engine/browser/browser_launcher";
+import type { BrowserVersionCheck } from "../../engine/browser/browser_version";
 import {
   WebformSessionManager,
   type FormConfig,
@@ -34,13 +35,45 @@ import {
   type FieldSpec,
 } from "../../engine/browser/form_interactor";
 import { SubmissionMonitor } from "../../engine/browser/submission_monitor";
+import { formatFormFeedback } from "../../engine/browser/form_feedback";
+import { captureFailureScreenshot } from "../../engine/browser/failure_screenshots";
+import {
+  StepAnnotator,
+  isStepAnnotationEnabled,
+} from "../../engine/browser/step_annotator";
+import { RunRecorder } from "../../engine/browser/run_recorder";
+import { confirmDropdownResolutions } from "../../engine/browser/dropdown_resolutions";
+import {
+  applySelectorHotfix,
+  getSelectorHotfix,
+  type SelectorHotfix,
+} from "../../engine/config/selector_hotfix";
+import {
+  getSubmissionRateLimiter,
+  type SubmissionRateLimiter,
+} from "../../engine/browser/rate_limiter";
+import {
+  getActiveFillProfile,
+  type FillSpeedProfile,
+} from "../../engine/config/fill_profiles";
 import {
   LoginManager,
+  isAuthRedirectUrl,
   type BrowserManager,
 } from "../utils/authentication_flow";
 import { botLogger } from "@sheetpilot/shared/logger";
-import { getQuarterForDate } from "../../engine/config/quarter_config";
-import { appSettings } from "@sheetpilot/shared";
+import {
+  formTitleMatchesQuarter,
+  getQuarterByFormId,
+  getQuarterForDate,
+  type QuarterDefinition,
+} from "../../engine/config/quarter_config";
+import {
+  appSettings,
+  type SubmissionFieldLocator,
+  type SubmissionFormRunReport,
+  type SubmissionRowReport,
+} from "@sheetpilot/shared";
 import { checkAborted, setupAbortHandler } from "../utils/abort-utils";
 
 /**
@@ -92,12 +125,35 @@ export class BotOrchestrator {
   sessionManager: WebformSessionManager | null = null;
   /** Form interactor for field filling */
   formInteractor: FormInteractor | null = null;
+  /** Element outlines and step banner for visible runs */
+  annotator: StepAnnotator | null = null;
+  /**
+   * Records browser interactions for replay in tests; created in `start()`
+   * when RECORD_RUN_DIR is set, or assign one before `start()`
+   */
+  recorder: RunRecorder | null = null;
   /** Login manager instance for authentication */
   login_manager: LoginManager | null = null;
   /** Optional callback for progress updates during automation */
   progress_callback: ((pct: number, msg: string) => void) | undefined;
   /** Dynamic form configuration */
   formConfig: FormConfig;
+  /** How fields are filled; set before `start()` to override FILL_SPEED_PROFILE */
+  fillProfile: FillSpeedProfile = getActiveFillProfile();
+  /** Paces submissions and backs off when Smartsheet throttles */
+  rateLimiter: SubmissionRateLimiter = getSubmissionRateLimiter();
+  /** Quarter whose form this batch targets (null for forms outside QUARTER_DEFINITIONS) */
+  quarter: QuarterDefinition | null;
+  /** Field definitions with the quarter's overrides and selector hotfix applied */
+  fieldDefinitions: Record<string, Cfg.FieldDefinition>;
+  /** Selector hotfix configured when this bot was created, if any */
+  selectorHotfix: SelectorHotfix | null;
+  /** Launched browser's version check; set by `start()` */
+  browserVersionCheck: BrowserVersionCheck | null = null;
+  /** Detail of the last `run_automation` call (timings, locators, outcomes) */
+  report: SubmissionFormRunReport | null = null;
+  /** Row report being filled in by `_processRow` */
+  private currentRow: SubmissionRowReport | null = null;
 
   /**
    * Creates a new BotOrchestrator instance
@@ -130,6 +186,18 @@ export class BotOrchestrator {
     });
     this.progress_callback = progress_callback;
     this.formConfig = formConfig;
+    this.quarter = getQuarterByFormId(formConfig.FORM_ID);
+    this.selectorHotfix = getSelectorHotfix();
+    this.fieldDefinitions = applySelectorHotfix(
+      Cfg.resolveFieldDefinitions(this.quarter?.fieldOverrides),
+      this.selectorHotfix
+    );
+    if (this.quarter?.fieldOverrides) {
+      botLogger.info("Applying quarter field overrides", {
+        quarter: this.quarter.id,
+        overrides: this.quarter.fieldOverrides,
+      });
+    }
     this.browserLauncher = new BrowserLauncher(this.headless);
   }
 
@@ -144,6 +212,7 @@ export class BotOrchestrator {
 
       // Launch browser using BrowserLauncher
       const browser = await this.browserLauncher.launch();
+      this.browserVersionCheck = this.browserLauncher.getVersionCheck();
       botLogger.debug("Browser launched successfully");
 
       // Initialize session manager for context/page management
@@ -151,6 +220,11 @@ export class BotOrchestrator {
       await this.sessionManager.initContexts(1);
       botLogger.debug("Session manager initialized with 1 context");
 
+      if (!this.recorder && this.cfg.RECORD_RUN_DIR) {
+        this.recorder = new RunRecorder(this.formConfig);
+      }
+      this.recorder?.attach(this.sessionManager.getDefaultPage());
+
       // Navigate to the form BASE_URL before doing anything else
       // This ensures pages have actual content to interact with
       botLogger.info("Navigating to form base URL", {
@@ -160,10 +234,23 @@ export class BotOrchestrator {
       botLogger.debug("Successfully navigated to form base URL");
 
       // Initialize form interactor for field filling
-      this.formInteractor = new FormInteractor(() =>
-        this.sessionManager!.getDefaultPage()
+      this.formInteractor = new FormInteractor(
+        () => this.sessionManager!.getDefaultPage(),
+        this.fillProfile
       );
-      botLogger.debug("Form interactor initialized");
+      botLogger.debug("Form interactor initialized", {
+        fillProfile: this.fillProfile.name,
+      });
+
+      this.annotator = new StepAnnotator(
+        () => this.sessionManager!.getDefaultPage(),
+        isStepAnnotationEnabled(this.headless)
+      );
+      this.formInteractor.annotator = this.annotator;
+      this.formInteractor.recorder = this.recorder;
+      if (this.annotator.enabled) {
+        botLogger.info("Highlighting interactions in the browser window");
+      }
 
       // Create a BrowserManager adapter for LoginManager
       const browserManagerAdapter: BrowserManager = {
@@ -190,6 +277,18 @@ export class BotOrchestrator {
    */
   async close(): Promise<void> {
     botLogger.info("Closing BotOrchestrator and all composable helpers");
+    if (this.recorder) {
+      this.recorder.detach();
+      if (this.cfg.RECORD_RUN_DIR) {
+        try {
+          this.recorder.saveTo(this.cfg.RECORD_RUN_DIR);
+        } catch (err: unknown) {
+          botLogger.warn("Could not save run recording", {
+            error: err instanceof Error ? err.message : String(err),
+          });
+        }
+      }
+    }
     try {
       await this.sessionManager?.closeAll();
       await this.browserLauncher.closeAll();
@@ -197,6 +296,7 @@ export class BotOrchestrator {
     } finally {
       this.sessionManager = null;
       this.formInteractor = null;
+      this.annotator = null;
       this.login_manager = null;
     }
   }
@@ -445,6 +545,57 @@ export class BotOrchestrator {
     return null;
   }
 
+  /**
+   * Replays the login flow when the page was redirected to sign-in because
+   * the session timed out. Never throws; a failed re-login is logged and the
+   * row fails as it would have.
+   * @private
+   * @param email - User email for authentication
+   * @param password - User password for authentication
+   * @param rowIndex - Row being processed, for logging
+   * @param report - Run report counting re-logins
+   * @returns True if the session had expired and the login was replayed
+   */
+  private async _reloginIfSessionExpired(
+    email: string,
+    password: string,
+    rowIndex: number,
+    report: SubmissionFormRunReport
+  ): Promise<boolean> {
+    let url: string;
+    try {
+      url = this.require_page().url();
+    } catch {
+      return false;
+    }
+    if (!isAuthRedirectUrl(url) || !this.login_manager) return false;
+
+    const relogins = report.relogins ?? 0;
+    if (relogins >= Cfg.MAX_MID_RUN_RELOGINS) {
+      botLogger.error("Session expired again; no re-login attempts left", {
+        rowIndex,
+        relogins,
+      });
+      return false;
+    }
+    report.relogins = relogins + 1;
+    try {
+      await this.annotator?.step("Session expired: logging in again");
+      await this.login_manager.relogin(email, password, 0);
+      botLogger.info("Resuming after re-login", {
+        rowIndex,
+        relogins: report.relogins,
+      });
+      return true;
+    } catch (err: unknown) {
+      botLogger.error("Could not log in again after session expired", {
+        rowIndex,
+        error: err instanceof Error ? err.message : String(err),
+      });
+      return false;
+    }
+  }
+
   /**
    * Attempts to recover from a row processing error by navigating back to the base form URL
    * @private
@@ -492,6 +643,20 @@ export class BotOrchestrator {
     // Check if aborted before processing each row
     checkAborted(abortSignal, `Automation (row ${rowIndex + 1}/${totalRows})`);
 
+    const rowReport: SubmissionRowReport = {
+      rowIndex,
+      entryId: null,
+      outcome: "skipped",
+      error: null,
+      fillMs: null,
+      submitMs: null,
+      submitAttempts: 0,
+      throttleRetries: 0,
+      fields: {},
+      screenshot: null,
+    };
+    this.report?.rows.push(rowReport);
+
     // Skip completed rows: callers can pass a sheet export that already includes
     // status for prior submissions.
     if (
@@ -513,6 +678,9 @@ export class BotOrchestrator {
 
     const rowTimer = botLogger.startTimer("row-process");
     let rowOutcome: "success" | "error" | "skipped" = "error";
+    let rowError: string | null = null;
+    this.currentRow = rowReport;
+    this.formInteractor?.dropdownResolutions.clear();
 
     try {
       const progress = this._calculateProgress(rowIndex, totalRows);
@@ -533,7 +701,16 @@ export class BotOrchestrator {
           reason: "Missing required fields",
         });
         rowOutcome = "skipped";
-        return [false, "Missing required fields"];
+        rowError = "Missing required fields";
+        return [false, rowError];
+      }
+      const missingQuarterField = this._find_missing_quarter_required_field(fields);
+      if (missingQuarterField) {
+        const reason = `${missingQuarterField} is required on the ${this.quarter?.name ?? "selected"} form`;
+        botLogger.warn("Row skipped", { rowIndex, reason });
+        rowOutcome = "skipped";
+        rowError = reason;
+        return [false, reason];
       }
 
       // Validate quarter match before filling: submitting a Q3 entry to a Q4 form is
@@ -549,35 +726,48 @@ export class BotOrchestrator {
             error: quarterError,
           });
           rowOutcome = "error";
+          rowError = quarterError;
           return [false, quarterError];
         }
       }
 
       // Ensure the form has loaded and the network has settled before interacting.
       await this.sessionManager!.waitForFormReady();
+      await this.annotator?.step(`Row ${rowIndex + 1} of ${totalRows}: filling fields`);
 
       // Fill fields
       botLogger.verbose("Filling form fields", { rowIndex });
       const fillTimer = botLogger.startTimer("row-fill");
+      const fillStartedAt = Date.now();
       await this._fill_fields(fields);
+      rowReport.fillMs = Date.now() - fillStartedAt;
       fillTimer.done({ rowIndex });
 
       // Submit is optional: tests and debugging sometimes run in "fill-only" mode.
       if (Cfg.SUBMIT_FORM_AFTER_FILLING) {
         // Submit with retry (Initial + Level 1 + Level 2 = 3 attempts)
         const submitTimer = botLogger.startTimer("row-submit");
+        const submitStartedAt = Date.now();
         const submissionSuccess = await this._submitWithRetryWithFields(
           rowIndex,
           fields
         );
+        rowReport.submitMs = Date.now() - submitStartedAt;
         submitTimer.done({ rowIndex, success: submissionSuccess });
         if (!submissionSuccess) {
           rowOutcome = "error";
-          return [
-            false,
-            "Form submission failed after 3 attempts (initial + Level 1 retry + Level 2 retry)",
-          ];
+          rowError =
+            rowReport.rejection?.message ??
+            (rowReport.postconditionFailure
+              ? `Submission not confirmed: postcondition failed (${rowReport.postconditionFailure})`
+              : "Form submission failed after 3 attempts (initial + Level 1 retry + Level 2 retry)");
+          return [false, rowError];
         }
+        // The form accepted the row, so its ambiguous dropdown choices were right
+        confirmDropdownResolutions(
+          this.formInteractor?.dropdownResolutions.values() ?? [],
+          this.formInteractor?.dropdownStore ?? null
+        );
       }
 
       botLogger.info("Row completed successfully", { rowIndex });
@@ -587,7 +777,18 @@ export class BotOrchestrator {
       );
       rowOutcome = "success";
       return [true, null];
+    } catch (e: unknown) {
+      rowError = String((e as Error)?.message ?? e);
+      throw e;
     } finally {
+      rowReport.outcome =
+        rowOutcome === "success"
+          ? "submitted"
+          : rowOutcome === "error"
+            ? "failed"
+            : "skipped";
+      rowReport.error = rowError;
+      this.currentRow = null;
       rowTimer.done({ rowIndex, outcome: rowOutcome });
     }
   }
@@ -608,7 +809,7 @@ export class BotOrchestrator {
       attempt: 1,
       retryLevel: "initial",
     });
-    const success = await monitor.submitForm();
+    const success = await this._submitPaced(monitor, rowIndex);
 
     if (success) {
       botLogger.info("Initial submission succeeded", {
@@ -654,7 +855,7 @@ export class BotOrchestrator {
       attempt: 2,
       retryLevel: "level-1",
     });
-    const success = await monitor.submitForm();
+    const success = await this._submitPaced(monitor, rowIndex);
 
     if (success) {
       botLogger.info("Level 1 retry succeeded", {
@@ -715,7 +916,7 @@ export class BotOrchestrator {
       attempt: 3,
       retryLevel: "level-2",
     });
-    const success = await monitor.submitForm();
+    const success = await this._submitPaced(monitor, rowIndex);
 
     if (success) {
       botLogger.info("Level 2 retry succeeded", {
@@ -730,12 +931,97 @@ export class BotOrchestrator {
     return false;
   }
 
+  /**
+   * Submits once through the rate limiter. Throttled responses (429/503) are
+   * retried after a jittered backoff without using up a regular retry level.
+   * @private
+   * @param monitor - SubmissionMonitor instance
+   * @param rowIndex - Row index for logging
+   * @returns Promise resolving to true if submission succeeded, false otherwise
+   */
+  private async _submitPaced(
+    monitor: SubmissionMonitor,
+    rowIndex: number
+  ): Promise<boolean> {
+    const { maxThrottleRetries } = this.rateLimiter.getSettings();
+    for (let attempt = 0; ; attempt++) {
+      await this.rateLimiter.acquire();
+      const startedAt = Date.now();
+      if (this.currentRow) this.currentRow.submitAttempts++;
+      await this.annotator?.step(
+        `Row ${rowIndex + 1}: submitting (attempt ${this.currentRow?.submitAttempts ?? 1})`
+      );
+      const success = await monitor.submitForm();
+      if (this.currentRow) {
+        if (success && Object.keys(monitor.lastCaptures).length > 0) {
+          this.currentRow.captures = monitor.lastCaptures;
+        }
+        if (monitor.lastPostconditionFailure) {
+          this.currentRow.postconditionFailure = monitor.lastPostconditionFailure;
+        } else {
+          delete this.currentRow.postconditionFailure;
+        }
+      }
+      const throttle = monitor.lastThrottle;
+      if (!throttle) {
+        this.rateLimiter.reportCompleted(Date.now() - startedAt);
+        return success;
+      }
+      if (attempt >= maxThrottleRetries) {
+        botLogger.error("Submission still throttled after backoff retries", {
+          rowIndex,
+          throttleRetries: attempt,
+          status: throttle.status,
+        });
+        return false;
+      }
+      const backoffMs = this.rateLimiter.reportThrottle(attempt, throttle);
+      if (this.currentRow) this.currentRow.throttleRetries++;
+      botLogger.info("Retrying throttled submission after backoff", {
+        rowIndex,
+        throttleRetry: attempt + 1,
+        backoffMs,
+      });
+    }
+  }
+
+  /**
+   * Records validation errors the form showed on the row report. Retrying a
+   * rejected row resubmits the same data, so callers stop retrying.
+   * @private
+   * @param monitor - SubmissionMonitor instance
+   * @param rowIndex - Row index for logging
+   * @returns True if the form rejected the row
+   */
+  private _recordRejection(
+    monitor: SubmissionMonitor,
+    rowIndex: number
+  ): boolean {
+    const feedback = monitor.lastFeedback;
+    if (!feedback) return false;
+    const message = formatFormFeedback(feedback, this.fieldDefinitions);
+    if (this.currentRow) {
+      this.currentRow.rejection = {
+        message,
+        banner: feedback.banner,
+        fieldErrors: feedback.fieldErrors,
+      };
+    }
+    botLogger.error("Form rejected the row; not retrying", {
+      rowIndex,
+      reason: message,
+      fieldErrors: Object.keys(feedback.fieldErrors),
+    });
+    return true;
+  }
+
   /**
    * Submits form with two-level retry logic:
    * - Level 1 retry: Quick retry - just click submit again after 1s delay (no form re-fill)
    * - Level 2 retry: Full retry - re-fill form and submit after 2s delay
    *
    * Flow: Initial → failed → Level 1 retry → failed → Level 2 retry → failed → give up
+   * A submit the form rejects with validation errors is not retried.
    *
    * @private
    * @param rowIndex - Row index for logging
@@ -748,26 +1034,37 @@ export class BotOrchestrator {
   ): Promise<boolean> {
     const monitor = new SubmissionMonitor(
       () => this.require_page(),
-      this.formConfig.SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS
+      this.formConfig.SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS,
+      this.fieldDefinitions
     );
+    monitor.recorder = this.recorder;
 
     // Attempt 1: Initial submit
     let success = await this._attemptInitialSubmission(monitor, rowIndex);
     if (success) {
       return true;
     }
+    if (this._recordRejection(monitor, rowIndex)) {
+      return false;
+    }
 
     // Attempt 2: Level 1 retry - quick retry, just click submit again (no form re-fill)
     success = await this._attemptLevel1Retry(monitor, rowIndex);
     if (success) {
       return true;
     }
+    if (this._recordRejection(monitor, rowIndex)) {
+      return false;
+    }
 
     // Attempt 3: Level 2 retry - re-fill form and submit
     success = await this._attemptLevel2Retry(monitor, rowIndex, fields);
     if (success) {
       return true;
     }
+    if (this._recordRejection(monitor, rowIndex)) {
+      return false;
+    }
 
     botLogger.error("All submission attempts exhausted", {
       rowIndex,
@@ -784,6 +1081,8 @@ export class BotOrchestrator {
    * Notes:
    * - The workflow logs into context 0 once, then processes rows sequentially.
    *   This avoids cross-row state bleed and keeps the UI in a predictable state.
+   *   If the session times out mid-run (the page lands on a sign-in host), the
+   *   login is replayed and the run resumes from the row that failed.
    * - `AutomationResult.success` currently means “at least one row submitted”.
    *   A run that skips all rows (already complete) returns `success: false` but
    *   does not imply a system error.
@@ -802,6 +1101,28 @@ export class BotOrchestrator {
     const submitted: number[] = [];
     const failed_rows: Array<[number, string]> = [];
     const total_rows = df.length;
+    const startedAt = Date.now();
+    const report: SubmissionFormRunReport = {
+      formId: this.formConfig.FORM_ID,
+      formUrl: this.formConfig.BASE_URL,
+      quarterId: this.quarter?.id ?? null,
+      fillProfile: this.fillProfile.name,
+      startedAt: new Date(startedAt).toISOString(),
+      finishedAt: null,
+      durationMs: null,
+      loginMs: null,
+      titleCheck: "skipped",
+      ...(this.selectorHotfix ? { selectorHotfixId: this.selectorHotfix.id } : {}),
+      ...(this.browserVersionCheck
+        ? { browserVersion: this.browserVersionCheck.version }
+        : {}),
+      ...(this.browserVersionCheck?.message
+        ? { browserVersionWarning: this.browserVersionCheck.message }
+        : {}),
+      rows: [],
+      error: null,
+    };
+    this.report = report;
 
     // Register an abort handler that closes the browser immediately.
     // This limits “zombie” Chromium processes when a caller cancels mid-run.
@@ -827,7 +1148,10 @@ export class BotOrchestrator {
       if (!this.login_manager) {
         throw new Error("Login manager not initialized");
       }
+      await this.annotator?.step("Logging in");
+      const loginStartedAt = Date.now();
       await this.login_manager.run_login_steps(email, password, 0);
+      report.loginMs = Date.now() - loginStartedAt;
       loginTimer.done({ contextIndex: 0 });
 
       // Check if aborted after login
@@ -836,6 +1160,24 @@ export class BotOrchestrator {
       botLogger.info("Login complete", { progress: 20 });
       this.progress_callback?.(20, "Login complete");
 
+      // Refuse to submit anything if the loaded form isn't the quarter's form
+      // (e.g. a stale placeholder form ID pointing at a test sheet)
+      const titleError = await this._verifyFormTitle();
+      if (Cfg.VERIFY_FORM_TITLE && this.quarter) {
+        report.titleCheck = titleError ? "failed" : "passed";
+      }
+      if (titleError) {
+        report.error = titleError;
+        return {
+          success: false,
+          submitted_indices: [],
+          errors: df.map((_, idx): [number, string] => [idx, titleError]),
+          total_rows,
+          success_count: 0,
+          failure_count: total_rows,
+        };
+      }
+
       const status_col = this.cfg.STATUS_COLUMN_NAME ?? "Status";
       const complete_val = this.cfg.STATUS_COMPLETE ?? "Complete";
       botLogger.info("Processing rows", {
@@ -851,6 +1193,9 @@ export class BotOrchestrator {
         const row = df[i];
         if (!row) continue;
 
+        // The sign-in session can time out between rows on long runs
+        await this._reloginIfSessionExpired(email, password, idx, report);
+
         try {
           const [success, errorMessage] = await this._processRow(
             row,
@@ -863,7 +1208,14 @@ export class BotOrchestrator {
 
           if (!success) {
             if (errorMessage) {
+              // Resume from this row if a session timeout made it fail
+              if (await this._reloginIfSessionExpired(email, password, idx, report)) {
+                report.rows = report.rows.filter((r) => r.rowIndex !== idx);
+                i--;
+                continue;
+              }
               failed_rows.push([idx, errorMessage]);
+              await this._captureRowFailure(idx);
             }
             // If errorMessage is null, the row was skipped (e.g., completed), which is not an error
             continue;
@@ -877,7 +1229,13 @@ export class BotOrchestrator {
             error: errorMsg,
           });
 
+          if (await this._reloginIfSessionExpired(email, password, idx, report)) {
+            report.rows = report.rows.filter((r) => r.rowIndex !== idx);
+            i--;
+            continue;
+          }
           failed_rows.push([idx, errorMsg]);
+          await this._captureRowFailure(idx);
 
           // Attempt to recover by returning to the base form URL. This provides
           // a clean starting point for the next row after transient UI errors.
@@ -908,17 +1266,18 @@ export class BotOrchestrator {
         failure_count: failed_rows.length,
       };
     } catch (e: unknown) {
+      report.error = `Automation failed: ${String((e as Error)?.message ?? e)}`;
       return {
         success: false,
         submitted_indices: [],
-        errors: [
-          [-1, `Automation failed: ${String((e as Error)?.message ?? e)}`],
-        ],
+        errors: [[-1, report.error]],
         total_rows,
         success_count: 0,
         failure_count: total_rows,
       };
     } finally {
+      report.finishedAt = new Date().toISOString();
+      report.durationMs = Date.now() - startedAt;
       // Clean up abort listener
       if (cleanupAbortHandler) {
         cleanupAbortHandler();
@@ -926,6 +1285,69 @@ export class BotOrchestrator {
     }
   }
 
+  /**
+   * Screenshots the page for a failed row and notes the file on its report.
+   * Rows skipped for bad data are left alone; the page says nothing about them.
+   * @private
+   * @param rowIndex - Row index of the failed row
+   */
+  private async _captureRowFailure(rowIndex: number): Promise<void> {
+    const rowReport = this.report?.rows.find((r) => r.rowIndex === rowIndex);
+    if (!rowReport || rowReport.outcome !== "failed" || !this.sessionManager) {
+      return;
+    }
+    let page;
+    try {
+      page = this.require_page();
+    } catch {
+      // Browser already closed (e.g. cancelled); nothing to capture
+      return;
+    }
+    rowReport.screenshot = await captureFailureScreenshot(
+      page,
+      `${this.formConfig.FORM_ID}-row${rowIndex + 1}`
+    );
+  }
+
+  /**
+   * Checks the loaded form's page title and headings against the quarter this
+   * batch targets. Skipped for forms outside QUARTER_DEFINITIONS (e.g. the mock
+   * website) and when VERIFY_FORM_TITLE is off.
+   * @private
+   * @returns Error message if the form doesn't belong to the quarter, null otherwise
+   */
+  private async _verifyFormTitle(): Promise<string | null> {
+    if (!Cfg.VERIFY_FORM_TITLE || !this.quarter) return null;
+
+    await this.sessionManager!.waitForFormReady();
+    const page = this.require_page();
+    const texts: string[] = [await page.title().catch(() => "")];
+    for (const selector of Cfg.FORM_TITLE_SELECTORS) {
+      const text = await page
+        .locator(selector)
+        .first()
+        .textContent({ timeout: 1000 })
+        .catch(() => null);
+      if (text) texts.push(text.trim());
+    }
+    const titleText = texts.filter(Boolean).join(" | ");
+
+    if (formTitleMatchesQuarter(this.quarter, titleText)) {
+      botLogger.info("Form title matches quarter", {
+        quarter: this.quarter.id,
+        titleText,
+      });
+      return null;
+    }
+
+    botLogger.error("Form title does not match the expected quarter", {
+      quarter: this.quarter.id,
+      formId: this.formConfig.FORM_ID,
+      titleText,
+    });
+    return `Form ${this.formConfig.FORM_ID} does not look like the ${this.quarter.name} timesheet (found "${titleText.substring(0, 120) || "no title"}"); nothing was submitted`;
+  }
+
   /**
    * Builds field mapping from a data row using field definitions
    * @private
@@ -937,7 +1359,7 @@ export class BotOrchestrator {
   ): Record<string, unknown> {
     const fields: Record<string, unknown> = {};
     for (const key of Cfg.FIELD_ORDER) {
-      const spec = Cfg.FIELD_DEFINITIONS[key];
+      const spec = this.fieldDefinitions[key];
       if (!spec) continue;
       const label = spec["label"];
       if (!(label in row)) continue;
@@ -964,7 +1386,7 @@ export class BotOrchestrator {
   ): Promise<boolean> {
     let specBase: Record<string, unknown> | undefined;
     try {
-      specBase = Cfg.FIELD_DEFINITIONS[field_key] as unknown as Record<
+      specBase = this.fieldDefinitions[field_key] as unknown as Record<
         string,
         unknown
       >;
@@ -988,14 +1410,26 @@ export class BotOrchestrator {
       }
 
       const spec = { ...specBase };
-
-      // Use project-specific locator for tool field if available
-      if (field_key === "tool") {
+      const hotfixLocator = this.selectorHotfix?.fields?.[field_key];
+      let locatorSource: SubmissionFieldLocator["source"] = hotfixLocator
+        ? "hotfix"
+        : this.quarter?.fieldOverrides?.[field_key]?.locator
+          ? "quarter-override"
+          : "default";
+
+      // Use project-specific locator for tool field if available, unless a
+      // hotfix or this quarter's form pins its own locator
+      if (
+        field_key === "tool" &&
+        !hotfixLocator &&
+        !this.quarter?.fieldOverrides?.["tool"]?.locator
+      ) {
         const project_name = String(fields["project_code"] ?? "Unknown");
         const project_specific_locator =
           this.get_project_specific_tool_locator(project_name);
         if (project_specific_locator) {
           spec["locator"] = project_specific_locator;
+          locatorSource = "project";
           botLogger.verbose("Using project-specific tool locator", {
             fieldKey: field_key,
             projectName: project_name,
@@ -1013,12 +1447,19 @@ export class BotOrchestrator {
         valueType: typeof value,
       });
 
+      if (this.currentRow) {
+        this.currentRow.fields[field_key] = {
+          locator: String(spec["locator"] ?? ""),
+          source: locatorSource,
+        };
+      }
+
       // Inject the field value using FormInteractor
       botLogger.info(`[INJECT_START] About to inject ${field_key}`, {
         fieldKey: field_key,
         valueLength: String(value).length,
       });
-      await this.formInteractor!.fillField(spec as FieldSpec, String(value));
+      await this.formInteractor!.fillField(spec as FieldSpec, String(value), field_key);
 
       fillStats.filled++;
       botLogger.info(`[INJECT_SUCCESS] Successfully injected ${field_key}`, {
@@ -1107,6 +1548,30 @@ export class BotOrchestrator {
     }
     return true;
   }
+
+  /**
+   * Finds a field the quarter's form marks mandatory (`optional: false`) that
+   * the row leaves empty. Tool only applies to projects that have a tool list.
+   * @private
+   * @param fields - Object containing field values to validate
+   * @returns Label of the first missing field, or null if none is missing
+   */
+  private _find_missing_quarter_required_field(
+    fields: Record<string, unknown>
+  ): string | null {
+    for (const field_key of Cfg.FIELD_ORDER) {
+      const spec = this.fieldDefinitions[field_key];
+      if (!spec || spec.optional !== false) continue;
+      if (
+        field_key === "tool" &&
+        !this.get_project_specific_tool_locator(String(fields["project_code"] ?? ""))
+      ) {
+        continue;
+      }
+      if (!this._should_process_field(field_key, fields)) return spec.label;
+    }
+    return null;
+  }
 }
 
 /**
@@ -0,0 +1,186 @@
+/**
+ * Fill speed benchmark.
+ *
+ * Runs the normal fill pipeline (`BotOrchestrator.run_automation`) against the
+ * mock form N times per speed profile and reports timing distributions, so a
+ * change to the default fill profile can be measured before it ships.
+ *
+ * Only the automation phase is timed; browser start and close are excluded so
+ * launch cost doesn't drown out per-field differences.
+ */
+import { performance } from "node:perf_hooks";
+import { BotOrchestrator } from "./bot_orchestation";
+import * as Cfg from "../../engine/config/automation_config";
+import type { FormConfig } from "../../engine/browser/webform_session";
+import {
+  FILL_SPEED_PROFILES,
+  getFillProfile,
+} from "../../engine/config/fill_profiles";
+import { createMockFormConfig } from "../utils/quarter-processing";
+import { botLogger } from "@sheetpilot/shared/logger";
+
+export interface TimingSummary {
+  count: number;
+  minMs: number;
+  maxMs: number;
+  meanMs: number;
+  medianMs: number;
+  p90Ms: number;
+  stdDevMs: number;
+}
+
+export interface FillBenchmarkOptions {
+  rows: Array<Record<string, unknown>>;
+  email: string;
+  password: string;
+  /** Profile names to compare; defaults to every known profile */
+  profiles?: string[];
+  /** Runs per profile */
+  iterations: number;
+  /** Defaults to the mock website form */
+  formConfig?: FormConfig;
+  headless?: boolean;
+  abortSignal?: AbortSignal;
+  onProgress?: (completed: number, total: number, message: string) => void;
+}
+
+export interface FillBenchmarkProfileResult {
+  profile: string;
+  runs: number;
+  failures: number;
+  /** Duration of each successful run */
+  perRun: TimingSummary;
+  /** Successful run durations divided by row count */
+  perRow: TimingSummary;
+  errors: string[];
+}
+
+export interface FillBenchmarkReport {
+  rowCount: number;
+  iterations: number;
+  results: FillBenchmarkProfileResult[];
+}
+
+const round = (value: number): number => Math.round(value * 10) / 10;
+
+/**
+ * Summarizes a list of durations in milliseconds.
+ * Percentiles use the nearest-rank method; an empty list yields zeros.
+ */
+export function summarizeTimings(samples: number[]): TimingSummary {
+  if (samples.length === 0) {
+    return { count: 0, minMs: 0, maxMs: 0, meanMs: 0, medianMs: 0, p90Ms: 0, stdDevMs: 0 };
+  }
+  const sorted = [...samples].sort((a, b) => a - b);
+  const rank = (p: number): number =>
+    sorted[Math.min(sorted.length - 1, Math.ceil(p * sorted.length) - 1)] ?? 0;
+  const mean = sorted.reduce((sum, v) => sum + v, 0) / sorted.length;
+  const variance =
+    sorted.reduce((sum, v) => sum + (v - mean) ** 2, 0) / sorted.length;
+  return {
+    count: sorted.length,
+    minMs: round(sorted[0] ?? 0),
+    maxMs: round(sorted[sorted.length - 1] ?? 0),
+    meanMs: round(mean),
+    medianMs: round(rank(0.5)),
+    p90Ms: round(rank(0.9)),
+    stdDevMs: round(Math.sqrt(variance)),
+  };
+}
+
+/**
+ * Runs the fill pipeline `iterations` times for each profile.
+ * Profiles are interleaved per iteration so drift in the mock server affects
+ * them equally.
+ *
+ * @throws Error if a profile name is unknown or `iterations` is not positive
+ */
+export async function runFillBenchmark(
+  options: FillBenchmarkOptions
+): Promise<FillBenchmarkReport> {
+  const profileNames = options.profiles?.length
+    ? options.profiles
+    : Object.keys(FILL_SPEED_PROFILES);
+  const profiles = profileNames.map((name) => getFillProfile(name));
+  if (!Number.isInteger(options.iterations) || options.iterations < 1) {
+    throw new Error("Benchmark iterations must be a positive integer");
+  }
+  const formConfig = options.formConfig ?? createMockFormConfig();
+  const rowCount = Math.max(options.rows.length, 1);
+  const total = profiles.length * options.iterations;
+
+  const durations = new Map<string, number[]>(profiles.map((p) => [p.name, []]));
+  const errors = new Map<string, string[]>(profiles.map((p) => [p.name, []]));
+  let completed = 0;
+
+  botLogger.info("Starting fill benchmark", {
+    profiles: profileNames,
+    iterations: options.iterations,
+    rowCount: options.rows.length,
+  });
+
+  for (let i = 0; i < options.iterations; i++) {
+    for (const profile of profiles) {
+      if (options.abortSignal?.aborted) {
+        throw new Error("Fill benchmark was cancelled");
+      }
+      const bot = new BotOrchestrator(Cfg, formConfig, options.headless ?? true);
+      bot.fillProfile = profile;
+      try {
+        await bot.start();
+        const startedAt = performance.now();
+        const [ok, , runErrors] = await bot.run_automation(
+          options.rows,
+          [options.email, options.password],
+          options.abortSignal
+        );
+        const elapsed = performance.now() - startedAt;
+        if (ok) {
+          durations.get(profile.name)!.push(elapsed);
+        } else {
+          errors
+            .get(profile.name)!
+            .push(runErrors.map(([, message]) => message).join("; ") || "Run failed");
+        }
+      } catch (err: unknown) {
+        errors.get(profile.name)!.push(err instanceof Error ? err.message : String(err));
+      } finally {
+        await bot.close().catch((closeError: unknown) => {
+          botLogger.warn("Could not close benchmark browser", {
+            error: closeError instanceof Error ? closeError.message : String(closeError),
+          });
+        });
+      }
+      completed++;
+      options.onProgress?.(
+        completed,
+        total,
+        `Profile ${profile.name}: run ${i + 1} of ${options.iterations}`
+      );
+    }
+  }
+
+  const results = profiles.map((profile): FillBenchmarkProfileResult => {
+    const samples = durations.get(profile.name) ?? [];
+    const failed = errors.get(profile.name) ?? [];
+    return {
+      profile: profile.name,
+      runs: samples.length + failed.length,
+      failures: failed.length,
+      perRun: summarizeTimings(samples),
+      perRow: summarizeTimings(samples.map((ms) => ms / rowCount)),
+      errors: failed,
+    };
+  });
+
+  botLogger.info("Fill benchmark complete", {
+    results: results.map((r) => ({
+      profile: r.profile,
+      failures: r.failures,
+      medianMs: r.perRun.medianMs,
+      p90Ms: r.perRun.p90Ms,
+    })),
+  });
+
+  return { rowCount: options.rows.length, iterations: options.iterations, results };
+}
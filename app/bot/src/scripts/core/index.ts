@@ -41,7 +41,7 @@ export {
 } from "./bot_orchestation";
 import { BotOrchestrator } from "./bot_orchestation";
 import * as Cfg from "../../engine/config/automation_config";
-import { appSettings } from "@sheetpilot/shared";
+import { appSettings, type SubmissionFormRunReport } from "@sheetpilot/shared";
 import { botLogger } from "@sheetpilot/shared/logger";
 
 // Authentication and login management
@@ -62,6 +62,17 @@ export * from "../../engine/config/automation_config";
 // Quarter configuration and routing
 export * from "../../engine/config/quarter_config";
 
+// Fill speed profiles and the benchmark that compares them
+export * from "../../engine/config/fill_profiles";
+export {
+  runFillBenchmark,
+  summarizeTimings,
+  type FillBenchmarkOptions,
+  type FillBenchmarkProfileResult,
+  type FillBenchmarkReport,
+  type TimingSummary,
+} from "./fill_benchmark";
+
 /**
  * Runs timesheet automation for a batch of rows.
  *
@@ -87,6 +98,8 @@ export async function runTimesheet(
   ok: boolean;
   submitted: number[];
   errors: Array<[number, string]>;
+  /** Timings, locators and per-row outcomes for the run artifact */
+  report?: SubmissionFormRunReport;
 }> {
   // Prefer the explicit parameter, otherwise use the UI-controlled setting.
   // `appSettings.browserHeadless` updates at runtime when a user changes Settings.
@@ -160,6 +173,7 @@ export async function runTimesheet(
       ok: success,
       submitted: submitted_indices,
       errors: errors,
+      ...(bot.report ? { report: bot.report } : {}),
     };
   } catch (error) {
     // Check if error is due to abort or browser closure
@@ -44,6 +44,25 @@ export interface BrowserManager {
  */
 export class BotNavigationError extends Error {}
 
+/**
+ * Whether a URL is on a sign-in host, meaning the session expired and the
+ * page was redirected away from the form
+ */
+export function isAuthRedirectUrl(
+  url: string,
+  hosts: readonly string[] = C.AUTH_REDIRECT_HOSTS
+): boolean {
+  let host: string;
+  try {
+    host = new URL(url).hostname.toLowerCase();
+  } catch {
+    return false;
+  }
+  return hosts.some(
+    (authHost) => host === authHost || host.endsWith(`.${authHost}`)
+  );
+}
+
 /**
  * Manages authentication and login processes for the automation system
  *
@@ -342,6 +361,37 @@ export class LoginManager {
     timer.done({ email, contextIndex });
   }
 
+  /**
+   * Logs in again after the session expired mid-run, then returns to the form
+   *
+   * @param email - User email for authentication
+   * @param password - User password for authentication
+   * @param contextIndex - Context whose session expired
+   */
+  async relogin(
+    email: string,
+    password: string,
+    contextIndex = 0
+  ): Promise<void> {
+    authLogger.warn("Session expired mid-run, logging in again", {
+      email,
+      contextIndex,
+    });
+    this.loginStates[contextIndex] = false;
+    await this.run_login_steps(email, password, contextIndex);
+
+    const page = this.browser_manager.getPage(contextIndex);
+    if (isAuthRedirectUrl(page.url())) {
+      throw new BotNavigationError(
+        "Still on the sign-in page after logging in again"
+      );
+    }
+    if (!page.url().startsWith(this.formConfig.BASE_URL)) {
+      await this._navigate_to_base(page, C.GLOBAL_TIMEOUT * 1000);
+    }
+    authLogger.info("Logged in again, back on the form", { contextIndex });
+  }
+
   /**
    * Navigates to the base URL for authentication
    * @private
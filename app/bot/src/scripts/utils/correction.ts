@@ -0,0 +1,56 @@
+/**
+ * @fileoverview Correction Utilities
+ *
+ * An amended entry replaces one that was already submitted. The form has no
+ * way to edit a past row, so the amendment is submitted as a new row marked
+ * as a correction (in the description or a dedicated field, per
+ * CORRECTION_MODE) so reviewers can pair it with the original.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { CORRECTION_MODE } from '../../engine/config/automation_config';
+
+/** Bot row column used when CORRECTION_MODE is "field" */
+export const CORRECTION_LABEL = 'Correction';
+
+const DESCRIPTION_CORRECTION_SUFFIX = / \(CORRECTION of [^)]+\)$/;
+
+/**
+ * Builds the note that identifies the corrected original
+ *
+ * @param originalDate - Work date of the submitted entry being corrected
+ */
+export function formatCorrectionNote(originalDate: string): string {
+  return `CORRECTION of ${originalDate}`;
+}
+
+/**
+ * Marks a bot row as a correction according to CORRECTION_MODE
+ *
+ * Apply before the submission key so the key stays the description's suffix.
+ *
+ * @param row - Bot row keyed by column label
+ * @param originalDate - Date of the entry being corrected; rows without one are returned unchanged
+ * @param mode - Override for the configured mode (for tests)
+ */
+export function applyCorrection<T extends Record<string, string | number | null | undefined>>(
+  row: T,
+  originalDate: string | null | undefined,
+  mode: 'description' | 'field' | 'off' = CORRECTION_MODE
+): T {
+  if (!originalDate || mode === 'off') {
+    return row;
+  }
+  const note = formatCorrectionNote(originalDate);
+  if (mode === 'field') {
+    return { ...row, [CORRECTION_LABEL]: note };
+  }
+  const description = String(row['Task Description'] ?? '').replace(DESCRIPTION_CORRECTION_SUFFIX, '');
+  return {
+    ...row,
+    'Task Description': `${description} (${note})`
+  };
+}
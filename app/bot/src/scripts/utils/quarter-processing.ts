@@ -10,14 +10,26 @@
  */
 
 import type { TimesheetEntry } from "@sheetpilot/shared";
-import type { SubmissionResult } from "@sheetpilot/shared";
+import type {
+  SubmissionFormRunReport,
+  SubmissionResult,
+} from "@sheetpilot/shared";
 import {
   getQuarterForDate,
+  getQuarterFormStatusMessage,
   groupEntriesByQuarter,
+  isPlaceholderQuarter,
 } from "../../engine/config/quarter_config";
 import { createFormConfig } from "../../engine/config/automation_config";
+import type { FormConfig } from "../../engine/browser/webform_session";
 import { botLogger } from "@sheetpilot/shared/logger";
 import { checkAborted } from "./abort-utils";
+import {
+  getSubmissionOrder,
+  orderEntriesForSubmission,
+  orderQuarterGroups,
+  type SubmissionOrder,
+} from "./submission-order";
 
 /**
  * Configuration for processing a quarter group
@@ -45,6 +57,7 @@ export interface QuarterProcessingConfig {
     ok: boolean;
     submitted: number[];
     errors: Array<[number, string]>;
+    report?: SubmissionFormRunReport;
   }>;
   /** Email for authentication */
   email: string;
@@ -56,6 +69,57 @@ export interface QuarterProcessingConfig {
   abortSignal?: AbortSignal | undefined;
   /** Whether to use mock website */
   useMockWebsite?: boolean | undefined;
+  /** Order to submit in (defaults to the configured order) */
+  submissionOrder?: SubmissionOrder | undefined;
+}
+
+/**
+ * Form configuration for the local mock website (`MOCK_WEBSITE_URL`,
+ * `MOCK_FORM_ID`). BASE_URL is the root URL so the bot can run the login flow.
+ */
+export function createMockFormConfig(): FormConfig {
+  const mockBaseUrl =
+    process.env["MOCK_WEBSITE_URL"] || "http://localhost:3000";
+  const mockFormId =
+    process.env["MOCK_FORM_ID"] || "0197cbae7daf72bdb96b3395b500d414";
+  botLogger.info("Using mock website for submission", {
+    mockBaseUrl,
+    mockFormId,
+  });
+
+  const mockDomain = mockBaseUrl.replace(/^https?:\/\//, "");
+  return {
+    BASE_URL: mockBaseUrl,
+    FORM_ID: mockFormId,
+    SUBMISSION_ENDPOINT: `${mockBaseUrl}/api/submit/${mockFormId}`,
+    SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: [
+      `**${mockDomain}/api/submit/**`,
+      `**${mockDomain}/**`,
+    ],
+  };
+}
+
+const dateRange = (entries: TimesheetEntry[]): { firstDate: string; lastDate: string } => {
+  const dates = entries.map((entry) => entry.date).sort();
+  return { firstDate: dates[0] ?? "", lastDate: dates[dates.length - 1] ?? "" };
+};
+
+/**
+ * Maps one quarter's 0-100 progress onto its share of a multi-quarter run so
+ * the overall bar keeps moving forward; single-quarter runs pass through
+ */
+function scaleProgressToPart(
+  progressCallback: ((percent: number, message: string) => void) | undefined,
+  partIndex: number,
+  partCount: number,
+  quarterName: string
+): ((percent: number, message: string) => void) | undefined {
+  if (!progressCallback || partCount <= 1) return progressCallback;
+  return (percent, message) =>
+    progressCallback(
+      Math.round((partIndex * 100 + percent) / partCount),
+      `${quarterName} (form ${partIndex + 1} of ${partCount}): ${message}`
+    );
 }
 
 /**
@@ -85,15 +149,34 @@ export async function processEntriesByQuarter(
 
   const allSubmittedIds: number[] = [];
   const allFailedIds: number[] = [];
+  const formRuns: SubmissionFormRunReport[] = [];
+  const rejectedEntries: NonNullable<SubmissionResult["rejectedEntries"]> = [];
+  const capturedValues: NonNullable<SubmissionResult["capturedValues"]> = [];
+  const browserWarnings: string[] = [];
+  const unavailableForms: string[] = [];
   let overallSuccess = true;
 
-  // Process each quarter separately with appropriate form configuration
-  for (const [quarterId, quarterEntries] of Array.from(
-    quarterGroups.entries()
-  )) {
+  // Process each quarter separately with appropriate form configuration, in the
+  // submission order, so a batch spanning a quarter boundary is one run over two forms
+  const submissionOrder = config.submissionOrder ?? getSubmissionOrder();
+  const orderedGroups = orderQuarterGroups(
+    Array.from(quarterGroups.entries()),
+    ([, groupEntries]) => ({
+      quarter: groupEntries[0] ? getQuarterForDate(groupEntries[0].date) : null,
+      ...dateRange(groupEntries),
+    }),
+    submissionOrder
+  ).map(([quarterId, groupEntries]) => [
+    quarterId,
+    orderEntriesForSubmission(groupEntries, submissionOrder),
+  ] as const);
+  for (const [groupIndex, [quarterId, quarterEntries]] of orderedGroups.entries()) {
     botLogger.info("Processing quarter", {
       quarterId,
       entryCount: quarterEntries.length,
+      part: groupIndex + 1,
+      parts: orderedGroups.length,
+      submissionOrder,
     });
 
     // Get quarter definition for form configuration
@@ -113,6 +196,23 @@ export async function processEntriesByQuarter(
       continue;
     }
 
+    // A placeholder form ID would send the bot to a dead URL; refuse the
+    // quarter with the reason instead of failing somewhere in navigation
+    if (!config.useMockWebsite && isPlaceholderQuarter(quarterDef)) {
+      const message = getQuarterFormStatusMessage(quarterDef, "placeholder");
+      botLogger.error("Refusing submission to placeholder quarter form", {
+        quarterId,
+        formId: quarterDef.formId,
+        entryCount: quarterEntries.length,
+      });
+      quarterEntries.forEach((entry) => {
+        if (entry.id) allFailedIds.push(entry.id);
+      });
+      if (message) unavailableForms.push(message);
+      overallSuccess = false;
+      continue;
+    }
+
     // Create form configuration for this quarter
     let formConfig: {
       BASE_URL: string;
@@ -121,27 +221,7 @@ export async function processEntriesByQuarter(
       SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: string[];
     };
     if (config.useMockWebsite) {
-      const mockBaseUrl =
-        process.env["MOCK_WEBSITE_URL"] || "http://localhost:3000";
-      const mockFormId =
-        process.env["MOCK_FORM_ID"] || "0197cbae7daf72bdb96b3395b500d414";
-      botLogger.info("Using mock website for submission", {
-        mockBaseUrl,
-        mockFormId,
-      });
-
-      // Create custom form config for mock website with localhost URL patterns
-      // BASE_URL should be root URL so bot can navigate through login flow
-      const mockDomain = mockBaseUrl.replace(/^https?:\/\//, "");
-      formConfig = {
-        BASE_URL: mockBaseUrl,
-        FORM_ID: mockFormId,
-        SUBMISSION_ENDPOINT: `${mockBaseUrl}/api/submit/${mockFormId}`,
-        SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: [
-          `**${mockDomain}/api/submit/**`,
-          `**${mockDomain}/**`,
-        ],
-      };
+      formConfig = createMockFormConfig();
     } else {
       formConfig = createFormConfig(quarterDef.formUrl, quarterDef.formId);
     }
@@ -170,12 +250,17 @@ export async function processEntriesByQuarter(
       throw new Error("Submission was cancelled");
     }
 
-    const { ok, submitted, errors } = await config.runBot(
+    const { ok, submitted, errors, report } = await config.runBot(
       botRows,
       config.email,
       config.password,
       formConfig,
-      config.progressCallback ?? undefined,
+      scaleProgressToPart(
+        config.progressCallback ?? undefined,
+        groupIndex,
+        orderedGroups.length,
+        quarterDef.name
+      ),
       undefined,
       config.abortSignal ?? undefined
     );
@@ -221,6 +306,32 @@ export async function processEntriesByQuarter(
     allSubmittedIds.push(...submittedIds);
     allFailedIds.push(...failedIds);
 
+    if (report) {
+      formRuns.push({
+        ...report,
+        quarterId: quarterDef.id,
+        rows: report.rows.map((row) => ({
+          ...row,
+          entryId: ids[row.rowIndex] ?? null,
+        })),
+      });
+      if (
+        report.browserVersionWarning &&
+        !browserWarnings.includes(report.browserVersionWarning)
+      ) {
+        browserWarnings.push(report.browserVersionWarning);
+      }
+      for (const row of report.rows) {
+        const entryId = ids[row.rowIndex];
+        if (row.rejection && entryId !== undefined) {
+          rejectedEntries.push({ ...row.rejection, entryId });
+        }
+        if (row.captures && row.outcome === "submitted" && entryId !== undefined) {
+          capturedValues.push({ entryId, values: row.captures });
+        }
+      }
+    }
+
     if (!ok) {
       overallSuccess = false;
     }
@@ -233,5 +344,10 @@ export async function processEntriesByQuarter(
     totalProcessed: entries.length,
     successCount: allSubmittedIds.length,
     removedCount: allFailedIds.length,
+    ...(unavailableForms.length > 0 ? { error: unavailableForms.join("; ") } : {}),
+    ...(formRuns.length > 0 ? { formRuns } : {}),
+    ...(rejectedEntries.length > 0 ? { rejectedEntries } : {}),
+    ...(capturedValues.length > 0 ? { capturedValues } : {}),
+    ...(browserWarnings.length > 0 ? { browserWarnings } : {}),
   };
 }
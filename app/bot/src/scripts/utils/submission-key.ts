@@ -0,0 +1,91 @@
+/**
+ * @fileoverview Submission Key Utilities
+ *
+ * Each entry gets a stable idempotency key the first time it is submitted.
+ * The key is typed into the form (as a description suffix or a dedicated
+ * field) so a submission that reached the sheet can be recognised later
+ * even if the app never recorded it as Complete. The desktop app sets where
+ * from the `submission_key_mode` setting; without one, SUBMISSION_KEY_MODE
+ * from the environment applies.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { botLogger } from '@sheetpilot/shared/logger';
+import { SUBMISSION_KEY_MODE } from '../../engine/config/automation_config';
+
+export const SUBMISSION_KEY_MODES = ['description', 'field', 'off'] as const;
+
+export type SubmissionKeyMode = (typeof SUBMISSION_KEY_MODES)[number];
+
+let keyMode: SubmissionKeyMode = SUBMISSION_KEY_MODE;
+
+/** Bot row column used when SUBMISSION_KEY_MODE is "field" */
+export const SUBMISSION_KEY_LABEL = 'Submission Key';
+
+/** Keys look like SP-7K2M9QX4AB (base32, no ambiguous 0/1/8/9) */
+export const SUBMISSION_KEY_PATTERN = /\bSP-[A-Z2-7]{10}\b/;
+
+const DESCRIPTION_KEY_SUFFIX = / \[SP-[A-Z2-7]{10}\]$/;
+
+/**
+ * Mode for a stored (possibly missing or malformed) setting; anything else
+ * keeps the environment default
+ */
+export function resolveSubmissionKeyMode(raw: unknown): SubmissionKeyMode {
+  return SUBMISSION_KEY_MODES.find((candidate) => candidate === raw) ?? SUBMISSION_KEY_MODE;
+}
+
+/**
+ * Sets where later submissions type the key
+ */
+export function configureSubmissionKeyMode(next: SubmissionKeyMode): void {
+  keyMode = next;
+  botLogger.verbose('Submission key mode configured', { mode: keyMode });
+}
+
+export function getSubmissionKeyMode(): SubmissionKeyMode {
+  return keyMode;
+}
+
+/**
+ * Appends the key to a task description, replacing any key already there
+ */
+export function appendSubmissionKeyToDescription(description: string, key: string): string {
+  return `${description.replace(DESCRIPTION_KEY_SUFFIX, '')} [${key}]`;
+}
+
+/**
+ * Extracts every submission key found in free text (e.g. a sheet export)
+ */
+export function extractSubmissionKeys(text: string): string[] {
+  const pattern = new RegExp(SUBMISSION_KEY_PATTERN.source, 'g');
+  return [...new Set(text.match(pattern) ?? [])];
+}
+
+/**
+ * Adds the submission key to a bot row according to the configured mode
+ *
+ * @param row - Bot row keyed by column label
+ * @param key - Entry's submission key; rows without one are returned unchanged
+ * @param mode - Override for the configured mode (for tests)
+ */
+export function applySubmissionKey<T extends Record<string, string | number | null | undefined>>(
+  row: T,
+  key: string | null | undefined,
+  mode: SubmissionKeyMode = keyMode
+): T {
+  if (!key || mode === 'off') {
+    return row;
+  }
+  if (mode === 'field') {
+    return { ...row, [SUBMISSION_KEY_LABEL]: key };
+  }
+  const description = row['Task Description'];
+  return {
+    ...row,
+    'Task Description': appendSubmissionKeyToDescription(String(description ?? ''), key)
+  };
+}
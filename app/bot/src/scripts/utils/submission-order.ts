@@ -0,0 +1,100 @@
+/**
+ * Submission ordering.
+ *
+ * Which entries the bot submits first. The desktop app sets the order from
+ * the `submission_order` setting; the prepared submission plan uses the same
+ * order so what the user reviews is what runs.
+ *
+ * - `chronological` (default): oldest quarter and oldest entries first
+ * - `newest-first`: the latest entries first, e.g. to get the current week in
+ *   before the form closes
+ * - `deadline`: the quarter whose form locks soonest first (quarters without a
+ *   lock date after, oldest first); entries within a quarter oldest first
+ *
+ * Entries outside every configured quarter always come last; they fail anyway.
+ */
+import { botLogger } from "@sheetpilot/shared/logger";
+import {
+  getQuarterLockDate,
+  type QuarterDefinition,
+} from "../../engine/config/quarter_config";
+
+export const SUBMISSION_ORDERS = ["chronological", "newest-first", "deadline"] as const;
+
+export type SubmissionOrder = (typeof SUBMISSION_ORDERS)[number];
+
+export const DEFAULT_SUBMISSION_ORDER: SubmissionOrder = "chronological";
+
+/** What ordering needs to know about a group of entries bound for one form */
+export interface SubmissionOrderGroup {
+  quarter: QuarterDefinition | null;
+  /** Earliest and latest entry date (YYYY-MM-DD) */
+  firstDate: string;
+  lastDate: string;
+}
+
+let order: SubmissionOrder = DEFAULT_SUBMISSION_ORDER;
+
+/**
+ * Order for a stored (possibly missing or malformed) setting
+ */
+export function resolveSubmissionOrder(raw: unknown): SubmissionOrder {
+  return SUBMISSION_ORDERS.find((candidate) => candidate === raw) ?? DEFAULT_SUBMISSION_ORDER;
+}
+
+/**
+ * Sets the order later submissions run in
+ */
+export function configureSubmissionOrder(next: SubmissionOrder): void {
+  order = next;
+  botLogger.verbose("Submission order configured", { order });
+}
+
+export function getSubmissionOrder(): SubmissionOrder {
+  return order;
+}
+
+/**
+ * Entries of one group in the order they are filled (stable for equal dates)
+ */
+export function orderEntriesForSubmission<T extends { date: string }>(
+  entries: T[],
+  submissionOrder: SubmissionOrder = order
+): T[] {
+  const direction = submissionOrder === "newest-first" ? -1 : 1;
+  return [...entries].sort((a, b) => direction * a.date.localeCompare(b.date));
+}
+
+/**
+ * Groups in the order their forms are run
+ *
+ * @param describe - Quarter and date range of a group
+ */
+export function orderQuarterGroups<G>(
+  groups: G[],
+  describe: (group: G) => SubmissionOrderGroup,
+  submissionOrder: SubmissionOrder = order
+): G[] {
+  const compare = (a: SubmissionOrderGroup, b: SubmissionOrderGroup): number => {
+    if (!a.quarter || !b.quarter) {
+      return Number(!a.quarter) - Number(!b.quarter);
+    }
+    switch (submissionOrder) {
+      case "newest-first":
+        return b.lastDate.localeCompare(a.lastDate);
+      case "deadline": {
+        const lockA = getQuarterLockDate(a.quarter);
+        const lockB = getQuarterLockDate(b.quarter);
+        if (lockA !== lockB) {
+          if (!lockA) return 1;
+          if (!lockB) return -1;
+          return lockA.localeCompare(lockB);
+        }
+        return a.firstDate.localeCompare(b.firstDate);
+      }
+      case "chronological":
+        return a.firstDate.localeCompare(b.firstDate);
+    }
+  };
+  return [...groups].sort((a, b) => compare(describe(a), describe(b)));
+}
@@ -0,0 +1,35 @@
+/**
+ * @fileoverview Auth Redirect Detection Tests
+ *
+ * Covers recognising a mid-run redirect to the Microsoft sign-in page, which
+ * triggers the automatic re-login.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import { isAuthRedirectUrl } from '../src/scripts/utils/authentication_flow';
+
+describe('isAuthRedirectUrl', () => {
+  it('detects sign-in hosts', () => {
+    expect(
+      isAuthRedirectUrl('https://login.microsoftonline.com/common/oauth2/v2.0/authorize?client_id=x')
+    ).toBe(true);
+    expect(isAuthRedirectUrl('https://LOGIN.MICROSOFTONLINE.COM/tenant/saml2')).toBe(true);
+  });
+
+  it('ignores the form and look-alike hosts', () => {
+    expect(isAuthRedirectUrl('https://app.smartsheet.com/b/form/abc123')).toBe(false);
+    expect(isAuthRedirectUrl('https://login.microsoftonline.com.example.net/')).toBe(false);
+    expect(
+      isAuthRedirectUrl('https://app.smartsheet.com/b/form/abc?next=login.microsoftonline.com')
+    ).toBe(false);
+  });
+
+  it('treats unparseable URLs as not redirected', () => {
+    expect(isAuthRedirectUrl('about:blank')).toBe(false);
+    expect(isAuthRedirectUrl('not a url')).toBe(false);
+  });
+});
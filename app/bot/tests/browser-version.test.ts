@@ -0,0 +1,63 @@
+/**
+ * @fileoverview Browser Version Check Tests
+ *
+ * Covers reading the major version from browser version strings and
+ * classifying it against the supported and tested range.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  checkBrowserVersion,
+  parseBrowserMajorVersion,
+  type BrowserVersionRange
+} from '../src/engine/browser/browser_version';
+
+const range: BrowserVersionRange = { minimum: 110, testedMin: 120, testedMax: 141 };
+
+describe('parseBrowserMajorVersion', () => {
+  it('reads the major version with or without a product prefix', () => {
+    expect(parseBrowserMajorVersion('120.0.6099.28')).toBe(120);
+    expect(parseBrowserMajorVersion('HeadlessChrome/131.0.6778.33')).toBe(131);
+    expect(parseBrowserMajorVersion('unknown')).toBeNull();
+  });
+});
+
+describe('checkBrowserVersion', () => {
+  it('accepts versions in the tested range without a message', () => {
+    expect(checkBrowserVersion('120.0.6099.28', range)).toEqual({
+      version: '120.0.6099.28',
+      major: 120,
+      status: 'tested',
+      message: null
+    });
+    expect(checkBrowserVersion('141.0.7390.54', range).status).toBe('tested');
+  });
+
+  it('warns about versions outside the tested range', () => {
+    const older = checkBrowserVersion('115.0.5790.170', range);
+    expect(older.status).toBe('untested');
+    expect(older.message).toContain('Chrome 115 has not been tested');
+
+    expect(checkBrowserVersion('142.0.7444.59', range).status).toBe('untested');
+  });
+
+  it('refuses versions below the minimum with what to update to', () => {
+    const check = checkBrowserVersion('109.0.5414.120', range);
+
+    expect(check.status).toBe('unsupported');
+    expect(check.message).toBe(
+      'Chrome 109 is too old for SheetPilot. Update Chrome to version 110 or newer and try again.'
+    );
+  });
+
+  it('warns when the version cannot be read', () => {
+    const check = checkBrowserVersion('', range);
+
+    expect(check.status).toBe('unknown');
+    expect(check.message).toContain('tested with Chrome 120-141');
+  });
+});
@@ -0,0 +1,188 @@
+/**
+ * @fileoverview Automation Config Lint Tests
+ *
+ * Covers selector syntax checks, login step and field order findings,
+ * quarter form checks and the form reachability probe.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  checkQuarterFormReachability,
+  checkSelectorSyntax,
+  getAutomationConfigSnapshot,
+  lintAutomationConfig,
+  type AutomationConfigSnapshot
+} from '../src/engine/config/config_lint';
+import type { FieldDefinition } from '../src/engine/config/automation_config';
+import type { QuarterDefinition } from '../src/engine/config/quarter_config';
+
+const field = (label: string, locator: string, optional = false): FieldDefinition => ({
+  label,
+  locator,
+  optional,
+  validation: () => true,
+  error_message: () => ''
+});
+
+const quarter: QuarterDefinition = {
+  id: 'Q9-2030',
+  name: 'Q9 2030',
+  startDate: '2030-01-01',
+  endDate: '2030-03-31',
+  formUrl: 'https://app.smartsheet.com/b/form/0123456789abcdef0123456789abcdef',
+  formId: '0123456789abcdef0123456789abcdef'
+};
+
+const snapshot = (overrides: Partial<AutomationConfigSnapshot> = {}): AutomationConfigSnapshot => ({
+  loginSteps: [
+    { name: 'Email', action: 'input', locator: "input[type='email']", value_key: 'email' },
+    { name: 'Next', action: 'click', locator: 'role=button[name="Next"]' },
+    { name: 'Done', action: 'wait', element_selector: '#form', wait_condition: 'visible' }
+  ],
+  fieldDefinitions: {
+    project: field('Project', "input[aria-label='Project']"),
+    hours: field('Hours', "input[aria-label='Hours']"),
+    tool: field('Tool', "input[aria-label='Tool']", true)
+  },
+  fieldOrder: ['project', 'hours', 'tool'],
+  quarters: [quarter],
+  submitButtonSelectors: ["button[data-client-id='form_submit_btn']"],
+  hotfix: null,
+  ...overrides
+});
+
+const targets = (config: AutomationConfigSnapshot) =>
+  lintAutomationConfig(config).findings.map((finding) => `${finding.severity} ${finding.target}`);
+
+describe('checkSelectorSyntax', () => {
+  it('accepts CSS, role and chained selectors', () => {
+    expect(checkSelectorSyntax("input[aria-label='Hours'], #hours > .value")).toBeNull();
+    expect(checkSelectorSyntax('div:has(> label) input:not([disabled])')).toBeNull();
+    expect(checkSelectorSyntax('role=combobox[name="Project"] >> text=FAB')).toBeNull();
+    expect(checkSelectorSyntax('xpath=//input[@name="hours"]')).toBeNull();
+  });
+
+  it('reports broken selectors', () => {
+    expect(checkSelectorSyntax("input[aria-label='Hours'")).not.toBeNull();
+    expect(checkSelectorSyntax('div >')).not.toBeNull();
+    expect(checkSelectorSyntax('#')).not.toBeNull();
+    expect(checkSelectorSyntax('a,,b')).not.toBeNull();
+    expect(checkSelectorSyntax('role=')).not.toBeNull();
+    expect(checkSelectorSyntax('  ')).not.toBeNull();
+  });
+});
+
+describe('lintAutomationConfig', () => {
+  it('finds nothing in a well-formed config', () => {
+    expect(lintAutomationConfig(snapshot())).toEqual({ ok: true, errors: 0, warnings: 0, findings: [] });
+  });
+
+  it('reports input steps without value_key and wait steps without selector', () => {
+    const report = lintAutomationConfig(
+      snapshot({
+        loginSteps: [
+          { name: 'Email', action: 'input', locator: "input[type='email']" },
+          { name: 'Done', action: 'wait' },
+          { name: 'Jump', action: 'hop' }
+        ]
+      })
+    );
+    expect(report.ok).toBe(false);
+    expect(report.findings.map((finding) => finding.message)).toEqual([
+      'Input step has no value_key',
+      'Wait step has no element_selector',
+      'Action "hop" is not one of wait, input, click'
+    ]);
+  });
+
+  it('reports invalid and duplicate selectors', () => {
+    const config = snapshot({
+      fieldDefinitions: {
+        project: field('Project', "input[aria-label='Project']"),
+        hours: field('Hours', "input[aria-label='Project']"),
+        tool: field('Tool', 'input[', true)
+      }
+    });
+    expect(targets(config)).toEqual(['error field:tool', 'error field:hours']);
+  });
+
+  it('reports required fields missing from FIELD_ORDER and unknown or repeated keys', () => {
+    const config = snapshot({ fieldOrder: ['project', 'project', 'task'] });
+    const messages = lintAutomationConfig(config).findings.map((finding) => `${finding.target}: ${finding.message}`);
+    expect(messages).toEqual([
+      'field:project: Field appears more than once in FIELD_ORDER',
+      'field:task: FIELD_ORDER names a field that is not defined',
+      'field:hours: Required field is missing from FIELD_ORDER, so it is never filled',
+      'field:tool: Optional field is not in FIELD_ORDER, so it is never filled'
+    ]);
+  });
+
+  it('reports fields a quarter makes required that the order skips', () => {
+    const config = snapshot({
+      fieldOrder: ['project', 'hours'],
+      quarters: [{ ...quarter, fieldOverrides: { tool: { optional: false } } }]
+    });
+    expect(lintAutomationConfig(config).findings).toContainEqual({
+      severity: 'error',
+      area: 'fields',
+      target: 'field:tool',
+      message: 'Required field is missing from FIELD_ORDER on the Q9 2030 form, so it is never filled'
+    });
+  });
+
+  it('reports bad form URLs and repeated quarters', () => {
+    const config = snapshot({
+      quarters: [
+        quarter,
+        { ...quarter, id: 'Q9-2030', formUrl: 'http://forms.example.com/0123456789abcdef0123456789abcdef' },
+        { ...quarter, id: 'Q10-2030', formUrl: 'not a url', formId: 'fedcba9876543210fedcba9876543210' }
+      ]
+    });
+    const report = lintAutomationConfig(config);
+    expect(report.findings.filter((finding) => finding.area === 'quarters').map((finding) => finding.message)).toEqual([
+      'Quarter ID is used more than once',
+      'Uses the same form as Q9-2030',
+      'Form URL must use https',
+      'Form URL "not a url" is not a valid URL',
+      'Form ID is not a Smartsheet form ID or is not part of the form URL',
+      'Form URL host forms.example.com is not a Smartsheet host'
+    ]);
+  });
+
+  it('passes on the shipped configuration', () => {
+    const report = lintAutomationConfig(getAutomationConfigSnapshot());
+    expect(report.findings.filter((finding) => finding.severity === 'error')).toEqual([]);
+  });
+});
+
+describe('checkQuarterFormReachability', () => {
+  it('reports error statuses and network failures and skips placeholders', async () => {
+    const requested: string[] = [];
+    const fetchFn = (async (url: string) => {
+      requested.push(url);
+      if (url.endsWith('fedcba9876543210fedcba9876543210')) throw new Error('ENOTFOUND');
+      return new Response('', { status: url === quarter.formUrl ? 200 : 404 });
+    }) as unknown as typeof fetch;
+    const other = 'https://app.smartsheet.com/b/form/fedcba9876543210fedcba9876543210';
+
+    const findings = await checkQuarterFormReachability(
+      [
+        quarter,
+        { ...quarter, id: 'Q10-2030', formUrl: `${quarter.formUrl}/gone` },
+        { ...quarter, id: 'Q11-2030', formUrl: other, formId: 'fedcba9876543210fedcba9876543210' },
+        { ...quarter, id: 'Q12-2030', formUrl: 'https://app.smartsheet.com/b/form/TBD', formId: 'TBD' }
+      ],
+      fetchFn
+    );
+
+    expect(requested).toHaveLength(3);
+    expect(findings).toEqual([
+      { severity: 'error', area: 'quarters', target: 'quarter:Q10-2030', message: 'Form URL answered HTTP 404' },
+      { severity: 'error', area: 'quarters', target: 'quarter:Q11-2030', message: 'Form URL could not be reached: ENOTFOUND' }
+    ]);
+  });
+});
@@ -0,0 +1,65 @@
+/**
+ * @fileoverview Dropdown Resolution Tests
+ *
+ * Covers picking a previously confirmed option when a typed value matches
+ * several dropdown options, and passing confirmed choices to the store.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import {
+  chooseDropdownOption,
+  confirmDropdownResolutions,
+  type DropdownResolution,
+  type DropdownResolutionStore
+} from '../src/engine/browser/dropdown_resolutions';
+
+const candidates = ['FAB-Line 1', 'FAB-Line 2', 'FAB-Metrology'];
+
+describe('chooseDropdownOption', () => {
+  it('picks the previously confirmed option by exact text', () => {
+    expect(chooseDropdownOption(candidates, 'FAB-Metrology')).toBe(2);
+    expect(chooseDropdownOption(candidates, ' FAB-Line 2 ')).toBe(1);
+  });
+
+  it('keeps the default option when nothing better is known', () => {
+    expect(chooseDropdownOption(candidates, null)).toBeNull();
+    expect(chooseDropdownOption(candidates, 'FAB-Line 1')).toBeNull();
+    expect(chooseDropdownOption(candidates, 'FAB-Retired')).toBeNull();
+    expect(chooseDropdownOption(candidates, 'fab-metrology')).toBeNull();
+    expect(chooseDropdownOption(['FAB-Metrology'], 'FAB-Metrology')).toBeNull();
+  });
+});
+
+describe('confirmDropdownResolutions', () => {
+  const resolution: DropdownResolution = {
+    fieldKey: 'project_code',
+    typedValue: 'FAB',
+    optionText: 'FAB-Metrology',
+    candidates
+  };
+
+  it('passes each resolution to the store', () => {
+    const store: DropdownResolutionStore = { lookup: vi.fn(), confirm: vi.fn() };
+    confirmDropdownResolutions([resolution], store);
+    expect(store.confirm).toHaveBeenCalledWith(resolution);
+  });
+
+  it('does not fail the row when the store throws', () => {
+    const store: DropdownResolutionStore = {
+      lookup: vi.fn(),
+      confirm: vi.fn(() => {
+        throw new Error('database is locked');
+      })
+    };
+    expect(() => confirmDropdownResolutions([resolution, resolution], store)).not.toThrow();
+    expect(store.confirm).toHaveBeenCalledTimes(2);
+  });
+
+  it('does nothing without a store', () => {
+    expect(() => confirmDropdownResolutions([resolution], null)).not.toThrow();
+  });
+});
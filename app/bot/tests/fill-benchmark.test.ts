@@ -0,0 +1,69 @@
+/**
+ * @fileoverview Fill Benchmark Tests
+ *
+ * Covers fill speed profile lookup and the timing summary used by the
+ * fill benchmark report.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { afterEach, describe, it, expect } from 'vitest';
+import { summarizeTimings } from '../src/scripts/core/fill_benchmark';
+import {
+  DEFAULT_FILL_PROFILE,
+  getActiveFillProfile,
+  getFillProfile
+} from '../src/engine/config/fill_profiles';
+
+describe('fill speed profiles', () => {
+  afterEach(() => {
+    delete process.env['FILL_SPEED_PROFILE'];
+  });
+
+  it('looks up known profiles by name', () => {
+    expect(getFillProfile('type-human').mode).toBe('type');
+    expect(getFillProfile('inject').mode).toBe('inject');
+  });
+
+  it('rejects unknown profile names', () => {
+    expect(() => getFillProfile('warp')).toThrow(/Unknown fill speed profile 'warp'/);
+  });
+
+  it('uses FILL_SPEED_PROFILE and falls back to the default when it is unknown', () => {
+    process.env['FILL_SPEED_PROFILE'] = 'type-fast';
+    expect(getActiveFillProfile().name).toBe('type-fast');
+
+    process.env['FILL_SPEED_PROFILE'] = 'warp';
+    expect(getActiveFillProfile().name).toBe(DEFAULT_FILL_PROFILE);
+  });
+});
+
+describe('summarizeTimings', () => {
+  it('returns zeros for no samples', () => {
+    expect(summarizeTimings([])).toEqual({
+      count: 0, minMs: 0, maxMs: 0, meanMs: 0, medianMs: 0, p90Ms: 0, stdDevMs: 0
+    });
+  });
+
+  it('reports min, max, mean, nearest-rank percentiles and standard deviation', () => {
+    const summary = summarizeTimings([50, 10, 40, 20, 30, 60, 70, 80, 90, 100]);
+    expect(summary).toEqual({
+      count: 10,
+      minMs: 10,
+      maxMs: 100,
+      meanMs: 55,
+      medianMs: 50,
+      p90Ms: 90,
+      stdDevMs: 28.7
+    });
+  });
+
+  it('handles a single sample', () => {
+    const summary = summarizeTimings([123.456]);
+    expect(summary.medianMs).toBe(123.5);
+    expect(summary.p90Ms).toBe(123.5);
+    expect(summary.stdDevMs).toBe(0);
+  });
+});
@@ -0,0 +1,62 @@
+/**
+ * @fileoverview Form Feedback Tests
+ *
+ * Covers matching Smartsheet field error markers to field keys and the
+ * failure reason built from scraped feedback.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import type { FieldDefinition } from '../src/engine/config/automation_config';
+import {
+  formatFormFeedback,
+  hasFormFeedback,
+  matchErrorToField
+} from '../src/engine/browser/form_feedback';
+
+const field = (label: string): FieldDefinition => ({
+  label,
+  locator: `input[aria-label='${label}']`,
+  validation: () => true,
+  error_message: () => ''
+});
+
+const fields: Record<string, FieldDefinition> = {
+  hours: field('Hours'),
+  charge_code: field('Charge Code'),
+  detail_code: field('Detail Charge Code')
+};
+
+describe('matchErrorToField', () => {
+  it('matches error text to a field by label, preferring the longest label', () => {
+    expect(matchErrorToField('Hours must be a number', fields)).toBe('hours');
+    expect(matchErrorToField('Detail Charge Code is required', fields)).toBe('detail_code');
+    expect(matchErrorToField('charge code is required', fields)).toBe('charge_code');
+  });
+
+  it('returns null when no label appears', () => {
+    expect(matchErrorToField('Something went wrong', fields)).toBeNull();
+  });
+});
+
+describe('formatFormFeedback', () => {
+  it('lists field errors by label after the banner', () => {
+    const feedback = {
+      banner: 'Please fix the errors below',
+      fieldErrors: { hours: 'Must be a number' },
+      unmatchedErrors: ['Value not in list']
+    };
+
+    expect(hasFormFeedback(feedback)).toBe(true);
+    expect(formatFormFeedback(feedback, fields)).toBe(
+      'Smartsheet rejected the entry: Please fix the errors below (Hours: Must be a number; Value not in list)'
+    );
+  });
+
+  it('treats an empty scrape as no feedback', () => {
+    expect(hasFormFeedback({ banner: null, fieldErrors: {}, unmatchedErrors: [] })).toBe(false);
+  });
+});
@@ -0,0 +1,97 @@
+/**
+ * @fileoverview Form Inspector Tests
+ *
+ * Covers labels, suggested locators and field key matches built from inputs
+ * read off a form.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import type { FieldDefinition } from '../src/engine/config/automation_config';
+import {
+  buildFormInspection,
+  resolveInputLabel,
+  suggestLocator,
+  type RawFormInput
+} from '../src/engine/browser/form_inspector';
+
+const field = (label: string, optional = false): FieldDefinition => ({
+  label,
+  locator: `input[aria-label='${label}']`,
+  validation: () => true,
+  error_message: () => '',
+  optional
+});
+
+const fields: Record<string, FieldDefinition> = {
+  hours: field('Hours'),
+  task_description: field('Task Description'),
+  tool: field('Tool', true)
+};
+
+const input = (overrides: Partial<RawFormInput>): RawFormInput => ({
+  tag: 'input',
+  type: 'text',
+  role: null,
+  id: null,
+  name: null,
+  placeholder: null,
+  ariaLabel: null,
+  ariaLabelledByText: null,
+  labelText: null,
+  ariaRequired: false,
+  required: false,
+  ...overrides
+});
+
+const form = { formUrl: 'https://app.smartsheet.com/b/form/abc', formId: 'abc', title: 'Q1 Timesheet' };
+
+describe('resolveInputLabel', () => {
+  it('prefers the label element and drops the required marker', () => {
+    expect(resolveInputLabel(input({ labelText: ' Hours \n *', ariaLabel: 'hrs' }))).toBe('Hours');
+    expect(resolveInputLabel(input({ placeholder: 'mm/dd/yyyy' }))).toBe('mm/dd/yyyy');
+    expect(resolveInputLabel(input({}))).toBe('');
+  });
+});
+
+describe('suggestLocator', () => {
+  it('prefers aria-label, then role and name, then id, name and placeholder', () => {
+    expect(suggestLocator(input({ ariaLabel: 'Hours', id: 'f1' }))).toBe("input[aria-label='Hours']");
+    expect(
+      suggestLocator(input({ tag: 'div', role: 'textbox', labelText: 'Task Description' }))
+    ).toBe("role=textbox[name='Task Description']");
+    expect(suggestLocator(input({ id: 'field-7', name: 'hours' }))).toBe('#field-7');
+    expect(suggestLocator(input({ id: '7:r1' }))).toBe("input[id='7:r1']");
+    expect(suggestLocator(input({ name: 'hours' }))).toBe("input[name='hours']");
+    expect(suggestLocator(input({ placeholder: 'mm/dd/yyyy' }))).toBe("input[placeholder='mm/dd/yyyy']");
+    expect(suggestLocator(input({}))).toBeNull();
+  });
+
+  it('escapes quotes in attribute values', () => {
+    expect(suggestLocator(input({ ariaLabel: "Tech's Tool" }))).toBe("input[aria-label='Tech\\'s Tool']");
+  });
+});
+
+describe('buildFormInspection', () => {
+  it('maps inputs to field keys by label and reports missing required fields', () => {
+    const inspection = buildFormInspection(
+      [
+        input({ ariaLabel: 'Hours Worked' }),
+        input({ ariaLabel: 'Hours (overtime)' }),
+        input({ ariaLabel: 'Site' })
+      ],
+      form,
+      fields
+    );
+
+    expect(inspection.inputs.map((i) => i.fieldKey)).toEqual(['hours', null, null]);
+    expect(inspection.suggestedFieldOverrides).toEqual({
+      hours: { locator: "input[aria-label='Hours Worked']" }
+    });
+    expect(inspection.missingFields).toEqual(['task_description']);
+    expect(inspection.title).toBe('Q1 Timesheet');
+  });
+});
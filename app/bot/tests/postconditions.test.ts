@@ -0,0 +1,57 @@
+/**
+ * @fileoverview Submission Postcondition Tests
+ *
+ * Covers checking URL and page-text postconditions after a submit and
+ * capturing the values they match.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  checkPostconditions,
+  type SubmissionPostcondition
+} from '../src/engine/browser/postconditions';
+
+const conditions: SubmissionPostcondition[] = [
+  { kind: 'url-contains', value: '/confirmation' },
+  { kind: 'page-matches', pattern: 'Submission ID:\\s*([A-Z0-9-]+)', capture: 'submissionId' }
+];
+
+describe('checkPostconditions', () => {
+  it('passes and captures the matched group', () => {
+    const result = checkPostconditions(
+      {
+        url: 'https://app.smartsheet.com/b/form/abc/confirmation',
+        text: 'Thank you! Submission ID: SUB-4821'
+      },
+      conditions
+    );
+
+    expect(result).toEqual({ passed: true, failed: null, captures: { submissionId: 'SUB-4821' } });
+  });
+
+  it('reports the first postcondition that failed', () => {
+    const result = checkPostconditions(
+      { url: 'https://app.smartsheet.com/b/form/abc', text: 'Submission ID: SUB-1' },
+      conditions
+    );
+
+    expect(result.passed).toBe(false);
+    expect(result.failed).toBe('URL contains "/confirmation"');
+  });
+
+  it('uses the description when one is configured', () => {
+    const result = checkPostconditions({ url: 'https://x/confirmation', text: 'Saved' }, [
+      { kind: 'page-matches', pattern: 'Submission ID', description: 'submission ID shown' }
+    ]);
+
+    expect(result.failed).toBe('submission ID shown');
+  });
+
+  it('passes when nothing is configured', () => {
+    expect(checkPostconditions({ url: '', text: '' }, []).passed).toBe(true);
+  });
+});
@@ -0,0 +1,101 @@
+/**
+ * @fileoverview Quarter Form Status Tests
+ *
+ * Covers placeholder, locked and configured quarter forms, how they show in
+ * the quarter list and closure checks, and refusing submissions to
+ * placeholder forms.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import {
+  QUARTER_DEFINITIONS,
+  getQuarterClosure,
+  getQuarterFormStatus,
+  getQuarters,
+  isPlaceholderQuarter,
+  type QuarterDefinition
+} from '../src/engine/config/quarter_config';
+import { processEntriesByQuarter } from '../src/scripts/utils/quarter-processing';
+
+const configured: QuarterDefinition = {
+  id: 'Q9-2030',
+  name: 'Q9 2030',
+  startDate: '2030-01-01',
+  endDate: '2030-03-31',
+  formUrl: 'https://app.smartsheet.com/b/form/0123456789abcdef0123456789abcdef',
+  formId: '0123456789abcdef0123456789abcdef'
+};
+
+const placeholder: QuarterDefinition = {
+  id: 'Q2-2099',
+  name: 'Q2 2099',
+  startDate: '2099-04-01',
+  endDate: '2099-06-30',
+  formUrl: 'https://app.smartsheet.com/b/form/TBD',
+  formId: 'TBD'
+};
+
+const today = new Date(2030, 1, 15);
+
+describe('getQuarterFormStatus', () => {
+  it('treats marked quarters and non-Smartsheet form IDs as placeholders', () => {
+    expect(isPlaceholderQuarter(configured)).toBe(false);
+    expect(isPlaceholderQuarter(placeholder)).toBe(true);
+    expect(isPlaceholderQuarter({ ...configured, status: 'placeholder' })).toBe(true);
+    expect(isPlaceholderQuarter({ ...configured, formId: '0'.repeat(32) })).toBe(true);
+    expect(
+      isPlaceholderQuarter({ ...configured, formUrl: 'https://app.smartsheet.com/b/form/other' })
+    ).toBe(true);
+  });
+
+  it('reports locked forms by status or a passed lock date', () => {
+    expect(getQuarterFormStatus(configured, today)).toBe('configured');
+    expect(getQuarterFormStatus({ ...configured, status: 'locked' }, today)).toBe('locked');
+    expect(getQuarterFormStatus({ ...configured, lockDate: '2030-02-14' }, today)).toBe('locked');
+    expect(getQuarterFormStatus({ ...configured, lockDate: '2030-02-15' }, today)).toBe('configured');
+    expect(getQuarterFormStatus({ ...placeholder, status: 'locked' }, today)).toBe('placeholder');
+  });
+});
+
+describe('placeholder quarters', () => {
+  beforeEach(() => {
+    QUARTER_DEFINITIONS.push(placeholder);
+  });
+
+  afterEach(() => {
+    QUARTER_DEFINITIONS.splice(QUARTER_DEFINITIONS.indexOf(placeholder), 1);
+  });
+
+  it('are listed with their status and reason', () => {
+    const quarters = getQuarters(today);
+    const listed = quarters.find((q) => q.id === placeholder.id);
+
+    expect(quarters.map((q) => q.startDate)).toEqual([...quarters.map((q) => q.startDate)].sort());
+    expect(listed?.status).toBe('placeholder');
+    expect(listed?.statusMessage).toContain('Q2 2099 form has not been set up yet');
+  });
+
+  it('are unavailable for their dates', () => {
+    const closure = getQuarterClosure('2099-05-01', today);
+    expect(closure.state).toBe('unavailable');
+    expect(closure.quarter?.id).toBe(placeholder.id);
+    expect(closure.message).toContain('cannot be submitted until its form is configured');
+  });
+
+  it('refuse submissions without running the bot', async () => {
+    const runBot = vi.fn();
+    const result = await processEntriesByQuarter(
+      [{ id: 7, date: '2099-05-01' } as Parameters<typeof processEntriesByQuarter>[0][number]],
+      { toBotRow: () => ({}), runBot, email: 'tech@example.com', password: 'secret' }
+    );
+
+    expect(runBot).not.toHaveBeenCalled();
+    expect(result.ok).toBe(false);
+    expect(result.removedIds).toEqual([7]);
+    expect(result.error).toContain('Q2 2099 form has not been set up yet');
+  });
+});
@@ -0,0 +1,233 @@
+/**
+ * @fileoverview Run Recorder Tests
+ *
+ * Covers recording a run's browser interactions, replaying a recording
+ * against another base URL and comparing recordings. The last suite replays
+ * a recorded run against the mock website when MOCK_WEBSITE_URL and
+ * RUN_RECORDING_FIXTURE are set.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { EventEmitter } from 'node:events';
+import * as fs from 'node:fs';
+import * as os from 'node:os';
+import * as path from 'node:path';
+import { describe, it, expect } from 'vitest';
+import type { Page } from 'playwright';
+import {
+  RunRecorder,
+  compareRunRecordings,
+  loadRunRecording,
+  rebaseUrl,
+  replayRunRecording,
+  type RunRecording
+} from '../src/engine/browser/run_recorder';
+import type { FormConfig } from '../src/engine/browser/webform_session';
+
+const liveForm: FormConfig = {
+  BASE_URL: 'https://app.smartsheet.com/b/form/abc123',
+  FORM_ID: 'abc123',
+  SUBMISSION_ENDPOINT: 'https://forms.smartsheet.com/api/submit/abc123',
+  SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: []
+};
+
+/** Page stand-in that emits the events the recorder listens to */
+class FakePage extends EventEmitter {
+  currentUrl = 'about:blank';
+  fills: Array<[string, string]> = [];
+  clicks: string[] = [];
+  /** Where a submit click takes the page, if anywhere */
+  submitTarget: string | null = null;
+  private readonly frame = { url: () => this.currentUrl };
+
+  mainFrame() {
+    return this.frame;
+  }
+
+  url() {
+    return this.currentUrl;
+  }
+
+  async goto(url: string) {
+    this.navigate(url);
+  }
+
+  navigate(url: string) {
+    this.currentUrl = url;
+    this.emit('framenavigated', this.frame);
+    this.respond('GET', url, 200, 'document');
+  }
+
+  respond(method: string, url: string, status: number, resourceType: string) {
+    this.emit('response', {
+      url: () => url,
+      status: () => status,
+      request: () => ({ method: () => method, resourceType: () => resourceType })
+    });
+  }
+
+  locator(selector: string) {
+    return {
+      fill: async (value: string) => {
+        this.fills.push([selector, value]);
+      },
+      first: () => ({
+        click: async () => {
+          this.clicks.push(selector);
+          if (this.submitTarget) this.navigate(this.submitTarget);
+        }
+      })
+    };
+  }
+
+  async waitForLoadState() {}
+
+  asPage(): Page {
+    return this as unknown as Page;
+  }
+}
+
+const recordLiveRun = (): RunRecording => {
+  const page = new FakePage();
+  const recorder = new RunRecorder(liveForm);
+  recorder.attach(page.asPage());
+  page.navigate('https://login.microsoftonline.com/oauth2/authorize?code=secret');
+  page.navigate(`${liveForm.BASE_URL}?session=xyz`);
+  page.respond('GET', 'https://app.smartsheet.com/static/app.js', 200, 'script');
+  recorder.recordFill('Project', "input[name='project']", 'FL-Carver Techs');
+  recorder.recordFill('Hours', "input[name='hours']", '2');
+  recorder.recordSubmit("button[type='submit']");
+  page.respond('POST', liveForm.SUBMISSION_ENDPOINT, 200, 'fetch');
+  page.navigate(`${liveForm.BASE_URL}/confirmation`);
+  recorder.detach();
+  page.navigate('https://app.smartsheet.com/after-detach');
+  return recorder.toRecording();
+};
+
+describe('RunRecorder', () => {
+  it('records navigations, fills, submits and document/XHR responses without query strings', () => {
+    const recording = recordLiveRun();
+
+    expect(recording.formId).toBe('abc123');
+    expect(recording.interactions).toEqual([
+      { type: 'navigate', url: 'https://login.microsoftonline.com/oauth2/authorize' },
+      { type: 'response', method: 'GET', url: 'https://login.microsoftonline.com/oauth2/authorize', status: 200 },
+      { type: 'navigate', url: liveForm.BASE_URL },
+      { type: 'response', method: 'GET', url: liveForm.BASE_URL, status: 200 },
+      { type: 'fill', field: 'Project', locator: "input[name='project']", value: 'FL-Carver Techs' },
+      { type: 'fill', field: 'Hours', locator: "input[name='hours']", value: '2' },
+      { type: 'submit', selector: "button[type='submit']" },
+      { type: 'response', method: 'POST', url: liveForm.SUBMISSION_ENDPOINT, status: 200 },
+      { type: 'navigate', url: `${liveForm.BASE_URL}/confirmation` },
+      { type: 'response', method: 'GET', url: `${liveForm.BASE_URL}/confirmation`, status: 200 }
+    ]);
+  });
+
+  it('saves recordings that load back', () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'sheetpilot-recording-'));
+    try {
+      const recorder = new RunRecorder(liveForm);
+      recorder.recordFill('Hours', "input[name='hours']", '2');
+      const file = recorder.saveTo(dir);
+
+      expect(loadRunRecording(file)).toEqual(recorder.toRecording());
+
+      fs.writeFileSync(file, JSON.stringify({ version: 99, interactions: [] }));
+      expect(() => loadRunRecording(file)).toThrow(/Not a version 1 run recording/);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
+
+describe('rebaseUrl', () => {
+  it('moves URLs under the base and leaves others alone', () => {
+    expect(rebaseUrl('https://a.com/form/x/confirmation', 'https://a.com/form/x', 'http://localhost:3000/'))
+      .toBe('http://localhost:3000/confirmation');
+    expect(rebaseUrl('https://a.com/form/xy', 'https://a.com/form/x', 'http://localhost:3000'))
+      .toBe('https://a.com/form/xy');
+  });
+});
+
+describe('replayRunRecording', () => {
+  it('replays form steps against another base URL and skips sign-in pages', async () => {
+    const page = new FakePage();
+    page.submitTarget = 'http://localhost:3000/confirmation';
+
+    const replayed = await replayRunRecording(page.asPage(), recordLiveRun(), 'http://localhost:3000/');
+
+    expect(page.fills).toEqual([
+      ["input[name='project']", 'FL-Carver Techs'],
+      ["input[name='hours']", '2']
+    ]);
+    expect(page.clicks).toEqual(["button[type='submit']"]);
+    expect(replayed.baseUrl).toBe('http://localhost:3000');
+    expect(replayed.interactions.filter((step) => step.type === 'navigate')).toEqual([
+      { type: 'navigate', url: 'http://localhost:3000' },
+      { type: 'navigate', url: 'http://localhost:3000/confirmation' }
+    ]);
+  });
+});
+
+describe('compareRunRecordings', () => {
+  const mockRun = (status: number, hours: string): RunRecording => {
+    const page = new FakePage();
+    const recorder = new RunRecorder({
+      BASE_URL: 'http://localhost:3000',
+      FORM_ID: 'abc123',
+      SUBMISSION_ENDPOINT: 'http://localhost:3000/api/submit/abc123',
+      SUBMIT_SUCCESS_RESPONSE_URL_PATTERNS: []
+    });
+    recorder.attach(page.asPage());
+    page.navigate('http://localhost:3000');
+    recorder.recordFill('Project', "input[name='project']", 'FL-Carver Techs');
+    recorder.recordFill('Hours', "input[name='hours']", hours);
+    recorder.recordSubmit("button[type='submit']");
+    page.respond('POST', 'http://localhost:3000/api/submit/abc123', status, 'fetch');
+    page.navigate('http://localhost:3000/confirmation');
+    return recorder.toRecording();
+  };
+
+  it('matches runs that did the same thing on different hosts', () => {
+    const live = recordLiveRun();
+    const withMockEndpoint: RunRecording = {
+      ...live,
+      submissionEndpoint: `${live.baseUrl}/api/submit/abc123`,
+      interactions: live.interactions.map((step) =>
+        step.type === 'response' && step.method === 'POST'
+          ? { ...step, url: `${live.baseUrl}/api/submit/abc123` }
+          : step
+      )
+    };
+
+    expect(compareRunRecordings(withMockEndpoint, mockRun(200, '2'))).toEqual([]);
+  });
+
+  it('reports steps that differ', () => {
+    expect(compareRunRecordings(mockRun(200, '2'), mockRun(500, '3'))).toEqual([
+      'Step 3: expected fill Hours with "2", got fill Hours with "3"',
+      'Step 5: expected POST /api/submit/abc123 -> 200, got POST /api/submit/abc123 -> 500'
+    ]);
+  });
+});
+
+const mockWebsiteUrl = process.env['MOCK_WEBSITE_URL'];
+const recordingFixture = process.env['RUN_RECORDING_FIXTURE'];
+
+describe.skipIf(!mockWebsiteUrl || !recordingFixture)('replay against the mock website', () => {
+  it('reproduces the recorded run', async () => {
+    const { chromium } = await import('playwright');
+    const recording = loadRunRecording(recordingFixture!);
+    const browser = await chromium.launch({ headless: true });
+    try {
+      const page = await browser.newPage();
+      const replayed = await replayRunRecording(page, recording, mockWebsiteUrl!);
+      expect(compareRunRecordings(recording, replayed)).toEqual([]);
+    } finally {
+      await browser.close();
+    }
+  }, 60000);
+});
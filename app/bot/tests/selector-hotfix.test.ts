@@ -0,0 +1,62 @@
+/**
+ * @fileoverview Selector Hotfix Tests
+ *
+ * Covers applying a selector hotfix's field locators and submit button
+ * selectors on top of the built-in ones.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  applySelectorHotfix,
+  getSubmitButtonSelectors,
+  type SelectorHotfix
+} from '../src/engine/config/selector_hotfix';
+import type { FieldDefinition } from '../src/engine/config/automation_config';
+
+const field = (label: string, locator: string): FieldDefinition => ({
+  label,
+  locator,
+  validation: () => true,
+  error_message: () => ''
+});
+
+const definitions: Record<string, FieldDefinition> = {
+  project: field('Project', "input[name='project']"),
+  hours: field('Hours', "input[name='hours']")
+};
+
+const hotfix: SelectorHotfix = {
+  id: '2025-03-14-project-locator',
+  fields: { project: "input[aria-label='Project']", unknown_field: '#nope' },
+  submitButton: ["button[data-client-id='form_submit_btn']", "button[type='submit']"]
+};
+
+describe('applySelectorHotfix', () => {
+  it('replaces locators for known fields and ignores unknown ones', () => {
+    const patched = applySelectorHotfix(definitions, hotfix);
+
+    expect(patched['project']?.locator).toBe("input[aria-label='Project']");
+    expect(patched['project']?.label).toBe('Project');
+    expect(patched['hours']).toBe(definitions['hours']);
+    expect(patched['unknown_field']).toBeUndefined();
+    expect(definitions['project']?.locator).toBe("input[name='project']");
+  });
+
+  it('returns the definitions unchanged without a hotfix', () => {
+    expect(applySelectorHotfix(definitions, null)).toBe(definitions);
+  });
+});
+
+describe('getSubmitButtonSelectors', () => {
+  it('tries hotfix selectors first without duplicates', () => {
+    expect(getSubmitButtonSelectors(["button[type='submit']", 'button.submit'], hotfix)).toEqual([
+      "button[data-client-id='form_submit_btn']",
+      "button[type='submit']",
+      'button.submit'
+    ]);
+  });
+});
@@ -0,0 +1,82 @@
+/**
+ * @fileoverview Step Annotator Tests
+ *
+ * Covers when interaction highlighting is enabled and that annotation never
+ * touches the page when disabled or fails a run when the page rejects it.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { afterEach, describe, it, expect, vi } from 'vitest';
+import type { Locator, Page } from 'playwright';
+import { appSettings } from '@sheetpilot/shared';
+import {
+  StepAnnotator,
+  isStepAnnotationEnabled
+} from '../src/engine/browser/step_annotator';
+
+describe('isStepAnnotationEnabled', () => {
+  afterEach(() => {
+    appSettings.highlightInteractions = false;
+  });
+
+  it('follows the settings toggle for visible browsers', () => {
+    expect(isStepAnnotationEnabled(false)).toBe(false);
+    appSettings.highlightInteractions = true;
+    expect(isStepAnnotationEnabled(false)).toBe(true);
+  });
+
+  it('never annotates headless browsers', () => {
+    appSettings.highlightInteractions = true;
+    expect(isStepAnnotationEnabled(true)).toBe(false);
+  });
+});
+
+describe('StepAnnotator', () => {
+  it('does not touch the page when disabled', async () => {
+    const evaluate = vi.fn();
+    const annotator = new StepAnnotator(() => ({ evaluate }) as unknown as Page, false);
+    const locator = { first: vi.fn() } as unknown as Locator;
+
+    await annotator.step('Logging in');
+    await annotator.highlight(locator, 'Project');
+
+    expect(evaluate).not.toHaveBeenCalled();
+    expect(locator.first).not.toHaveBeenCalled();
+  });
+
+  it('injects the banner and outline when enabled', async () => {
+    const pageEvaluate = vi.fn().mockResolvedValue(undefined);
+    const locatorEvaluate = vi.fn().mockResolvedValue(undefined);
+    const annotator = new StepAnnotator(() => ({ evaluate: pageEvaluate }) as unknown as Page, true);
+    const locator = { first: () => ({ evaluate: locatorEvaluate }) } as unknown as Locator;
+
+    await annotator.step('Row 1 of 3: filling fields');
+    await annotator.highlight(locator, 'Project: FL-Carver Techs');
+
+    expect(pageEvaluate).toHaveBeenCalledWith(
+      expect.any(Function),
+      expect.objectContaining({ text: 'SheetPilot: Row 1 of 3: filling fields' })
+    );
+    expect(locatorEvaluate).toHaveBeenCalledWith(
+      expect.any(Function),
+      expect.objectContaining({ text: 'Project: FL-Carver Techs' }),
+      { timeout: 1000 }
+    );
+  });
+
+  it('swallows page errors so annotation never fails a run', async () => {
+    const annotator = new StepAnnotator(
+      () => ({ evaluate: vi.fn().mockRejectedValue(new Error('Execution context was destroyed')) }) as unknown as Page,
+      true
+    );
+    const locator = {
+      first: () => ({ evaluate: vi.fn().mockRejectedValue(new Error('Timeout')) })
+    } as unknown as Locator;
+
+    await expect(annotator.step('Submitting')).resolves.toBeUndefined();
+    await expect(annotator.highlight(locator, 'Hours')).resolves.toBeUndefined();
+  });
+});
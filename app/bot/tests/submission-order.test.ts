@@ -0,0 +1,89 @@
+/**
+ * @fileoverview Submission Order Tests
+ *
+ * Covers reading the order setting, ordering entries and quarter groups, and
+ * the order quarter processing hands rows to the bot.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect, vi } from 'vitest';
+import type { TimesheetEntry } from '@sheetpilot/shared';
+import {
+  orderEntriesForSubmission,
+  orderQuarterGroups,
+  resolveSubmissionOrder
+} from '../src/scripts/utils/submission-order';
+import { processEntriesByQuarter } from '../src/scripts/utils/quarter-processing';
+
+const entry = (id: number, date: string): TimesheetEntry =>
+  ({ id, date, hours: 1, project: 'OSC-BBB', taskDescription: `Task ${id}` }) as TimesheetEntry;
+
+describe('resolveSubmissionOrder', () => {
+  it('falls back to chronological for missing or unknown values', () => {
+    expect(resolveSubmissionOrder('deadline')).toBe('deadline');
+    expect(resolveSubmissionOrder('random')).toBe('chronological');
+    expect(resolveSubmissionOrder(undefined)).toBe('chronological');
+  });
+});
+
+describe('orderEntriesForSubmission', () => {
+  it('sorts by date in the order direction, keeping same-day entries in place', () => {
+    const entries = [entry(1, '2025-10-02'), entry(2, '2025-10-01'), entry(3, '2025-10-02')];
+    expect(orderEntriesForSubmission(entries, 'chronological').map((e) => e.id)).toEqual([2, 1, 3]);
+    expect(orderEntriesForSubmission(entries, 'newest-first').map((e) => e.id)).toEqual([1, 3, 2]);
+  });
+});
+
+describe('orderQuarterGroups', () => {
+  const q = (id: string, lockDate?: string) => ({
+    id,
+    name: id,
+    startDate: '',
+    endDate: '',
+    formUrl: '',
+    formId: id,
+    ...(lockDate ? { lockDate } : {})
+  });
+  const groups = [
+    { quarter: q('Q4', '2026-01-20'), firstDate: '2025-12-01', lastDate: '2025-12-30' },
+    { quarter: null, firstDate: '2019-01-01', lastDate: '2019-01-01' },
+    { quarter: q('Q1', '2026-01-10'), firstDate: '2026-01-02', lastDate: '2026-01-05' },
+    { quarter: q('Q3'), firstDate: '2025-09-01', lastDate: '2025-09-02' }
+  ];
+  const ids = (order: 'chronological' | 'newest-first' | 'deadline') =>
+    orderQuarterGroups(groups, (group) => group, order).map((group) => group.quarter?.id ?? null);
+
+  it('keeps entries outside every quarter last in every order', () => {
+    expect(ids('chronological')).toEqual(['Q3', 'Q4', 'Q1', null]);
+    expect(ids('newest-first')).toEqual(['Q1', 'Q4', 'Q3', null]);
+    expect(ids('deadline')).toEqual(['Q1', 'Q4', 'Q3', null]);
+  });
+});
+
+describe('processEntriesByQuarter ordering', () => {
+  it('hands rows to the bot newest first when configured', async () => {
+    const runBot = vi.fn(async (rows: Array<Record<string, unknown>>) => ({
+      ok: true,
+      submitted: rows.map((_, index) => index),
+      errors: [] as Array<[number, string]>
+    }));
+
+    const result = await processEntriesByQuarter(
+      [entry(1, '2026-01-05'), entry(2, '2025-12-30'), entry(3, '2026-01-06')],
+      {
+        toBotRow: (e) => ({ id: e.id ?? null }),
+        runBot,
+        email: 'tech@example.com',
+        password: 'secret',
+        useMockWebsite: true,
+        submissionOrder: 'newest-first'
+      }
+    );
+
+    expect(runBot.mock.calls.map(([rows]) => rows.map((row) => row['id']))).toEqual([[3, 1], [2]]);
+    expect(result.submittedIds).toEqual([3, 1, 2]);
+  });
+});
@@ -0,0 +1,60 @@
+/**
+ * @fileoverview Submission Verification Level Tests
+ *
+ * Covers which submit evidence each verification level accepts and reading
+ * the level from a stored setting.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { describe, it, expect } from 'vitest';
+import {
+  isSubmissionVerified,
+  resolveVerificationLevel,
+  type SubmissionEvidence
+} from '../src/engine/browser/verification_level';
+
+const none: SubmissionEvidence = {
+  successResponseCount: 0,
+  responseHasIndicator: false,
+  submissionIdCount: 0,
+  domIndicator: false,
+  confirmationUrl: false
+};
+
+const bareResponse: SubmissionEvidence = { ...none, successResponseCount: 1 };
+const responseWithId: SubmissionEvidence = { ...bareResponse, submissionIdCount: 1 };
+const banner: SubmissionEvidence = { ...none, domIndicator: true };
+const confirmationPage: SubmissionEvidence = { ...none, confirmationUrl: true };
+
+describe('isSubmissionVerified', () => {
+  it('lenient accepts a banner or a validated 2xx response', () => {
+    expect(isSubmissionVerified(banner, 'lenient', true)).toBe(true);
+    expect(isSubmissionVerified(responseWithId, 'lenient', true)).toBe(true);
+    expect(isSubmissionVerified(bareResponse, 'lenient', true)).toBe(false);
+    expect(isSubmissionVerified(bareResponse, 'lenient', false)).toBe(true);
+    expect(isSubmissionVerified(none, 'lenient', false)).toBe(false);
+  });
+
+  it('standard requires the page to confirm the submit', () => {
+    expect(isSubmissionVerified(banner, 'standard', true)).toBe(true);
+    expect(isSubmissionVerified(confirmationPage, 'standard', true)).toBe(true);
+    expect(isSubmissionVerified(responseWithId, 'standard', true)).toBe(false);
+  });
+
+  it('strict requires a submission response with a submissionId', () => {
+    expect(isSubmissionVerified(responseWithId, 'strict', true)).toBe(true);
+    expect(isSubmissionVerified({ ...banner, confirmationUrl: true }, 'strict', true)).toBe(false);
+    expect(isSubmissionVerified({ ...bareResponse, responseHasIndicator: true }, 'strict', false)).toBe(false);
+  });
+});
+
+describe('resolveVerificationLevel', () => {
+  it('falls back to lenient for missing or unknown settings', () => {
+    expect(resolveVerificationLevel('strict')).toBe('strict');
+    expect(resolveVerificationLevel(undefined)).toBe('lenient');
+    expect(resolveVerificationLevel('paranoid')).toBe('lenient');
+  });
+});
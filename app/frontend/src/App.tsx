@@ -36,6 +36,7 @@ import LoginDialog from "./components/LoginDialog";
 import { DataProvider, useData } from "./contexts/DataContext";
 import { SessionProvider, useSession } from "./contexts/SessionContext";
 import { initializeTheme } from "./utils/theme-manager";
+import { watchClockSkew } from "./utils/clock-skew";
 import "./styles/App.css";
 import "./styles/transitions.css";
 import {
@@ -45,6 +46,7 @@ import {
   removeAllUpdateListeners,
 } from "./services/ipc/updates";
 import { logDebug, logInfo, logUserAction } from "./services/ipc/logger";
+import { getStatusCounts } from "./services/ipc/timesheet";
 export { AboutBody } from "./components/AboutBody";
 export { Splash } from "./components/Splash";
 
@@ -154,6 +156,15 @@ function AppContent() {
   useEffect(() => {
     initializeTheme();
   }, []);
+
+  // Date errors mention a wrong PC clock once the backend has checked it
+  useEffect(() => watchClockSkew(), []);
+
+  // Loading the page resets the window title; ask the backend to re-apply
+  // the draft/failed counts (it keeps them current from then on)
+  useEffect(() => {
+    void getStatusCounts();
+  }, []);
   useEffect(() => {
     const rootElement = document.getElementById("root");
     if (!rootElement) return;
@@ -1,5 +1,9 @@
-import { exportLogs as exportLogsIpc } from "@/services/ipc/logs";
+import {
+  exportCrashReports as exportCrashReportsIpc,
+  exportLogs as exportLogsIpc,
+} from "@/services/ipc/logs";
 import { logError, logInfo, logWarn } from "@/services/ipc/logger";
+import { deliverExport } from "@/utils/export-delivery";
 
 const validateExportLogsInputs = (
   token: string | null,
@@ -81,31 +85,26 @@ const validateExportLogsResponse = (
   return result;
 };
 
-const downloadLogFile = (
+const deliverLogFile = async (
   content: string,
   filename: string,
   mimeType: string | undefined,
   setError: (error: string) => void
-): string | null => {
+): Promise<boolean> => {
   try {
-    const blob = new Blob([content], { type: mimeType || "text/plain" });
-    const downloadUrl = URL.createObjectURL(blob);
-    const a = document.createElement("a");
-    a.href = downloadUrl;
-    a.download = filename;
-    document.body.appendChild(a);
-    a.click();
-    document.body.removeChild(a);
-
-    logInfo("Logs exported successfully", { filename });
-    return downloadUrl;
-  } catch (blobError) {
-    const errorMsg = `Failed to create download: ${blobError instanceof Error ? blobError.message : String(blobError)}`;
+    const delivery = await deliverExport(content, filename, mimeType || "text/plain");
+    if (delivery === "copied") {
+      window.alert("Saving files is disabled on this device, so the export was copied to the clipboard");
+    }
+    logInfo("Logs exported successfully", { filename, delivery });
+    return true;
+  } catch (deliveryError) {
+    const errorMsg = `Failed to create download: ${deliveryError instanceof Error ? deliveryError.message : String(deliveryError)}`;
     setError(errorMsg);
-    logError("Export logs failed - blob creation error", {
-      error: blobError instanceof Error ? blobError.message : String(blobError),
+    logError("Export logs failed - delivery error", {
+      error: deliveryError instanceof Error ? deliveryError.message : String(deliveryError),
     });
-    return null;
+    return false;
   }
 };
 
@@ -113,7 +112,7 @@ const tryExportLogs = async (
   token: string,
   logPath: string,
   setError: (error: string) => void
-): Promise<string | null> => {
+): Promise<boolean> => {
   try {
     // logPath is already the full path to the latest log file from the backend
     const response = await exportLogsIpc(token, logPath, "txt");
@@ -124,10 +123,10 @@ const tryExportLogs = async (
       setError
     );
     if (!validatedData) {
-      return null;
+      return false;
     }
 
-    return downloadLogFile(
+    return await deliverLogFile(
       validatedData.content,
       validatedData.filename,
       validatedData.mimeType,
@@ -140,18 +139,7 @@ const tryExportLogs = async (
       error: errorMsg,
       stack: err instanceof Error ? err.stack : undefined,
     });
-    return null;
-  }
-};
-
-const cleanupDownloadUrl = (downloadUrl: string): void => {
-  try {
-    URL.revokeObjectURL(downloadUrl);
-  } catch (revokeError) {
-    logWarn("Could not revoke blob URL", {
-      error:
-        revokeError instanceof Error ? revokeError.message : String(revokeError),
-    });
+    return false;
   }
 };
 
@@ -170,9 +158,39 @@ export const exportLogs = async (
   setError("");
 
   // token is validated as non-null by validateExportLogsInputs
-  const downloadUrl = await tryExportLogs(token!, logPath, setError);
-  if (downloadUrl) {
-    cleanupDownloadUrl(downloadUrl);
+  await tryExportLogs(token!, logPath, setError);
+  setIsExporting(false);
+};
+
+export const exportCrashReports = async (
+  token: string | null,
+  setIsExporting: (exporting: boolean) => void,
+  setError: (error: string) => void
+) => {
+  if (!token) {
+    setError("Session token not available");
+    logWarn("Export crash reports attempted without session token");
+    return;
+  }
+
+  setIsExporting(true);
+  setError("");
+
+  try {
+    const response = await exportCrashReportsIpc(token);
+    const validatedData = validateExportLogsResponse(response, "crash-reports", setError);
+    if (validatedData) {
+      await deliverLogFile(
+        validatedData.content,
+        validatedData.filename,
+        validatedData.mimeType,
+        setError
+      );
+    }
+  } catch (err) {
+    const errorMsg = err instanceof Error ? err.message : "Unknown error";
+    setError(errorMsg);
+    logError("Export crash reports error", { error: errorMsg });
   }
   setIsExporting(false);
 };
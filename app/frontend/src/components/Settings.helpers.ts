@@ -74,13 +74,20 @@ export const loadLogFiles = async (
 };
 
 export const loadSettings = async (
-  setHeadlessMode: (mode: boolean) => void
+  setHeadlessMode: (mode: boolean) => void,
+  setHighlightInteractions?: (enabled: boolean) => void
 ) => {
   try {
     const response = await getSetting("browserHeadless");
     if (response?.success && response.value !== undefined) {
       setHeadlessMode(Boolean(response.value));
     }
+    if (setHighlightInteractions) {
+      const highlight = await getSetting("highlightInteractions");
+      if (highlight?.success && highlight.value !== undefined) {
+        setHighlightInteractions(Boolean(highlight.value));
+      }
+    }
   } catch (err) {
     logError("Could not load settings", {
       error: err instanceof Error ? err.message : String(err),
@@ -88,6 +95,34 @@ export const loadSettings = async (
   }
 };
 
+export const handleHighlightInteractionsToggle = async (
+  checked: boolean,
+  setIsLoadingSettings: (loading: boolean) => void,
+  setHighlightInteractions: (enabled: boolean) => void,
+  setError: (error: string) => void
+) => {
+  setIsLoadingSettings(true);
+  try {
+    const response = await setSetting("highlightInteractions", checked);
+    if (response?.success) {
+      setHighlightInteractions(checked);
+      logInfo("Interaction highlighting setting updated", { highlightInteractions: checked });
+    } else {
+      setError("Could not save interaction highlighting setting");
+      logError("Could not save interaction highlighting setting", {
+        error: response?.error,
+      });
+    }
+  } catch (err) {
+    setError(err instanceof Error ? err.message : "Unknown error");
+    logError("Interaction highlighting toggle error", {
+      error: err instanceof Error ? err.message : String(err),
+    });
+  } finally {
+    setIsLoadingSettings(false);
+  }
+};
+
 export const loadThemeSettings = async (
   setThemeMode: (mode: ThemeMode) => void
 ) => {
@@ -291,4 +326,4 @@ export const handleAdminRebuildDatabase = async (
   }
 };
 
-export { exportLogs } from "./Settings.helpers.logs";
+export { exportLogs, exportCrashReports } from "./Settings.helpers.logs";
@@ -24,6 +24,7 @@ import CardContent from '@mui/material/CardContent';
 import Typography from '@mui/material/Typography';
 import SettingsIcon from '@mui/icons-material/Settings';
 import { useSession } from '@/contexts/SessionContext';
+import { onSettingsChanged, removeSettingsChangedListener } from '@/services/ipc/settings';
 import type { ThemeMode } from '@/utils/theme-manager';
 import './Settings.css';
 import {
@@ -32,12 +33,14 @@ import {
   loadSettings as loadSettingsHelper,
   loadThemeSettings as loadThemeSettingsHelper,
   handleHeadlessModeToggle as handleHeadlessModeToggleHelper,
+  handleHighlightInteractionsToggle as handleHighlightInteractionsToggleHelper,
   handleThemeModeChange as handleThemeModeChangeHelper,
   handleUpdateCredentials as handleUpdateCredentialsHelper,
   handleLogout as handleLogoutHelper,
   handleAdminClearCredentials as handleAdminClearCredentialsHelper,
   handleAdminRebuildDatabase as handleAdminRebuildDatabaseHelper,
-  exportLogs as exportLogsHelper
+  exportLogs as exportLogsHelper,
+  exportCrashReports as exportCrashReportsHelper
 } from './Settings.helpers';
 import {
   ExportLogsDialog,
@@ -100,6 +103,7 @@ function Settings() {
   
   // Settings state
   const [headlessMode, setHeadlessMode] = useState<boolean>(false);
+  const [highlightInteractions, setHighlightInteractions] = useState<boolean>(false);
   const [themeMode, setThemeMode] = useState<ThemeMode>('auto');
   const [isLoadingSettings, setIsLoadingSettings] = useState(false);
 
@@ -112,7 +116,7 @@ function Settings() {
   }, [token]);
 
   const loadSettings = useCallback(async () => {
-    await loadSettingsHelper(setHeadlessMode);
+    await loadSettingsHelper(setHeadlessMode, setHighlightInteractions);
     await loadThemeSettingsHelper(setThemeMode);
   }, []);
 
@@ -120,6 +124,10 @@ function Settings() {
     await handleHeadlessModeToggleHelper(checked, setIsLoadingSettings, setHeadlessMode, setError);
   }, []);
 
+  const handleHighlightInteractionsToggle = useCallback(async (checked: boolean) => {
+    await handleHighlightInteractionsToggleHelper(checked, setIsLoadingSettings, setHighlightInteractions, setError);
+  }, []);
+
   const handleThemeModeChange = useCallback(async (mode: ThemeMode) => {
     await handleThemeModeChangeHelper(mode, setIsLoadingSettings, setThemeMode, setError);
   }, []);
@@ -131,6 +139,16 @@ function Settings() {
     void loadSettings();
   }, [loadLogFiles, loadStoredCredentials, loadSettings]);
 
+  // Settings saved elsewhere (e.g. another window) show up without reopening the page
+  useEffect(() => {
+    onSettingsChanged(({ keys }) => {
+      if (keys.includes('browserHeadless') || keys.includes('highlightInteractions')) {
+        void loadSettingsHelper(setHeadlessMode, setHighlightInteractions);
+      }
+    });
+    return () => removeSettingsChangedListener();
+  }, []);
+
   const handleUpdateCredentials = useCallback(async () => {
     await handleUpdateCredentialsHelper(
       updateEmail,
@@ -173,6 +191,10 @@ function Settings() {
     await exportLogsHelper(token, logFiles, logPath, setIsExporting, setError);
   }, [token, logFiles, logPath]);
 
+  const exportCrashReports = useCallback(async () => {
+    await exportCrashReportsHelper(token, setIsExporting, setError);
+  }, [token]);
+
   return (
     <div className="settings-container">
       {/* Main Container Card */}
@@ -217,6 +239,7 @@ function Settings() {
         isExporting={isExporting}
         isLoading={isLoading}
         onExport={exportLogs}
+        onExportCrashReports={exportCrashReports}
       />
 
       <UserGuideDialog
@@ -283,9 +306,11 @@ function Settings() {
         }}
         error={error}
         headlessMode={headlessMode}
+        highlightInteractions={highlightInteractions}
         themeMode={themeMode}
         isLoadingSettings={isLoadingSettings}
         onHeadlessModeToggle={handleHeadlessModeToggle}
+        onHighlightInteractionsToggle={handleHighlightInteractionsToggle}
         onThemeModeChange={handleThemeModeChange}
       />
 
@@ -4,6 +4,32 @@
  */
 
 import { exportToCSV as exportToCSVIpc } from "@/services/ipc/timesheet";
+import { deliverExport } from "@/utils/export-delivery";
+
+interface SearchableArchiveEntry {
+  project: string;
+  task_description: string;
+  external_ref?: string | null;
+}
+
+/**
+ * Entries whose external reference, task description or project contains the
+ * query (case-insensitive); a blank query keeps every entry
+ */
+export function filterArchiveEntries<T extends SearchableArchiveEntry>(
+  entries: T[],
+  query: string
+): T[] {
+  const needle = query.trim().toLowerCase();
+  if (!needle) {
+    return entries;
+  }
+  return entries.filter((entry) =>
+    [entry.external_ref, entry.task_description, entry.project].some((value) =>
+      value?.toLowerCase().includes(needle)
+    )
+  );
+}
 
 interface ExportResponse {
   success: boolean;
@@ -13,21 +39,6 @@ interface ExportResponse {
   entryCount?: number;
 }
 
-/**
- * Download CSV content as a file
- */
-function downloadCSVFile(csvContent: string, filename: string): void {
-  const blob = new Blob([csvContent], { type: "text/csv" });
-  const downloadUrl = URL.createObjectURL(blob);
-  const a = document.createElement("a");
-  a.href = downloadUrl;
-  a.download = filename;
-  document.body.appendChild(a);
-  a.click();
-  document.body.removeChild(a);
-  URL.revokeObjectURL(downloadUrl);
-}
-
 /**
  * Generate default filename for CSV export
  */
@@ -39,10 +50,10 @@ function getDefaultFilename(): string {
 /**
  * Handle CSV export with validation and error handling
  */
-export async function handleCSVExport(): Promise<void> {
-  window.logger?.userAction("export-to-csv-clicked");
+export async function handleCSVExport(templateName?: string): Promise<void> {
+  window.logger?.userAction("export-to-csv-clicked", { templateName });
 
-  const response: ExportResponse = await exportToCSVIpc();
+  const response: ExportResponse = await exportToCSVIpc(templateName);
 
   if (!response.success) {
     const errorMsg = response.error || "Could not export CSV";
@@ -59,12 +70,17 @@ export async function handleCSVExport(): Promise<void> {
   }
 
   const filename = response.filename || getDefaultFilename();
-  downloadCSVFile(response.csvContent, filename);
+  const delivery = await deliverExport(response.csvContent, filename, "text/csv");
 
   window.logger?.info("CSV exported successfully", {
     filename,
     entryCount: response.entryCount,
+    delivery,
   });
 
-  window.alert(`Successfully exported ${response.entryCount || 0} entries to ${filename}`);
+  window.alert(
+    delivery === "copied"
+      ? `Saving files is disabled on this device, so ${response.entryCount || 0} entries were copied to the clipboard as CSV`
+      : `Successfully exported ${response.entryCount || 0} entries to ${filename}`
+  );
 }
@@ -1,4 +1,4 @@
-import { useState, useMemo, memo, useCallback } from "react";
+import { useState, useMemo, memo, useCallback, useEffect } from "react";
 import { HotTable } from "@handsontable/react-wrapper";
 import { registerAllModules } from "handsontable/registry";
 import DownloadIcon from "@mui/icons-material/Download";
@@ -6,12 +6,15 @@ import RefreshIcon from "@mui/icons-material/Refresh";
 import IconButton from "@mui/material/IconButton";
 import Tooltip from "@mui/material/Tooltip";
 import CircularProgress from "@mui/material/CircularProgress";
+import MenuItem from "@mui/material/MenuItem";
+import TextField from "@mui/material/TextField";
 import "handsontable/styles/handsontable.css";
 import "handsontable/styles/ht-theme-horizon.css";
 import { useData } from "@/contexts/DataContext";
 import { useHandsontableTheme } from "@/hooks/useHandsontableTheme";
 import { StatusButton } from "@/components/StatusButton";
-import { handleCSVExport } from "./DatabaseViewer.helpers";
+import { listExportTemplates } from "@/services/ipc/timesheet";
+import { filterArchiveEntries, handleCSVExport } from "./DatabaseViewer.helpers";
 import "./DatabaseViewer.css";
 
 type ButtonStatus = "neutral" | "ready" | "warning";
@@ -29,6 +32,10 @@ interface TimesheetEntry {
   task_description: string;
   status?: string;
   submitted_at?: string;
+  created_at?: string | null;
+  updated_at?: string | null;
+  submitted_run_id?: number | null;
+  external_ref?: string | null;
 }
 
 interface Credential {
@@ -44,6 +51,18 @@ function Archive() {
   const [activeTab] = useState<"timesheet" | "credentials">("timesheet");
   const [isExporting, setIsExporting] = useState(false);
   const [isManualRefreshing, setIsManualRefreshing] = useState(false);
+  const [exportTemplates, setExportTemplates] = useState<ExportTemplate[]>([]);
+  // Empty string selects the default columns
+  const [exportTemplateName, setExportTemplateName] = useState("");
+  const [searchQuery, setSearchQuery] = useState("");
+
+  useEffect(() => {
+    void listExportTemplates().then((result) => {
+      if (result.success && result.templates) {
+        setExportTemplates(result.templates);
+      }
+    });
+  }, []);
 
   // Use shared DataContext instead of local state
   const {
@@ -88,6 +107,11 @@ function Archive() {
       tool: entry.tool || "",
       chargeCode: entry.detail_charge_code || "",
       taskDescription: entry.task_description,
+      externalRef: entry.external_ref || "",
+      createdAt: entry.created_at || "",
+      updatedAt: entry.updated_at || "",
+      submittedAt: entry.submitted_at || "",
+      submittedRunId: entry.submitted_run_id ?? "",
     }));
     window.logger?.verbose("[Archive] Formatted timesheet data", {
       entryCount: formatted.length,
@@ -119,6 +143,11 @@ function Archive() {
       { data: "tool", title: "Tool", width: 100 },
       { data: "chargeCode", title: "Detail Charge Code", width: 120 },
       { data: "taskDescription", title: "Task Description", width: 200 },
+      { data: "externalRef", title: "External Ref", width: 110 },
+      { data: "createdAt", title: "Created", width: 150 },
+      { data: "updatedAt", title: "Last Edited", width: 150 },
+      { data: "submittedAt", title: "Submitted", width: 150 },
+      { data: "submittedRunId", title: "Run", width: 70 },
     ],
     []
   );
@@ -156,7 +185,7 @@ function Archive() {
     if (isExporting) return;
     setIsExporting(true);
     try {
-      await handleCSVExport();
+      await handleCSVExport(exportTemplateName || undefined);
     } catch (error) {
       const errorMsg = error instanceof Error ? error.message : String(error);
       window.alert(`Export failed: ${errorMsg}`);
@@ -164,7 +193,7 @@ function Archive() {
     } finally {
       setIsExporting(false);
     }
-  }, [isExporting]);
+  }, [isExporting, exportTemplateName]);
 
   // Validate archive data for button status - MUST be before early returns
   const buttonStatus: ButtonStatus = useMemo(() => {
@@ -205,6 +234,31 @@ function Archive() {
         >
           Export to CSV
         </StatusButton>
+        {exportTemplates.length > 0 && (
+          <TextField
+            select
+            size="small"
+            label="Columns"
+            value={exportTemplateName}
+            onChange={(event) => setExportTemplateName(event.target.value)}
+            sx={{ minWidth: 180 }}
+          >
+            <MenuItem value="">Default columns</MenuItem>
+            {exportTemplates.map((template) => (
+              <MenuItem key={template.name} value={template.name}>
+                {template.name}
+              </MenuItem>
+            ))}
+          </TextField>
+        )}
+        <TextField
+          size="small"
+          label="Search"
+          placeholder="Work order or description"
+          value={searchQuery}
+          onChange={(event) => setSearchQuery(event.target.value)}
+          sx={{ minWidth: 220 }}
+        />
         <Tooltip title="Refresh archive data" placement="bottom">
           <span>
             <IconButton
@@ -245,7 +299,9 @@ function Archive() {
         </div>
       ) : activeTab === "timesheet" ? (
         <HotTable
-          data={formatTimesheetData(archiveData.timesheet)}
+          data={formatTimesheetData(
+            filterArchiveEntries(archiveData.timesheet, searchQuery)
+          )}
           columns={timesheetColumns}
           colHeaders={true}
           rowHeaders={true}
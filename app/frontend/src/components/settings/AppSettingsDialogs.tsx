@@ -21,9 +21,11 @@ interface ApplicationSettingsDialogProps {
   onClose: () => void;
   error: string;
   headlessMode: boolean;
+  highlightInteractions: boolean;
   themeMode: ThemeMode;
   isLoadingSettings: boolean;
   onHeadlessModeToggle: (checked: boolean) => void;
+  onHighlightInteractionsToggle: (checked: boolean) => void;
   onThemeModeChange: (mode: ThemeMode) => void;
 }
 
@@ -32,9 +34,11 @@ export const ApplicationSettingsDialog = ({
   onClose,
   error,
   headlessMode,
+  highlightInteractions,
   themeMode,
   isLoadingSettings,
   onHeadlessModeToggle,
+  onHighlightInteractionsToggle,
   onThemeModeChange,
 }: ApplicationSettingsDialogProps) => {
   return (
@@ -122,6 +126,24 @@ export const ApplicationSettingsDialog = ({
               </Box>
             }
           />
+          <FormControlLabel
+            control={
+              <Switch
+                checked={highlightInteractions}
+                onChange={(e) => onHighlightInteractionsToggle(e.target.checked)}
+                disabled={isLoadingSettings || headlessMode}
+              />
+            }
+            label={
+              <Box>
+                <Typography variant="body1">Highlight Interactions</Typography>
+                <Typography variant="caption" color="text.secondary">
+                  Outline each field as it is filled and show the current step
+                  in the browser window. Only applies when headless mode is off.
+                </Typography>
+              </Box>
+            }
+          />
         </Box>
       </DialogContent>
       <DialogActions>
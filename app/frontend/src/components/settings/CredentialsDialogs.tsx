@@ -8,6 +8,13 @@ import DialogTitle from "@mui/material/DialogTitle";
 import DialogContent from "@mui/material/DialogContent";
 import DialogActions from "@mui/material/DialogActions";
 import TextField from "@mui/material/TextField";
+import IconButton from "@mui/material/IconButton";
+import InputAdornment from "@mui/material/InputAdornment";
+import VisibilityIcon from "@mui/icons-material/Visibility";
+import VisibilityOffIcon from "@mui/icons-material/VisibilityOff";
+import { useEffect, useState } from "react";
+import { revealCredentials } from "@/services/ipc/credentials";
+import { useSession } from "@/contexts/SessionContext";
 import { autoCompleteEmailDomain } from "@/utils/emailAutoComplete";
 
 interface UpdateCredentialsDialogProps {
@@ -39,6 +46,45 @@ export const UpdateCredentialsDialog = ({
   isUpdatingCredentials,
   onUpdate,
 }: UpdateCredentialsDialogProps) => {
+  const { token } = useSession();
+  const [showPassword, setShowPassword] = useState(false);
+  const [isRevealing, setIsRevealing] = useState(false);
+  const [revealError, setRevealError] = useState("");
+
+  useEffect(() => {
+    if (!open) {
+      setShowPassword(false);
+      setRevealError("");
+    }
+  }, [open]);
+
+  // A typed password is just unmasked; the stored one needs the backend,
+  // which may ask for Windows Hello / Touch ID first
+  const handleTogglePassword = async () => {
+    setRevealError("");
+    if (showPassword || updatePassword || storedCredentials.length === 0) {
+      setShowPassword(!showPassword);
+      return;
+    }
+    if (!token) {
+      setRevealError("Log in to show the stored password");
+      return;
+    }
+    window.logger?.userAction("credentials-reveal-clicked");
+    setIsRevealing(true);
+    try {
+      const result = await revealCredentials(token, "smartsheet");
+      if (result.success && result.password !== undefined) {
+        onPasswordChange(result.password);
+        setShowPassword(true);
+      } else {
+        setRevealError(result.error || "Could not show the stored password");
+      }
+    } finally {
+      setIsRevealing(false);
+    }
+  };
+
   return (
     <Dialog
       open={open}
@@ -72,13 +118,34 @@ export const UpdateCredentialsDialog = ({
           <TextField
             fullWidth
             label="Password"
-            type="password"
+            type={showPassword ? "text" : "password"}
             value={updatePassword}
             onChange={(e) => onPasswordChange(e.target.value)}
             placeholder="Your password"
             margin="normal"
             variant="outlined"
+            slotProps={{
+              input: {
+                endAdornment: (
+                  <InputAdornment position="end">
+                    <IconButton
+                      aria-label={showPassword ? "Hide password" : "Show password"}
+                      onClick={() => void handleTogglePassword()}
+                      disabled={isRevealing}
+                      edge="end"
+                    >
+                      {showPassword ? <VisibilityOffIcon /> : <VisibilityIcon />}
+                    </IconButton>
+                  </InputAdornment>
+                ),
+              },
+            }}
           />
+          {revealError && (
+            <Alert severity="warning" sx={{ mt: 1 }}>
+              {revealError}
+            </Alert>
+          )}
         </Box>
       </DialogContent>
       <DialogActions>
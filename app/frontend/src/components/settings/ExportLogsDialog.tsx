@@ -16,6 +16,7 @@ interface ExportLogsDialogProps {
   isExporting: boolean;
   isLoading: boolean;
   onExport: () => void;
+  onExportCrashReports?: () => void;
 }
 
 export const ExportLogsDialog = ({
@@ -26,6 +27,7 @@ export const ExportLogsDialog = ({
   isExporting,
   isLoading,
   onExport,
+  onExportCrashReports,
 }: ExportLogsDialogProps) => {
   return (
     <Dialog
@@ -57,6 +59,11 @@ export const ExportLogsDialog = ({
       </DialogContent>
       <DialogActions>
         <Button onClick={onClose}>Cancel</Button>
+        {onExportCrashReports && (
+          <Button onClick={onExportCrashReports} disabled={isExporting || isLoading}>
+            Export Crash Reports
+          </Button>
+        )}
         <Button
           variant="contained"
           startIcon={
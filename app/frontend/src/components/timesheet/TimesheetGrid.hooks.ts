@@ -2,7 +2,7 @@
  * Custom hooks for TimesheetGrid component
  */
 
-import React, { useEffect, useRef, useCallback } from "react";
+import React, { useEffect, useRef, useCallback, useState } from "react";
 import type { HotTableRef } from "@handsontable/react-wrapper";
 import type { TimesheetRow } from "./schema/timesheet.schema";
 import { loadMacros } from "@/utils/macroStorage";
@@ -10,6 +10,7 @@ import { detectWeekdayPattern } from "@/utils/smartDate";
 import { saveRowToDatabase } from "./persistence/timesheet.persistence";
 import { batchSaveToDatabase as batchSaveToDatabaseUtil } from "./persistence/timesheet.persistence";
 import type { DateEditor } from "./TimesheetGrid.types";
+import { getDraftStats } from "@/services/ipc/timesheet";
 
 /**
  * Hook to load macros on mount
@@ -230,3 +231,30 @@ export function useHandleAfterBeginEditing<
     [hotTableRef, setValidationErrors]
   );
 }
+
+/**
+ * Hook to fetch draft statistics for the toolbar
+ *
+ * Refetches whenever the grid reaches the saved state, so the figures come
+ * from the database rather than from unsaved rows.
+ */
+export function useDraftStats(
+  saveButtonState: "saved" | "saving" | "save"
+): DraftStats | null {
+  const [stats, setStats] = useState<DraftStats | null>(null);
+
+  useEffect(() => {
+    if (saveButtonState !== "saved") return;
+    let cancelled = false;
+    void getDraftStats().then((result) => {
+      if (!cancelled && result.success && result.stats) {
+        setStats(result.stats);
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [saveButtonState]);
+
+  return stats;
+}
@@ -69,6 +69,7 @@ import {
 } from "@sheetpilot/shared/business-config";
 import {
   cancelTimesheetSubmission,
+  listEntryCategories,
   loadDraft as loadDraftIpc,
   resetInProgress as resetInProgressIpc,
 } from "@/services/ipc/timesheet";
@@ -121,6 +122,7 @@ import {
   useBatchSaveToDatabase,
   useHandleBeforePaste,
   useHandleAfterBeginEditing,
+  useDraftStats,
 } from "./TimesheetGrid.hooks";
 
 // Register all Handsontable modules
@@ -182,6 +184,7 @@ const TimesheetGrid = forwardRef<TimesheetGridHandle, TimesheetGridProps>(
     const [projects, setProjects] = useState<readonly string[]>([]);
     const [chargeCodes, setChargeCodes] = useState<readonly string[]>([]);
     const [_businessConfigError, setBusinessConfigError] = useState<string | null>(null);
+    const [categories, setCategories] = useState<readonly string[]>([]);
 
     // Macro state
     const [macros, setMacros] = useState<MacroRow[]>([]);
@@ -213,6 +216,7 @@ const TimesheetGrid = forwardRef<TimesheetGridHandle, TimesheetGridProps>(
     >("saved");
     const unsavedRowsRef = useRef<Map<number, TimesheetRow>>(new Map());
     const saveStartTimeRef = useRef<number | null>(null);
+    const draftStats = useDraftStats(saveButtonState);
 
     const {
       timesheetDraftData,
@@ -452,6 +456,24 @@ const TimesheetGrid = forwardRef<TimesheetGridHandle, TimesheetGridProps>(
       };
     }, []);
 
+    // Category names for the Category column; teams without categories get an empty list
+    useEffect(() => {
+      let isMounted = true;
+      void listEntryCategories().then((result) => {
+        if (!isMounted) {
+          return;
+        }
+        if (result.success) {
+          setCategories((result.categories ?? []).map((category) => category.name));
+        } else {
+          logWarn("[TimesheetGrid] Could not load categories", { error: result.error });
+        }
+      });
+      return () => {
+        isMounted = false;
+      };
+    }, []);
+
     // Update column sources when projects or chargeCodes change (after initial load)
     useEffect(() => {
       // Skip if data hasn't loaded yet (initial state)
@@ -743,8 +765,8 @@ const TimesheetGrid = forwardRef<TimesheetGridHandle, TimesheetGridProps>(
     // WHY: Include projects and chargeCodes in dependency array so columns update when data loads from database
     // Database is single source of truth - empty arrays if not loaded yet
     const columnDefinitions = useMemo(
-      () => getColumnDefinitions(projects, chargeCodes),
-      [projects, chargeCodes]
+      () => getColumnDefinitions(projects, chargeCodes, categories),
+      [projects, chargeCodes, categories]
     );
     
     // WHY: Memoize these props to prevent creating new object/array references on every render.
@@ -843,6 +865,7 @@ const TimesheetGrid = forwardRef<TimesheetGridHandle, TimesheetGridProps>(
           onShowWeeklySummary={() => setShowWeeklySummary(true)}
           saveButtonState={saveButtonState}
           onSave={handleManualSave}
+          draftStats={draftStats}
         />
         <HotTable
           ref={hotTableRef}
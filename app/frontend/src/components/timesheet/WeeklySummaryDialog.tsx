@@ -19,6 +19,7 @@ import Typography from "@mui/material/Typography";
 import IconButton from "@mui/material/IconButton";
 import ChevronLeftIcon from "@mui/icons-material/ChevronLeft";
 import ChevronRightIcon from "@mui/icons-material/ChevronRight";
+import ContentCopyIcon from "@mui/icons-material/ContentCopy";
 import {
   getWeekBounds,
   getWeekKey,
@@ -26,6 +27,7 @@ import {
   formatWeekRange,
   getWeekDays,
 } from "@/utils/weekSummary";
+import { exportToClipboard } from "@/services/ipc/timesheet";
 import { useWeekNavigation } from "./hooks/useWeekNavigation";
 import WeeklySummaryTable from "./WeeklySummaryTable";
 import "./WeeklySummaryDialog.css";
@@ -168,6 +170,23 @@ const WeeklySummaryDialog = ({
   const { canGoPrevious, canGoNext, handlePreviousWeek, handleNextWeek } =
     useWeekNavigation(allWeeks, currentWeekKey, setCurrentWeekKey);
 
+  const [copyMessage, setCopyMessage] = useState<string | null>(null);
+
+  const handleCopyWeek = async () => {
+    window.logger?.userAction("weekly-summary-copy-clicked", { week: currentWeekKey });
+    // getWeekKey formats any date as YYYY-MM-DD
+    const result = await exportToClipboard({
+      startDate: getWeekKey(sunday),
+      endDate: getWeekKey(saturday),
+      format: "markdown",
+    });
+    setCopyMessage(
+      result.success
+        ? `Copied ${result.entryCount ?? 0} entries`
+        : result.error || "Could not copy entries"
+    );
+  };
+
   return (
     <Dialog
       open={open}
@@ -223,6 +242,18 @@ const WeeklySummaryDialog = ({
         <WeeklySummaryTable archiveData={currentWeekData} weekDays={weekDays} />
       </DialogContent>
       <DialogActions sx={{ p: 2 }}>
+        {copyMessage && (
+          <Typography variant="body2" sx={{ mr: "auto" }} role="status">
+            {copyMessage}
+          </Typography>
+        )}
+        <Button
+          onClick={() => void handleCopyWeek()}
+          startIcon={<ContentCopyIcon />}
+          disabled={currentWeekData.length === 0}
+        >
+          Copy week
+        </Button>
         <Button onClick={onClose} variant="contained" color="primary">
           Close
         </Button>
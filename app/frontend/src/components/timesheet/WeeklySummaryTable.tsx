@@ -22,6 +22,7 @@ import {
   getDayName,
   formatDateShort,
 } from "@/utils/weekSummary";
+import { formatHours } from "@sheetpilot/shared";
 import "./WeeklySummaryDialog.css";
 
 /**
@@ -50,9 +51,9 @@ interface WeeklySummaryTableProps {
 /**
  * Format hours as string with decimal places
  */
-const formatHours = (hours: number | null): string => {
+const formatCellHours = (hours: number | null): string => {
   if (hours === null || hours === 0) return "-";
-  return hours.toFixed(2);
+  return formatHours(hours);
 };
 
 /**
@@ -142,7 +143,7 @@ export const WeeklySummaryTable: React.FC<WeeklySummaryTableProps> = ({
                   align="center"
                   className="weekly-summary-hours-cell"
                 >
-                  {formatHours(hours)}
+                  {formatCellHours(hours)}
                 </TableCell>
               ))}
               <TableCell
@@ -150,7 +151,7 @@ export const WeeklySummaryTable: React.FC<WeeklySummaryTableProps> = ({
                 className="weekly-summary-project-total"
               >
                 <Typography variant="body2" sx={{ fontWeight: "bold" }}>
-                  {formatHours(summary.total)}
+                  {formatCellHours(summary.total)}
                 </Typography>
               </TableCell>
             </TableRow>
@@ -168,7 +169,7 @@ export const WeeklySummaryTable: React.FC<WeeklySummaryTableProps> = ({
                 className="weekly-summary-day-total"
               >
                 <Typography variant="body2" sx={{ fontWeight: "bold" }}>
-                  {formatHours(hours)}
+                  {formatCellHours(hours)}
                 </Typography>
               </TableCell>
             ))}
@@ -177,7 +178,7 @@ export const WeeklySummaryTable: React.FC<WeeklySummaryTableProps> = ({
                 variant="body2"
                 sx={{ fontWeight: "bold", fontSize: "1.1em" }}
               >
-                {formatHours(grandTotal)}
+                {formatCellHours(grandTotal)}
               </Typography>
             </TableCell>
           </TableRow>
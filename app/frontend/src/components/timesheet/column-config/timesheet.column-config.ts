@@ -8,16 +8,20 @@
  * Database is the single source of truth - projects and chargeCodes must be provided.
  */
 
+import { ENTRY_KINDS } from "@sheetpilot/shared";
+
 /**
  * Get column definitions for timesheet grid
  *
  * @param projects - Projects array from database (empty array if not loaded yet)
  * @param chargeCodes - Charge codes array from database (empty array if not loaded yet)
+ * @param categories - Names from the managed category list (empty if none are set up)
  * @returns Array of column definition objects
  */
 export function getColumnDefinitions(
   projects: readonly string[],
-  chargeCodes: readonly string[]
+  chargeCodes: readonly string[],
+  categories: readonly string[] = []
 ) {
   return [
     { data: "id", title: "ID", type: "numeric", width: 0.1, readOnly: true }, // Hidden ID column for row identity
@@ -77,5 +81,35 @@ export function getColumnDefinitions(
       className: "htLeft",
       maxLength: 120,
     },
+    {
+      data: "externalRef",
+      title: "Ref",
+      placeholder: "Work order",
+      className: "htCenter",
+      maxLength: 64,
+    },
+    {
+      data: "category",
+      title: "Category",
+      type: "dropdown",
+      source: [...categories],
+      strict: true,
+      allowInvalid: false,
+      placeholder: "",
+      className: "htCenter",
+      trimDropdown: true,
+    },
+    {
+      // Appended last so the tool and charge code column indices stay put
+      data: "kind",
+      title: "Kind",
+      type: "dropdown",
+      source: [...ENTRY_KINDS],
+      strict: true,
+      allowInvalid: false,
+      placeholder: "work",
+      className: "htCenter",
+      trimDropdown: true,
+    },
   ];
 }
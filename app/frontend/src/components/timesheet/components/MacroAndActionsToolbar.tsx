@@ -8,6 +8,7 @@ import PlayArrowIcon from '@mui/icons-material/PlayArrow';
 import SummarizeIcon from '@mui/icons-material/Summarize';
 import type { MacroRow } from '@/utils/macroStorage';
 import { isMacroEmpty } from '@/utils/macroStorage';
+import { formatHours } from '@sheetpilot/shared';
 
 type ButtonStatus = 'neutral' | 'ready' | 'warning';
 type SaveButtonState = 'saved' | 'saving' | 'save';
@@ -24,6 +25,7 @@ interface MacroAndActionsToolbarProps {
   onShowWeeklySummary: () => void;
   saveButtonState: SaveButtonState;
   onSave: () => void;
+  draftStats?: DraftStats | null;
 }
 
 export default function MacroAndActionsToolbar({
@@ -38,6 +40,7 @@ export default function MacroAndActionsToolbar({
   onShowWeeklySummary,
   saveButtonState,
   onSave,
+  draftStats,
 }: MacroAndActionsToolbarProps) {
   // Determine submit button appearance based on save state and validation status
   const getSubmitButtonConfig = () => {
@@ -89,6 +92,21 @@ export default function MacroAndActionsToolbar({
 
   const submitConfig = getSubmitButtonConfig();
 
+  const draftStatsLabel = draftStats && draftStats.draftCount > 0
+    ? `${draftStats.draftCount} draft${draftStats.draftCount === 1 ? '' : 's'} · ${formatHours(draftStats.totalHours)} h`
+    : null;
+  const draftStatsTooltip = draftStats
+    ? [
+        draftStats.earliestDate && draftStats.latestDate
+          ? `${draftStats.earliestDate} to ${draftStats.latestDate} (${draftStats.datesCovered} day${draftStats.datesCovered === 1 ? '' : 's'})`
+          : null,
+        ...draftStats.quarters.map(
+          (q) => `${q.quarterName ?? 'Outside configured quarters'}: ${q.draftCount} (${formatHours(q.hours)} h)`
+        ),
+        draftStats.undatedCount > 0 ? `Without a date: ${draftStats.undatedCount}` : null,
+      ].filter(Boolean).join('\n')
+    : '';
+
   return (
     <div className="macro-actions-toolbar">
       {/* Left section: Edit Macros icon + Macro buttons */}
@@ -149,6 +167,11 @@ export default function MacroAndActionsToolbar({
 
       {/* Right section: Summary and Submit actions */}
       <div className="actions-section">
+        {draftStatsLabel && (
+          <Tooltip title={<span style={{ whiteSpace: 'pre-line' }}>{draftStatsTooltip}</span>} placement="bottom">
+            <span className="draft-stats-label">{draftStatsLabel}</span>
+          </Tooltip>
+        )}
         <Button
           variant="outlined"
           size="medium"
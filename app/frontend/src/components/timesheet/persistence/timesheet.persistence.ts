@@ -1,5 +1,5 @@
 import type { TimesheetRow } from '@/components/timesheet/schema/timesheet.schema';
-import { deleteDraft, saveDraft, loadDraft } from '@/services/ipc/timesheet';
+import { deleteDraft, saveDraft, loadDraft, queueDraftSave } from '@/services/ipc/timesheet';
 import { logDebug, logError, logInfo, logVerbose, logWarn } from '@/services/ipc/logger';
 
 const LOCAL_BACKUP_KEY = 'sheetpilot_timesheet_backup';
@@ -14,7 +14,10 @@ function isRowNonEmpty(row: TimesheetRow): boolean {
     row.project ||
     row.taskDescription ||
     row.tool ||
-    row.chargeCode
+    row.chargeCode ||
+    row.externalRef ||
+    row.category ||
+    (row.kind !== undefined && row.kind !== 'work')
   );
 }
 
@@ -135,9 +138,13 @@ export function saveLocalBackup(data: TimesheetRow[]): void {
 
 /**
  * Save a single row to the database and return the saved entry
+ *
+ * With a rowKey the row goes through the backend's batched auto-save instead
+ * of being written immediately.
  */
 export async function saveRowToDatabase(
-  row: TimesheetRow
+  row: TimesheetRow,
+  rowKey?: string
 ): Promise<{ success: boolean; entry?: TimesheetRow; error?: string }> {
   try {
     // Allow partial row saves - no validation check for required fields
@@ -148,7 +155,7 @@ export async function saveRowToDatabase(
       hasProject: !!row.project,
       hasTaskDescription: !!row.taskDescription
     });
-    const result = await saveDraft(row);
+    const result = rowKey ? await queueDraftSave(row, rowKey) : await saveDraft(row);
     if (result.success && result.entry) {
       logVerbose('Row saved to database successfully', { id: result.entry.id, date: result.entry.date });
       return { success: true, entry: result.entry };
@@ -80,7 +80,9 @@ export function createSaveAndReloadRow(
     const abortController = setupSaveAbortController(inFlightSavesRef, rowIdx);
 
     try {
-      const saveResult = await saveRowToDatabase(row);
+      // New rows have no id yet, so they are keyed by grid position until saved
+      const rowKey = row.id ? `id-${row.id}` : `row-${rowIdx}`;
+      const saveResult = await saveRowToDatabase(row, rowKey);
 
       await processSaveResult(
         saveResult,
@@ -1,31 +1,19 @@
+import { isValidHours, type TimesheetRow } from '@sheetpilot/shared';
+
 /**
  * Timesheet row data structure
- * 
- * Represents a single time entry in the editable grid.
- * All fields are optional to support partial data entry during editing.
- * 
+ *
+ * Represents a single time entry in the editable grid. Defined in
+ * `@sheetpilot/shared` so the grid, preload bridge and draft handlers agree
+ * on its fields.
+ *
  * Data format notes:
  * - date: MM/DD/YYYY format (validates both MM/DD/YYYY and YYYY-MM-DD, normalizes to MM/DD/YYYY)
  * - hours: Decimal values in 15-minute increments (0.25 = 15 min, 0.5 = 30 min, 1.0 = 1 hour, etc.)
  *   Range: 0.25 to 24.0 hours
  * - tool/chargeCode: null when not applicable based on business rules
  */
-export interface TimesheetRow {
-  /** Database ID (assigned after first save) */
-  id?: number;
-  /** Date in MM/DD/YYYY format */
-  date?: string;
-  /** Hours worked as decimal (15-minute increments: 0.25, 0.5, 0.75, 1.0, etc.) */
-  hours?: number;
-  /** Project name from PROJECTS config */
-  project?: string;
-  /** Tool name (null if project doesn't require tools) */
-  tool?: string | null;
-  /** Charge code (null if tool doesn't require charge code) */
-  chargeCode?: string | null;
-  /** Task description text (max 120 characters for SmartSheet) */
-  taskDescription?: string;
-}
+export type { TimesheetRow };
 
 type DateParts = {
   month: number;
@@ -125,31 +113,7 @@ export function isValidDate(dateStr?: string): boolean {
   return isValidDateParts(dateParts);
 }
 
-/**
- * Check if hours value is valid
- * 
- * Validates that hours is:
- * - A number
- * - In 15-minute increments (multiple of 0.25)
- * - Within range: 0.25 to 24.0 hours
- * 
- * @param hours - Hours value to validate
- * @returns true if valid, false otherwise
- */
-export function isValidHours(hours?: number | null): boolean {
-  if (hours === undefined || hours === null) return false;
-  if (typeof hours !== 'number' || isNaN(hours)) return false;
-  
-  // Check if it's a multiple of 0.25 (15-minute increments)
-  // Use modulo with tolerance for floating point precision
-  const remainder = (hours * 4) % 1;
-  if (Math.abs(remainder) > 0.0001 && Math.abs(remainder - 1) > 0.0001) {
-    return false;
-  }
-  
-  // Check range: 0.25 to 24.0
-  return hours >= 0.25 && hours <= 24.0;
-}
+export { isValidHours };
 
 export function normalizeRowData(row: TimesheetRow, projectNeedsTools: (p?: string) => boolean, toolNeedsChargeCode: (t?: string) => boolean): TimesheetRow {
   const normalized = { ...row };
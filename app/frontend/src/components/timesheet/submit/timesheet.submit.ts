@@ -10,6 +10,8 @@ export interface SubmitResponse {
   error?: string;
   submitResult?: SubmitResult;
   dbPath?: string;
+  /** Set when Smartsheet rejected some entries while the rest went through */
+  partial?: boolean;
 }
 
 import { submitTimesheet as submitTimesheetIpc } from '@/services/ipc/timesheet';
@@ -69,6 +71,11 @@ async function submitTimesheet(
     return res;
   }
 
+  if (res.partial) {
+    logWarn('Smartsheet rejected some entries; they stay pending with a failure reason', {
+      submitResult: res.submitResult,
+    });
+  }
   logInfo(buildSubmitMessage(res.submitResult));
   await refreshAfterSubmit(res.submitResult, onRefresh);
 
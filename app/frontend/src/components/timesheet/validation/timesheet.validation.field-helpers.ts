@@ -7,7 +7,9 @@ import {
   doesProjectNeedTools,
   doesToolNeedChargeCode,
 } from "@sheetpilot/shared/business-config";
+import { entryKindNeedsProject, formatHours, MAX_ENTRY_HOURS } from "@sheetpilot/shared";
 import { isDateInAllowedRange } from "@/utils/smartDate";
+import { withClockSkewNote } from "@/utils/clock-skew";
 import {
   calculateDraftHoursForDate,
   calculateSubmittedHoursForDate,
@@ -18,8 +20,9 @@ type SubmittedEntry = { date: string; hours: number | null };
 function validateDateField(value: unknown): string | null {
   if (!value) return "Please enter a date";
   if (!isValidDate(String(value))) return "Date must be like 01/15/2024";
+  // The allowed range follows today's date, so a wrong clock is called out
   if (!isDateInAllowedRange(String(value)))
-    return "Date must be within allowed quarter range";
+    return withClockSkewNote("Date must be within allowed quarter range");
   return null;
 }
 
@@ -45,12 +48,12 @@ function validateTotalHoursForDate(
   // Total = draft (excluding current) + submitted + new hours for current row
   const totalHours = draftHoursExcludingCurrent + submittedHours + hoursValue;
 
-  if (totalHours <= 24.0) {
+  if (totalHours <= MAX_ENTRY_HOURS) {
     return null;
   }
 
   const draftTotal = draftHoursExcludingCurrent + hoursValue;
-  return `Total hours for ${date} exceeds 24 hours. Current total: ${totalHours.toFixed(2)} hours (${submittedHours.toFixed(2)} submitted + ${draftTotal.toFixed(2)} draft). Maximum allowed: 24.00 hours.`;
+  return `Total hours for ${date} exceeds 24 hours. Current total: ${formatHours(totalHours)} hours (${formatHours(submittedHours)} submitted + ${formatHours(draftTotal)} draft). Maximum allowed: ${formatHours(MAX_ENTRY_HOURS)} hours.`;
 }
 
 function validateHoursField(
@@ -89,7 +92,12 @@ function validateHoursField(
   return null;
 }
 
-function validateProjectField(value: unknown): string | null {
+function validateProjectField(
+  value: unknown,
+  rowData: TimesheetRow | undefined
+): string | null {
+  // PTO and holidays are mapped to a project (or left out) when submitting
+  if (!value && !entryKindNeedsProject(rowData?.kind)) return null;
   if (!value) return "Please pick a project";
   return null;
 }
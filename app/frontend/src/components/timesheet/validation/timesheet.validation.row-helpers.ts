@@ -7,6 +7,7 @@ import {
   doesProjectNeedTools,
   doesToolNeedChargeCode,
 } from "@sheetpilot/shared/business-config";
+import { entryKindNeedsProject } from "@sheetpilot/shared";
 
 function getDateError(row: TimesheetRow, rowNum: number): string | null {
   if (!row.date) {
@@ -29,7 +30,7 @@ function getHoursError(row: TimesheetRow, rowNum: number): string | null {
 }
 
 function getProjectError(row: TimesheetRow, rowNum: number): string | null {
-  if (!row.project) {
+  if (!row.project && entryKindNeedsProject(row.kind)) {
     return `Row ${rowNum}: Missing project`;
   }
   return null;
@@ -46,7 +46,7 @@ export function validateField(
     }
 
     case "project":
-      return validateProjectField(value);
+      return validateProjectField(value, rowData);
 
     case "tool": {
       return validateToolField(value, rowData);
@@ -10,6 +10,7 @@ import {
   type ReactNode,
   type SetStateAction,
 } from "react";
+import type { TimesheetRow } from "@sheetpilot/shared";
 import { useSession } from "./SessionContext";
 import { loadDraft } from "@/services/ipc/timesheet";
 import { getAllArchiveData } from "@/services/ipc/database";
@@ -21,16 +22,6 @@ import {
   logDebug,
 } from "@/services/ipc/logger";
 
-interface TimesheetRow {
-  id?: number;
-  date?: string;
-  hours?: number;
-  project?: string;
-  tool?: string | null;
-  chargeCode?: string | null;
-  taskDescription?: string;
-}
-
 interface TimesheetEntry {
   id: number;
   date: string;
@@ -41,6 +32,10 @@ interface TimesheetEntry {
   task_description: string;
   status?: string;
   submitted_at?: string;
+  created_at?: string | null;
+  updated_at?: string | null;
+  submitted_run_id?: number | null;
+  external_ref?: string | null;
 }
 
 interface Credential {
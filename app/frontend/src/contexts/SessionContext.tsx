@@ -8,6 +8,7 @@
  * - On mount: Attempts to restore session from localStorage
  * - On login: Stores session token and user info
  * - On logout: Clears session and notifies backend
+ * - On revocation: Clears session when the backend ends it (security policy)
  * 
  * Security features:
  * - Token validation on session restore
@@ -16,8 +17,14 @@
  */
 
 import { createContext, useContext, useState, useEffect, useCallback, useMemo, type ReactNode } from 'react';
-import { logout as logoutIpc, validateSession as validateSessionIpc } from '@/services/ipc/auth';
+import {
+  logout as logoutIpc,
+  onSessionRevoked,
+  removeSessionRevokedListener,
+  validateSession as validateSessionIpc
+} from '@/services/ipc/auth';
 import { logError, logInfo, logVerbose } from '@/services/ipc/logger';
+import { loadThemeFromSettings } from '@/utils/theme-manager';
 
 /**
  * Session context interface providing authentication state and actions
@@ -94,6 +101,7 @@ export function SessionProvider({ children }: SessionProviderProps) {
             setEmail(result.email);
             setIsAdmin(result.isAdmin || false);
             logInfo('Session restored', { email: result.email });
+            void loadThemeFromSettings();
           } else {
             // Session invalid, clear it
             localStorage.removeItem('sessionToken');
@@ -111,6 +119,23 @@ export function SessionProvider({ children }: SessionProviderProps) {
     void loadSession();
   }, []);
 
+  /**
+   * Drop local session state when the backend revokes the session
+   *
+   * WHY: The security policy can end every session when the workstation
+   * locks; the stored token is already invalid, so no logout call is needed.
+   */
+  useEffect(() => {
+    onSessionRevoked(({ reason }) => {
+      setToken(null);
+      setEmail(null);
+      setIsAdmin(false);
+      localStorage.removeItem('sessionToken');
+      logInfo('Session revoked by backend', { reason });
+    });
+    return () => removeSessionRevokedListener();
+  }, []);
+
   /**
    * Log in user and persist session
    * 
@@ -124,6 +149,8 @@ export function SessionProvider({ children }: SessionProviderProps) {
     setIsAdmin(newIsAdmin);
     localStorage.setItem('sessionToken', newToken);
     logInfo('User logged in', { email: newEmail, isAdmin: newIsAdmin });
+    // Theme is a per-user preference
+    void loadThemeFromSettings();
   }, []);
 
   /**
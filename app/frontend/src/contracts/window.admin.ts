@@ -21,6 +21,250 @@ declare global {
       rebuildDatabase: (
         token: string
       ) => Promise<{ success: boolean; error?: string }>;
+      /**
+       * Run a single SELECT on a read-only connection (not destructive)
+       *
+       * Rows are returned as arrays in `columns` order and capped at 1000.
+       */
+      runReadonlyQuery: (
+        token: string,
+        sql: string,
+        params?:
+          | Array<string | number | null>
+          | Record<string, string | number | null>
+      ) => Promise<{
+        success: boolean;
+        columns?: string[];
+        rows?: unknown[][];
+        rowCount?: number;
+        truncated?: boolean;
+        elapsedMs?: number;
+        error?: string;
+      }>;
+      /**
+       * Scan for rows that break timesheet invariants (e.g. Complete without
+       * submitted_at, stuck in_progress, expired sessions); with `repair`,
+       * fix the issues that have an unambiguous fix
+       */
+      checkDataIntegrity: (
+        token: string,
+        repair?: boolean
+      ) => Promise<{
+        success: boolean;
+        report?: DataIntegrityReport;
+        error?: string;
+      }>;
+      /**
+       * Rename a project or charge code on submitted entries after payroll
+       * renames it; every recode is recorded in the recode history. With
+       * `dryRun`, only counts the matching entries.
+       */
+      bulkRecode: (
+        token: string,
+        request: BulkRecodeRequest
+      ) => Promise<{
+        success: boolean;
+        count?: number;
+        entryIds?: number[];
+        /** Recode history row; null for dry runs or when nothing matched */
+        historyId?: number | null;
+        error?: string;
+      }>;
+      /**
+       * Remove a submitted entry payroll has already corrected server-side.
+       * The row is kept in deleted entries with the reason, which is also
+       * written to the audit log.
+       */
+      deleteCompletedEntry: (
+        token: string,
+        id: number,
+        reason: string
+      ) => Promise<{
+        success: boolean;
+        /** The row as it was before deletion */
+        entry?: PersistedEntry;
+        /** Deleted entries row holding the copy */
+        deletionId?: number;
+        error?: string;
+        /** SUBMISSION_LOCKED while a submission is running */
+        code?: string;
+      }>;
+      /**
+       * Delete sessions whose expiry has passed, and sessions without an
+       * expiry older than 30 days (left by app runs that never logged out)
+       */
+      purgeExpiredSessions: (
+        token: string
+      ) => Promise<{ success: boolean; purged?: number; error?: string }>;
+      /** Session counts per user and the oldest active session (not destructive) */
+      getSessionStats: (
+        token: string
+      ) => Promise<{ success: boolean; stats?: SessionStats; error?: string }>;
+      /** Sign a user out of every session on this machine */
+      revokeUserSessions: (
+        token: string,
+        email: string
+      ) => Promise<{ success: boolean; revoked?: number; error?: string }>;
+      /**
+       * Load a form in the bot browser and list its labeled inputs with
+       * suggested locators, for building its field mapping (not destructive)
+       */
+      inspectForm: (
+        token: string,
+        request: { baseUrl: string; formId: string }
+      ) => Promise<{
+        success: boolean;
+        inspection?: FormInspection;
+        error?: string;
+      }>;
+      /**
+       * Statically check selectors, login steps, field order and quarter
+       * forms; `checkReachability` also requests each form URL (not destructive)
+       */
+      lintAutomationConfig: (
+        token: string,
+        checkReachability?: boolean
+      ) => Promise<{
+        success: boolean;
+        report?: AutomationConfigLintReport;
+        error?: string;
+      }>;
+      /** Options the bot remembered for ambiguous dropdown matches (not destructive) */
+      listDropdownResolutions: (
+        token: string,
+        fieldKey?: string
+      ) => Promise<{
+        success: boolean;
+        resolutions?: DropdownResolution[];
+        error?: string;
+      }>;
+      /** Forget a remembered option so the bot takes the first match again */
+      deleteDropdownResolution: (
+        token: string,
+        id: number
+      ) => Promise<{ success: boolean; error?: string }>;
+    };
+  }
+
+  interface BulkRecodeRequest {
+    field: 'project' | 'chargeCode';
+    fromValue: string;
+    toValue: string;
+    filter?: {
+      startDate?: string;
+      endDate?: string;
+      /** Only entries booked to this project */
+      project?: string;
     };
+    dryRun?: boolean;
+  }
+
+  interface DataIntegrityIssue {
+    kind:
+      | 'complete-missing-submitted-at'
+      | 'complete-missing-fields'
+      | 'unknown-status'
+      | 'invalid-hours'
+      | 'stale-in-progress'
+      | 'dangling-amendment'
+      | 'dangling-submitted-run'
+      | 'expired-session';
+    description: string;
+    count: number;
+    /** First 50 affected entry IDs; empty for sessions */
+    ids: number[];
+    repairable: boolean;
+    repaired: number;
+  }
+
+  interface InspectedFormInput {
+    tag: string;
+    type: string | null;
+    role: string | null;
+    id: string | null;
+    name: string | null;
+    placeholder: string | null;
+    ariaLabel: string | null;
+    /** Text of the elements referenced by aria-labelledby */
+    ariaLabelledByText: string | null;
+    labelText: string | null;
+    ariaRequired: boolean;
+    required: boolean;
+    /** Best human-readable label */
+    label: string;
+    /** Null when nothing stable identifies the input */
+    suggestedLocator: string | null;
+    /** Field key matched by label, e.g. "hours" */
+    fieldKey: string | null;
+  }
+
+  interface FormInspection {
+    formUrl: string;
+    formId: string;
+    title: string;
+    inputs: InspectedFormInput[];
+    /** Locators by field key for the quarter's `fieldOverrides` */
+    suggestedFieldOverrides: Record<string, { optional?: boolean; locator?: string }>;
+    /** Required field keys no input was matched to */
+    missingFields: string[];
+  }
+
+  interface AutomationConfigFinding {
+    /** Errors break runs; warnings are likely mistakes */
+    severity: 'error' | 'warning';
+    area: 'selectors' | 'login' | 'fields' | 'quarters';
+    /** e.g. "field:hours", "login:AAD Email", "quarter:Q1-2026" */
+    target: string;
+    message: string;
+  }
+
+  interface AutomationConfigLintReport {
+    /** True when there are no errors */
+    ok: boolean;
+    errors: number;
+    warnings: number;
+    /** Errors first */
+    findings: AutomationConfigFinding[];
+  }
+
+  interface DropdownResolution {
+    id: number;
+    /** Field key, e.g. "project_code" */
+    fieldKey: string;
+    typedValue: string;
+    /** Option selected when the value matched several */
+    optionText: string;
+    candidates: string[];
+    /** Submitted rows that used this option since it was first confirmed */
+    confirmedCount: number;
+    firstConfirmedAt: string;
+    lastConfirmedAt: string;
+  }
+
+  interface UserSessionStats {
+    email: string;
+    sessions: number;
+    active: number;
+    isAdmin: boolean;
+    /** created_at of the user's oldest active session */
+    oldestActiveCreatedAt: string | null;
+  }
+
+  interface SessionStats {
+    total: number;
+    active: number;
+    /** Sessions purgeExpiredSessions would delete */
+    expired: number;
+    oldestActiveCreatedAt: string | null;
+    /** Users with the most sessions first */
+    users: UserSessionStats[];
+  }
+
+  interface DataIntegrityReport {
+    checkedAt: string;
+    repairRequested: boolean;
+    issues: DataIntegrityIssue[];
+    totalIssues: number;
+    totalRepaired: number;
   }
 }
@@ -12,6 +12,56 @@ declare global {
     api?: {
       /** Test IPC communication */
       ping: (msg: string) => Promise<string>;
+      /** Version, database connection and platform capabilities (file dialogs, Downloads folder) */
+      getHealth: () => Promise<{
+        success: boolean;
+        health?: AppHealth;
+        error?: string;
+      }>;
+      /** dev/test/prod and whether submissions may use the mock website */
+      getEnvironment?: () => Promise<{
+        success: boolean;
+        environment?: AppEnvironmentInfo;
+        error?: string;
+      }>;
+      /** Fired at startup when the system clock is off by more than the threshold */
+      onClockSkew?: (callback: (status: ClockSkewStatus) => void) => void;
+      removeClockSkewListener?: () => void;
     };
   }
+
+  interface AppHealth {
+    version: string;
+    database: { connected: boolean };
+    capabilities: {
+      fileDialogs: boolean;
+      downloadsWritable: boolean;
+      /** 'string' when exports cannot be saved as files and are returned as text */
+      exportMode: 'download' | 'string';
+      checkedAt: string;
+    };
+    /** Startup clock check; null until it has run */
+    clock: ClockSkewStatus | null;
+  }
+
+  interface AppEnvironmentInfo {
+    /** Packaged builds are always prod */
+    environment: 'dev' | 'test' | 'prod';
+    source: 'packaged' | 'profile' | 'node-env' | 'default';
+    mockRoutingAllowed: boolean;
+    /** e.g. "DEV"; null in prod */
+    label: string | null;
+  }
+
+  interface ClockSkewStatus {
+    /** Local time minus reference time; positive when the clock is ahead */
+    skewMs: number | null;
+    source: 'file-times' | 'http-date' | null;
+    /** True when the skew is past the threshold */
+    skewed: boolean;
+    thresholdMs: number;
+    checkedAt: string;
+    /** e.g. "Your computer clock is about 3 weeks behind", when skewed */
+    message: string | null;
+  }
 }
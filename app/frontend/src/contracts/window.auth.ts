@@ -34,6 +34,9 @@ declare global {
       getCurrentSession: (
         token: string
       ) => Promise<{ email: string; token: string; isAdmin: boolean } | null>;
+      /** Fires when the backend ends the session (e.g. workstation locked) */
+      onSessionRevoked: (callback: (payload: { reason: string }) => void) => void;
+      removeSessionRevokedListener: () => void;
     };
   }
 }
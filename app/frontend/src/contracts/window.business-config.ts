@@ -67,6 +67,8 @@ declare global {
           requires_tools?: boolean;
           display_order?: number;
           is_active?: boolean;
+          retired_on?: string | null;
+          successor_project?: string | null;
         }
       ) => Promise<{
         success: boolean;
@@ -104,6 +106,8 @@ declare global {
           requires_tools?: boolean;
           display_order?: number;
           is_active?: boolean;
+          retired_on?: string | null;
+          successor_project?: string | null;
         }
       ) => Promise<{
         success: boolean;
@@ -76,6 +76,8 @@ declare global {
           requires_tools?: boolean;
           display_order?: number;
           is_active?: boolean;
+          retired_on?: string | null;
+          successor_project?: string | null;
         }
       ) => Promise<{
         success: boolean;
@@ -116,6 +118,8 @@ declare global {
           requires_tools?: boolean;
           display_order?: number;
           is_active?: boolean;
+          retired_on?: string | null;
+          successor_project?: string | null;
         }
       ) => Promise<{
         success: boolean;
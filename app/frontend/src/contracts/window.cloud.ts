@@ -0,0 +1,77 @@
+/**
+ * @fileoverview Window API - OneDrive/SharePoint archive uploads
+ */
+
+export {};
+
+declare global {
+  interface Window {
+    /**
+     * OneDrive/SharePoint uploads via Microsoft Graph
+     *
+     * Sign-in uses the device-code flow; the refresh token is stored
+     * encrypted in the main process and never exposed to the renderer.
+     */
+    cloud?: {
+      /** Connection state and any sign-in in progress */
+      getStatus: () => Promise<{
+        success: boolean;
+        status?: CloudConnectionStatus;
+        error?: string;
+      }>;
+      /** Start device-code sign-in; show the returned code to the user */
+      connect: (token: string, config: {
+        /** Azure app registration (client) ID */
+        clientId: string;
+        /** Tenant ID, domain, or "organizations" */
+        tenantId: string;
+        /** Drive folder, e.g. "Timesheets/Archive" */
+        folderPath: string;
+      }) => Promise<{
+        success: boolean;
+        prompt?: CloudDeviceCodePrompt;
+        error?: string;
+      }>;
+      /** Forget the stored token */
+      disconnect: (token: string) => Promise<{ success: boolean; error?: string }>;
+      /** Export submitted entries and queue an upload job */
+      uploadTimesheetExport: (
+        token: string,
+        format?: "csv" | "xlsx",
+        /** Export template to use (default columns when omitted) */
+        templateName?: string
+      ) => Promise<CloudUploadResponse & { entryCount?: number }>;
+      /** Queue an upload of the most recent log file for support */
+      uploadLatestLog: (token: string) => Promise<CloudUploadResponse>;
+    };
+  }
+
+  interface CloudDeviceCodePrompt {
+    userCode: string;
+    verificationUri: string;
+    message: string;
+    expiresAt: string;
+  }
+
+  interface CloudConnectionStatus {
+    connected: boolean;
+    account: string | null;
+    folderPath: string | null;
+    pendingLogin: CloudDeviceCodePrompt | null;
+    lastError: string | null;
+  }
+
+  interface CloudUploadResponse {
+    success: boolean;
+    /** Background job performing the upload; see window.jobs */
+    jobId?: string;
+    error?: string;
+  }
+
+  /** Result of a finished "cloud-upload" job */
+  interface CloudUploadResult {
+    remotePath: string;
+    webUrl: string | null;
+    size: number;
+  }
+}
@@ -31,6 +31,25 @@ declare global {
         }>;
         error?: string;
       }>;
+      /** Which credentials are in the OS keychain and which still live only in SQLite */
+      migrationAudit: () => Promise<{
+        success: boolean;
+        keychainAvailable?: boolean;
+        entries?: Array<{
+          service: string;
+          email: string;
+          status: 'migrated' | 'sqlite-only' | 'keychain-only' | 'mismatch';
+        }>;
+        sqliteOnly?: string[];
+        error?: string;
+      }>;
+      /** Show a stored password; needs a valid session and may require Windows Hello / Touch ID first */
+      reveal: (token: string, service: string) => Promise<{
+        success: boolean;
+        email?: string;
+        password?: string;
+        error?: string;
+      }>;
       /** Delete credentials for a service */
       delete: (
         service: string
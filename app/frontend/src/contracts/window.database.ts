@@ -26,6 +26,9 @@ declare global {
           task_description: string;
           status?: string;
           submitted_at?: string;
+          created_at?: string | null;
+          updated_at?: string | null;
+          submitted_run_id?: number | null;
         }>;
         error?: string;
       }>;
@@ -42,6 +45,9 @@ declare global {
           task_description: string;
           status?: string;
           submitted_at?: string;
+          created_at?: string | null;
+          updated_at?: string | null;
+          submitted_run_id?: number | null;
         }>;
         credentials?: Array<{
           id: number;
@@ -50,8 +56,134 @@ declare global {
           created_at: string;
           updated_at: string;
         }>;
+        /** Set outside prod so non-production data can be marked */
+        environment?: 'dev' | 'test';
+        error?: string;
+      }>;
+      /**
+       * Get one page of submitted entries; pass `nextCursor` back for the next
+       * page. Large pages come back in `gzipped` when `gzip` is set.
+       */
+      getArchivePage: (
+        token: string,
+        request?: ArchivePageRequest
+      ) => Promise<ArchivePageResponse>;
+      /** Describe the live database: tables, columns, row counts, schema version */
+      getSchemaInfo: (token: string) => Promise<{
+        success: boolean;
+        schemaInfo?: DatabaseSchemaInfo;
+        error?: string;
+      }>;
+      /** Statement timing, slow statements and lock timeouts since startup */
+      getConnectionStats: (token: string) => Promise<{
+        success: boolean;
+        stats?: DatabaseConnectionStats;
+        error?: string;
+      }>;
+      /** Whether this build may write to the database (no session needed) */
+      getCompatibilityInfo: () => Promise<{
+        success: boolean;
+        compatibility?: DatabaseCompatibilityInfo;
         error?: string;
       }>;
     };
   }
+
+  interface DatabaseCompatibilityInfo {
+    dbPath: string;
+    /** Version recorded in the database */
+    schemaVersion: number;
+    /** Newest version this app build understands */
+    appSchemaVersion: number;
+    /** False when a newer app version migrated the database; writes are refused */
+    compatible: boolean;
+    /** Why writes are refused (only when not compatible) */
+    message?: string;
+  }
+
+  interface DatabaseConnectionStats {
+    /** How long a statement waits for another process's lock */
+    busyTimeoutMs: number;
+    /** Statements at least this slow are counted and logged */
+    slowStatementMs: number;
+    executions: number;
+    totalMs: number;
+    maxMs: number;
+    slowStatements: number;
+    /** Statements that gave up waiting for a lock */
+    busyTimeouts: number;
+    /** Newest first */
+    recentSlow: Array<{ sql: string; durationMs: number; at: string }>;
+  }
+
+  interface DatabaseSchemaInfo {
+    dbPath: string;
+    /** Version recorded in the database */
+    schemaVersion: number;
+    /** Version this app build migrates to */
+    expectedSchemaVersion: number;
+    /** Database was written by a newer app version */
+    isNewerThanApp: boolean;
+    sqliteVersion: string;
+    journalMode: string;
+    tables: Array<{
+      name: string;
+      rowCount: number;
+      columns: Array<{
+        name: string;
+        type: string;
+        notNull: boolean;
+        defaultValue: string | null;
+        primaryKey: boolean;
+      }>;
+      indexes: string[];
+    }>;
+  }
+
+  /** Columns an archive page can return */
+  type ArchivePageColumn =
+    | 'id'
+    | 'date'
+    | 'hours'
+    | 'project'
+    | 'tool'
+    | 'detail_charge_code'
+    | 'task_description'
+    | 'status'
+    | 'submitted_at'
+    | 'submission_key'
+    | 'amends_id'
+    | 'created_at'
+    | 'updated_at'
+    | 'submitted_run_id'
+    | 'submission_captures'
+    | 'time_zone'
+    | 'external_ref'
+    | 'category'
+    | 'entry_kind';
+
+  interface ArchivePageRequest {
+    /** `nextCursor` from the previous page; omit for the first page */
+    cursor?: string;
+    /** Entries per page (default 500, max 5000) */
+    limit?: number;
+    /** Columns to return; id and date are always included */
+    columns?: ArchivePageColumn[];
+    /** Gzip the entries when the page is large */
+    gzip?: boolean;
+  }
+
+  interface ArchivePageResponse {
+    success: boolean;
+    entries?: Array<Partial<Record<ArchivePageColumn, unknown>> & { id: number; date: string }>;
+    /** Gzipped JSON of the entries array, sent instead of `entries` */
+    gzipped?: Uint8Array;
+    count?: number;
+    /** Null after the last page */
+    nextCursor?: string | null;
+    /** Total submitted entries; first page only */
+    totalCount?: number;
+    environment?: 'dev' | 'test';
+    error?: string;
+  }
 }
@@ -0,0 +1,55 @@
+/**
+ * @fileoverview Window API - Background jobs
+ */
+
+export {};
+
+declare global {
+  interface Window {
+    /**
+     * Background jobs (exports, uploads, submissions)
+     *
+     * Long operations return a job ID; poll it with getStatus or subscribe
+     * to progress events. Finished jobs are kept in run history, so status
+     * stays available after a restart.
+     */
+    jobs?: {
+      /** Current state of a job */
+      getStatus: (jobId: string) => Promise<{
+        success: boolean;
+        job?: JobSnapshot;
+        error?: string;
+      }>;
+      /** Queued, running and recently finished jobs, newest first */
+      list: () => Promise<{
+        success: boolean;
+        jobs: JobSnapshot[];
+        error?: string;
+      }>;
+      /** Cancel a queued or running job */
+      cancel: (jobId: string) => Promise<{ success: boolean; error?: string }>;
+      /** Called on every job state change and (throttled) progress update */
+      onJobProgress: (callback: (job: JobSnapshot) => void) => void;
+      removeJobProgressListener: () => void;
+    };
+  }
+
+  interface JobSnapshot {
+    id: string;
+    /** e.g. "scheduled-export", "cloud-upload", "timesheet-submission" */
+    type: string;
+    status: "queued" | "running" | "succeeded" | "failed" | "cancelled";
+    trigger: "manual" | "scheduled";
+    /** 0-100 */
+    percent: number;
+    message: string | null;
+    /** Task-specific result once succeeded */
+    result: unknown;
+    error: string | null;
+    /** Run history row, once started */
+    runId: number | null;
+    createdAt: string;
+    startedAt: string | null;
+    finishedAt: string | null;
+  }
+}
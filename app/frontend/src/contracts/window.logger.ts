@@ -2,7 +2,18 @@
  * @fileoverview Window API - Structured logging
  */
 
-export {};
+/**
+ * Structured fields attached to a frontend log entry.
+ * Logged as top-level metadata so backend filtering by category also covers renderer events.
+ */
+export interface FrontendLogFields {
+  /** Log category used for filtering (e.g. "timesheet", "user-action") */
+  category?: string;
+  /** Component that produced the entry */
+  component?: string;
+  /** Correlation ID for the user action that triggered the entry */
+  userActionId?: string;
+}
 
 declare global {
   interface Window {
@@ -13,17 +24,17 @@ declare global {
      */
     logger?: {
       /** Log error level message */
-      error: (message: string, data?: unknown) => void;
+      error: (message: string, data?: unknown, fields?: FrontendLogFields) => void;
       /** Log warning level message */
-      warn: (message: string, data?: unknown) => void;
+      warn: (message: string, data?: unknown, fields?: FrontendLogFields) => void;
       /** Log info level message */
-      info: (message: string, data?: unknown) => void;
+      info: (message: string, data?: unknown, fields?: FrontendLogFields) => void;
       /** Log verbose level message */
-      verbose: (message: string, data?: unknown) => void;
+      verbose: (message: string, data?: unknown, fields?: FrontendLogFields) => void;
       /** Log debug level message */
-      debug: (message: string, data?: unknown) => void;
+      debug: (message: string, data?: unknown, fields?: FrontendLogFields) => void;
       /** Log user action for analytics */
-      userAction: (action: string, data?: unknown) => void;
+      userAction: (action: string, data?: unknown, fields?: FrontendLogFields) => void;
     };
     /**
      * Internal counter for skipping afterChange events.
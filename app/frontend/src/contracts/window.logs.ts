@@ -2,7 +2,52 @@
  * @fileoverview Window API - Log file operations
  */
 
-export {};
+/** Filters for querying log lines by date range, level and run */
+export interface LogQueryFilter {
+  /** Inclusive lower bound (ISO 8601) */
+  from?: string;
+  /** Inclusive upper bound (ISO 8601) */
+  to?: string;
+  levels?: Array<"error" | "warn" | "info" | "verbose" | "debug" | "silly">;
+  runId?: string;
+  /** Case-insensitive substring match on the message */
+  search?: string;
+  limit?: number;
+}
+
+/** A single parsed log line returned by a log query */
+export interface LogQueryLine {
+  fileName: string;
+  lineNumber: number;
+  timestamp: string;
+  level: string;
+  runId: string | null;
+  component: string | null;
+  message: string;
+}
+
+/** Summary of a crash report saved after a main-process or renderer crash */
+export interface CrashReportSummary {
+  id: string;
+  kind: "uncaught-exception" | "unhandled-rejection" | "renderer-gone" | "child-process-gone";
+  createdAt: string;
+  message: string;
+  appVersion: string;
+}
+
+/** Startup phase timings, in milliseconds after process start */
+export interface StartupTimings {
+  phases: Array<{
+    name: string;
+    startMs: number;
+    durationMs: number;
+    /** Ran in the background after the window was shown */
+    deferred: boolean;
+    status: "ok" | "failed";
+  }>;
+  windowShownMs: number | null;
+  deferredCompletedMs: number | null;
+}
 
 declare global {
   interface Window {
@@ -31,6 +76,37 @@ declare global {
         mimeType?: string;
         error?: string;
       }>;
+      /** Query log lines, served from the log index when it is enabled */
+      query: (
+        token: string,
+        filter?: LogQueryFilter
+      ) => Promise<{
+        success: boolean;
+        source?: "index" | "files";
+        lines?: LogQueryLine[];
+        error?: string;
+      }>;
+      /** List saved crash reports */
+      listCrashReports: (token: string) => Promise<{
+        success: boolean;
+        reports?: CrashReportSummary[];
+        error?: string;
+      }>;
+      /** Export all crash reports (with minidump locations) for download */
+      exportCrashReports: (token: string) => Promise<{
+        success: boolean;
+        content?: string;
+        filename?: string;
+        mimeType?: string;
+        reportCount?: number;
+        error?: string;
+      }>;
+      /** Get startup phase timings for diagnostics */
+      getStartupTimings: () => Promise<{
+        success: boolean;
+        timings?: StartupTimings;
+        error?: string;
+      }>;
     };
   }
 }
@@ -0,0 +1,47 @@
+/**
+ * @fileoverview Window API - Per-user preferences
+ */
+
+export {};
+
+declare global {
+  interface Window {
+    /**
+     * Per-user preferences
+     *
+     * Stored per session email, separately from machine settings, so users
+     * sharing a workstation keep their own defaults. Requires a session token.
+     */
+    preferences?: {
+      /** Get the logged-in user's preferences */
+      get: (token: string) => Promise<{
+        success: boolean;
+        preferences?: UserPreferences;
+        error?: string;
+      }>;
+      /** Merge updates into the user's preferences; null resets a preference */
+      set: (
+        token: string,
+        updates: UserPreferencesUpdate
+      ) => Promise<{
+        success: boolean;
+        preferences?: UserPreferences;
+        error?: string;
+      }>;
+    };
+  }
+
+  interface UserPreferences {
+    themeMode?: "auto" | "light" | "dark";
+    /** First day of the week in weekly views */
+    weekStart?: "sunday" | "monday";
+    /** Hours are rounded to this step when entered */
+    hoursRounding?: 0.25 | 0.5 | 1;
+    /** Project prefilled on new rows */
+    defaultProject?: string;
+  }
+
+  type UserPreferencesUpdate = {
+    [K in keyof UserPreferences]?: UserPreferences[K] | null;
+  };
+}
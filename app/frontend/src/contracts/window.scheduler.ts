@@ -0,0 +1,205 @@
+/**
+ * @fileoverview Window API - Scheduled exports and run history
+ */
+
+export {};
+
+declare global {
+  interface Window {
+    /**
+     * Scheduled exports and run history
+     *
+     * Schedules are persisted in the database and executed by the main
+     * process scheduler, even when no window is focused.
+     */
+    scheduler?: {
+      /** List configured export schedules */
+      listExportSchedules: () => Promise<{
+        success: boolean;
+        schedules?: ExportSchedule[];
+        error?: string;
+      }>;
+      /** Create (no id) or update (with id) an export schedule */
+      saveExportSchedule: (schedule: ExportScheduleInput) => Promise<{
+        success: boolean;
+        id?: number;
+        nextRunAt?: string;
+        error?: string;
+      }>;
+      /** Delete an export schedule */
+      deleteExportSchedule: (
+        id: number
+      ) => Promise<{ success: boolean; error?: string }>;
+      /** Queue an export schedule to run now; follow it via window.jobs */
+      runExportScheduleNow: (id: number) => Promise<{
+        success: boolean;
+        jobId?: string;
+        error?: string;
+      }>;
+      /** Recent background runs, newest first */
+      getRunHistory: (query?: { jobType?: string; limit?: number }) => Promise<{
+        success: boolean;
+        runs: RunHistoryRecord[];
+        error?: string;
+      }>;
+      /** Full record of one submission run (inputs, outcomes, timings, screenshots) */
+      getRunArtifact: (runId: number) => Promise<{
+        success: boolean;
+        artifact?: RunArtifact;
+        error?: string;
+      }>;
+      /** ZIP of the run artifact, rows, settings and environment, with descriptions hashed and secrets removed */
+      exportReproBundle: (runId: number) => Promise<{
+        success: boolean;
+        content?: Uint8Array;
+        filename?: string;
+        mimeType?: string;
+        entryCount?: number;
+        error?: string;
+      }>;
+      /** Whether network backups are set up, and the manifest of backups on the share */
+      getNetworkBackupStatus: () => Promise<{
+        success: boolean;
+        status?: NetworkBackupStatus;
+        error?: string;
+      }>;
+      /** Queue a network backup now; follow it via window.jobs */
+      runNetworkBackupNow: () => Promise<{
+        success: boolean;
+        jobId?: string;
+        error?: string;
+      }>;
+    };
+  }
+
+  /** Backups in one machine/user folder on the network share */
+  interface BackupManifest {
+    version: 1;
+    host: string;
+    user: string;
+    /** How the backups are encrypted, for whoever restores them */
+    encryption: string;
+    /** Newest last */
+    backups: Array<{
+      file: string;
+      createdAt: string;
+      sizeBytes: number;
+      /** SHA-256 of the encrypted file */
+      sha256: string;
+      schemaVersion: number;
+      entryCount: number;
+      /** When the copy on the share passed integrity_check */
+      verifiedAt: string;
+    }>;
+  }
+
+  interface NetworkBackupStatus {
+    /** network_backup is enabled with a folder set */
+    configured: boolean;
+    /** SHEETPILOT_BACKUP_KEY is set */
+    keyAvailable: boolean;
+    folder: string | null;
+    manifest: BackupManifest | null;
+    /** Why the manifest could not be read (e.g. the share is offline) */
+    manifestError?: string;
+  }
+
+  interface ExportScheduleInput {
+    id?: number;
+    name: string;
+    format: "csv" | "xlsx";
+    /** Absolute folder path */
+    targetDir: string;
+    /** Day of month to run on (1-28) */
+    dayOfMonth: number;
+    isActive: boolean;
+    /** Also upload each export to the connected OneDrive/SharePoint folder */
+    uploadToCloud?: boolean;
+  }
+
+  interface ExportSchedule extends Required<ExportScheduleInput> {
+    lastRunAt: string | null;
+    nextRunAt: string | null;
+  }
+
+  interface RunHistoryRecord {
+    id: number;
+    jobType: string;
+    trigger: "manual" | "scheduled";
+    status: "running" | "success" | "failure" | "cancelled";
+    /** Background job that produced this run, if any */
+    jobId: string | null;
+    detail: Record<string, unknown> | null;
+    error: string | null;
+    startedAt: string;
+    finishedAt: string | null;
+  }
+
+  interface RunArtifactRow {
+    rowIndex: number;
+    entryId: number | null;
+    outcome: "submitted" | "failed" | "skipped";
+    error: string | null;
+    fillMs: number | null;
+    submitMs: number | null;
+    submitAttempts: number;
+    throttleRetries: number;
+    /** Locator used per field and where it came from */
+    fields: Record<
+      string,
+      { locator: string; source: "default" | "quarter-override" | "project" | "hotfix" }
+    >;
+    screenshot: string | null;
+  }
+
+  interface RunArtifactForm {
+    formId: string;
+    formUrl: string;
+    quarterId: string | null;
+    fillProfile: string;
+    startedAt: string;
+    finishedAt: string | null;
+    durationMs: number | null;
+    loginMs: number | null;
+    titleCheck: "passed" | "failed" | "skipped";
+    rows: RunArtifactRow[];
+    error: string | null;
+  }
+
+  interface RunArtifact {
+    version: number;
+    runId: number;
+    inputs: {
+      email: string | null;
+      useMockWebsite: boolean;
+      fillProfile: string;
+      rateLimit: Record<string, number>;
+      entries: Array<{
+        id: number;
+        date: string;
+        hours: number | null;
+        project: string;
+        tool: string | null;
+        chargeCode: string | null;
+        taskDescription: string;
+        submissionKey: string | null;
+        amendsId: number | null;
+      }>;
+    };
+    outcome: {
+      ok: boolean;
+      error: string | null;
+      submittedIds: number[];
+      removedIds: number[];
+      totalProcessed: number;
+    };
+    timing: { startedAt: string; finishedAt: string; durationMs: number };
+    forms: RunArtifactForm[];
+    screenshots: Array<{
+      entryId: number | null;
+      formId: string;
+      rowIndex: number;
+      path: string;
+    }>;
+  }
+}
@@ -21,12 +21,21 @@ declare global {
         key: string,
         value: unknown
       ) => Promise<{ success: boolean; error?: string }>;
+      /** Set a protected setting (e.g. security_policy); requires an admin session */
+      setProtected: (
+        token: string,
+        key: string,
+        value: unknown
+      ) => Promise<{ success: boolean; error?: string }>;
       /** Get all settings */
       getAll: () => Promise<{
         success: boolean;
         settings?: Record<string, unknown>;
         error?: string;
       }>;
+      /** Called with the changed keys after settings are saved (replaces any previous listener) */
+      onChanged: (callback: (payload: { keys: string[] }) => void) => void;
+      removeChangedListener: () => void;
     };
   }
 }
@@ -16,13 +16,10 @@ declare global {
         token: string,
         useMockWebsite?: boolean
       ) => Promise<{
-        submitResult?: {
-          ok: boolean;
-          successCount: number;
-          removedCount: number;
-          totalProcessed: number;
-        };
-        dbPath?: string;
+        /** Background job running the submission; its result is a SubmitJobResult */
+        jobId?: string;
+        /** Set outside prod so non-production runs can be marked */
+        environment?: 'dev' | 'test';
         error?: string;
       }>;
       cancel: () => Promise<{
@@ -30,11 +27,49 @@ declare global {
         message?: string;
         error?: string;
       }>;
+      /** Check pending entries for closed or closing quarter forms and rule violations before submitting */
+      preflightSubmission: () => Promise<{
+        success: boolean;
+        pendingCount?: number;
+        warnings?: BackfillWarning[];
+        /** Target forms in submission order; one submit runs them all */
+        plan?: SubmissionPlan;
+        /** Charge code quotas the pending entries would exceed (never blocking) */
+        quotaWarnings?: QuotaWarning[];
+        /** Validation rule violations across pending entries */
+        ruleViolations?: ValidationRuleViolation[];
+        /** Entries booked to a project on or after its retirement date (blocking) */
+        retiredProjects?: RetiredProjectIssue[];
+        /** Signed selector hotfix the next run will apply, if one is published */
+        selectorHotfix?: { id: string; fields: string[]; submitButtonSelectors: number } | null;
+        /** Why a published selector hotfix will not be applied (never blocking) */
+        selectorHotfixError?: string | null;
+        /** True if any violation is a blocking rule or a project is retired */
+        blocked?: boolean;
+        error?: string;
+      }>;
+      /** Estimate bot runs, form loads and duration for submitting the pending entries now */
+      planSubmission: () => Promise<{
+        success: boolean;
+        plan?: SubmissionPlan;
+        estimate?: SubmissionEstimate;
+        quotaWarnings?: QuotaWarning[];
+        error?: string;
+      }>;
       devSimulateSuccess: () => Promise<{
         success: boolean;
         count?: number;
         error?: string;
       }>;
+      /** Dev only: time the fill pipeline on the mock form per speed profile; result lands on the job */
+      devBenchmarkFill: (request: {
+        iterations: number;
+        profiles?: string[];
+      }) => Promise<{
+        success: boolean;
+        jobId?: string;
+        error?: string;
+      }>;
       saveDraft: (row: {
         id?: number;
         date?: string;
@@ -43,20 +78,40 @@ declare global {
         tool?: string | null;
         chargeCode?: string | null;
         taskDescription?: string;
+        timeZone?: string | null;
+        externalRef?: string | null;
+        /** Name of a category from listEntryCategories; null clears it */
+        category?: string | null;
+        /** PTO and holidays need no project; null means work */
+        kind?: EntryKind | null;
       }) => Promise<{
         success: boolean;
         changes?: number;
         id?: number;
-        entry?: {
-          id: number;
-          date: string;
-          hours: number;
-          project: string;
-          tool?: string | null;
-          chargeCode?: string | null;
-          taskDescription: string;
-        };
+        entry?: PersistedEntry;
+        /** Set when the entry's quarter form is closed or about to close */
+        warning?: BackfillWarning;
+        /** Validation rules the saved entry breaks (enforced at submission) */
+        ruleViolations?: ValidationRuleViolation[];
         error?: string;
+        /** SUBMISSION_LOCKED while a submission is running (see getAppLockState) */
+        code?: string;
+      }>;
+      /**
+       * Queue rows with unsaved edits; the backend coalesces them per rowKey
+       * and saves them on a timer, reporting through onDraftSaved
+       */
+      markDirty: (rows: DirtyDraftRow[]) => Promise<{
+        success: boolean;
+        error?: string;
+        code?: string;
+      }>;
+      /** Save all queued rows now */
+      flushDirty: () => Promise<{
+        success: boolean;
+        results?: DraftSaveResult[];
+        error?: string;
+        code?: string;
       }>;
       loadDraft: () => Promise<{
         success: boolean;
@@ -68,6 +123,16 @@ declare global {
           tool?: string | null;
           chargeCode?: string | null;
           taskDescription?: string;
+          /** Set on amendment drafts: the submitted entry being corrected */
+          amendsId?: number;
+          /** Why Smartsheet rejected the entry's last submission */
+          failureReason?: string;
+          /** Zone the work was done in, if away from home */
+          timeZone?: string;
+          externalRef?: string;
+          category?: string;
+          /** Left out for work */
+          kind?: EntryKind;
         }>;
         error?: string;
       }>;
@@ -84,18 +149,283 @@ declare global {
         };
         error?: string;
       }>;
-      deleteDraft: (
-        id: number
-      ) => Promise<{ success: boolean; error?: string }>;
+      /** Delete a draft; submitted entries are locked and cannot be deleted */
+      deleteDraft: (id: number) => Promise<{
+        success: boolean;
+        changes?: number;
+        id?: number;
+        /** The row as it was before deletion */
+        entry?: PersistedEntry;
+        error?: string;
+        code?: string;
+      }>;
+      /**
+       * Move drafts booked to a retired project (on or after its retirement
+       * date) to the project that replaces it
+       */
+      reassignRetiredProject: (project: string) => Promise<{
+        success: boolean;
+        successor?: string;
+        /** Drafts that were moved */
+        ids?: number[];
+        error?: string;
+        code?: string;
+      }>;
+      /**
+       * Clone a submitted entry into a new draft that amends it
+       *
+       * Returns the existing open amendment if there already is one.
+       */
+      amendEntry: (id: number) => Promise<{
+        success: boolean;
+        id?: number;
+        entry?: {
+          id: number;
+          date: string;
+          hours?: number;
+          project: string;
+          tool?: string | null;
+          chargeCode?: string | null;
+          taskDescription: string;
+          /** Submitted entry this draft amends */
+          amendsId?: number;
+        };
+        error?: string;
+        code?: string;
+      }>;
       resetInProgress: () => Promise<{
         success: boolean;
         count?: number;
         error?: string;
       }>;
-      exportToCSV: () => Promise<{
+      /** Export submitted entries, using an export template's columns when named */
+      exportToCSV: (templateName?: string) => Promise<{
+        success: boolean;
+        /** Background job building the CSV; its result is a CsvExportJobResult */
+        jobId?: string;
+        error?: string;
+      }>;
+      /** Copy entries in a date range to the system clipboard as TSV, CSV or Markdown */
+      exportToClipboard: (request: ClipboardExportRequest) => Promise<{
+        success: boolean;
+        entryCount?: number;
+        characters?: number;
+        error?: string;
+      }>;
+      /** Saved export templates and the columns available to them */
+      listExportTemplates: () => Promise<{
+        success: boolean;
+        templates?: ExportTemplate[];
+        columns?: Array<{ id: ExportColumnId; header: string }>;
+        error?: string;
+      }>;
+      /** Save an export template (replaces one with the same name) */
+      saveExportTemplate: (template: ExportTemplate) => Promise<{
+        success: boolean;
+        templates?: ExportTemplate[];
+        error?: string;
+      }>;
+      deleteExportTemplate: (templateName: string) => Promise<{
+        success: boolean;
+        error?: string;
+      }>;
+      /** Saved description templates and the placeholders they can use */
+      listDescriptionTemplates: () => Promise<{
+        success: boolean;
+        templates?: DescriptionTemplate[];
+        variables?: DescriptionTemplateVariable[];
+        error?: string;
+      }>;
+      /** Save a description template (creates one when id is omitted) */
+      saveDescriptionTemplate: (template: { id?: string; name: string; template: string }) => Promise<{
+        success: boolean;
+        template?: DescriptionTemplate;
+        error?: string;
+      }>;
+      deleteDescriptionTemplate: (templateId: string) => Promise<{
+        success: boolean;
+        error?: string;
+      }>;
+      /** Fill in a template for a new draft; missing lists placeholders that had no value */
+      renderDescription: (
+        templateId: string,
+        vars: Partial<Record<DescriptionTemplateVariable, string | null>>
+      ) => Promise<{
+        success: boolean;
+        description?: string;
+        missing?: DescriptionTemplateVariable[];
+        error?: string;
+      }>;
+      /** Categories entries can be labelled with, with a color hint for each */
+      listEntryCategories: () => Promise<{
+        success: boolean;
+        categories?: EntryCategory[];
+        error?: string;
+      }>;
+      /**
+       * Save a category (creates one when id is omitted); renaming one
+       * renames it on the entries that use it
+       */
+      saveEntryCategory: (category: { id?: string; name: string; color?: string }) => Promise<{
+        success: boolean;
+        category?: EntryCategory;
+        renamedEntries?: number;
+        error?: string;
+        code?: string;
+      }>;
+      /** Delete a category and clear it from the entries that use it */
+      deleteEntryCategory: (categoryId: string) => Promise<{
+        success: boolean;
+        clearedEntries?: number;
+        error?: string;
+        code?: string;
+      }>;
+      /** Propose the next likely draft row for a date from recent history */
+      suggestNextEntry: (date: string) => Promise<{
+        success: boolean;
+        suggestion?: {
+          date: string;
+          hours: number;
+          project: string;
+          tool: string | null;
+          chargeCode: string | null;
+          taskDescription: string;
+          confidence: number;
+          occurrences: number;
+          basis: "follows-previous-block" | "same-weekday" | "recent-history";
+        } | null;
+        error?: string;
+      }>;
+      /** Read Outlook meetings for a range and propose draft rows (nothing is saved) */
+      previewCalendarImport: (request: CalendarImportRequest) => Promise<{
+        success: boolean;
+        drafts?: CalendarDraftPreview[];
+        skipped?: Array<{ eventId: string; subject: string; reason: string }>;
+        error?: string;
+      }>;
+      /** Insert the previewed meetings the user kept as draft entries */
+      importCalendarDrafts: (
+        drafts: Array<{
+          date: string;
+          hours: number;
+          project: string;
+          tool?: string | null;
+          chargeCode?: string | null;
+          taskDescription: string;
+          timeZone?: string;
+          category?: string | null;
+        }>
+      ) => Promise<{
+        success: boolean;
+        /** Background job inserting the drafts; its result is a CalendarImportJobResult */
+        jobId?: string;
+        error?: string;
+        code?: string;
+      }>;
+      /** Hours per tool per project for a date range (submitted entries unless includeDrafts) */
+      getToolUsage: (query: ToolUsageQuery) => Promise<{
+        success: boolean;
+        startDate?: string;
+        endDate?: string;
+        rows?: ToolUsageRow[];
+        totalHours?: number;
+        error?: string;
+      }>;
+      /** Tool usage aggregate as CSV */
+      exportToolUsageCSV: (query: ToolUsageQuery) => Promise<{
         success: boolean;
         csvContent?: string;
+        rowCount?: number;
+        filename?: string;
+        error?: string;
+      }>;
+      /** Hours used per configured charge code quota, counting pending drafts */
+      getQuotaStatus: () => Promise<{
+        success: boolean;
+        quotas?: ChargeCodeQuotaStatus[];
+        error?: string;
+      }>;
+      /** Draft count, hours, date range and per-quarter breakdown for the editor header */
+      getDraftStats: () => Promise<{
+        success: boolean;
+        stats?: DraftStats;
+        error?: string;
+      }>;
+      /** Configured quarters with whether their form can be submitted to */
+      getQuarters: () => Promise<{
+        success: boolean;
+        quarters?: QuarterSummary[];
+        error?: string;
+      }>;
+      /** Whether a running submission is holding entries; draft changes are refused while locked */
+      getAppLockState: () => Promise<{
+        success: boolean;
+        state?: AppLockState;
+        error?: string;
+      }>;
+      /** Drafts, failed, submitting and queued counts (also sets the window title and badge) */
+      getStatusCounts: () => Promise<{
+        success: boolean;
+        counts?: EntryStatusCounts;
+        error?: string;
+      }>;
+      /**
+       * Mark entries whose submission key (SP-…) appears in the pasted sheet
+       * contents as Complete, so a retry doesn't submit them twice
+       */
+      reconcileSubmissionKeys: (text: string) => Promise<{
+        success: boolean;
+        keysFound?: number;
+        /** Entries marked Complete */
+        matchedIds?: number[];
+        alreadyCompleteKeys?: string[];
+        /** Keys not found locally (submitted from another machine, or edited) */
+        unknownKeys?: string[];
+        error?: string;
+      }>;
+      /**
+       * Submitted and draft hours for every day of `year`, for the calendar
+       * heatmap; draft hours show days logged but never submitted
+       */
+      getActivityHeatmap: (year: number) => Promise<{
+        success: boolean;
+        year?: number;
+        days?: ActivityHeatmapDay[];
+        error?: string;
+      }>;
+      /**
+       * Working days (per the workweek settings and holiday calendar) with no
+       * hours or fewer than the daily target, for the gaps panel; days after
+       * today are not checked
+       */
+      getMissingDays: (query: { startDate: string; endDate: string }) => Promise<{
+        success: boolean;
+        startDate?: string;
+        /** The requested end date, or today if that is earlier */
+        endDate?: string;
+        dailyTargetHours?: number;
+        days?: MissingTimesheetDay[];
+        error?: string;
+      }>;
+      /**
+       * Compare the week starting on `weekStart` with what was last
+       * submitted for it: added, removed and modified entries with
+       * field-level changes
+       */
+      diffWeek: (weekStart: string) => Promise<{
+        success: boolean;
+        diff?: WeekDiff;
+        error?: string;
+      }>;
+      /**
+       * Screen reader friendly summary of the week starting on `weekStart`:
+       * a labelled section per day and one sentence per entry, no tables
+       */
+      exportWeeklySummary: (request: WeeklySummaryExportRequest) => Promise<{
+        success: boolean;
+        content?: string;
         entryCount?: number;
+        totalHours?: number;
         filename?: string;
         error?: string;
       }>;
@@ -110,6 +440,413 @@ declare global {
       ) => void;
       /** Unsubscribe from progress updates */
       removeProgressListener: () => void;
+      /** Subscribe to auto-save flush results (one event per flush) */
+      onDraftSaved: (
+        callback: (payload: { results: DraftSaveResult[] }) => void
+      ) => void;
+      /** Unsubscribe from auto-save flush results */
+      removeDraftSavedListener: () => void;
+      /** Subscribe to status count changes (sent only when a count changes) */
+      onStatusCounts: (callback: (counts: EntryStatusCounts) => void) => void;
+      /** Unsubscribe from status count changes */
+      removeStatusCountsListener: () => void;
+    };
+  }
+
+  interface BackfillWarning {
+    id?: number;
+    date: string;
+    /** closing: locks soon; closed: can no longer be submitted; unavailable: no form configured */
+    state: "closing" | "closed" | "unavailable";
+    quarterId: string | null;
+    lockDate: string | null;
+    message: string;
+  }
+
+  interface SubmissionPlanGroup {
+    /** Null for entries dated outside every configured quarter */
+    quarterId: string | null;
+    quarterName: string | null;
+    formUrl: string | null;
+    entryIds: number[];
+    hours: number;
+    firstDate: string | null;
+    lastDate: string | null;
+  }
+
+  interface SubmissionPlan {
+    /** chronological (oldest first), newest-first, or deadline (form that locks soonest first) */
+    order: 'chronological' | 'newest-first' | 'deadline';
+    groups: SubmissionPlanGroup[];
+    /** True when the entries go to more than one quarter form */
+    splitAcrossQuarters: boolean;
+  }
+
+  interface SubmissionStrategyEstimate {
+    /** Browser launches + logins */
+    botRuns: number;
+    logins: number;
+    /** One per entry submitted */
+    formLoads: number;
+    estimatedMs: number;
+  }
+
+  interface SubmissionEstimate {
+    entryCount: number;
+    /** Entries outside every configured quarter; they fail without a run */
+    unroutableEntries: number;
+    /** What submitting now does: one run per quarter form */
+    batched: SubmissionStrategyEstimate;
+    /** For comparison: a separate run for every entry */
+    perEntry: SubmissionStrategyEstimate;
+    groups: Array<{ quarterId: string; entryCount: number; estimatedMs: number }>;
+    timing: {
+      /** "history" when recent runs supplied the timings */
+      basis: "history" | "defaults";
+      sampleForms: number;
+      sampleRows: number;
+      rowMs: number;
+      formOverheadMs: number;
+      failureRate: number;
+    };
+  }
+
+  interface DirtyDraftRow {
+    /** Identifies the grid row in flush results (new rows have no id yet) */
+    rowKey: string;
+    id?: number;
+    date?: string;
+    hours?: number;
+    project?: string;
+    tool?: string | null;
+    chargeCode?: string | null;
+    taskDescription?: string;
+    timeZone?: string | null;
+    externalRef?: string | null;
+    category?: string | null;
+    kind?: EntryKind | null;
+  }
+
+  /** Row as persisted, returned by save and delete */
+  interface PersistedEntry {
+    id: number;
+    date: string;
+    hours: number;
+    project: string;
+    tool?: string | null;
+    chargeCode?: string | null;
+    taskDescription: string;
+    amendsId?: number;
+    failureReason?: string;
+    timeZone?: string;
+    externalRef?: string;
+    category?: string;
+    /** Left out for work */
+    kind?: EntryKind;
+    /** When the entry's fields were last saved */
+    updatedAt?: string;
+  }
+
+  interface DraftSaveResult {
+    rowKey: string;
+    success: boolean;
+    changes?: number;
+    id?: number;
+    entry?: PersistedEntry;
+    warning?: BackfillWarning;
+    ruleViolations?: ValidationRuleViolation[];
+    error?: string;
+  }
+
+  interface ClipboardExportRequest {
+    startDate: string;
+    endDate: string;
+    format: "tsv" | "csv" | "markdown";
+    /** Also include entries that have not been submitted */
+    includeDrafts?: boolean;
+    /** Export template columns to use (date, hours, project, tool, charge code and task by default) */
+    templateName?: string;
+  }
+
+  type ExportColumnId =
+    | "date"
+    | "hours"
+    | "project"
+    | "tool"
+    | "chargeCode"
+    | "taskDescription"
+    | "externalRef"
+    | "category"
+    | "status"
+    | "submittedAt"
+    | "correction"
+    | "correctedHours";
+
+  /** Column selection, order and header names for an export */
+  interface ExportTemplate {
+    name: string;
+    /** Columns in export order; header overrides the default column name */
+    columns: Array<{ id: ExportColumnId; header?: string }>;
+  }
+
+  type DescriptionTemplateVariable = 'project' | 'tool' | 'date' | 'ticket';
+
+  interface DescriptionTemplate {
+    id: string;
+    name: string;
+    /** Task description with {project}, {tool}, {date} and {ticket} placeholders */
+    template: string;
+  }
+
+  /** What an entry's hours were spent on (see ENTRY_KINDS in @sheetpilot/shared) */
+  type EntryKind = "work" | "pto" | "holiday" | "training";
+
+  interface EntryCategory {
+    id: string;
+    name: string;
+    /** Color hint, "#rrggbb" */
+    color: string;
+  }
+
+  interface CalendarImportRequest {
+    startDate: string;
+    endDate: string;
+    /** Defaults to "Internal Meetings" */
+    project?: string;
+    tool?: string | null;
+    chargeCode?: string | null;
+    /** Include events with any of these Outlook categories */
+    includeCategories?: string[];
+    /** Include events whose subject contains any of these */
+    includeKeywords?: string[];
+    /** Exclude events whose subject contains any of these */
+    excludeKeywords?: string[];
+    /** Skip events shown as free (default true) */
+    skipFree?: boolean;
+    /** IANA zone the meetings took place in (e.g. on a trip); recorded on each draft */
+    timeZone?: string;
+  }
+
+  interface CalendarDraftPreview {
+    eventId: string;
+    date: string;
+    hours: number;
+    project: string;
+    tool: string | null;
+    chargeCode: string | null;
+    taskDescription: string;
+    /** Zone the meeting took place in; the date is in the home zone */
+    timeZone?: string;
+    /** An entry with the same date, project and description already exists */
+    isDuplicate: boolean;
+  }
+
+  interface ToolUsageQuery {
+    startDate: string;
+    endDate: string;
+    includeDrafts?: boolean;
+  }
+
+  interface WeeklySummaryExportRequest {
+    /** First day of the week, YYYY-MM-DD */
+    weekStart: string;
+    format: 'text' | 'markdown';
+    includeDrafts?: boolean;
+  }
+
+  interface ToolUsageRow {
+    project: string;
+    tool: string | null;
+    hours: number;
+    entryCount: number;
+  }
+
+  interface ActivityHeatmapDay {
+    /** YYYY-MM-DD */
+    date: string;
+    submittedHours: number;
+    draftHours: number;
+  }
+
+  interface MissingTimesheetDay {
+    /** YYYY-MM-DD */
+    date: string;
+    /** 0 = Sunday to 6 = Saturday */
+    weekday: number;
+    /** Submitted plus draft hours */
+    loggedHours: number;
+    submittedHours: number;
+    draftHours: number;
+    shortfallHours: number;
+    reason: 'no-hours' | 'under-target';
+  }
+
+  interface DraftQuarterStats {
+    /** Null for drafts dated outside every configured quarter */
+    quarterId: string | null;
+    quarterName: string | null;
+    draftCount: number;
+    hours: number;
+  }
+
+  interface DraftStats {
+    draftCount: number;
+    totalHours: number;
+    /** Distinct dates with at least one draft */
+    datesCovered: number;
+    earliestDate: string | null;
+    latestDate: string | null;
+    /** Drafts without a date yet */
+    undatedCount: number;
+    quarters: DraftQuarterStats[];
+  }
+
+  interface QuarterSummary {
+    id: string;
+    name: string;
+    startDate: string;
+    endDate: string;
+    formUrl: string;
+    formId: string;
+    lockDate: string | null;
+    /** placeholder: no real form yet; locked: the form no longer accepts entries */
+    status: 'configured' | 'placeholder' | 'locked';
+    /** Why the form cannot be submitted to; null when configured */
+    statusMessage: string | null;
+  }
+
+  interface WeekDiffEntry {
+    id: number;
+    /** Submitted entry this draft corrects, if any */
+    amendsId: number | null;
+    date: string | null;
+    hours: number | null;
+    project: string | null;
+    tool: string | null;
+    chargeCode: string | null;
+    taskDescription: string | null;
+  }
+
+  interface WeekDiffFieldChange {
+    field: 'date' | 'hours' | 'project' | 'tool' | 'chargeCode' | 'taskDescription';
+    before: string | number | null;
+    after: string | number | null;
+  }
+
+  interface WeekDiff {
+    weekStart: string;
+    weekEnd: string;
+    /** New drafts, and corrections that move an entry into the week */
+    added: WeekDiffEntry[];
+    /** Submitted entries whose correction moves them out of the week */
+    removed: Array<{ entry: WeekDiffEntry; draftId: number; movedToDate: string | null }>;
+    /** Submitted entries with a pending correction */
+    modified: Array<{
+      originalId: number;
+      draftId: number;
+      before: WeekDiffEntry;
+      after: WeekDiffEntry;
+      changes: WeekDiffFieldChange[];
+    }>;
+    /** Submitted entries with no pending change */
+    unchangedCount: number;
+  }
+
+  interface RetiredProjectIssue {
+    id: number;
+    date: string;
+    project: string;
+    /** First day the project no longer accepts hours */
+    retiredOn: string;
+    successor: string | null;
+    message: string;
+  }
+
+  interface AppLockState {
+    locked: boolean;
+    reason: 'submission' | null;
+    /** When the lock was taken (ISO timestamp) */
+    since: string | null;
+    /** Run history ID of the submission holding the lock */
+    runId: number | null;
+  }
+
+  interface EntryStatusCounts {
+    /** Drafts never sent */
+    drafts: number;
+    /** Drafts sent before but not accepted */
+    failed: number;
+    /** Entries the current submission is sending */
+    submitting: number;
+    /** Submission jobs waiting to start */
+    queued: number;
+  }
+
+  interface ChargeCodeQuotaStatus {
+    chargeCode: string;
+    quarterId: string;
+    /** Hours budgeted for the quarter */
+    hours: number;
+    submittedHours: number;
+    /** Hours in drafts that would be submitted next */
+    pendingHours: number;
+    projectedHours: number;
+    /** Negative once the quota is exceeded */
+    remainingHours: number;
+    /** 'near' from 90% of the quota */
+    state: 'ok' | 'near' | 'over';
+  }
+
+  interface QuotaWarning {
+    chargeCode: string;
+    quarterId: string;
+    quotaHours: number;
+    projectedHours: number;
+    /** Pending entries booked to the charge code in that quarter */
+    entryIds: number[];
+    message: string;
+  }
+
+  /** Result of a timesheet submission job; a job refused by validation rules keeps only ruleViolations */
+  interface SubmitJobResult {
+    submitResult?: {
+      ok: boolean;
+      successCount: number;
+      removedCount: number;
+      totalProcessed: number;
+      /** Entries Smartsheet rejected; they stay pending with a failure reason */
+      rejectedEntries?: Array<{
+        entryId: number;
+        message: string;
+        banner: string | null;
+        fieldErrors: Record<string, string>;
+      }>;
+      /** Set when the installed Chrome is outside the tested version range */
+      browserWarnings?: string[];
+    };
+    dbPath?: string;
+    /** Set when Smartsheet rejected some entries while the rest went through */
+    partial?: boolean;
+    /** Blocking rule violations that stopped the submission */
+    ruleViolations?: ValidationRuleViolation[];
+  }
+
+  /** Result of a succeeded CSV export job */
+  interface CsvExportJobResult {
+    csvContent: string;
+    entryCount: number;
+    /** Hours as submitted vs. after superseded originals are replaced by corrections */
+    totals: {
+      submittedHours: number;
+      correctedHours: number;
+      correctionCount: number;
     };
+    filename: string;
+  }
+
+  /** Result of a succeeded calendar import job */
+  interface CalendarImportJobResult {
+    inserted: number;
+    duplicates: number;
   }
 }
@@ -18,6 +18,11 @@
  * - logger: Structured logging
  * - updates: Auto-update system
  * - settings: Application configuration
+ * - scheduler: Scheduled exports and run history
+ * - cloud: OneDrive/SharePoint archive uploads
+ * - jobs: Background job status, cancellation and progress events
+ * - validationRules: User-defined validation rules
+ * - preferences: Per-user preferences
  */
 
 // Import all window API contract modules to ensure they are loaded
@@ -32,5 +37,10 @@ import "./window.logger";
 import "./window.updates";
 import "./window.settings";
 import "./window.businessConfig";
+import "./window.scheduler";
+import "./window.cloud";
+import "./window.jobs";
+import "./window.validationRules";
+import "./window.preferences";
 
 export {};
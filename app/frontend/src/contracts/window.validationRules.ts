@@ -0,0 +1,98 @@
+/**
+ * @fileoverview Window API - User-defined validation rules
+ */
+
+export {};
+
+declare global {
+  interface Window {
+    /**
+     * User-defined validation rules
+     *
+     * Rules are checked when drafts are saved (reported as `ruleViolations`)
+     * and before submission, where `block` rules stop the submission.
+     * Creating, updating and deleting rules requires an admin session.
+     */
+    validationRules?: {
+      /** List all rules, including inactive ones */
+      list: () => Promise<{
+        success: boolean;
+        rules?: ValidationRule[];
+        error?: string;
+      }>;
+      /** Create a rule (admin only) */
+      create: (
+        token: string,
+        rule: ValidationRuleInput
+      ) => Promise<{ success: boolean; id?: number; error?: string }>;
+      /** Replace a rule's definition (admin only) */
+      update: (
+        token: string,
+        id: number,
+        rule: ValidationRuleInput
+      ) => Promise<{ success: boolean; error?: string }>;
+      /** Delete a rule (admin only) */
+      delete: (
+        token: string,
+        id: number
+      ) => Promise<{ success: boolean; error?: string }>;
+    };
+  }
+
+  type ValidationRuleField =
+    | "date"
+    | "hours"
+    | "project"
+    | "tool"
+    | "chargeCode"
+    | "taskDescription";
+
+  /**
+   * String operators are case-sensitive; `matches` takes a regular
+   * expression; `atMost`/`atLeast` compare numbers
+   */
+  type ValidationRuleOperator =
+    | "required"
+    | "empty"
+    | "equals"
+    | "notEquals"
+    | "startsWith"
+    | "contains"
+    | "matches"
+    | "atMost"
+    | "atLeast";
+
+  interface ValidationRuleCondition {
+    field: ValidationRuleField;
+    operator: ValidationRuleOperator;
+    value?: string | null;
+  }
+
+  /**
+   * The entry breaks the rule when `when` holds (or is absent) and the
+   * field condition does not, e.g. tool `required` when project
+   * `startsWith` "FAB-"
+   */
+  interface ValidationRuleInput extends ValidationRuleCondition {
+    name: string;
+    when?: ValidationRuleCondition | null;
+    action: "warn" | "block";
+    message: string;
+    isActive?: boolean;
+  }
+
+  interface ValidationRule extends ValidationRuleInput {
+    id: number;
+    when: ValidationRuleCondition | null;
+    isActive: boolean;
+  }
+
+  interface ValidationRuleViolation {
+    ruleId: number;
+    ruleName: string;
+    entryId?: number;
+    field: ValidationRuleField;
+    action: "warn" | "block";
+    message: string;
+  }
+}
@@ -12,4 +12,112 @@ export async function rebuildDatabase(token: string): Promise<{ success: boolean
   return window.admin.rebuildDatabase(token);
 }
 
+export async function runReadonlyQuery(
+  token: string,
+  sql: string,
+  params?: Array<string | number | null> | Record<string, string | number | null>
+): Promise<{ success: boolean; columns?: string[]; rows?: unknown[][]; rowCount?: number; truncated?: boolean; elapsedMs?: number; error?: string }> {
+  if (!window.admin?.runReadonlyQuery) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.runReadonlyQuery(token, sql, params);
+}
+
+export async function checkDataIntegrity(
+  token: string,
+  repair?: boolean
+): Promise<{ success: boolean; report?: DataIntegrityReport; error?: string }> {
+  if (!window.admin?.checkDataIntegrity) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.checkDataIntegrity(token, repair);
+}
+
+export async function bulkRecode(
+  token: string,
+  request: BulkRecodeRequest
+): Promise<{ success: boolean; count?: number; entryIds?: number[]; historyId?: number | null; error?: string }> {
+  if (!window.admin?.bulkRecode) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.bulkRecode(token, request);
+}
+
+export async function deleteCompletedEntry(
+  token: string,
+  id: number,
+  reason: string
+): Promise<{ success: boolean; entry?: PersistedEntry; deletionId?: number; error?: string }> {
+  if (!window.admin?.deleteCompletedEntry) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.deleteCompletedEntry(token, id, reason);
+}
+
+export async function purgeExpiredSessions(
+  token: string
+): Promise<{ success: boolean; purged?: number; error?: string }> {
+  if (!window.admin?.purgeExpiredSessions) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.purgeExpiredSessions(token);
+}
+
+export async function getSessionStats(
+  token: string
+): Promise<{ success: boolean; stats?: SessionStats; error?: string }> {
+  if (!window.admin?.getSessionStats) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.getSessionStats(token);
+}
+
+export async function revokeUserSessions(
+  token: string,
+  email: string
+): Promise<{ success: boolean; revoked?: number; error?: string }> {
+  if (!window.admin?.revokeUserSessions) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.revokeUserSessions(token, email);
+}
 
+export async function inspectForm(
+  token: string,
+  request: { baseUrl: string; formId: string }
+): Promise<{ success: boolean; inspection?: FormInspection; error?: string }> {
+  if (!window.admin?.inspectForm) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.inspectForm(token, request);
+}
+
+export async function lintAutomationConfig(
+  token: string,
+  checkReachability?: boolean
+): Promise<{ success: boolean; report?: AutomationConfigLintReport; error?: string }> {
+  if (!window.admin?.lintAutomationConfig) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.lintAutomationConfig(token, checkReachability);
+}
+
+export async function listDropdownResolutions(
+  token: string,
+  fieldKey?: string
+): Promise<{ success: boolean; resolutions?: DropdownResolution[]; error?: string }> {
+  if (!window.admin?.listDropdownResolutions) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.listDropdownResolutions(token, fieldKey);
+}
+
+export async function deleteDropdownResolution(
+  token: string,
+  id: number
+): Promise<{ success: boolean; error?: string }> {
+  if (!window.admin?.deleteDropdownResolution) {
+    return { success: false, error: 'Admin API not available' };
+  }
+  return window.admin.deleteDropdownResolution(token, id);
+}
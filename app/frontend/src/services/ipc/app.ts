@@ -0,0 +1,25 @@
+export async function getAppHealth(): Promise<{ success: boolean; health?: AppHealth; error?: string }> {
+  if (!window.api?.getHealth) {
+    return { success: false, error: 'App API not available' };
+  }
+  return window.api.getHealth();
+}
+
+export async function getAppEnvironment(): Promise<{
+  success: boolean;
+  environment?: AppEnvironmentInfo;
+  error?: string;
+}> {
+  if (!window.api?.getEnvironment) {
+    return { success: false, error: 'App API not available' };
+  }
+  return window.api.getEnvironment();
+}
+
+export function onClockSkew(callback: (status: ClockSkewStatus) => void): void {
+  window.api?.onClockSkew?.(callback);
+}
+
+export function removeClockSkewListener(): void {
+  window.api?.removeClockSkewListener?.();
+}
@@ -24,4 +24,10 @@ export async function logout(token: string): Promise<{ success: boolean; error?:
   return window.auth.logout(token);
 }
 
+export function onSessionRevoked(callback: (payload: { reason: string }) => void): void {
+  window.auth?.onSessionRevoked?.(callback);
+}
 
+export function removeSessionRevokedListener(): void {
+  window.auth?.removeSessionRevokedListener?.();
+}
@@ -138,6 +138,8 @@ export async function updateProject(
     requires_tools?: boolean;
     display_order?: number;
     is_active?: boolean;
+    retired_on?: string | null;
+    successor_project?: string | null;
   }
 ): Promise<{
   success: boolean;
@@ -202,6 +204,8 @@ export async function addProject(
     requires_tools?: boolean;
     display_order?: number;
     is_active?: boolean;
+    retired_on?: string | null;
+    successor_project?: string | null;
   }
 ): Promise<{
   success: boolean;
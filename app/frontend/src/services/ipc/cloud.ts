@@ -0,0 +1,34 @@
+export async function getCloudStatus(): Promise<{ success: boolean; status?: CloudConnectionStatus; error?: string }> {
+  if (!window.cloud?.getStatus) {
+    return { success: false, error: 'Cloud API not available' };
+  }
+  return window.cloud.getStatus();
+}
+
+export async function connectCloud(token: string, config: { clientId: string; tenantId: string; folderPath: string }): Promise<{ success: boolean; prompt?: CloudDeviceCodePrompt; error?: string }> {
+  if (!window.cloud?.connect) {
+    return { success: false, error: 'Cloud API not available' };
+  }
+  return window.cloud.connect(token, config);
+}
+
+export async function disconnectCloud(token: string): Promise<{ success: boolean; error?: string }> {
+  if (!window.cloud?.disconnect) {
+    return { success: false, error: 'Cloud API not available' };
+  }
+  return window.cloud.disconnect(token);
+}
+
+export async function uploadTimesheetExportToCloud(token: string, format: 'csv' | 'xlsx' = 'csv', templateName?: string): Promise<CloudUploadResponse & { entryCount?: number }> {
+  if (!window.cloud?.uploadTimesheetExport) {
+    return { success: false, error: 'Cloud API not available' };
+  }
+  return window.cloud.uploadTimesheetExport(token, format, templateName);
+}
+
+export async function uploadLatestLogToCloud(token: string): Promise<CloudUploadResponse> {
+  if (!window.cloud?.uploadLatestLog) {
+    return { success: false, error: 'Cloud API not available' };
+  }
+  return window.cloud.uploadLatestLog(token);
+}
@@ -20,4 +20,31 @@ export async function storeCredentials(service: string, email: string, password:
   return window.credentials.store(service, email, password);
 }
 
+export async function revealCredentials(token: string, service: string): Promise<{
+  success: boolean;
+  email?: string;
+  password?: string;
+  error?: string;
+}> {
+  if (!window.credentials?.reveal) {
+    return { success: false, error: 'Credentials API not available' };
+  }
+  return window.credentials.reveal(token, service);
+}
 
+export async function auditCredentialMigration(): Promise<{
+  success: boolean;
+  keychainAvailable?: boolean;
+  entries?: Array<{
+    service: string;
+    email: string;
+    status: 'migrated' | 'sqlite-only' | 'keychain-only' | 'mismatch';
+  }>;
+  sqliteOnly?: string[];
+  error?: string;
+}> {
+  if (!window.credentials?.migrationAudit) {
+    return { success: false, error: 'Credentials API not available' };
+  }
+  return window.credentials.migrationAudit();
+}
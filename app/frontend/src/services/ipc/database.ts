@@ -10,6 +10,9 @@ export interface ArchiveResponse {
     task_description: string;
     status?: string;
     submitted_at?: string;
+    created_at?: string | null;
+    updated_at?: string | null;
+    submitted_run_id?: number | null;
   }>;
   credentials?: Array<{
     id: number;
@@ -29,3 +32,61 @@ export async function getAllArchiveData(
   }
   return window.database.getAllArchiveData(token);
 }
+
+export async function getSchemaInfo(
+  token: string
+): Promise<{ success: boolean; schemaInfo?: DatabaseSchemaInfo; error?: string } | null> {
+  if (!window.database?.getSchemaInfo) {
+    return null;
+  }
+  return window.database.getSchemaInfo(token);
+}
+
+export async function getConnectionStats(
+  token: string
+): Promise<{ success: boolean; stats?: DatabaseConnectionStats; error?: string } | null> {
+  if (!window.database?.getConnectionStats) {
+    return null;
+  }
+  return window.database.getConnectionStats(token);
+}
+
+export async function getCompatibilityInfo(): Promise<{
+  success: boolean;
+  compatibility?: DatabaseCompatibilityInfo;
+  error?: string;
+} | null> {
+  if (!window.database?.getCompatibilityInfo) {
+    return null;
+  }
+  return window.database.getCompatibilityInfo();
+}
+
+async function gunzipEntries(
+  gzipped: Uint8Array
+): Promise<NonNullable<ArchivePageResponse["entries"]>> {
+  const stream = new Blob([gzipped])
+    .stream()
+    .pipeThrough(new DecompressionStream("gzip"));
+  const json = await new Response(stream).text();
+  return JSON.parse(json) as NonNullable<ArchivePageResponse["entries"]>;
+}
+
+/**
+ * Gets one page of submitted entries; gzipped pages are unpacked so callers
+ * always receive `entries`
+ */
+export async function getArchivePage(
+  token: string,
+  request?: ArchivePageRequest
+): Promise<ArchivePageResponse | null> {
+  if (!window.database?.getArchivePage) {
+    return null;
+  }
+  const response = await window.database.getArchivePage(token, request);
+  if (!response.success || !response.gzipped) {
+    return response;
+  }
+  const { gzipped, ...rest } = response;
+  return { ...rest, entries: await gunzipEntries(gzipped) };
+}
@@ -0,0 +1,63 @@
+export async function getJobStatus(jobId: string): Promise<{ success: boolean; job?: JobSnapshot; error?: string }> {
+  if (!window.jobs?.getStatus) {
+    return { success: false, error: 'Jobs API not available' };
+  }
+  return window.jobs.getStatus(jobId);
+}
+
+/** Interval between status checks while following a job */
+const JOB_POLL_INTERVAL_MS = 500;
+
+/**
+ * Polls a job until it succeeds, fails or is cancelled
+ */
+export async function waitForJob(jobId: string): Promise<{ success: boolean; job?: JobSnapshot; error?: string }> {
+  for (;;) {
+    const res = await getJobStatus(jobId);
+    if (!res.success || !res.job) {
+      return { success: false, error: res.error ?? 'Job not found' };
+    }
+    if (res.job.status === 'succeeded' || res.job.status === 'failed' || res.job.status === 'cancelled') {
+      return { success: true, job: res.job };
+    }
+    await new Promise((resolve) => setTimeout(resolve, JOB_POLL_INTERVAL_MS));
+  }
+}
+
+/**
+ * Follows a job to completion and returns its result, or the error it failed
+ * with (plus any result the failed job kept, e.g. blocking rule violations)
+ */
+export async function getJobResult<T>(jobId: string): Promise<{ success: true; result: T } | { success: false; error: string; result?: Partial<T> }> {
+  const res = await waitForJob(jobId);
+  if (!res.job) {
+    return { success: false, error: res.error ?? 'Job not found' };
+  }
+  if (res.job.status !== 'succeeded') {
+    const error = res.job.error ?? `Job ${res.job.status}`;
+    return res.job.result ? { success: false, error, result: res.job.result as Partial<T> } : { success: false, error };
+  }
+  return { success: true, result: res.job.result as T };
+}
+
+export async function listJobs(): Promise<{ success: boolean; jobs: JobSnapshot[]; error?: string }> {
+  if (!window.jobs?.list) {
+    return { success: false, jobs: [], error: 'Jobs API not available' };
+  }
+  return window.jobs.list();
+}
+
+export async function cancelJob(jobId: string): Promise<{ success: boolean; error?: string }> {
+  if (!window.jobs?.cancel) {
+    return { success: false, error: 'Jobs API not available' };
+  }
+  return window.jobs.cancel(jobId);
+}
+
+export function onJobProgress(callback: (job: JobSnapshot) => void): void {
+  window.jobs?.onJobProgress(callback);
+}
+
+export function removeJobProgressListener(): void {
+  window.jobs?.removeJobProgressListener();
+}
@@ -1,25 +1,27 @@
-export function logError(message: string, data?: unknown): void {
-  window.logger?.error?.(message, data);
+import type { FrontendLogFields } from '@/contracts/window.logger';
+
+export function logError(message: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.error?.(message, data, fields);
 }
 
-export function logWarn(message: string, data?: unknown): void {
-  window.logger?.warn?.(message, data);
+export function logWarn(message: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.warn?.(message, data, fields);
 }
 
-export function logInfo(message: string, data?: unknown): void {
-  window.logger?.info?.(message, data);
+export function logInfo(message: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.info?.(message, data, fields);
 }
 
-export function logVerbose(message: string, data?: unknown): void {
-  window.logger?.verbose?.(message, data);
+export function logVerbose(message: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.verbose?.(message, data, fields);
 }
 
-export function logDebug(message: string, data?: unknown): void {
-  window.logger?.debug?.(message, data);
+export function logDebug(message: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.debug?.(message, data, fields);
 }
 
-export function logUserAction(action: string, data?: unknown): void {
-  window.logger?.userAction?.(action, data);
+export function logUserAction(action: string, data?: unknown, fields?: FrontendLogFields): void {
+  window.logger?.userAction?.(action, data, fields);
 }
 
 
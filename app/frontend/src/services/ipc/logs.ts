@@ -1,3 +1,10 @@
+import type {
+  CrashReportSummary,
+  LogQueryFilter,
+  LogQueryLine,
+  StartupTimings
+} from '@/contracts/window.logs';
+
 export async function getLogPath(
   token: string
 ): Promise<{ success: boolean; logPath?: string; logFiles?: string[]; error?: string } | null> {
@@ -18,4 +25,44 @@ export async function exportLogs(
   return window.logs.exportLogs(token, logPath, format);
 }
 
+export async function queryLogs(
+  token: string,
+  filter: LogQueryFilter = {}
+): Promise<{ success: boolean; source?: 'index' | 'files'; lines?: LogQueryLine[]; error?: string } | null> {
+  if (!window.logs?.query) {
+    return null;
+  }
+  return window.logs.query(token, filter);
+}
+
+export async function listCrashReports(
+  token: string
+): Promise<{ success: boolean; reports?: CrashReportSummary[]; error?: string } | null> {
+  if (!window.logs?.listCrashReports) {
+    return null;
+  }
+  return window.logs.listCrashReports(token);
+}
+
+export async function exportCrashReports(
+  token: string
+): Promise<{
+  success: boolean;
+  content?: string;
+  filename?: string;
+  mimeType?: string;
+  reportCount?: number;
+  error?: string;
+} | null> {
+  if (!window.logs?.exportCrashReports) {
+    return null;
+  }
+  return window.logs.exportCrashReports(token);
+}
 
+export async function getStartupTimings(): Promise<{ success: boolean; timings?: StartupTimings; error?: string } | null> {
+  if (!window.logs?.getStartupTimings) {
+    return null;
+  }
+  return window.logs.getStartupTimings();
+}
@@ -0,0 +1,13 @@
+export async function getPreferences(token: string): Promise<{ success: boolean; preferences?: UserPreferences; error?: string }> {
+  if (!window.preferences?.get) {
+    return { success: false, error: 'Preferences API not available' };
+  }
+  return window.preferences.get(token);
+}
+
+export async function setPreferences(token: string, updates: UserPreferencesUpdate): Promise<{ success: boolean; preferences?: UserPreferences; error?: string }> {
+  if (!window.preferences?.set) {
+    return { success: false, error: 'Preferences API not available' };
+  }
+  return window.preferences.set(token, updates);
+}
@@ -0,0 +1,69 @@
+export async function listExportSchedules(): Promise<{ success: boolean; schedules?: ExportSchedule[]; error?: string }> {
+  if (!window.scheduler?.listExportSchedules) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.listExportSchedules();
+}
+
+export async function saveExportSchedule(schedule: ExportScheduleInput): Promise<{ success: boolean; id?: number; nextRunAt?: string; error?: string }> {
+  if (!window.scheduler?.saveExportSchedule) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.saveExportSchedule(schedule);
+}
+
+export async function deleteExportSchedule(id: number): Promise<{ success: boolean; error?: string }> {
+  if (!window.scheduler?.deleteExportSchedule) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.deleteExportSchedule(id);
+}
+
+export async function runExportScheduleNow(id: number): Promise<{ success: boolean; jobId?: string; error?: string }> {
+  if (!window.scheduler?.runExportScheduleNow) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.runExportScheduleNow(id);
+}
+
+export async function getRunHistory(query?: { jobType?: string; limit?: number }): Promise<{ success: boolean; runs: RunHistoryRecord[]; error?: string }> {
+  if (!window.scheduler?.getRunHistory) {
+    return { success: false, runs: [], error: 'Scheduler API not available' };
+  }
+  return window.scheduler.getRunHistory(query);
+}
+
+export async function getRunArtifact(runId: number): Promise<{ success: boolean; artifact?: RunArtifact; error?: string }> {
+  if (!window.scheduler?.getRunArtifact) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.getRunArtifact(runId);
+}
+
+export async function exportReproBundle(runId: number): Promise<{
+  success: boolean;
+  content?: Uint8Array;
+  filename?: string;
+  mimeType?: string;
+  entryCount?: number;
+  error?: string;
+}> {
+  if (!window.scheduler?.exportReproBundle) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.exportReproBundle(runId);
+}
+
+export async function getNetworkBackupStatus(): Promise<{ success: boolean; status?: NetworkBackupStatus; error?: string }> {
+  if (!window.scheduler?.getNetworkBackupStatus) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.getNetworkBackupStatus();
+}
+
+export async function runNetworkBackupNow(): Promise<{ success: boolean; jobId?: string; error?: string }> {
+  if (!window.scheduler?.runNetworkBackupNow) {
+    return { success: false, error: 'Scheduler API not available' };
+  }
+  return window.scheduler.runNetworkBackupNow();
+}
@@ -12,4 +12,21 @@ export async function setSetting(key: string, value: unknown): Promise<{ success
   return window.settings.set(key, value);
 }
 
+export async function setProtectedSetting(
+  token: string,
+  key: string,
+  value: unknown
+): Promise<{ success: boolean; error?: string } | null> {
+  if (!window.settings?.setProtected) {
+    return null;
+  }
+  return window.settings.setProtected(token, key, value);
+}
 
+export function onSettingsChanged(callback: (payload: { keys: string[] }) => void): void {
+  window.settings?.onChanged?.(callback);
+}
+
+export function removeSettingsChangedListener(): void {
+  window.settings?.removeChangedListener?.();
+}
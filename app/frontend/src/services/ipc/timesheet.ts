@@ -1,4 +1,5 @@
 import type { TimesheetRow } from '@/components/timesheet/schema/timesheet.schema';
+import { getJobResult } from './jobs';
 
 export interface SubmitResult {
   ok: boolean;
@@ -12,6 +13,11 @@ export interface SubmitResponse {
   error?: string;
   submitResult?: SubmitResult;
   dbPath?: string;
+  /** Blocking rule violations when the submission was refused */
+  ruleViolations?: ValidationRuleViolation[];
+  /** Set when Smartsheet rejected some entries; they stay pending with a failure reason */
+  partial?: boolean;
+  jobId?: string;
 }
 
 type DraftPayload = {
@@ -22,6 +28,8 @@ type DraftPayload = {
   tool?: string | null;
   chargeCode?: string | null;
   taskDescription?: string;
+  category?: string | null;
+  kind?: TimesheetRow['kind'] | null;
 };
 
 const buildDraftPayload = (row: TimesheetRow): DraftPayload => {
@@ -52,6 +60,16 @@ const buildDraftPayload = (row: TimesheetRow): DraftPayload => {
       key: 'taskDescription',
       value: row.taskDescription,
       include: Boolean(row.taskDescription)
+    },
+    {
+      key: 'category',
+      value: row.category || null,
+      include: row.category !== undefined
+    },
+    {
+      key: 'kind',
+      value: row.kind ?? null,
+      include: row.kind !== undefined
     }
   ];
 
@@ -67,7 +85,27 @@ export async function submitTimesheet(token: string, useMockWebsite?: boolean):
     window.logger?.warn('Submit not available');
     return { error: 'Timesheet API not available' };
   }
-  return window.timesheet.submit(token, useMockWebsite);
+  const res = await window.timesheet.submit(token, useMockWebsite);
+  if (!res.jobId) {
+    return { error: res.error ?? 'Submission did not start' };
+  }
+  // Progress arrives through submission progress events while the job runs
+  const job = await getJobResult<SubmitJobResult>(res.jobId);
+  return job.success ? { ...job.result, jobId: res.jobId } : { ...job.result, error: job.error, jobId: res.jobId };
+}
+
+export async function preflightSubmission(): Promise<{ success: boolean; pendingCount?: number; warnings?: BackfillWarning[]; plan?: SubmissionPlan; quotaWarnings?: QuotaWarning[]; ruleViolations?: ValidationRuleViolation[]; retiredProjects?: RetiredProjectIssue[]; selectorHotfix?: { id: string; fields: string[]; submitButtonSelectors: number } | null; selectorHotfixError?: string | null; blocked?: boolean; error?: string }> {
+  if (!window.timesheet?.preflightSubmission) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.preflightSubmission();
+}
+
+export async function planSubmission(): Promise<{ success: boolean; plan?: SubmissionPlan; estimate?: SubmissionEstimate; quotaWarnings?: QuotaWarning[]; error?: string }> {
+  if (!window.timesheet?.planSubmission) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.planSubmission();
 }
 
 export async function cancelTimesheetSubmission(): Promise<{ success: boolean; message?: string; error?: string }> {
@@ -84,7 +122,14 @@ export async function devSimulateSuccess(): Promise<{ success: boolean; count?:
   return window.timesheet.devSimulateSuccess();
 }
 
-export async function saveDraft(row: TimesheetRow): Promise<{ success: boolean; entry?: TimesheetRow; error?: string }> {
+export async function devBenchmarkFill(request: { iterations: number; profiles?: string[] }): Promise<{ success: boolean; jobId?: string; error?: string }> {
+  if (!window.timesheet?.devBenchmarkFill) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.devBenchmarkFill(request);
+}
+
+export async function saveDraft(row: TimesheetRow): Promise<{ success: boolean; entry?: TimesheetRow; warning?: BackfillWarning; ruleViolations?: ValidationRuleViolation[]; error?: string }> {
   if (!window.timesheet?.saveDraft) {
     return { success: false, error: 'Timesheet API not available' };
   }
@@ -92,11 +137,95 @@ export async function saveDraft(row: TimesheetRow): Promise<{ success: boolean;
   const payload = buildDraftPayload(row);
   const res = await window.timesheet.saveDraft(payload);
   if (res.success && res.entry) {
-    return { success: true, entry: res.entry };
+    return {
+      success: true,
+      entry: res.entry,
+      ...(res.warning ? { warning: res.warning } : {}),
+      ...(res.ruleViolations ? { ruleViolations: res.ruleViolations } : {})
+    };
   }
   return { success: false, error: res.error || 'Unknown error' };
 }
 
+type SaveDraftResult = { success: boolean; entry?: TimesheetRow; warning?: BackfillWarning; ruleViolations?: ValidationRuleViolation[]; error?: string };
+
+/** How long a queued row waits for its flush result before the save is reported as failed */
+const DRAFT_SAVE_TIMEOUT_MS = 15000;
+
+const dirtySaveWaiters = new Map<string, Array<(result: SaveDraftResult) => void>>();
+let draftSavedListenerRegistered = false;
+
+const toSaveDraftResult = (result: DraftSaveResult): SaveDraftResult => {
+  if (result.success && result.entry) {
+    return {
+      success: true,
+      entry: result.entry,
+      ...(result.warning ? { warning: result.warning } : {}),
+      ...(result.ruleViolations ? { ruleViolations: result.ruleViolations } : {})
+    };
+  }
+  return { success: false, error: result.error || 'Unknown error' };
+};
+
+const resolveDirtySave = (rowKey: string, result: SaveDraftResult): void => {
+  const waiters = dirtySaveWaiters.get(rowKey);
+  dirtySaveWaiters.delete(rowKey);
+  waiters?.forEach((resolve) => resolve(result));
+};
+
+const ensureDraftSavedListener = (): void => {
+  if (draftSavedListenerRegistered) {
+    return;
+  }
+  window.timesheet?.onDraftSaved(({ results }) => {
+    for (const result of results) {
+      resolveDirtySave(result.rowKey, toSaveDraftResult(result));
+    }
+  });
+  draftSavedListenerRegistered = true;
+};
+
+/**
+ * Queues a row for the backend's timed auto-save and resolves with its flush result
+ *
+ * Edits queued under the same rowKey before a flush are saved once, with the
+ * latest values. Falls back to an immediate save when auto-save is unavailable.
+ */
+export async function queueDraftSave(row: TimesheetRow, rowKey: string): Promise<SaveDraftResult> {
+  if (!window.timesheet?.markDirty || !window.timesheet.onDraftSaved) {
+    return saveDraft(row);
+  }
+  ensureDraftSavedListener();
+
+  const result = new Promise<SaveDraftResult>((resolve) => {
+    const timer = setTimeout(() => {
+      resolve({ success: false, error: 'Timed out waiting for auto-save' });
+    }, DRAFT_SAVE_TIMEOUT_MS);
+    const waiters = dirtySaveWaiters.get(rowKey) ?? [];
+    waiters.push((saved) => {
+      clearTimeout(timer);
+      resolve(saved);
+    });
+    dirtySaveWaiters.set(rowKey, waiters);
+  });
+
+  const queued = await window.timesheet.markDirty([{ rowKey, ...buildDraftPayload(row) }]);
+  if (!queued.success) {
+    resolveDirtySave(rowKey, { success: false, error: queued.error || 'Unknown error' });
+  }
+  return result;
+}
+
+/**
+ * Saves every queued row now (results are also delivered to queueDraftSave callers)
+ */
+export async function flushDirtyDrafts(): Promise<{ success: boolean; results?: DraftSaveResult[]; error?: string }> {
+  if (!window.timesheet?.flushDirty) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.flushDirty();
+}
+
 export async function loadDraft(): Promise<{ success: boolean; entries?: TimesheetRow[]; error?: string }> {
   if (!window.timesheet?.loadDraft) {
     return { success: false, error: 'Timesheet API not available', entries: [] };
@@ -111,13 +240,27 @@ export async function loadDraftById(id: number): Promise<{ success: boolean; ent
   return window.timesheet.loadDraftById(id) as Promise<{ success: boolean; entry?: TimesheetRow; error?: string }>;
 }
 
-export async function deleteDraft(id: number): Promise<{ success: boolean; error?: string }> {
+export async function deleteDraft(id: number): Promise<{ success: boolean; entry?: TimesheetRow; error?: string }> {
   if (!window.timesheet?.deleteDraft) {
     return { success: false, error: 'Timesheet API not available' };
   }
   return window.timesheet.deleteDraft(id);
 }
 
+export async function reassignRetiredProject(project: string): Promise<{ success: boolean; successor?: string; ids?: number[]; error?: string }> {
+  if (!window.timesheet?.reassignRetiredProject) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.reassignRetiredProject(project);
+}
+
+export async function amendEntry(id: number): Promise<{ success: boolean; id?: number; entry?: TimesheetRow; error?: string }> {
+  if (!window.timesheet?.amendEntry) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.amendEntry(id) as Promise<{ success: boolean; id?: number; entry?: TimesheetRow; error?: string }>;
+}
+
 export async function resetInProgress(): Promise<{ success: boolean; count?: number; error?: string }> {
   if (!window.timesheet?.resetInProgress) {
     return { success: false, error: 'Timesheet API not available' };
@@ -125,11 +268,232 @@ export async function resetInProgress(): Promise<{ success: boolean; count?: num
   return window.timesheet.resetInProgress();
 }
 
-export async function exportToCSV(): Promise<{ success: boolean; csvContent?: string; entryCount?: number; filename?: string; error?: string }> {
+export async function exportToCSV(templateName?: string): Promise<{ success: boolean; csvContent?: string; entryCount?: number; filename?: string; error?: string }> {
   if (!window.timesheet?.exportToCSV) {
     return { success: false, error: 'Timesheet API not available' };
   }
-  return window.timesheet.exportToCSV();
+  const res = await window.timesheet.exportToCSV(templateName);
+  if (!res.success || !res.jobId) {
+    return { success: false, error: res.error ?? 'Export did not start' };
+  }
+  const job = await getJobResult<CsvExportJobResult>(res.jobId);
+  return job.success ? { success: true, ...job.result } : { success: false, error: job.error };
+}
+
+export async function exportToClipboard(request: ClipboardExportRequest): Promise<{ success: boolean; entryCount?: number; characters?: number; error?: string }> {
+  if (!window.timesheet?.exportToClipboard) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.exportToClipboard(request);
+}
+
+export async function listExportTemplates(): Promise<{ success: boolean; templates?: ExportTemplate[]; columns?: Array<{ id: ExportColumnId; header: string }>; error?: string }> {
+  if (!window.timesheet?.listExportTemplates) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.listExportTemplates();
+}
+
+export async function saveExportTemplate(template: ExportTemplate): Promise<{ success: boolean; templates?: ExportTemplate[]; error?: string }> {
+  if (!window.timesheet?.saveExportTemplate) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.saveExportTemplate(template);
+}
+
+export async function deleteExportTemplate(templateName: string): Promise<{ success: boolean; error?: string }> {
+  if (!window.timesheet?.deleteExportTemplate) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.deleteExportTemplate(templateName);
+}
+
+export async function listDescriptionTemplates(): Promise<{ success: boolean; templates?: DescriptionTemplate[]; variables?: DescriptionTemplateVariable[]; error?: string }> {
+  if (!window.timesheet?.listDescriptionTemplates) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.listDescriptionTemplates();
+}
+
+export async function saveDescriptionTemplate(template: { id?: string; name: string; template: string }): Promise<{ success: boolean; template?: DescriptionTemplate; error?: string }> {
+  if (!window.timesheet?.saveDescriptionTemplate) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.saveDescriptionTemplate(template);
+}
+
+export async function deleteDescriptionTemplate(templateId: string): Promise<{ success: boolean; error?: string }> {
+  if (!window.timesheet?.deleteDescriptionTemplate) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.deleteDescriptionTemplate(templateId);
+}
+
+export async function listEntryCategories(): Promise<{ success: boolean; categories?: EntryCategory[]; error?: string }> {
+  if (!window.timesheet?.listEntryCategories) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.listEntryCategories();
+}
+
+export async function saveEntryCategory(category: { id?: string; name: string; color?: string }): Promise<{ success: boolean; category?: EntryCategory; renamedEntries?: number; error?: string }> {
+  if (!window.timesheet?.saveEntryCategory) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.saveEntryCategory(category);
+}
+
+export async function deleteEntryCategory(categoryId: string): Promise<{ success: boolean; clearedEntries?: number; error?: string }> {
+  if (!window.timesheet?.deleteEntryCategory) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.deleteEntryCategory(categoryId);
+}
+
+export async function renderDescription(
+  templateId: string,
+  vars: Partial<Record<DescriptionTemplateVariable, string | null>>
+): Promise<{ success: boolean; description?: string; missing?: DescriptionTemplateVariable[]; error?: string }> {
+  if (!window.timesheet?.renderDescription) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.renderDescription(templateId, vars);
+}
+
+export interface NextEntrySuggestion {
+  date: string;
+  hours: number;
+  project: string;
+  tool: string | null;
+  chargeCode: string | null;
+  taskDescription: string;
+  confidence: number;
+  occurrences: number;
+  basis: 'follows-previous-block' | 'same-weekday' | 'recent-history';
+}
+
+export async function suggestNextEntry(date: string): Promise<{ success: boolean; suggestion?: NextEntrySuggestion | null; error?: string }> {
+  if (!window.timesheet?.suggestNextEntry) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.suggestNextEntry(date);
+}
+
+export async function previewCalendarImport(request: CalendarImportRequest): Promise<{ success: boolean; drafts?: CalendarDraftPreview[]; skipped?: Array<{ eventId: string; subject: string; reason: string }>; error?: string }> {
+  if (!window.timesheet?.previewCalendarImport) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.previewCalendarImport(request);
+}
+
+/**
+ * Imports the selected meetings as drafts, optionally all labelled with one category
+ */
+export async function importCalendarDrafts(drafts: CalendarDraftPreview[], category?: string): Promise<{ success: boolean; inserted?: number; duplicates?: number; error?: string }> {
+  if (!window.timesheet?.importCalendarDrafts) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  const res = await window.timesheet.importCalendarDrafts(
+    drafts.map(({ date, hours, project, tool, chargeCode, taskDescription, timeZone }) => ({
+      date,
+      hours,
+      project,
+      tool,
+      chargeCode,
+      taskDescription,
+      ...(timeZone ? { timeZone } : {}),
+      ...(category ? { category } : {})
+    }))
+  );
+  if (!res.success || !res.jobId) {
+    return { success: false, error: res.error ?? 'Import did not start' };
+  }
+  const job = await getJobResult<CalendarImportJobResult>(res.jobId);
+  return job.success ? { success: true, ...job.result } : { success: false, error: job.error };
+}
+
+export async function getToolUsage(query: ToolUsageQuery): Promise<{ success: boolean; startDate?: string; endDate?: string; rows?: ToolUsageRow[]; totalHours?: number; error?: string }> {
+  if (!window.timesheet?.getToolUsage) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getToolUsage(query);
+}
+
+export async function exportToolUsageCSV(query: ToolUsageQuery): Promise<{ success: boolean; csvContent?: string; rowCount?: number; filename?: string; error?: string }> {
+  if (!window.timesheet?.exportToolUsageCSV) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.exportToolUsageCSV(query);
+}
+
+export async function getQuotaStatus(): Promise<{ success: boolean; quotas?: ChargeCodeQuotaStatus[]; error?: string }> {
+  if (!window.timesheet?.getQuotaStatus) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getQuotaStatus();
+}
+
+export async function getDraftStats(): Promise<{ success: boolean; stats?: DraftStats; error?: string }> {
+  if (!window.timesheet?.getDraftStats) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getDraftStats();
+}
+
+export async function getQuarters(): Promise<{ success: boolean; quarters?: QuarterSummary[]; error?: string }> {
+  if (!window.timesheet?.getQuarters) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getQuarters();
+}
+
+export async function getAppLockState(): Promise<{ success: boolean; state?: AppLockState; error?: string }> {
+  if (!window.timesheet?.getAppLockState) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getAppLockState();
+}
+
+export async function getStatusCounts(): Promise<{ success: boolean; counts?: EntryStatusCounts; error?: string }> {
+  if (!window.timesheet?.getStatusCounts) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getStatusCounts();
+}
+
+export async function getActivityHeatmap(year: number): Promise<{ success: boolean; year?: number; days?: ActivityHeatmapDay[]; error?: string }> {
+  if (!window.timesheet?.getActivityHeatmap) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getActivityHeatmap(year);
+}
+
+export async function getMissingDays(query: { startDate: string; endDate: string }): Promise<{ success: boolean; startDate?: string; endDate?: string; dailyTargetHours?: number; days?: MissingTimesheetDay[]; error?: string }> {
+  if (!window.timesheet?.getMissingDays) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.getMissingDays(query);
+}
+
+export async function diffWeek(weekStart: string): Promise<{ success: boolean; diff?: WeekDiff; error?: string }> {
+  if (!window.timesheet?.diffWeek) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.diffWeek(weekStart);
+}
+
+export async function exportWeeklySummary(request: WeeklySummaryExportRequest): Promise<{ success: boolean; content?: string; entryCount?: number; totalHours?: number; filename?: string; error?: string }> {
+  if (!window.timesheet?.exportWeeklySummary) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.exportWeeklySummary(request);
+}
+
+export async function reconcileSubmissionKeys(text: string): Promise<{ success: boolean; keysFound?: number; matchedIds?: number[]; alreadyCompleteKeys?: string[]; unknownKeys?: string[]; error?: string }> {
+  if (!window.timesheet?.reconcileSubmissionKeys) {
+    return { success: false, error: 'Timesheet API not available' };
+  }
+  return window.timesheet.reconcileSubmissionKeys(text);
 }
 
 export function onSubmissionProgress(callback: (progress: { percent: number; current: number; total: number; message: string }) => void): void {
@@ -140,4 +504,12 @@ export function removeProgressListener(): void {
   window.timesheet?.removeProgressListener?.();
 }
 
+export function onStatusCounts(callback: (counts: EntryStatusCounts) => void): void {
+  window.timesheet?.onStatusCounts?.(callback);
+}
+
+export function removeStatusCountsListener(): void {
+  window.timesheet?.removeStatusCountsListener?.();
+}
+
 
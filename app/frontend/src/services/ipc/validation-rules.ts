@@ -0,0 +1,27 @@
+export async function listValidationRules(): Promise<{ success: boolean; rules?: ValidationRule[]; error?: string }> {
+  if (!window.validationRules?.list) {
+    return { success: false, error: 'Validation rules API not available' };
+  }
+  return window.validationRules.list();
+}
+
+export async function createValidationRule(token: string, rule: ValidationRuleInput): Promise<{ success: boolean; id?: number; error?: string }> {
+  if (!window.validationRules?.create) {
+    return { success: false, error: 'Validation rules API not available' };
+  }
+  return window.validationRules.create(token, rule);
+}
+
+export async function updateValidationRule(token: string, id: number, rule: ValidationRuleInput): Promise<{ success: boolean; error?: string }> {
+  if (!window.validationRules?.update) {
+    return { success: false, error: 'Validation rules API not available' };
+  }
+  return window.validationRules.update(token, id, rule);
+}
+
+export async function deleteValidationRule(token: string, id: number): Promise<{ success: boolean; error?: string }> {
+  if (!window.validationRules?.delete) {
+    return { success: false, error: 'Validation rules API not available' };
+  }
+  return window.validationRules.delete(token, id);
+}
@@ -68,4 +68,9 @@ export const mockAuthAPI = {
     }
     return null;
   },
+
+  // The mock backend never revokes sessions
+  onSessionRevoked: (_callback: (payload: { reason: string }) => void): void => {},
+
+  removeSessionRevokedListener: (): void => {},
 };
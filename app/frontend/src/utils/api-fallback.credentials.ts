@@ -27,6 +27,17 @@ export const mockCredentialsAPI = {
     };
   },
 
+  reveal: async (
+    _token: string,
+    service: string
+  ): Promise<{ success: boolean; email?: string; password?: string; error?: string }> => {
+    console.log("[MockAPI] Revealing credentials for:", service);
+    return {
+      success: false,
+      error: "Showing passwords is not available in development mode",
+    };
+  },
+
   delete: async (
     service: string
   ): Promise<{ success: boolean; message: string; changes: number }> => {
@@ -47,4 +47,32 @@ export const mockDatabaseAPI = {
       credentials: mockCredentials,
     };
   },
+
+  getArchivePage: async (
+    token: string,
+    request?: { cursor?: string; limit?: number }
+  ): Promise<{
+    success: boolean;
+    entries?: TimesheetEntry[];
+    count?: number;
+    nextCursor?: string | null;
+    totalCount?: number;
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Getting archive page");
+    if (!token) {
+      return { success: false, error: "Session token is required" };
+    }
+    const start = request?.cursor ? Number(request.cursor) || 0 : 0;
+    const limit = request?.limit ?? 500;
+    const entries = mockArchiveData.slice(start, start + limit);
+    const next = start + entries.length;
+    return {
+      success: true,
+      entries,
+      count: entries.length,
+      nextCursor: next < mockArchiveData.length ? String(next) : null,
+      ...(start === 0 ? { totalCount: mockArchiveData.length } : {}),
+    };
+  },
 };
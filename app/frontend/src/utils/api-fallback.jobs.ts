@@ -0,0 +1,47 @@
+/** Mock jobs finish as soon as they are queued */
+const mockJobs = new Map<string, JobSnapshot>();
+
+export const completeMockJob = (type: string, result: unknown): string => {
+  const id = `mock-job-${mockJobs.size + 1}`;
+  const now = new Date().toISOString();
+  mockJobs.set(id, {
+    id,
+    type,
+    status: "succeeded",
+    trigger: "manual",
+    percent: 100,
+    message: null,
+    result,
+    error: null,
+    runId: null,
+    createdAt: now,
+    startedAt: now,
+    finishedAt: now,
+  });
+  return id;
+};
+
+export const mockJobsAPI = {
+  getStatus: async (
+    jobId: string
+  ): Promise<{ success: boolean; job?: JobSnapshot; error?: string }> => {
+    const job = mockJobs.get(jobId);
+    return job ? { success: true, job } : { success: false, error: "Job not found" };
+  },
+
+  list: async (): Promise<{ success: boolean; jobs: JobSnapshot[] }> => ({
+    success: true,
+    jobs: [...mockJobs.values()].reverse(),
+  }),
+
+  cancel: async (): Promise<{ success: boolean; error?: string }> => ({
+    success: false,
+    error: "Job already finished",
+  }),
+
+  onJobProgress: (): void => {
+    console.log("[MockAPI] Job progress listener registered");
+  },
+
+  removeJobProgressListener: (): void => {},
+};
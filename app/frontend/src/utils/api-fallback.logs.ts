@@ -1,3 +1,10 @@
+import type {
+  CrashReportSummary,
+  LogQueryFilter,
+  LogQueryLine,
+  StartupTimings,
+} from "@/contracts/window.logs";
+
 export const mockLogsAPI = {
   getLogPath: async (
     _token: string
@@ -37,4 +44,56 @@ export const mockLogsAPI = {
       mimeType: format === "json" ? "application/json" : "text/plain",
     };
   },
+
+  query: async (
+    _token: string,
+    filter: LogQueryFilter = {}
+  ): Promise<{
+    success: boolean;
+    source?: "index" | "files";
+    lines?: LogQueryLine[];
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Querying logs:", filter);
+    return { success: true, source: "files", lines: [] };
+  },
+
+  listCrashReports: async (
+    _token: string
+  ): Promise<{ success: boolean; reports?: CrashReportSummary[]; error?: string }> => {
+    console.log("[MockAPI] Listing crash reports");
+    return { success: true, reports: [] };
+  },
+
+  exportCrashReports: async (
+    _token: string
+  ): Promise<{
+    success: boolean;
+    content?: string;
+    filename?: string;
+    mimeType?: string;
+    reportCount?: number;
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Exporting crash reports");
+    return {
+      success: true,
+      content: '{"reports": []}',
+      filename: `crash_reports_${new Date().toISOString().split("T")[0]}.json`,
+      mimeType: "application/json",
+      reportCount: 0,
+    };
+  },
+
+  getStartupTimings: async (): Promise<{
+    success: boolean;
+    timings?: StartupTimings;
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Getting startup timings");
+    return {
+      success: true,
+      timings: { phases: [], windowShownMs: null, deferredCompletedMs: null },
+    };
+  },
 };
@@ -1,5 +1,6 @@
 import type { TimesheetRow } from "./api-fallback.types";
 import { mockTimesheetData } from "./api-fallback.data";
+import { completeMockJob } from "./api-fallback.jobs";
 
 export const mockTimesheetAPI = {
   loadDraft: async (): Promise<{
@@ -34,39 +35,78 @@ export const mockTimesheetAPI = {
   },
 
   submit: async (): Promise<{
-    submitResult?: {
-      ok: boolean;
-      successCount: number;
-      removedCount: number;
-      totalProcessed: number;
-    };
-    dbPath?: string;
+    jobId?: string;
     error?: string;
   }> => {
     console.log("[MockAPI] Submitting timesheet");
     return {
-      submitResult: {
-        ok: true,
-        successCount: 1,
-        removedCount: 0,
-        totalProcessed: 1,
-      },
+      jobId: completeMockJob("timesheet-submission", {
+        submitResult: {
+          ok: true,
+          successCount: 1,
+          removedCount: 0,
+          totalProcessed: 1,
+        },
+      }),
     };
   },
 
   exportToCSV: async (): Promise<{
     success: boolean;
-    csvContent?: string;
-    entryCount?: number;
-    filename?: string;
+    jobId?: string;
     error?: string;
   }> => {
     console.log("[MockAPI] Exporting to CSV");
     return {
       success: true,
-      csvContent: "Date,Hours,Project\n2024-10-25,8.00,SheetPilot Development",
-      entryCount: 1,
-      filename: "timesheet_export_2024-10-25.csv",
+      jobId: completeMockJob("csv-export", {
+        csvContent: "Date,Hours,Project\n2024-10-25,8.00,SheetPilot Development",
+        entryCount: 1,
+        totals: { submittedHours: 8, correctedHours: 8, correctionCount: 0 },
+        filename: "timesheet_export_2024-10-25.csv",
+      }),
+    };
+  },
+
+  getDraftStats: async (): Promise<{
+    success: boolean;
+    stats?: DraftStats;
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Getting draft statistics");
+    const totalHours = mockTimesheetData.reduce((sum, row) => sum + (row.hours ?? 0), 0);
+    return {
+      success: true,
+      stats: {
+        draftCount: mockTimesheetData.length,
+        totalHours,
+        datesCovered: new Set(mockTimesheetData.map((row) => row.date)).size,
+        earliestDate: null,
+        latestDate: null,
+        undatedCount: 0,
+        quarters: [],
+      },
+    };
+  },
+
+  getQuarters: async (): Promise<{
+    success: boolean;
+    quarters?: QuarterSummary[];
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Getting quarters");
+    return { success: true, quarters: [] };
+  },
+
+  getStatusCounts: async (): Promise<{
+    success: boolean;
+    counts?: EntryStatusCounts;
+    error?: string;
+  }> => {
+    console.log("[MockAPI] Getting status counts");
+    return {
+      success: true,
+      counts: { drafts: mockTimesheetData.length, failed: 0, submitting: 0, queued: 0 },
     };
   },
 };
@@ -1,6 +1,7 @@
 import { mockAuthAPI } from "./api-fallback.auth";
 import { mockCredentialsAPI } from "./api-fallback.credentials";
 import { mockDatabaseAPI } from "./api-fallback.database";
+import { mockJobsAPI } from "./api-fallback.jobs";
 import { mockLogsAPI } from "./api-fallback.logs";
 import { mockTimesheetAPI } from "./api-fallback.timesheet";
 
@@ -59,6 +60,11 @@ export function initializeAPIFallback(): void {
       api: mockLogsAPI,
       log: "[APIFallback] Mock logs API initialized",
     },
+    {
+      key: "jobs",
+      api: mockJobsAPI,
+      log: "[APIFallback] Mock jobs API initialized",
+    },
   ];
 
   fallbackEntries.forEach((entry) =>
@@ -75,4 +81,5 @@ export {
   mockCredentialsAPI,
   mockDatabaseAPI,
   mockLogsAPI,
+  mockJobsAPI,
 };
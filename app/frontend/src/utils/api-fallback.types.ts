@@ -1,12 +1,4 @@
-export interface TimesheetRow {
-  id?: number;
-  date?: string;
-  hours?: number;
-  project?: string;
-  tool?: string | null;
-  chargeCode?: string | null;
-  taskDescription?: string;
-}
+export type { TimesheetRow } from "@sheetpilot/shared";
 
 export interface TimesheetEntry {
   id: number;
@@ -0,0 +1,53 @@
+/**
+ * Clock skew
+ *
+ * Keeps the backend's startup clock check so date errors in the grid can
+ * say when the PC clock is the real problem. The status is read from app
+ * health when the app loads and updated from the `app:clockSkew` event.
+ */
+
+import { annotateWithClockSkew } from "@sheetpilot/shared";
+import { getAppHealth, onClockSkew, removeClockSkewListener } from "@/services/ipc/app";
+import { logWarn } from "@/services/ipc/logger";
+
+let clockSkew: ClockSkewStatus | null = null;
+
+function setClockSkew(status: ClockSkewStatus | null): void {
+  clockSkew = status;
+  if (status?.skewed) {
+    logWarn("System clock is off", { skewMs: status.skewMs, source: status.source });
+  }
+}
+
+/**
+ * Starts tracking the clock check; returns a function that stops it
+ */
+export function watchClockSkew(): () => void {
+  onClockSkew(setClockSkew);
+  void getAppHealth()
+    .then((response) => {
+      if (response.health?.clock) setClockSkew(response.health.clock);
+    })
+    .catch(() => undefined);
+  return () => removeClockSkewListener();
+}
+
+export function getClockSkew(): ClockSkewStatus | null {
+  return clockSkew;
+}
+
+/**
+ * Adds the clock note to a date error when the clock is off
+ */
+export function withClockSkewNote(message: string): string {
+  return clockSkew?.skewed
+    ? annotateWithClockSkew(message, clockSkew.skewMs, clockSkew.thresholdMs)
+    : message;
+}
+
+/**
+ * Forgets the clock check (for tests)
+ */
+export function resetClockSkew(): void {
+  clockSkew = null;
+}
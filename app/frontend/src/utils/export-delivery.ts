@@ -0,0 +1,66 @@
+/**
+ * Export delivery
+ *
+ * Saves export content as a file when the app can (save dialog or Downloads
+ * folder). On locked-down kiosk builds where neither is available
+ * (`exportMode: 'string'` in app health), the content is copied to the
+ * clipboard instead so the export is not silently lost.
+ */
+
+import { getAppHealth } from "@/services/ipc/app";
+
+export type ExportDelivery = "downloaded" | "copied";
+
+let exportModePromise: Promise<AppHealth["capabilities"]["exportMode"]> | null = null;
+
+/**
+ * How exports are delivered; checked once per session. Without the health
+ * API (e.g. the browser dev build) files are downloaded as before.
+ */
+export function getExportMode(): Promise<AppHealth["capabilities"]["exportMode"]> {
+  exportModePromise ??= getAppHealth()
+    .then((response) => response.health?.capabilities.exportMode ?? "download")
+    .catch(() => "download" as const);
+  return exportModePromise;
+}
+
+/**
+ * Forgets the cached export mode (for tests)
+ */
+export function resetExportMode(): void {
+  exportModePromise = null;
+}
+
+function downloadFile(content: string, filename: string, mimeType: string): void {
+  const blob = new Blob([content], { type: mimeType });
+  const downloadUrl = URL.createObjectURL(blob);
+  const a = document.createElement("a");
+  a.href = downloadUrl;
+  a.download = filename;
+  document.body.appendChild(a);
+  a.click();
+  document.body.removeChild(a);
+  URL.revokeObjectURL(downloadUrl);
+}
+
+/**
+ * Saves `content` as `filename`, or copies it to the clipboard when files
+ * cannot be saved on this device
+ *
+ * @throws Error if the content could be neither saved nor copied
+ */
+export async function deliverExport(
+  content: string,
+  filename: string,
+  mimeType = "text/plain"
+): Promise<ExportDelivery> {
+  if ((await getExportMode()) === "download") {
+    downloadFile(content, filename, mimeType);
+    return "downloaded";
+  }
+  if (!navigator.clipboard?.writeText) {
+    throw new Error("Saving files is disabled on this device and the clipboard is unavailable");
+  }
+  await navigator.clipboard.writeText(content);
+  return "copied";
+}
@@ -2,6 +2,9 @@
  * Theme Settings Backend Integration
  * Handles loading and saving theme preferences to backend settings
  * Extracted from theme-manager.ts to reduce complexity
+ *
+ * While someone is logged in the theme is a per-user preference; the machine
+ * setting is only used before login.
  */
 
 import {
@@ -29,10 +32,30 @@ function isValidThemeMode(value: unknown): value is ThemeMode {
   return value === "auto" || value === "light" || value === "dark";
 }
 
+/**
+ * Session token of the logged-in user, if any
+ */
+function getSessionToken(): string | null {
+  try {
+    return localStorage.getItem("sessionToken");
+  } catch {
+    return null;
+  }
+}
+
 /**
  * Load theme from backend settings API
  */
 async function loadFromBackend(): Promise<ThemeMode | null> {
+  const token = getSessionToken();
+  if (token && window.preferences?.get) {
+    const response = await window.preferences.get(token);
+    if (response?.success) {
+      const mode = response.preferences?.themeMode;
+      return isValidThemeMode(mode) ? mode : null;
+    }
+  }
+
   if (!window.settings?.get) {
     return null;
   }
@@ -49,6 +72,14 @@ async function loadFromBackend(): Promise<ThemeMode | null> {
  * Save theme to backend settings API
  */
 async function saveToBackend(mode: ThemeMode): Promise<boolean> {
+  const token = getSessionToken();
+  if (token && window.preferences?.set) {
+    const response = await window.preferences.set(token, { themeMode: mode });
+    if (response?.success) {
+      return true;
+    }
+  }
+
   if (!window.settings?.set) {
     return false;
   }
@@ -7,6 +7,7 @@
  * @version 1.0.0
  */
 
+import { SubmissionStatus } from "@sheetpilot/shared";
 import type { WeekSummary } from "./weekSummary";
 
 /**
@@ -73,7 +74,7 @@ export function filterWeekEntries(
   saturday: Date
 ): TimesheetEntry[] {
   return entries.filter((entry) => {
-    if (entry.status !== "Complete") return false;
+    if (entry.status !== SubmissionStatus.COMPLETE) return false;
 
     const normalizedDate = normalizeDate(entry.date);
     if (!normalizedDate) return false;
@@ -14,6 +14,7 @@ import {
   convertMapToSummaries,
 } from "./weekSummary.helpers";
 import { getWeekBounds } from "./weekSummary.formatting";
+import { SubmissionStatus } from "@sheetpilot/shared";
 
 /**
  * Submitted timesheet entry from archive
@@ -104,9 +105,8 @@ export function getAllWeeksWithData(entries: TimesheetEntry[]): string[] {
   const weekSet = new Set<string>();
 
   // Filter to only completed entries
-  // Note: Status can be "Complete" (string) or other values
   const completedEntries = entries.filter((entry) => {
-    return entry.status === "Complete";
+    return entry.status === SubmissionStatus.COMPLETE;
   });
 
   for (const entry of completedEntries) {
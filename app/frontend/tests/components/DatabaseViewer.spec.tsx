@@ -26,6 +26,7 @@ vi.mock('@handsontable/react-wrapper', () => ({
 }));
 
 import Archive from '../../src/components/archive/DatabaseViewer';
+import { filterArchiveEntries } from '../../src/components/archive/DatabaseViewer.helpers';
 import { render, screen } from '@testing-library/react';
 import '@testing-library/jest-dom/vitest';
 
@@ -200,4 +201,20 @@ describe('Archive Non-Editability', () => {
   });
 });
 
+describe('Archive search', () => {
+  const entries = [
+    { id: 1, project: 'FL-Carver Techs', task_description: 'Calibration', external_ref: 'WO-104233' },
+    { id: 2, project: 'Training', task_description: 'Safety refresher for WO-99', external_ref: null },
+    { id: 3, project: 'PTO/RTO', task_description: 'Vacation' }
+  ];
+
+  it('matches the external reference, description or project, ignoring case', () => {
+    expect(filterArchiveEntries(entries, 'wo-104').map((entry) => entry.id)).toEqual([1]);
+    expect(filterArchiveEntries(entries, 'WO-').map((entry) => entry.id)).toEqual([1, 2]);
+    expect(filterArchiveEntries(entries, 'pto').map((entry) => entry.id)).toEqual([3]);
+  });
 
+  it('keeps every entry for a blank query', () => {
+    expect(filterArchiveEntries(entries, '  ')).toBe(entries);
+  });
+});
@@ -0,0 +1,53 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { deliverExport, getExportMode, resetExportMode } from '../../src/utils/export-delivery';
+
+const health = (exportMode: 'download' | 'string') => ({
+  success: true,
+  health: {
+    version: '1.6.0',
+    database: { connected: true },
+    capabilities: { fileDialogs: false, downloadsWritable: exportMode === 'download', exportMode, checkedAt: '' },
+    clock: null
+  }
+});
+
+describe('export-delivery', () => {
+  const writeText = vi.fn(async () => undefined);
+
+  beforeEach(() => {
+    resetExportMode();
+    writeText.mockClear();
+    Object.defineProperty(navigator, 'clipboard', { value: { writeText }, configurable: true });
+    URL.createObjectURL = vi.fn(() => 'blob:export');
+    URL.revokeObjectURL = vi.fn();
+  });
+
+  afterEach(() => {
+    delete window.api;
+  });
+
+  it('downloads when the health API is missing', async () => {
+    expect(await getExportMode()).toBe('download');
+    expect(await deliverExport('a,b', 'export.csv', 'text/csv')).toBe('downloaded');
+    expect(URL.createObjectURL).toHaveBeenCalled();
+    expect(writeText).not.toHaveBeenCalled();
+  });
+
+  it('copies to the clipboard when files cannot be saved', async () => {
+    window.api = { ping: vi.fn(), getHealth: vi.fn(async () => health('string')) };
+
+    expect(await deliverExport('a,b', 'export.csv', 'text/csv')).toBe('copied');
+    expect(writeText).toHaveBeenCalledWith('a,b');
+    expect(URL.createObjectURL).not.toHaveBeenCalled();
+  });
+
+  it('checks app health once per session', async () => {
+    const getHealth = vi.fn(async () => health('download'));
+    window.api = { ping: vi.fn(), getHealth };
+
+    await deliverExport('one', 'one.txt');
+    await deliverExport('two', 'two.txt');
+
+    expect(getHealth).toHaveBeenCalledTimes(1);
+  });
+});
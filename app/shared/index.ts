@@ -22,6 +22,28 @@ export * from './src/types/contracts/ISubmissionService';
 // Types - Errors
 export * from './src/types/errors';
 
+// Types - Submission status
+export * from './src/types/submission-status';
+
+// Types - Entry kind
+export * from './src/types/entry-kind';
+
+// Types - Timesheet row (IPC/grid contract)
+export * from './src/types/timesheet-row';
+
 // Utils
 export * from './src/utils/format-conversions';
+export * from './src/utils/clock-skew';
+export {
+  MINUTES_PER_DAY,
+  HOURS_INCREMENT,
+  MIN_ENTRY_HOURS,
+  MAX_ENTRY_HOURS,
+  minutesBetween,
+  hoursBetweenTimes,
+  isQuarterHourIncrement,
+  isValidHours,
+  roundMinutesToQuarterHours,
+  formatHours
+} from './src/utils/time-utils';
 
@@ -139,5 +139,15 @@ export function initializeLogging(): void {
     });
 }
 
+/**
+ * Flush pending log writes before the process exits
+ * Switches the file transport to synchronous writes so nothing logged during
+ * shutdown is lost, then records a final entry.
+ */
+export function flushLogging(): void {
+    log.transports.file.sync = true;
+    appLogger.info('Logging system shutting down', { sessionId: SESSION_ID });
+}
+
 // Export the base electron-log for advanced use cases
 export { log as electronLog };
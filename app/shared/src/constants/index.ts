@@ -76,6 +76,11 @@ export const appSettings = {
    * This can be toggled via Settings UI
    */
   browserHeadless: false,
+  /**
+   * Outline the element being filled and show the current step in the
+   * browser window. Only applies when the browser is visible.
+   */
+  highlightInteractions: false,
 };
 
 /**
@@ -86,6 +91,15 @@ export function getBrowserHeadless(): boolean {
   return appSettings.browserHeadless;
 }
 
+/**
+ * Set interaction highlighting for visible browser runs
+ * Should only be called from settings handlers
+ */
+export function setHighlightInteractions(value: boolean): void {
+  appSettings.highlightInteractions = value;
+  getLogger()?.info("Interaction highlighting updated", { enabled: value });
+}
+
 /**
  * Set browser headless mode
  * Should only be called from settings handlers
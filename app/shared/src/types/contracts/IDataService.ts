@@ -10,6 +10,7 @@
  */
 
 import type { IPlugin } from "@sheetpilot/shared/plugin-types";
+import type { TimesheetEntryStatus } from "../submission-status";
 
 /**
  * Timesheet entry data structure
@@ -25,6 +26,10 @@ export interface TimesheetEntry {
   tool?: string | null;
   chargeCode?: string | null;
   taskDescription: string;
+  /** Idempotency key sent with the submission so duplicates can be detected */
+  submissionKey?: string | null;
+  /** Date (mm/dd/yyyy) of the submitted entry this amendment corrects */
+  correctionOf?: string | null;
 }
 
 /**
@@ -42,8 +47,14 @@ export interface DbTimesheetEntry {
   tool?: string | null;
   detail_charge_code?: string | null;
   task_description: string;
-  status?: string | null;
+  status?: TimesheetEntryStatus;
   submitted_at?: string | null;
+  /** When the entry was first saved */
+  created_at?: string | null;
+  /** Last edit to the entry's fields (status changes excluded) */
+  updated_at?: string | null;
+  /** Run history ID of the submission that sent it */
+  submitted_run_id?: number | null;
 }
 
 /**
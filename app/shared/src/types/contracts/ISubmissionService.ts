@@ -22,6 +22,80 @@ export interface ValidationResult {
   warnings?: string[];
 }
 
+/**
+ * How one field was located while filling a row
+ */
+export interface SubmissionFieldLocator {
+  locator: string;
+  /** Field definition, the quarter's override, the project's tool dropdown, or a selector hotfix */
+  source: "default" | "quarter-override" | "project" | "hotfix";
+}
+
+/**
+ * Validation feedback Smartsheet showed when it rejected a row
+ */
+export interface SubmissionRowRejection {
+  /** One-line reason, stored as the entry's failure reason */
+  message: string;
+  /** Form-level error banner text */
+  banner: string | null;
+  /** Error text per field key (e.g. `hours`) */
+  fieldErrors: Record<string, string>;
+}
+
+/**
+ * Outcome of one entry on a form
+ */
+export interface SubmissionRowReport {
+  /** Index into the rows sent to the form */
+  rowIndex: number;
+  /** Entry the row came from, once the index is mapped back */
+  entryId: number | null;
+  outcome: "submitted" | "failed" | "skipped";
+  error: string | null;
+  fillMs: number | null;
+  submitMs: number | null;
+  /** Submit clicks made, including retries */
+  submitAttempts: number;
+  /** Submits repeated because Smartsheet throttled the request */
+  throttleRetries: number;
+  fields: Record<string, SubmissionFieldLocator>;
+  /** Failure screenshot path, when one was captured */
+  screenshot: string | null;
+  /** Set when the form rejected the row with validation errors */
+  rejection?: SubmissionRowRejection;
+  /** Values captured by submission postconditions (e.g. a submission ID) */
+  captures?: Record<string, string>;
+  /** Postcondition that failed on the last submit attempt */
+  postconditionFailure?: string;
+}
+
+/**
+ * What happened on one form during a submission (one per quarter form)
+ */
+export interface SubmissionFormRunReport {
+  formId: string;
+  formUrl: string;
+  quarterId: string | null;
+  fillProfile: string;
+  startedAt: string;
+  finishedAt: string | null;
+  durationMs: number | null;
+  loginMs: number | null;
+  titleCheck: "passed" | "failed" | "skipped";
+  /** Times the run logged in again after its session expired */
+  relogins?: number;
+  /** Selector hotfix applied to this run, if any */
+  selectorHotfixId?: string;
+  /** Version string of the browser the run used */
+  browserVersion?: string;
+  /** Set when the browser version is outside the tested range */
+  browserVersionWarning?: string;
+  rows: SubmissionRowReport[];
+  /** Error that stopped the whole form run, if any */
+  error: string | null;
+}
+
 /**
  * Submission result for timesheet entries
  */
@@ -33,6 +107,14 @@ export interface SubmissionResult {
   successCount: number;
   removedCount: number;
   error?: string;
+  /** Per-form detail for run artifacts, when the service records it */
+  formRuns?: SubmissionFormRunReport[];
+  /** Entries the form rejected with validation errors (a subset of removedIds) */
+  rejectedEntries?: Array<SubmissionRowRejection & { entryId: number }>;
+  /** Values captured by submission postconditions, per submitted entry */
+  capturedValues?: Array<{ entryId: number; values: Record<string, string> }>;
+  /** Browser version warnings from the form runs (kept in run history) */
+  browserWarnings?: string[];
 }
 
 /**
@@ -0,0 +1,47 @@
+/**
+ * @fileoverview Entry Kind
+ *
+ * What an entry's hours were spent on. Work is the default (a NULL kind
+ * column); PTO and holidays are time off and need no project, tool or
+ * charge code in the grid. How non-work kinds reach the form is configured
+ * per submission service profile (see backend logic/entry-kinds.ts).
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+export const ENTRY_KINDS = ['work', 'pto', 'holiday', 'training'] as const;
+
+export type EntryKind = typeof ENTRY_KINDS[number];
+
+/** Kind of entries saved without one */
+export const DEFAULT_ENTRY_KIND: EntryKind = 'work';
+
+/** Grid and report labels */
+export const ENTRY_KIND_LABELS: Readonly<Record<EntryKind, string>> = {
+  work: 'Work',
+  pto: 'PTO',
+  holiday: 'Holiday',
+  training: 'Training'
+};
+
+export function isEntryKind(value: unknown): value is EntryKind {
+  return typeof value === 'string' && (ENTRY_KINDS as readonly string[]).includes(value);
+}
+
+/**
+ * Kind of an entry, treating missing or unknown values as work
+ */
+export function parseEntryKind(value: unknown): EntryKind {
+  return isEntryKind(value) ? value : DEFAULT_ENTRY_KIND;
+}
+
+/**
+ * Whether the kind needs a project (and so a tool and charge code when the
+ * project uses them); time off does not
+ */
+export function entryKindNeedsProject(kind: unknown): boolean {
+  const parsed = parseEntryKind(kind);
+  return parsed !== 'pto' && parsed !== 'holiday';
+}
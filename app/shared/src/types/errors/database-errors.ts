@@ -49,3 +49,17 @@ export class DatabaseTransactionError extends DatabaseError {
     super(`Transaction failed: ${operation}`, 'DB_TRANSACTION_ERROR', { ...context, operation });
   }
 }
+
+/**
+ * Database was written by a newer app version, so this version refuses to
+ * change it
+ */
+export class DatabaseSchemaTooNewError extends DatabaseError {
+  constructor(context: Record<string, unknown> = {}) {
+    super(
+      'This database was created by a newer version of Sheetpilot. Install the latest version to make changes.',
+      'DB_SCHEMA_TOO_NEW',
+      context
+    );
+  }
+}
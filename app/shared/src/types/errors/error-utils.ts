@@ -51,6 +51,10 @@ export function extractErrorContext(error: unknown): Record<string, unknown> {
  */
 export function isRetryableError(error: unknown): boolean {
     if (error instanceof AppError) {
+        // Retrying cannot make a newer schema writable
+        if (error.code === 'DB_SCHEMA_TOO_NEW') {
+            return false;
+        }
         return error.category === ErrorCategory.NETWORK ||
                error.category === ErrorCategory.DATABASE;
     }
@@ -12,6 +12,7 @@ export {
     DatabaseConnectionError,
     DatabaseQueryError,
     DatabaseSchemaError,
+    DatabaseSchemaTooNewError,
     DatabaseTransactionError
 } from './database-errors';
 
@@ -31,7 +32,8 @@ export {
     SubmissionTimeoutError,
     SubmissionServiceUnavailableError,
     SubmissionFailedError,
-    NoEntriesToSubmitError
+    NoEntriesToSubmitError,
+    SubmissionLockedError
 } from './submission-errors';
 
 // Validation errors
@@ -69,3 +69,12 @@ export class NoEntriesToSubmitError extends SubmissionError {
         );
     }
 }
+
+/**
+ * Entries cannot be changed while a submission is running
+ */
+export class SubmissionLockedError extends SubmissionError {
+    constructor(message: string = 'A submission is running', context: Record<string, unknown> = {}) {
+        super(message, 'SUBMISSION_LOCKED', context);
+    }
+}
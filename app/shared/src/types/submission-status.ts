@@ -0,0 +1,53 @@
+/**
+ * @fileoverview Submission Status
+ *
+ * Values stored in the timesheet status column. A NULL status means the
+ * entry is still a draft; every other value must come from SubmissionStatus
+ * so queries, responses and the recovery sweep agree on the spelling.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+/**
+ * Submission statuses of a timesheet entry
+ */
+export const SubmissionStatus = {
+  /** Being sent to the form by the current submission */
+  IN_PROGRESS: 'in_progress',
+  /** Accepted by the form; the row is locked */
+  COMPLETE: 'Complete'
+} as const;
+
+export type SubmissionStatus = typeof SubmissionStatus[keyof typeof SubmissionStatus];
+
+/** Status column value: a submission status, or null for drafts */
+export type TimesheetEntryStatus = SubmissionStatus | null;
+
+const SUBMISSION_STATUS_VALUES: readonly string[] = Object.values(SubmissionStatus);
+
+/**
+ * Checks that a value is exactly one of the known submission statuses
+ */
+export function isSubmissionStatus(value: unknown): value is SubmissionStatus {
+  return typeof value === 'string' && SUBMISSION_STATUS_VALUES.includes(value);
+}
+
+/**
+ * Reads a status column value, accepting case and whitespace variants
+ *
+ * @returns The canonical status, null for drafts, or undefined if the value is not a known status
+ */
+export function parseSubmissionStatus(value: unknown): TimesheetEntryStatus | undefined {
+  if (value === null || value === undefined) {
+    return null;
+  }
+  if (typeof value !== 'string') {
+    return undefined;
+  }
+  const normalized = value.trim().toLowerCase();
+  return Object.values(SubmissionStatus).find(
+    (status) => status.toLowerCase() === normalized
+  );
+}
@@ -0,0 +1,120 @@
+/**
+ * @fileoverview Timesheet Row Contract
+ *
+ * The editor grid, the preload bridge and the draft IPC handlers all pass
+ * timesheet rows around in camelCase, while the database returns snake_case
+ * columns. Each side used to declare its own copy of the row and do its own
+ * renaming, so a field added on one side (e.g. `failureReason`) silently
+ * arrived as undefined on the other. This is the one definition of the row
+ * and the one place the columns are renamed.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+import { isEntryKind, type EntryKind } from './entry-kind';
+
+/**
+ * Timesheet row as exchanged over IPC and edited in the grid
+ *
+ * All fields are optional so partially entered rows can be saved.
+ */
+export interface TimesheetRow {
+  /** Database ID (assigned after first save) */
+  id?: number;
+  /** MM/DD/YYYY (YYYY-MM-DD is accepted and normalized) */
+  date?: string;
+  /** Decimal hours in 15-minute increments, 0.25 to 24.0 */
+  hours?: number;
+  project?: string;
+  /** Null when the project does not use tools */
+  tool?: string | null;
+  /** Null when the tool does not need a charge code */
+  chargeCode?: string | null;
+  taskDescription?: string;
+  /** Submitted entry this draft amends (set by the amend flow) */
+  amendsId?: number;
+  /** Why Smartsheet rejected the entry's last submission (read-only) */
+  failureReason?: string;
+  /** IANA zone the work was done in when away from home; date and hours stay in the home zone */
+  timeZone?: string;
+  /** Work order number or other reference in the MES */
+  externalRef?: string;
+  /** Local label for the kind of work (see the managed category list); never submitted */
+  category?: string;
+  /** Work, PTO, holiday or training; missing means work */
+  kind?: EntryKind;
+  /** When the entry's fields were last saved (read-only) */
+  updatedAt?: string;
+}
+
+/**
+ * Timesheet table columns a row is built from
+ */
+export interface TimesheetRowSource {
+  id: number;
+  date: string;
+  hours: number | null;
+  project: string;
+  tool?: string | null;
+  detail_charge_code?: string | null;
+  task_description: string;
+  amends_id?: number | null;
+  failure_reason?: string | null;
+  time_zone?: string | null;
+  external_ref?: string | null;
+  category?: string | null;
+  entry_kind?: string | null;
+  updated_at?: string | null;
+}
+
+/**
+ * Column each row field is read from; adding a field to `TimesheetRow`
+ * without mapping it here fails the typecheck
+ */
+export const TIMESHEET_ROW_COLUMNS: Readonly<Record<keyof TimesheetRow, keyof TimesheetRowSource>> = {
+  id: 'id',
+  date: 'date',
+  hours: 'hours',
+  project: 'project',
+  tool: 'tool',
+  chargeCode: 'detail_charge_code',
+  taskDescription: 'task_description',
+  amendsId: 'amends_id',
+  failureReason: 'failure_reason',
+  timeZone: 'time_zone',
+  externalRef: 'external_ref',
+  category: 'category',
+  kind: 'entry_kind',
+  updatedAt: 'updated_at'
+};
+
+/**
+ * Converts a timesheet table row to the IPC/grid shape
+ *
+ * Empty tool and charge code become null; missing hours, amendment link,
+ * failure reason, time zone, external reference, category, kind and
+ * last-saved time are left out rather than sent as null. Only non-work kinds
+ * are sent, so rows without a kind read as work.
+ */
+export function toTimesheetRow(source: TimesheetRowSource): TimesheetRow {
+  return {
+    id: source.id,
+    date: source.date,
+    ...(source.hours !== null ? { hours: source.hours } : {}),
+    project: source.project,
+    tool: source.tool || null,
+    chargeCode: source.detail_charge_code || null,
+    taskDescription: source.task_description,
+    ...(source.amends_id ? { amendsId: source.amends_id } : {}),
+    ...(source.failure_reason ? { failureReason: source.failure_reason } : {}),
+    ...(source.time_zone ? { timeZone: source.time_zone } : {}),
+    ...(source.external_ref ? { externalRef: source.external_ref } : {}),
+    ...(source.category ? { category: source.category } : {}),
+    ...(source.entry_kind && isEntryKind(source.entry_kind) && source.entry_kind !== 'work'
+      ? { kind: source.entry_kind }
+      : {}),
+    ...(source.updated_at ? { updatedAt: source.updated_at } : {})
+  };
+}
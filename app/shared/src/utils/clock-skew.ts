@@ -0,0 +1,69 @@
+/**
+ * @fileoverview Clock Skew Wording
+ *
+ * A PC clock that is days or weeks off makes quarter checks, backfill
+ * warnings and session expiry misbehave in ways that look like bugs. The
+ * backend measures the skew at startup; these helpers turn it into the note
+ * appended to date and session errors so backend and renderer word it the
+ * same way.
+ *
+ * Skew is local time minus reference time: positive means the clock is ahead.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+/** Skew below this is ignored (default when settings do not override it) */
+export const CLOCK_SKEW_THRESHOLD_MS = 10 * 60 * 1000;
+
+const MINUTE_MS = 60 * 1000;
+const HOUR_MS = 60 * MINUTE_MS;
+const DAY_MS = 24 * HOUR_MS;
+
+const plural = (count: number, unit: string): string => `${count} ${unit}${count === 1 ? '' : 's'}`;
+
+/**
+ * Rough size of a skew, in the largest whole unit ("3 weeks", "5 hours")
+ */
+export function formatClockSkew(skewMs: number): string {
+  const abs = Math.abs(skewMs);
+  if (abs >= 14 * DAY_MS) return plural(Math.round(abs / (7 * DAY_MS)), 'week');
+  if (abs >= DAY_MS) return plural(Math.round(abs / DAY_MS), 'day');
+  if (abs >= HOUR_MS) return plural(Math.round(abs / HOUR_MS), 'hour');
+  return plural(Math.max(1, Math.round(abs / MINUTE_MS)), 'minute');
+}
+
+/**
+ * Whether a measured skew is large enough to warn about
+ */
+export function isClockSkewed(
+  skewMs: number | null | undefined,
+  thresholdMs: number = CLOCK_SKEW_THRESHOLD_MS
+): skewMs is number {
+  return typeof skewMs === 'number' && Number.isFinite(skewMs) && Math.abs(skewMs) >= thresholdMs;
+}
+
+/**
+ * Sentence describing the skew, e.g. "Your computer clock is about 3 weeks behind"
+ */
+export function describeClockSkew(skewMs: number): string {
+  return `Your computer clock is about ${formatClockSkew(skewMs)} ${skewMs > 0 ? 'ahead' : 'behind'}`;
+}
+
+/**
+ * Appends the clock note to an error when the skew is past the threshold;
+ * otherwise returns the message unchanged
+ *
+ * @example
+ * annotateWithClockSkew('Date must be within allowed quarter range', -21 * 86400000)
+ * // 'Date must be within allowed quarter range (Your computer clock is about 3 weeks behind; fix the clock and restart SheetPilot)'
+ */
+export function annotateWithClockSkew(
+  message: string,
+  skewMs: number | null | undefined,
+  thresholdMs: number = CLOCK_SKEW_THRESHOLD_MS
+): string {
+  if (!isClockSkewed(skewMs, thresholdMs)) return message;
+  return `${message} (${describeClockSkew(skewMs)}; fix the clock and restart SheetPilot)`;
+}
@@ -9,45 +9,8 @@
  * @since 2025
  */
 
-/**
- * Converts time string (HH:mm) to minutes since midnight
- * 
- * @param timeStr - Time string in HH:mm format (e.g., "08:00", "17:30")
- * @returns Minutes since midnight (0-1439)
- * @throws Error if time format is invalid
- * 
- * @example
- * parseTimeToMinutes("08:00") // returns 480
- * parseTimeToMinutes("17:30") // returns 1050
- */
-export function parseTimeToMinutes(timeStr: string): number {
-  const parts = timeStr.split(':');
-  if (parts.length !== 2 || !parts[0] || !parts[1]) {
-    throw new Error(`Invalid time format: ${timeStr}. Expected HH:mm`);
-  }
-  const hours = parseInt(parts[0], 10);
-  const minutes = parseInt(parts[1], 10);
-  if (isNaN(hours) || isNaN(minutes)) {
-    throw new Error(`Invalid time format: ${timeStr}. Expected HH:mm`);
-  }
-  return hours * 60 + minutes;
-}
-
-/**
- * Converts minutes since midnight to time string (HH:mm)
- * 
- * @param minutes - Minutes since midnight (0-1439)
- * @returns Time string in HH:mm format (e.g., "08:00", "17:30")
- * 
- * @example
- * formatMinutesToTime(480)  // returns "08:00"
- * formatMinutesToTime(1050) // returns "17:30"
- */
-export function formatMinutesToTime(minutes: number): string {
-  const hours = Math.floor(minutes / 60);
-  const mins = minutes % 60;
-  return `${hours.toString().padStart(2, '0')}:${mins.toString().padStart(2, '0')}`;
-}
+// Time parsing and formatting live in time-utils; re-exported for existing imports
+export { parseTimeToMinutes, formatMinutesToTime } from './time-utils';
 
 /**
  * Converts date from YYYY-MM-DD to MM/DD/YYYY format
@@ -0,0 +1,134 @@
+/**
+ * @fileoverview Time Utilities
+ *
+ * Single implementation of clock-time parsing and formatting, quarter-hour
+ * increments and overnight duration math. Backend validation, the data
+ * services and the renderer all use these so they agree on edge cases.
+ *
+ * @author Andrew Hughes
+ * @version 1.0.0
+ * @since 2025
+ */
+
+/** Minutes in one day */
+export const MINUTES_PER_DAY = 24 * 60;
+
+/** Hours are recorded in 15-minute increments */
+export const HOURS_INCREMENT = 0.25;
+
+/** Smallest hours value an entry may have */
+export const MIN_ENTRY_HOURS = 0.25;
+
+/** Largest hours value an entry (or a single day) may have */
+export const MAX_ENTRY_HOURS = 24.0;
+
+/** Tolerance for floating point error when checking increments */
+const INCREMENT_TOLERANCE = 0.0001;
+
+const TIME_PATTERN = /^(\d{1,2}):(\d{2})$/;
+
+/**
+ * Converts time string (HH:mm) to minutes since midnight
+ *
+ * @param timeStr - Time string in HH:mm format (e.g., "08:00", "17:30")
+ * @returns Minutes since midnight (0-1439)
+ * @throws Error if time format is invalid
+ *
+ * @example
+ * parseTimeToMinutes("08:00") // returns 480
+ * parseTimeToMinutes("17:30") // returns 1050
+ */
+export function parseTimeToMinutes(timeStr: string): number {
+  const match = TIME_PATTERN.exec(timeStr.trim());
+  if (!match?.[1] || !match[2]) {
+    throw new Error(`Invalid time format: ${timeStr}. Expected HH:mm`);
+  }
+  const hours = parseInt(match[1], 10);
+  const minutes = parseInt(match[2], 10);
+  if (hours > 23 || minutes > 59) {
+    throw new Error(`Invalid time format: ${timeStr}. Expected HH:mm`);
+  }
+  return hours * 60 + minutes;
+}
+
+/**
+ * Converts minutes since midnight to time string (HH:mm)
+ *
+ * Values of a day or more are not wrapped, so durations such as 25:00 can be shown.
+ *
+ * @param minutes - Minutes since midnight (0-1439)
+ * @returns Time string in HH:mm format (e.g., "08:00", "17:30")
+ *
+ * @example
+ * formatMinutesToTime(480)  // returns "08:00"
+ * formatMinutesToTime(1050) // returns "17:30"
+ */
+export function formatMinutesToTime(minutes: number): string {
+  const whole = Math.round(minutes);
+  const hours = Math.floor(whole / 60);
+  const mins = whole % 60;
+  return `${hours.toString().padStart(2, '0')}:${mins.toString().padStart(2, '0')}`;
+}
+
+/**
+ * Minutes from start to end; an end earlier than the start is taken to be on the next day
+ *
+ * @example
+ * minutesBetween(1320, 120) // returns 240 (22:00 to 02:00)
+ */
+export function minutesBetween(startMinutes: number, endMinutes: number): number {
+  const diff = endMinutes - startMinutes;
+  return diff < 0 ? diff + MINUTES_PER_DAY : diff;
+}
+
+/**
+ * Hours between two HH:mm times, crossing midnight when the end is earlier
+ *
+ * @throws Error if either time is invalid
+ */
+export function hoursBetweenTimes(start: string, end: string): number {
+  return minutesBetween(parseTimeToMinutes(start), parseTimeToMinutes(end)) / 60;
+}
+
+/**
+ * Checks that hours fall on a 15-minute boundary
+ */
+export function isQuarterHourIncrement(hours: number): boolean {
+  const remainder = (hours / HOURS_INCREMENT) % 1;
+  return (
+    Math.abs(remainder) < INCREMENT_TOLERANCE ||
+    Math.abs(remainder - 1) < INCREMENT_TOLERANCE
+  );
+}
+
+/**
+ * Checks if hours value is valid for an entry
+ *
+ * Validates that hours is:
+ * - A number
+ * - In 15-minute increments (multiple of 0.25)
+ * - Within range: 0.25 to 24.0 hours
+ */
+export function isValidHours(hours?: number | null): boolean {
+  if (hours === undefined || hours === null) return false;
+  if (typeof hours !== 'number' || isNaN(hours)) return false;
+  return (
+    isQuarterHourIncrement(hours) &&
+    hours >= MIN_ENTRY_HOURS &&
+    hours <= MAX_ENTRY_HOURS
+  );
+}
+
+/**
+ * Rounds a duration in minutes to hours in 15-minute increments (nearest quarter hour)
+ */
+export function roundMinutesToQuarterHours(minutes: number): number {
+  return Math.round(minutes / 15) / 4;
+}
+
+/**
+ * Formats hours with two decimals (e.g. 7.5 -> "7.50")
+ */
+export function formatHours(hours: number): string {
+  return hours.toFixed(2);
+}
@@ -0,0 +1,34 @@
+import { describe, it, expect } from 'vitest';
+import {
+  SubmissionStatus,
+  isSubmissionStatus,
+  parseSubmissionStatus
+} from '@sheetpilot/shared/src/types/submission-status';
+
+describe('submission-status', () => {
+  describe('isSubmissionStatus', () => {
+    it('should accept only the canonical spellings', () => {
+      expect(isSubmissionStatus('Complete')).toBe(true);
+      expect(isSubmissionStatus('in_progress')).toBe(true);
+      expect(isSubmissionStatus('complete')).toBe(false);
+      expect(isSubmissionStatus(null)).toBe(false);
+    });
+  });
+
+  describe('parseSubmissionStatus', () => {
+    it('should treat null and undefined as drafts', () => {
+      expect(parseSubmissionStatus(null)).toBeNull();
+      expect(parseSubmissionStatus(undefined)).toBeNull();
+    });
+
+    it('should normalize case and whitespace variants', () => {
+      expect(parseSubmissionStatus(' complete ')).toBe(SubmissionStatus.COMPLETE);
+      expect(parseSubmissionStatus('IN_PROGRESS')).toBe(SubmissionStatus.IN_PROGRESS);
+    });
+
+    it('should reject unknown statuses', () => {
+      expect(parseSubmissionStatus('failed')).toBeUndefined();
+      expect(parseSubmissionStatus(1)).toBeUndefined();
+    });
+  });
+});
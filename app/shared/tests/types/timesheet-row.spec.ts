@@ -0,0 +1,83 @@
+import { describe, it, expect } from 'vitest';
+import {
+  TIMESHEET_ROW_COLUMNS,
+  toTimesheetRow,
+  type TimesheetRowSource
+} from '@sheetpilot/shared/src/types/timesheet-row';
+
+const source: TimesheetRowSource = {
+  id: 7,
+  date: '01/15/2025',
+  hours: 2.5,
+  project: 'FL-Carver Techs',
+  tool: 'DECA Meter',
+  detail_charge_code: 'EPR1',
+  task_description: 'Calibration',
+  amends_id: 3,
+  failure_reason: 'Hours: Must be a number',
+  time_zone: 'America/Chicago',
+  external_ref: 'WO-123456',
+  category: 'Production support',
+  entry_kind: 'pto',
+  updated_at: '2025-01-15 16:02:11'
+};
+
+describe('timesheet-row', () => {
+  describe('toTimesheetRow', () => {
+    it('should rename every column to its camelCase field', () => {
+      const row = toTimesheetRow(source);
+
+      expect(row).toEqual({
+        id: 7,
+        date: '01/15/2025',
+        hours: 2.5,
+        project: 'FL-Carver Techs',
+        tool: 'DECA Meter',
+        chargeCode: 'EPR1',
+        taskDescription: 'Calibration',
+        amendsId: 3,
+        failureReason: 'Hours: Must be a number',
+        timeZone: 'America/Chicago',
+        externalRef: 'WO-123456',
+        category: 'Production support',
+        kind: 'pto',
+        updatedAt: '2025-01-15 16:02:11'
+      });
+      for (const [field, column] of Object.entries(TIMESHEET_ROW_COLUMNS)) {
+        expect(row[field as keyof typeof row]).toBe(source[column]);
+      }
+    });
+
+    it('should null empty tool and charge code and leave out unset optional fields', () => {
+      const row = toTimesheetRow({
+        ...source,
+        hours: null,
+        tool: '',
+        detail_charge_code: null,
+        amends_id: null,
+        failure_reason: null,
+        time_zone: null,
+        external_ref: '',
+        category: null,
+        entry_kind: null,
+        updated_at: null
+      });
+
+      expect(row.tool).toBeNull();
+      expect(row.chargeCode).toBeNull();
+      expect(row).not.toHaveProperty('hours');
+      expect(row).not.toHaveProperty('amendsId');
+      expect(row).not.toHaveProperty('failureReason');
+      expect(row).not.toHaveProperty('timeZone');
+      expect(row).not.toHaveProperty('externalRef');
+      expect(row).not.toHaveProperty('category');
+      expect(row).not.toHaveProperty('kind');
+      expect(row).not.toHaveProperty('updatedAt');
+    });
+
+    it('should leave out work and unknown kinds', () => {
+      expect(toTimesheetRow({ ...source, entry_kind: 'work' })).not.toHaveProperty('kind');
+      expect(toTimesheetRow({ ...source, entry_kind: 'vacation' })).not.toHaveProperty('kind');
+    });
+  });
+});
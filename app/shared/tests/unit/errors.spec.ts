@@ -5,6 +5,7 @@ import {
   DatabaseError,
   DatabaseConnectionError,
   DatabaseSchemaError,
+  DatabaseSchemaTooNewError,
   DatabaseQueryError,
   CredentialsError,
   CredentialsNotFoundError,
@@ -17,7 +18,8 @@ import {
   IPCError,
   ConfigurationError,
   BusinessLogicError,
-  SystemError
+  SystemError,
+  isRetryableError
 } from '@sheetpilot/shared/errors';
 
 describe('errors', () => {
@@ -92,6 +94,14 @@ describe('errors', () => {
       expect(error.code).toBe('DB_SCHEMA_ERROR');
     });
 
+    it('should create schema too new error that is not retried', () => {
+      const error = new DatabaseSchemaTooNewError({ schemaVersion: 20, appSchemaVersion: 13 });
+
+      expect(error).toBeInstanceOf(DatabaseError);
+      expect(error.code).toBe('DB_SCHEMA_TOO_NEW');
+      expect(isRetryableError(error)).toBe(false);
+    });
+
     it('should create query error', () => {
       const error = new DatabaseQueryError('Query failed', { sql: 'SELECT * FROM test' });
       
@@ -0,0 +1,37 @@
+import { describe, it, expect } from 'vitest';
+import {
+  annotateWithClockSkew,
+  describeClockSkew,
+  formatClockSkew,
+  isClockSkewed
+} from '@sheetpilot/shared/src/utils/clock-skew';
+
+const MINUTE = 60 * 1000;
+const DAY = 24 * 60 * MINUTE;
+
+describe('clock-skew', () => {
+  it('should describe the skew in the largest sensible unit', () => {
+    expect(formatClockSkew(-21 * DAY)).toBe('3 weeks');
+    expect(formatClockSkew(2 * DAY)).toBe('2 days');
+    expect(formatClockSkew(90 * MINUTE)).toBe('2 hours');
+    expect(formatClockSkew(-12 * MINUTE)).toBe('12 minutes');
+    expect(describeClockSkew(-21 * DAY)).toBe('Your computer clock is about 3 weeks behind');
+    expect(describeClockSkew(DAY)).toBe('Your computer clock is about 1 day ahead');
+  });
+
+  it('should only flag skew past the threshold', () => {
+    expect(isClockSkewed(null)).toBe(false);
+    expect(isClockSkewed(5 * MINUTE)).toBe(false);
+    expect(isClockSkewed(-10 * MINUTE)).toBe(true);
+    expect(isClockSkewed(5 * MINUTE, MINUTE)).toBe(true);
+  });
+
+  it('should annotate messages only when the clock is off', () => {
+    expect(annotateWithClockSkew('Date must be within allowed quarter range', 30 * 1000)).toBe(
+      'Date must be within allowed quarter range'
+    );
+    expect(annotateWithClockSkew('Date must be within allowed quarter range', -21 * DAY)).toBe(
+      'Date must be within allowed quarter range (Your computer clock is about 3 weeks behind; fix the clock and restart SheetPilot)'
+    );
+  });
+});
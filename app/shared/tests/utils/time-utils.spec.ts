@@ -0,0 +1,98 @@
+import { describe, it, expect } from 'vitest';
+import {
+  MINUTES_PER_DAY,
+  parseTimeToMinutes,
+  formatMinutesToTime,
+  minutesBetween,
+  hoursBetweenTimes,
+  isQuarterHourIncrement,
+  isValidHours,
+  roundMinutesToQuarterHours,
+  formatHours
+} from '@sheetpilot/shared/src/utils/time-utils';
+
+/** Every minute of the day; the domain is small enough to check exhaustively */
+const ALL_MINUTES = Array.from({ length: MINUTES_PER_DAY }, (_, i) => i);
+
+/** Every valid entry hours value (0.25 to 24 in quarter hours) */
+const ALL_QUARTER_HOURS = Array.from({ length: 96 }, (_, i) => (i + 1) / 4);
+
+describe('time-utils', () => {
+  describe('round trips', () => {
+    it('should parse every formatted minute of the day back to itself', () => {
+      for (const minutes of ALL_MINUTES) {
+        expect(parseTimeToMinutes(formatMinutesToTime(minutes))).toBe(minutes);
+      }
+    });
+
+    it('should format every parsed time back to its zero-padded form', () => {
+      for (const minutes of ALL_MINUTES) {
+        const time = formatMinutesToTime(minutes);
+        expect(time).toMatch(/^\d{2}:\d{2}$/);
+        expect(formatMinutesToTime(parseTimeToMinutes(time))).toBe(time);
+      }
+    });
+
+    it('should accept every quarter hour and round its minutes back to it', () => {
+      for (const hours of ALL_QUARTER_HOURS) {
+        expect(isValidHours(hours)).toBe(true);
+        expect(roundMinutesToQuarterHours(hours * 60)).toBe(hours);
+      }
+    });
+  });
+
+  describe('parseTimeToMinutes', () => {
+    it('should reject out of range and malformed times', () => {
+      expect(() => parseTimeToMinutes('24:00')).toThrow('Invalid time format');
+      expect(() => parseTimeToMinutes('08:60')).toThrow('Invalid time format');
+      expect(() => parseTimeToMinutes('8:5')).toThrow('Invalid time format');
+      expect(() => parseTimeToMinutes('-1:00')).toThrow('Invalid time format');
+    });
+
+    it('should accept single digit hours', () => {
+      expect(parseTimeToMinutes('8:05')).toBe(485);
+    });
+  });
+
+  describe('overnight math', () => {
+    it('should add a day when the end is before the start', () => {
+      expect(minutesBetween(22 * 60, 2 * 60)).toBe(240);
+      expect(hoursBetweenTimes('23:45', '00:15')).toBe(0.5);
+    });
+
+    it('should always return a duration within one day', () => {
+      for (let start = 0; start < MINUTES_PER_DAY; start += 7) {
+        for (let end = 0; end < MINUTES_PER_DAY; end += 11) {
+          const duration = minutesBetween(start, end);
+          expect(duration).toBeGreaterThanOrEqual(0);
+          expect(duration).toBeLessThan(MINUTES_PER_DAY);
+          expect((start + duration) % MINUTES_PER_DAY).toBe(end);
+        }
+      }
+    });
+  });
+
+  describe('increments', () => {
+    it('should reject values off the 15-minute grid or out of range', () => {
+      expect(isQuarterHourIncrement(1.1)).toBe(false);
+      expect(isQuarterHourIncrement(0.1 + 0.2 + 0.45)).toBe(true);
+      expect(isValidHours(0)).toBe(false);
+      expect(isValidHours(24.25)).toBe(false);
+      expect(isValidHours(null)).toBe(false);
+      expect(isValidHours(NaN)).toBe(false);
+    });
+
+    it('should round minutes to the nearest quarter hour', () => {
+      expect(roundMinutesToQuarterHours(7)).toBe(0);
+      expect(roundMinutesToQuarterHours(8)).toBe(0.25);
+      expect(roundMinutesToQuarterHours(52)).toBe(0.75);
+    });
+  });
+
+  describe('formatHours', () => {
+    it('should format with two decimals', () => {
+      expect(formatHours(7.5)).toBe('7.50');
+      expect(formatHours(0.25)).toBe('0.25');
+    });
+  });
+});